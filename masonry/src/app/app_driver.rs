@@ -1,6 +1,8 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use winit::window::Theme as SystemTheme;
+
 use crate::app::{MasonryState, RenderRoot};
 use crate::core::{Action, WidgetId};
 
@@ -31,6 +33,16 @@ pub trait AppDriver {
     ///
     /// Use cases include loading fonts.
     fn on_start(&mut self, state: &mut MasonryState) {}
+
+    #[allow(unused_variables)]
+    // reason: otherwise `ctx` would need to be named `_ctx` which behaves badly when using rust-analyzer to implement the trait
+    /// A hook which will be executed when the platform's light or dark preference changes.
+    ///
+    /// This is also executed when the window is created, before its first frame is painted,
+    /// so that apps which follow the preference don't start with the wrong colors.
+    /// The widgets have already been sent [`Update::ThemeChanged`](crate::core::Update::ThemeChanged)
+    /// when this is called.
+    fn on_system_theme_changed(&mut self, ctx: &mut DriverCtx<'_>, theme: SystemTheme) {}
 }
 
 impl DriverCtx<'_> {
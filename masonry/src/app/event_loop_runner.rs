@@ -20,7 +20,7 @@ use winit::event::{
     WindowEvent as WinitWindowEvent,
 };
 use winit::event_loop::ActiveEventLoop;
-use winit::window::{Window, WindowAttributes, WindowId};
+use winit::window::{Theme as SystemTheme, Window, WindowAttributes, WindowId};
 
 use crate::app::{
     AppDriver, DriverCtx, RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy,
@@ -157,7 +157,8 @@ pub fn run_with(
 
 impl ApplicationHandler<MasonryUserEvent> for MainState<'_> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        self.masonry_state.handle_resumed(event_loop);
+        self.masonry_state
+            .handle_resumed(event_loop, self.app_driver.as_mut());
     }
 
     fn suspended(&mut self, event_loop: &ActiveEventLoop) {
@@ -256,7 +257,7 @@ impl MasonryState<'_> {
     }
 
     // --- MARK: RESUMED ---
-    pub fn handle_resumed(&mut self, event_loop: &ActiveEventLoop) {
+    pub fn handle_resumed(&mut self, event_loop: &ActiveEventLoop, app_driver: &mut dyn AppDriver) {
         match std::mem::replace(
             &mut self.window,
             // TODO: Is there a better default value which could be used?
@@ -283,6 +284,7 @@ impl MasonryState<'_> {
                 ))
                 .unwrap();
                 let scale_factor = window.scale_factor();
+                let system_theme = window.theme().unwrap_or(SystemTheme::Dark);
                 self.window = WindowState::Rendering {
                     window,
                     surface,
@@ -290,6 +292,13 @@ impl MasonryState<'_> {
                 };
                 self.render_root
                     .handle_window_event(WindowEvent::Rescale(scale_factor));
+                // The driver is always told the initial system theme, even if it matches the default.
+                self.render_root
+                    .handle_window_event(WindowEvent::SystemThemeChanged(system_theme));
+                let mut driver_ctx = DriverCtx {
+                    render_root: &mut self.render_root,
+                };
+                app_driver.on_system_theme_changed(&mut driver_ctx, system_theme);
                 // Render one frame before showing the window to avoid flashing
                 if visible {
                     let (scene, tree_update) = self.render_root.redraw();
@@ -500,6 +509,14 @@ impl MasonryState<'_> {
                 self.render_root
                     .handle_window_event(WindowEvent::Resize(size));
             }
+            WinitWindowEvent::ThemeChanged(system_theme) => {
+                self.render_root
+                    .handle_window_event(WindowEvent::SystemThemeChanged(system_theme));
+                let mut driver_ctx = DriverCtx {
+                    render_root: &mut self.render_root,
+                };
+                app_driver.on_system_theme_changed(&mut driver_ctx, system_theme);
+            }
             WinitWindowEvent::ModifiersChanged(modifiers) => {
                 self.pointer_state.mods = modifiers;
                 self.render_root
@@ -511,7 +528,7 @@ impl MasonryState<'_> {
                 is_synthetic: false, // TODO: Introduce an escape hatch for synthetic keys
             } => {
                 self.render_root.handle_text_event(TextEvent::KeyboardKey(
-                    event,
+                    event.into(),
                     self.pointer_state.mods.state(),
                 ));
             }
@@ -0,0 +1,184 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use smallvec::SmallVec;
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Point, Size};
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, LayoutCtx, PaintCtx, PointerEvent,
+    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+
+/// The actual root of the widget tree, which draws layers above the app's root widget.
+///
+/// Layers are used for content such as tooltips and popups, which shouldn't be clipped by
+/// the widget which creates them. They are added and removed using
+/// [`EventCtx::add_layer`] and [`EventCtx::remove_layer`].
+///
+/// This widget is an implementation detail of [`RenderRoot`](crate::app::RenderRoot):
+/// the "root widget" seen by users of Masonry is its first child.
+pub(crate) struct LayerStack {
+    root: WidgetPod<dyn Widget>,
+    /// The layers, in increasing z-order.
+    layers: Vec<Layer>,
+}
+
+struct Layer {
+    widget: WidgetPod<dyn Widget>,
+    /// The requested position of the layer's top-left corner, in window coordinates.
+    position: Point,
+    /// The widget which added the layer.
+    ///
+    /// The layer is removed when this widget is removed from the tree.
+    owner: WidgetId,
+}
+
+impl LayerStack {
+    pub(crate) fn new(root: WidgetPod<dyn Widget>) -> Self {
+        Self {
+            root,
+            layers: Vec::new(),
+        }
+    }
+
+    /// The ids of the layers whose owner doesn't satisfy `is_in_tree`.
+    pub(crate) fn orphaned_layers(&self, is_in_tree: impl Fn(WidgetId) -> bool) -> Vec<WidgetId> {
+        self.layers
+            .iter()
+            .filter(|layer| !is_in_tree(layer.owner))
+            .map(|layer| layer.widget.id())
+            .collect()
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl LayerStack {
+    /// Add a layer above all existing layers, which is owned by the widget `owner`.
+    pub(crate) fn add_layer(
+        this: &mut WidgetMut<'_, Self>,
+        widget: WidgetPod<dyn Widget>,
+        position: Point,
+        owner: WidgetId,
+    ) {
+        this.widget.layers.push(Layer {
+            widget,
+            position,
+            owner,
+        });
+        this.ctx.children_changed();
+    }
+
+    /// Remove the layer whose root widget has the given id.
+    ///
+    /// Does nothing if there is no such layer, e.g. because it was already removed.
+    pub(crate) fn remove_layer(this: &mut WidgetMut<'_, Self>, id: WidgetId) {
+        let Some(idx) = this
+            .widget
+            .layers
+            .iter()
+            .position(|layer| layer.widget.id() == id)
+        else {
+            return;
+        };
+        let layer = this.widget.layers.remove(idx);
+        this.ctx.remove_child(layer.widget);
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for LayerStack {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.root);
+        for layer in &mut self.layers {
+            ctx.register_child(&mut layer.widget);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.root, bc);
+        ctx.place_child(&mut self.root, Point::ORIGIN);
+
+        let layer_bc = BoxConstraints::new(Size::ZERO, size);
+        for layer in &mut self.layers {
+            let layer_size = ctx.run_layout(&mut layer.widget, &layer_bc);
+            // Move the layer so that it fits within the window.
+            // If it's too big to fit, we prefer showing its top-left corner.
+            let origin = Point::new(
+                layer.position.x.min(size.width - layer_size.width).max(0.),
+                layer
+                    .position
+                    .y
+                    .min(size.height - layer_size.height)
+                    .max(0.),
+            );
+            ctx.place_child(&mut layer.widget, origin);
+        }
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    // The stack is the root of the accessibility tree, rather than the app's root widget,
+    // so that the layers are part of the tree too. Assistive technologies skip over
+    // generic containers, so the app's root widget (usually a `RootWidget`, with the
+    // `Window` role) is still the first node they present.
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        // Pointer events outside of the root widget and the layers shouldn't target the stack.
+        false
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        std::iter::once(self.root.id())
+            .chain(self.layers.iter().map(|layer| layer.widget.id()))
+            .collect()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("LayerStack", id = ctx.widget_id().trace())
+    }
+}
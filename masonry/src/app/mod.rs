@@ -5,6 +5,7 @@
 
 mod app_driver;
 mod event_loop_runner;
+mod layer_stack;
 mod render_root;
 mod tracing_backend;
 
@@ -14,5 +15,6 @@ pub use event_loop_runner::{
 };
 pub use render_root::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
 
+pub(crate) use layer_stack::LayerStack;
 pub(crate) use render_root::{MutateCallback, RenderRootState};
 pub(crate) use tracing_backend::{try_init_test_tracing, try_init_tracing};
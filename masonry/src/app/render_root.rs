@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 
 use accesskit::{ActionRequest, TreeUpdate};
 use anymap3::AnyMap;
@@ -11,9 +12,9 @@ use tracing::{info_span, warn};
 use tree_arena::{ArenaMut, TreeArena};
 use vello::Scene;
 use vello::kurbo::{
-    Rect, {self},
+    Rect, Size, {self},
 };
-use winit::window::ResizeDirection;
+use winit::window::{ResizeDirection, Theme as SystemTheme};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
@@ -21,9 +22,10 @@ use std::time::Instant;
 use web_time::Instant;
 
 use crate::Handled;
+use crate::app::LayerStack;
 use crate::core::{
-    AccessEvent, Action, BrushIndex, PointerEvent, PropertiesRef, QueryCtx, TextEvent, Widget,
-    WidgetArena, WidgetId, WidgetMut, WidgetPod, WidgetRef, WidgetState, WindowEvent,
+    AccessEvent, Action, BrushIndex, Hotkey, PointerEvent, PropertiesRef, QueryCtx, TextEvent,
+    Widget, WidgetArena, WidgetId, WidgetMut, WidgetPod, WidgetRef, WidgetState, WindowEvent,
 };
 use crate::dpi::{LogicalPosition, LogicalSize, PhysicalSize};
 use crate::passes::accessibility::run_accessibility_pass;
@@ -37,10 +39,11 @@ use crate::passes::mutate::{mutate_widget, run_mutate_pass};
 use crate::passes::paint::run_paint_pass;
 use crate::passes::update::{
     run_update_disabled_pass, run_update_focus_chain_pass, run_update_focus_pass,
-    run_update_pointer_pass, run_update_scroll_pass, run_update_stashed_pass,
-    run_update_widget_tree_pass,
+    run_update_pointer_pass, run_update_scroll_pass, run_update_size_pass, run_update_stashed_pass,
+    run_update_theme_pass, run_update_widget_tree_pass,
 };
 use crate::passes::{PassTracing, recurse_on_children};
+use crate::theme::{Theme, default_theme};
 use cursor_icon::CursorIcon;
 
 /// We ensure that any valid initial IME area is sent to the platform by storing an invalid initial
@@ -57,8 +60,14 @@ const INVALID_IME_AREA: Rect = Rect::new(f64::NAN, f64::NAN, f64::NAN, f64::NAN)
 /// This is also the type that owns the widget tree.
 pub struct RenderRoot {
     /// Root of the widget tree.
+    ///
+    /// This is a [`LayerStack`] containing the root widget passed to [`RenderRoot::new`],
+    /// and any layers added by widgets.
     pub(crate) root: WidgetPod<dyn Widget>,
 
+    /// The root widget passed to [`RenderRoot::new`].
+    pub(crate) app_root_id: WidgetId,
+
     /// Whether the window size should be determined by the content or the user.
     pub(crate) size_policy: WindowSizePolicy,
 
@@ -87,6 +96,9 @@ pub struct RenderRoot {
 
 /// State shared between passes.
 pub(crate) struct RenderRootState {
+    /// The id of the [`LayerStack`] at the root of the widget tree.
+    pub(crate) layer_stack_id: WidgetId,
+
     /// Queue of signals to be processed by the event loop.
     pub(crate) signal_queue: VecDeque<RenderRootSignal>,
 
@@ -99,6 +111,16 @@ pub(crate) struct RenderRootState {
     /// Widget that will be focused once the `update_focus` pass is run.
     pub(crate) next_focused_widget: Option<WidgetId>,
 
+    /// Widgets which focus can't leave, such as open modals, in the order they were added.
+    ///
+    /// Only the last of these is in effect.
+    pub(crate) focus_traps: Vec<WidgetId>,
+
+    /// Hotkeys and the widgets which registered them, in the order they were registered.
+    ///
+    /// Entries for removed widgets are cleaned up when a hotkey is next pressed.
+    pub(crate) hotkeys: Vec<(WidgetId, Hotkey)>,
+
     /// Most recently clicked widget.
     ///
     /// This is used to pick the focused widget on Tab events.
@@ -110,6 +132,14 @@ pub(crate) struct RenderRootState {
     /// Widgets that have requested to be scrolled into view.
     pub(crate) scroll_request_targets: Vec<(WidgetId, Rect)>,
 
+    /// When a widget last scrolled in response to a mouse wheel event.
+    ///
+    /// Used by [`WheelAdjust`](crate::widgets::WheelAdjust) to avoid interrupting scroll gestures.
+    pub(crate) last_wheel_scroll: Option<Instant>,
+
+    /// Widgets whose size changed during layout, along with their size before that layout.
+    pub(crate) size_changed_widgets: Vec<(WidgetId, Size)>,
+
     /// List of ancestors of the currently hovered widget.
     pub(crate) hovered_path: Vec<WidgetId>,
 
@@ -148,6 +178,12 @@ pub(crate) struct RenderRootState {
     ///
     /// Kurbo coordinates are assumed to be in logical pixels
     pub(crate) scale_factor: f64,
+
+    /// The theme of widgets which don't have one set by an ancestor.
+    pub(crate) theme: Arc<Theme>,
+
+    /// The platform's light or dark preference.
+    pub(crate) system_theme: SystemTheme,
 }
 
 pub(crate) struct MutateCallback {
@@ -255,20 +291,31 @@ impl RenderRoot {
         } = options;
         let debug_paint = std::env::var("MASONRY_DEBUG_PAINT").is_ok_and(|it| !it.is_empty());
 
+        let app_root = WidgetPod::new(root_widget).erased();
+        let app_root_id = app_root.id();
+        let layer_stack = WidgetPod::new(LayerStack::new(app_root)).erased();
+        let layer_stack_id = layer_stack.id();
+
         let mut root = Self {
-            root: WidgetPod::new(root_widget).erased(),
+            root: layer_stack,
+            app_root_id,
             size_policy,
             size: PhysicalSize::new(0, 0),
             last_anim: None,
             last_mouse_pos: None,
             global_state: RenderRootState {
+                layer_stack_id,
                 signal_queue: VecDeque::new(),
                 focused_widget: None,
                 focused_path: Vec::new(),
                 next_focused_widget: None,
+                focus_traps: Vec::new(),
+                hotkeys: Vec::new(),
                 most_recently_clicked_widget: None,
                 window_focused: true,
                 scroll_request_targets: Vec::new(),
+                size_changed_widgets: Vec::new(),
+                last_wheel_scroll: None,
                 hovered_path: Vec::new(),
                 pointer_capture_target: None,
                 cursor_icon: CursorIcon::Default,
@@ -291,6 +338,8 @@ impl RenderRoot {
                     hovered_widget: None,
                 },
                 scale_factor,
+                theme: default_theme(),
+                system_theme: SystemTheme::Dark,
             },
             widget_arena: WidgetArena {
                 widgets: TreeArena::new(),
@@ -376,6 +425,17 @@ impl RenderRoot {
                     .emit_signal(RenderRootSignal::RequestRedraw);
                 Handled::Yes
             }
+            WindowEvent::SystemThemeChanged(system_theme) => {
+                if self.global_state.system_theme == system_theme {
+                    return Handled::No;
+                }
+                self.global_state.system_theme = system_theme;
+                // Every widget may be styled based on the system theme, not only those
+                // whose theme changed.
+                run_update_theme_pass(self, true);
+                self.run_rewrite_passes();
+                Handled::Yes
+            }
         }
     }
 
@@ -473,37 +533,44 @@ impl RenderRoot {
         self.global_state.cursor_icon
     }
 
+    /// The theme of widgets which don't have one set by an ancestor.
+    pub fn theme(&self) -> &Theme {
+        &self.global_state.theme
+    }
+
+    /// Change the theme of widgets which don't have one set by an ancestor.
+    ///
+    /// The widgets aren't recreated; they are sent [`Update::ThemeChanged`](crate::core::Update::ThemeChanged),
+    /// then laid out and painted again.
+    /// See [`Theme`] for details.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.global_state.theme = Arc::new(theme);
+        self.root_state_mut().needs_update_theme = true;
+        self.run_rewrite_passes();
+    }
+
+    /// The platform's light or dark preference.
+    ///
+    /// This is [`Dark`](SystemTheme::Dark) if the platform doesn't report a preference.
+    /// Widgets are sent [`Update::ThemeChanged`](crate::core::Update::ThemeChanged) when this changes.
+    pub fn system_theme(&self) -> SystemTheme {
+        self.global_state.system_theme
+    }
+
     // --- MARK: ACCESS WIDGETS---
     /// Get a [`WidgetRef`] to the root widget.
     pub fn get_root_widget(&self) -> WidgetRef<dyn Widget> {
-        let root_state_token = self.widget_arena.states.roots();
-        let root_widget_token = self.widget_arena.widgets.roots();
-        let root_properties_token = self.widget_arena.properties.roots();
-        let state_ref = root_state_token
-            .into_item(self.root.id())
-            .expect("root widget not in widget tree");
-        let widget_ref = root_widget_token
-            .into_item(self.root.id())
-            .expect("root widget not in widget tree");
-        let properties_ref = root_properties_token
-            .into_item(self.root.id())
-            .expect("root widget not in widget tree");
-        let widget = &**widget_ref.item;
-        let ctx = QueryCtx {
-            global_state: &self.global_state,
-            widget_state_children: state_ref.children,
-            widget_children: widget_ref.children,
-            widget_state: state_ref.item,
-            properties_children: properties_ref.children,
-        };
-        let properties = PropertiesRef {
-            map: properties_ref.item,
-        };
-        WidgetRef {
-            ctx,
-            properties,
-            widget,
-        }
+        self.get_widget(self.app_root_id)
+            .expect("root widget not in widget tree")
+    }
+
+    /// Get a [`WidgetRef`] to the [`LayerStack`] at the root of the widget tree.
+    ///
+    /// Unlike [`get_root_widget`](Self::get_root_widget), this includes layers,
+    /// so it should be used to find the widget at a given position.
+    pub(crate) fn get_layer_stack(&self) -> WidgetRef<dyn Widget> {
+        self.get_widget(self.root.id())
+            .expect("layer stack not in widget tree")
     }
 
     /// Get a [`WidgetRef`] to a specific widget.
@@ -542,7 +609,7 @@ impl RenderRoot {
     ///
     /// Because of how `WidgetMut` works, it can only be passed to a user-provided callback.
     pub fn edit_root_widget<R>(&mut self, f: impl FnOnce(WidgetMut<'_, dyn Widget>) -> R) -> R {
-        let res = mutate_widget(self, self.root.id(), f);
+        let res = mutate_widget(self, self.app_root_id, f);
 
         self.run_rewrite_passes();
 
@@ -589,9 +656,11 @@ impl RenderRoot {
             run_update_widget_tree_pass(self);
             run_update_disabled_pass(self);
             run_update_stashed_pass(self);
+            run_update_theme_pass(self, false);
             run_update_focus_chain_pass(self);
             run_update_focus_pass(self);
             run_layout_pass(self);
+            run_update_size_pass(self);
             run_update_scroll_pass(self);
             run_compose_pass(self);
             run_update_pointer_pass(self);
@@ -686,12 +755,13 @@ impl RenderRoot {
     }
 
     pub(crate) fn widget_from_focus_chain(&mut self, forward: bool) -> Option<WidgetId> {
+        let focus_chain = self.focus_chain();
         let focused_widget = self
             .global_state
             .focused_widget
             .or(self.global_state.most_recently_clicked_widget);
         let focused_idx = focused_widget.and_then(|focused_widget| {
-            self.focus_chain()
+            focus_chain
                 .iter()
                 // Find where the focused widget is in the focus chain
                 .position(|id| id == &focused_widget)
@@ -699,28 +769,53 @@ impl RenderRoot {
 
         if let Some(idx) = focused_idx {
             // Return the id that's next to it in the focus chain
-            let len = self.focus_chain().len();
+            let len = focus_chain.len();
             let new_idx = if forward {
                 (idx + 1) % len
             } else {
                 (idx + len - 1) % len
             };
-            Some(self.focus_chain()[new_idx])
+            Some(focus_chain[new_idx])
         } else {
             // If no widget is currently focused or the
             // currently focused widget isn't in the focus chain,
             // then we'll just return the first/last entry of the chain, if any.
             if forward {
-                self.focus_chain().first().copied()
+                focus_chain.first().copied()
             } else {
-                self.focus_chain().last().copied()
+                focus_chain.last().copied()
             }
         }
     }
 
     // TODO - Store in RenderRootState
-    pub(crate) fn focus_chain(&mut self) -> &[WidgetId] {
-        &self.root_state().focus_chain
+    /// The widgets which Tab moves focus between, in order.
+    ///
+    /// Widgets with a positive tab index come first, in ascending order of index,
+    /// followed by those with no tab index or an index of 0, in tree order.
+    /// Widgets with a negative tab index are left out.
+    pub(crate) fn focus_chain(&self) -> Vec<WidgetId> {
+        // Whilst focus is trapped, e.g. by a modal, Tab only moves between the widgets inside it.
+        let trap_state = self
+            .global_state
+            .focus_traps
+            .last()
+            .and_then(|trap| self.widget_arena.states.find(*trap));
+        let chain = match &trap_state {
+            Some(state) => &state.item.focus_chain,
+            None => &self.root_state().focus_chain,
+        };
+
+        let mut chain: Vec<_> = chain
+            .iter()
+            .filter(|(_, tab_index)| tab_index.is_none_or(|index| index >= 0))
+            .collect();
+        // The sort is stable, so widgets with the same index stay in tree order.
+        chain.sort_by_key(|(_, tab_index)| match tab_index {
+            Some(index) if *index > 0 => (false, *index),
+            _ => (true, 0),
+        });
+        chain.into_iter().map(|(id, _)| *id).collect()
     }
 
     pub(crate) fn needs_rewrite_passes(&self) -> bool {
@@ -2,8 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::Any;
+use std::ops::Range;
 
-use crate::core::PointerButton;
+use vello::kurbo::{Point, Size, Vec2};
+
+use crate::core::{Hotkey, PointerButton, PointerEvent};
+use crate::widgets::CheckboxState;
 
 // TODO - Replace actions with an associated type on the Widget trait
 // See https://github.com/linebender/xilem/issues/664
@@ -20,8 +24,57 @@ pub enum Action {
     TextChanged(String),
     /// Text entered.
     TextEntered(String),
-    /// A checkbox was toggled.
-    CheckboxToggled(bool),
+    /// The user cleared the text, using the clear button of a
+    /// [`Textbox`](crate::widgets::Textbox).
+    ///
+    /// The change to the now empty text is also reported with `TextChanged`, as usual.
+    TextCleared,
+    /// The selection or caret of a text area moved.
+    TextSelectionChanged(SelectionChange),
+    /// A link in a text area was clicked, and this is its URL.
+    ///
+    /// See [`TextArea::with_autolink`](crate::widgets::TextArea::with_autolink).
+    LinkClicked(String),
+    /// A checkbox was toggled, and is now in the given state.
+    CheckboxToggled(CheckboxState),
+    /// A slider was adjusted.
+    Slider(SliderAction),
+    /// A radio button was interacted with.
+    RadioButton(RadioButtonAction),
+    /// A drop down was interacted with.
+    DropDown(DropDownAction),
+    /// A modal was closed by the user, e.g. by pressing Escape.
+    ModalDismissed,
+    /// An item was chosen from a context menu.
+    ///
+    /// This is the path of indices leading to the item: the index of an item in the top-level
+    /// menu, followed by its index in each submenu.
+    ContextMenuItemSelected(Vec<usize>),
+    /// A portal was scrolled.
+    Portal(PortalAction),
+    /// The splitter bar of a [`Split`](crate::widgets::Split) was moved by the user.
+    SplitResized(SplitChange),
+    /// The user selected the tab with the given index in a [`Tabs`](crate::widgets::Tabs).
+    TabSelected(usize),
+    /// The user expanded (`true`) or collapsed (`false`) an
+    /// [`Expander`](crate::widgets::Expander).
+    ExpanderToggled(bool),
+    /// A [`VirtualList`](crate::widgets::VirtualList) needs widgets for a different range of rows,
+    /// e.g. because it was scrolled.
+    VirtualListRange(Range<usize>),
+    /// A [`GestureDetector`](crate::widgets::GestureDetector) recognised a gesture.
+    Gesture(GestureAction),
+    /// A canvas received a pointer event.
+    CanvasPointer(CanvasPointerEvent),
+    /// A hotkey registered by a [`Hotkeys`](crate::widgets::Hotkeys) widget was pressed.
+    HotkeyPressed(Hotkey),
+    /// The pointer started (`true`) or stopped (`false`) hovering a
+    /// [`HoverDetector`](crate::widgets::HoverDetector).
+    HoverChanged(bool),
+    /// An active [`Ticker`](crate::widgets::Ticker) received an animation frame.
+    ///
+    /// This is the time since the previous frame, in nanoseconds.
+    AnimationFrame(u64),
     // FIXME - This is a huge hack
     /// Other.
     Other(Box<dyn Any + Send>),
@@ -33,7 +86,25 @@ impl PartialEq for Action {
             (Self::ButtonPressed(l_button), Self::ButtonPressed(r_button)) => l_button == r_button,
             (Self::TextChanged(l0), Self::TextChanged(r0)) => l0 == r0,
             (Self::TextEntered(l0), Self::TextEntered(r0)) => l0 == r0,
+            (Self::TextCleared, Self::TextCleared) => true,
+            (Self::TextSelectionChanged(l0), Self::TextSelectionChanged(r0)) => l0 == r0,
+            (Self::LinkClicked(l0), Self::LinkClicked(r0)) => l0 == r0,
             (Self::CheckboxToggled(l0), Self::CheckboxToggled(r0)) => l0 == r0,
+            (Self::Slider(l0), Self::Slider(r0)) => l0 == r0,
+            (Self::RadioButton(l0), Self::RadioButton(r0)) => l0 == r0,
+            (Self::DropDown(l0), Self::DropDown(r0)) => l0 == r0,
+            (Self::ModalDismissed, Self::ModalDismissed) => true,
+            (Self::ContextMenuItemSelected(l0), Self::ContextMenuItemSelected(r0)) => l0 == r0,
+            (Self::Portal(l0), Self::Portal(r0)) => l0 == r0,
+            (Self::SplitResized(l0), Self::SplitResized(r0)) => l0 == r0,
+            (Self::TabSelected(l0), Self::TabSelected(r0)) => l0 == r0,
+            (Self::ExpanderToggled(l0), Self::ExpanderToggled(r0)) => l0 == r0,
+            (Self::VirtualListRange(l0), Self::VirtualListRange(r0)) => l0 == r0,
+            (Self::Gesture(l0), Self::Gesture(r0)) => l0 == r0,
+            (Self::CanvasPointer(l0), Self::CanvasPointer(r0)) => l0 == r0,
+            (Self::HotkeyPressed(l0), Self::HotkeyPressed(r0)) => l0 == r0,
+            (Self::HoverChanged(l0), Self::HoverChanged(r0)) => l0 == r0,
+            (Self::AnimationFrame(l0), Self::AnimationFrame(r0)) => l0 == r0,
             // FIXME
             // (Self::Other(val_l), Self::Other(val_r)) => false,
             _ => false,
@@ -47,8 +118,162 @@ impl std::fmt::Debug for Action {
             Self::ButtonPressed(button) => f.debug_tuple("ButtonPressed").field(button).finish(),
             Self::TextChanged(text) => f.debug_tuple("TextChanged").field(text).finish(),
             Self::TextEntered(text) => f.debug_tuple("TextEntered").field(text).finish(),
+            Self::TextCleared => write!(f, "TextCleared"),
+            Self::TextSelectionChanged(selection) => f
+                .debug_tuple("TextSelectionChanged")
+                .field(selection)
+                .finish(),
+            Self::LinkClicked(url) => f.debug_tuple("LinkClicked").field(url).finish(),
             Self::CheckboxToggled(b) => f.debug_tuple("CheckboxChecked").field(b).finish(),
+            Self::Slider(action) => f.debug_tuple("Slider").field(action).finish(),
+            Self::RadioButton(action) => f.debug_tuple("RadioButton").field(action).finish(),
+            Self::DropDown(action) => f.debug_tuple("DropDown").field(action).finish(),
+            Self::ModalDismissed => write!(f, "ModalDismissed"),
+            Self::ContextMenuItemSelected(path) => f
+                .debug_tuple("ContextMenuItemSelected")
+                .field(path)
+                .finish(),
+            Self::Portal(action) => f.debug_tuple("Portal").field(action).finish(),
+            Self::SplitResized(change) => f.debug_tuple("SplitResized").field(change).finish(),
+            Self::TabSelected(index) => f.debug_tuple("TabSelected").field(index).finish(),
+            Self::ExpanderToggled(expanded) => {
+                f.debug_tuple("ExpanderToggled").field(expanded).finish()
+            }
+            Self::VirtualListRange(range) => {
+                f.debug_tuple("VirtualListRange").field(range).finish()
+            }
+            Self::Gesture(action) => f.debug_tuple("Gesture").field(action).finish(),
+            Self::CanvasPointer(event) => f.debug_tuple("CanvasPointer").field(event).finish(),
+            Self::HotkeyPressed(hotkey) => f.debug_tuple("HotkeyPressed").field(hotkey).finish(),
+            Self::HoverChanged(hovered) => f.debug_tuple("HoverChanged").field(hovered).finish(),
+            Self::AnimationFrame(interval) => {
+                f.debug_tuple("AnimationFrame").field(interval).finish()
+            }
             Self::Other(_) => write!(f, "Other(...)"),
         }
     }
 }
+
+/// A change to the selection of a [`TextArea`](crate::widgets::TextArea).
+///
+/// When nothing is selected, `start` and `end` are both the position of the caret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionChange {
+    /// The byte offset of the start of the selection.
+    pub start: usize,
+    /// The byte offset of the end of the selection.
+    pub end: usize,
+    /// Whether the selection was changed by the app, e.g. using
+    /// [`TextArea::select_byte_range`](crate::widgets::TextArea::select_byte_range),
+    /// rather than by the user.
+    pub programmatic: bool,
+}
+
+/// A change to the split point of a [`Split`](crate::widgets::Split).
+///
+/// Both fields describe the same position of the splitter bar, so apps can persist
+/// whichever suits them, and restore it with
+/// [`Split::split_point`](crate::widgets::Split::split_point) or
+/// [`Split::first_pane_size`](crate::widgets::Split::first_pane_size).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplitChange {
+    /// The split point, as a fraction of the space shared by the two children.
+    pub fraction: f64,
+    /// The size of the first child along the split axis, in logical pixels.
+    pub first_pane_size: f64,
+}
+
+/// Events from a [`Slider`](crate::widgets::Slider).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliderAction {
+    /// The value was changed by the user.
+    ///
+    /// This is sent continuously whilst the thumb is being dragged.
+    ValueChanged(f64),
+    /// The user released the thumb after dragging it.
+    DragFinished,
+}
+
+/// Events from a [`RadioButton`](crate::widgets::RadioButton).
+///
+/// A radio button doesn't know about the other buttons in its group, so
+/// moving the selection with the arrow keys is left to the code which owns the group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RadioButtonAction {
+    /// The radio button was selected by the user.
+    Selected,
+    /// The user asked to select the next radio button in the group.
+    SelectNext,
+    /// The user asked to select the previous radio button in the group.
+    SelectPrevious,
+}
+
+/// Events from a [`DropDown`](crate::widgets::DropDown).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropDownAction {
+    /// The user selected the option with the given index.
+    Selected(usize),
+}
+
+/// Events from a [`Portal`](crate::widgets::Portal).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PortalAction {
+    /// The viewport moved, or the size of the portal or its content changed.
+    ///
+    /// This is sent at most once per compose pass, so several changes whilst handling
+    /// one event or animation frame are reported together.
+    ScrollChanged {
+        /// How far the viewport is from the top left of the content.
+        offset: Vec2,
+        /// The size of the portal.
+        viewport: Size,
+        /// The size of the portal's content.
+        content: Size,
+    },
+}
+
+/// Gestures recognised by a [`GestureDetector`](crate::widgets::GestureDetector).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GestureAction {
+    /// The child was pressed and released without moving far or being held for long.
+    Tap,
+    /// The child was tapped again soon after a tap.
+    ///
+    /// This is sent after the [`Tap`](Self::Tap) for the second tap.
+    DoubleTap,
+    /// The child was pressed and held without moving far.
+    ///
+    /// This is sent whilst the pointer is still down, and no tap is sent when it is released.
+    LongPress,
+    /// The child was pressed and the pointer moved.
+    Drag {
+        /// Where the pointer was pressed, relative to the top left of the detector.
+        start: Point,
+        /// How far the pointer has moved from `start`.
+        delta: Vec2,
+        /// Which part of the drag this is.
+        phase: DragPhase,
+    },
+}
+
+/// The part of a drag reported by [`GestureAction::Drag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragPhase {
+    /// The pointer moved further than the drag threshold from where it was pressed.
+    Started,
+    /// The pointer moved during the drag.
+    Moved,
+    /// The pointer was released, ending the drag.
+    Ended,
+    /// The drag was interrupted, e.g. because the pointer left the window.
+    Cancelled,
+}
+
+/// A pointer event received by a [`Canvas`](crate::widgets::Canvas).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanvasPointerEvent {
+    /// The event itself.
+    pub event: PointerEvent,
+    /// The position of the pointer relative to the top left of the canvas.
+    pub position: Point,
+}
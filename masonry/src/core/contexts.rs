@@ -5,21 +5,27 @@
 
 use accesskit::TreeUpdate;
 use anymap3::AnyMap;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
 use dpi::LogicalPosition;
 use parley::{FontContext, LayoutContext};
 use tracing::{trace, warn};
 use tree_arena::{ArenaMutList, ArenaRefList};
-use winit::window::ResizeDirection;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+use winit::window::{ResizeDirection, Theme as SystemTheme};
 
-use crate::app::{MutateCallback, RenderRootSignal, RenderRootState};
+use crate::app::{LayerStack, MutateCallback, RenderRootSignal, RenderRootState};
 use crate::core::{
-    Action, AllowRawMut, BoxConstraints, BrushIndex, CreateWidget, FromDynWidget, PropertiesMut,
-    PropertiesRef, Widget, WidgetId, WidgetMut, WidgetPod, WidgetRef, WidgetState,
+    Action, AllowRawMut, BoxConstraints, BrushIndex, CreateWidget, FromDynWidget, Hotkey,
+    PropertiesMut, PropertiesRef, Widget, WidgetId, WidgetMut, WidgetPod, WidgetRef, WidgetState,
 };
-use crate::kurbo::{Affine, Insets, Point, Rect, Size, Vec2};
+use crate::kurbo::{Affine, Insets, Point, Rect, Shape, Size, Vec2};
 use crate::passes::layout::run_layout_on;
 use crate::peniko::Color;
-use crate::theme::get_debug_color;
+use crate::theme::{Theme, get_debug_color};
 
 // Note - Most methods defined in this file revolve around `WidgetState` fields.
 // Consider reading `WidgetState` documentation (especially the documented naming scheme)
@@ -397,21 +403,14 @@ impl EventCtx<'_> {
         self.global_state.pointer_capture_target = None;
     }
 
-    /// Send a signal to parent widgets to scroll this widget into view.
-    pub fn request_scroll_to_this(&mut self) {
-        let rect = self.widget_state.layout_rect();
-        self.global_state
-            .scroll_request_targets
-            .push((self.widget_state.id, rect));
+    /// Record that this widget scrolled in response to a mouse wheel event.
+    pub(crate) fn record_wheel_scroll(&mut self) {
+        self.global_state.last_wheel_scroll = Some(Instant::now());
     }
 
-    /// Send a signal to parent widgets to scroll this area into view.
-    ///
-    /// `rect` is in local coordinates.
-    pub fn request_scroll_to(&mut self, rect: Rect) {
-        self.global_state
-            .scroll_request_targets
-            .push((self.widget_state.id, rect));
+    /// When a widget last scrolled in response to a mouse wheel event.
+    pub(crate) fn last_wheel_scroll(&self) -> Option<Instant> {
+        self.global_state.last_wheel_scroll
     }
 
     /// Set the event as "handled", which stops its propagation to parent
@@ -433,31 +432,6 @@ impl EventCtx<'_> {
         self.target
     }
 
-    /// Request [text focus].
-    ///
-    /// Because only one widget can be focused at a time, multiple focus requests
-    /// from different widgets during a single event cycle means that the last
-    /// widget that requests focus will override the previous requests.
-    ///
-    /// [text focus]: crate::doc::doc_06_masonry_concepts#text-focus
-    pub fn request_focus(&mut self) {
-        trace!("request_focus");
-        // We need to send the request even if we're currently focused,
-        // because we may have a sibling widget that already requested focus
-        // and we have no way of knowing that yet. We need to override that
-        // to deliver on the "last focus request wins" promise.
-        let id = self.widget_id();
-        self.global_state.next_focused_widget = Some(id);
-    }
-
-    /// Transfer [text focus] to the widget with the given `WidgetId`.
-    ///
-    /// [text focus]: crate::doc::doc_06_masonry_concepts#text-focus
-    pub fn set_focus(&mut self, target: WidgetId) {
-        trace!("set_focus target={:?}", target);
-        self.global_state.next_focused_widget = Some(target);
-    }
-
     /// Give up [text focus].
     ///
     /// This should only be called by a widget that currently has focus.
@@ -685,6 +659,7 @@ impl LayoutCtx<'_> {
         // 2) An easy potential source of items not being visible when expected
         trace!("set_clip_path {path:?}");
         self.widget_state.clip_path = Some(path);
+        self.widget_state.clip_shape = None;
         // TODO - Updating the clip path may have
         // other knock-on effects we'd need to document.
         self.widget_state.request_accessibility = true;
@@ -698,6 +673,7 @@ impl LayoutCtx<'_> {
     pub fn clear_clip_path(&mut self) {
         trace!("clear_clip_path");
         self.widget_state.clip_path = None;
+        self.widget_state.clip_shape = None;
         // TODO - Updating the clip path may have
         // other knock-on effects we'd need to document.
         self.widget_state.request_accessibility = true;
@@ -706,6 +682,23 @@ impl LayoutCtx<'_> {
     }
 }
 
+impl_context_method!(MutateCtx<'_>, LayoutCtx<'_>, {
+    /// Gives the widget a clip path of any shape, such as a rounded rectangle or a circle.
+    ///
+    /// This has the same effects as [`LayoutCtx::set_clip_path`], using the exact shape
+    /// both to mask painting and to test whether pointer events reach the widget's children.
+    /// It can be changed outside of layout, for widgets whose clip doesn't affect their size.
+    pub fn set_clip_shape(&mut self, shape: &impl Shape) {
+        let path = shape.to_path(0.1);
+        trace!("set_clip_shape {path:?}");
+        self.widget_state.clip_path = Some(path.bounding_box());
+        self.widget_state.clip_shape = Some(path);
+        self.widget_state.request_accessibility = true;
+        self.widget_state.needs_accessibility = true;
+        self.widget_state.needs_paint = true;
+    }
+});
+
 impl ComposeCtx<'_> {
     // TODO - Remove?
     /// Returns whether [`Widget::compose`] will be called on this widget.
@@ -764,6 +757,15 @@ impl_context_method!(
             self.widget_state.size
         }
 
+        /// Whether the layout size has changed since this widget was last painted.
+        ///
+        /// This is always true before the widget is first painted.
+        /// Widgets can use this to avoid recomputing size-dependent paint data (such as paths)
+        /// on every paint.
+        pub fn size_changed_since_last_paint(&self) -> bool {
+            self.widget_state.size_changed_since_paint
+        }
+
         // TODO - Remove
         #[allow(dead_code, reason = "Only used in tests")]
         pub(crate) fn local_layout_rect(&self) -> Rect {
@@ -942,12 +944,94 @@ impl_context_method!(
         pub fn is_stashed(&self) -> bool {
             self.widget_state.is_stashed
         }
+
+        /// The theme this widget should be drawn with.
+        ///
+        /// This is the theme set on this widget or its closest ancestor with [`set_theme`],
+        /// or the window's theme if there isn't one.
+        /// Widgets are sent [`Update::ThemeChanged`] when this changes.
+        ///
+        /// [`set_theme`]: EventCtx::set_theme
+        /// [`Update::ThemeChanged`]: crate::core::Update::ThemeChanged
+        pub fn theme(&self) -> &Theme {
+            &self.widget_state.theme
+        }
+
+        /// The platform's light or dark preference.
+        ///
+        /// This is [`Dark`](SystemTheme::Dark) if the platform doesn't report a preference.
+        /// Widgets are sent [`Update::ThemeChanged`] when this changes.
+        ///
+        /// [`Update::ThemeChanged`]: crate::core::Update::ThemeChanged
+        pub fn system_theme(&self) -> SystemTheme {
+            self.global_state.system_theme
+        }
     }
 );
 
 // --- MARK: UPDATE FLAGS ---
 // Methods on MutateCtx, EventCtx, and UpdateCtx
 impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, {
+    /// Request [text focus].
+    ///
+    /// Because only one widget can be focused at a time, multiple focus requests
+    /// from different widgets during a single event cycle means that the last
+    /// widget that requests focus will override the previous requests.
+    ///
+    /// [text focus]: crate::doc::doc_06_masonry_concepts#text-focus
+    pub fn request_focus(&mut self) {
+        trace!("request_focus");
+        // We need to send the request even if we're currently focused,
+        // because we may have a sibling widget that already requested focus
+        // and we have no way of knowing that yet. We need to override that
+        // to deliver on the "last focus request wins" promise.
+        let id = self.widget_id();
+        self.global_state.next_focused_widget = Some(id);
+    }
+
+    /// Transfer [text focus] to the widget with the given `WidgetId`.
+    ///
+    /// [text focus]: crate::doc::doc_06_masonry_concepts#text-focus
+    pub fn set_focus(&mut self, target: WidgetId) {
+        trace!("set_focus target={:?}", target);
+        self.global_state.next_focused_widget = Some(target);
+    }
+
+    /// The widget which will have [text focus] once the current pass is over, if any.
+    ///
+    /// [text focus]: crate::doc::doc_06_masonry_concepts#text-focus
+    pub(crate) fn next_focused_widget(&self) -> Option<WidgetId> {
+        self.global_state.next_focused_widget
+    }
+
+    /// Focus this widget, and prevent [text focus] from leaving its subtree until
+    /// [`release_focus_trap`](Self::release_focus_trap) is called or this widget is removed.
+    ///
+    /// Returns the widget which had focus before, so that focus can be restored later.
+    ///
+    /// [text focus]: crate::doc::doc_06_masonry_concepts#text-focus
+    pub(crate) fn trap_focus(&mut self) -> Option<WidgetId> {
+        let id = self.widget_id();
+        trace!("trap_focus");
+        let previous = self.global_state.next_focused_widget;
+        self.global_state.focus_traps.push(id);
+        self.global_state.next_focused_widget = Some(id);
+        previous
+    }
+
+    /// Stop trapping focus in this widget's subtree, and move focus to `restore`.
+    ///
+    /// If `restore` is `None`, this widget's subtree loses focus.
+    /// See [`trap_focus`](Self::trap_focus).
+    pub(crate) fn release_focus_trap(&mut self, restore: Option<WidgetId>) {
+        let id = self.widget_id();
+        trace!("release_focus_trap restore={:?}", restore);
+        self.global_state.focus_traps.retain(|trap| *trap != id);
+        if restore.is_some() || self.widget_state.has_focus_target {
+            self.global_state.next_focused_widget = restore;
+        }
+    }
+
     /// Request a [`paint`](crate::core::Widget::paint) and an [`accessibility`](crate::core::Widget::accessibility) pass.
     pub fn request_render(&mut self) {
         trace!("request_render");
@@ -1000,6 +1084,23 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, {
         self.widget_state.request_compose = true;
     }
 
+    /// Send a signal to parent widgets to scroll this widget into view.
+    pub fn request_scroll_to_this(&mut self) {
+        let rect = self.widget_state.layout_rect();
+        self.global_state
+            .scroll_request_targets
+            .push((self.widget_state.id, rect));
+    }
+
+    /// Send a signal to parent widgets to scroll this area into view.
+    ///
+    /// `rect` is in local coordinates.
+    pub fn request_scroll_to(&mut self, rect: Rect) {
+        self.global_state
+            .scroll_request_targets
+            .push((self.widget_state.id, rect));
+    }
+
     /// Request an animation frame.
     pub fn request_anim_frame(&mut self) {
         trace!("request_anim_frame");
@@ -1061,6 +1162,16 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, {
         self.widget_state.is_explicitly_disabled = disabled;
     }
 
+    /// Set the theme of this widget and its descendants, or with `None`, use the theme
+    /// of this widget's parent.
+    ///
+    /// Descendants which have their own theme set keep it.
+    /// See [`theme`](Self::theme) for more information.
+    pub fn set_theme(&mut self, theme: Option<Arc<Theme>>) {
+        self.widget_state.needs_update_theme = true;
+        self.widget_state.explicit_theme = theme;
+    }
+
     /// Set the transform for this widget.
     ///
     /// It behaves similarly as CSS transforms
@@ -1069,6 +1180,114 @@ impl_context_method!(MutateCtx<'_>, EventCtx<'_>, UpdateCtx<'_>, {
         self.widget_state.transform_changed = true;
         self.request_compose();
     }
+
+    /// Set the opacity of this widget and its descendants, from 0.0 (invisible) to 1.0 (opaque).
+    ///
+    /// Whilst this is less than 1.0, the widget and its descendants are painted into a
+    /// layer clipped to the widget's bounding rect. At 0.0, they aren't drawn at all.
+    pub fn set_opacity(&mut self, opacity: f64) {
+        self.widget_state.opacity = opacity.clamp(0.0, 1.0);
+        self.request_paint_only();
+    }
+
+    /// Set the position of this widget in the order Tab moves focus in.
+    ///
+    /// Widgets with a positive index are focused first, in ascending order of index,
+    /// followed by those with no index or an index of 0, in tree order.
+    /// Widgets with a negative index are skipped by Tab, but can still be focused
+    /// by clicking them or with [`request_focus`](EventCtx::request_focus).
+    ///
+    /// The index also applies to this widget's descendants which don't have one of their own,
+    /// so it can be set on a container of a widget which accepts focus.
+    pub fn set_tab_index(&mut self, tab_index: Option<i32>) {
+        self.widget_state.tab_index = tab_index;
+        self.widget_state.needs_update_focus_chain = true;
+    }
+
+    /// Register key combinations which this widget handles whether or not it is focused,
+    /// replacing any it registered before.
+    ///
+    /// When a key is pressed which the focused widget doesn't handle and which matches one
+    /// of these, this widget receives a [`TextEvent::Hotkey`](crate::core::TextEvent::Hotkey).
+    /// If several widgets register the same hotkey, a warning is logged and the widget which
+    /// registered it first receives it.
+    /// Hotkeys of disabled or stashed widgets are ignored.
+    pub fn set_hotkeys(&mut self, hotkeys: Vec<Hotkey>) {
+        let id = self.widget_id();
+        trace!("set_hotkeys {:?}", hotkeys);
+        let registered = &mut self.global_state.hotkeys;
+        // Keep this widget's place in the registration order.
+        let position = registered
+            .iter()
+            .position(|(widget, _)| *widget == id)
+            .unwrap_or(registered.len());
+        registered.retain(|(widget, _)| *widget != id);
+        for hotkey in &hotkeys {
+            if let Some((other, _)) = registered.iter().find(|(_, other)| other == hotkey) {
+                warn!(
+                    "Hotkey {:?} is registered by both widget {} and widget {}; \
+                     only the widget which registered it first will receive it.",
+                    hotkey, other, id
+                );
+            }
+        }
+        registered.splice(
+            position..position,
+            hotkeys.into_iter().map(|hotkey| (id, hotkey)),
+        );
+    }
+
+    /// Show `layer` above all other content in the window, with its top-left corner at `position`.
+    ///
+    /// Layers are used for content such as tooltips and popups, which shouldn't be clipped by
+    /// their creator's ancestors. `position` is in window coordinates; the layer is moved
+    /// so that it fits within the window if possible.
+    /// The layer is laid out with loose constraints of the window's size.
+    ///
+    /// Returns the id of the layer's root widget, which should be passed to
+    /// [`remove_layer`](Self::remove_layer) once the layer is no longer needed.
+    /// If this widget is removed from the tree, the layer is removed along with it.
+    ///
+    /// The layer is added in the next [mutate pass](crate::doc::doc_05_pass_system#the-mutate-pass).
+    pub fn add_layer(&mut self, layer: impl Widget, position: Point) -> WidgetId {
+        trace!("add_layer");
+        let layer = WidgetPod::new(layer).erased();
+        let id = layer.id();
+        let owner = self.widget_id();
+        let stack_id = self.global_state.layer_stack_id;
+        self.mutate_widget_later(stack_id, move |mut stack| {
+            LayerStack::add_layer(&mut stack.downcast(), layer, position, owner);
+        });
+        id
+    }
+
+    /// Remove a layer added using [`add_layer`](Self::add_layer).
+    ///
+    /// Does nothing if the layer was already removed.
+    pub fn remove_layer(&mut self, id: WidgetId) {
+        trace!("remove_layer");
+        let stack_id = self.global_state.layer_stack_id;
+        self.mutate_widget_later(stack_id, move |mut stack| {
+            LayerStack::remove_layer(&mut stack.downcast(), id);
+        });
+    }
+
+    /// Queue a callback that will be called with a [`WidgetMut`] for any widget in the tree.
+    ///
+    /// This is used for communication between widgets in layers and the widgets which
+    /// added them, which aren't each others' ancestors.
+    /// Unlike [`mutate_later`](Self::mutate_later), the callback doesn't need to be `Send`.
+    pub(crate) fn mutate_widget_later(
+        &mut self,
+        id: WidgetId,
+        f: impl FnOnce(WidgetMut<'_, dyn Widget>) + 'static,
+    ) {
+        let callback = MutateCallback {
+            id,
+            callback: Box::new(f),
+        };
+        self.global_state.mutate_callbacks.push(callback);
+    }
 });
 
 // --- MARK: OTHER METHODS ---
@@ -1097,6 +1316,40 @@ impl_context_method!(
             }
         }
 
+        /// Set the (local) transform of a child widget.
+        ///
+        /// This is for containers which animate the appearance of their children,
+        /// and replaces any transform the child has set on itself.
+        pub fn set_child_transform(
+            &mut self,
+            child: &mut WidgetPod<impl Widget + ?Sized>,
+            transform: Affine,
+        ) {
+            let child_state = self.get_child_state_mut(child);
+            if child_state.transform != transform {
+                child_state.transform = transform;
+                child_state.transform_changed = true;
+                self.widget_state.needs_compose = true;
+            }
+        }
+
+        /// Set the opacity of a child widget and its descendants, from 0.0 (invisible) to 1.0 (opaque).
+        ///
+        /// This is for containers which animate the appearance of their children,
+        /// and replaces any opacity the child has set on itself.
+        pub fn set_child_opacity(
+            &mut self,
+            child: &mut WidgetPod<impl Widget + ?Sized>,
+            opacity: f64,
+        ) {
+            let opacity = opacity.clamp(0.0, 1.0);
+            let child_state = self.get_child_state_mut(child);
+            if child_state.opacity != opacity {
+                child_state.opacity = opacity;
+                self.widget_state.needs_paint = true;
+            }
+        }
+
         // TODO - Remove from MutateCtx?
         /// Queue a callback that will be called with a [`WidgetMut`] for this widget.
         ///
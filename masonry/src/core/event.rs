@@ -6,11 +6,11 @@
 use std::path::PathBuf;
 
 use vello::kurbo::Point;
-use winit::event::{Force, Ime, KeyEvent, Modifiers};
-use winit::keyboard::ModifiersState;
+use winit::event::{ElementState, Force, Ime, Modifiers};
+use winit::keyboard::{Key, KeyCode, KeyLocation, ModifiersState, PhysicalKey, SmolStr};
 
 use crate::dpi::{LogicalPosition, PhysicalPosition, PhysicalSize};
-use crate::kurbo::Rect;
+use crate::kurbo::{Rect, Size};
 
 // TODO - Occluded(bool) event
 // TODO - winit ActivationTokenDone thing
@@ -27,6 +27,10 @@ pub enum WindowEvent {
     AnimFrame,
     /// The accessibility tree must be rebuilt.
     RebuildAccessTree,
+    /// The platform's light or dark preference changed.
+    ///
+    /// This is also sent when the window is created, with the initial preference.
+    SystemThemeChanged(winit::window::Theme),
 }
 
 /// An indicator of which pointer button was pressed.
@@ -188,7 +192,7 @@ impl From<PointerButton> for PointerButtons {
 ///
 /// A pointer in this context can be a mouse, a pen, a touch screen, etc. Though
 /// Masonry currently doesn't really support multiple pointers.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum PointerEvent {
     /// A pointer was pressed.
     PointerDown(PointerButton, PointerState),
@@ -228,6 +232,8 @@ pub enum PointerEvent {
 #[derive(Debug, Clone)]
 pub enum TextEvent {
     /// A keyboard event.
+    ///
+    /// This holds Masonry's [`KeyEvent`], which unlike winit's, can be created in tests.
     KeyboardKey(KeyEvent, ModifiersState),
     /// An IME event.
     Ime(Ime),
@@ -235,6 +241,91 @@ pub enum TextEvent {
     ModifierChange(ModifiersState),
     /// The window took or lost focus.
     WindowFocusChange(bool),
+    /// A key combination which this widget registered with
+    /// [`set_hotkeys`](crate::core::EventCtx::set_hotkeys) was pressed,
+    /// and the focused widget didn't handle it.
+    Hotkey(Hotkey),
+}
+
+/// A keyboard key being pressed or released.
+///
+/// This has the same fields as winit's [`KeyEvent`](winit::event::KeyEvent), other than
+/// its platform-specific ones, so that it can also be created in tests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    /// The key's position on the keyboard, regardless of the keyboard layout.
+    pub physical_key: PhysicalKey,
+    /// The key, as interpreted by the keyboard layout and the held modifiers.
+    pub logical_key: Key,
+    /// The text typed by this key press, if any.
+    pub text: Option<SmolStr>,
+    /// Which of several keys with the same meaning was used, e.g. the left or right Shift key.
+    pub location: KeyLocation,
+    /// Whether the key was pressed or released.
+    pub state: ElementState,
+    /// Whether this press was repeated because the key is being held down.
+    pub repeat: bool,
+}
+
+impl KeyEvent {
+    /// A press of the key at `code`, which is interpreted as `key`.
+    ///
+    /// If `key` is a character, the event types that character.
+    ///
+    /// This is mostly useful for testing.
+    pub fn pressed(code: KeyCode, key: impl Into<Key>) -> Self {
+        let logical_key = key.into();
+        let text = match &logical_key {
+            Key::Character(character) => Some(character.clone()),
+            _ => None,
+        };
+        Self {
+            physical_key: PhysicalKey::Code(code),
+            logical_key,
+            text,
+            location: KeyLocation::Standard,
+            state: ElementState::Pressed,
+            repeat: false,
+        }
+    }
+
+    /// A release of the key at `code`, which is interpreted as `key`.
+    ///
+    /// This is mostly useful for testing.
+    pub fn released(code: KeyCode, key: impl Into<Key>) -> Self {
+        Self {
+            text: None,
+            state: ElementState::Released,
+            ..Self::pressed(code, key)
+        }
+    }
+}
+
+impl From<winit::event::KeyEvent> for KeyEvent {
+    fn from(event: winit::event::KeyEvent) -> Self {
+        Self {
+            physical_key: event.physical_key,
+            logical_key: event.logical_key,
+            text: event.text,
+            location: event.location,
+            state: event.state,
+            repeat: event.repeat,
+        }
+    }
+}
+
+/// A key combination, such as Ctrl+S, which widgets can handle without being focused.
+///
+/// See [`EventCtx::set_hotkeys`](crate::core::EventCtx::set_hotkeys).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    /// The modifier keys which must be held, and no others.
+    pub mods: ModifiersState,
+    /// The logical key which must be pressed.
+    ///
+    /// Characters are matched regardless of case, so that e.g. Ctrl+Shift+S
+    /// can be written with a lowercase `s`.
+    pub key: Key,
 }
 
 // TODO - Go into more detail.
@@ -248,7 +339,7 @@ pub struct AccessEvent {
 }
 
 /// The persistent state of a pointer.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PointerState {
     // TODO
     // pub device_id: DeviceId,
@@ -333,6 +424,29 @@ pub enum Update {
     /// [`set_stashed`]: crate::core::EventCtx::set_stashed
     StashedChanged(bool),
 
+    /// Called when the [theme](crate::theme::Theme) which applies to the widget changes.
+    ///
+    /// The new theme is available from [`UpdateCtx::theme`](crate::core::UpdateCtx::theme).
+    /// The widget is laid out and painted again afterwards, so this only needs to be handled
+    /// by widgets which store values taken from the theme.
+    ///
+    /// This is also sent to every widget when the platform's light or dark preference
+    /// changes, which is available from [`UpdateCtx::system_theme`](crate::core::UpdateCtx::system_theme).
+    ///
+    /// To change the theme of a widget and its descendants, see
+    /// [`set_theme`](crate::core::EventCtx::set_theme).
+    ThemeChanged,
+
+    /// Called when the size of the widget has changed during the layout pass.
+    ///
+    /// This is sent after layout and before paint, so that widgets can update any state
+    /// which depends on their size. It is not sent for the first layout of a widget;
+    /// use [`Update::WidgetAdded`] for initialisation instead.
+    ///
+    /// The contained value is the new size, which is also available from
+    /// [`UpdateCtx::size`](crate::core::UpdateCtx::size).
+    SizeChanged(Size),
+
     /// Called when a child widgets uses
     /// [`EventCtx::request_scroll_to_this`](crate::core::EventCtx::request_scroll_to_this).
     RequestPanToChild(Rect),
@@ -451,7 +565,49 @@ impl PointerEvent {
     }
 }
 
+impl Hotkey {
+    /// Create a hotkey for pressing `key` whilst holding `mods`.
+    pub fn new(mods: ModifiersState, key: impl Into<Key>) -> Self {
+        Self {
+            mods,
+            key: key.into(),
+        }
+    }
+
+    /// Create a hotkey for typing `character` whilst holding `mods`, such as `"s"` for Ctrl+S.
+    pub fn character(mods: ModifiersState, character: &str) -> Self {
+        Self::new(mods, Key::Character(character.into()))
+    }
+
+    /// Whether pressing `key` whilst holding `mods` triggers this hotkey.
+    pub fn matches(&self, key: &Key, mods: ModifiersState) -> bool {
+        if mods != self.mods {
+            return false;
+        }
+        match (&self.key, key) {
+            (Key::Character(expected), Key::Character(pressed)) => {
+                expected.to_lowercase() == pressed.to_lowercase()
+            }
+            (expected, pressed) => expected == pressed,
+        }
+    }
+}
+
 impl TextEvent {
+    /// Constructor for events of the key at `code` being pressed whilst holding `mods`.
+    ///
+    /// See [`KeyEvent::pressed`] for details. This is mostly useful for testing.
+    pub fn key_down(code: KeyCode, key: impl Into<Key>, mods: ModifiersState) -> Self {
+        Self::KeyboardKey(KeyEvent::pressed(code, key), mods)
+    }
+
+    /// Constructor for events of the key at `code` being released whilst holding `mods`.
+    ///
+    /// This is mostly useful for testing.
+    pub fn key_up(code: KeyCode, key: impl Into<Key>, mods: ModifiersState) -> Self {
+        Self::KeyboardKey(KeyEvent::released(code, key), mods)
+    }
+
     /// Constructor for IME Preedit events.
     ///
     /// This is mostly useful for testing.
@@ -488,6 +644,7 @@ impl TextEvent {
             Self::Ime(Ime::Preedit(_, _)) => "Ime::Preedit(\"...\")",
             Self::ModifierChange(_) => "ModifierChange",
             Self::WindowFocusChange(_) => "WindowFocusChange",
+            Self::Hotkey(_) => "Hotkey",
         }
     }
 
@@ -502,6 +659,7 @@ impl TextEvent {
             // Basically every mouse click/scroll event seems to produce a modifier change event.
             Self::ModifierChange(_) => true,
             Self::WindowFocusChange(_) => false,
+            Self::Hotkey(_) => false,
         }
     }
 }
@@ -568,6 +726,8 @@ impl Update {
             Self::WidgetAdded => "WidgetAdded",
             Self::DisabledChanged(_) => "DisabledChanged",
             Self::StashedChanged(_) => "StashedChanged",
+            Self::ThemeChanged => "ThemeChanged",
+            Self::SizeChanged(_) => "SizeChanged",
             Self::RequestPanToChild(_) => "RequestPanToChild",
             Self::HoveredChanged(_) => "HoveredChanged",
             Self::ChildHoveredChanged(_) => "ChildHoveredChanged",
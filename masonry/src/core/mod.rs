@@ -20,26 +20,31 @@ mod widget_pod;
 mod widget_ref;
 mod widget_state;
 
-pub use action::Action;
+pub use action::{
+    Action, CanvasPointerEvent, DragPhase, DropDownAction, GestureAction, PortalAction,
+    RadioButtonAction, SelectionChange, SliderAction, SplitChange,
+};
 pub use box_constraints::BoxConstraints;
 pub use contexts::{
     AccessCtx, ComposeCtx, EventCtx, IsContext, LayoutCtx, MutateCtx, PaintCtx, QueryCtx,
     RawWrapper, RawWrapperMut, RegisterCtx, UpdateCtx,
 };
 pub use event::{
-    AccessEvent, PointerButton, PointerEvent, PointerState, TextEvent, Update, WindowEvent,
-    WindowTheme,
+    AccessEvent, Hotkey, KeyEvent, PointerButton, PointerEvent, PointerState, TextEvent, Update,
+    WindowEvent, WindowTheme,
 };
 pub use object_fit::ObjectFit;
 pub use properties::{Properties, PropertiesMut, PropertiesRef};
-pub use text::{ArcStr, BrushIndex, StyleProperty, StyleSet, render_text};
+pub use text::{
+    ArcStr, BrushIndex, RichText, StyleAttribute, StyleProperty, StyleSet, render_text,
+};
 pub use widget::find_widget_at_pos;
 pub use widget::{AllowRawMut, FromDynWidget, Widget, WidgetId};
 pub use widget_mut::WidgetMut;
 pub use widget_pod::WidgetPod;
 pub use widget_ref::WidgetRef;
 
-pub(crate) use text::default_styles;
+pub(crate) use text::{DEFAULT_LINE_HEIGHT, default_styles, push_spans, span_brushes};
 pub(crate) use widget_arena::WidgetArena;
 pub(crate) use widget_pod::CreateWidget;
 pub(crate) use widget_state::WidgetState;
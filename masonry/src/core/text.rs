@@ -10,7 +10,9 @@
 //!
 //! All of these have the same set of global styling options, and can contain rich text
 
-use parley::GenericFamily;
+use std::ops::Range;
+
+use parley::{FontStyle, FontWeight, GenericFamily, RangedBuilder};
 
 /// A reference counted string slice.
 ///
@@ -33,12 +35,147 @@ pub type StyleProperty = parley::StyleProperty<'static, BrushIndex>;
 /// A set of styles specialised for use within Masonry.
 pub type StyleSet = parley::StyleSet<BrushIndex>;
 
+/// The default height of each line of text, as a multiple of the font size.
+pub(crate) const DEFAULT_LINE_HEIGHT: f32 = 1.2;
+
 /// Applies the default text styles for Masonry into `styles`.
 pub(crate) fn default_styles(styles: &mut StyleSet) {
-    styles.insert(StyleProperty::LineHeight(1.2));
+    styles.insert(StyleProperty::LineHeight(DEFAULT_LINE_HEIGHT));
     styles.insert(GenericFamily::SystemUi.into());
 }
 
+/// A single style which can be applied to a range of a [`RichText`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum StyleAttribute {
+    /// Font weight, e.g. [`FontWeight::BOLD`].
+    Weight(FontWeight),
+    /// Font style, e.g. [`FontStyle::Italic`].
+    Style(FontStyle),
+    /// Font size, in logical pixels.
+    Size(f32),
+    /// The brush used to paint the text, which is usually its color.
+    Brush(Brush),
+    /// Whether the text is underlined.
+    Underline(bool),
+    /// Whether the text is struck through.
+    Strikethrough(bool),
+}
+
+/// Text with styles applied to some of its byte ranges.
+///
+/// Spans are applied on top of the global styles of the widget displaying the text,
+/// with later spans taking precedence where they overlap.
+/// Ranges which fall outside of the text, or not on `char` boundaries, are ignored.
+///
+/// Rich text can be displayed by [`Label::rich`](crate::widgets::Label::rich) and
+/// [`Prose::rich`](crate::widgets::Prose::rich).
+///
+/// ## Example
+///
+/// ```
+/// use masonry::core::{RichText, StyleAttribute};
+/// use masonry::parley::FontWeight;
+///
+/// let text = RichText::new("Hello bold world")
+///     .with_span(6..10, StyleAttribute::Weight(FontWeight::BOLD));
+/// assert_eq!(text.spans().len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichText {
+    text: ArcStr,
+    spans: Vec<(Range<usize>, StyleAttribute)>,
+}
+
+impl RichText {
+    /// Create rich text with no spans.
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        Self {
+            text: text.into(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Builder-style method to apply `attribute` to the byte range `range`.
+    pub fn with_span(mut self, range: Range<usize>, attribute: StyleAttribute) -> Self {
+        self.push_span(range, attribute);
+        self
+    }
+
+    /// Apply `attribute` to the byte range `range`.
+    pub fn push_span(&mut self, range: Range<usize>, attribute: StyleAttribute) {
+        self.spans.push((range, attribute));
+    }
+
+    /// The unstyled text.
+    pub fn text(&self) -> &ArcStr {
+        &self.text
+    }
+
+    /// The styled ranges of the text, in the order they were added.
+    pub fn spans(&self) -> &[(Range<usize>, StyleAttribute)] {
+        &self.spans
+    }
+
+    /// Split this into its text and spans.
+    pub fn into_parts(self) -> (ArcStr, Vec<(Range<usize>, StyleAttribute)>) {
+        (self.text, self.spans)
+    }
+}
+
+impl<T: Into<ArcStr>> From<T> for RichText {
+    fn from(text: T) -> Self {
+        Self::new(text)
+    }
+}
+
+/// Push `spans` as ranged styles into `builder`.
+///
+/// Brush spans are given consecutive brush indices starting from 1,
+/// matching the order of [`span_brushes`].
+/// Index 0 is left for the widget's own brush.
+pub(crate) fn push_spans(
+    builder: &mut RangedBuilder<'_, BrushIndex>,
+    text: &str,
+    spans: &[(Range<usize>, StyleAttribute)],
+) {
+    let mut next_brush = 1;
+    for (range, attribute) in spans {
+        let valid = range.start < range.end
+            && text.is_char_boundary(range.start)
+            && text.is_char_boundary(range.end);
+        let property = match attribute {
+            StyleAttribute::Weight(weight) => StyleProperty::FontWeight(*weight),
+            StyleAttribute::Style(style) => StyleProperty::FontStyle(*style),
+            StyleAttribute::Size(size) => StyleProperty::FontSize(*size),
+            StyleAttribute::Brush(_) => {
+                let idx = BrushIndex(next_brush);
+                // The index is consumed even for an invalid range, to keep `span_brushes` simple.
+                next_brush += 1;
+                StyleProperty::Brush(idx)
+            }
+            StyleAttribute::Underline(underline) => StyleProperty::Underline(*underline),
+            StyleAttribute::Strikethrough(strikethrough) => {
+                StyleProperty::Strikethrough(*strikethrough)
+            }
+        };
+        if valid {
+            builder.push(property, range.clone());
+        }
+    }
+}
+
+/// The brushes of the [`StyleAttribute::Brush`] spans in `spans`, in order.
+///
+/// These should follow the base brush in the `brushes` argument to [`render_text()`].
+pub(crate) fn span_brushes(
+    spans: &[(Range<usize>, StyleAttribute)],
+) -> impl Iterator<Item = &Brush> {
+    spans.iter().filter_map(|(_, attribute)| match attribute {
+        StyleAttribute::Brush(brush) => Some(brush),
+        _ => None,
+    })
+}
+
 use parley::{Layout, PositionedLayoutItem};
 use vello::Scene;
 use vello::kurbo::{Affine, Line, Stroke};
@@ -19,7 +19,7 @@ use crate::core::{
     PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
     UpdateCtx, WidgetRef,
 };
-use crate::kurbo::{Point, Size};
+use crate::kurbo::{Point, Shape, Size};
 
 /// A unique identifier for a single [`Widget`].
 ///
@@ -409,8 +409,10 @@ pub fn find_widget_at_pos<'c>(
     if widget.ctx.widget_state.bounding_rect.contains(pos) {
         let local_pos = widget.ctx().widget_state.window_transform.inverse() * pos;
 
+        let clip_shape = widget.ctx.widget_state.clip_shape.as_ref();
         if widget.ctx.is_stashed()
             || Some(false) == widget.ctx.clip_path().map(|clip| clip.contains(local_pos))
+            || clip_shape.is_some_and(|shape| !shape.contains(local_pos))
         {
             return None;
         }
@@ -82,6 +82,14 @@ impl<W: Widget + ?Sized> WidgetPod<W> {
         matches!(self.inner, WidgetPodInner::Create(_))
     }
 
+    /// The widget, if it hasn't been inserted into the widget tree yet.
+    pub(crate) fn inner_mut(&mut self) -> Option<&mut W> {
+        match &mut self.inner {
+            WidgetPodInner::Create(inner) => Some(&mut inner.widget),
+            WidgetPodInner::Inserted => None,
+        }
+    }
+
     pub(crate) fn take_inner(&mut self) -> Option<CreateWidget<W>> {
         match std::mem::replace(&mut self.inner, WidgetPodInner::Inserted) {
             WidgetPodInner::Create(widget) => Some(widget),
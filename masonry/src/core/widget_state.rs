@@ -1,9 +1,12 @@
 // Copyright 2018 the Xilem Authors and the Druid Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use vello::kurbo::{Affine, Insets, Point, Rect, Size, Vec2};
+use std::sync::Arc;
+
+use vello::kurbo::{Affine, BezPath, Insets, Point, Rect, Size, Vec2};
 
 use crate::core::WidgetId;
+use crate::theme::{Theme, default_theme};
 
 // TODO - Reduce WidgetState size.
 // See https://github.com/linebender/xilem/issues/706
@@ -95,6 +98,10 @@ pub(crate) struct WidgetState {
     // Currently Kurbo doesn't really provide a type that lets us
     // efficiently hold an arbitrary shape.
     pub(crate) clip_path: Option<Rect>,
+    /// The exact shape of the clip, in local coordinates, if it isn't just `clip_path`.
+    ///
+    /// When this is set, `clip_path` is its bounding box.
+    pub(crate) clip_shape: Option<BezPath>,
 
     /// Local transform of this widget in the parent coordinate space.
     pub(crate) transform: Affine,
@@ -106,11 +113,20 @@ pub(crate) struct WidgetState {
     pub(crate) scroll_translation: Vec2,
     /// The `transform` or `scroll_translation` has changed.
     pub(crate) transform_changed: bool,
+    /// The opacity this widget and its descendants are painted with, from 0.0 to 1.0.
+    pub(crate) opacity: f64,
+    /// The position of this widget and its descendants in the order Tab moves focus in.
+    pub(crate) tab_index: Option<i32>,
+    /// The `size` has changed since the widget was last painted.
+    pub(crate) size_changed_since_paint: bool,
 
     // --- PASSES ---
     /// `WidgetAdded` hasn't been sent to this widget yet.
     pub(crate) is_new: bool,
 
+    /// The layout method has been called on this widget at least once.
+    pub(crate) is_laid_out: bool,
+
     /// A flag used to track and debug missing calls to `place_child`.
     pub(crate) is_expecting_place_child_call: bool,
 
@@ -143,10 +159,16 @@ pub(crate) struct WidgetState {
     pub(crate) needs_update_disabled: bool,
     /// This widget or a descendant changed its `is_explicitly_stashed` value
     pub(crate) needs_update_stashed: bool,
+    /// This widget or a descendant changed its `explicit_theme` value
+    pub(crate) needs_update_theme: bool,
 
     pub(crate) needs_update_focus_chain: bool,
 
-    pub(crate) focus_chain: Vec<WidgetId>,
+    /// The focusable widgets in this widget's subtree, in tree order, with their tab index.
+    ///
+    /// A widget's tab index is inherited from its closest ancestor which has one set,
+    /// if it doesn't have one itself.
+    pub(crate) focus_chain: Vec<(WidgetId, Option<i32>)>,
 
     pub(crate) children_changed: bool,
 
@@ -161,6 +183,12 @@ pub(crate) struct WidgetState {
     /// This widget or an ancestor has been stashed.
     pub(crate) is_stashed: bool,
 
+    /// The theme set on this widget, which overrides its parent's theme.
+    pub(crate) explicit_theme: Option<Arc<Theme>>,
+    /// The theme set on this widget or its closest ancestor which has one,
+    /// or the window's theme.
+    pub(crate) theme: Arc<Theme>,
+
     /// In the hovered path, starting from window and ending at the hovered widget.
     /// Descendants of the hovered widget are not in the hovered path.
     pub(crate) has_hovered: bool,
@@ -194,14 +222,19 @@ impl WidgetState {
             accepts_text_input: false,
             ime_area: None,
             clip_path: Option::default(),
+            clip_shape: None,
             scroll_translation: Vec2::ZERO,
             transform_changed: false,
+            size_changed_since_paint: true,
             is_explicitly_disabled: false,
             is_explicitly_stashed: false,
             is_disabled: false,
             is_stashed: false,
+            explicit_theme: None,
+            theme: default_theme(),
             baseline_offset: 0.0,
             is_new: true,
+            is_laid_out: false,
             has_hovered: false,
             is_hovered: false,
             request_layout: true,
@@ -217,6 +250,7 @@ impl WidgetState {
             needs_anim: true,
             needs_update_disabled: true,
             needs_update_stashed: true,
+            needs_update_theme: true,
             focus_chain: Vec::new(),
             children_changed: true,
             needs_update_focus_chain: true,
@@ -225,6 +259,8 @@ impl WidgetState {
             window_transform: Affine::IDENTITY,
             bounding_rect: Rect::ZERO,
             transform,
+            opacity: 1.0,
+            tab_index: None,
         }
     }
 
@@ -246,6 +282,7 @@ impl WidgetState {
             needs_anim: false,
             needs_update_disabled: false,
             needs_update_stashed: false,
+            needs_update_theme: false,
             children_changed: false,
             needs_update_focus_chain: false,
             ..Self::new(id, "<root>", Affine::IDENTITY)
@@ -269,6 +306,7 @@ impl WidgetState {
         self.children_changed |= child_state.children_changed;
         self.needs_update_focus_chain |= child_state.needs_update_focus_chain;
         self.needs_update_stashed |= child_state.needs_update_stashed;
+        self.needs_update_theme |= child_state.needs_update_theme;
     }
 
     /// The paint region for this widget.
@@ -327,5 +365,6 @@ impl WidgetState {
             || self.needs_compose
             || self.needs_update_disabled
             || self.needs_update_stashed
+            || self.needs_update_theme
     }
 }
@@ -4,7 +4,7 @@
 use dpi::LogicalPosition;
 use tracing::{debug, info_span, trace};
 use winit::event::ElementState;
-use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::keyboard::{Key, KeyCode, ModifiersState, PhysicalKey};
 
 use crate::Handled;
 use crate::app::{RenderRoot, RenderRootSignal};
@@ -27,7 +27,7 @@ fn get_pointer_target(
         // TODO - Apply scale?
         let pointer_pos = (pointer_pos.x, pointer_pos.y).into();
         return root
-            .get_root_widget()
+            .get_layer_stack()
             .find_widget_at_pos(pointer_pos)
             .map(|widget| widget.id());
     }
@@ -228,6 +228,10 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
     );
 
     if let TextEvent::KeyboardKey(key, mods) = event {
+        if key.state == ElementState::Pressed && handled == Handled::No {
+            handled = run_hotkey_pass(root, &key.logical_key, *mods);
+        }
+
         // Handle Tab focus
         if key.physical_key == PhysicalKey::Code(KeyCode::Tab)
             && key.state == ElementState::Pressed
@@ -272,6 +276,35 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
     handled
 }
 
+// --- MARK: HOTKEYS ---
+/// Send a [`TextEvent::Hotkey`] to the first widget which registered a hotkey matching
+/// the pressed `key` and `mods`, if any.
+fn run_hotkey_pass(root: &mut RenderRoot, key: &Key, mods: ModifiersState) -> Handled {
+    root.global_state
+        .hotkeys
+        .retain(|(id, _)| root.widget_arena.has(*id));
+    let Some((target, hotkey)) = root
+        .global_state
+        .hotkeys
+        .iter()
+        .find(|(id, hotkey)| hotkey.matches(key, mods) && root.is_still_interactive(*id))
+        .cloned()
+    else {
+        return Handled::No;
+    };
+
+    run_event_pass(
+        root,
+        Some(target),
+        &TextEvent::Hotkey(hotkey),
+        false,
+        |widget, ctx, props, event| {
+            widget.on_text_event(ctx, props, event);
+        },
+        true,
+    )
+}
+
 // --- MARK: ACCESS EVENT ---
 /// See the [passes documentation](../doc/05_pass_system.md#event-passes).
 pub(crate) fn run_on_access_event_pass(
@@ -188,6 +188,16 @@ pub(crate) fn run_layout_on<W: Widget + ?Sized>(
 
     let state_mut = parent_ctx.widget_state_children.item_mut(id).unwrap();
     parent_ctx.widget_state.merge_up(state_mut.item);
+    let old_size = state_mut.item.size;
+    if old_size != new_size {
+        state_mut.item.size_changed_since_paint = true;
+        // The initial size is covered by `Update::WidgetAdded`, so we don't notify for it.
+        let size_changes = &mut parent_ctx.global_state.size_changed_widgets;
+        if state_mut.item.is_laid_out && !size_changes.iter().any(|(other, _)| *other == id) {
+            size_changes.push((id, old_size));
+        }
+    }
+    state_mut.item.is_laid_out = true;
     state_mut.item.size = new_size;
     new_size
 }
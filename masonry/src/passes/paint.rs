@@ -60,6 +60,8 @@ fn paint_widget(
             map: properties.item,
         };
         widget.item.paint(&mut ctx, &props, scene);
+
+        state.item.size_changed_since_paint = false;
     }
 
     state.item.request_paint = false;
@@ -70,8 +72,30 @@ fn paint_widget(
     let transform = state.item.window_transform;
     let scene = scenes.get(&id).unwrap();
 
+    // Fully transparent widgets and their descendants are still painted, so that their
+    // paint flags are cleared, but the result is thrown away rather than composited.
+    let mut discarded_scene;
+    let complete_scene = if state.item.opacity == 0.0 {
+        discarded_scene = Scene::new();
+        &mut discarded_scene
+    } else {
+        complete_scene
+    };
+
+    let translucent = state.item.opacity < 1.0;
+    if translucent {
+        complete_scene.push_layer(
+            Mix::Normal,
+            state.item.opacity as f32,
+            Affine::IDENTITY,
+            &state.item.bounding_rect,
+        );
+    }
     if let Some(clip) = clip {
-        complete_scene.push_layer(Mix::Clip, 1., transform, &clip);
+        match &state.item.clip_shape {
+            Some(shape) => complete_scene.push_layer(Mix::Clip, 1., transform, shape),
+            None => complete_scene.push_layer(Mix::Clip, 1., transform, &clip),
+        }
     }
 
     complete_scene.append(scene, Some(transform));
@@ -118,6 +142,9 @@ fn paint_widget(
     if has_clip {
         complete_scene.pop_layer();
     }
+    if translucent {
+        complete_scene.pop_layer();
+    }
 }
 
 // --- MARK: ROOT ---
@@ -2,19 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use anymap3::AnyMap;
 use cursor_icon::CursorIcon;
 use tracing::{info_span, trace};
 use tree_arena::ArenaMut;
 
-use crate::app::{RenderRoot, RenderRootSignal, RenderRootState};
+use crate::app::{LayerStack, RenderRoot, RenderRootSignal, RenderRootState};
 use crate::core::{
     PointerEvent, PropertiesMut, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
     WidgetId, WidgetState,
 };
 use crate::passes::event::{run_on_pointer_event_pass, run_on_text_event_pass};
+use crate::passes::mutate::mutate_widget;
 use crate::passes::{enter_span, enter_span_if, merge_state_up, recurse_on_children};
+use crate::theme::Theme;
 
 // --- MARK: HELPERS ---
 /// Returns the id path starting from the given widget id and ending at the root.
@@ -214,6 +217,8 @@ pub(crate) fn run_update_widget_tree_pass(root: &mut RenderRoot) {
         ctx.register_child(&mut root.root);
     }
 
+    remove_orphaned_layers(root);
+
     let (root_widget, mut root_state, root_properties) =
         root.widget_arena.get_all_mut(root.root.id());
     update_widget_tree(
@@ -224,6 +229,23 @@ pub(crate) fn run_update_widget_tree_pass(root: &mut RenderRoot) {
     );
 }
 
+/// Remove the layers whose owner has been removed from the widget tree.
+///
+/// See [`EventCtx::add_layer`](crate::core::EventCtx::add_layer).
+fn remove_orphaned_layers(root: &mut RenderRoot) {
+    let stack_id = root.root.id();
+    let orphans = root
+        .get_layer_stack()
+        .downcast::<LayerStack>()
+        .expect("root widget should be a LayerStack")
+        .orphaned_layers(|id| root.widget_arena.has(id));
+    for id in orphans {
+        mutate_widget(root, stack_id, |mut stack| {
+            LayerStack::remove_layer(&mut stack.downcast(), id);
+        });
+    }
+}
+
 // ----------------
 
 // --- MARK: UPDATE DISABLED ---
@@ -401,6 +423,94 @@ pub(crate) fn run_update_stashed_pass(root: &mut RenderRoot) {
 
 // ----------------
 
+// --- MARK: UPDATE THEME ---
+/// See the [passes documentation](../doc/05_pass_system.md#update-passes).
+fn update_theme_for_widget(
+    global_state: &mut RenderRootState,
+    mut widget: ArenaMut<'_, Box<dyn Widget>>,
+    mut state: ArenaMut<'_, WidgetState>,
+    mut properties: ArenaMut<'_, AnyMap>,
+    parent_theme: &Arc<Theme>,
+    restyle_all: bool,
+) {
+    let _span = enter_span(
+        global_state,
+        widget.reborrow(),
+        state.reborrow(),
+        properties.reborrow(),
+    );
+    let id = state.item.id;
+
+    let theme = state.item.explicit_theme.as_ref().unwrap_or(parent_theme);
+    let changed = !Arc::ptr_eq(theme, &state.item.theme);
+    if !state.item.needs_update_theme && !changed && !restyle_all {
+        return;
+    }
+
+    if changed || restyle_all {
+        state.item.theme = theme.clone();
+        let mut ctx = UpdateCtx {
+            global_state,
+            widget_state: state.item,
+            widget_state_children: state.children.reborrow_mut(),
+            widget_children: widget.children.reborrow_mut(),
+            properties_children: properties.children.reborrow_mut(),
+        };
+        let mut props = PropertiesMut {
+            map: properties.item,
+        };
+        widget
+            .item
+            .update(&mut ctx, &mut props, &Update::ThemeChanged);
+        // Themes can change sizes as well as colors.
+        state.item.request_layout = true;
+        state.item.needs_layout = true;
+        state.item.request_paint = true;
+        state.item.needs_paint = true;
+    }
+
+    state.item.needs_update_theme = false;
+
+    let theme = state.item.theme.clone();
+    let parent_state = state.item;
+    recurse_on_children(
+        id,
+        widget.reborrow_mut(),
+        state.children,
+        properties.children,
+        |widget, mut state, properties| {
+            update_theme_for_widget(
+                global_state,
+                widget,
+                state.reborrow_mut(),
+                properties,
+                &theme,
+                restyle_all,
+            );
+            parent_state.merge_up(state.item);
+        },
+    );
+}
+
+/// If `restyle_all` is set, every widget is sent [`Update::ThemeChanged`],
+/// e.g. because the system theme changed.
+pub(crate) fn run_update_theme_pass(root: &mut RenderRoot, restyle_all: bool) {
+    let _span = info_span!("update_theme").entered();
+
+    let theme = root.global_state.theme.clone();
+    let (root_widget, root_state, root_properties) = root.widget_arena.get_all_mut(root.root.id());
+    update_theme_for_widget(
+        &mut root.global_state,
+        root_widget,
+        root_state,
+        root_properties,
+        &theme,
+        restyle_all,
+    );
+}
+
+// ----------------
+
 // --- MARK: FOCUS CHAIN ---
 
 // TODO https://github.com/linebender/xilem/issues/376 - Some implicit invariants:
@@ -415,7 +525,7 @@ fn update_focus_chain_for_widget(
     mut widget: ArenaMut<'_, Box<dyn Widget>>,
     mut state: ArenaMut<'_, WidgetState>,
     mut properties: ArenaMut<'_, AnyMap>,
-    parent_focus_chain: &mut Vec<WidgetId>,
+    parent_focus_chain: &mut Vec<(WidgetId, Option<i32>)>,
 ) {
     let _span = enter_span(
         global_state,
@@ -426,6 +536,10 @@ fn update_focus_chain_for_widget(
     let id = state.item.id;
 
     if !state.item.needs_update_focus_chain {
+        // This subtree's chain is unchanged, but the parent's is rebuilt from its children's.
+        if !state.item.is_disabled {
+            parent_focus_chain.extend(&state.item.focus_chain);
+        }
         return;
     }
 
@@ -435,7 +549,7 @@ fn update_focus_chain_for_widget(
 
     state.item.focus_chain.clear();
     if state.item.accepts_focus {
-        state.item.focus_chain.push(id);
+        state.item.focus_chain.push((id, None));
     }
     state.item.needs_update_focus_chain = false;
 
@@ -457,6 +571,13 @@ fn update_focus_chain_for_widget(
         },
     );
 
+    // Descendants without a tab index of their own take this widget's.
+    if let Some(tab_index) = state.item.tab_index {
+        for (_, index) in &mut state.item.focus_chain {
+            index.get_or_insert(tab_index);
+        }
+    }
+
     if !state.item.is_disabled {
         parent_focus_chain.extend(&state.item.focus_chain);
     }
@@ -500,6 +621,17 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
         }
     }
 
+    // If focus is trapped (e.g. by a modal), it can't move outside of the trapping widget.
+    root.global_state
+        .focus_traps
+        .retain(|id| root.widget_arena.has(*id));
+    if let Some(trap) = root.global_state.focus_traps.last().copied() {
+        let next_focused_path = get_id_path(root, root.global_state.next_focused_widget);
+        if !next_focused_path.contains(&trap) {
+            root.global_state.next_focused_widget = Some(trap);
+        }
+    }
+
     let prev_focused = root.global_state.focused_widget;
     let was_ime_active = root.global_state.is_ime_active;
 
@@ -666,6 +798,30 @@ pub(crate) fn run_update_scroll_pass(root: &mut RenderRoot) {
 
 // ----------------
 
+// --- MARK: UPDATE SIZE ---
+/// Send [`Update::SizeChanged`] to widgets whose size changed during the layout pass.
+///
+/// See the [passes documentation](../doc/05_pass_system.md#update-passes).
+pub(crate) fn run_update_size_pass(root: &mut RenderRoot) {
+    if root.global_state.size_changed_widgets.is_empty() {
+        return;
+    }
+    let _span = info_span!("update_size").entered();
+
+    let size_changed_widgets = std::mem::take(&mut root.global_state.size_changed_widgets);
+    for (target, old_size) in size_changed_widgets {
+        run_single_update_pass(root, Some(target), |widget, ctx, props| {
+            let size = ctx.widget_state.size;
+            // The size may have been changed back within the same layout pass.
+            if size != old_size {
+                widget.update(ctx, props, &Update::SizeChanged(size));
+            }
+        });
+    }
+}
+
+// ----------------
+
 // --- MARK: UPDATE POINTER ---
 /// See the [passes documentation](../doc/05_pass_system.md#update-passes).
 pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
@@ -681,7 +837,7 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
     if root.global_state.inspector_state.is_picking_widget {
         if let Some(pos) = pointer_pos {
             root.global_state.inspector_state.hovered_widget = root
-                .get_root_widget()
+                .get_layer_stack()
                 .find_widget_at_pos(pos)
                 .map(|widget| widget.id());
         }
@@ -700,7 +856,7 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
     // -- UPDATE HOVERED WIDGETS --
     let mut next_hovered_widget = if let Some(pos) = pointer_pos {
         // TODO - Apply scale?
-        root.get_root_widget()
+        root.get_layer_stack()
             .find_widget_at_pos(pos)
             .map(|widget| widget.id())
     } else {
@@ -803,7 +959,7 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
         };
 
         if state.item.is_disabled {
-            CursorIcon::Default
+            CursorIcon::NotAllowed
         } else {
             widget.item.get_cursor(&ctx, pos)
         }
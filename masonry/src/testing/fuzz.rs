@@ -0,0 +1,261 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Randomized input replay for widget robustness tests.
+
+use tracing::debug;
+use winit::event::Ime;
+use winit::keyboard::ModifiersState;
+
+use crate::core::{PointerButton, PointerEvent, TextEvent, Widget, WidgetId, WidgetRef};
+use crate::kurbo::{Point, Vec2};
+use crate::testing::TestHarness;
+
+/// A small, seeded xorshift generator.
+///
+/// We don't need statistical quality here, only reproducibility without an extra dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Xorshift is stuck at zero, so we perturb the seed.
+        Self((seed ^ 0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// Returns a value in `0..n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Returns a value in `0.0..max`.
+    fn float(&mut self, max: f64) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64 * max
+    }
+
+    fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+const BUTTONS: [PointerButton; 3] = [
+    PointerButton::Primary,
+    PointerButton::Secondary,
+    PointerButton::Auxiliary,
+];
+
+const SAMPLE_TEXT: [&str; 6] = ["a", "Hello", " ", "ab cd", "\n", "xyz"];
+
+/// Generates randomized but structurally valid input sequences and replays them
+/// against a [`TestHarness`].
+///
+/// Widgets are expected to cope with unusual event orderings, such as a pointer
+/// being released without having been pressed, an IME commit without a preedit,
+/// or the window losing focus in the middle of a drag.
+/// These are hard to enumerate by hand, so this type generates them from a seed.
+///
+/// After every event, the fuzzer checks that:
+///
+/// - The [focused widget](crate::doc::doc_06_masonry_concepts#text-focus), if any, is in the tree and accepts focus.
+/// - The [pointer capture target](crate::doc::doc_06_masonry_concepts#pointer-capture), if any, is in the tree.
+/// - The rewrite passes have left no pending layout.
+///
+/// Any panic (including `debug_panic` failures in debug builds) fails the test.
+/// The same seed always produces the same event sequence, so failures can be reproduced
+/// by re-running with the seed printed in the panic message.
+///
+/// Keyboard events are not generated yet.
+///
+/// ## Example
+///
+/// ```
+/// use masonry::testing::{InputFuzzer, TestHarness};
+/// use masonry::widgets::Textbox;
+///
+/// let mut harness = TestHarness::create(Textbox::new("Hello"));
+/// InputFuzzer::new(0x5EED).with_iterations(50).run(&mut harness);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct InputFuzzer {
+    seed: u64,
+    iterations: usize,
+}
+
+impl InputFuzzer {
+    /// The number of events generated by default.
+    ///
+    /// This is kept modest so that fuzz tests run quickly as part of `cargo test`.
+    pub const DEFAULT_ITERATIONS: usize = 200;
+
+    /// Create a fuzzer which generates events from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            iterations: Self::DEFAULT_ITERATIONS,
+        }
+    }
+
+    /// Set the number of events to generate.
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Replay a randomized event sequence against `harness`, checking invariants after each event.
+    ///
+    /// ## Panics
+    ///
+    /// If a widget panics while handling an event, or if an invariant is broken.
+    #[track_caller]
+    pub fn run(&self, harness: &mut TestHarness) {
+        let mut rng = Rng::new(self.seed);
+        let window_size = harness.window_size();
+        // We go a bit beyond the window bounds so that pointers can leave widgets.
+        let width = window_size.width as f64 + 20.0;
+        let height = window_size.height as f64 + 20.0;
+
+        for step in 0..self.iterations {
+            let event = match rng.below(12) {
+                0..=3 => {
+                    let pos = Point::new(rng.float(width) - 10.0, rng.float(height) - 10.0);
+                    harness.mouse_move(pos);
+                    format!("mouse_move({pos:?})")
+                }
+                4 => {
+                    let button = BUTTONS[rng.below(BUTTONS.len())];
+                    harness.mouse_button_press(button);
+                    format!("mouse_button_press({button:?})")
+                }
+                5 => {
+                    // Deliberately not paired with a press.
+                    let button = BUTTONS[rng.below(BUTTONS.len())];
+                    harness.mouse_button_release(button);
+                    format!("mouse_button_release({button:?})")
+                }
+                6 => {
+                    let delta = Vec2::new(rng.float(40.0) - 20.0, rng.float(40.0) - 20.0);
+                    harness.mouse_wheel(delta);
+                    format!("mouse_wheel({delta:?})")
+                }
+                7 => {
+                    harness.process_pointer_event(PointerEvent::new_pointer_leave());
+                    "pointer_leave".into()
+                }
+                8 => {
+                    let ime = random_ime(&mut rng);
+                    let name = format!("{ime:?}");
+                    harness.process_text_event(TextEvent::Ime(ime));
+                    name
+                }
+                9 => {
+                    let focused = rng.bool();
+                    harness.process_text_event(TextEvent::WindowFocusChange(focused));
+                    format!("WindowFocusChange({focused})")
+                }
+                10 => {
+                    let mods = random_modifiers(&mut rng);
+                    harness.process_text_event(TextEvent::ModifierChange(mods));
+                    format!("ModifierChange({mods:?})")
+                }
+                _ => {
+                    let candidates = focusable_widgets(harness.root_widget());
+                    let target = if candidates.is_empty() || rng.below(3) == 0 {
+                        None
+                    } else {
+                        Some(candidates[rng.below(candidates.len())])
+                    };
+                    harness.focus_on(target);
+                    format!("focus_on({target:?})")
+                }
+            };
+            debug!("Fuzz step {step}: {event}");
+            self.check_invariants(harness, step, &event);
+        }
+    }
+
+    #[track_caller]
+    fn check_invariants(self, harness: &TestHarness, step: usize, event: &str) {
+        let seed = self.seed;
+        if let Some(id) = harness.focused_widget_id() {
+            let Some(widget) = harness.try_get_widget(id) else {
+                panic!(
+                    "Fuzz seed {seed:#x}, step {step} ({event}): focused widget {id} is not in the tree"
+                );
+            };
+            if !widget.ctx().accepts_focus() {
+                panic!(
+                    "Fuzz seed {seed:#x}, step {step} ({event}): focused widget {id} doesn't accept focus"
+                );
+            }
+        }
+        if let Some(id) = harness.pointer_capture_target_id() {
+            if harness.try_get_widget(id).is_none() {
+                panic!(
+                    "Fuzz seed {seed:#x}, step {step} ({event}): pointer capture target {id} is not in the tree"
+                );
+            }
+        }
+        if harness.root_widget().ctx().widget_state.needs_layout {
+            panic!("Fuzz seed {seed:#x}, step {step} ({event}): layout flags were not cleared");
+        }
+    }
+}
+
+fn random_ime(rng: &mut Rng) -> Ime {
+    let text = SAMPLE_TEXT[rng.below(SAMPLE_TEXT.len())].to_string();
+    match rng.below(5) {
+        0 => Ime::Enabled,
+        1 => Ime::Disabled,
+        2 => Ime::Commit(text),
+        3 => Ime::Preedit(String::new(), None),
+        _ => {
+            // The sample text is ASCII, so every offset is a char boundary.
+            let start = rng.below(text.len() + 1);
+            let end = start + rng.below(text.len() + 1 - start);
+            let cursor = rng.bool().then_some((start, end));
+            Ime::Preedit(text, cursor)
+        }
+    }
+}
+
+fn random_modifiers(rng: &mut Rng) -> ModifiersState {
+    let mut state = ModifiersState::empty();
+    for flag in [
+        ModifiersState::SHIFT,
+        ModifiersState::CONTROL,
+        ModifiersState::ALT,
+        ModifiersState::SUPER,
+    ] {
+        if rng.below(4) == 0 {
+            state |= flag;
+        }
+    }
+    state
+}
+
+fn focusable_widgets(root: WidgetRef<'_, dyn Widget>) -> Vec<WidgetId> {
+    fn visit(widget: WidgetRef<'_, dyn Widget>, ids: &mut Vec<WidgetId>) {
+        let ctx = widget.ctx();
+        if ctx.is_stashed() || ctx.is_disabled() {
+            return;
+        }
+        if ctx.accepts_focus() {
+            ids.push(widget.id());
+        }
+        for child in widget.children() {
+            visit(child, ids);
+        }
+    }
+
+    let mut ids = Vec::new();
+    visit(root, &mut ids);
+    ids
+}
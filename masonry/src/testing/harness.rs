@@ -6,9 +6,11 @@
 use std::collections::VecDeque;
 use std::num::NonZeroUsize;
 
+use accesskit::{ActionData, ActionRequest};
 use cursor_icon::CursorIcon;
 use dpi::LogicalSize;
 use image::{DynamicImage, ImageReader, Rgba, RgbaImage};
+use parley::{FontContext, LayoutContext};
 use tracing::debug;
 use vello::RendererOptions;
 use vello::util::{RenderContext, block_on_wgpu};
@@ -17,14 +19,15 @@ use wgpu::{
     TextureDescriptor, TextureFormat, TextureUsages,
 };
 use winit::event::Ime;
+use winit::keyboard::{KeyCode, ModifiersState, NamedKey};
 
 use crate::Handled;
 use crate::app::{
     RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy, try_init_test_tracing,
 };
 use crate::core::{
-    Action, PointerButton, PointerEvent, PointerState, TextEvent, Widget, WidgetId, WidgetMut,
-    WidgetRef, WindowEvent,
+    Action, BrushIndex, PointerButton, PointerEvent, PointerState, TextEvent, Widget, WidgetId,
+    WidgetMut, WidgetRef, WindowEvent,
 };
 use crate::dpi::{LogicalPosition, PhysicalPosition, PhysicalSize};
 use crate::kurbo::{Point, Size, Vec2};
@@ -268,6 +271,24 @@ impl TestHarness {
         handled
     }
 
+    /// Send an accessibility action to the widget with the given id.
+    ///
+    /// This simulates a request from assistive technology, such as a screen reader.
+    /// This will run [rewrite passes](crate::doc::doc_05_pass_system#rewrite-passes) after the event is processed.
+    pub fn process_access_event(
+        &mut self,
+        target: WidgetId,
+        action: accesskit::Action,
+        data: Option<ActionData>,
+    ) {
+        self.render_root.handle_access_event(ActionRequest {
+            action,
+            target: target.into(),
+            data,
+        });
+        self.process_signals();
+    }
+
     fn process_signals(&mut self) {
         while let Some(signal) = self.render_root.pop_signal() {
             match signal {
@@ -483,7 +504,7 @@ impl TestHarness {
         }
         if self
             .render_root
-            .get_root_widget()
+            .get_layer_stack()
             .find_widget_at_pos(widget_center)
             .map(|w| w.id())
             != Some(id)
@@ -529,6 +550,20 @@ impl TestHarness {
         self.process_signals();
     }
 
+    /// Press and release Tab, or Shift+Tab if `forward` is false.
+    ///
+    /// Unless the focused widget handles these presses, this moves the focus to the next
+    /// (or previous) widget in the focus chain.
+    pub fn focus_next(&mut self, forward: bool) {
+        let mods = if forward {
+            ModifiersState::empty()
+        } else {
+            ModifiersState::SHIFT
+        };
+        self.process_text_event(TextEvent::key_down(KeyCode::Tab, NamedKey::Tab, mods));
+        self.process_text_event(TextEvent::key_up(KeyCode::Tab, NamedKey::Tab, mods));
+    }
+
     /// Run an animation pass on the widget tree.
     pub fn animate_ms(&mut self, ms: u64) {
         run_update_anim_pass(&mut self.render_root, ms * 1_000_000);
@@ -566,6 +601,14 @@ impl TestHarness {
             .find_widget_by_id(self.render_root.global_state.focused_widget?)
     }
 
+    /// Return the id of the [focused widget](crate::doc::doc_06_masonry_concepts#text-focus).
+    ///
+    /// Unlike [`focused_widget`](Self::focused_widget), this returns the id even if the
+    /// widget can no longer be found in the tree.
+    pub fn focused_widget_id(&self) -> Option<WidgetId> {
+        self.render_root.global_state.focused_widget
+    }
+
     /// Return a [`WidgetRef`] to the widget which [captures pointer events](crate::doc::doc_06_masonry_concepts#pointer-capture).
     pub fn pointer_capture_target(&self) -> Option<WidgetRef<'_, dyn Widget>> {
         self.render_root
@@ -611,6 +654,18 @@ impl TestHarness {
         self.render_root.edit_widget(id, f)
     }
 
+    /// Call `f` with the app's font and text layout contexts.
+    ///
+    /// This can be used to lay out text owned by a widget which isn't in the widget tree,
+    /// for example to check that its layout is rebuilt with the app's fonts.
+    pub fn with_text_contexts<R>(
+        &mut self,
+        f: impl FnOnce(&mut FontContext, &mut LayoutContext<BrushIndex>) -> R,
+    ) -> R {
+        let state = &mut self.render_root.global_state;
+        f(&mut state.font_context, &mut state.text_layout_context)
+    }
+
     /// Pop the oldest [`Action`] emitted by the widget tree.
     pub fn pop_action(&mut self) -> Option<(Action, WidgetId)> {
         self.action_queue.pop_front()
@@ -3,11 +3,13 @@
 
 //! Helper tools for writing unit tests.
 
+mod fuzz;
 mod harness;
 mod helper_widgets;
 mod screenshots;
 mod snapshot_utils;
 
+pub use fuzz::InputFuzzer;
 pub use harness::{TestHarness, TestHarnessParams};
 pub use helper_widgets::{ModularWidget, Record, Recorder, Recording, ReplaceChild, TestWidgetExt};
 
@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Theme keys and initial values.
+//!
+//! The constants in this module are the values of the default [`Theme`].
 
 #![allow(missing_docs)]
 
+use std::sync::{Arc, LazyLock};
+
 use crate::kurbo::Insets;
 use crate::peniko::Color;
 
@@ -17,6 +21,7 @@ pub const DISABLED_TEXT_COLOR: Color = Color::from_rgb8(0xa0, 0xa0, 0x9a);
 pub const PLACEHOLDER_COLOR: Color = Color::from_rgb8(0x80, 0x80, 0x80);
 pub const PRIMARY_LIGHT: Color = Color::from_rgb8(0x5c, 0xc4, 0xff);
 pub const PRIMARY_DARK: Color = Color::from_rgb8(0x00, 0x8d, 0xdd);
+pub const ACCENT_COLOR: Color = Color::from_rgb8(0x5c, 0xc4, 0xff);
 pub const PROGRESS_BAR_RADIUS: f64 = 4.;
 pub const BACKGROUND_LIGHT: Color = Color::from_rgb8(0x3a, 0x3a, 0x3a);
 pub const BACKGROUND_DARK: Color = Color::from_rgb8(0x31, 0x31, 0x31);
@@ -36,6 +41,7 @@ pub const SELECTED_TEXT_BACKGROUND_COLOR: Color = Color::from_rgb8(0x43, 0x70, 0
 pub const SELECTED_TEXT_INACTIVE_BACKGROUND_COLOR: Color = Color::from_rgb8(0x74, 0x74, 0x74);
 pub const SELECTION_TEXT_COLOR: Color = Color::from_rgb8(0x00, 0x00, 0x00);
 pub const CURSOR_COLOR: Color = Color::WHITE;
+pub const SCRIM_COLOR: Color = Color::from_rgba8(0x00, 0x00, 0x00, 0x99);
 pub const TEXT_SIZE_NORMAL: f32 = 15.0;
 pub const TEXT_SIZE_LARGE: f32 = 24.0;
 pub const BASIC_WIDGET_HEIGHT: f64 = 18.0;
@@ -53,10 +59,108 @@ pub const SCROLLBAR_PAD: f64 = 2.;
 pub const SCROLLBAR_MIN_SIZE: f64 = 45.;
 pub const SCROLLBAR_RADIUS: f64 = 5.;
 pub const SCROLLBAR_EDGE_WIDTH: f64 = 1.;
+pub const KINETIC_SCROLL_DECELERATION: f64 = 4.0;
 pub const WIDGET_PADDING_VERTICAL: f64 = 10.0;
 pub const WIDGET_PADDING_HORIZONTAL: f64 = 8.0;
 pub const WIDGET_CONTROL_COMPONENT_PADDING: f64 = 4.0;
 
+/// The colors and sizes which widgets are drawn with.
+///
+/// The theme can be changed at runtime for the whole window with
+/// [`RenderRoot::set_theme`](crate::app::RenderRoot::set_theme), or for part of the widget tree
+/// with [`Themed`](crate::widgets::Themed).
+/// Widgets read the theme which applies to them with e.g. [`PaintCtx::theme`], and are sent
+/// [`Update::ThemeChanged`] when it changes, after which they are laid out and painted again.
+///
+/// Not every widget reads every part of the theme yet; the rest still use the constants
+/// in this module.
+///
+/// [`PaintCtx::theme`]: crate::core::PaintCtx::theme
+/// [`Update::ThemeChanged`]: crate::core::Update::ThemeChanged
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// The color behind widgets, such as the window background.
+    ///
+    /// Masonry doesn't paint this itself, but apps can use it for their own backgrounds.
+    pub background_color: Color,
+    /// The color of text which doesn't have its own brush.
+    pub text_color: Color,
+    /// The color of disabled text which doesn't have its own disabled brush.
+    pub disabled_text_color: Color,
+    /// The color used to highlight e.g. focused widgets.
+    pub accent_color: Color,
+    /// The color of the caret in editable text which doesn't have its own caret brush.
+    pub caret_color: Color,
+    /// The font size of normal text.
+    pub text_size_normal: f32,
+    /// The font size of larger text, such as headings.
+    pub text_size_large: f32,
+    /// The top color of the gradient filling buttons.
+    pub button_light: Color,
+    /// The bottom color of the gradient filling buttons.
+    pub button_dark: Color,
+    /// The top color of the gradient filling disabled buttons.
+    pub disabled_button_light: Color,
+    /// The bottom color of the gradient filling disabled buttons.
+    pub disabled_button_dark: Color,
+    /// The color of the outline of hovered buttons.
+    pub border_light: Color,
+    /// The color of the outline of buttons which aren't hovered.
+    pub border_dark: Color,
+}
+
+impl Theme {
+    /// The default theme, with light text on a dark background.
+    pub const fn dark() -> Self {
+        Self {
+            background_color: WINDOW_BACKGROUND_COLOR,
+            text_color: TEXT_COLOR,
+            disabled_text_color: DISABLED_TEXT_COLOR,
+            accent_color: ACCENT_COLOR,
+            caret_color: CURSOR_COLOR,
+            text_size_normal: TEXT_SIZE_NORMAL,
+            text_size_large: TEXT_SIZE_LARGE,
+            button_light: BUTTON_LIGHT,
+            button_dark: BUTTON_DARK,
+            disabled_button_light: DISABLED_BUTTON_LIGHT,
+            disabled_button_dark: DISABLED_BUTTON_DARK,
+            border_light: BORDER_LIGHT,
+            border_dark: BORDER_DARK,
+        }
+    }
+
+    /// A theme with dark text on a light background.
+    pub const fn light() -> Self {
+        Self {
+            background_color: Color::from_rgb8(0xf5, 0xf5, 0xf5),
+            text_color: Color::from_rgb8(0x1e, 0x1e, 0x1e),
+            disabled_text_color: Color::from_rgb8(0x8a, 0x8a, 0x8a),
+            accent_color: PRIMARY_DARK,
+            caret_color: Color::BLACK,
+            text_size_normal: TEXT_SIZE_NORMAL,
+            text_size_large: TEXT_SIZE_LARGE,
+            button_light: Color::WHITE,
+            button_dark: Color::from_rgb8(0xe0, 0xe0, 0xe0),
+            disabled_button_light: Color::from_rgb8(0xf0, 0xf0, 0xf0),
+            disabled_button_dark: Color::from_rgb8(0xe6, 0xe6, 0xe6),
+            border_light: Color::from_rgb8(0x5a, 0x5a, 0x5a),
+            border_dark: Color::from_rgb8(0xb0, 0xb0, 0xb0),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// The default theme, shared so that widgets which use it don't each allocate a copy.
+pub(crate) fn default_theme() -> Arc<Theme> {
+    static DEFAULT_THEME: LazyLock<Arc<Theme>> = LazyLock::new(|| Arc::new(Theme::default()));
+    DEFAULT_THEME.clone()
+}
+
 static DEBUG_COLOR: &[Color] = &[
     Color::from_rgb8(230, 25, 75),
     Color::from_rgb8(60, 180, 75),
@@ -3,33 +3,58 @@
 
 //! A button widget.
 
+use std::time::Duration;
+
 use accesskit::{Node, Role};
 use smallvec::{SmallVec, smallvec};
 use tracing::{Span, trace, trace_span};
 use vello::Scene;
+use vello::kurbo::Affine;
+use vello::peniko::{Brush, Fill};
+use winit::keyboard::{Key, NamedKey};
 
 use crate::core::{
-    AccessCtx, AccessEvent, Action, ArcStr, BoxConstraints, EventCtx, LayoutCtx, PaintCtx,
-    PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, AccessEvent, Action, ArcStr, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx,
+    PaintCtx, PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, TextEvent,
+    Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::kurbo::{Insets, Size};
 use crate::theme;
 use crate::util::{UnitPoint, fill_lin_gradient, stroke};
-use crate::widgets::Label;
+use crate::widgets::{CrossAxisAlignment, Flex, Image, Label, SizedBox};
 
 // The minimum padding added to a button.
 // NOTE: these values are chosen to match the existing look of TextBox; these
 // should be reevaluated at some point.
 const LABEL_INSETS: Insets = Insets::uniform_xy(8., 2.);
 
-/// A button with a text label.
+// The size of the icons of buttons created with `Button::with_icon`,
+// which is roughly the height of text at the default size.
+const ICON_SIZE: f64 = 16.;
+// The gap between the icon and the text of those buttons.
+const ICON_GAP: f64 = 4.;
+
+/// A button with a text label, or any other content.
 ///
 /// Emits [`Action::ButtonPressed`] when pressed.
+/// The whole button responds to the pointer, including the parts covered by its content.
+/// It can also be pressed with the Space key whilst focused, which acts like the primary
+/// pointer button.
 ///
 #[doc = crate::include_screenshot!("widget/screenshots/masonry__widget__button__tests__hello.png", "Button with text label.")]
-pub struct Button {
-    label: WidgetPod<Label>,
+pub struct Button<W: ?Sized = Label> {
+    child: WidgetPod<W>,
+    /// The brush to fill the button with whilst it is hovered.
+    ///
+    /// When this is `None`, the normal gradient is used.
+    hover_brush: Option<Brush>,
+    /// How long the button must be held before it starts repeating,
+    /// and the time between repeats, if it repeats at all.
+    auto_repeat: Option<(Duration, Duration)>,
+    /// The pointer button which is held, and the time until the next repeat, whilst repeating.
+    repeat: Option<(PointerButton, Duration)>,
+    /// Whether the button is being held down with the Space key.
+    key_held: bool,
 }
 
 // --- MARK: BUILDERS ---
@@ -59,16 +84,108 @@ impl Button {
     /// let button = Button::from_label(label);
     /// ```
     pub fn from_label(label: Label) -> Self {
-        Self {
-            label: WidgetPod::new(label),
-        }
+        Self::from_label_pod(WidgetPod::new(label))
     }
 
     /// Create a new button with the provided [`Label`] with a predetermined id.
     ///
     /// This constructor is useful for toolkits which use Masonry (such as Xilem).
     pub fn from_label_pod(label: WidgetPod<Label>) -> Self {
-        Self { label }
+        Self::from_child(label)
+    }
+}
+
+/// Where the icon of a [`Button`] is placed, relative to its text.
+///
+/// See [`Button::with_icon`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IconPosition {
+    /// The icon is placed before the text.
+    #[default]
+    Leading,
+    /// The icon is placed after the text.
+    Trailing,
+}
+
+impl Button<Flex> {
+    /// Create a new button with an icon next to a text label.
+    ///
+    /// The icon is scaled to fit a square about as tall as the text,
+    /// and the icon and text are centered in the button.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masonry::peniko::{Image as ImageBuf, ImageFormat};
+    /// use masonry::widgets::{Button, IconPosition, Image};
+    ///
+    /// let icon = ImageBuf::new(vec![255; 4 * 16 * 16].into(), ImageFormat::Rgba8, 16, 16);
+    /// let button = Button::with_icon(Image::new(icon), "Save", IconPosition::Leading);
+    /// ```
+    pub fn with_icon(icon: Image, text: impl Into<ArcStr>, position: IconPosition) -> Self {
+        let icon = SizedBox::new(icon).width(ICON_SIZE).height(ICON_SIZE);
+        let label = Label::new(text);
+        let content = Flex::row()
+            .cross_axis_alignment(CrossAxisAlignment::Center)
+            .gap(ICON_GAP);
+        let content = match position {
+            IconPosition::Leading => content.with_child(icon).with_child(label),
+            IconPosition::Trailing => content.with_child(label).with_child(icon),
+        };
+        Self::from_child(WidgetPod::new(content))
+    }
+}
+
+impl<W: Widget + ?Sized> Button<W> {
+    /// Create a new button containing `child`.
+    ///
+    /// `child` is centered in the button, and is laid out with at least the button's padding
+    /// around it.
+    /// Pointer events on `child` still press the button, unless `child` handles them itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use masonry::core::{Widget, WidgetPod};
+    /// use masonry::widgets::{Button, Label};
+    ///
+    /// let child = WidgetPod::new(Label::new("Bold")).erased();
+    /// let button: Button<dyn Widget> = Button::from_child(child);
+    /// ```
+    pub fn from_child(child: WidgetPod<W>) -> Self {
+        Self {
+            child,
+            hover_brush: None,
+            auto_repeat: None,
+            repeat: None,
+            key_held: false,
+        }
+    }
+
+    /// Set the brush which will fill this button whilst the pointer is over it,
+    /// so that it visibly responds to hovering.
+    ///
+    /// If this is `None`, only the border is highlighted.
+    /// This isn't used whilst the button is pressed or disabled.
+    /// To modify this on an active button, use [`set_hover_brush`](Self::set_hover_brush).
+    pub fn with_hover_brush(mut self, hover_brush: impl Into<Option<Brush>>) -> Self {
+        self.hover_brush = hover_brush.into();
+        self
+    }
+
+    /// Make this button submit [`Action::ButtonPressed`] repeatedly whilst it is held down,
+    /// like the arrows of a spin box or a scrollbar.
+    ///
+    /// The action is submitted as soon as the button is pressed, then once it has been held
+    /// for `initial_delay`, and then every `interval` until it is released, the pointer
+    /// leaves it, it loses focus whilst held with the Space key, or it is disabled.
+    /// Releasing the button doesn't submit another action.
+    /// If `interval` is zero, the action is submitted on every animation frame.
+    ///
+    /// To modify this on an active button, use [`set_auto_repeat`](Self::set_auto_repeat).
+    pub fn with_auto_repeat(mut self, initial_delay: Duration, interval: Duration) -> Self {
+        self.auto_repeat = Some((initial_delay, interval));
+        self
     }
 }
 
@@ -80,12 +197,45 @@ impl Button {
     }
 
     pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
-        this.ctx.get_mut(&mut this.widget.label)
+        Self::child_mut(this)
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> Button<W> {
+    /// Get a mutable reference to the content of this button.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the brush which will fill this button whilst it is hovered.
+    ///
+    /// The runtime equivalent of [`with_hover_brush`](Self::with_hover_brush).
+    pub fn set_hover_brush(this: &mut WidgetMut<'_, Self>, hover_brush: impl Into<Option<Brush>>) {
+        this.widget.hover_brush = hover_brush.into();
+        if this.ctx.has_hovered() {
+            this.ctx.request_paint_only();
+        }
+    }
+
+    /// Set the delay before this button repeats whilst held and the time between repeats,
+    /// or `None` to only submit an action when the button is released.
+    ///
+    /// Setting this to `None` whilst the button is held stops it repeating.
+    ///
+    /// The runtime equivalent of [`with_auto_repeat`](Self::with_auto_repeat).
+    pub fn set_auto_repeat(
+        this: &mut WidgetMut<'_, Self>,
+        auto_repeat: Option<(Duration, Duration)>,
+    ) {
+        this.widget.auto_repeat = auto_repeat;
+        if auto_repeat.is_none() {
+            this.widget.repeat = None;
+        }
     }
 }
 
 // --- MARK: IMPL WIDGET ---
-impl Widget for Button {
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Button<W> {
     fn on_pointer_event(
         &mut self,
         ctx: &mut EventCtx,
@@ -93,19 +243,30 @@ impl Widget for Button {
         event: &PointerEvent,
     ) {
         match event {
-            PointerEvent::PointerDown(_, _) => {
+            PointerEvent::PointerDown(button, _) => {
                 if !ctx.is_disabled() {
                     ctx.capture_pointer();
                     // Changes in pointer capture impact appearance, but not accessibility node
                     ctx.request_paint_only();
                     trace!("Button {:?} pressed", ctx.widget_id());
+                    if let Some((initial_delay, _)) = self.auto_repeat {
+                        ctx.submit_action(Action::ButtonPressed(*button));
+                        self.repeat = Some((*button, initial_delay));
+                        ctx.request_anim_frame();
+                    }
                 }
             }
             PointerEvent::PointerUp(button, _) => {
-                if ctx.is_pointer_capture_target() && ctx.is_hovered() && !ctx.is_disabled() {
+                // Repeating buttons have already submitted their action when pressed.
+                if self.auto_repeat.is_none()
+                    && ctx.is_pointer_capture_target()
+                    && ctx.is_hovered()
+                    && !ctx.is_disabled()
+                {
                     ctx.submit_action(Action::ButtonPressed(*button));
                     trace!("Button {:?} released", ctx.widget_id());
                 }
+                self.repeat = None;
                 // Changes in pointer capture impact appearance, but not accessibility node
                 ctx.request_paint_only();
             }
@@ -115,10 +276,40 @@ impl Widget for Button {
 
     fn on_text_event(
         &mut self,
-        _ctx: &mut EventCtx,
+        ctx: &mut EventCtx,
         _props: &mut PropertiesMut<'_>,
-        _event: &TextEvent,
+        event: &TextEvent,
     ) {
+        let TextEvent::KeyboardKey(key_event, _) = event else {
+            return;
+        };
+        // Keys pressed in the content also reach this widget.
+        if key_event.logical_key != Key::Named(NamedKey::Space) || !ctx.is_focus_target() {
+            return;
+        }
+        if key_event.state.is_pressed() {
+            // The key repeating is ignored, as the button repeats at its own rate.
+            if !key_event.repeat && !ctx.is_disabled() {
+                self.key_held = true;
+                ctx.request_paint_only();
+                trace!("Button {:?} pressed", ctx.widget_id());
+                if let Some((initial_delay, _)) = self.auto_repeat {
+                    ctx.submit_action(Action::ButtonPressed(PointerButton::Primary));
+                    self.repeat = Some((PointerButton::Primary, initial_delay));
+                    ctx.request_anim_frame();
+                }
+            }
+        } else if self.key_held {
+            // Repeating buttons have already submitted their action when pressed.
+            if self.auto_repeat.is_none() && !ctx.is_disabled() {
+                ctx.submit_action(Action::ButtonPressed(PointerButton::Primary));
+                trace!("Button {:?} released", ctx.widget_id());
+            }
+            self.key_held = false;
+            self.repeat = None;
+            ctx.request_paint_only();
+        }
+        ctx.set_handled();
     }
 
     fn on_access_event(
@@ -127,7 +318,7 @@ impl Widget for Button {
         _props: &mut PropertiesMut<'_>,
         event: &AccessEvent,
     ) {
-        if ctx.target() == ctx.widget_id() {
+        if ctx.target() == ctx.widget_id() && !ctx.is_disabled() {
             match event.action {
                 accesskit::Action::Click => {
                     ctx.submit_action(Action::ButtonPressed(PointerButton::Primary));
@@ -137,9 +328,57 @@ impl Widget for Button {
         }
     }
 
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let (Some((button, mut remaining)), Some((_, repeat_interval))) =
+            (self.repeat, self.auto_repeat)
+        else {
+            return;
+        };
+        // The pointer leaving the button stops it repeating, even if it comes back.
+        let held = if self.key_held {
+            ctx.is_focus_target()
+        } else {
+            ctx.is_pointer_capture_target() && ctx.is_hovered()
+        };
+        if !held || ctx.is_disabled() {
+            self.repeat = None;
+            return;
+        }
+        // Long frames can be due more than one repeat.
+        let mut elapsed = Duration::from_nanos(interval);
+        while elapsed >= remaining {
+            ctx.submit_action(Action::ButtonPressed(button));
+            elapsed -= remaining;
+            remaining = repeat_interval;
+            if repeat_interval.is_zero() {
+                break;
+            }
+        }
+        self.repeat = Some((button, remaining.saturating_sub(elapsed)));
+        ctx.request_anim_frame();
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
     fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
         match event {
-            Update::HoveredChanged(_) | Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+            Update::FocusChanged(false) if self.key_held => {
+                // The Space key's release won't reach this button any more.
+                self.key_held = false;
+                self.repeat = None;
+                ctx.request_paint_only();
+            }
+            Update::HoveredChanged(_)
+            | Update::ChildHoveredChanged(_)
+            | Update::FocusChanged(_)
+            | Update::DisabledChanged(_) => {
                 ctx.request_paint_only();
             }
             _ => {}
@@ -147,7 +386,7 @@ impl Widget for Button {
     }
 
     fn register_children(&mut self, ctx: &mut crate::core::RegisterCtx) {
-        ctx.register_child(&mut self.label);
+        ctx.register_child(&mut self.child);
     }
 
     fn layout(
@@ -159,9 +398,9 @@ impl Widget for Button {
         let padding = Size::new(LABEL_INSETS.x_value(), LABEL_INSETS.y_value());
         let label_bc = bc.shrink(padding).loosen();
 
-        let label_size = ctx.run_layout(&mut self.label, &label_bc);
+        let label_size = ctx.run_layout(&mut self.child, &label_bc);
 
-        let baseline = ctx.child_baseline_offset(&self.label);
+        let baseline = ctx.child_baseline_offset(&self.child);
         ctx.set_baseline_offset(baseline + LABEL_INSETS.y1);
 
         // HACK: to make sure we look okay at default sizes when beside a textbox,
@@ -174,14 +413,15 @@ impl Widget for Button {
         ));
 
         let label_offset = (button_size.to_vec2() - label_size.to_vec2()) / 2.0;
-        ctx.place_child(&mut self.label, label_offset.to_point());
+        ctx.place_child(&mut self.child, label_offset.to_point());
 
         button_size
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
-        let is_active = ctx.is_pointer_capture_target() && !ctx.is_disabled();
-        let is_hovered = ctx.is_hovered();
+        let is_active = (ctx.is_pointer_capture_target() || self.key_held) && !ctx.is_disabled();
+        // The button is highlighted whilst its content is hovered, too.
+        let is_hovered = ctx.has_hovered();
         let size = ctx.size();
         let stroke_width = theme::BUTTON_BORDER_WIDTH;
 
@@ -190,28 +430,36 @@ impl Widget for Button {
             .inset(-stroke_width / 2.0)
             .to_rounded_rect(theme::BUTTON_BORDER_RADIUS);
 
+        let theme = ctx.theme();
         let bg_gradient = if ctx.is_disabled() {
-            [theme::DISABLED_BUTTON_LIGHT, theme::DISABLED_BUTTON_DARK]
+            [theme.disabled_button_light, theme.disabled_button_dark]
         } else if is_active {
-            [theme::BUTTON_DARK, theme::BUTTON_LIGHT]
+            [theme.button_dark, theme.button_light]
         } else {
-            [theme::BUTTON_LIGHT, theme::BUTTON_DARK]
+            [theme.button_light, theme.button_dark]
         };
 
-        let border_color = if is_hovered && !ctx.is_disabled() {
-            theme::BORDER_LIGHT
+        let border_color = if ctx.is_focus_target() {
+            theme.accent_color
+        } else if is_hovered && !ctx.is_disabled() {
+            theme.border_light
         } else {
-            theme::BORDER_DARK
+            theme.border_dark
         };
 
         stroke(scene, &rounded_rect, border_color, stroke_width);
-        fill_lin_gradient(
-            scene,
-            &rounded_rect,
-            bg_gradient,
-            UnitPoint::TOP,
-            UnitPoint::BOTTOM,
-        );
+        match &self.hover_brush {
+            Some(brush) if is_hovered && !is_active && !ctx.is_disabled() => {
+                scene.fill(Fill::NonZero, Affine::IDENTITY, brush, None, &rounded_rect);
+            }
+            _ => fill_lin_gradient(
+                scene,
+                &rounded_rect,
+                bg_gradient,
+                UnitPoint::TOP,
+                UnitPoint::BOTTOM,
+            ),
+        }
     }
 
     fn accessibility_role(&self) -> Role {
@@ -223,15 +471,17 @@ impl Widget for Button {
         // the child label already has a 'name' property.
         // This is more of a proof of concept of `get_raw_ref()`.
         if false {
-            let label = ctx.get_raw_ref(&self.label);
-            let name = label.widget().text().as_ref().to_string();
-            node.set_value(name);
+            let child = ctx.get_raw_ref(&self.child);
+            if let Some(label) = child.widget().as_any().downcast_ref::<Label>() {
+                let name = label.text().as_ref().to_string();
+                node.set_value(name);
+            }
         }
         node.add_action(accesskit::Action::Click);
     }
 
     fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
-        smallvec![self.label.id()]
+        smallvec![self.child.id()]
     }
 
     fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
@@ -243,6 +493,7 @@ impl Widget for Button {
 #[cfg(test)]
 mod tests {
     use insta::assert_debug_snapshot;
+    use vello::peniko::{Image as ImageBuf, ImageFormat};
 
     use super::*;
     use crate::assert_render_snapshot;
@@ -250,6 +501,15 @@ mod tests {
     use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
     use crate::theme::PRIMARY_LIGHT;
 
+    fn icon() -> Image {
+        Image::new(ImageBuf::new(
+            vec![255; 4 * 8 * 8].into(),
+            ImageFormat::Rgba8,
+            8,
+            8,
+        ))
+    }
+
     #[test]
     fn simple_button() {
         let [button_id] = widget_ids();
@@ -302,4 +562,214 @@ mod tests {
         // We don't use assert_eq because we don't want rich assert
         assert!(image_1 == image_2);
     }
+
+    #[test]
+    fn hover_brush() {
+        let hover = |harness: &mut TestHarness| {
+            let button_id = harness.root_widget().id();
+            harness.mouse_move_to(button_id);
+            assert!(harness.get_widget(button_id).ctx().is_hovered());
+        };
+        let image_1 = {
+            let button = Button::new("Hello").with_hover_brush(Brush::from(PRIMARY_LIGHT));
+            let mut harness = TestHarness::create_with_size(button, Size::new(50.0, 50.0));
+            hover(&mut harness);
+            harness.render()
+        };
+
+        let image_2 = {
+            let mut harness =
+                TestHarness::create_with_size(Button::new("Hello"), Size::new(50.0, 50.0));
+            hover(&mut harness);
+            harness.edit_root_widget(|mut button| {
+                let mut button = button.downcast::<Button>();
+                Button::set_hover_brush(&mut button, Brush::from(PRIMARY_LIGHT));
+            });
+            harness.render()
+        };
+
+        let image_3 = {
+            let mut harness =
+                TestHarness::create_with_size(Button::new("Hello"), Size::new(50.0, 50.0));
+            hover(&mut harness);
+            harness.render()
+        };
+
+        assert!(image_1 == image_2);
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel,
+        // so the images can't differ.
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            assert!(image_1 != image_3);
+        }
+    }
+
+    #[test]
+    fn button_with_child_content() {
+        let [icon_id] = widget_ids();
+        let content = Flex::row()
+            .with_child(icon().with_id(icon_id))
+            .with_child(Label::new("Save"));
+        let mut harness = TestHarness::create(Button::from_child(WidgetPod::new(content)));
+        let button_id = harness.root_widget().id();
+
+        // Hovering the content highlights the whole button.
+        harness.mouse_move_to(icon_id);
+        let button = harness.get_widget(button_id);
+        assert!(button.ctx().has_hovered());
+        assert!(!button.ctx().is_hovered());
+
+        harness.mouse_click_on(icon_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ButtonPressed(PointerButton::Primary), button_id))
+        );
+    }
+
+    /// The number of button presses submitted since the last call.
+    fn presses(harness: &mut TestHarness) -> usize {
+        std::iter::from_fn(|| harness.pop_action())
+            .filter(|(action, _)| matches!(action, Action::ButtonPressed(_)))
+            .count()
+    }
+
+    #[test]
+    fn auto_repeat_cadence() {
+        let button = Button::new("+")
+            .with_auto_repeat(Duration::from_millis(300), Duration::from_millis(100));
+        let mut harness = TestHarness::create(button);
+        let button_id = harness.root_widget().id();
+
+        harness.mouse_move_to(button_id);
+        harness.mouse_button_press(PointerButton::Primary);
+        assert_eq!(presses(&mut harness), 1, "The first press is immediate");
+        harness.animate_ms(299);
+        assert_eq!(presses(&mut harness), 0);
+        harness.animate_ms(1);
+        assert_eq!(presses(&mut harness), 1);
+        harness.animate_ms(50);
+        assert_eq!(presses(&mut harness), 0);
+        harness.animate_ms(50);
+        assert_eq!(presses(&mut harness), 1);
+        // A long frame catches up on the repeats it covered.
+        harness.animate_ms(250);
+        assert_eq!(presses(&mut harness), 2);
+
+        // Releasing stops the repeats, without pressing again.
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(presses(&mut harness), 0);
+        harness.animate_ms(1000);
+        assert_eq!(presses(&mut harness), 0);
+    }
+
+    #[test]
+    fn auto_repeat_stops() {
+        let [button_id, other_id] = widget_ids();
+        let button = Button::new("+")
+            .with_auto_repeat(Duration::from_millis(300), Duration::from_millis(100));
+        let widget = Flex::column()
+            .with_child_id(button, button_id)
+            .with_child_id(SizedBox::empty().width(50.).height(50.), other_id);
+        let mut harness = TestHarness::create(widget);
+
+        // Leaving the button stops it repeating, even if the pointer comes back.
+        harness.mouse_move_to(button_id);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.animate_ms(300);
+        assert_eq!(presses(&mut harness), 2);
+        harness.mouse_move_to(other_id);
+        harness.animate_ms(300);
+        harness.mouse_move_to(button_id);
+        harness.animate_ms(300);
+        assert_eq!(presses(&mut harness), 0);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(presses(&mut harness), 0);
+
+        // So does disabling it.
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.animate_ms(300);
+        assert_eq!(presses(&mut harness), 2);
+        harness.edit_widget(button_id, |mut button| button.ctx.set_disabled(true));
+        harness.animate_ms(300);
+        assert_eq!(presses(&mut harness), 0);
+    }
+
+    #[test]
+    fn space_key_presses() {
+        use winit::keyboard::{KeyCode, ModifiersState};
+
+        let [plain_id, repeating_id] = widget_ids();
+        let repeating = Button::new("+")
+            .with_auto_repeat(Duration::from_millis(300), Duration::from_millis(100));
+        let widget = Flex::column()
+            .with_child_id(Button::new("Hello"), plain_id)
+            .with_child_id(repeating, repeating_id);
+        let mut harness = TestHarness::create(widget);
+        let space = |harness: &mut TestHarness, pressed: bool| {
+            let mods = ModifiersState::empty();
+            harness.process_text_event(if pressed {
+                TextEvent::key_down(KeyCode::Space, NamedKey::Space, mods)
+            } else {
+                TextEvent::key_up(KeyCode::Space, NamedKey::Space, mods)
+            });
+        };
+
+        // Buttons without auto-repeat are pressed when Space is released.
+        harness.focus_on(Some(plain_id));
+        space(&mut harness, true);
+        assert_eq!(presses(&mut harness), 0);
+        space(&mut harness, false);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ButtonPressed(PointerButton::Primary), plain_id))
+        );
+
+        // Holding Space repeats like holding the pointer does.
+        harness.focus_on(Some(repeating_id));
+        space(&mut harness, true);
+        assert_eq!(presses(&mut harness), 1, "The first press is immediate");
+        harness.animate_ms(300);
+        assert_eq!(presses(&mut harness), 1);
+        harness.animate_ms(200);
+        assert_eq!(presses(&mut harness), 2);
+        space(&mut harness, false);
+        harness.animate_ms(1000);
+        assert_eq!(presses(&mut harness), 0);
+
+        // Losing focus stops the repeats.
+        space(&mut harness, true);
+        assert_eq!(presses(&mut harness), 1);
+        harness.focus_on(Some(plain_id));
+        harness.animate_ms(1000);
+        assert_eq!(presses(&mut harness), 0);
+        // The release then goes to the newly focused button, which wasn't pressed.
+        space(&mut harness, false);
+        assert_eq!(presses(&mut harness), 0);
+    }
+
+    #[test]
+    fn icon_position() {
+        /// The horizontal positions of the icon and the label of a button.
+        fn layout(position: IconPosition) -> (f64, f64) {
+            let button = Button::with_icon(icon(), "Save", position);
+            let harness = TestHarness::create(button);
+            let button = harness.root_widget();
+            let button_children = button.children();
+            let content = button_children[0].children();
+            let icon = content
+                .iter()
+                .find(|child| child.downcast::<SizedBox>().is_some())
+                .unwrap();
+            let label = content
+                .iter()
+                .find(|child| child.downcast::<Label>().is_some())
+                .unwrap();
+            assert_eq!(icon.ctx().size(), Size::new(ICON_SIZE, ICON_SIZE));
+            (icon.ctx().window_origin().x, label.ctx().window_origin().x)
+        }
+
+        let (icon_x, label_x) = layout(IconPosition::Leading);
+        assert!(icon_x < label_x, "A leading icon should be before the text");
+        let (icon_x, label_x) = layout(IconPosition::Trailing);
+        assert!(icon_x > label_x, "A trailing icon should be after the text");
+    }
 }
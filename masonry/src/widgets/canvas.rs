@@ -0,0 +1,350 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which paints using a closure.
+
+use accesskit::{Node, Role};
+use smallvec::SmallVec;
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, CanvasPointerEvent, EventCtx, LayoutCtx,
+    PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::kurbo::Size;
+
+type Painter = Box<dyn FnMut(&mut PaintCtx, &mut Scene, Size)>;
+
+/// A widget which paints using a closure, for simple custom drawings such as charts.
+///
+/// The closure is given the size of the canvas, and anything it paints outside
+/// of that is clipped.
+/// What it paints is reused until the canvas is resized, or [`repaint`](Self::repaint)
+/// or [`set_painter`](Self::set_painter) is called.
+///
+/// The canvas fills the space given to it by its parent, or takes its
+/// [preferred size](Self::with_preferred_size) if one is set.
+/// If [pointer events](Self::with_pointer_events) are enabled, the canvas submits an
+/// [`Action::CanvasPointer`] for each pointer event it receives.
+pub struct Canvas {
+    painter: Painter,
+    /// What `painter` last painted.
+    painted: Scene,
+    /// Whether `painted` is out of date, even if the size hasn't changed.
+    needs_repaint: bool,
+    preferred_size: Option<Size>,
+    /// Whether pointer events are reported as actions.
+    ///
+    /// The canvas always accepts pointer interaction, as that can't be changed after the
+    /// widget is created, so this is checked when each event is received instead.
+    pointer_events: bool,
+}
+
+// --- MARK: BUILDERS ---
+impl Canvas {
+    /// Create a canvas which paints using `painter`.
+    pub fn new(painter: impl FnMut(&mut PaintCtx, &mut Scene, Size) + 'static) -> Self {
+        Self {
+            painter: Box::new(painter),
+            painted: Scene::new(),
+            needs_repaint: true,
+            preferred_size: None,
+            pointer_events: false,
+        }
+    }
+
+    /// Builder-style method for setting the size the canvas would like to be.
+    ///
+    /// This is still constrained by the canvas's parent.
+    pub fn with_preferred_size(mut self, size: impl Into<Option<Size>>) -> Self {
+        self.preferred_size = size.into();
+        self
+    }
+
+    /// Builder-style method for setting whether the canvas reports pointer events as actions.
+    pub fn with_pointer_events(mut self, pointer_events: bool) -> Self {
+        self.pointer_events = pointer_events;
+        self
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl Canvas {
+    /// Set the closure used to paint the canvas, and repaint it.
+    pub fn set_painter(
+        this: &mut WidgetMut<'_, Self>,
+        painter: impl FnMut(&mut PaintCtx, &mut Scene, Size) + 'static,
+    ) {
+        this.widget.painter = Box::new(painter);
+        this.widget.needs_repaint = true;
+        this.ctx.request_paint_only();
+    }
+
+    /// Repaint the canvas, for when the state used by its painter has changed.
+    pub fn repaint(this: &mut WidgetMut<'_, Self>) {
+        this.widget.needs_repaint = true;
+        this.ctx.request_paint_only();
+    }
+
+    /// Set the size the canvas would like to be.
+    ///
+    /// See also [`with_preferred_size`](Self::with_preferred_size).
+    pub fn set_preferred_size(this: &mut WidgetMut<'_, Self>, size: impl Into<Option<Size>>) {
+        this.widget.preferred_size = size.into();
+        this.ctx.request_layout();
+    }
+
+    /// Set whether the canvas reports pointer events as actions.
+    ///
+    /// See also [`with_pointer_events`](Self::with_pointer_events).
+    pub fn set_pointer_events(this: &mut WidgetMut<'_, Self>, pointer_events: bool) {
+        this.widget.pointer_events = pointer_events;
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for Canvas {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if !self.pointer_events {
+            return;
+        }
+        if let PointerEvent::PointerDown(..) = event {
+            // So that drags which leave the canvas are still reported.
+            ctx.capture_pointer();
+        }
+        ctx.submit_action(Action::CanvasPointer(CanvasPointerEvent {
+            position: event.local_position(ctx),
+            event: event.clone(),
+        }));
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx) {}
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, _event: &Update) {}
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = match self.preferred_size {
+            Some(size) => bc.constrain(size),
+            // Fill the bounded axes, and take as little space as possible on the others.
+            None => {
+                let width = if bc.is_width_bounded() {
+                    bc.max().width
+                } else {
+                    bc.min().width
+                };
+                let height = if bc.is_height_bounded() {
+                    bc.max().height
+                } else {
+                    bc.min().height
+                };
+                Size::new(width, height)
+            }
+        };
+        ctx.set_clip_path(size.to_rect());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        // The canvas is also painted after it is laid out, even if its size hasn't changed,
+        // so the painter is only called when what it would paint may be different.
+        if self.needs_repaint || ctx.size_changed_since_last_paint() {
+            self.painted.reset();
+            let size = ctx.size();
+            (self.painter)(ctx, &mut self.painted, size);
+            self.needs_repaint = false;
+        }
+        scene.append(&self.painted, None);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Canvas
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        SmallVec::new()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Canvas", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use vello::kurbo::{Affine, Point, Vec2};
+    use vello::peniko::Fill;
+
+    use super::*;
+    use crate::core::PointerButton;
+    use crate::palette;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::Flex;
+
+    #[test]
+    fn paints_with_size() {
+        let painted = Arc::new(AtomicUsize::new(0));
+        let painted_clone = painted.clone();
+        let canvas = Canvas::new(move |_ctx, scene, size| {
+            assert_eq!(size, Size::new(40., 30.));
+            painted_clone.fetch_add(1, Ordering::Relaxed);
+            scene.fill(
+                Fill::NonZero,
+                Affine::IDENTITY,
+                palette::css::RED,
+                None,
+                &size.to_rect(),
+            );
+        })
+        .with_preferred_size(Size::new(40., 30.));
+
+        let mut harness = TestHarness::create(Flex::column().with_child(canvas));
+        let _ = harness.render();
+        assert_eq!(painted.load(Ordering::Relaxed), 1);
+
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            let mut canvas = Flex::child_mut(&mut flex, 0).unwrap();
+            Canvas::repaint(&mut canvas.downcast());
+        });
+        let _ = harness.render();
+        assert_eq!(painted.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn paints_again_when_resized() {
+        let [canvas_id] = widget_ids();
+        let painted = Arc::new(AtomicUsize::new(0));
+        let painted_clone = painted.clone();
+        let canvas = Canvas::new(move |_, _, _| {
+            painted_clone.fetch_add(1, Ordering::Relaxed);
+        })
+        .with_preferred_size(Size::new(40., 30.));
+
+        let mut harness = TestHarness::create(Flex::column().with_child_id(canvas, canvas_id));
+        let _ = harness.render();
+        assert_eq!(painted.load(Ordering::Relaxed), 1);
+
+        // Laying the canvas out again at the same size reuses what was painted.
+        harness.edit_widget(canvas_id, |mut canvas| {
+            Canvas::set_preferred_size(&mut canvas.downcast(), Size::new(40., 30.));
+        });
+        let _ = harness.render();
+        assert_eq!(painted.load(Ordering::Relaxed), 1);
+
+        harness.edit_widget(canvas_id, |mut canvas| {
+            Canvas::set_preferred_size(&mut canvas.downcast(), Size::new(50., 30.));
+        });
+        let _ = harness.render();
+        assert_eq!(painted.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn fills_constraints() {
+        let canvas = Canvas::new(|_, _, _| {});
+        let harness = TestHarness::create_with_size(canvas, Size::new(50., 60.));
+        assert_eq!(harness.root_widget().ctx().size(), Size::new(50., 60.));
+    }
+
+    #[test]
+    fn pointer_events() {
+        let [canvas_id] = widget_ids();
+        let canvas = Canvas::new(|_, _, _| {})
+            .with_preferred_size(Size::new(40., 30.))
+            .with_pointer_events(true);
+
+        let mut harness = TestHarness::create(Flex::column().with_child_id(canvas, canvas_id));
+        let canvas_origin = harness.get_widget(canvas_id).ctx().window_origin();
+        harness.mouse_move(canvas_origin + Vec2::new(10., 5.));
+        harness.mouse_button_press(PointerButton::Primary);
+
+        let mut positions = Vec::new();
+        while let Some((action, id)) = harness.pop_action() {
+            assert_eq!(id, canvas_id);
+            let Action::CanvasPointer(event) = action else {
+                panic!("Unexpected action {action:?}");
+            };
+            positions.push((event.event.short_name(), event.position));
+        }
+        assert_eq!(
+            positions.last(),
+            Some(&("PointerDown", Point::new(10., 5.)))
+        );
+        assert_eq!(harness.pointer_capture_target_id(), Some(canvas_id));
+
+        // Without pointer events, the canvas doesn't report anything.
+        harness.edit_widget(canvas_id, |mut canvas| {
+            Canvas::set_pointer_events(&mut canvas.downcast(), false);
+        });
+        harness.mouse_button_release(PointerButton::Primary);
+        harness.mouse_move(canvas_origin + Vec2::new(20., 10.));
+        assert!(harness.pop_action().is_none());
+    }
+
+    #[test]
+    fn enable_pointer_events_later() {
+        let [canvas_id] = widget_ids();
+        let canvas = Canvas::new(|_, _, _| {}).with_preferred_size(Size::new(40., 30.));
+
+        let mut harness = TestHarness::create(Flex::column().with_child_id(canvas, canvas_id));
+        let canvas_origin = harness.get_widget(canvas_id).ctx().window_origin();
+        harness.mouse_move(canvas_origin + Vec2::new(10., 5.));
+        assert!(harness.pop_action().is_none());
+
+        harness.edit_widget(canvas_id, |mut canvas| {
+            Canvas::set_pointer_events(&mut canvas.downcast(), true);
+        });
+        harness.mouse_move(canvas_origin + Vec2::new(20., 10.));
+        let Some((Action::CanvasPointer(event), id)) = harness.pop_action() else {
+            panic!("The canvas should report pointer events once they're enabled");
+        };
+        assert_eq!(id, canvas_id);
+        assert_eq!(event.event.short_name(), "PointerMove");
+        assert_eq!(event.position, Point::new(20., 10.));
+
+        // Actions for the same event compare equal.
+        assert_eq!(
+            Action::CanvasPointer(event.clone()),
+            Action::CanvasPointer(event)
+        );
+    }
+}
@@ -18,36 +18,95 @@ use crate::theme;
 use crate::util::{UnitPoint, fill_lin_gradient, stroke};
 use crate::widgets::Label;
 
+/// The state of a [`Checkbox`].
+///
+/// A `bool` can be used wherever a `CheckboxState` is expected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckboxState {
+    /// The checkbox is not checked.
+    #[default]
+    Unchecked,
+    /// The checkbox is checked.
+    Checked,
+    /// The checkbox is neither checked nor unchecked.
+    ///
+    /// This is commonly used for a checkbox which controls a group of other checkboxes,
+    /// when only some of the group are checked.
+    /// The user can't put a checkbox into this state; clicking it makes it checked.
+    Indeterminate,
+}
+
+impl CheckboxState {
+    /// Whether this is [`CheckboxState::Checked`].
+    pub fn is_checked(self) -> bool {
+        self == Self::Checked
+    }
+
+    /// The state after the checkbox is clicked.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Unchecked | Self::Indeterminate => Self::Checked,
+            Self::Checked => Self::Unchecked,
+        }
+    }
+}
+
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
+
 /// A checkbox that can be toggled.
 ///
+/// As well as being checked or unchecked, a checkbox can be shown as
+/// [indeterminate](CheckboxState::Indeterminate).
+///
 #[doc = crate::include_screenshot!("widget/screenshots/masonry__widget__checkbox__tests__hello_checked.png", "Checkbox with checked state.")]
 pub struct Checkbox {
-    checked: bool,
+    state: CheckboxState,
     label: WidgetPod<Label>,
 }
 
 impl Checkbox {
     /// Create a new `Checkbox` with a text label.
-    pub fn new(checked: bool, text: impl Into<ArcStr>) -> Self {
+    pub fn new(state: impl Into<CheckboxState>, text: impl Into<ArcStr>) -> Self {
         Self {
-            checked,
+            state: state.into(),
             label: WidgetPod::new(Label::new(text)),
         }
     }
 
     /// Create a new `Checkbox` with the given label.
-    pub fn from_label(checked: bool, label: Label) -> Self {
+    pub fn from_label(state: impl Into<CheckboxState>, label: Label) -> Self {
         Self {
-            checked,
+            state: state.into(),
             label: WidgetPod::new(label),
         }
     }
+
+    /// The current state of the checkbox.
+    pub fn state(&self) -> CheckboxState {
+        self.state
+    }
+
+    fn toggle(&mut self, ctx: &mut EventCtx) {
+        self.state = self.state.toggled();
+        ctx.submit_action(Action::CheckboxToggled(self.state));
+    }
 }
 
 // --- MARK: WIDGETMUT ---
 impl Checkbox {
-    pub fn set_checked(this: &mut WidgetMut<'_, Self>, checked: bool) {
-        this.widget.checked = checked;
+    /// Set the state of the checkbox.
+    ///
+    /// This accepts a `bool`, or a [`CheckboxState`] to make the checkbox indeterminate.
+    pub fn set_checked(this: &mut WidgetMut<'_, Self>, state: impl Into<CheckboxState>) {
+        this.widget.state = state.into();
         // Checked state impacts appearance and accessibility node
         this.ctx.request_render();
     }
@@ -83,8 +142,7 @@ impl Widget for Checkbox {
             }
             PointerEvent::PointerUp(_, _) => {
                 if ctx.is_pointer_capture_target() && ctx.is_hovered() && !ctx.is_disabled() {
-                    self.checked = !self.checked;
-                    ctx.submit_action(Action::CheckboxToggled(self.checked));
+                    self.toggle(ctx);
                     trace!("Checkbox {:?} released", ctx.widget_id());
                 }
                 // Checked state impacts appearance and accessibility node
@@ -108,11 +166,10 @@ impl Widget for Checkbox {
         _props: &mut PropertiesMut<'_>,
         event: &AccessEvent,
     ) {
-        if ctx.target() == ctx.widget_id() {
+        if ctx.target() == ctx.widget_id() && !ctx.is_disabled() {
             match event.action {
                 accesskit::Action::Click => {
-                    self.checked = !self.checked;
-                    ctx.submit_action(Action::CheckboxToggled(self.checked));
+                    self.toggle(ctx);
                     // Checked state impacts appearance and accessibility node
                     ctx.request_render();
                 }
@@ -183,12 +240,18 @@ impl Widget for Checkbox {
 
         stroke(scene, &rect, border_color, border_width);
 
-        if self.checked {
-            // Paint the checkmark
+        if self.state != CheckboxState::Unchecked {
             let mut path = BezPath::new();
-            path.move_to((4.0, 9.0));
-            path.line_to((8.0, 13.0));
-            path.line_to((14.0, 5.0));
+            if self.state == CheckboxState::Checked {
+                // Paint the checkmark
+                path.move_to((4.0, 9.0));
+                path.line_to((8.0, 13.0));
+                path.line_to((14.0, 5.0));
+            } else {
+                // Paint a dash for the indeterminate state
+                path.move_to((5.0, 9.0));
+                path.line_to((13.0, 9.0));
+            }
 
             let style = Stroke {
                 width: 2.0,
@@ -224,11 +287,11 @@ impl Widget for Checkbox {
             node.set_value(name);
         }
         node.add_action(accesskit::Action::Click);
-        if self.checked {
-            node.set_toggled(Toggled::True);
-        } else {
-            node.set_toggled(Toggled::False);
-        }
+        node.set_toggled(match self.state {
+            CheckboxState::Unchecked => Toggled::False,
+            CheckboxState::Checked => Toggled::True,
+            CheckboxState::Indeterminate => Toggled::Mixed,
+        });
     }
 
     fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
@@ -240,10 +303,10 @@ impl Widget for Checkbox {
     }
 
     fn get_debug_text(&self) -> Option<String> {
-        if self.checked {
-            Some("[X]".to_string())
-        } else {
-            Some("[ ]".to_string())
+        match self.state {
+            CheckboxState::Unchecked => Some("[ ]".to_string()),
+            CheckboxState::Checked => Some("[X]".to_string()),
+            CheckboxState::Indeterminate => Some("[-]".to_string()),
         }
     }
 }
@@ -274,7 +337,7 @@ mod tests {
         harness.mouse_click_on(checkbox_id);
         assert_eq!(
             harness.pop_action(),
-            Some((Action::CheckboxToggled(true), checkbox_id))
+            Some((Action::CheckboxToggled(CheckboxState::Checked), checkbox_id))
         );
 
         assert_debug_snapshot!(harness.root_widget());
@@ -283,7 +346,39 @@ mod tests {
         harness.mouse_click_on(checkbox_id);
         assert_eq!(
             harness.pop_action(),
-            Some((Action::CheckboxToggled(false), checkbox_id))
+            Some((
+                Action::CheckboxToggled(CheckboxState::Unchecked),
+                checkbox_id
+            ))
+        );
+    }
+
+    #[test]
+    fn indeterminate_checkbox() {
+        let [checkbox_id] = widget_ids();
+        let widget = Checkbox::new(CheckboxState::Indeterminate, "Select all").with_id(checkbox_id);
+
+        let mut harness = TestHarness::create(widget);
+        assert_eq!(
+            harness.get_widget(checkbox_id).get_debug_text().as_deref(),
+            Some("[-]")
+        );
+
+        // Clicking an indeterminate checkbox checks it
+        harness.mouse_click_on(checkbox_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::CheckboxToggled(CheckboxState::Checked), checkbox_id))
+        );
+
+        harness.edit_root_widget(|mut checkbox| {
+            let mut checkbox = checkbox.downcast::<Checkbox>();
+            Checkbox::set_checked(&mut checkbox, CheckboxState::Indeterminate);
+        });
+        let checkbox = harness.get_widget(checkbox_id);
+        assert_eq!(
+            checkbox.downcast::<Checkbox>().unwrap().state(),
+            CheckboxState::Indeterminate
         );
     }
 
@@ -0,0 +1,220 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which clips its child to a shape.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{BezPath, Ellipse, Point, RoundedRectRadii, Shape, Size};
+
+/// The shape a [`Clip`] clips its child to.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ClipShape {
+    /// The child's layout rectangle.
+    #[default]
+    Rect,
+    /// The child's layout rectangle, with its corners rounded by the given radii.
+    RoundedRect(RoundedRectRadii),
+    /// The largest ellipse which fits in the child's layout rectangle.
+    ///
+    /// For a square child, this is a circle.
+    Ellipse,
+    /// A custom path, in the child's coordinate space.
+    Path(BezPath),
+}
+
+impl ClipShape {
+    /// The child's layout rectangle, with its corners rounded by `radii`.
+    ///
+    /// This accepts a single radius for all corners, or the radii of each corner.
+    pub fn rounded(radii: impl Into<RoundedRectRadii>) -> Self {
+        Self::RoundedRect(radii.into())
+    }
+
+    /// The path of this shape for a child of the given size.
+    fn to_path(&self, size: Size) -> BezPath {
+        match self {
+            Self::Rect => size.to_rect().to_path(0.1),
+            Self::RoundedRect(radii) => size.to_rounded_rect(*radii).to_path(0.1),
+            Self::Ellipse => Ellipse::from_rect(size.to_rect()).to_path(0.1),
+            Self::Path(path) => path.clone(),
+        }
+    }
+}
+
+/// A widget which clips its child to a [shape](ClipShape), such as a circle for avatars
+/// or a rounded rectangle for cards.
+///
+/// Pointer events outside of the shape don't reach the child.
+pub struct Clip<W: ?Sized> {
+    child: WidgetPod<W>,
+    shape: ClipShape,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Clip<W> {
+    /// Create a new `Clip` which clips `child` to its layout rectangle.
+    ///
+    /// Use [`with_shape`](Self::with_shape) to clip to a different shape.
+    pub fn new(child: W) -> Self {
+        Self::from_pod(WidgetPod::new(child))
+    }
+}
+
+impl<W: Widget + ?Sized> Clip<W> {
+    /// Create a new `Clip` which clips the child in `child` to its layout rectangle.
+    pub fn from_pod(child: WidgetPod<W>) -> Self {
+        Self {
+            child,
+            shape: ClipShape::default(),
+        }
+    }
+
+    /// Builder-style method for setting the shape the child is clipped to.
+    pub fn with_shape(mut self, shape: ClipShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// The shape the child is clipped to.
+    pub fn shape(&self) -> &ClipShape {
+        &self.shape
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Clip<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the shape the child is clipped to.
+    ///
+    /// This only requires the widget to be painted again, not laid out.
+    pub fn set_shape(this: &mut WidgetMut<'_, Self>, shape: ClipShape) {
+        let path = shape.to_path(this.ctx.size());
+        this.widget.shape = shape;
+        this.ctx.set_clip_shape(&path);
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Clip<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, _event: &Update) {}
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        ctx.set_clip_shape(&self.shape.to_path(size));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Clip", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use vello::kurbo::Vec2;
+
+    use super::*;
+    use crate::core::PointerButton;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Canvas, Flex};
+
+    #[test]
+    fn ellipse_hit_testing() {
+        let [clip_id, canvas_id] = widget_ids();
+        let canvas = Canvas::new(|_, _, _| {})
+            .with_preferred_size(Size::new(40., 40.))
+            .with_pointer_events(true);
+        let clip = Clip::from_pod(WidgetPod::new_with_id(canvas, canvas_id))
+            .with_shape(ClipShape::Ellipse);
+        let widget = Flex::column().with_child_id(clip, clip_id);
+
+        let mut harness = TestHarness::create(widget);
+        let origin = harness.get_widget(clip_id).ctx().window_origin();
+
+        // The corner is outside the circle, so the canvas doesn't see it.
+        harness.mouse_move(origin + Vec2::new(2., 2.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert!(harness.pop_action().is_none());
+
+        harness.mouse_move(origin + Vec2::new(20., 20.));
+        let (_, id) = harness.pop_action().unwrap();
+        assert_eq!(id, canvas_id);
+
+        // Once the clip is a rectangle, the corner reaches the canvas.
+        harness.edit_widget(clip_id, |mut clip| {
+            Clip::<Canvas>::set_shape(&mut clip.downcast(), ClipShape::Rect);
+        });
+        while harness.pop_action().is_some() {}
+        harness.mouse_move(origin + Vec2::new(2., 2.));
+        let (_, id) = harness.pop_action().unwrap();
+        assert_eq!(id, canvas_id);
+    }
+}
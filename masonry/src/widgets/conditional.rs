@@ -0,0 +1,210 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which can hide its child without removing it.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+
+/// A widget which shows or hides its child.
+///
+/// Whilst hidden, the child is [stashed](crate::doc::doc_06_masonry_concepts#stashed), so it isn't
+/// painted, doesn't get events and isn't in the accessibility tree, and this widget takes
+/// up no space.
+/// Unlike removing the child, its state (such as a text area's selection) is kept for when
+/// it is shown again, and the position of this widget in its parent doesn't change.
+///
+/// Note that a hidden `Conditional` is still a child of its parent, so e.g. a [`Flex`](super::Flex)
+/// still adds spacing around it.
+pub struct Conditional<W: ?Sized> {
+    child: WidgetPod<W>,
+    shown: bool,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Conditional<W> {
+    /// Create a new `Conditional` which shows `child`.
+    pub fn new(child: W) -> Self {
+        Self::from_pod(WidgetPod::new(child))
+    }
+}
+
+impl<W: Widget + ?Sized> Conditional<W> {
+    /// Create a new `Conditional` which shows the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>) -> Self {
+        Self { child, shown: true }
+    }
+
+    /// Builder-style method for setting whether the child is shown.
+    pub fn with_shown(mut self, shown: bool) -> Self {
+        self.shown = shown;
+        self
+    }
+
+    /// Whether the child is shown.
+    pub fn is_shown(&self) -> bool {
+        self.shown
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Conditional<W> {
+    /// Get a mutable reference to the child.
+    ///
+    /// This can be used whilst the child is hidden.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set whether the child is shown.
+    pub fn set_shown(this: &mut WidgetMut<'_, Self>, shown: bool) {
+        if this.widget.shown == shown {
+            return;
+        }
+        this.widget.shown = shown;
+        this.ctx.set_stashed(&mut this.widget.child, !shown);
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Conditional<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            if !self.shown {
+                ctx.set_stashed(&mut self.child, true);
+            }
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        if !self.shown {
+            ctx.skip_layout(&mut self.child);
+            return bc.constrain(Size::ZERO);
+        }
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Conditional", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Flex, Label, TextArea};
+
+    #[test]
+    fn toggling_keeps_state() {
+        let [conditional_id, area_id] = widget_ids();
+        let widget = Flex::column()
+            .with_child_id(
+                Conditional::new(Label::new("Sometimes shown")).with_shown(false),
+                conditional_id,
+            )
+            .with_child_id(TextArea::new_editable("Sibling text"), area_id);
+
+        let mut harness = TestHarness::create(widget);
+        assert_eq!(harness.get_widget(conditional_id).ctx().size(), Size::ZERO);
+        assert!(
+            harness.get_widget(conditional_id).children()[0]
+                .ctx()
+                .is_stashed()
+        );
+
+        harness.edit_widget(area_id, |mut area| {
+            TextArea::select_byte_range(&mut area.downcast::<TextArea<true>>(), 3, 7);
+        });
+        let area_y = |harness: &TestHarness| harness.get_widget(area_id).ctx().window_origin().y;
+        let hidden_y = area_y(&harness);
+
+        let set_shown = |harness: &mut TestHarness, shown| {
+            harness.edit_widget(conditional_id, |mut conditional| {
+                Conditional::<Label>::set_shown(&mut conditional.downcast(), shown);
+            });
+        };
+        set_shown(&mut harness, true);
+        assert_ne!(harness.get_widget(conditional_id).ctx().size(), Size::ZERO);
+        assert!(
+            !harness.get_widget(conditional_id).children()[0]
+                .ctx()
+                .is_stashed()
+        );
+        assert!(area_y(&harness) > hidden_y);
+
+        set_shown(&mut harness, false);
+        assert_eq!(area_y(&harness), hidden_y);
+        // The sibling is the same widget, with the same selection.
+        let area = harness.get_widget(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.selection_range(), 3..7);
+    }
+}
@@ -0,0 +1,827 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which shows a context menu when its child is right-clicked.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{BezPath, Insets, Point, Rect, Size};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, ArcStr, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx,
+    PaintCtx, PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::theme;
+use crate::util::{fill_color, stroke};
+use crate::widgets::Label;
+
+/// The padding around each item in a menu.
+const ITEM_INSETS: Insets = Insets::uniform_xy(8., 3.);
+/// The width reserved for the arrow of items which open a submenu, to the right of the label.
+const SUBMENU_ARROW_WIDTH: f64 = 16.;
+/// The minimum width of a menu.
+const MIN_MENU_WIDTH: f64 = 120.;
+
+/// An item in a [`ContextMenu`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItem {
+    /// The text shown for this item.
+    pub label: ArcStr,
+    /// Whether this item can be chosen.
+    pub enabled: bool,
+    /// The items of the submenu opened by this item.
+    ///
+    /// If this is empty, choosing the item submits an action instead.
+    pub submenu: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    /// Create a new enabled item without a submenu.
+    pub fn new(label: impl Into<ArcStr>) -> Self {
+        Self {
+            label: label.into(),
+            enabled: true,
+            submenu: Vec::new(),
+        }
+    }
+
+    /// Builder-style method to set whether this item can be chosen.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Builder-style method to set the items of the submenu opened by this item.
+    pub fn with_submenu(mut self, submenu: impl IntoIterator<Item = MenuItem>) -> Self {
+        self.submenu = submenu.into_iter().collect();
+        self
+    }
+}
+
+/// A widget which shows a menu of [`MenuItem`]s when its child is clicked with the
+/// secondary pointer button (usually the right mouse button).
+///
+/// The menu can also be opened from the keyboard by pressing Shift+F10 or the Menu key whilst
+/// the child (or the context menu itself) is focused.
+///
+/// The menu and its submenus are shown in layers (see [`EventCtx::add_layer`]).
+/// Hovering an item which has a submenu opens it.
+/// Whilst the menu is open, the arrow keys move between items and submenus, Enter or Space
+/// chooses the highlighted item, and Escape closes the innermost open menu.
+/// The menu is also closed by clicking outside of it.
+///
+/// This widget emits [`Action::ContextMenuItemSelected`] when the user chooses an item.
+pub struct ContextMenu<W: ?Sized> {
+    child: WidgetPod<W>,
+    items: Vec<MenuItem>,
+    /// The open menus: the top-level menu, followed by any open submenus.
+    levels: Vec<MenuLevel>,
+    /// The widget which was focused before the menu was opened.
+    restore_focus: Option<WidgetId>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MenuLevel {
+    /// The id of the layer containing the menu.
+    layer: WidgetId,
+    highlighted: Option<usize>,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> ContextMenu<W> {
+    /// Create a new `ContextMenu` which shows `items` when `child` is right-clicked.
+    pub fn new(child: W, items: impl IntoIterator<Item = MenuItem>) -> Self {
+        Self::from_pod(WidgetPod::new(child), items)
+    }
+}
+
+impl<W: Widget + ?Sized> ContextMenu<W> {
+    /// Create a new `ContextMenu` which shows `items` when the child in `child` is right-clicked.
+    pub fn from_pod(child: WidgetPod<W>, items: impl IntoIterator<Item = MenuItem>) -> Self {
+        Self {
+            child,
+            items: items.into_iter().collect(),
+            levels: Vec::new(),
+            restore_focus: None,
+        }
+    }
+
+    /// The items shown in the menu.
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    /// Whether the menu is currently open.
+    pub fn is_open(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    /// The items of the menu at `depth`, where 0 is the top-level menu.
+    fn items_at(&self, depth: usize) -> &[MenuItem] {
+        let mut items = &self.items[..];
+        for level in &self.levels[..depth] {
+            match level.highlighted.and_then(|index| items.get(index)) {
+                Some(item) => items = &item.submenu,
+                None => return &[],
+            }
+        }
+        items
+    }
+
+    /// Forget the menus from `depth` onwards, returning the ids of their layers so that they
+    /// can be removed.
+    fn take_layers(&mut self, depth: usize) -> Vec<WidgetId> {
+        let depth = depth.min(self.levels.len());
+        self.levels
+            .drain(depth..)
+            .map(|level| level.layer)
+            .collect()
+    }
+}
+
+/// The index of the first enabled item in `items` after `from` in the given direction,
+/// or the first (or last) enabled item if `from` is `None`.
+fn next_enabled(items: &[MenuItem], from: Option<usize>, forwards: bool) -> Option<usize> {
+    let enabled = |&index: &usize| items[index].enabled;
+    match (from, forwards) {
+        (None, true) => (0..items.len()).find(enabled),
+        (None, false) => (0..items.len()).rev().find(enabled),
+        (Some(from), true) => (from + 1..items.len()).find(enabled),
+        (Some(from), false) => (0..from).rev().find(enabled),
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> ContextMenu<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the items shown in the menu.
+    ///
+    /// This closes the menu if it is open.
+    pub fn set_items(this: &mut WidgetMut<'_, Self>, items: impl IntoIterator<Item = MenuItem>) {
+        Self::close_menu(this);
+        this.widget.items = items.into_iter().collect();
+    }
+
+    /// Close all open menus, and restore focus to the widget which had it before.
+    fn close_menu(this: &mut WidgetMut<'_, Self>) {
+        for layer in this.widget.take_layers(0) {
+            this.ctx.remove_layer(layer);
+        }
+        if let Some(id) = this.widget.restore_focus.take() {
+            this.ctx.set_focus(id);
+        }
+        this.ctx.request_render();
+    }
+
+    /// Highlight the item at `index` in the menu at `depth`, closing any submenus of
+    /// the previously highlighted item.
+    fn set_highlighted(this: &mut WidgetMut<'_, Self>, depth: usize, index: Option<usize>) {
+        let Some(level) = this.widget.levels.get_mut(depth) else {
+            return;
+        };
+        if level.highlighted == index {
+            return;
+        }
+        level.highlighted = index;
+        let layer = level.layer;
+        this.ctx.mutate_widget_later(layer, move |mut list| {
+            MenuList::set_highlighted(&mut list.downcast(), index);
+        });
+        for layer in this.widget.take_layers(depth + 1) {
+            this.ctx.remove_layer(layer);
+        }
+    }
+
+    /// Handle the pointer hovering or the user activating an item in the menu at `depth`.
+    fn on_list_event(this: &mut WidgetMut<'_, Self>, event: ListEvent) {
+        let ListEvent {
+            depth,
+            index,
+            submenu_origin,
+            activate,
+        } = event;
+        if depth >= this.widget.levels.len() {
+            // The menu was closed since the event was sent.
+            return;
+        }
+        let Some(item) = this.widget.items_at(depth).get(index) else {
+            return;
+        };
+        let enabled = item.enabled;
+        let submenu_first = next_enabled(&item.submenu, None, true);
+        let has_submenu = !item.submenu.is_empty();
+
+        Self::set_highlighted(this, depth, Some(index));
+        if !enabled {
+            return;
+        }
+        if has_submenu {
+            if this.widget.levels.len() == depth + 1 {
+                let highlighted = if activate { submenu_first } else { None };
+                let list = MenuList::new::<W>(
+                    this.ctx.widget_id(),
+                    depth + 1,
+                    this.widget.items_at(depth + 1),
+                    highlighted,
+                );
+                let layer = this.ctx.add_layer(list, submenu_origin);
+                this.widget.levels.push(MenuLevel { layer, highlighted });
+            } else if activate {
+                Self::set_highlighted(this, depth + 1, submenu_first);
+            }
+        } else if activate {
+            let path = this.widget.levels[..depth]
+                .iter()
+                .filter_map(|level| level.highlighted)
+                .chain(std::iter::once(index))
+                .collect();
+            Self::close_menu(this);
+            this.ctx
+                .submit_action(Action::ContextMenuItemSelected(path));
+        }
+    }
+
+    /// Close the innermost open menu, or all menus if only the top-level menu is open.
+    fn close_innermost(this: &mut WidgetMut<'_, Self>) {
+        match this.widget.levels.len() {
+            0 => {}
+            1 => Self::close_menu(this),
+            len => {
+                for layer in this.widget.take_layers(len - 1) {
+                    this.ctx.remove_layer(layer);
+                }
+            }
+        }
+    }
+}
+
+impl<W: Widget + FromDynWidget + ?Sized> ContextMenu<W> {
+    /// Open the top-level menu with its top-left corner at `position`, in window coordinates.
+    fn open(&mut self, ctx: &mut EventCtx, position: Point, highlight_first: bool) {
+        for layer in self.take_layers(0) {
+            ctx.remove_layer(layer);
+        }
+        if self.items.is_empty() {
+            return;
+        }
+        let highlighted = if highlight_first {
+            next_enabled(&self.items, None, true)
+        } else {
+            None
+        };
+        let list = MenuList::new::<W>(ctx.widget_id(), 0, &self.items, highlighted);
+        let layer = ctx.add_layer(list, position);
+        self.levels.push(MenuLevel { layer, highlighted });
+        // The menu is navigated using the keyboard through this widget.
+        if self.restore_focus.is_none() {
+            self.restore_focus = ctx.next_focused_widget();
+        }
+        ctx.request_focus();
+        ctx.request_render();
+    }
+
+    /// Close all open menus, and restore focus to the widget which had it before.
+    fn close(&mut self, ctx: &mut EventCtx) {
+        for layer in self.take_layers(0) {
+            ctx.remove_layer(layer);
+        }
+        if let Some(id) = self.restore_focus.take() {
+            ctx.set_focus(id);
+        }
+        ctx.request_render();
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for ContextMenu<W> {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::PointerDown(button, state) = event {
+            if self.is_open() {
+                self.close(ctx);
+            }
+            if *button == PointerButton::Secondary && !ctx.is_disabled() {
+                self.open(ctx, Point::new(state.position.x, state.position.y), false);
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::KeyboardKey(key_event, modifiers) = event else {
+            return;
+        };
+        if !key_event.state.is_pressed() || ctx.is_disabled() {
+            return;
+        }
+        let opens_menu = match &key_event.logical_key {
+            Key::Named(NamedKey::ContextMenu) => true,
+            Key::Named(NamedKey::F10) => modifiers.shift_key(),
+            _ => false,
+        };
+        let Some(level) = self.levels.last().copied() else {
+            if opens_menu {
+                let position = ctx.window_origin() + (0., ctx.size().height);
+                self.open(ctx, position, true);
+                ctx.set_handled();
+            }
+            return;
+        };
+
+        let depth = self.levels.len() - 1;
+        let items = self.items_at(depth);
+        let highlighted_item = level.highlighted.and_then(|index| items.get(index));
+        match &key_event.logical_key {
+            Key::Named(NamedKey::ArrowDown | NamedKey::ArrowUp) => {
+                let forwards = key_event.logical_key == Key::Named(NamedKey::ArrowDown);
+                let Some(index) = next_enabled(items, level.highlighted, forwards) else {
+                    ctx.set_handled();
+                    return;
+                };
+                ctx.mutate_self_later(move |mut this| {
+                    Self::set_highlighted(&mut this.downcast(), depth, Some(index));
+                });
+            }
+            Key::Named(NamedKey::ArrowRight | NamedKey::Enter | NamedKey::Space) => {
+                let Some((index, item)) = level.highlighted.zip(highlighted_item) else {
+                    ctx.set_handled();
+                    return;
+                };
+                let is_right = key_event.logical_key == Key::Named(NamedKey::ArrowRight);
+                if item.enabled && (!is_right || !item.submenu.is_empty()) {
+                    // The menu knows where its items are, so it reports where to put the submenu.
+                    ctx.mutate_widget_later(level.layer, move |mut list| {
+                        MenuList::activate(&mut list.downcast(), index);
+                    });
+                }
+            }
+            Key::Named(NamedKey::ArrowLeft) if depth > 0 => {
+                ctx.mutate_self_later(|mut this| {
+                    Self::close_innermost(&mut this.downcast());
+                });
+            }
+            Key::Named(NamedKey::Escape) => {
+                ctx.mutate_self_later(|mut this| {
+                    Self::close_innermost(&mut this.downcast());
+                });
+            }
+            _ if opens_menu => {}
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            // Focus moved elsewhere, e.g. because the user clicked outside of the menu.
+            Update::FocusChanged(false)
+            | Update::StashedChanged(true)
+            | Update::DisabledChanged(true) => {
+                for layer in self.take_layers(0) {
+                    ctx.remove_layer(layer);
+                }
+                self.restore_focus = None;
+                ctx.request_render();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        if self.is_open() {
+            node.set_owns(
+                self.levels
+                    .iter()
+                    .map(|level| level.layer.into())
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("ContextMenu", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: MENU LIST ---
+/// An event sent by a [`MenuList`] to the [`ContextMenu`] which opened it.
+#[derive(Clone, Copy, Debug)]
+struct ListEvent {
+    /// The depth of the menu, where 0 is the top-level menu.
+    depth: usize,
+    /// The index of the item in the menu.
+    index: usize,
+    /// Where the submenu of the item should be shown, in window coordinates.
+    submenu_origin: Point,
+    /// Whether the item was activated (e.g. clicked), rather than just hovered.
+    activate: bool,
+}
+
+/// A menu shown in a layer whilst a [`ContextMenu`] is open.
+struct MenuList {
+    /// The context menu which opened this menu.
+    owner: WidgetId,
+    /// Forwards events to the owner, whose type depends on its child.
+    on_event: fn(WidgetMut<'_, dyn Widget>, ListEvent),
+    depth: usize,
+    items: Vec<WidgetPod<Label>>,
+    has_submenu: Vec<bool>,
+    highlighted: Option<usize>,
+    /// The height of each item, computed in layout.
+    item_height: f64,
+}
+
+impl MenuList {
+    fn new<W: Widget + FromDynWidget + ?Sized>(
+        owner: WidgetId,
+        depth: usize,
+        items: &[MenuItem],
+        highlighted: Option<usize>,
+    ) -> Self {
+        Self {
+            owner,
+            on_event: |mut owner, event| {
+                ContextMenu::<W>::on_list_event(&mut owner.downcast(), event);
+            },
+            depth,
+            items: items
+                .iter()
+                .map(|item| {
+                    let brush = if item.enabled {
+                        theme::TEXT_COLOR
+                    } else {
+                        theme::DISABLED_TEXT_COLOR
+                    };
+                    WidgetPod::new(Label::new(item.label.clone()).with_brush(brush))
+                })
+                .collect(),
+            has_submenu: items.iter().map(|item| !item.submenu.is_empty()).collect(),
+            highlighted,
+            item_height: 0.,
+        }
+    }
+
+    fn index_at(&self, pos: Point) -> Option<usize> {
+        if pos.y < 0. || self.item_height <= 0. {
+            return None;
+        }
+        let index = (pos.y / self.item_height) as usize;
+        (index < self.items.len()).then_some(index)
+    }
+
+    /// Where the submenu of the item at `index` should be shown, given this menu's window
+    /// origin and size.
+    fn submenu_origin(&self, origin: Point, size: Size, index: usize) -> Point {
+        origin + (size.width, index as f64 * self.item_height)
+    }
+
+    fn send(&self, ctx: &mut EventCtx, index: usize, activate: bool) {
+        let event = ListEvent {
+            depth: self.depth,
+            index,
+            submenu_origin: self.submenu_origin(ctx.window_origin(), ctx.size(), index),
+            activate,
+        };
+        let on_event = self.on_event;
+        ctx.mutate_widget_later(self.owner, move |owner| on_event(owner, event));
+    }
+
+    fn set_highlighted(this: &mut WidgetMut<'_, Self>, highlighted: Option<usize>) {
+        this.widget.highlighted = highlighted;
+        this.ctx.request_paint_only();
+    }
+
+    /// Activate the item at `index` on behalf of the user.
+    fn activate(this: &mut WidgetMut<'_, Self>, index: usize) {
+        let origin = this.ctx.window_origin();
+        let size = this.ctx.size();
+        let event = ListEvent {
+            depth: this.widget.depth,
+            index,
+            submenu_origin: this.widget.submenu_origin(origin, size, index),
+            activate: true,
+        };
+        let on_event = this.widget.on_event;
+        this.ctx
+            .mutate_widget_later(this.widget.owner, move |owner| on_event(owner, event));
+    }
+}
+
+impl Widget for MenuList {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::PointerMove(_) => {
+                if let Some(index) = self.index_at(event.local_position(ctx)) {
+                    if Some(index) != self.highlighted {
+                        self.send(ctx, index, false);
+                    }
+                }
+            }
+            PointerEvent::PointerDown(_, _) => {
+                // Clicking outside of the focused context menu would otherwise take focus from
+                // it, which closes the menu.
+                ctx.set_focus(self.owner);
+                ctx.capture_pointer();
+            }
+            PointerEvent::PointerUp(_, _) => {
+                if ctx.is_pointer_capture_target() && ctx.is_hovered() {
+                    if let Some(index) = self.index_at(event.local_position(ctx)) {
+                        self.send(ctx, index, true);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        for item in &mut self.items {
+            ctx.register_child(item);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let arrow_width = if self.has_submenu.contains(&true) {
+            SUBMENU_ARROW_WIDTH
+        } else {
+            0.
+        };
+        let padding = Size::new(ITEM_INSETS.x_value() + arrow_width, ITEM_INSETS.y_value());
+        let item_bc = bc.shrink(padding).loosen();
+        let mut width = MIN_MENU_WIDTH;
+        let mut label_height: f64 = 0.;
+        for item in &mut self.items {
+            let size = ctx.run_layout(item, &item_bc);
+            width = width.max(size.width + padding.width);
+            label_height = label_height.max(size.height);
+        }
+        self.item_height = label_height + padding.height;
+        for (index, item) in self.items.iter_mut().enumerate() {
+            let origin = Point::new(
+                ITEM_INSETS.x0,
+                index as f64 * self.item_height + ITEM_INSETS.y0,
+            );
+            ctx.place_child(item, origin);
+        }
+        bc.constrain(Size::new(width, self.items.len() as f64 * self.item_height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.size();
+        let border_width = 1.;
+        let rect = size
+            .to_rect()
+            .inset(-border_width / 2.)
+            .to_rounded_rect(theme::BUTTON_BORDER_RADIUS);
+        fill_color(scene, &rect, theme::BACKGROUND_DARK);
+
+        if let Some(highlighted) = self.highlighted {
+            let highlight = Rect::new(
+                0.,
+                highlighted as f64 * self.item_height,
+                size.width,
+                (highlighted + 1) as f64 * self.item_height,
+            );
+            fill_color(scene, &highlight, theme::PRIMARY_DARK);
+        }
+
+        // Paint an arrow pointing right for each item which opens a submenu.
+        for (index, _) in self.has_submenu.iter().enumerate().filter(|(_, has)| **has) {
+            let center = Point::new(
+                size.width - ITEM_INSETS.x1 - SUBMENU_ARROW_WIDTH / 2.,
+                (index as f64 + 0.5) * self.item_height,
+            );
+            let mut arrow = BezPath::new();
+            arrow.move_to((center.x - 2., center.y - 4.));
+            arrow.line_to((center.x + 2., center.y));
+            arrow.line_to((center.x - 2., center.y + 4.));
+            stroke(scene, &arrow, theme::TEXT_COLOR, 1.5);
+        }
+        stroke(scene, &rect, theme::BORDER_LIGHT, border_width);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Menu
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        self.items.iter().map(|item| item.id()).collect()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("MenuList", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::Flex;
+
+    fn edit_menu(label_id: WidgetId) -> ContextMenu<Label> {
+        ContextMenu::from_pod(
+            WidgetPod::new_with_id(Label::new("Right-click me"), label_id),
+            [
+                MenuItem::new("Cut").with_enabled(false),
+                MenuItem::new("Copy"),
+                MenuItem::new("Paste as")
+                    .with_submenu([MenuItem::new("Plain text"), MenuItem::new("Rich text")]),
+            ],
+        )
+    }
+
+    fn levels(harness: &TestHarness, id: WidgetId) -> Vec<MenuLevel> {
+        let menu = harness.get_widget(id);
+        menu.downcast::<ContextMenu<Label>>()
+            .unwrap()
+            .levels
+            .clone()
+    }
+
+    /// The center of the item at `index` in the menu shown in `layer`.
+    fn item_center(harness: &TestHarness, layer: WidgetId, index: usize) -> Point {
+        let list = harness.get_widget(layer);
+        let item_height = list.downcast::<MenuList>().unwrap().item_height;
+        let rect = list.ctx().bounding_rect();
+        Point::new(rect.x0 + 20., rect.y0 + item_height * (index as f64 + 0.5))
+    }
+
+    fn click_at(harness: &mut TestHarness, pos: Point, button: PointerButton) {
+        harness.mouse_move(pos);
+        harness.mouse_button_press(button);
+        harness.mouse_button_release(button);
+    }
+
+    #[test]
+    fn right_click_opens_and_chooses() {
+        let [menu_id, label_id] = widget_ids();
+        let widget = Flex::column().with_child_id(edit_menu(label_id), menu_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(400., 400.));
+
+        let label_center = harness.get_widget(label_id).ctx().bounding_rect().center();
+        click_at(&mut harness, label_center, PointerButton::Secondary);
+        let levels = levels(&harness, menu_id);
+        assert_eq!(levels.len(), 1);
+        let list_origin = harness.get_widget(levels[0].layer).ctx().window_origin();
+        assert_eq!(list_origin, label_center);
+
+        // Disabled items can't be chosen.
+        let cut = item_center(&harness, levels[0].layer, 0);
+        click_at(&mut harness, cut, PointerButton::Primary);
+        assert_eq!(harness.pop_action(), None);
+
+        let copy = item_center(&harness, levels[0].layer, 1);
+        click_at(&mut harness, copy, PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ContextMenuItemSelected(vec![1]), menu_id))
+        );
+        assert!(harness.try_get_widget(levels[0].layer).is_none());
+        assert!(
+            !harness
+                .get_widget(menu_id)
+                .downcast::<ContextMenu<Label>>()
+                .unwrap()
+                .is_open()
+        );
+    }
+
+    #[test]
+    fn hovering_opens_submenu() {
+        let [menu_id, label_id] = widget_ids();
+        let widget = Flex::column().with_child_id(edit_menu(label_id), menu_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(400., 400.));
+
+        let label_center = harness.get_widget(label_id).ctx().bounding_rect().center();
+        click_at(&mut harness, label_center, PointerButton::Secondary);
+        let top_layer = levels(&harness, menu_id)[0].layer;
+
+        let paste_as = item_center(&harness, top_layer, 2);
+        harness.mouse_move(paste_as);
+        let levels = levels(&harness, menu_id);
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels[0].highlighted, Some(2));
+        let top_rect = harness.get_widget(top_layer).ctx().bounding_rect();
+        let submenu_origin = harness.get_widget(levels[1].layer).ctx().window_origin();
+        assert_eq!(submenu_origin.x, top_rect.x1);
+
+        let rich_text = item_center(&harness, levels[1].layer, 1);
+        click_at(&mut harness, rich_text, PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ContextMenuItemSelected(vec![2, 1]), menu_id))
+        );
+        assert!(harness.try_get_widget(levels[1].layer).is_none());
+    }
+
+    #[test]
+    fn click_outside_closes() {
+        let [menu_id, label_id] = widget_ids();
+        let widget = Flex::column().with_child_id(edit_menu(label_id), menu_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(400., 400.));
+
+        let label_center = harness.get_widget(label_id).ctx().bounding_rect().center();
+        click_at(&mut harness, label_center, PointerButton::Secondary);
+        assert_eq!(levels(&harness, menu_id).len(), 1);
+
+        click_at(&mut harness, Point::new(390., 390.), PointerButton::Primary);
+        assert!(levels(&harness, menu_id).is_empty());
+        assert_eq!(harness.pop_action(), None);
+    }
+
+    #[test]
+    fn keyboard_navigation_skips_disabled_items() {
+        let items = edit_menu(WidgetId::next()).items;
+        assert_eq!(next_enabled(&items, None, true), Some(1));
+        assert_eq!(next_enabled(&items, Some(1), true), Some(2));
+        assert_eq!(next_enabled(&items, Some(2), true), None);
+        assert_eq!(next_enabled(&items, Some(1), false), None);
+        assert_eq!(next_enabled(&items, None, false), Some(2));
+    }
+}
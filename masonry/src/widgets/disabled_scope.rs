@@ -0,0 +1,199 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which can disable its child.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+
+/// A widget which disables its child, and all of the child's descendants, whilst `disabled` is set.
+///
+/// Disabled widgets are painted dimmed, can't be focused, and ignore clicks and key presses,
+/// but are still hovered, so that e.g. a tooltip can explain why they are disabled.
+/// See [`EventCtx::is_disabled`] for more details.
+pub struct DisabledScope<W: ?Sized> {
+    child: WidgetPod<W>,
+    disabled: bool,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> DisabledScope<W> {
+    /// Create a new `DisabledScope` around `child`.
+    pub fn new(child: W, disabled: bool) -> Self {
+        Self::from_pod(WidgetPod::new(child), disabled)
+    }
+}
+
+impl<W: Widget + ?Sized> DisabledScope<W> {
+    /// Create a new `DisabledScope` around the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>, disabled: bool) -> Self {
+        Self { child, disabled }
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> DisabledScope<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set whether the child is disabled.
+    ///
+    /// The child is still disabled if an ancestor of this widget is disabled.
+    pub fn set_disabled(this: &mut WidgetMut<'_, Self>, disabled: bool) {
+        this.widget.disabled = disabled;
+        this.ctx.set_disabled(disabled);
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for DisabledScope<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        // Disabling this widget disables all of its descendants.
+        if let Update::WidgetAdded = event {
+            ctx.set_disabled(self.disabled);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("DisabledScope", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use cursor_icon::CursorIcon;
+
+    use super::*;
+    use crate::core::{Action, PointerButton};
+    use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+    use crate::widgets::{Button, Checkbox, CheckboxState, SizedBox};
+
+    #[test]
+    fn disabled_child_ignores_input() {
+        let [button_id] = widget_ids();
+        let widget = DisabledScope::new(Button::new("Save").with_id(button_id), true);
+        let mut harness = TestHarness::create(widget);
+        assert!(harness.get_widget(button_id).ctx().is_disabled());
+
+        harness.mouse_click_on(button_id);
+        harness.process_access_event(button_id, accesskit::Action::Click, None);
+        assert_eq!(harness.pop_action(), None);
+
+        // Disabled widgets are still hovered, so they can have tooltips.
+        harness.mouse_move_to(button_id);
+        assert!(harness.get_widget(button_id).ctx().is_hovered());
+        assert_eq!(harness.cursor_icon(), CursorIcon::NotAllowed);
+    }
+
+    #[test]
+    fn reenable_child() {
+        let [checkbox_id] = widget_ids();
+        let widget = DisabledScope::new(
+            Checkbox::new(false, "Remember me").with_id(checkbox_id),
+            true,
+        );
+        let mut harness = TestHarness::create(widget);
+        let disabled = harness.render();
+
+        harness.edit_root_widget(|mut scope| {
+            let mut scope = scope.downcast::<DisabledScope<SizedBox>>();
+            DisabledScope::set_disabled(&mut scope, false);
+        });
+        assert!(!harness.get_widget(checkbox_id).ctx().is_disabled());
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            assert!(
+                disabled != harness.render(),
+                "Checkbox should no longer be dimmed"
+            );
+        }
+
+        harness.mouse_click_on(checkbox_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::CheckboxToggled(CheckboxState::Checked), checkbox_id))
+        );
+        harness.mouse_move_to(checkbox_id);
+        assert_ne!(harness.cursor_icon(), CursorIcon::NotAllowed);
+
+        // Disabling a widget which was pressed doesn't send a click.
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.edit_root_widget(|mut scope| {
+            let mut scope = scope.downcast::<DisabledScope<SizedBox>>();
+            DisabledScope::set_disabled(&mut scope, true);
+        });
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action(), None);
+    }
+}
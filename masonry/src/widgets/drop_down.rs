@@ -0,0 +1,677 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A drop down list for choosing one of several options.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{BezPath, Insets, Point, Rect, Size};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, ArcStr, BoxConstraints, DropDownAction, EventCtx, LayoutCtx,
+    PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::theme;
+use crate::util::{UnitPoint, fill_color, fill_lin_gradient, stroke};
+use crate::widgets::Label;
+
+const LABEL_INSETS: Insets = Insets::uniform_xy(8., 2.);
+/// The width reserved for the chevron, to the right of the label.
+const CHEVRON_WIDTH: f64 = 16.;
+/// The padding around each option in the popup list.
+const ITEM_INSETS: Insets = Insets::uniform_xy(8., 3.);
+
+/// A control showing the selected one of several options, which opens a list of all the
+/// options when clicked.
+///
+/// The list is shown in a layer (see [`EventCtx::add_layer`]) below the drop down.
+/// It can also be opened by pressing Space or Enter whilst the drop down is focused.
+/// Whilst the list is open, the arrow keys move the highlighted option, typing a letter
+/// highlights the next option starting with that letter, Escape closes the list, and
+/// Enter or clicking an option selects it.
+///
+/// This widget emits [`DropDownAction::Selected`] when the user selects a different option.
+pub struct DropDown {
+    options: Vec<ArcStr>,
+    /// The index of the selected option.
+    ///
+    /// If this is out of range, no option is shown.
+    selected: usize,
+    label: WidgetPod<Label>,
+    /// The open popup list, if any.
+    ///
+    /// Its layer is removed along with the drop down, if the drop down is removed whilst open.
+    popup: Option<Popup>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Popup {
+    /// The id of the layer containing the list.
+    layer: WidgetId,
+    highlighted: usize,
+}
+
+// --- MARK: BUILDERS ---
+impl DropDown {
+    /// Create a new `DropDown` with the given options, of which the one at `selected` is selected.
+    pub fn new(options: impl IntoIterator<Item = impl Into<ArcStr>>, selected: usize) -> Self {
+        let options: Vec<ArcStr> = options.into_iter().map(Into::into).collect();
+        let text = options.get(selected).cloned().unwrap_or_default();
+        Self {
+            options,
+            selected,
+            label: WidgetPod::new(Label::new(text)),
+            popup: None,
+        }
+    }
+
+    /// The options which can be chosen.
+    pub fn options(&self) -> &[ArcStr] {
+        &self.options
+    }
+
+    /// The index of the selected option.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Whether the list of options is currently open.
+    pub fn is_open(&self) -> bool {
+        self.popup.is_some()
+    }
+
+    fn open(&mut self, ctx: &mut EventCtx) {
+        if self.popup.is_some() || self.options.is_empty() {
+            return;
+        }
+        let highlighted = self.selected.min(self.options.len() - 1);
+        let list = DropDownList::new(
+            ctx.widget_id(),
+            &self.options,
+            highlighted,
+            ctx.size().width,
+        );
+        let position = ctx.window_origin() + (0., ctx.size().height);
+        let layer = ctx.add_layer(list, position);
+        self.popup = Some(Popup { layer, highlighted });
+        ctx.request_render();
+    }
+
+    /// Forget the popup list, returning the id of its layer so that it can be removed.
+    fn take_popup_layer(&mut self) -> Option<WidgetId> {
+        self.popup.take().map(|popup| popup.layer)
+    }
+
+    fn highlight(&mut self, ctx: &mut EventCtx, index: usize) {
+        let Some(popup) = &mut self.popup else {
+            return;
+        };
+        popup.highlighted = index;
+        ctx.mutate_widget_later(popup.layer, move |mut list| {
+            DropDownList::set_highlighted(&mut list.downcast(), index);
+        });
+    }
+}
+
+/// The first option after `after` (wrapping around) which starts with `letter`, ignoring case.
+fn option_with_initial(options: &[ArcStr], letter: char, after: usize) -> Option<usize> {
+    let len = options.len();
+    (1..=len)
+        .map(|offset| (after + offset) % len)
+        .find(|&index| {
+            options[index]
+                .chars()
+                .next()
+                .is_some_and(|initial| initial.to_lowercase().eq(letter.to_lowercase()))
+        })
+}
+
+// --- MARK: WIDGETMUT ---
+impl DropDown {
+    /// Set the options, and the index of the selected option.
+    ///
+    /// This closes the list of options if it is open.
+    pub fn set_options(
+        this: &mut WidgetMut<'_, Self>,
+        options: impl IntoIterator<Item = impl Into<ArcStr>>,
+        selected: usize,
+    ) {
+        if let Some(layer) = this.widget.take_popup_layer() {
+            this.ctx.remove_layer(layer);
+        }
+        this.widget.options = options.into_iter().map(Into::into).collect();
+        Self::set_selected(this, selected);
+    }
+
+    /// Set the index of the selected option.
+    ///
+    /// This doesn't emit an action.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, selected: usize) {
+        this.widget.selected = selected;
+        let text = this
+            .widget
+            .options
+            .get(selected)
+            .cloned()
+            .unwrap_or_default();
+        Label::set_text(&mut this.ctx.get_mut(&mut this.widget.label), text);
+        this.ctx.request_render();
+    }
+
+    /// Select the option at `index` on behalf of the user, and close the list.
+    fn choose(this: &mut WidgetMut<'_, Self>, index: usize) {
+        if let Some(layer) = this.widget.take_popup_layer() {
+            this.ctx.remove_layer(layer);
+        }
+        if index != this.widget.selected && index < this.widget.options.len() {
+            Self::set_selected(this, index);
+            this.ctx
+                .submit_action(Action::DropDown(DropDownAction::Selected(index)));
+        }
+        this.ctx.request_render();
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for DropDown {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::PointerDown(_, _) => {
+                if !ctx.is_disabled() {
+                    ctx.capture_pointer();
+                    ctx.request_paint_only();
+                }
+            }
+            PointerEvent::PointerUp(_, _) => {
+                if ctx.is_pointer_capture_target() && ctx.is_hovered() && !ctx.is_disabled() {
+                    ctx.request_focus();
+                    match self.take_popup_layer() {
+                        Some(layer) => {
+                            ctx.remove_layer(layer);
+                            ctx.request_render();
+                        }
+                        None => self.open(ctx),
+                    }
+                }
+                ctx.request_paint_only();
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::KeyboardKey(key_event, _) = event else {
+            return;
+        };
+        if !key_event.state.is_pressed() || ctx.is_disabled() {
+            return;
+        }
+        let last = self.options.len().saturating_sub(1);
+        match (&key_event.logical_key, self.popup) {
+            (Key::Named(NamedKey::Space | NamedKey::Enter), None) => self.open(ctx),
+            (Key::Named(NamedKey::Space | NamedKey::Enter), Some(popup)) => {
+                let index = popup.highlighted;
+                ctx.mutate_self_later(move |mut this| {
+                    Self::choose(&mut this.downcast(), index);
+                });
+            }
+            (Key::Named(NamedKey::Escape), Some(popup)) => {
+                self.popup = None;
+                ctx.remove_layer(popup.layer);
+                ctx.request_render();
+            }
+            (Key::Named(NamedKey::ArrowDown), Some(popup)) => {
+                self.highlight(ctx, (popup.highlighted + 1).min(last));
+            }
+            (Key::Named(NamedKey::ArrowUp), Some(popup)) => {
+                self.highlight(ctx, popup.highlighted.saturating_sub(1));
+            }
+            (Key::Named(NamedKey::Home), Some(_)) => self.highlight(ctx, 0),
+            (Key::Named(NamedKey::End), Some(_)) => self.highlight(ctx, last),
+            (_, Some(popup)) => {
+                let Some(letter) = key_event.text.as_ref().and_then(|text| text.chars().next())
+                else {
+                    return;
+                };
+                let Some(index) = option_with_initial(&self.options, letter, popup.highlighted)
+                else {
+                    return;
+                };
+                self.highlight(ctx, index);
+            }
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.target() != ctx.widget_id() || ctx.is_disabled() {
+            return;
+        }
+        match event.action {
+            accesskit::Action::Click | accesskit::Action::Expand if self.popup.is_none() => {
+                self.open(ctx);
+            }
+            accesskit::Action::Click | accesskit::Action::Collapse => {
+                if let Some(layer) = self.take_popup_layer() {
+                    ctx.remove_layer(layer);
+                    ctx.request_render();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::FocusChanged(false)
+            | Update::StashedChanged(true)
+            | Update::DisabledChanged(true) => {
+                if let Some(layer) = self.take_popup_layer() {
+                    ctx.remove_layer(layer);
+                }
+                ctx.request_render();
+            }
+            Update::HoveredChanged(_) | Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let padding = Size::new(
+            LABEL_INSETS.x_value() + CHEVRON_WIDTH,
+            LABEL_INSETS.y_value(),
+        );
+        let label_bc = bc.shrink(padding).loosen();
+        let label_size = ctx.run_layout(&mut self.label, &label_bc);
+
+        let size = bc.constrain(Size::new(
+            label_size.width + padding.width,
+            (label_size.height + padding.height).max(theme::BORDERED_WIDGET_HEIGHT),
+        ));
+        let label_offset = Point::new(LABEL_INSETS.x0, (size.height - label_size.height) / 2.);
+        ctx.place_child(&mut self.label, label_offset);
+
+        let baseline = ctx.child_baseline_offset(&self.label);
+        ctx.set_baseline_offset(baseline + (size.height - label_offset.y - label_size.height));
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.size();
+        let stroke_width = theme::BUTTON_BORDER_WIDTH;
+        let rounded_rect = size
+            .to_rect()
+            .inset(-stroke_width / 2.0)
+            .to_rounded_rect(theme::BUTTON_BORDER_RADIUS);
+
+        let bg_gradient = if ctx.is_disabled() {
+            [theme::DISABLED_BUTTON_LIGHT, theme::DISABLED_BUTTON_DARK]
+        } else {
+            [theme::BUTTON_LIGHT, theme::BUTTON_DARK]
+        };
+        let border_color = if ctx.is_focus_target() || self.popup.is_some() {
+            theme::PRIMARY_LIGHT
+        } else if ctx.is_hovered() && !ctx.is_disabled() {
+            theme::BORDER_LIGHT
+        } else {
+            theme::BORDER_DARK
+        };
+        fill_lin_gradient(
+            scene,
+            &rounded_rect,
+            bg_gradient,
+            UnitPoint::TOP,
+            UnitPoint::BOTTOM,
+        );
+        stroke(scene, &rounded_rect, border_color, stroke_width);
+
+        // Paint the chevron, pointing up whilst the list is open.
+        let center = Point::new(
+            size.width - LABEL_INSETS.x1 - CHEVRON_WIDTH / 2.,
+            size.height / 2.,
+        );
+        let direction = if self.popup.is_some() { -1. } else { 1. };
+        let mut chevron = BezPath::new();
+        chevron.move_to((center.x - 4., center.y - 2. * direction));
+        chevron.line_to((center.x, center.y + 2. * direction));
+        chevron.line_to((center.x + 4., center.y - 2. * direction));
+        let brush = if ctx.is_disabled() {
+            theme::DISABLED_TEXT_COLOR
+        } else {
+            theme::TEXT_COLOR
+        };
+        stroke(scene, &chevron, brush, 1.5);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ComboBox
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.add_action(accesskit::Action::Click);
+        node.set_expanded(self.popup.is_some());
+        if let Some(popup) = &self.popup {
+            node.add_action(accesskit::Action::Collapse);
+            node.set_owns(vec![popup.layer.into()]);
+        } else {
+            node.add_action(accesskit::Action::Expand);
+        }
+        if let Some(option) = self.options.get(self.selected) {
+            node.set_value(option.to_string());
+        }
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.label.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("DropDown", id = ctx.widget_id().trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        self.options.get(self.selected).map(ToString::to_string)
+    }
+}
+
+// --- MARK: POPUP LIST ---
+/// The list of options shown in a layer whilst a [`DropDown`] is open.
+struct DropDownList {
+    /// The drop down which opened this list.
+    owner: WidgetId,
+    items: Vec<WidgetPod<Label>>,
+    highlighted: usize,
+    /// The width of the owner, which the list should be at least as wide as.
+    min_width: f64,
+    /// The height of each item, computed in layout.
+    item_height: f64,
+}
+
+impl DropDownList {
+    fn new(owner: WidgetId, options: &[ArcStr], highlighted: usize, min_width: f64) -> Self {
+        Self {
+            owner,
+            items: options
+                .iter()
+                .map(|option| WidgetPod::new(Label::new(option.clone())))
+                .collect(),
+            highlighted,
+            min_width,
+            item_height: 0.,
+        }
+    }
+
+    fn index_at(&self, pos: Point) -> Option<usize> {
+        if pos.y < 0. || self.item_height <= 0. {
+            return None;
+        }
+        let index = (pos.y / self.item_height) as usize;
+        (index < self.items.len()).then_some(index)
+    }
+
+    fn set_highlighted(this: &mut WidgetMut<'_, Self>, highlighted: usize) {
+        this.widget.highlighted = highlighted;
+        this.ctx.request_paint_only();
+    }
+}
+
+impl Widget for DropDownList {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::PointerMove(_) => {
+                if let Some(index) = self.index_at(event.local_position(ctx)) {
+                    if index != self.highlighted {
+                        self.highlighted = index;
+                        ctx.request_paint_only();
+                    }
+                }
+            }
+            PointerEvent::PointerDown(_, _) => {
+                // Clicking outside of the focused drop down would otherwise take focus from
+                // it, which closes this list.
+                ctx.set_focus(self.owner);
+                ctx.capture_pointer();
+            }
+            PointerEvent::PointerUp(_, _) => {
+                if ctx.is_pointer_capture_target() && ctx.is_hovered() {
+                    if let Some(index) = self.index_at(event.local_position(ctx)) {
+                        ctx.mutate_widget_later(self.owner, move |mut drop_down| {
+                            DropDown::choose(&mut drop_down.downcast(), index);
+                        });
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        for item in &mut self.items {
+            ctx.register_child(item);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let padding = Size::new(ITEM_INSETS.x_value(), ITEM_INSETS.y_value());
+        let item_bc = bc.shrink(padding).loosen();
+        let mut width = self.min_width;
+        let mut label_height: f64 = 0.;
+        for item in &mut self.items {
+            let size = ctx.run_layout(item, &item_bc);
+            width = width.max(size.width + padding.width);
+            label_height = label_height.max(size.height);
+        }
+        self.item_height = label_height + padding.height;
+        for (index, item) in self.items.iter_mut().enumerate() {
+            let origin = Point::new(
+                ITEM_INSETS.x0,
+                index as f64 * self.item_height + ITEM_INSETS.y0,
+            );
+            ctx.place_child(item, origin);
+        }
+        bc.constrain(Size::new(width, self.items.len() as f64 * self.item_height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let border_width = 1.;
+        let rect = ctx
+            .size()
+            .to_rect()
+            .inset(-border_width / 2.)
+            .to_rounded_rect(theme::BUTTON_BORDER_RADIUS);
+        fill_color(scene, &rect, theme::BACKGROUND_DARK);
+
+        if self.highlighted < self.items.len() {
+            let highlight = Rect::new(
+                0.,
+                self.highlighted as f64 * self.item_height,
+                ctx.size().width,
+                (self.highlighted + 1) as f64 * self.item_height,
+            );
+            fill_color(scene, &highlight, theme::PRIMARY_DARK);
+        }
+        stroke(scene, &rect, theme::BORDER_LIGHT, border_width);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ListBox
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        self.items.iter().map(|item| item.id()).collect()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("DropDownList", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::PointerButton;
+    use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+    use crate::widgets::Flex;
+
+    fn fruit() -> DropDown {
+        DropDown::new(["Apple", "Banana", "Blueberry", "Cherry"], 0)
+    }
+
+    fn popup(harness: &TestHarness, id: WidgetId) -> Option<Popup> {
+        harness.get_widget(id).downcast::<DropDown>().unwrap().popup
+    }
+
+    #[test]
+    fn click_opens_and_selects() {
+        let [drop_down_id] = widget_ids();
+        let widget = Flex::column().with_child(fruit().with_id(drop_down_id));
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+
+        harness.mouse_click_on(drop_down_id);
+        let popup = popup(&harness, drop_down_id).expect("list should be open");
+        assert_eq!(popup.highlighted, 0);
+        assert_eq!(harness.focused_widget_id(), Some(drop_down_id));
+        let drop_down_rect = harness.get_widget(drop_down_id).ctx().bounding_rect();
+        let list = harness.get_widget(popup.layer);
+        let list_rect = list.ctx().bounding_rect();
+        assert_eq!(list_rect.y0, drop_down_rect.y1);
+        assert!(list_rect.width() >= drop_down_rect.width());
+
+        // Click on "Cherry".
+        let item_height = list.downcast::<DropDownList>().unwrap().item_height;
+        harness.mouse_move((list_rect.x0 + 10., list_rect.y0 + item_height * 3.5));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::DropDown(DropDownAction::Selected(3)), drop_down_id))
+        );
+        let drop_down = harness.get_widget(drop_down_id);
+        let drop_down = drop_down.downcast::<DropDown>().unwrap();
+        assert_eq!(drop_down.selected(), 3);
+        assert!(!drop_down.is_open());
+        assert!(harness.try_get_widget(popup.layer).is_none());
+        assert_eq!(harness.focused_widget_id(), Some(drop_down_id));
+    }
+
+    #[test]
+    fn click_outside_closes() {
+        let [drop_down_id] = widget_ids();
+        let widget = Flex::column().with_child(fruit().with_id(drop_down_id));
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+
+        harness.mouse_click_on(drop_down_id);
+        assert!(popup(&harness, drop_down_id).is_some());
+
+        harness.mouse_move((190., 190.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert!(popup(&harness, drop_down_id).is_none());
+        assert_eq!(harness.pop_action(), None);
+    }
+
+    #[test]
+    fn removed_while_open() {
+        let [drop_down_id] = widget_ids();
+        let widget = Flex::column().with_child(fruit().with_id(drop_down_id));
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+
+        harness.mouse_click_on(drop_down_id);
+        let popup = popup(&harness, drop_down_id).expect("list should be open");
+
+        // Removing the drop down whilst it is open also removes its list.
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            Flex::remove_child(&mut flex, 0);
+        });
+        assert!(harness.try_get_widget(drop_down_id).is_none());
+        assert!(harness.try_get_widget(popup.layer).is_none());
+
+        // Clicking where the list was doesn't send anything to the removed drop down.
+        harness.mouse_move((10., 40.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action(), None);
+    }
+
+    #[test]
+    fn type_ahead() {
+        let options = fruit().options;
+        assert_eq!(option_with_initial(&options, 'b', 0), Some(1));
+        assert_eq!(option_with_initial(&options, 'B', 1), Some(2));
+        // Wraps around to the first match.
+        assert_eq!(option_with_initial(&options, 'b', 2), Some(1));
+        assert_eq!(option_with_initial(&options, 'a', 0), Some(0));
+        assert_eq!(option_with_initial(&options, 'z', 0), None);
+    }
+}
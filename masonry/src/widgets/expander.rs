@@ -0,0 +1,484 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A section which can be collapsed to hide its content.
+
+use std::f64::consts::FRAC_PI_2;
+use std::time::Duration;
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Affine, BezPath, Point, Rect, Size};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, LayoutCtx, PaintCtx, PointerButton,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::util::stroke;
+
+/// The width reserved for the chevron, to the left of the header.
+const CHEVRON_WIDTH: f64 = 20.;
+/// The vertical padding around the header.
+const HEADER_PADDING: f64 = 4.;
+/// How long expanding or collapsing takes, if it is animated.
+const ANIMATION_DURATION: Duration = Duration::from_millis(150);
+
+/// A section with a header, which can be clicked to show or hide the content below it.
+///
+/// The section can also be expanded or collapsed by pressing Space or Enter whilst it is
+/// focused. A chevron to the left of the header points right whilst the section is collapsed,
+/// and down whilst it is expanded.
+///
+/// The content of a collapsed section is stashed, so it keeps its state but isn't laid out.
+/// The change in height can be animated using [`with_animation`](Self::with_animation).
+///
+/// This widget emits [`Action::ExpanderToggled`] when the user expands or collapses it.
+pub struct Expander {
+    header: WidgetPod<dyn Widget>,
+    content: WidgetPod<dyn Widget>,
+    expanded: bool,
+    animated: bool,
+    /// How much of the content is shown, from `0.0` whilst collapsed to `1.0` whilst expanded.
+    ///
+    /// This is only in between whilst animating.
+    progress: f64,
+    /// The height of the header row, computed in layout.
+    header_height: f64,
+}
+
+// --- MARK: BUILDERS ---
+impl Expander {
+    /// Create a new `Expander` with the given header and content.
+    pub fn new(header: impl Widget, content: impl Widget, expanded: bool) -> Self {
+        Self::from_pods(
+            WidgetPod::new(header).erased(),
+            WidgetPod::new(content).erased(),
+            expanded,
+        )
+    }
+
+    /// Create a new `Expander` with the given header and content pods.
+    pub fn from_pods(
+        header: WidgetPod<dyn Widget>,
+        content: WidgetPod<dyn Widget>,
+        expanded: bool,
+    ) -> Self {
+        Self {
+            header,
+            content,
+            expanded,
+            animated: false,
+            progress: if expanded { 1. } else { 0. },
+            header_height: 0.,
+        }
+    }
+
+    /// Builder-style method to set whether expanding and collapsing is animated.
+    ///
+    /// This is off by default, in which case the content is shown or hidden at once.
+    pub fn with_animation(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Whether the content is shown.
+    ///
+    /// This is `true` as soon as the section starts expanding, and `false` as soon as it
+    /// starts collapsing.
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// The height of the content which is visible whilst animating, given its full height.
+    fn visible_height(&self, content_height: f64) -> f64 {
+        // Ease out, so that the animation slows down as it finishes.
+        let eased = 1. - (1. - self.progress).powi(2);
+        content_height * eased
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl Expander {
+    /// Expand or collapse the section.
+    ///
+    /// This doesn't emit an action.
+    pub fn set_expanded(this: &mut WidgetMut<'_, Self>, expanded: bool) {
+        if this.widget.expanded == expanded {
+            return;
+        }
+        this.widget.expanded = expanded;
+        if this.widget.animated {
+            this.ctx.request_anim_frame();
+        } else {
+            this.widget.progress = if expanded { 1. } else { 0. };
+        }
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Set whether expanding and collapsing is animated.
+    ///
+    /// If animation is turned off part way through an animation, the section jumps to
+    /// its final height.
+    pub fn set_animation(this: &mut WidgetMut<'_, Self>, animated: bool) {
+        this.widget.animated = animated;
+        if !animated {
+            this.widget.progress = if this.widget.expanded { 1. } else { 0. };
+            this.ctx.request_layout();
+        }
+    }
+
+    /// Get a mutable reference to the header.
+    pub fn header_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.header)
+    }
+
+    /// Get a mutable reference to the content.
+    pub fn content_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.content)
+    }
+
+    /// Expand or collapse the section on behalf of the user.
+    fn toggle(this: &mut WidgetMut<'_, Self>) {
+        let expanded = !this.widget.expanded;
+        Self::set_expanded(this, expanded);
+        this.ctx.submit_action(Action::ExpanderToggled(expanded));
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for Expander {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::PointerDown(PointerButton::Primary, state) => {
+                if ctx.is_disabled() {
+                    return;
+                }
+                let pos = Point::new(state.position.x, state.position.y);
+                let local_pos = pos - ctx.window_origin().to_vec2();
+                if local_pos.y < self.header_height {
+                    ctx.capture_pointer();
+                    ctx.set_handled();
+                }
+            }
+            PointerEvent::PointerUp(PointerButton::Primary, _) => {
+                if ctx.is_pointer_capture_target() && ctx.is_hovered() && !ctx.is_disabled() {
+                    ctx.request_focus();
+                    ctx.mutate_self_later(|mut this| {
+                        Self::toggle(&mut this.downcast());
+                    });
+                    ctx.set_handled();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::KeyboardKey(key_event, _) = event else {
+            return;
+        };
+        // Keys pressed in the content also reach this widget.
+        if !key_event.state.is_pressed() || !ctx.is_focus_target() || ctx.is_disabled() {
+            return;
+        }
+        if matches!(
+            key_event.logical_key,
+            Key::Named(NamedKey::Space | NamedKey::Enter)
+        ) {
+            ctx.mutate_self_later(|mut this| {
+                Self::toggle(&mut this.downcast());
+            });
+            ctx.set_handled();
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.target() != ctx.widget_id() || ctx.is_disabled() {
+            return;
+        }
+        let toggle = match event.action {
+            accesskit::Action::Click => true,
+            accesskit::Action::Expand => !self.expanded,
+            accesskit::Action::Collapse => self.expanded,
+            _ => false,
+        };
+        if toggle {
+            ctx.mutate_self_later(|mut this| {
+                Self::toggle(&mut this.downcast());
+            });
+            ctx.set_handled();
+        }
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let step = Duration::from_nanos(interval).as_secs_f64() / ANIMATION_DURATION.as_secs_f64();
+        let target = if self.expanded { 1. } else { 0. };
+        self.progress = if self.expanded {
+            (self.progress + step).min(target)
+        } else {
+            (self.progress - step).max(target)
+        };
+        if self.progress != target {
+            ctx.request_anim_frame();
+        }
+        ctx.request_layout();
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.header);
+        ctx.register_child(&mut self.content);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let header_bc = bc.shrink((CHEVRON_WIDTH, 2. * HEADER_PADDING)).loosen();
+        let header_size = ctx.run_layout(&mut self.header, &header_bc);
+        self.header_height = header_size.height + 2. * HEADER_PADDING;
+        ctx.place_child(&mut self.header, Point::new(CHEVRON_WIDTH, HEADER_PADDING));
+        let header_width = header_size.width + CHEVRON_WIDTH;
+
+        // The content is kept whilst collapsed, but isn't laid out.
+        let collapsed = self.progress == 0.;
+        ctx.set_stashed(&mut self.content, collapsed);
+        if collapsed {
+            ctx.skip_layout(&mut self.content);
+            ctx.clear_clip_path();
+            return bc.constrain(Size::new(header_width, self.header_height));
+        }
+
+        let content_bc = bc.shrink((0., self.header_height)).loosen();
+        let content_size = ctx.run_layout(&mut self.content, &content_bc);
+        ctx.place_child(&mut self.content, Point::new(0., self.header_height));
+
+        let size = bc.constrain(Size::new(
+            header_width.max(content_size.width),
+            self.header_height + self.visible_height(content_size.height),
+        ));
+        if self.progress < 1. {
+            ctx.set_clip_path(size.to_rect());
+        } else {
+            ctx.clear_clip_path();
+        }
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let theme = ctx.theme();
+        let header_rect = Rect::new(0., 0., ctx.size().width, self.header_height);
+        if ctx.is_focus_target() {
+            stroke(scene, &header_rect.inset(-1.), theme.accent_color, 1.);
+        }
+
+        // The chevron points right whilst collapsed, and turns to point down as it expands.
+        let center = Point::new(CHEVRON_WIDTH / 2., self.header_height / 2.);
+        let mut chevron = BezPath::new();
+        chevron.move_to((-2., -4.));
+        chevron.line_to((2., 0.));
+        chevron.line_to((-2., 4.));
+        chevron.apply_affine(
+            Affine::translate(center.to_vec2()) * Affine::rotate(self.progress * FRAC_PI_2),
+        );
+        let brush = if ctx.is_disabled() {
+            theme.disabled_text_color
+        } else if ctx.is_hovered() {
+            theme.accent_color
+        } else {
+            theme.text_color
+        };
+        stroke(scene, &chevron, brush, 1.5);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Group
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.add_action(accesskit::Action::Click);
+        node.set_expanded(self.expanded);
+        if self.expanded {
+            node.add_action(accesskit::Action::Collapse);
+        } else {
+            node.add_action(accesskit::Action::Expand);
+        }
+        node.set_labelled_by(vec![self.header.id().into()]);
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.header.id(), self.content.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Expander", id = ctx.widget_id().trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(
+            if self.expanded {
+                "expanded"
+            } else {
+                "collapsed"
+            }
+            .to_string(),
+        )
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+    use crate::widgets::{Label, SizedBox};
+
+    fn content_is_stashed(harness: &TestHarness, content_id: WidgetId) -> bool {
+        harness.get_widget(content_id).ctx().is_stashed()
+    }
+
+    #[test]
+    fn click_toggles() {
+        let [expander_id, header_id, content_id] = widget_ids();
+        let widget = Expander::new(
+            Label::new("Section").with_id(header_id),
+            Label::new("Content").with_id(content_id),
+            false,
+        )
+        .with_id(expander_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+        assert!(content_is_stashed(&harness, content_id));
+
+        harness.mouse_click_on(header_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ExpanderToggled(true), expander_id))
+        );
+        assert_eq!(harness.focused_widget_id(), Some(expander_id));
+        assert!(!content_is_stashed(&harness, content_id));
+        let expander = harness.get_widget(expander_id);
+        assert!(expander.downcast::<Expander>().unwrap().is_expanded());
+
+        // Clicking the content doesn't collapse the section.
+        harness.mouse_click_on(content_id);
+        assert_eq!(harness.pop_action(), None);
+
+        harness.mouse_click_on(header_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ExpanderToggled(false), expander_id))
+        );
+        assert!(content_is_stashed(&harness, content_id));
+    }
+
+    #[test]
+    fn access_actions() {
+        let [expander_id, content_id] = widget_ids();
+        let widget = Expander::new(
+            Label::new("Section"),
+            Label::new("Content").with_id(content_id),
+            true,
+        )
+        .with_id(expander_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+
+        // Expanding an expanded section does nothing.
+        harness.process_access_event(expander_id, accesskit::Action::Expand, None);
+        assert_eq!(harness.pop_action(), None);
+
+        harness.process_access_event(expander_id, accesskit::Action::Collapse, None);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ExpanderToggled(false), expander_id))
+        );
+        assert!(content_is_stashed(&harness, content_id));
+
+        // Setting the state isn't a user action.
+        harness.edit_widget(expander_id, |mut expander| {
+            Expander::set_expanded(&mut expander.downcast(), true);
+        });
+        assert_eq!(harness.pop_action(), None);
+        assert!(!content_is_stashed(&harness, content_id));
+    }
+
+    #[test]
+    fn animated_height() {
+        let [expander_id, content_id] = widget_ids();
+        let widget = Expander::new(
+            SizedBox::empty().width(50.).height(20.),
+            SizedBox::empty()
+                .width(50.)
+                .height(100.)
+                .with_id(content_id),
+            false,
+        )
+        .with_animation(true)
+        .with_id(expander_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 400.));
+        let height = |harness: &TestHarness| harness.get_widget(expander_id).ctx().size().height;
+        let header_height = 20. + 2. * HEADER_PADDING;
+        assert_eq!(height(&harness), header_height);
+
+        harness.edit_widget(expander_id, |mut expander| {
+            Expander::set_expanded(&mut expander.downcast(), true);
+        });
+        harness.animate_ms(75);
+        assert!(!content_is_stashed(&harness, content_id));
+        let partway = height(&harness);
+        assert!(
+            partway > header_height && partway < header_height + 100.,
+            "the content should be partly shown whilst animating, got height {partway}"
+        );
+
+        harness.animate_ms(100);
+        assert_eq!(height(&harness), header_height + 100.);
+
+        harness.edit_widget(expander_id, |mut expander| {
+            Expander::set_expanded(&mut expander.downcast(), false);
+        });
+        // The content is only stashed once it has been hidden.
+        assert!(!content_is_stashed(&harness, content_id));
+        harness.animate_ms(200);
+        assert!(content_is_stashed(&harness, content_id));
+        assert_eq!(height(&harness), header_height);
+    }
+}
@@ -3,6 +3,8 @@
 
 //! A widget that arranges its children in a one-dimensional array.
 
+use std::ops::Range;
+
 use accesskit::{Node, Role};
 use smallvec::SmallVec;
 use tracing::{Span, trace_span};
@@ -29,6 +31,8 @@ pub struct Flex {
     children: Vec<Child>,
     old_bc: BoxConstraints,
     gap: Option<f64>,
+    wrap: FlexWrap,
+    run_spacing: f64,
 }
 
 /// Optional parameters for an item in a [`Flex`] container (row or column).
@@ -41,6 +45,8 @@ pub struct Flex {
 pub struct FlexParams {
     flex: Option<f64>,
     alignment: Option<CrossAxisAlignment>,
+    min_main: Option<f64>,
+    max_main: Option<f64>,
 }
 
 /// An axis in visual space.
@@ -94,6 +100,41 @@ pub enum MainAxisAlignment {
     SpaceAround,
 }
 
+/// Whether the children of a [`Flex`] are wrapped onto multiple lines.
+///
+/// Equivalent to the css [flex-wrap] property.
+///
+/// [flex-wrap]: https://developer.mozilla.org/en-US/docs/Web/CSS/flex-wrap
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlexWrap {
+    /// All children are laid out in a single line, even if it overflows the container.
+    #[default]
+    NoWrap,
+    /// Children which don't fit in the available main-axis space start a new line (or "run").
+    ///
+    /// Each run is as big as its largest child on the cross axis, and the main axis
+    /// alignment is applied to each run separately.
+    /// Flex children share the space left over in their run.
+    ///
+    /// If the container is unbounded on its main axis, there is no wrapping.
+    Wrap,
+}
+
+/// A line of children in a wrapping [`Flex`].
+struct Run {
+    /// The indices of the children in the run.
+    children: Range<usize>,
+    /// The size of the run on the main axis, including gaps.
+    major: f64,
+    /// The size of the run on the cross axis.
+    minor: f64,
+    /// The largest distance from the top of a child in the run to its baseline.
+    max_above_baseline: f64,
+}
+
+// We start with a small value to avoid divide-by-zero errors.
+const MIN_FLEX_SUM: f64 = 0.0001;
+
 struct Spacing {
     alignment: MainAxisAlignment,
     extra: f64,
@@ -112,6 +153,8 @@ enum Child {
         widget: WidgetPod<dyn Widget>,
         alignment: Option<CrossAxisAlignment>,
         flex: f64,
+        min_main: Option<f64>,
+        max_main: Option<f64>,
     },
     FixedSpacer(f64, f64),
     FlexedSpacer(f64, f64),
@@ -129,6 +172,8 @@ impl Flex {
             fill_major_axis: false,
             old_bc: BoxConstraints::tight(Size::ZERO),
             gap: None,
+            wrap: FlexWrap::NoWrap,
+            run_spacing: 0.0,
         }
     }
 
@@ -212,6 +257,31 @@ impl Flex {
         self
     }
 
+    /// Builder-style method for setting whether children wrap onto multiple lines.
+    ///
+    /// To modify this on an active flex, use [`set_wrap`](Self::set_wrap).
+    pub fn wrap(mut self, wrap: FlexWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Builder-style method for setting the spacing between lines in logical pixels,
+    /// when children [wrap](Self::wrap).
+    ///
+    /// This defaults to zero.
+    ///
+    /// ## Panics
+    ///
+    /// If `run_spacing` is not a non-negative finite value.
+    pub fn run_spacing(mut self, run_spacing: f64) -> Self {
+        if run_spacing.is_finite() && run_spacing >= 0.0 {
+            self.run_spacing = run_spacing;
+        } else {
+            panic!("Invalid `run_spacing` {run_spacing}, expected a non-negative finite value.")
+        }
+        self
+    }
+
     /// Builder-style variant of [`Flex::add_child`].
     ///
     /// Convenient for assembling a group of widgets in a single expression.
@@ -373,6 +443,29 @@ impl Flex {
         this.ctx.request_layout();
     }
 
+    /// Set whether children wrap onto multiple lines.
+    pub fn set_wrap(this: &mut WidgetMut<'_, Self>, wrap: FlexWrap) {
+        this.widget.wrap = wrap;
+        // Children are given different constraints when wrapping, so
+        // make sure that they are all laid out again.
+        this.widget.old_bc = BoxConstraints::tight(Size::ZERO);
+        this.ctx.request_layout();
+    }
+
+    /// Set the spacing between lines in logical pixels, when children wrap.
+    ///
+    /// ## Panics
+    ///
+    /// If `run_spacing` is not a non-negative finite value.
+    pub fn set_run_spacing(this: &mut WidgetMut<'_, Self>, run_spacing: f64) {
+        if run_spacing.is_finite() && run_spacing >= 0.0 {
+            this.widget.run_spacing = run_spacing;
+        } else {
+            panic!("Invalid `run_spacing` {run_spacing}, expected a non-negative finite value.")
+        }
+        this.ctx.request_layout();
+    }
+
     /// Add a non-flex child widget.
     ///
     /// See also [`with_child`].
@@ -541,6 +634,19 @@ impl Flex {
         this.ctx.request_layout();
     }
 
+    /// Move the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// Unlike removing and re-adding it, this preserves the state of the child widget.
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` is out of bounds.
+    pub fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
+
     pub fn child_mut<'t>(
         this: &'t mut WidgetMut<'_, Self>,
         idx: usize,
@@ -744,7 +850,49 @@ impl FlexParams {
         Self {
             flex,
             alignment: alignment.into(),
+            min_main: None,
+            max_main: None,
+        }
+    }
+
+    /// Builder-style method to set the smallest size a flex child is given on the main axis.
+    ///
+    /// If a child's share of the free space is outside of its bounds, it is clamped to them,
+    /// and the rest of the space is shared between the other flex children.
+    /// If there isn't enough space for every child's minimum, each child still gets its
+    /// minimum, and the [`Flex`] overflows.
+    ///
+    /// This has no effect on non-flex children.
+    ///
+    /// ## Panics
+    ///
+    /// If `min_main` is not a non-negative finite value.
+    pub fn with_min_main(mut self, min_main: f64) -> Self {
+        if min_main.is_finite() && min_main >= 0.0 {
+            self.min_main = Some(min_main);
+        } else {
+            panic!("Invalid `min_main` {min_main}, expected a non-negative finite value.")
+        }
+        self
+    }
+
+    /// Builder-style method to set the largest size a flex child is given on the main axis.
+    ///
+    /// See [`with_min_main`](Self::with_min_main) for how the bounds are applied.
+    /// If the minimum is larger than the maximum, the minimum wins.
+    ///
+    /// This has no effect on non-flex children.
+    ///
+    /// ## Panics
+    ///
+    /// If `max_main` is not a non-negative finite value.
+    pub fn with_max_main(mut self, max_main: f64) -> Self {
+        if max_main.is_finite() && max_main >= 0.0 {
+            self.max_main = Some(max_main);
+        } else {
+            panic!("Invalid `max_main` {max_main}, expected a non-negative finite value.")
         }
+        self
     }
 }
 
@@ -862,6 +1010,24 @@ impl From<CrossAxisAlignment> for FlexParams {
 }
 
 impl Child {
+    /// The flex factor and main axis bounds of a flex child or spacer, for [`resolve_flex_sizes`].
+    fn flex_item(&self) -> Option<(f64, f64, f64)> {
+        match self {
+            Self::Flex {
+                flex,
+                min_main,
+                max_main,
+                ..
+            } => Some((
+                *flex,
+                min_main.unwrap_or(0.0),
+                max_main.unwrap_or(f64::INFINITY),
+            )),
+            Self::FlexedSpacer(flex, _) => Some((*flex, 0.0, f64::INFINITY)),
+            _ => None,
+        }
+    }
+
     fn widget_mut(&mut self) -> Option<&mut WidgetPod<dyn Widget>> {
         match self {
             Self::Fixed { widget, .. } | Self::Flex { widget, .. } => Some(widget),
@@ -884,6 +1050,57 @@ fn axis_default_spacer(axis: Axis) -> f64 {
     }
 }
 
+/// Share `free_space` between the flex items, given as `(flex, min, max)`, returning the
+/// unrounded size of each item on the main axis.
+///
+/// Like css flexbox, items whose share is out of their bounds are clamped and frozen,
+/// and the space left is shared again between the other items, until no bounds are violated.
+fn resolve_flex_sizes(free_space: f64, items: &[(f64, f64, f64)]) -> Vec<f64> {
+    let mut sizes = vec![0.0; items.len()];
+    let mut frozen = vec![false; items.len()];
+    loop {
+        let mut remaining = free_space;
+        let mut flex_sum = MIN_FLEX_SUM;
+        for (&(flex, ..), (&size, &frozen)) in items.iter().zip(sizes.iter().zip(&frozen)) {
+            if frozen {
+                remaining -= size;
+            } else {
+                flex_sum += flex;
+            }
+        }
+        let px_per_flex = remaining.max(0.0) / flex_sum;
+
+        // Positive if clamping grew the items overall, negative if it shrank them.
+        let mut total_violation = 0.0;
+        let mut targets = vec![0.0; items.len()];
+        for (idx, &(flex, min, max)) in items.iter().enumerate() {
+            if frozen[idx] {
+                continue;
+            }
+            targets[idx] = flex * px_per_flex;
+            sizes[idx] = targets[idx].min(max).max(min);
+            if sizes[idx] != targets[idx] {
+                total_violation += sizes[idx] - targets[idx];
+            }
+        }
+
+        let mut any_frozen = false;
+        for ((frozen, &size), &target) in frozen.iter_mut().zip(&sizes).zip(&targets) {
+            if *frozen || size == target {
+                continue;
+            }
+            let grew = size > target;
+            if total_violation == 0.0 || (total_violation > 0.0) == grew {
+                *frozen = true;
+                any_frozen = true;
+            }
+        }
+        if !any_frozen {
+            return sizes;
+        }
+    }
+}
+
 fn new_flex_child(params: FlexParams, widget: WidgetPod<dyn Widget>) -> Child {
     if let Some(flex) = params.flex {
         if flex.is_normal() && flex > 0.0 {
@@ -891,6 +1108,8 @@ fn new_flex_child(params: FlexParams, widget: WidgetPod<dyn Widget>) -> Child {
                 widget,
                 alignment: params.alignment,
                 flex,
+                min_main: params.min_main,
+                max_main: params.max_main,
             }
         } else {
             tracing::warn!(
@@ -909,6 +1128,225 @@ fn new_flex_child(params: FlexParams, widget: WidgetPod<dyn Widget>) -> Child {
     }
 }
 
+impl Flex {
+    /// Lay out the children in runs which each fit in the main axis, for [`FlexWrap::Wrap`].
+    fn layout_wrapped(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints) -> Size {
+        let loosened_bc = bc.loosen();
+        let gap = self.gap.unwrap_or(axis_default_spacer(self.direction));
+        let max_major = self.direction.major(bc.max());
+
+        // Measure non-flex children, and split the children into runs.
+        // Flex children only need their minimum size, and otherwise take up the space left over
+        // in their run.
+        let mut runs = Vec::new();
+        let mut run_start = 0;
+        let mut run_major = 0.0;
+        for (idx, child) in self.children.iter_mut().enumerate() {
+            let child_major = match child {
+                Child::Fixed { widget, .. } => {
+                    let child_size = ctx.run_layout(widget, &loosened_bc);
+                    self.direction.major(child_size)
+                }
+                Child::FixedSpacer(len, calculated_size) => {
+                    *calculated_size = len.max(0.0);
+                    *calculated_size
+                }
+                Child::Flex { min_main, .. } => min_main.unwrap_or(0.0),
+                Child::FlexedSpacer(..) => 0.0,
+            };
+            let needed = if idx == run_start {
+                child_major
+            } else {
+                run_major + gap + child_major
+            };
+            if idx > run_start && needed > max_major {
+                runs.push(run_start..idx);
+                run_start = idx;
+                run_major = child_major;
+            } else {
+                run_major = needed;
+            }
+        }
+        if run_start < self.children.len() {
+            runs.push(run_start..self.children.len());
+        }
+
+        // Measure flex children within each run.
+        let runs: Vec<Run> = runs
+            .into_iter()
+            .map(|range| {
+                let children = &mut self.children[range.clone()];
+                let total_gap = (children.len() - 1) as f64 * gap;
+                let mut major_non_flex = total_gap;
+                for child in children.iter() {
+                    match child {
+                        Child::Fixed { widget, .. } => {
+                            major_non_flex += self.direction.major(ctx.child_size(widget));
+                        }
+                        Child::FixedSpacer(_, calculated_size) => {
+                            major_non_flex += *calculated_size;
+                        }
+                        Child::Flex { .. } | Child::FlexedSpacer(..) => {}
+                    }
+                }
+                let flex_items: Vec<_> = children.iter().filter_map(Child::flex_item).collect();
+                let mut flex_sizes =
+                    resolve_flex_sizes((max_major - major_non_flex).max(0.0), &flex_items)
+                        .into_iter();
+
+                let mut remainder = 0.0;
+                let mut major = total_gap;
+                let mut minor = 0_f64;
+                let mut max_above_baseline = 0_f64;
+                let mut max_below_baseline = 0_f64;
+                let mut any_use_baseline = false;
+                for child in children.iter_mut() {
+                    let (widget, alignment, child_size) = match child {
+                        Child::Fixed { widget, alignment } => {
+                            let child_size = ctx.child_size(widget);
+                            (widget, alignment, child_size)
+                        }
+                        Child::Flex {
+                            widget,
+                            alignment,
+                            min_main,
+                            ..
+                        } => {
+                            let desired_major = flex_sizes.next().unwrap_or(0.0) + remainder;
+                            let actual_major = desired_major.round();
+                            remainder = desired_major - actual_major;
+                            let min_major = min_main.unwrap_or(0.0).min(actual_major);
+                            let child_bc =
+                                self.direction
+                                    .constraints(&loosened_bc, min_major, actual_major);
+                            let child_size = ctx.run_layout(widget, &child_bc);
+                            (widget, alignment, child_size)
+                        }
+                        Child::FixedSpacer(_, calculated_size) => {
+                            major += *calculated_size;
+                            continue;
+                        }
+                        Child::FlexedSpacer(_, calculated_size) => {
+                            let desired_major = flex_sizes.next().unwrap_or(0.0) + remainder;
+                            *calculated_size = desired_major.round();
+                            remainder = desired_major - *calculated_size;
+                            major += *calculated_size;
+                            continue;
+                        }
+                    };
+                    let alignment = alignment.unwrap_or(self.cross_alignment);
+                    any_use_baseline |= alignment == CrossAxisAlignment::Baseline;
+                    let baseline_offset = ctx.child_baseline_offset(widget);
+
+                    major += self.direction.major(child_size);
+                    minor = minor.max(self.direction.minor(child_size));
+                    max_above_baseline =
+                        max_above_baseline.max(child_size.height - baseline_offset);
+                    max_below_baseline = max_below_baseline.max(baseline_offset);
+                }
+                if any_use_baseline && self.direction == Axis::Horizontal {
+                    minor = minor.max(max_above_baseline + max_below_baseline);
+                }
+                Run {
+                    children: range,
+                    major,
+                    minor,
+                    max_above_baseline,
+                }
+            })
+            .collect();
+
+        let container_major = if self.fill_major_axis {
+            max_major
+        } else {
+            runs.iter()
+                .map(|run| run.major)
+                .fold(self.direction.major(bc.min()), f64::max)
+        };
+
+        // Place the children, applying the main axis alignment to each run.
+        let mut run_minor = 0.0;
+        let mut child_paint_rect = Rect::ZERO;
+        for (run_idx, run) in runs.iter().enumerate() {
+            if run_idx > 0 {
+                run_minor += self.run_spacing;
+            }
+            let mut spacing = Spacing::new(
+                self.main_alignment,
+                (container_major - run.major).max(0.0),
+                run.children.len(),
+            );
+            let mut major = spacing.next().unwrap_or(0.);
+            for child in &mut self.children[run.children.clone()] {
+                match child {
+                    Child::Fixed { widget, alignment }
+                    | Child::Flex {
+                        widget, alignment, ..
+                    } => {
+                        let child_size = ctx.child_size(widget);
+                        let alignment = alignment.unwrap_or(self.cross_alignment);
+                        let child_minor_offset = match alignment {
+                            CrossAxisAlignment::Baseline
+                                if matches!(self.direction, Axis::Horizontal) =>
+                            {
+                                let child_baseline = ctx.child_baseline_offset(widget);
+                                run.max_above_baseline - (child_size.height - child_baseline)
+                            }
+                            CrossAxisAlignment::Fill => {
+                                let fill_size: Size = self
+                                    .direction
+                                    .pack(self.direction.major(child_size), run.minor)
+                                    .into();
+                                if child_size != fill_size {
+                                    ctx.run_layout(widget, &BoxConstraints::tight(fill_size));
+                                }
+                                0.0
+                            }
+                            _ => alignment.align(run.minor - self.direction.minor(child_size)),
+                        };
+
+                        let child_pos: Point = self
+                            .direction
+                            .pack(major, run_minor + child_minor_offset)
+                            .into();
+                        ctx.place_child(widget, child_pos);
+                        child_paint_rect = child_paint_rect.union(ctx.widget_state.paint_rect());
+                        major += self.direction.major(child_size);
+                    }
+                    Child::FlexedSpacer(_, calculated_size)
+                    | Child::FixedSpacer(_, calculated_size) => {
+                        major += *calculated_size;
+                    }
+                }
+                major += spacing.next().unwrap_or(0.);
+                major += gap;
+            }
+            run_minor += run.minor;
+        }
+
+        let minor = run_minor.max(self.direction.minor(bc.min()));
+        let my_size: Size = self.direction.pack(container_major, minor).into();
+
+        let insets = child_paint_rect - my_size.to_rect();
+        ctx.set_paint_insets(insets);
+
+        // The baseline is that of the last child, which is in the last run.
+        let baseline_offset = self
+            .children
+            .iter()
+            .rev()
+            .find_map(Child::widget)
+            .map(|widget| {
+                let child_bl = ctx.child_baseline_offset(widget);
+                let child_max_y = ctx.child_layout_rect(widget).max_y();
+                child_bl + (my_size.height - child_max_y)
+            })
+            .unwrap_or(0.0);
+        ctx.set_baseline_offset(baseline_offset);
+        my_size
+    }
+}
+
 // --- MARK: IMPL WIDGET---
 impl Widget for Flex {
     fn on_pointer_event(
@@ -947,6 +1385,10 @@ impl Widget for Flex {
         _props: &mut PropertiesMut<'_>,
         bc: &BoxConstraints,
     ) -> Size {
+        if self.wrap == FlexWrap::Wrap && self.direction.major(bc.max()).is_finite() {
+            return self.layout_wrapped(ctx, bc);
+        }
+
         // we loosen our constraints when passing to children.
         let loosened_bc = bc.loosen();
 
@@ -968,8 +1410,6 @@ impl Widget for Flex {
         let total_gap = self.children.len().saturating_sub(1) as f64 * gap;
         // Measure non-flex children.
         let mut major_non_flex = total_gap;
-        // We start with a small value to avoid divide-by-zero errors.
-        const MIN_FLEX_SUM: f64 = 0.0001;
         let mut flex_sum = MIN_FLEX_SUM;
         for child in &mut self.children {
             match child {
@@ -1026,27 +1466,33 @@ impl Widget for Flex {
         let mut remainder: f64 = 0.0;
 
         let mut major_flex: f64 = 0.0;
-        let px_per_flex = remaining / flex_sum;
+        let flex_items: Vec<_> = self.children.iter().filter_map(Child::flex_item).collect();
+        let mut flex_sizes = resolve_flex_sizes(remaining, &flex_items).into_iter();
         // Measure flex children.
         for child in &mut self.children {
             match child {
                 Child::Flex {
                     widget,
-                    flex,
                     alignment,
+                    min_main,
+                    ..
                 } => {
+                    let flex_size = flex_sizes.next().unwrap_or(0.0);
                     // The BoxConstraints of flex-children depends on the size of every sibling, which
                     // received layout earlier. Therefore we use any_changed.
                     let child_size = if any_changed || ctx.child_needs_layout(widget) {
                         let alignment = alignment.unwrap_or(self.cross_alignment);
                         any_use_baseline |= alignment == CrossAxisAlignment::Baseline;
 
-                        let desired_major = (*flex) * px_per_flex + remainder;
+                        let desired_major = flex_size + remainder;
                         let actual_major = desired_major.round();
                         remainder = desired_major - actual_major;
 
                         let old_size = ctx.widget_state.layout_rect().size();
-                        let child_bc = self.direction.constraints(&loosened_bc, 0.0, actual_major);
+                        let min_major = min_main.unwrap_or(0.0).min(actual_major);
+                        let child_bc =
+                            self.direction
+                                .constraints(&loosened_bc, min_major, actual_major);
                         let child_size = ctx.run_layout(widget, &child_bc);
 
                         if old_size != child_size {
@@ -1067,8 +1513,8 @@ impl Widget for Flex {
                         max_above_baseline.max(child_size.height - baseline_offset);
                     max_below_baseline = max_below_baseline.max(baseline_offset);
                 }
-                Child::FlexedSpacer(flex, calculated_size) => {
-                    let desired_major = (*flex) * px_per_flex + remainder;
+                Child::FlexedSpacer(_, calculated_size) => {
+                    let desired_major = flex_sizes.next().unwrap_or(0.0) + remainder;
                     *calculated_size = desired_major.round();
                     remainder = desired_major - *calculated_size;
                     major_flex += *calculated_size;
@@ -1242,8 +1688,10 @@ impl Widget for Flex {
 mod tests {
     use super::*;
     use crate::assert_render_snapshot;
-    use crate::testing::TestHarness;
-    use crate::widgets::Label;
+    use smallvec::smallvec;
+
+    use crate::testing::{ModularWidget, TestHarness, widget_ids};
+    use crate::widgets::{Label, SizedBox};
 
     #[test]
     #[allow(clippy::cognitive_complexity)]
@@ -1635,6 +2083,177 @@ mod tests {
         // TODO - test out-of-bounds access?
     }
 
+    #[test]
+    fn gap_with_flex_children() {
+        let [fixed_id, flex_1_id, flex_2_id] = widget_ids();
+        let widget = Flex::row()
+            .gap(10.0)
+            .with_child_id(SizedBox::empty().width(20.0), fixed_id)
+            .with_flex_child_pod(
+                WidgetPod::new_with_id(SizedBox::empty().expand_width(), flex_1_id).erased(),
+                1.0,
+            )
+            .with_flex_child_pod(
+                WidgetPod::new_with_id(SizedBox::empty().expand_width(), flex_2_id).erased(),
+                1.0,
+            );
+        let harness = TestHarness::create_with_size(widget, Size::new(200.0, 50.0));
+
+        // The two gaps are taken out of the space shared between the flex children.
+        let rect = |id| harness.get_widget(id).ctx().local_layout_rect();
+        assert_eq!(rect(fixed_id).x0, 0.0);
+        assert_eq!(rect(flex_1_id).x0, 30.0);
+        assert_eq!(rect(flex_1_id).width(), 80.0);
+        assert_eq!(rect(flex_2_id).x0, 120.0);
+        assert_eq!(rect(flex_2_id).width(), 80.0);
+    }
+
+    fn expanding_flex_child(flex: Flex, id: WidgetId, params: FlexParams) -> Flex {
+        flex.with_flex_child_pod(
+            WidgetPod::new_with_id(SizedBox::empty().expand_width(), id).erased(),
+            params,
+        )
+    }
+
+    #[test]
+    fn flex_child_min_max_main() {
+        let [capped_id, min_id, free_id] = widget_ids();
+        let widget = Flex::row().gap(0.0);
+        let widget = expanding_flex_child(
+            widget,
+            capped_id,
+            FlexParams::new(1.0, None).with_max_main(50.0),
+        );
+        let widget = expanding_flex_child(
+            widget,
+            min_id,
+            FlexParams::new(1.0, None).with_min_main(120.0),
+        );
+        let widget = expanding_flex_child(widget, free_id, FlexParams::new(1.0, None));
+        let harness = TestHarness::create_with_size(widget, Size::new(300.0, 50.0));
+
+        // The capped child's share is given to the others, which both end up above 120.
+        let rect = |id| harness.get_widget(id).ctx().local_layout_rect();
+        assert_eq!(rect(capped_id).width(), 50.0);
+        assert_eq!(rect(min_id).x0, 50.0);
+        assert_eq!(rect(min_id).width(), 125.0);
+        assert_eq!(rect(free_id).x0, 175.0);
+        assert_eq!(rect(free_id).width(), 125.0);
+    }
+
+    #[test]
+    fn flex_child_unsatisfiable_bounds() {
+        let [first_id, second_id, free_id, inverted_id] = widget_ids();
+        let widget = Flex::row().gap(0.0);
+        let widget = expanding_flex_child(
+            widget,
+            first_id,
+            FlexParams::new(1.0, None).with_min_main(150.0),
+        );
+        let widget = expanding_flex_child(
+            widget,
+            second_id,
+            FlexParams::new(1.0, None).with_min_main(150.0),
+        );
+        let widget = expanding_flex_child(widget, free_id, FlexParams::new(1.0, None));
+        // The minimum wins over the maximum.
+        let widget = expanding_flex_child(
+            widget,
+            inverted_id,
+            FlexParams::new(1.0, None)
+                .with_min_main(30.0)
+                .with_max_main(10.0),
+        );
+        let harness = TestHarness::create_with_size(widget, Size::new(200.0, 50.0));
+
+        // The minimums don't fit, so every child gets its minimum and the flex overflows.
+        let rect = |id| harness.get_widget(id).ctx().local_layout_rect();
+        assert_eq!(rect(first_id).width(), 150.0);
+        assert_eq!(rect(second_id).x0, 150.0);
+        assert_eq!(rect(second_id).width(), 150.0);
+        assert_eq!(rect(free_id).x0, 300.0);
+        assert_eq!(rect(free_id).width(), 0.0);
+        assert_eq!(rect(inverted_id).x0, 300.0);
+        assert_eq!(rect(inverted_id).width(), 30.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid `min_main`")]
+    fn negative_min_main() {
+        let _ = FlexParams::new(1.0, None).with_min_main(-1.0);
+    }
+
+    /// A harness in which `flex` is laid out with the given constraints.
+    fn harness_with_constraints(flex: Flex, flex_id: WidgetId, bc: BoxConstraints) -> TestHarness {
+        let parent = ModularWidget::new(WidgetPod::new_with_id(flex, flex_id))
+            .register_children_fn(|child, ctx| {
+                ctx.register_child(child);
+            })
+            .layout_fn(move |child, ctx, _, _| {
+                ctx.run_layout(child, &bc);
+                ctx.place_child(child, Point::ZERO);
+                Size::new(400.0, 400.0)
+            })
+            .children_fn(|child| smallvec![child.id()]);
+        TestHarness::create_with_size(parent, Size::new(400.0, 400.0))
+    }
+
+    fn sized(width: f64, height: f64) -> SizedBox {
+        SizedBox::empty().width(width).height(height)
+    }
+
+    #[test]
+    fn wrap_into_runs() {
+        let [flex_id, a_id, b_id, c_id] = widget_ids();
+        let widget = Flex::row()
+            .wrap(FlexWrap::Wrap)
+            .gap(10.0)
+            .run_spacing(5.0)
+            .cross_axis_alignment(CrossAxisAlignment::End)
+            .with_child_id(sized(40.0, 20.0), a_id)
+            .with_child_id(sized(40.0, 40.0), b_id)
+            .with_child_id(sized(40.0, 20.0), c_id);
+        // The cross axis is unbounded, so the runs set the height.
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(100.0, f64::INFINITY));
+        let harness = harness_with_constraints(widget, flex_id, bc);
+
+        let origin = |id| harness.get_widget(id).ctx().local_layout_rect().origin();
+        // Each run is aligned on the cross axis by its own largest child.
+        assert_eq!(origin(a_id), Point::new(0.0, 20.0));
+        assert_eq!(origin(b_id), Point::new(50.0, 0.0));
+        // The third child doesn't fit in the first run.
+        assert_eq!(origin(c_id), Point::new(0.0, 45.0));
+        assert_eq!(
+            harness.get_widget(flex_id).ctx().size(),
+            Size::new(90.0, 65.0)
+        );
+    }
+
+    #[test]
+    fn wrap_flex_child_within_run() {
+        let [flex_id, flex_child_id, wrapped_id] = widget_ids();
+        let widget = Flex::row()
+            .wrap(FlexWrap::Wrap)
+            .gap(10.0)
+            .main_axis_alignment(MainAxisAlignment::Center)
+            .with_child(sized(60.0, 20.0))
+            .with_flex_child_pod(
+                WidgetPod::new_with_id(SizedBox::empty().expand_width(), flex_child_id).erased(),
+                1.0,
+            )
+            .with_child_id(sized(60.0, 20.0), wrapped_id);
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(100.0, 100.0));
+        let harness = harness_with_constraints(widget, flex_id, bc);
+
+        // The flex child takes up the rest of the first run.
+        let rect = |id| harness.get_widget(id).ctx().local_layout_rect();
+        assert_eq!(rect(flex_child_id).x0, 70.0);
+        assert_eq!(rect(flex_child_id).width(), 30.0);
+        // The main axis alignment applies to the second run on its own.
+        assert_eq!(rect(wrapped_id).x0, 20.0);
+        assert_eq!(rect(wrapped_id).y0, 20.0);
+    }
+
     #[test]
     fn divide_by_zero() {
         let widget = Flex::column().with_flex_spacer(0.0);
@@ -0,0 +1,510 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which recognises taps, long presses and drags on its child.
+
+use std::time::Duration;
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Point, Size, Vec2};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, DragPhase, EventCtx, FromDynWidget,
+    GestureAction, LayoutCtx, PaintCtx, PointerButton, PointerEvent, PropertiesMut, PropertiesRef,
+    QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+
+/// The default longest time between a tap and the next press for them to be a double tap.
+pub const DEFAULT_DOUBLE_TAP_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The default time the pointer must be held down for a long press.
+pub const DEFAULT_LONG_PRESS_DELAY: Duration = Duration::from_millis(500);
+
+/// The default distance in logical pixels the pointer must move whilst down to start a drag.
+pub const DEFAULT_DRAG_THRESHOLD: f64 = 4.0;
+
+/// A widget which recognises gestures made with the primary pointer button on its child,
+/// and submits them as [`Action::Gesture`].
+///
+/// A press which is released before moving [far](Self::with_drag_threshold) or being held
+/// for [long](Self::with_long_press_delay) is a [tap](GestureAction::Tap),
+/// and a tap which starts soon after another is also a [double tap](GestureAction::DoubleTap).
+/// A press which is held is a [long press](GestureAction::LongPress),
+/// and one which moves is a [drag](GestureAction::Drag).
+///
+/// Gestures are cancelled when the pointer leaves the window, when this widget is
+/// disabled or stashed, and when the pointer leaves this widget whilst it doesn't
+/// [capture the pointer](Self::with_capture_drags).
+/// A drag which is cancelled is reported with [`DragPhase::Cancelled`].
+///
+/// Events which the child handles aren't seen by this widget, so e.g. a button inside it
+/// still works as normal, but won't be tapped.
+pub struct GestureDetector<W: ?Sized> {
+    child: WidgetPod<W>,
+    double_tap_interval: Duration,
+    long_press_delay: Duration,
+    drag_threshold: f64,
+    capture_drags: bool,
+    state: GestureState,
+    /// The time since the last tap, whilst a press would make it a double tap.
+    since_tap: Option<Duration>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GestureState {
+    /// The pointer isn't pressed on the child.
+    Idle,
+    /// The pointer was pressed at `start`, and hasn't moved far or been held for long.
+    Pressed {
+        start: Point,
+        held: Duration,
+        /// Whether releasing the pointer would be a double tap.
+        double_tap: bool,
+    },
+    /// The pointer was held down until it was a long press, and hasn't been released.
+    LongPressed,
+    /// The pointer was pressed at `start`, and has since moved by `delta`.
+    Dragging { start: Point, delta: Vec2 },
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> GestureDetector<W> {
+    /// Create a new `GestureDetector` which recognises gestures on `child`.
+    pub fn new(child: W) -> Self {
+        Self::from_pod(WidgetPod::new(child))
+    }
+}
+
+impl<W: Widget + ?Sized> GestureDetector<W> {
+    /// Create a new `GestureDetector` which recognises gestures on the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>) -> Self {
+        Self {
+            child,
+            double_tap_interval: DEFAULT_DOUBLE_TAP_INTERVAL,
+            long_press_delay: DEFAULT_LONG_PRESS_DELAY,
+            drag_threshold: DEFAULT_DRAG_THRESHOLD,
+            capture_drags: true,
+            state: GestureState::Idle,
+            since_tap: None,
+        }
+    }
+
+    /// Set the longest time between a tap and the next press for them to be a double tap.
+    ///
+    /// This defaults to [`DEFAULT_DOUBLE_TAP_INTERVAL`].
+    pub fn with_double_tap_interval(mut self, interval: Duration) -> Self {
+        self.double_tap_interval = interval;
+        self
+    }
+
+    /// Set how long the pointer must be held down for a long press.
+    ///
+    /// This defaults to [`DEFAULT_LONG_PRESS_DELAY`].
+    pub fn with_long_press_delay(mut self, delay: Duration) -> Self {
+        self.long_press_delay = delay;
+        self
+    }
+
+    /// Set how far in logical pixels the pointer must move whilst down to start a drag.
+    ///
+    /// This defaults to [`DEFAULT_DRAG_THRESHOLD`].
+    pub fn with_drag_threshold(mut self, threshold: f64) -> Self {
+        self.drag_threshold = threshold;
+        self
+    }
+
+    /// Set whether to [capture the pointer](EventCtx::capture_pointer) when it is pressed,
+    /// so that drags continue when the pointer leaves this widget.
+    ///
+    /// This takes the capture from the child if it captured the pointer itself.
+    /// This defaults to true.
+    pub fn with_capture_drags(mut self, capture_drags: bool) -> Self {
+        self.capture_drags = capture_drags;
+        self
+    }
+
+    /// Stop tracking the current gesture, returning the action for a cancelled drag, if any.
+    fn cancel(&mut self) -> Option<GestureAction> {
+        self.since_tap = None;
+        match std::mem::replace(&mut self.state, GestureState::Idle) {
+            GestureState::Dragging { start, delta } => Some(GestureAction::Drag {
+                start,
+                delta,
+                phase: DragPhase::Cancelled,
+            }),
+            _ => None,
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> GestureDetector<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the longest time between a tap and the next press for them to be a double tap.
+    pub fn set_double_tap_interval(this: &mut WidgetMut<'_, Self>, interval: Duration) {
+        this.widget.double_tap_interval = interval;
+    }
+
+    /// Set how long the pointer must be held down for a long press.
+    pub fn set_long_press_delay(this: &mut WidgetMut<'_, Self>, delay: Duration) {
+        this.widget.long_press_delay = delay;
+    }
+
+    /// Set how far in logical pixels the pointer must move whilst down to start a drag.
+    pub fn set_drag_threshold(this: &mut WidgetMut<'_, Self>, threshold: f64) {
+        this.widget.drag_threshold = threshold;
+    }
+
+    /// Set whether to capture the pointer when it is pressed.
+    ///
+    /// This applies from the next press.
+    pub fn set_capture_drags(this: &mut WidgetMut<'_, Self>, capture_drags: bool) {
+        this.widget.capture_drags = capture_drags;
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for GestureDetector<W> {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::PointerDown(PointerButton::Primary, _) => {
+                if self.capture_drags {
+                    ctx.capture_pointer();
+                }
+                self.state = GestureState::Pressed {
+                    start: event.local_position(ctx),
+                    held: Duration::ZERO,
+                    double_tap: self.since_tap.take().is_some(),
+                };
+                // For the long press.
+                ctx.request_anim_frame();
+                ctx.set_handled();
+            }
+            PointerEvent::PointerMove(_) => match self.state {
+                GestureState::Pressed { start, .. } => {
+                    let delta = event.local_position(ctx) - start;
+                    if delta.hypot() > self.drag_threshold {
+                        self.state = GestureState::Dragging { start, delta };
+                        ctx.submit_action(Action::Gesture(GestureAction::Drag {
+                            start,
+                            delta,
+                            phase: DragPhase::Started,
+                        }));
+                        ctx.set_handled();
+                    }
+                }
+                GestureState::Dragging { start, .. } => {
+                    let delta = event.local_position(ctx) - start;
+                    self.state = GestureState::Dragging { start, delta };
+                    ctx.submit_action(Action::Gesture(GestureAction::Drag {
+                        start,
+                        delta,
+                        phase: DragPhase::Moved,
+                    }));
+                    ctx.set_handled();
+                }
+                GestureState::Idle | GestureState::LongPressed => {}
+            },
+            PointerEvent::PointerUp(PointerButton::Primary, _) => {
+                match std::mem::replace(&mut self.state, GestureState::Idle) {
+                    GestureState::Pressed { double_tap, .. } => {
+                        ctx.submit_action(Action::Gesture(GestureAction::Tap));
+                        if double_tap {
+                            ctx.submit_action(Action::Gesture(GestureAction::DoubleTap));
+                        } else {
+                            self.since_tap = Some(Duration::ZERO);
+                            ctx.request_anim_frame();
+                        }
+                        ctx.set_handled();
+                    }
+                    GestureState::Dragging { start, delta } => {
+                        ctx.submit_action(Action::Gesture(GestureAction::Drag {
+                            start,
+                            delta,
+                            phase: DragPhase::Ended,
+                        }));
+                        ctx.set_handled();
+                    }
+                    GestureState::LongPressed => ctx.set_handled(),
+                    GestureState::Idle => {}
+                }
+            }
+            PointerEvent::PointerLeave(_) => {
+                if let Some(action) = self.cancel() {
+                    ctx.submit_action(Action::Gesture(action));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let interval = Duration::from_nanos(interval);
+        let mut needs_frame = false;
+        if let Some(since_tap) = self.since_tap {
+            let since_tap = since_tap + interval;
+            if since_tap > self.double_tap_interval {
+                self.since_tap = None;
+            } else {
+                self.since_tap = Some(since_tap);
+                needs_frame = true;
+            }
+        }
+        if let GestureState::Pressed {
+            start,
+            held,
+            double_tap,
+        } = self.state
+        {
+            let held = held + interval;
+            if held >= self.long_press_delay {
+                self.state = GestureState::LongPressed;
+                ctx.submit_action(Action::Gesture(GestureAction::LongPress));
+            } else {
+                self.state = GestureState::Pressed {
+                    start,
+                    held,
+                    double_tap,
+                };
+                needs_frame = true;
+            }
+        }
+        if needs_frame {
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        let cancel = match event {
+            // Without pointer capture, the rest of the gesture won't be seen.
+            Update::HoveredChanged(false) | Update::ChildHoveredChanged(false) => {
+                !ctx.has_hovered() && !ctx.is_pointer_capture_target()
+            }
+            Update::DisabledChanged(true) | Update::StashedChanged(true) => true,
+            _ => false,
+        };
+        if cancel {
+            if let Some(action) = self.cancel() {
+                ctx.submit_action(Action::Gesture(action));
+            }
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("GestureDetector", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Flex, SizedBox};
+
+    /// A harness with a 100x100 gesture detector in its top left corner, and its id.
+    fn create_harness(detector: GestureDetector<SizedBox>) -> (TestHarness, WidgetId) {
+        let [detector_id] = widget_ids();
+        let widget = Flex::row()
+            .with_child_id(detector, detector_id)
+            .with_child(SizedBox::empty().width(100.0).height(100.0));
+        let harness = TestHarness::create_with_size(widget, Size::new(200.0, 100.0));
+        (harness, detector_id)
+    }
+
+    fn detector() -> GestureDetector<SizedBox> {
+        GestureDetector::new(SizedBox::empty().width(100.0).height(100.0))
+    }
+
+    /// The gestures recognised since the last call.
+    fn gestures(harness: &mut TestHarness) -> Vec<GestureAction> {
+        std::iter::from_fn(|| harness.pop_action())
+            .filter_map(|(action, _)| match action {
+                Action::Gesture(gesture) => Some(gesture),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn click(harness: &mut TestHarness) {
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    #[test]
+    fn tap_and_double_tap() {
+        let (mut harness, _) = create_harness(detector());
+        harness.mouse_move((50.0, 50.0));
+        click(&mut harness);
+        assert_eq!(gestures(&mut harness), [GestureAction::Tap]);
+
+        harness.animate_ms(100);
+        click(&mut harness);
+        assert_eq!(
+            gestures(&mut harness),
+            [GestureAction::Tap, GestureAction::DoubleTap]
+        );
+
+        // A third tap starts a new pair.
+        click(&mut harness);
+        assert_eq!(gestures(&mut harness), [GestureAction::Tap]);
+
+        // Taps further apart than the interval aren't double taps.
+        harness.animate_ms(600);
+        click(&mut harness);
+        assert_eq!(gestures(&mut harness), [GestureAction::Tap]);
+    }
+
+    #[test]
+    fn long_press() {
+        let (mut harness, _) =
+            create_harness(detector().with_long_press_delay(Duration::from_millis(300)));
+        harness.mouse_move((50.0, 50.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.animate_ms(200);
+        assert_eq!(gestures(&mut harness), []);
+
+        // The long press is reported before the pointer is released, and isn't also a tap.
+        harness.animate_ms(200);
+        assert_eq!(gestures(&mut harness), [GestureAction::LongPress]);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(gestures(&mut harness), []);
+    }
+
+    #[test]
+    fn drag_outside_with_capture() {
+        let (mut harness, _) = create_harness(detector());
+        let start = Point::new(50.0, 50.0);
+        harness.mouse_move(start);
+        harness.mouse_button_press(PointerButton::Primary);
+        // Small movements are still part of a tap.
+        harness.mouse_move((52.0, 50.0));
+        assert_eq!(gestures(&mut harness), []);
+
+        harness.mouse_move((70.0, 50.0));
+        harness.mouse_move((150.0, 60.0));
+        harness.mouse_button_release(PointerButton::Primary);
+        let drag = |delta: Vec2, phase| GestureAction::Drag {
+            start,
+            delta,
+            phase,
+        };
+        assert_eq!(
+            gestures(&mut harness),
+            [
+                drag(Vec2::new(20.0, 0.0), DragPhase::Started),
+                drag(Vec2::new(100.0, 10.0), DragPhase::Moved),
+                drag(Vec2::new(100.0, 10.0), DragPhase::Ended),
+            ]
+        );
+    }
+
+    #[test]
+    fn drag_cancelled() {
+        // Without pointer capture, the drag is cancelled when the pointer leaves the detector.
+        let (mut harness, _) = create_harness(detector().with_capture_drags(false));
+        harness.mouse_move((50.0, 50.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((90.0, 50.0));
+        harness.mouse_move((150.0, 50.0));
+        let cancelled = GestureAction::Drag {
+            start: Point::new(50.0, 50.0),
+            delta: Vec2::new(40.0, 0.0),
+            phase: DragPhase::Cancelled,
+        };
+        assert_eq!(gestures(&mut harness).last(), Some(&cancelled));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(gestures(&mut harness), []);
+
+        // With capture, it is cancelled when the pointer leaves the window.
+        let (mut captured, _) = create_harness(detector());
+        captured.mouse_move((50.0, 50.0));
+        captured.mouse_button_press(PointerButton::Primary);
+        captured.mouse_move((90.0, 50.0));
+        captured.process_pointer_event(PointerEvent::new_pointer_leave());
+        assert_eq!(gestures(&mut captured).last(), Some(&cancelled));
+    }
+
+    #[test]
+    fn disabled_cancels_long_press() {
+        let (mut harness, detector_id) = create_harness(detector());
+        harness.mouse_move((50.0, 50.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.edit_widget(detector_id, |mut detector| detector.ctx.set_disabled(true));
+        harness.animate_ms(600);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(gestures(&mut harness), []);
+    }
+}
@@ -94,6 +94,15 @@ impl Child {
         self.width = params.width;
         self.height = params.height;
     }
+
+    /// The number of columns and rows spanned by this child, limited to the cells remaining
+    /// in the grid after its position.
+    fn clamped_span(&self, grid_width: i32, grid_height: i32) -> (i32, i32) {
+        (
+            self.width.min(grid_width - self.x).max(1),
+            self.height.min(grid_height - self.y).max(1),
+        )
+    }
 }
 
 fn new_grid_child(params: GridParams, widget: WidgetPod<dyn Widget>) -> Child {
@@ -218,6 +227,20 @@ impl Grid {
         Some(this.ctx.get_mut(child))
     }
 
+    /// Set the number of columns and rows spanned by the child at `idx`.
+    ///
+    /// Spans which extend past the edge of the grid are cut off at the edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn set_child_span(this: &mut WidgetMut<'_, Self>, idx: usize, cols: i32, rows: i32) {
+        let child = &mut this.widget.children[idx];
+        let params = GridParams::new(child.x, child.y, cols, rows);
+        child.update_params(params);
+        this.ctx.request_layout();
+    }
+
     /// Updates the grid parameters for the child at `idx`,
     ///
     /// # Panics
@@ -238,6 +261,20 @@ impl Grid {
         this.ctx.remove_child(child.widget);
         this.ctx.request_layout();
     }
+
+    /// Move the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// The position of each child in the grid is set by its [`GridParams`], so this only
+    /// changes the order in which children are painted and focused.
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` is out of bounds.
+    pub fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
 }
 
 // --- MARK: IMPL WIDGET---
@@ -288,9 +325,10 @@ impl Widget for Grid {
         let width_unit = (total_size.width + self.grid_spacing) / (self.grid_width as f64);
         let height_unit = (total_size.height + self.grid_spacing) / (self.grid_height as f64);
         for child in &mut self.children {
+            let (cols, rows) = child.clamped_span(self.grid_width, self.grid_height);
             let cell_size = Size::new(
-                (child.width as f64 * width_unit - self.grid_spacing).max(0.0),
-                (child.height as f64 * height_unit - self.grid_spacing).max(0.0),
+                (cols as f64 * width_unit - self.grid_spacing).max(0.0),
+                (rows as f64 * height_unit - self.grid_spacing).max(0.0),
             );
             let child_bc = BoxConstraints::new(cell_size, cell_size);
             let _ = ctx.run_layout(&mut child.widget, &child_bc);
@@ -342,10 +380,12 @@ impl Widget for Grid {
 // --- MARK: TESTS ---
 #[cfg(test)]
 mod tests {
+    use vello::kurbo::Rect;
+
     use super::*;
     use crate::assert_render_snapshot;
-    use crate::testing::TestHarness;
-    use crate::widgets::button;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{SizedBox, button};
 
     #[test]
     fn test_grid_basics() {
@@ -417,6 +457,31 @@ mod tests {
         assert_render_snapshot!(harness, "with_negative_spacing");
     }
 
+    #[test]
+    fn test_spanning_children() {
+        let [header_id, cell_id] = widget_ids();
+        let widget = Grid::with_dimensions(2, 2)
+            .with_child_id(SizedBox::empty(), header_id, GridParams::new(0, 0, 2, 1))
+            .with_child_id(SizedBox::empty(), cell_id, GridParams::new(1, 1, 1, 1));
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 100.));
+        let rect = |harness: &TestHarness, id| harness.get_widget(id).ctx().local_layout_rect();
+        assert_eq!(rect(&harness, header_id), Rect::new(0., 0., 200., 50.));
+        assert_eq!(rect(&harness, cell_id), Rect::new(100., 50., 200., 100.));
+
+        harness.edit_root_widget(|mut grid| {
+            let mut grid = grid.downcast::<Grid>();
+            Grid::set_child_span(&mut grid, 0, 1, 2);
+        });
+        assert_eq!(rect(&harness, header_id), Rect::new(0., 0., 100., 100.));
+
+        // Spans past the edge of the grid are cut off.
+        harness.edit_root_widget(|mut grid| {
+            let mut grid = grid.downcast::<Grid>();
+            Grid::set_child_span(&mut grid, 1, 3, 3);
+        });
+        assert_eq!(rect(&harness, cell_id), Rect::new(100., 50., 200., 100.));
+    }
+
     #[test]
     fn test_widget_removal_and_modification() {
         let widget = Grid::with_dimensions(2, 2)
@@ -0,0 +1,267 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which handles key combinations pressed anywhere in the window.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, FromDynWidget, Hotkey, LayoutCtx,
+    PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+
+/// A widget which handles key combinations, such as Ctrl+S, whether or not it is focused.
+///
+/// When one of its hotkeys is pressed and the focused widget doesn't handle it,
+/// this emits [`Action::HotkeyPressed`].
+/// Otherwise, it lays out and paints its child unchanged.
+///
+/// See [`set_hotkeys`](EventCtx::set_hotkeys) for what happens when several widgets
+/// register the same hotkey.
+pub struct Hotkeys<W: ?Sized> {
+    child: WidgetPod<W>,
+    hotkeys: Vec<Hotkey>,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Hotkeys<W> {
+    /// Create a new `Hotkeys` around `child`, which handles `hotkeys`.
+    pub fn new(child: W, hotkeys: Vec<Hotkey>) -> Self {
+        Self::from_pod(WidgetPod::new(child), hotkeys)
+    }
+}
+
+impl<W: Widget + ?Sized> Hotkeys<W> {
+    /// Create a new `Hotkeys` around the child in `child`, which handles `hotkeys`.
+    pub fn from_pod(child: WidgetPod<W>, hotkeys: Vec<Hotkey>) -> Self {
+        Self { child, hotkeys }
+    }
+
+    /// The hotkeys this widget handles.
+    pub fn hotkeys(&self) -> &[Hotkey] {
+        &self.hotkeys
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Hotkeys<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the hotkeys this widget handles.
+    pub fn set_hotkeys(this: &mut WidgetMut<'_, Self>, hotkeys: Vec<Hotkey>) {
+        this.ctx.set_hotkeys(hotkeys.clone());
+        this.widget.hotkeys = hotkeys;
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Hotkeys<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        // Hotkeys are only sent to the widget which registered them, not its ancestors.
+        if let TextEvent::Hotkey(hotkey) = event {
+            if ctx.target() == ctx.widget_id() && self.hotkeys.contains(hotkey) {
+                ctx.submit_action(Action::HotkeyPressed(hotkey.clone()));
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            ctx.set_hotkeys(self.hotkeys.clone());
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Hotkeys", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use winit::keyboard::{Key, KeyCode, ModifiersState, NamedKey};
+
+    use super::*;
+    use crate::Handled;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Flex, Label, TextArea, Textbox};
+
+    /// Press the S key (producing `text`) whilst holding Ctrl and `mods`.
+    fn press_ctrl(harness: &mut TestHarness, text: &str, mods: ModifiersState) -> Handled {
+        harness.process_text_event(TextEvent::key_down(
+            KeyCode::KeyS,
+            Key::Character(text.into()),
+            ModifiersState::CONTROL | mods,
+        ))
+    }
+
+    fn ctrl_s() -> Hotkey {
+        Hotkey::character(ModifiersState::CONTROL, "s")
+    }
+
+    /// The hotkeys pressed since the last call, and the widgets they were sent to.
+    fn pressed(harness: &mut TestHarness) -> Vec<(Hotkey, WidgetId)> {
+        std::iter::from_fn(|| harness.pop_action())
+            .filter_map(|(action, id)| match action {
+                Action::HotkeyPressed(hotkey) => Some((hotkey, id)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hotkey_pressed_whilst_textbox_focused() {
+        let [hotkeys_id, textbox_id] = widget_ids();
+        let escape = Hotkey::new(ModifiersState::empty(), NamedKey::Escape);
+        let hotkeys = Hotkeys::from_pod(
+            WidgetPod::new_with_id(Textbox::new("hello"), textbox_id),
+            vec![ctrl_s(), escape.clone()],
+        );
+        let mut harness = TestHarness::create(Flex::column().with_child_id(hotkeys, hotkeys_id));
+        let area_id = harness
+            .get_widget(textbox_id)
+            .downcast::<Textbox>()
+            .unwrap()
+            .area_pod()
+            .id();
+        harness.focus_on(Some(area_id));
+        let text = |harness: &TestHarness| {
+            harness
+                .get_widget(area_id)
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .text()
+                .to_string()
+        };
+
+        // The focused textbox doesn't handle Ctrl+S, so it goes to the hotkey.
+        let handled = press_ctrl(&mut harness, "s", ModifiersState::empty());
+        assert!(handled.is_handled());
+        assert_eq!(pressed(&mut harness), [(ctrl_s(), hotkeys_id)]);
+        assert_eq!(harness.focused_widget_id(), Some(area_id));
+        assert_eq!(text(&harness), "hello");
+
+        // Characters are matched regardless of case.
+        press_ctrl(&mut harness, "S", ModifiersState::empty());
+        assert_eq!(pressed(&mut harness), [(ctrl_s(), hotkeys_id)]);
+
+        harness.process_text_event(TextEvent::key_down(
+            KeyCode::Escape,
+            NamedKey::Escape,
+            ModifiersState::empty(),
+        ));
+        assert_eq!(pressed(&mut harness), [(escape, hotkeys_id)]);
+
+        // The modifiers must match exactly.
+        let handled = press_ctrl(&mut harness, "s", ModifiersState::SHIFT);
+        assert!(!handled.is_handled());
+        assert_eq!(pressed(&mut harness), []);
+
+        // Keys which the textbox handles don't reach hotkeys.
+        harness.keyboard_type_chars("s");
+        assert_eq!(pressed(&mut harness), []);
+        assert_eq!(text(&harness), "hellos");
+    }
+
+    #[test]
+    fn conflicting_hotkeys_use_registration_order() {
+        let [first, second] = widget_ids();
+        let widget = Flex::column()
+            .with_child_id(Hotkeys::new(Label::new("First"), vec![ctrl_s()]), first)
+            .with_child_id(Hotkeys::new(Label::new("Second"), vec![ctrl_s()]), second);
+        let mut harness = TestHarness::create(widget);
+        let press_ctrl_s = |harness: &mut TestHarness| {
+            press_ctrl(harness, "s", ModifiersState::empty());
+            pressed(harness)
+        };
+        assert_eq!(press_ctrl_s(&mut harness), [(ctrl_s(), first)]);
+
+        // Re-registering keeps the widget's place in the order.
+        harness.edit_widget(first, |mut hotkeys| {
+            Hotkeys::set_hotkeys(&mut hotkeys.downcast::<Hotkeys<Label>>(), vec![ctrl_s()]);
+        });
+        assert_eq!(press_ctrl_s(&mut harness), [(ctrl_s(), first)]);
+
+        // Disabled widgets don't receive hotkeys.
+        harness.edit_widget(first, |mut hotkeys| hotkeys.ctx.set_disabled(true));
+        assert_eq!(press_ctrl_s(&mut harness), [(ctrl_s(), second)]);
+        harness.edit_widget(first, |mut hotkeys| hotkeys.ctx.set_disabled(false));
+
+        // Nor do widgets which have been removed.
+        harness.edit_root_widget(|mut flex| {
+            Flex::remove_child(&mut flex.downcast::<Flex>(), 0);
+        });
+        assert_eq!(press_ctrl_s(&mut harness), [(ctrl_s(), second)]);
+
+        harness.edit_widget(second, |mut hotkeys| {
+            Hotkeys::set_hotkeys(&mut hotkeys.downcast::<Hotkeys<Label>>(), vec![]);
+        });
+        assert_eq!(press_ctrl_s(&mut harness), []);
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which reports when the pointer enters or leaves its child.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+
+/// A widget which submits [`Action::HoverChanged`] when the pointer starts or stops
+/// hovering its child, or any of the child's descendants.
+///
+/// This also happens when the child moves under a stationary pointer, e.g. because
+/// a [`Portal`](crate::widgets::Portal) containing it was scrolled.
+/// Otherwise, it lays out and paints its child unchanged.
+pub struct HoverDetector<W: ?Sized> {
+    child: WidgetPod<W>,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> HoverDetector<W> {
+    /// Create a new `HoverDetector` around `child`.
+    pub fn new(child: W) -> Self {
+        Self::from_pod(WidgetPod::new(child))
+    }
+}
+
+impl<W: Widget + ?Sized> HoverDetector<W> {
+    /// Create a new `HoverDetector` around the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>) -> Self {
+        Self { child }
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> HoverDetector<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for HoverDetector<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        // The child always covers this widget, so this is sent whenever the hovered
+        // widget moves into or out of the child's subtree.
+        if let Update::ChildHoveredChanged(hovered) = event {
+            ctx.submit_action(Action::HoverChanged(*hovered));
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("HoverDetector", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use vello::kurbo::Vec2;
+
+    use super::*;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Flex, Portal, SizedBox};
+
+    fn square(size: f64) -> HoverDetector<SizedBox> {
+        HoverDetector::new(SizedBox::empty().width(size).height(size))
+    }
+
+    /// The hover changes since the last call, and the widgets which submitted them.
+    fn hover_changes(harness: &mut TestHarness) -> Vec<(bool, WidgetId)> {
+        std::iter::from_fn(|| harness.pop_action())
+            .filter_map(|(action, id)| match action {
+                Action::HoverChanged(hovered) => Some((hovered, id)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hover_follows_pointer() {
+        let [first, second] = widget_ids();
+        let widget = Flex::column()
+            .gap(0.0)
+            .with_child_id(square(50.0), first)
+            .with_child_id(square(50.0), second);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100.0, 100.0));
+        assert_eq!(hover_changes(&mut harness), []);
+
+        harness.mouse_move_to(first);
+        assert_eq!(hover_changes(&mut harness), [(true, first)]);
+        assert!(harness.get_widget(first).ctx().has_hovered());
+
+        // Moving within the same widget doesn't change anything.
+        harness.mouse_move_to(first);
+        assert_eq!(hover_changes(&mut harness), []);
+
+        // The widget which was hovered is told first.
+        harness.mouse_move_to(second);
+        assert_eq!(
+            hover_changes(&mut harness),
+            [(false, first), (true, second)]
+        );
+
+        harness.process_pointer_event(PointerEvent::new_pointer_leave());
+        assert_eq!(hover_changes(&mut harness), [(false, second)]);
+    }
+
+    #[test]
+    fn hover_follows_scroll_under_pointer() {
+        let [first, second] = widget_ids();
+        let content = Flex::column()
+            .gap(0.0)
+            .with_child_id(square(100.0), first)
+            .with_child_id(square(100.0), second)
+            .with_child(square(100.0));
+        let mut harness =
+            TestHarness::create_with_size(Portal::new(content), Size::new(100.0, 100.0));
+        harness.mouse_move((50.0, 50.0));
+        assert_eq!(hover_changes(&mut harness), [(true, first)]);
+
+        // The pointer doesn't move, but the widget under it does.
+        harness.mouse_wheel(Vec2::new(0.0, -10.0));
+        assert_eq!(
+            harness.get_widget(second).ctx().window_origin(),
+            Point::ORIGIN
+        );
+        assert_eq!(
+            hover_changes(&mut harness),
+            [(false, first), (true, second)]
+        );
+    }
+}
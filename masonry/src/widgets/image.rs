@@ -17,6 +17,7 @@ use crate::core::{
     WidgetId, WidgetMut,
 };
 use crate::kurbo::Size;
+use crate::widgets::{Alignment, HorizontalAlignment, VerticalAlignment};
 
 // TODO - Resolve name collision between masonry::Image and peniko::Image
 
@@ -30,6 +31,7 @@ use crate::kurbo::Size;
 pub struct Image {
     image_data: ImageBuf,
     object_fit: ObjectFit,
+    alignment: Alignment,
 }
 
 // --- MARK: BUILDERS ---
@@ -42,6 +44,7 @@ impl Image {
         Self {
             image_data,
             object_fit: ObjectFit::default(),
+            alignment: Alignment::default(),
         }
     }
 
@@ -51,6 +54,36 @@ impl Image {
         self.object_fit = mode;
         self
     }
+
+    /// Builder-style method for specifying where the image is placed when it doesn't
+    /// fill the widget, or which part of it is shown when it is clipped.
+    ///
+    /// The default is [`Alignment::Center`].
+    #[inline]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// The transform used to paint the image in a widget of the given size.
+    fn image_transform(&self, size: Size) -> Affine {
+        let image_size = Size::new(self.image_data.width as f64, self.image_data.height as f64);
+        let [scale_x, _, _, scale_y, _, _] =
+            self.object_fit.affine_to_fill(size, image_size).as_coeffs();
+        let free_x = size.width - image_size.width * scale_x;
+        let free_y = size.height - image_size.height * scale_y;
+        let origin_x = match self.alignment.horizontal() {
+            HorizontalAlignment::Leading => 0.0,
+            HorizontalAlignment::Center => free_x / 2.0,
+            HorizontalAlignment::Trailing => free_x,
+        };
+        let origin_y = match self.alignment.vertical() {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => free_y / 2.0,
+            VerticalAlignment::Bottom => free_y,
+        };
+        Affine::new([scale_x, 0., 0., scale_y, origin_x, origin_y])
+    }
 }
 
 // --- MARK: WIDGETMUT ---
@@ -62,6 +95,15 @@ impl Image {
         this.ctx.request_layout();
     }
 
+    /// Set where the image is placed when it doesn't fill the widget.
+    ///
+    /// This only changes how the image is painted, so doesn't affect the widget's layout.
+    #[inline]
+    pub fn set_alignment(this: &mut WidgetMut<'_, Self>, alignment: Alignment) {
+        this.widget.alignment = alignment;
+        this.ctx.request_paint_only();
+    }
+
     /// Set new `ImageBuf`.
     #[inline]
     pub fn set_image_data(this: &mut WidgetMut<'_, Self>, image_data: ImageBuf) {
@@ -117,6 +159,8 @@ impl Widget for Image {
         let image_aspect_ratio = image_size.height / image_size.width;
         match self.object_fit {
             ObjectFit::Contain => bc.constrain_aspect_ratio(image_aspect_ratio, image_size.width),
+            // Cover fills all the available space, and clips the parts of the image outside it.
+            ObjectFit::Cover if bc.is_width_bounded() && bc.is_height_bounded() => bc.max(),
             ObjectFit::Cover => Size::new(bc.max().width, bc.max().width * image_aspect_ratio),
             ObjectFit::Fill => bc.max(),
             ObjectFit::FitHeight => {
@@ -137,8 +181,7 @@ impl Widget for Image {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
-        let image_size = Size::new(self.image_data.width as f64, self.image_data.height as f64);
-        let transform = self.object_fit.affine_to_fill(ctx.size(), image_size);
+        let transform = self.image_transform(ctx.size());
 
         let clip_rect = ctx.size().to_rect();
         scene.push_layer(BlendMode::default(), 1., Affine::IDENTITY, &clip_rect);
@@ -248,6 +291,57 @@ mod tests {
         assert!(render_1 == render_2);
     }
 
+    #[test]
+    fn cover_fills_widget() {
+        let image_data = ImageBuf::new(vec![255; 4 * 8 * 8].into(), ImageFormat::Rgba8, 8, 8);
+        let image_widget = Image::new(image_data).fit_mode(ObjectFit::Cover);
+        let harness = TestHarness::create_with_size(image_widget, Size::new(100.0, 50.0));
+
+        // The image is clipped, rather than the widget overflowing its constraints.
+        let image = harness.root_widget();
+        assert_eq!(image.ctx().size(), Size::new(100.0, 50.0));
+        let transform = image
+            .downcast::<Image>()
+            .unwrap()
+            .image_transform(image.ctx().size());
+        assert_eq!(transform, Affine::new([12.5, 0., 0., 12.5, 0., -25.]));
+    }
+
+    #[test]
+    fn alignment() {
+        let image_data = ImageBuf::new(vec![255; 4 * 8 * 8].into(), ImageFormat::Rgba8, 8, 8);
+        let widget_size = Size::new(100.0, 50.0);
+        let transform = |fit, alignment| {
+            Image::new(image_data.clone())
+                .fit_mode(fit)
+                .alignment(alignment)
+                .image_transform(widget_size)
+        };
+
+        // The leftover space is split according to the alignment.
+        assert_eq!(
+            transform(ObjectFit::Contain, Alignment::Center),
+            Affine::new([6.25, 0., 0., 6.25, 25., 0.])
+        );
+        assert_eq!(
+            transform(ObjectFit::Contain, Alignment::Leading),
+            Affine::new([6.25, 0., 0., 6.25, 0., 0.])
+        );
+        assert_eq!(
+            transform(ObjectFit::None, Alignment::BottomTrailing),
+            Affine::new([1., 0., 0., 1., 92., 42.])
+        );
+        // When the image is clipped, the alignment picks which part is shown.
+        assert_eq!(
+            transform(ObjectFit::Cover, Alignment::Top),
+            Affine::new([12.5, 0., 0., 12.5, 0., 0.])
+        );
+        assert_eq!(
+            transform(ObjectFit::Cover, Alignment::Bottom),
+            Affine::new([12.5, 0., 0., 12.5, 0., -50.])
+        );
+    }
+
     #[test]
     fn layout() {
         let image_data = ImageBuf::new(vec![255; 4 * 8 * 8].into(), ImageFormat::Rgba8, 8, 8);
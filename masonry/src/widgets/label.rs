@@ -6,6 +6,7 @@
 //! A label widget.
 
 use std::mem::Discriminant;
+use std::ops::Range;
 
 use accesskit::{Node, NodeId, Role};
 use parley::layout::{Alignment, AlignmentOptions};
@@ -18,8 +19,9 @@ use vello::peniko::{BlendMode, Brush};
 
 use crate::core::{
     AccessCtx, AccessEvent, ArcStr, BoxConstraints, BrushIndex, EventCtx, LayoutCtx, PaintCtx,
-    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, StyleProperty, StyleSet,
-    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, default_styles, render_text,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, RichText, StyleAttribute,
+    StyleProperty, StyleSet, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    default_styles, push_spans, render_text, span_brushes,
 };
 use crate::theme;
 
@@ -50,7 +52,9 @@ pub struct Label {
 
     text: ArcStr,
     styles: StyleSet,
-    /// Whether `text` or `styles` has been updated since `text_layout` was created.
+    /// Styles applied to ranges of `text`, on top of `styles`.
+    spans: Vec<(Range<usize>, StyleAttribute)>,
+    /// Whether `text`, `styles` or `spans` has been updated since `text_layout` was created.
     ///
     /// If they have, the layout needs to be recreated.
     styles_changed: bool,
@@ -66,13 +70,17 @@ pub struct Label {
 
     /// The brush for drawing this label's text.
     ///
+    /// When this is `None`, the [theme's text color](crate::theme::Theme::text_color) is used.
     /// Requires a new paint if edited whilst `disabled_brush` is not being used.
-    brush: Brush,
+    brush: Option<Brush>,
     /// The brush to use whilst this widget is disabled.
     ///
-    /// When this is `None`, `brush` will be used.
+    /// When this is `None`, the [theme's disabled text color](crate::theme::Theme::disabled_text_color)
+    /// is used.
     /// Requires a new paint if edited whilst this widget is disabled.
     disabled_brush: Option<Brush>,
+    /// Whether to use `disabled_brush` whilst this widget is disabled, rather than `brush`.
+    show_disabled: bool,
     /// Whether to hint whilst drawing the text.
     ///
     /// Should be disabled whilst an animation involving this label is ongoing.
@@ -94,17 +102,27 @@ impl Label {
             accessibility: LayoutAccessibility::default(),
             text: text.into(),
             styles,
+            spans: Vec::new(),
             styles_changed: true,
             line_break_mode: LineBreaking::Overflow,
             alignment: Alignment::Start,
             alignment_changed: true,
             last_max_advance: None,
-            brush: theme::TEXT_COLOR.into(),
-            disabled_brush: Some(theme::DISABLED_TEXT_COLOR.into()),
+            brush: None,
+            disabled_brush: None,
+            show_disabled: true,
             hint: true,
         }
     }
 
+    /// Create a new label displaying rich text.
+    ///
+    /// The spans of `text` are applied on top of the label's global styles.
+    pub fn rich(text: RichText) -> Self {
+        let (text, spans) = text.into_parts();
+        Self::new(text).with_spans(spans)
+    }
+
     /// Get the current text of this label.
     ///
     /// To update the text of an active label, use [`set_text`](Self::set_text).
@@ -134,6 +152,14 @@ impl Label {
         (self, old)
     }
 
+    /// Set the styles applied to ranges of this label's text.
+    ///
+    /// To modify this on an active label, use [`set_spans`](Self::set_spans).
+    pub fn with_spans(mut self, spans: impl Into<Vec<(Range<usize>, StyleAttribute)>>) -> Self {
+        self.spans = spans.into();
+        self
+    }
+
     /// Set how line breaks will be handled by this label.
     ///
     /// To modify this on an active label, use [`set_line_break_mode`](Self::set_line_break_mode).
@@ -154,21 +180,25 @@ impl Label {
     /// Set the brush used to paint this label.
     ///
     /// In most cases, this will be the text's color, but gradients and images are also supported.
+    /// By default, the [theme's text color](crate::theme::Theme::text_color) is used.
     ///
     /// To modify this on an active label, use [`set_brush`](Self::set_brush).
     #[doc(alias = "with_color")]
     pub fn with_brush(mut self, brush: impl Into<Brush>) -> Self {
-        self.brush = brush.into();
+        self.brush = Some(brush.into());
         self
     }
 
     /// Set the brush which will be used to paint this label whilst it is disabled.
     ///
+    /// By default, the [theme's disabled text color](crate::theme::Theme::disabled_text_color)
+    /// is used.
     /// If this is `None`, the [normal brush](Self::with_brush) will be used.
     /// To modify this on an active label, use [`set_disabled_brush`](Self::set_disabled_brush).
     #[doc(alias = "with_color")]
     pub fn with_disabled_brush(mut self, disabled_brush: impl Into<Option<Brush>>) -> Self {
         self.disabled_brush = disabled_brush.into();
+        self.show_disabled = self.disabled_brush.is_some();
         self
     }
 
@@ -258,6 +288,8 @@ impl Label {
     }
 
     /// Replace the text of this widget.
+    ///
+    /// Any spans are kept, and those which no longer fit the new text are ignored.
     pub fn set_text(this: &mut WidgetMut<'_, Self>, new_text: impl Into<ArcStr>) {
         this.widget.text = new_text.into();
 
@@ -265,6 +297,29 @@ impl Label {
         this.ctx.request_layout();
     }
 
+    /// Replace both the text and the spans of this widget.
+    pub fn set_rich_text(this: &mut WidgetMut<'_, Self>, text: RichText) {
+        let (text, spans) = text.into_parts();
+        this.widget.text = text;
+        this.widget.spans = spans;
+
+        this.widget.styles_changed = true;
+        this.ctx.request_layout();
+    }
+
+    /// The runtime requivalent of [`with_spans`](Self::with_spans).
+    ///
+    /// The text is kept as is.
+    pub fn set_spans(
+        this: &mut WidgetMut<'_, Self>,
+        spans: impl Into<Vec<(Range<usize>, StyleAttribute)>>,
+    ) {
+        this.widget.spans = spans.into();
+
+        this.widget.styles_changed = true;
+        this.ctx.request_layout();
+    }
+
     /// The runtime requivalent of [`with_line_break_mode`](Self::with_line_break_mode).
     pub fn set_line_break_mode(this: &mut WidgetMut<'_, Self>, line_break_mode: LineBreaking) {
         this.widget.line_break_mode = line_break_mode;
@@ -283,11 +338,21 @@ impl Label {
     #[doc(alias = "set_color")]
     /// The runtime requivalent of [`with_brush`](Self::with_brush).
     pub fn set_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Brush>) {
-        let brush = brush.into();
-        this.widget.brush = brush;
+        this.widget.brush = Some(brush.into());
 
         // We need to repaint unless the disabled brush is currently being used.
-        if this.widget.disabled_brush.is_none() || this.ctx.is_disabled() {
+        if !this.widget.show_disabled || !this.ctx.is_disabled() {
+            this.ctx.request_paint_only();
+        }
+    }
+
+    /// Paint the text with the [theme's text color](crate::theme::Theme::text_color) again,
+    /// undoing [`set_brush`](Self::set_brush).
+    pub fn clear_brush(this: &mut WidgetMut<'_, Self>) {
+        this.widget.brush = None;
+
+        // We need to repaint unless the disabled brush is currently being used.
+        if !this.widget.show_disabled || !this.ctx.is_disabled() {
             this.ctx.request_paint_only();
         }
     }
@@ -295,6 +360,7 @@ impl Label {
     /// The runtime requivalent of [`with_disabled_brush`](Self::with_disabled_brush).
     pub fn set_disabled_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Option<Brush>>) {
         let brush = brush.into();
+        this.widget.show_disabled = brush.is_some();
         this.widget.disabled_brush = brush;
 
         if this.ctx.is_disabled() {
@@ -344,7 +410,7 @@ impl Widget for Label {
     fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
         match event {
             Update::DisabledChanged(_) => {
-                if self.disabled_brush.is_some() {
+                if self.show_disabled {
                     ctx.request_paint_only();
                 }
             }
@@ -377,6 +443,7 @@ impl Widget for Label {
             for prop in self.styles.inner().values() {
                 builder.push_default(prop.to_owned());
             }
+            push_spans(&mut builder, &self.text, &self.spans);
             builder.build_into(&mut self.text_layout, &self.text);
             self.styles_changed = false;
         }
@@ -430,14 +497,23 @@ impl Widget for Label {
         }
         let transform = Affine::translate((LABEL_X_PADDING, 0.));
 
-        let brush = if ctx.is_disabled() {
-            self.disabled_brush
+        let brushes: Vec<Brush> = if self.show_disabled && ctx.is_disabled() {
+            let disabled_brush = self
+                .disabled_brush
                 .clone()
-                .unwrap_or_else(|| self.brush.clone())
+                .unwrap_or_else(|| ctx.theme().disabled_text_color.into());
+            // Spans don't change the color of disabled text.
+            vec![disabled_brush; 1 + span_brushes(&self.spans).count()]
         } else {
-            self.brush.clone()
+            std::iter::once(
+                self.brush
+                    .clone()
+                    .unwrap_or_else(|| ctx.theme().text_color.into()),
+            )
+            .chain(span_brushes(&self.spans).cloned())
+            .collect()
         };
-        render_text(scene, transform, &self.text_layout, &[brush], self.hint);
+        render_text(scene, transform, &self.text_layout, &brushes, self.hint);
 
         if self.line_break_mode == LineBreaking::Clip {
             scene.pop_layer();
@@ -635,4 +711,64 @@ mod tests {
         // We don't use assert_eq because we don't want rich assert
         assert!(image_1 == image_2);
     }
+
+    #[test]
+    fn rich_label_spans() {
+        fn text_height(harness: &TestHarness) -> f32 {
+            let label = harness.root_widget().downcast::<Label>().unwrap();
+            label.text_layout.height()
+        }
+
+        let plain_height = text_height(&TestHarness::create(Label::new("Hello world")));
+
+        let text = RichText::new("Hello world")
+            .with_span(0..5, StyleAttribute::Size(40.0))
+            .with_span(6..11, StyleAttribute::Brush(PRIMARY_LIGHT.into()))
+            // Out of bounds spans are ignored.
+            .with_span(6..100, StyleAttribute::Underline(true));
+        let mut harness = TestHarness::create(Label::rich(text));
+        assert!(text_height(&harness) > plain_height);
+
+        harness.edit_root_widget(|mut label| {
+            let mut label = label.downcast::<Label>();
+            Label::set_spans(&mut label, Vec::new());
+        });
+        assert_eq!(text_height(&harness), plain_height);
+    }
+
+    #[test]
+    fn disabled_brush_follows_theme() {
+        use crate::theme::Theme;
+        use crate::widgets::Themed;
+
+        let render_disabled = |label: Label| {
+            let mut harness = TestHarness::create_with_size(
+                Themed::new(label, Theme::light()),
+                Size::new(50.0, 50.0),
+            );
+            harness.edit_root_widget(|mut themed| themed.ctx.set_disabled(true));
+            harness.render()
+        };
+
+        let themed = render_disabled(Label::new("Hello"));
+        let light = render_disabled(
+            Label::new("Hello").with_disabled_brush(Theme::light().disabled_text_color),
+        );
+        assert!(
+            themed == light,
+            "Disabled text should use the theme's color"
+        );
+
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel,
+        // so the images can't differ.
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            let dark = render_disabled(
+                Label::new("Hello").with_disabled_brush(Theme::dark().disabled_text_color),
+            );
+            assert!(
+                themed != dark,
+                "Disabled text shouldn't use the default theme's color"
+            );
+        }
+    }
 }
@@ -11,40 +11,92 @@ mod tests;
 
 mod align;
 mod button;
+mod canvas;
 mod checkbox;
+mod clip;
+mod conditional;
+mod context_menu;
+mod disabled_scope;
+mod drop_down;
+mod expander;
 mod flex;
+mod gesture_detector;
 mod grid;
+mod hotkeys;
+mod hover_detector;
 mod image;
 mod label;
+mod modal;
+mod opacity;
 mod portal;
 mod progress_bar;
 mod prose;
+mod radio_button;
 mod root_widget;
 mod scroll_bar;
 mod sized_box;
+mod slider;
 mod spinner;
 mod split;
+mod tab_index;
+mod tabs;
 mod text_area;
 mod textbox;
+mod themed;
+mod ticker;
+mod tooltip;
+mod transformed;
 mod variable_label;
+mod virtual_list;
+mod wheel_adjust;
 mod zstack;
 
 pub use self::align::Align;
-pub use self::button::Button;
-pub use self::checkbox::Checkbox;
-pub use self::flex::{Axis, CrossAxisAlignment, Flex, FlexParams, MainAxisAlignment};
+pub use self::button::{Button, IconPosition};
+pub use self::canvas::Canvas;
+pub use self::checkbox::{Checkbox, CheckboxState};
+pub use self::clip::{Clip, ClipShape};
+pub use self::conditional::Conditional;
+pub use self::context_menu::{ContextMenu, MenuItem};
+pub use self::disabled_scope::DisabledScope;
+pub use self::drop_down::DropDown;
+pub use self::expander::Expander;
+pub use self::flex::{Axis, CrossAxisAlignment, Flex, FlexParams, FlexWrap, MainAxisAlignment};
+pub use self::gesture_detector::{
+    DEFAULT_DOUBLE_TAP_INTERVAL, DEFAULT_DRAG_THRESHOLD, DEFAULT_LONG_PRESS_DELAY, GestureDetector,
+};
 pub use self::grid::{Grid, GridParams};
+pub use self::hotkeys::Hotkeys;
+pub use self::hover_detector::HoverDetector;
 pub use self::image::Image;
 pub use self::label::{Label, LineBreaking};
-pub use self::portal::Portal;
+pub use self::modal::{Modal, ModalOptions};
+pub use self::opacity::Opacity;
+pub use self::portal::{Portal, ScrollAxes};
 pub use self::progress_bar::ProgressBar;
 pub use self::prose::Prose;
+pub use self::radio_button::RadioButton;
 pub use self::root_widget::RootWidget;
-pub use self::scroll_bar::ScrollBar;
+pub use self::scroll_bar::{ScrollBar, ScrollBarStyle};
 pub use self::sized_box::{Padding, SizedBox};
+pub use self::slider::Slider;
 pub use self::spinner::Spinner;
 pub use self::split::Split;
-pub use self::text_area::TextArea;
-pub use self::textbox::Textbox;
+pub use self::tab_index::TabIndex;
+pub use self::tabs::{TabHeader, TabPanel, Tabs};
+pub use self::text_area::{
+    DecorationKind, Direction, HighlightStyle, LineMetric, LineNumbering, ScrollAnchor, TabAction,
+    TextArea, TextDecoration, TextSelection, TextStats,
+};
+pub use self::textbox::{BorderStyle, DisabledStyle, Textbox};
+pub use self::themed::Themed;
+pub use self::ticker::Ticker;
+pub use self::tooltip::{DEFAULT_TOOLTIP_DELAY, Tooltip};
+pub use self::transformed::Transformed;
 pub use self::variable_label::VariableLabel;
-pub use self::zstack::{Alignment, ChildAlignment, HorizontalAlignment, VerticalAlignment, ZStack};
+pub use self::virtual_list::VirtualList;
+pub use self::wheel_adjust::WheelAdjust;
+pub use self::zstack::{
+    Alignment, ChildAlignment, HorizontalAlignment, Transition, VerticalAlignment, ZStack,
+    ZStackSizing,
+};
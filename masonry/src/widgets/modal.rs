@@ -0,0 +1,328 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which shows its child above a scrim, blocking interaction with the widgets beneath.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Affine, Point, Size};
+use vello::peniko::{Brush, Fill};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::theme;
+
+/// Options controlling how a [`Modal`] looks and behaves.
+#[derive(Clone, Debug)]
+pub struct ModalOptions {
+    /// The brush used to dim the area behind the modal's content.
+    pub scrim: Brush,
+    /// Whether the user can close the modal by pressing Escape.
+    ///
+    /// When they do, [`Action::ModalDismissed`] is submitted.
+    pub dismissable: bool,
+}
+
+impl Default for ModalOptions {
+    fn default() -> Self {
+        Self {
+            scrim: theme::SCRIM_COLOR.into(),
+            dismissable: false,
+        }
+    }
+}
+
+/// A widget which, whilst open, shows its child centered above a scrim.
+///
+/// An open modal covers all the space given to it by its parent, and blocks pointer events to
+/// the widgets beneath it.
+/// [Text focus](crate::doc::doc_06_masonry_concepts#text-focus) is trapped inside the modal
+/// whilst it is open, and restored to the previously focused widget when it is closed.
+///
+/// Whilst closed, the modal takes up no space and its child is
+/// [stashed](crate::doc::doc_06_masonry_concepts#stashed).
+///
+/// Modals covering the whole window are usually added with
+/// [`RootWidget::push_overlay`](crate::widgets::RootWidget::push_overlay).
+pub struct Modal<W: ?Sized> {
+    child: WidgetPod<W>,
+    options: ModalOptions,
+    open: bool,
+    /// The widget which was focused before the modal was opened.
+    restore_focus: Option<WidgetId>,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Modal<W> {
+    /// Create a new open `Modal` showing `child`.
+    pub fn new(child: W, options: ModalOptions) -> Self {
+        Self::from_pod(WidgetPod::new(child), options)
+    }
+}
+
+impl<W: Widget + ?Sized> Modal<W> {
+    /// Create a new open `Modal` showing the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>, options: ModalOptions) -> Self {
+        Self {
+            child,
+            options,
+            open: true,
+            restore_focus: None,
+        }
+    }
+
+    /// Builder-style method to set whether the modal is open.
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Whether the modal is currently open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// The options this modal was created with.
+    pub fn options(&self) -> &ModalOptions {
+        &self.options
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Modal<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Open or close the modal.
+    pub fn set_open(this: &mut WidgetMut<'_, Self>, open: bool) {
+        if this.widget.open == open {
+            return;
+        }
+        this.widget.open = open;
+        this.ctx.set_stashed(&mut this.widget.child, !open);
+        if open {
+            this.widget.restore_focus = this.ctx.trap_focus();
+        } else {
+            this.ctx
+                .release_focus_trap(this.widget.restore_focus.take());
+        }
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Set the options controlling how the modal looks and behaves.
+    pub fn set_options(this: &mut WidgetMut<'_, Self>, options: ModalOptions) {
+        this.widget.options = options;
+        this.ctx.request_render();
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Modal<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::KeyboardKey(key_event, _) = event {
+            if self.open
+                && self.options.dismissable
+                && key_event.state.is_pressed()
+                && key_event.logical_key == Key::Named(NamedKey::Escape)
+            {
+                self.open = false;
+                ctx.set_stashed(&mut self.child, true);
+                ctx.release_focus_trap(self.restore_focus.take());
+                ctx.request_layout();
+                ctx.request_accessibility_update();
+                ctx.submit_action(Action::ModalDismissed);
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            if self.open {
+                self.restore_focus = ctx.trap_focus();
+            } else {
+                ctx.set_stashed(&mut self.child, true);
+            }
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        if !self.open {
+            ctx.skip_layout(&mut self.child);
+            return Size::ZERO;
+        }
+
+        let child_size = ctx.run_layout(&mut self.child, &bc.loosen());
+        let max = bc.max();
+        let size = Size::new(
+            if max.width.is_finite() {
+                max.width
+            } else {
+                child_size.width
+            },
+            if max.height.is_finite() {
+                max.height
+            } else {
+                child_size.height
+            },
+        );
+        let origin = Point::new(
+            ((size.width - child_size.width) / 2.).max(0.),
+            ((size.height - child_size.height) / 2.).max(0.),
+        );
+        ctx.place_child(&mut self.child, origin);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if !self.open {
+            return;
+        }
+        let rect = ctx.size().to_rect();
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            &self.options.scrim,
+            None,
+            &rect,
+        );
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Dialog
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        if self.open {
+            node.set_modal();
+        } else {
+            node.set_hidden();
+        }
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Modal", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::PointerButton;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Button, Flex, RootWidget};
+
+    fn push_button_overlay(harness: &mut TestHarness, button_id: WidgetId) {
+        harness.edit_root_widget(|mut root| {
+            let mut root = root.downcast::<RootWidget<Flex>>();
+            let button = WidgetPod::new_with_id(Button::new("Close"), button_id).erased();
+            RootWidget::push_overlay(&mut root, button, ModalOptions::default());
+        });
+    }
+
+    #[test]
+    fn blocks_pointer_and_traps_focus() {
+        let [below_id, overlay_button_id] = widget_ids();
+        let widget = RootWidget::new(Flex::column().with_child_id(Button::new("Below"), below_id));
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+
+        push_button_overlay(&mut harness, overlay_button_id);
+
+        // Clicking the button beneath the modal hits the scrim instead.
+        let below_center = harness.get_widget(below_id).ctx().bounding_rect().center();
+        harness.mouse_move(below_center);
+        assert!(!harness.get_widget(below_id).ctx().is_hovered());
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action(), None);
+
+        // Focus can't be moved outside of the modal.
+        harness.focus_on(Some(below_id));
+        assert_ne!(harness.focused_widget_id(), Some(below_id));
+
+        harness.focus_on(Some(overlay_button_id));
+        assert_eq!(harness.focused_widget_id(), Some(overlay_button_id));
+    }
+
+    #[test]
+    fn focus_restored_on_pop() {
+        let [below_id, overlay_button_id] = widget_ids();
+        let widget = RootWidget::new(Flex::column().with_child_id(Button::new("Below"), below_id));
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+        harness.focus_on(Some(below_id));
+
+        push_button_overlay(&mut harness, overlay_button_id);
+        assert_ne!(harness.focused_widget_id(), Some(below_id));
+
+        harness.edit_root_widget(|mut root| {
+            let mut root = root.downcast::<RootWidget<Flex>>();
+            RootWidget::pop_overlay(&mut root);
+        });
+        assert_eq!(harness.focused_widget_id(), Some(below_id));
+
+        // The button beneath can be clicked again.
+        harness.mouse_click_on(below_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ButtonPressed(PointerButton::Primary), below_id))
+        );
+    }
+
+    #[test]
+    fn closed_modal_is_transparent() {
+        let [below_id, modal_id] = widget_ids();
+        let modal = Modal::new(Button::new("Inside"), ModalOptions::default()).with_open(false);
+        let widget = Flex::column()
+            .with_child_id(Button::new("Below"), below_id)
+            .with_child_id(modal, modal_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 200.));
+
+        assert_eq!(harness.get_widget(modal_id).ctx().size(), Size::ZERO);
+        harness.focus_on(Some(below_id));
+        assert_eq!(harness.focused_widget_id(), Some(below_id));
+    }
+}
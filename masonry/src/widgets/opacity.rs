@@ -0,0 +1,251 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which makes its child translucent.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod, WidgetRef, find_widget_at_pos,
+};
+use crate::kurbo::{Point, Size};
+
+/// A widget which paints its child with the given alpha, from 0.0 (invisible) to 1.0 (opaque).
+///
+/// The child is still laid out when it is invisible, and by default can still be
+/// interacted with; use [`with_interactive_when_transparent`](Self::with_interactive_when_transparent)
+/// to change this.
+/// Whilst the alpha is less than 1.0, the child is painted into a layer clipped to the
+/// bounds of this widget, including the child's paint insets.
+/// At 0.0, the child isn't drawn at all.
+pub struct Opacity<W: ?Sized> {
+    child: WidgetPod<W>,
+    alpha: f64,
+    interactive_when_transparent: bool,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Opacity<W> {
+    /// Create a new `Opacity` which paints `child` with `alpha`.
+    pub fn new(child: W, alpha: f64) -> Self {
+        Self::from_pod(WidgetPod::new(child), alpha)
+    }
+}
+
+impl<W: Widget + ?Sized> Opacity<W> {
+    /// Create a new `Opacity` which paints the child in `child` with `alpha`.
+    pub fn from_pod(child: WidgetPod<W>, alpha: f64) -> Self {
+        Self {
+            child,
+            alpha: alpha.clamp(0.0, 1.0),
+            interactive_when_transparent: true,
+        }
+    }
+
+    /// Builder-style method for setting whether the child receives pointer events
+    /// when the alpha is 0.0.
+    ///
+    /// This is `true` by default.
+    pub fn with_interactive_when_transparent(mut self, interactive: bool) -> Self {
+        self.interactive_when_transparent = interactive;
+        self
+    }
+
+    /// The alpha the child is painted with.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Opacity<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the alpha the child is painted with.
+    ///
+    /// This only requires the widget to be painted again, not laid out.
+    pub fn set_alpha(this: &mut WidgetMut<'_, Self>, alpha: f64) {
+        this.widget.alpha = alpha.clamp(0.0, 1.0);
+        this.ctx.set_opacity(this.widget.alpha);
+    }
+
+    /// Set whether the child receives pointer events when the alpha is 0.0.
+    ///
+    /// See also [`with_interactive_when_transparent`](Self::with_interactive_when_transparent).
+    pub fn set_interactive_when_transparent(this: &mut WidgetMut<'_, Self>, interactive: bool) {
+        this.widget.interactive_when_transparent = interactive;
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Opacity<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            // The layer itself is pushed by the paint pass.
+            ctx.set_opacity(self.alpha);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn find_widget_at_pos<'c>(
+        &'c self,
+        ctx: QueryCtx<'c>,
+        props: PropertiesRef<'c>,
+        pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        if self.alpha <= 0.0 && !self.interactive_when_transparent {
+            return None;
+        }
+        find_widget_at_pos(
+            &WidgetRef {
+                widget: self,
+                properties: props,
+                ctx,
+            },
+            pos,
+        )
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Opacity", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::palette;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Button, Flex, SizedBox};
+
+    #[test]
+    fn transparent_child_is_laid_out() {
+        let [child_id] = widget_ids();
+        let child = SizedBox::empty()
+            .width(20.)
+            .height(20.)
+            .background(palette::css::RED);
+        let widget = Flex::column().with_child(Opacity::from_pod(
+            WidgetPod::new_with_id(child, child_id),
+            0.0,
+        ));
+
+        let window_size = Size::new(40., 40.);
+        let mut harness = TestHarness::create_with_size(widget, window_size);
+        assert_eq!(
+            harness.get_widget(child_id).ctx().size(),
+            Size::new(20., 20.)
+        );
+        assert!(
+            !harness
+                .get_widget(child_id)
+                .ctx()
+                .local_layout_rect()
+                .is_zero_area()
+        );
+
+        let empty = TestHarness::create_with_size(Flex::column(), window_size).render();
+        // We don't use assert_eq because we don't want rich assert
+        assert!(harness.render() == empty);
+
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            let mut opacity = Flex::child_mut(&mut flex, 0).unwrap();
+            Opacity::<SizedBox>::set_alpha(&mut opacity.downcast(), 1.0);
+        });
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel,
+        // so the images can't differ.
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            assert!(harness.render() != empty);
+        }
+    }
+
+    #[test]
+    fn transparent_child_interaction() {
+        let [button_id] = widget_ids();
+        let widget = Flex::column().with_child(Opacity::new(
+            SizedBox::new_with_id(Button::new("Hidden"), button_id),
+            0.0,
+        ));
+
+        let mut harness = TestHarness::create(widget);
+        harness.mouse_move_to(button_id);
+        assert!(harness.get_widget(button_id).ctx().is_hovered());
+
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            let mut opacity = Flex::child_mut(&mut flex, 0).unwrap();
+            Opacity::<SizedBox>::set_interactive_when_transparent(&mut opacity.downcast(), false);
+        });
+        harness.mouse_move_to(button_id);
+        assert!(!harness.get_widget(button_id).ctx().is_hovered());
+    }
+}
@@ -4,6 +4,7 @@
 #![allow(missing_docs)]
 
 use std::ops::Range;
+use std::time::Duration;
 
 use accesskit::{Node, Role};
 use smallvec::{SmallVec, smallvec};
@@ -12,11 +13,66 @@ use vello::Scene;
 use vello::kurbo::{Point, Rect, Size, Vec2};
 
 use crate::core::{
-    AccessCtx, AccessEvent, BoxConstraints, ComposeCtx, EventCtx, FromDynWidget, LayoutCtx,
-    PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, AccessEvent, Action, BoxConstraints, ComposeCtx, EventCtx, FromDynWidget, LayoutCtx,
+    PaintCtx, PointerEvent, PortalAction, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
-use crate::widgets::{Axis, ScrollBar};
+use crate::theme;
+use crate::widgets::{Axis, ScrollBar, ScrollBarStyle};
+
+/// How long overlay scrollbars take to fade in or out.
+const SCROLLBAR_FADE_TIME: Duration = Duration::from_millis(200);
+/// How quickly smooth scrolling catches up with the wheel.
+///
+/// Each second, the distance still to scroll shrinks by a factor of `e` this many times.
+const SMOOTH_SCROLL_RATE: f64 = 20.0;
+/// The speed, in logical pixels per second, below which a fling stops.
+const MIN_FLING_SPEED: f64 = 10.0;
+
+/// The state of animated scrolling in a [`Portal`].
+#[derive(Default, Debug, Clone, Copy)]
+struct ScrollMotion {
+    /// How much further smooth scrolling still has to move the viewport.
+    pending: Vec2,
+    /// How far the wheel has scrolled since the last animation frame.
+    recent: Vec2,
+    /// The speed of the current wheel gesture or fling, in logical pixels per second.
+    velocity: Vec2,
+    /// Whether the wheel gesture has ended, so the viewport is coasting at `velocity`.
+    flinging: bool,
+}
+
+impl ScrollMotion {
+    fn is_active(&self) -> bool {
+        self.pending != Vec2::ZERO || self.recent != Vec2::ZERO || self.velocity != Vec2::ZERO
+    }
+}
+
+/// The axes along which a [`Portal`] can be scrolled.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAxes {
+    /// The content can only be scrolled up and down.
+    #[default]
+    Vertical,
+    /// The content can only be scrolled left and right.
+    ///
+    /// The mouse wheel scrolls horizontally.
+    Horizontal,
+    /// The content can be scrolled along both axes.
+    ///
+    /// The mouse wheel scrolls horizontally whilst Shift is held.
+    Both,
+}
+
+impl ScrollAxes {
+    fn horizontal(self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+
+    fn vertical(self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+}
 
 // TODO - refactor - see https://github.com/linebender/xilem/issues/366
 // TODO - rename "Portal" to "ScrollPortal"?
@@ -34,10 +90,29 @@ pub struct Portal<W: Widget + ?Sized> {
     constrain_horizontal: bool,
     constrain_vertical: bool,
     must_fill: bool,
+    scroll_axes: ScrollAxes,
     scrollbar_horizontal: WidgetPod<ScrollBar>,
     scrollbar_horizontal_visible: bool,
     scrollbar_vertical: WidgetPod<ScrollBar>,
     scrollbar_vertical_visible: bool,
+    scrollbar_style: ScrollBarStyle,
+    overlay_scrollbars: bool,
+    /// How opaque the scrollbars are, from 0.0 to 1.0.
+    ///
+    /// This is always 1.0 unless `overlay_scrollbars` is true.
+    scrollbar_opacity: f64,
+    /// How long overlay scrollbars have been shown without any scrolling or hovering,
+    /// or `None` if they are fading out.
+    scrollbar_idle: Option<Duration>,
+    kinetic_scrolling: bool,
+    kinetic_deceleration: f64,
+    smooth_scrolling: bool,
+    motion: ScrollMotion,
+    /// The sizes from the last layout pass.
+    portal_size: Size,
+    content_size: Size,
+    /// The last scroll state sent as a [`PortalAction`], or `None` before the first compose.
+    reported_scroll: Option<PortalAction>,
 }
 
 // --- MARK: BUILDERS ---
@@ -55,11 +130,23 @@ impl<W: Widget + ?Sized> Portal<W> {
             constrain_horizontal: false,
             constrain_vertical: false,
             must_fill: false,
+            scroll_axes: ScrollAxes::Vertical,
             // TODO - remove (TODO: why?)
             scrollbar_horizontal: WidgetPod::new(ScrollBar::new(Axis::Horizontal, 1.0, 1.0)),
             scrollbar_horizontal_visible: false,
             scrollbar_vertical: WidgetPod::new(ScrollBar::new(Axis::Vertical, 1.0, 1.0)),
             scrollbar_vertical_visible: false,
+            scrollbar_style: ScrollBarStyle::default(),
+            overlay_scrollbars: false,
+            scrollbar_opacity: 1.0,
+            scrollbar_idle: None,
+            kinetic_scrolling: false,
+            kinetic_deceleration: theme::KINETIC_SCROLL_DECELERATION,
+            smooth_scrolling: false,
+            motion: ScrollMotion::default(),
+            portal_size: Size::ZERO,
+            content_size: Size::ZERO,
+            reported_scroll: None,
         }
     }
 
@@ -67,6 +154,25 @@ impl<W: Widget + ?Sized> Portal<W> {
         self.viewport_pos
     }
 
+    /// How far the viewport has been scrolled along each axis, from 0.0 at the start
+    /// of the content to 1.0 at the end.
+    ///
+    /// This is 0.0 along axes where the content fits inside the portal.
+    pub fn scroll_progress(&self) -> Vec2 {
+        let range = self.content_size - self.portal_size;
+        let progress = |pos: f64, range: f64| {
+            if range > 0.0 {
+                (pos / range).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+        Vec2::new(
+            progress(self.viewport_pos.x, range.width),
+            progress(self.viewport_pos.y, range.height),
+        )
+    }
+
     // TODO - rewrite doc
     /// Builder-style method for deciding whether to constrain the child vertically.
     ///
@@ -102,6 +208,84 @@ impl<W: Widget + ?Sized> Portal<W> {
         self.must_fill = must_fill;
         self
     }
+
+    /// Builder-style method to set the axes along which the content can be scrolled.
+    ///
+    /// The default is [`ScrollAxes::Vertical`].
+    /// Along other axes, the viewport stays at the start of the content and no
+    /// scrollbar is shown.
+    pub fn scroll_axes(mut self, scroll_axes: ScrollAxes) -> Self {
+        self.scroll_axes = scroll_axes;
+        self
+    }
+
+    /// Builder-style method to set how the scrollbars are drawn.
+    pub fn scrollbar_style(mut self, style: ScrollBarStyle) -> Self {
+        self.scrollbar_horizontal =
+            WidgetPod::new(ScrollBar::new(Axis::Horizontal, 1.0, 1.0).with_style(style.clone()));
+        self.scrollbar_vertical =
+            WidgetPod::new(ScrollBar::new(Axis::Vertical, 1.0, 1.0).with_style(style.clone()));
+        self.scrollbar_style = style;
+        self
+    }
+
+    /// Builder-style method to set whether the scrollbars are overlay scrollbars.
+    ///
+    /// The default is `false`, where the scrollbars are always shown whilst the content can
+    /// be scrolled.
+    /// Overlay scrollbars are hidden until the content is scrolled or the pointer is over
+    /// the portal. They then fade out once neither has happened for
+    /// [`SCROLLBAR_FADE_DELAY`](theme::SCROLLBAR_FADE_DELAY) milliseconds.
+    /// Hidden scrollbars can't be dragged, and let the pointer through to the content.
+    pub fn overlay_scrollbars(mut self, overlay: bool) -> Self {
+        self.overlay_scrollbars = overlay;
+        self.scrollbar_opacity = if overlay { 0.0 } else { 1.0 };
+        self
+    }
+
+    /// Builder-style method to set whether wheel scrolling keeps going after the gesture ends.
+    ///
+    /// The default is `false`.
+    /// When enabled, the speed of wheel and touchpad scrolling is tracked, and once the
+    /// scrolling stops, the viewport keeps moving at that speed, slowing down according
+    /// to [`kinetic_deceleration`](Self::kinetic_deceleration).
+    /// Any new scrolling or pressing the pointer stops the fling.
+    pub fn kinetic_scrolling(mut self, kinetic: bool) -> Self {
+        self.kinetic_scrolling = kinetic;
+        self
+    }
+
+    /// Builder-style method to set how quickly kinetic scrolling slows down.
+    ///
+    /// Each second, the speed of a fling shrinks by a factor of `e` this many times.
+    /// The default is [`KINETIC_SCROLL_DECELERATION`](theme::KINETIC_SCROLL_DECELERATION).
+    ///
+    /// # Panics
+    ///
+    /// If `deceleration` is negative or not finite.
+    #[track_caller]
+    pub fn kinetic_deceleration(mut self, deceleration: f64) -> Self {
+        validate_deceleration(deceleration);
+        self.kinetic_deceleration = deceleration;
+        self
+    }
+
+    /// Builder-style method to set whether scrolling with the wheel is animated.
+    ///
+    /// The default is `false`, where each wheel tick moves the viewport straight away.
+    /// When enabled, the viewport glides to its new position over a few frames.
+    /// Scrolling requested by assistive technologies is animated in the same way.
+    pub fn smooth_scrolling(mut self, smooth: bool) -> Self {
+        self.smooth_scrolling = smooth;
+        self
+    }
+}
+
+#[track_caller]
+fn validate_deceleration(deceleration: f64) {
+    if !(deceleration.is_finite() && deceleration >= 0.0) {
+        panic!("Invalid `deceleration` {deceleration}, expected a non-negative finite value.");
+    }
 }
 
 fn compute_pan_range(mut viewport: Range<f64>, target: Range<f64>) -> Range<f64> {
@@ -131,15 +315,42 @@ fn compute_pan_range(mut viewport: Range<f64>, target: Range<f64>) -> Range<f64>
 }
 
 impl<W: Widget + ?Sized> Portal<W> {
-    // TODO - rename
-    fn set_viewport_pos_raw(&mut self, portal_size: Size, content_size: Size, pos: Point) -> bool {
+    /// Show overlay scrollbars, restarting the delay before they fade out.
+    ///
+    /// Returns whether an animation frame should be requested.
+    fn wake_scrollbars(&mut self) -> bool {
+        if !self.overlay_scrollbars {
+            return false;
+        }
+        self.scrollbar_idle = Some(Duration::ZERO);
+        true
+    }
+
+    /// The closest position to `pos` which the viewport can be moved to.
+    fn clamp_viewport_pos(&self, portal_size: Size, content_size: Size, pos: Point) -> Point {
         let viewport_max_pos =
             (content_size - portal_size).clamp(Size::ZERO, Size::new(f64::INFINITY, f64::INFINITY));
-        let pos = Point::new(
+        let viewport_max_pos = Size::new(
+            if self.scroll_axes.horizontal() {
+                viewport_max_pos.width
+            } else {
+                0.0
+            },
+            if self.scroll_axes.vertical() {
+                viewport_max_pos.height
+            } else {
+                0.0
+            },
+        );
+        Point::new(
             pos.x.clamp(0.0, viewport_max_pos.width),
             pos.y.clamp(0.0, viewport_max_pos.height),
-        );
+        )
+    }
 
+    // TODO - rename
+    fn set_viewport_pos_raw(&mut self, portal_size: Size, content_size: Size, pos: Point) -> bool {
+        let pos = self.clamp_viewport_pos(portal_size, content_size, pos);
         if (pos - self.viewport_pos).hypot2() > 1e-12 {
             self.viewport_pos = pos;
             true
@@ -211,7 +422,92 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
         this.ctx.request_layout();
     }
 
+    /// Set the axes along which the content can be scrolled.
+    ///
+    /// See [`scroll_axes`](Portal::scroll_axes) for more details.
+    pub fn set_scroll_axes(this: &mut WidgetMut<'_, Self>, scroll_axes: ScrollAxes) {
+        this.widget.scroll_axes = scroll_axes;
+        // The viewport is clamped to the new axes in layout.
+        this.ctx.request_layout();
+        // The supported scroll actions depend on the axes.
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Set how the scrollbars are drawn.
+    pub fn set_scrollbar_style(this: &mut WidgetMut<'_, Self>, style: ScrollBarStyle) {
+        ScrollBar::set_style(&mut Self::horizontal_scrollbar_mut(this), style.clone());
+        ScrollBar::set_style(&mut Self::vertical_scrollbar_mut(this), style.clone());
+        this.widget.scrollbar_style = style;
+        // The scrollbars' thickness affects where they are placed.
+        this.ctx.request_layout();
+    }
+
+    /// Set whether the scrollbars are overlay scrollbars.
+    ///
+    /// See [`overlay_scrollbars`](Portal::overlay_scrollbars) for more details.
+    pub fn set_overlay_scrollbars(this: &mut WidgetMut<'_, Self>, overlay: bool) {
+        if this.widget.overlay_scrollbars == overlay {
+            return;
+        }
+        this.widget.overlay_scrollbars = overlay;
+        this.widget.scrollbar_idle = None;
+        let opacity = if overlay { 0.0 } else { 1.0 };
+        Self::set_scrollbar_opacity(this, opacity);
+    }
+
+    /// Set whether wheel scrolling keeps going after the gesture ends.
+    ///
+    /// See [`kinetic_scrolling`](Portal::kinetic_scrolling) for more details.
+    pub fn set_kinetic_scrolling(this: &mut WidgetMut<'_, Self>, kinetic: bool) {
+        this.widget.kinetic_scrolling = kinetic;
+        if !kinetic {
+            let motion = &mut this.widget.motion;
+            motion.recent = Vec2::ZERO;
+            motion.velocity = Vec2::ZERO;
+            motion.flinging = false;
+        }
+    }
+
+    /// Set how quickly kinetic scrolling slows down.
+    ///
+    /// See [`kinetic_deceleration`](Portal::kinetic_deceleration) for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `deceleration` is negative or not finite.
+    #[track_caller]
+    pub fn set_kinetic_deceleration(this: &mut WidgetMut<'_, Self>, deceleration: f64) {
+        validate_deceleration(deceleration);
+        this.widget.kinetic_deceleration = deceleration;
+    }
+
+    /// Set whether scrolling with the wheel is animated.
+    ///
+    /// If smooth scrolling is turned off part way through an animation, the viewport
+    /// jumps to where it was heading.
+    pub fn set_smooth_scrolling(this: &mut WidgetMut<'_, Self>, smooth: bool) {
+        this.widget.smooth_scrolling = smooth;
+        let pending = this.widget.motion.pending;
+        if !smooth && pending != Vec2::ZERO {
+            Self::pan_viewport_by(this, pending);
+        }
+    }
+
+    fn set_scrollbar_opacity(this: &mut WidgetMut<'_, Self>, opacity: f64) {
+        this.widget.scrollbar_opacity = opacity;
+        let mut scrollbar = Self::horizontal_scrollbar_mut(this);
+        scrollbar.widget.opacity = opacity;
+        scrollbar.ctx.request_render();
+        scrollbar.ctx.request_accessibility_update();
+        let mut scrollbar = Self::vertical_scrollbar_mut(this);
+        scrollbar.widget.opacity = opacity;
+        scrollbar.ctx.request_render();
+        scrollbar.ctx.request_accessibility_update();
+    }
+
     pub fn set_viewport_pos(this: &mut WidgetMut<'_, Self>, position: Point) -> bool {
+        // Moving the viewport directly stops any scrolling animation.
+        this.widget.motion = ScrollMotion::default();
         let portal_size = this.ctx.local_layout_rect().size();
         let content_size = this
             .ctx
@@ -231,10 +527,21 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
             Self::vertical_scrollbar_mut(this).widget.cursor_progress = progress_y;
             Self::vertical_scrollbar_mut(this).ctx.request_render();
             this.ctx.request_layout();
+            if this.widget.wake_scrollbars() {
+                this.ctx.request_anim_frame();
+            }
         }
         pos_changed
     }
 
+    /// Scroll so that the top left of the viewport is `offset` from the top left of the content.
+    ///
+    /// The offset is clamped so that the viewport stays within the content.
+    /// Returns whether the viewport moved.
+    pub fn scroll_to_offset(this: &mut WidgetMut<'_, Self>, offset: Vec2) -> bool {
+        Self::set_viewport_pos(this, offset.to_point())
+    }
+
     pub fn pan_viewport_by(this: &mut WidgetMut<'_, Self>, translation: Vec2) -> bool {
         Self::set_viewport_pos(this, this.widget.viewport_pos + translation)
     }
@@ -258,6 +565,169 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
     }
 }
 
+impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
+    /// Move the viewport to `pos` whilst handling an event, updating the scrollbars to match.
+    ///
+    /// Returns whether the viewport moved.
+    fn move_viewport_in_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        portal_size: Size,
+        content_size: Size,
+        pos: Point,
+    ) -> bool {
+        if !self.set_viewport_pos_raw(portal_size, content_size, pos) {
+            return false;
+        }
+        ctx.request_compose();
+        if self.wake_scrollbars() {
+            ctx.request_anim_frame();
+        }
+
+        let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_vertical);
+        scrollbar.widget().cursor_progress =
+            self.viewport_pos.y / (content_size - portal_size).height;
+        scrollbar.ctx().request_render();
+
+        std::mem::drop(scrollbar);
+
+        let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_horizontal);
+        scrollbar.widget().cursor_progress =
+            self.viewport_pos.x / (content_size - portal_size).width;
+        scrollbar.ctx().request_render();
+        true
+    }
+
+    /// Move the viewport to `pos` during an update, updating the scrollbars to match.
+    ///
+    /// Returns whether the viewport moved.
+    // TODO - This is the same as `move_viewport_in_event`, but the context types differ.
+    fn move_viewport_in_update(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        portal_size: Size,
+        content_size: Size,
+        pos: Point,
+    ) -> bool {
+        if !self.set_viewport_pos_raw(portal_size, content_size, pos) {
+            return false;
+        }
+        ctx.request_compose();
+        if self.wake_scrollbars() {
+            ctx.request_anim_frame();
+        }
+
+        let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_vertical);
+        scrollbar.widget().cursor_progress =
+            self.viewport_pos.y / (content_size - portal_size).height;
+        scrollbar.ctx().request_render();
+
+        std::mem::drop(scrollbar);
+
+        let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_horizontal);
+        scrollbar.widget().cursor_progress =
+            self.viewport_pos.x / (content_size - portal_size).width;
+        scrollbar.ctx().request_render();
+        true
+    }
+
+    /// Advance smooth scrolling and kinetic scrolling by `interval`.
+    fn animate_scrolling(&mut self, ctx: &mut UpdateCtx, interval: Duration) {
+        // The first frame of an animation has no interval, so nothing can be measured yet.
+        if interval.is_zero() {
+            ctx.request_anim_frame();
+            return;
+        }
+        let secs = interval.as_secs_f64();
+        let motion = &mut self.motion;
+
+        let mut delta = Vec2::ZERO;
+        if motion.pending != Vec2::ZERO {
+            let step = if motion.pending.hypot() < 0.5 {
+                motion.pending
+            } else {
+                motion.pending * (1.0 - (-SMOOTH_SCROLL_RATE * secs).exp())
+            };
+            motion.pending -= step;
+            delta += step;
+        }
+        if motion.flinging {
+            motion.velocity *= (-self.kinetic_deceleration * secs).exp();
+            delta += motion.velocity * secs;
+            if motion.velocity.hypot() < MIN_FLING_SPEED {
+                motion.velocity = Vec2::ZERO;
+                motion.flinging = false;
+            }
+        } else if motion.recent != Vec2::ZERO {
+            motion.velocity = motion.recent / secs;
+            motion.recent = Vec2::ZERO;
+        } else if motion.velocity != Vec2::ZERO {
+            // The wheel didn't scroll since the last frame, so the gesture has ended.
+            motion.flinging = true;
+        }
+
+        if delta != Vec2::ZERO {
+            let portal_size = ctx.size();
+            let content_size = ctx
+                .get_raw_ref(&mut self.child)
+                .ctx()
+                .local_layout_rect()
+                .size();
+            let pos = self.viewport_pos + delta;
+            if !self.move_viewport_in_update(ctx, portal_size, content_size, pos) {
+                // We've reached the edge of the content.
+                self.motion = ScrollMotion::default();
+            }
+        }
+        if self.motion.is_active() {
+            ctx.request_anim_frame();
+        }
+    }
+
+    /// Advance the fade of overlay scrollbars by `interval`.
+    fn animate_scrollbar_fade(&mut self, ctx: &mut UpdateCtx, interval: Duration) {
+        let step = interval.as_secs_f64() / SCROLLBAR_FADE_TIME.as_secs_f64();
+        let old_opacity = self.scrollbar_opacity;
+        let keep_animating = match self.scrollbar_idle {
+            Some(_) if ctx.has_hovered() => {
+                // The delay only starts once the pointer leaves.
+                self.scrollbar_idle = Some(Duration::ZERO);
+                self.scrollbar_opacity = (self.scrollbar_opacity + step).min(1.0);
+                self.scrollbar_opacity < 1.0
+            }
+            Some(idle) => {
+                let idle = idle + interval;
+                let fade_delay = Duration::from_millis(theme::SCROLLBAR_FADE_DELAY);
+                self.scrollbar_idle = (idle < fade_delay).then_some(idle);
+                self.scrollbar_opacity = (self.scrollbar_opacity + step).min(1.0);
+                true
+            }
+            None => {
+                self.scrollbar_opacity = (self.scrollbar_opacity - step).max(0.0);
+                self.scrollbar_opacity > 0.0
+            }
+        };
+        if keep_animating {
+            ctx.request_anim_frame();
+        }
+        if self.scrollbar_opacity == old_opacity {
+            return;
+        }
+
+        // Fading only changes how the scrollbars are painted, so they don't need a new layout.
+        let opacity = self.scrollbar_opacity;
+        let shown_changed = (old_opacity > 0.0) != (opacity > 0.0);
+        for scrollbar in [&mut self.scrollbar_horizontal, &mut self.scrollbar_vertical] {
+            let mut scrollbar = ctx.get_raw_mut(scrollbar);
+            scrollbar.widget().opacity = opacity;
+            scrollbar.ctx().request_render();
+            if shown_changed {
+                scrollbar.ctx().request_accessibility_update();
+            }
+        }
+    }
+}
+
 // --- MARK: IMPL WIDGET ---
 impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
     fn on_pointer_event(
@@ -276,16 +746,39 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
             .size();
 
         match event {
-            PointerEvent::MouseWheel(delta, _) => {
-                let delta = Vec2::new(delta.x * -SCROLLING_SPEED, delta.y * -SCROLLING_SPEED);
-                self.set_viewport_pos_raw(portal_size, content_size, self.viewport_pos + delta);
-                ctx.request_compose();
-
-                // TODO - horizontal scrolling?
-                let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_vertical);
-                scrollbar.widget().cursor_progress =
-                    self.viewport_pos.y / (content_size - portal_size).height;
-                scrollbar.ctx().request_render();
+            PointerEvent::MouseWheel(delta, state) => {
+                let mut delta = Vec2::new(delta.x * -SCROLLING_SPEED, delta.y * -SCROLLING_SPEED);
+                // Most mice only have a vertical wheel, so it is used for horizontal scrolling
+                // if that's the only way the content scrolls, or whilst Shift is held.
+                let horizontal_only = self.scroll_axes == ScrollAxes::Horizontal;
+                if delta.x == 0.0 && (horizontal_only || state.mods.state().shift_key()) {
+                    delta = Vec2::new(delta.y, 0.0);
+                }
+
+                // New scrolling takes over from a fling, and its speed is measured afresh.
+                self.motion.flinging = false;
+                self.motion.velocity = Vec2::ZERO;
+                if self.kinetic_scrolling {
+                    self.motion.recent += delta;
+                    ctx.request_anim_frame();
+                }
+
+                // Smooth scrolling continues from where the viewport is heading.
+                let from = self.viewport_pos + self.motion.pending;
+                let target = self.clamp_viewport_pos(portal_size, content_size, from + delta);
+                if target != from {
+                    ctx.record_wheel_scroll();
+                }
+                if self.smooth_scrolling {
+                    self.motion.pending = target - self.viewport_pos;
+                    ctx.request_anim_frame();
+                } else {
+                    self.move_viewport_in_event(ctx, portal_size, content_size, target);
+                }
+            }
+            PointerEvent::PointerDown(_, _) => {
+                // Touching the content stops it from scrolling.
+                self.motion = ScrollMotion::default();
             }
             _ => (),
         }
@@ -325,7 +818,11 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
         }
 
         if scrollbar_moved {
+            self.motion = ScrollMotion::default();
             ctx.request_compose();
+            if self.wake_scrollbars() {
+                ctx.request_anim_frame();
+            }
         }
     }
 
@@ -338,13 +835,48 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
     ) {
     }
 
-    // TODO - Handle scroll-related events?
     fn on_access_event(
         &mut self,
-        _ctx: &mut EventCtx,
+        ctx: &mut EventCtx,
         _props: &mut PropertiesMut<'_>,
-        _event: &AccessEvent,
+        event: &AccessEvent,
     ) {
+        let portal_size = ctx.size();
+        let target = match (event.action, &event.data) {
+            // These scroll by a page.
+            (accesskit::Action::ScrollUp, _) => {
+                self.viewport_pos - Vec2::new(0.0, portal_size.height)
+            }
+            (accesskit::Action::ScrollDown, _) => {
+                self.viewport_pos + Vec2::new(0.0, portal_size.height)
+            }
+            (accesskit::Action::ScrollLeft, _) => {
+                self.viewport_pos - Vec2::new(portal_size.width, 0.0)
+            }
+            (accesskit::Action::ScrollRight, _) => {
+                self.viewport_pos + Vec2::new(portal_size.width, 0.0)
+            }
+            (
+                accesskit::Action::SetScrollOffset,
+                Some(accesskit::ActionData::SetScrollOffset(offset)),
+            ) => Point::new(offset.x, offset.y),
+            _ => return,
+        };
+        let content_size = ctx
+            .get_raw_ref(&mut self.child)
+            .ctx()
+            .local_layout_rect()
+            .size();
+        let target = self.clamp_viewport_pos(portal_size, content_size, target);
+
+        self.motion = ScrollMotion::default();
+        if self.smooth_scrolling {
+            self.motion.pending = target - self.viewport_pos;
+            ctx.request_anim_frame();
+        } else {
+            self.move_viewport_in_event(ctx, portal_size, content_size, target);
+        }
+        ctx.set_handled();
     }
 
     fn register_children(&mut self, ctx: &mut RegisterCtx) {
@@ -363,7 +895,12 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
                     .local_layout_rect()
                     .size();
 
-                self.pan_viewport_to_raw(portal_size, content_size, *target);
+                self.motion = ScrollMotion::default();
+                if self.pan_viewport_to_raw(portal_size, content_size, *target)
+                    && self.wake_scrollbars()
+                {
+                    ctx.request_anim_frame();
+                }
                 ctx.request_compose();
 
                 // TODO - There's a lot of code here that's duplicated from the `MouseWheel`
@@ -382,10 +919,32 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
                     self.viewport_pos.x / (content_size - portal_size).width;
                 scrollbar.ctx().request_render();
             }
+            // Overlay scrollbars are shown whilst the pointer is over the portal,
+            // and start fading out once it leaves.
+            Update::ChildHoveredChanged(_) => {
+                if self.wake_scrollbars() {
+                    ctx.request_anim_frame();
+                }
+            }
             _ => {}
         }
     }
 
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let interval = Duration::from_nanos(interval);
+        if self.motion.is_active() {
+            self.animate_scrolling(ctx, interval);
+        }
+        if self.overlay_scrollbars {
+            self.animate_scrollbar_fade(ctx, interval);
+        }
+    }
+
     fn layout(
         &mut self,
         ctx: &mut LayoutCtx,
@@ -400,6 +959,8 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
 
         let content_size = ctx.run_layout(&mut self.child, &child_bc);
         let portal_size = bc.constrain(content_size);
+        self.portal_size = portal_size;
+        self.content_size = content_size;
 
         // TODO - document better
         // Recompute the portal offset for the new layout
@@ -410,10 +971,21 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
 
         ctx.place_child(&mut self.child, Point::ZERO);
 
-        self.scrollbar_horizontal_visible =
-            !self.constrain_horizontal && portal_size.width < content_size.width;
-        self.scrollbar_vertical_visible =
-            !self.constrain_vertical && portal_size.height < content_size.height;
+        self.scrollbar_horizontal_visible = self.scroll_axes.horizontal()
+            && !self.constrain_horizontal
+            && portal_size.width < content_size.width;
+        self.scrollbar_vertical_visible = self.scroll_axes.vertical()
+            && !self.constrain_vertical
+            && portal_size.height < content_size.height;
+
+        // When both scrollbars are visible, they stop short of the corner where they would
+        // overlap, so that it is clear which one is under the pointer.
+        let scrollbar_thickness = self.scrollbar_style.thickness();
+        let corner = if self.scrollbar_horizontal_visible && self.scrollbar_vertical_visible {
+            scrollbar_thickness
+        } else {
+            0.0
+        };
 
         ctx.set_stashed(
             &mut self.scrollbar_vertical,
@@ -428,10 +1000,15 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
             let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_horizontal);
             scrollbar.widget().portal_size = portal_size.width;
             scrollbar.widget().content_size = content_size.width;
+            scrollbar.widget().opacity = self.scrollbar_opacity;
             // TODO - request paint for scrollbar?
             std::mem::drop(scrollbar);
 
-            let scrollbar_size = ctx.run_layout(&mut self.scrollbar_horizontal, bc);
+            let scrollbar_bc = BoxConstraints::new(
+                Size::ZERO,
+                Size::new((portal_size.width - corner).max(0.0), portal_size.height),
+            );
+            let scrollbar_size = ctx.run_layout(&mut self.scrollbar_horizontal, &scrollbar_bc);
             ctx.place_child(
                 &mut self.scrollbar_horizontal,
                 Point::new(0.0, portal_size.height - scrollbar_size.height),
@@ -443,10 +1020,15 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
             let mut scrollbar = ctx.get_raw_mut(&mut self.scrollbar_vertical);
             scrollbar.widget().portal_size = portal_size.height;
             scrollbar.widget().content_size = content_size.height;
+            scrollbar.widget().opacity = self.scrollbar_opacity;
             // TODO - request paint for scrollbar?
             std::mem::drop(scrollbar);
 
-            let scrollbar_size = ctx.run_layout(&mut self.scrollbar_vertical, bc);
+            let scrollbar_bc = BoxConstraints::new(
+                Size::ZERO,
+                Size::new(portal_size.width, (portal_size.height - corner).max(0.0)),
+            );
+            let scrollbar_size = ctx.run_layout(&mut self.scrollbar_vertical, &scrollbar_bc);
             ctx.place_child(
                 &mut self.scrollbar_vertical,
                 Point::new(portal_size.width - scrollbar_size.width, 0.0),
@@ -459,7 +1041,22 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
     }
 
     fn compose(&mut self, ctx: &mut ComposeCtx) {
-        ctx.set_child_scroll_translation(&mut self.child, Vec2::new(0.0, -self.viewport_pos.y));
+        ctx.set_child_scroll_translation(&mut self.child, -self.viewport_pos.to_vec2());
+
+        // Compose runs at most once per frame, and after every layout, so reporting here
+        // coalesces several moves into one action and catches the offset being clamped
+        // when the content shrinks.
+        let scroll = PortalAction::ScrollChanged {
+            offset: self.viewport_pos.to_vec2(),
+            viewport: self.portal_size,
+            content: self.content_size,
+        };
+        match self.reported_scroll.replace(scroll) {
+            // The initial scroll state isn't a change.
+            None => {}
+            Some(reported) if reported == scroll => {}
+            Some(_) => ctx.submit_action(Action::Portal(scroll)),
+        }
     }
 
     fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
@@ -489,6 +1086,15 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
             node.set_scroll_y_max(y_max);
         }
 
+        if self.scroll_axes.vertical() {
+            node.add_action(accesskit::Action::ScrollUp);
+            node.add_action(accesskit::Action::ScrollDown);
+        }
+        if self.scroll_axes.horizontal() {
+            node.add_action(accesskit::Action::ScrollLeft);
+            node.add_action(accesskit::Action::ScrollRight);
+        }
+        node.add_action(accesskit::Action::SetScrollOffset);
         node.set_clips_children();
     }
 
@@ -510,10 +1116,15 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
 mod tests {
     use insta::assert_debug_snapshot;
 
+    use winit::event::Modifiers;
+    use winit::keyboard::ModifiersState;
+
     use super::*;
     use crate::assert_render_snapshot;
-    use crate::testing::{TestHarness, widget_ids};
-    use crate::widgets::{Button, Flex, SizedBox};
+    use crate::core::{PointerButton, PointerState};
+    use crate::dpi::LogicalPosition;
+    use crate::testing::{InputFuzzer, TestHarness, widget_ids};
+    use crate::widgets::{Button, Flex, SizedBox, Textbox};
 
     fn button(text: &'static str) -> impl Widget {
         SizedBox::new(Button::new(text)).width(70.0).height(40.0)
@@ -586,6 +1197,386 @@ mod tests {
         assert_render_snapshot!(harness, "button_list_scroll_to_item_13");
     }
 
+    #[test]
+    fn fuzz_input() {
+        let widget = Portal::new(
+            Flex::column()
+                .with_child(button("Item 1"))
+                .with_child(Textbox::new("Some text"))
+                .with_child(button("Item 2"))
+                .with_child(button("Item 3"))
+                .with_child(button("Item 4")),
+        );
+
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+
+        InputFuzzer::new(0x9047A1).run(&mut harness);
+    }
+
+    /// A grid of 5 by 5 boxes, each 60 by 60, with `first_id` the top left one.
+    fn wide_and_tall_content(first_id: WidgetId) -> Flex {
+        let cell = || SizedBox::empty().width(60.0).height(60.0);
+        let mut column = Flex::column().gap(0.0);
+        for row_idx in 0..5 {
+            let mut row = Flex::row().gap(0.0);
+            for col_idx in 0..5 {
+                row = if row_idx == 0 && col_idx == 0 {
+                    row.with_child_id(cell(), first_id)
+                } else {
+                    row.with_child(cell())
+                };
+            }
+            column = column.with_child(row);
+        }
+        column
+    }
+
+    fn wheel(harness: &mut TestHarness, y: f64, mods: ModifiersState) {
+        let mut state = PointerState::empty();
+        state.position = LogicalPosition::new(50., 50.);
+        state.mods = Modifiers::from(mods);
+        harness.process_pointer_event(PointerEvent::MouseWheel(LogicalPosition::new(0., y), state));
+    }
+
+    #[test]
+    fn horizontal_wheel_scrolling() {
+        let [first_id] = widget_ids();
+        let widget =
+            Portal::new(wide_and_tall_content(first_id)).scroll_axes(ScrollAxes::Horizontal);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        harness.mouse_move((50., 50.));
+
+        // With only the horizontal axis, the vertical wheel scrolls horizontally.
+        wheel(&mut harness, -3., ModifiersState::empty());
+        let origin = harness.get_widget(first_id).ctx().window_origin();
+        assert_eq!(origin, Point::new(-30., 0.));
+    }
+
+    #[test]
+    fn both_axes_shift_wheel() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id)).scroll_axes(ScrollAxes::Both);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        harness.mouse_move((50., 50.));
+
+        wheel(&mut harness, -3., ModifiersState::empty());
+        let origin = harness.get_widget(first_id).ctx().window_origin();
+        assert_eq!(origin, Point::new(0., -30.));
+
+        wheel(&mut harness, -2., ModifiersState::SHIFT);
+        let origin = harness.get_widget(first_id).ctx().window_origin();
+        assert_eq!(origin, Point::new(-20., -30.));
+    }
+
+    #[test]
+    fn viewport_clamped_to_scroll_axes() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id)).scroll_axes(ScrollAxes::Both);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<Flex>>();
+            Portal::set_viewport_pos(&mut portal, Point::new(1000., 1000.));
+        });
+        let portal = harness.root_widget().downcast::<Portal<Flex>>().unwrap();
+        assert_eq!(portal.get_viewport_pos(), Point::new(200., 200.));
+
+        // Scrolling to a child is clamped in the same way.
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<Flex>>();
+            Portal::set_scroll_axes(&mut portal, ScrollAxes::Vertical);
+            Portal::pan_viewport_to(&mut portal, Rect::new(250., 250., 300., 300.));
+        });
+        let portal = harness.root_widget().downcast::<Portal<Flex>>().unwrap();
+        assert_eq!(portal.get_viewport_pos(), Point::new(0., 200.));
+    }
+
+    #[test]
+    fn scrollbars_leave_corner_free() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id)).scroll_axes(ScrollAxes::Both);
+        let harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+
+        let portal = harness.root_widget().downcast::<Portal<Flex>>().unwrap();
+        let horizontal = harness
+            .get_widget(portal.scrollbar_horizontal.id())
+            .ctx()
+            .local_layout_rect();
+        let vertical = harness
+            .get_widget(portal.scrollbar_vertical.id())
+            .ctx()
+            .local_layout_rect();
+        assert_eq!(horizontal.intersect(vertical).area(), 0.0);
+        assert_eq!(horizontal.x1, vertical.x0);
+        assert_eq!(vertical.y1, horizontal.y0);
+    }
+
+    #[test]
+    fn overlay_scrollbars_fade() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id)).overlay_scrollbars(true);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        let scrollbar_id = harness
+            .root_widget()
+            .downcast::<Portal<Flex>>()
+            .unwrap()
+            .scrollbar_vertical
+            .id();
+        let opacity = |harness: &TestHarness| {
+            harness
+                .get_widget(scrollbar_id)
+                .downcast::<ScrollBar>()
+                .unwrap()
+                .opacity
+        };
+        let widget_at = |harness: &TestHarness, pos: Point| {
+            harness
+                .root_widget()
+                .find_widget_at_pos(pos)
+                .map(|w| w.id())
+        };
+        let scrollbar_pos = Point::new(95., 10.);
+
+        // Hidden scrollbars let the pointer through to the content.
+        assert_eq!(opacity(&harness), 0.0);
+        assert_ne!(widget_at(&harness, scrollbar_pos), Some(scrollbar_id));
+
+        // Scrolling fades them in.
+        harness.mouse_move((50., 50.));
+        wheel(&mut harness, -3., ModifiersState::empty());
+        harness.animate_ms(100);
+        let half_shown = opacity(&harness);
+        assert!(half_shown > 0.0 && half_shown < 1.0, "{half_shown}");
+        harness.animate_ms(200);
+        assert_eq!(opacity(&harness), 1.0);
+        assert_eq!(widget_at(&harness, scrollbar_pos), Some(scrollbar_id));
+
+        // They stay shown whilst hovered.
+        harness.animate_ms(5000);
+        assert_eq!(opacity(&harness), 1.0);
+
+        // Once the pointer leaves, they fade out after the delay.
+        harness.mouse_move((500., 500.));
+        harness.animate_ms(theme::SCROLLBAR_FADE_DELAY - 100);
+        assert_eq!(opacity(&harness), 1.0);
+        harness.animate_ms(200);
+        harness.animate_ms(300);
+        assert_eq!(opacity(&harness), 0.0);
+        assert_ne!(widget_at(&harness, scrollbar_pos), Some(scrollbar_id));
+    }
+
+    #[test]
+    fn scrollbar_style() {
+        let [first_id] = widget_ids();
+        let style = ScrollBarStyle {
+            width: 20.,
+            ..Default::default()
+        };
+        let widget = Portal::new(wide_and_tall_content(first_id)).scrollbar_style(style.clone());
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        let scrollbar_id = harness
+            .root_widget()
+            .downcast::<Portal<Flex>>()
+            .unwrap()
+            .scrollbar_vertical
+            .id();
+        let scrollbar_width =
+            |harness: &TestHarness| harness.get_widget(scrollbar_id).ctx().size().width;
+        assert_eq!(scrollbar_width(&harness), style.thickness());
+
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<Flex>>();
+            Portal::set_scrollbar_style(&mut portal, ScrollBarStyle::default());
+        });
+        assert_eq!(
+            scrollbar_width(&harness),
+            ScrollBarStyle::default().thickness()
+        );
+    }
+
+    fn viewport_pos(harness: &TestHarness) -> Point {
+        harness
+            .root_widget()
+            .downcast::<Portal<Flex>>()
+            .unwrap()
+            .get_viewport_pos()
+    }
+
+    /// Animated scrolling can leave the viewport a rounding error away from its target.
+    #[track_caller]
+    fn assert_viewport_near(harness: &TestHarness, expected: Point) {
+        let pos = viewport_pos(harness);
+        assert!((pos - expected).hypot() < 1e-6, "{pos:?} != {expected:?}");
+    }
+
+    #[test]
+    fn kinetic_fling() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id)).kinetic_scrolling(true);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        harness.mouse_move((50., 50.));
+
+        wheel(&mut harness, -1., ModifiersState::empty());
+        assert_eq!(viewport_pos(&harness), Point::new(0., 10.));
+        // The first frame measures the speed of the gesture, and the next one finds it has ended.
+        harness.animate_ms(16);
+        harness.animate_ms(16);
+        assert_eq!(viewport_pos(&harness), Point::new(0., 10.));
+
+        // The viewport then keeps moving, slowing down as it goes.
+        harness.animate_ms(16);
+        let first_step = viewport_pos(&harness).y - 10.;
+        assert!(first_step > 0., "{first_step}");
+        harness.animate_ms(16);
+        let second_step = viewport_pos(&harness).y - 10. - first_step;
+        assert!(
+            second_step > 0. && second_step < first_step,
+            "{second_step}"
+        );
+
+        // Pressing the pointer stops the fling.
+        harness.mouse_button_press(PointerButton::Primary);
+        let stopped_at = viewport_pos(&harness);
+        harness.animate_ms(16);
+        assert_eq!(viewport_pos(&harness), stopped_at);
+    }
+
+    #[test]
+    fn kinetic_fling_comes_to_rest() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id))
+            .kinetic_scrolling(true)
+            .kinetic_deceleration(10.);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        harness.mouse_move((50., 50.));
+
+        wheel(&mut harness, -1., ModifiersState::empty());
+        for _ in 0..200 {
+            harness.animate_ms(16);
+        }
+        let rest = viewport_pos(&harness);
+        assert!(rest.y > 10. && rest.y < 200., "{rest:?}");
+        harness.animate_ms(16);
+        assert_eq!(viewport_pos(&harness), rest);
+    }
+
+    #[test]
+    fn smooth_wheel_scrolling() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id)).smooth_scrolling(true);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        harness.mouse_move((50., 50.));
+
+        wheel(&mut harness, -3., ModifiersState::empty());
+        // The viewport glides towards the target, rather than jumping.
+        assert_eq!(viewport_pos(&harness), Point::ORIGIN);
+        harness.animate_ms(0);
+        harness.animate_ms(16);
+        let partway = viewport_pos(&harness).y;
+        assert!(partway > 0. && partway < 30., "{partway}");
+        for _ in 0..60 {
+            harness.animate_ms(16);
+        }
+        assert_viewport_near(&harness, Point::new(0., 30.));
+
+        // The target is clamped to the content.
+        wheel(&mut harness, -100., ModifiersState::empty());
+        for _ in 0..60 {
+            harness.animate_ms(16);
+        }
+        assert_viewport_near(&harness, Point::new(0., 200.));
+    }
+
+    #[test]
+    fn access_scroll_actions() {
+        let [first_id] = widget_ids();
+        let widget = Portal::new(wide_and_tall_content(first_id)).scroll_axes(ScrollAxes::Both);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        let portal_id = harness.root_widget().id();
+
+        harness.process_access_event(portal_id, accesskit::Action::ScrollDown, None);
+        assert_eq!(viewport_pos(&harness), Point::new(0., 100.));
+        harness.process_access_event(portal_id, accesskit::Action::ScrollRight, None);
+        assert_eq!(viewport_pos(&harness), Point::new(100., 100.));
+        harness.process_access_event(
+            portal_id,
+            accesskit::Action::SetScrollOffset,
+            Some(accesskit::ActionData::SetScrollOffset(
+                accesskit::Point::new(500., 20.),
+            )),
+        );
+        assert_eq!(viewport_pos(&harness), Point::new(200., 20.));
+
+        // With smooth scrolling, the same actions are animated.
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<Flex>>();
+            Portal::set_smooth_scrolling(&mut portal, true);
+        });
+        harness.process_access_event(portal_id, accesskit::Action::ScrollUp, None);
+        assert_eq!(viewport_pos(&harness), Point::new(200., 20.));
+        for _ in 0..60 {
+            harness.animate_ms(16);
+        }
+        assert_viewport_near(&harness, Point::new(200., 0.));
+    }
+
+    #[test]
+    fn scroll_changed_action() {
+        let widget = Portal::new(SizedBox::empty().width(100.).height(300.));
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+        let portal_id = harness.root_widget().id();
+        // The initial position isn't reported.
+        assert_eq!(harness.pop_action(), None);
+
+        // Several moves whilst handling one event are reported once.
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<SizedBox>>();
+            Portal::scroll_to_offset(&mut portal, Vec2::new(0., 100.));
+            Portal::scroll_to_offset(&mut portal, Vec2::new(0., 200.));
+        });
+        let scrolled = PortalAction::ScrollChanged {
+            offset: Vec2::new(0., 200.),
+            viewport: Size::new(100., 100.),
+            content: Size::new(100., 300.),
+        };
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::Portal(scrolled), portal_id))
+        );
+        assert_eq!(harness.pop_action(), None);
+        let progress = |harness: &TestHarness| {
+            harness
+                .root_widget()
+                .downcast::<Portal<SizedBox>>()
+                .unwrap()
+                .scroll_progress()
+        };
+        assert_eq!(progress(&harness), Vec2::new(0., 1.));
+
+        // Shrinking the content clamps the offset, which is reported too.
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<SizedBox>>();
+            SizedBox::set_height(&mut Portal::child_mut(&mut portal), 150.);
+        });
+        let clamped = PortalAction::ScrollChanged {
+            offset: Vec2::new(0., 50.),
+            viewport: Size::new(100., 100.),
+            content: Size::new(100., 150.),
+        };
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::Portal(clamped), portal_id))
+        );
+        assert_eq!(progress(&harness), Vec2::new(0., 1.));
+
+        // Scrolling to where the viewport already is sends nothing.
+        harness.edit_root_widget(|mut portal| {
+            let mut portal = portal.downcast::<Portal<SizedBox>>();
+            Portal::scroll_to_offset(&mut portal, Vec2::new(0., 50.));
+        });
+        assert_eq!(harness.pop_action(), None);
+    }
+
     // Helper function for panning tests
     fn make_range(repr: &str) -> Range<f64> {
         let repr = &repr[repr.find('_').unwrap()..];
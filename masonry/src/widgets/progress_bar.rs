@@ -13,13 +13,23 @@ use crate::core::{
     PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
     WidgetId, WidgetMut, WidgetPod,
 };
-use crate::kurbo::{Point, Size};
+use crate::kurbo::{Point, Rect, RoundedRect, Size};
 use crate::theme;
 use crate::util::{UnitPoint, fill_lin_gradient, stroke};
 use crate::widgets::{Label, LineBreaking};
 
+/// The time taken for the moving segment of an indeterminate progress bar
+/// to cross the bar, in seconds.
+const INDETERMINATE_PERIOD: f64 = 1.5;
+
+/// The width of the moving segment of an indeterminate progress bar, as a fraction of the bar's width.
+const INDETERMINATE_SEGMENT: f64 = 0.3;
+
 /// A progress bar.
 ///
+/// If the progress is unknown, the bar is [indeterminate](Self::indeterminate),
+/// and shows a segment moving along the bar.
+///
 #[doc = crate::include_screenshot!("widget/screenshots/masonry__widget__progress_bar__tests__25_percent_progressbar.png", "25% progress bar.")]
 pub struct ProgressBar {
     /// A value in the range `[0, 1]` inclusive, where 0 is 0% and 1 is 100% complete.
@@ -27,21 +37,48 @@ pub struct ProgressBar {
     /// `None` variant can be used to show a progress bar without a percentage.
     /// It is also used if an invalid float (outside of [0, 1]) is passed.
     progress: Option<f64>,
+    /// How far through its animation an indeterminate progress bar is, in the range `[0, 1)`.
+    t: f64,
     label: WidgetPod<Label>,
+    /// The outline and filled part of the bar, computed in `paint`.
+    ///
+    /// These depend on the size and progress, and are reset when either changes.
+    /// This is unused for indeterminate progress bars, as the filled part moves.
+    shapes: Option<(RoundedRect, RoundedRect)>,
 }
 
 impl ProgressBar {
     /// Create a new `ProgressBar`.
     ///
-    /// `progress` is a number between 0 and 1 inclusive. If it is `NaN`, then an
-    /// indefinite progress bar will be shown.
+    /// `progress` is a number between 0 and 1 inclusive. If it is `None` or `NaN`, then an
+    /// [indeterminate](Self::indeterminate) progress bar will be shown.
     /// Otherwise, the input will be clamped to [0, 1].
     pub fn new(mut progress: Option<f64>) -> Self {
         clamp_progress(&mut progress);
         let label = WidgetPod::new(
             Label::new(Self::value(progress)).with_line_break_mode(LineBreaking::Overflow),
         );
-        Self { progress, label }
+        Self {
+            progress,
+            t: 0.,
+            label,
+            shapes: None,
+        }
+    }
+
+    /// Create a new `ProgressBar` for an operation of unknown length.
+    ///
+    /// This is animated, until it is given a value using [`set_progress`](Self::set_progress).
+    pub fn indeterminate() -> Self {
+        Self::new(None)
+    }
+
+    /// The horizontal span of the moving segment of an indeterminate progress bar with the given width.
+    fn indeterminate_span(&self, width: f64) -> (f64, f64) {
+        let segment = width * INDETERMINATE_SEGMENT;
+        // The segment starts fully before the bar, and ends fully after it.
+        let start = self.t * (width + segment) - segment;
+        (start.max(0.), (start + segment).min(width))
     }
 
     fn value_accessibility(&self) -> Box<str> {
@@ -67,7 +104,12 @@ impl ProgressBar {
         clamp_progress(&mut progress);
         let progress_changed = this.widget.progress != progress;
         if progress_changed {
+            if this.widget.progress.is_some() && progress.is_none() {
+                this.widget.t = 0.;
+                this.ctx.request_anim_frame();
+            }
             this.widget.progress = progress;
+            this.widget.shapes = None;
             let mut label = this.ctx.get_mut(&mut this.widget.label);
             Label::set_text(&mut label, Self::value(progress));
         }
@@ -115,11 +157,39 @@ impl Widget for ProgressBar {
     ) {
     }
 
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        // Stop animating when there's nothing to animate, or when the bar can't be seen.
+        // The animation is restarted if either changes.
+        if self.progress.is_some() || ctx.is_stashed() {
+            return;
+        }
+        self.t = (self.t + (interval as f64) * 1e-9 / INDETERMINATE_PERIOD).rem_euclid(1.0);
+        ctx.request_anim_frame();
+        ctx.request_paint_only();
+    }
+
     fn register_children(&mut self, ctx: &mut RegisterCtx) {
         ctx.register_child(&mut self.label);
     }
 
-    fn update(&mut self, _ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, _event: &Update) {}
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::SizeChanged(_) => {
+                self.shapes = None;
+            }
+            Update::WidgetAdded | Update::StashedChanged(false) => {
+                if self.progress.is_none() {
+                    ctx.request_anim_frame();
+                }
+            }
+            _ => {}
+        }
+    }
 
     fn layout(
         &mut self,
@@ -148,11 +218,24 @@ impl Widget for ProgressBar {
     fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
         let border_width = 1.;
 
-        let rect = ctx
-            .size()
-            .to_rect()
-            .inset(-border_width / 2.)
-            .to_rounded_rect(2.);
+        let size = ctx.size();
+        let (rect, progress_rect) = if let Some(progress) = self.progress {
+            *self.shapes.get_or_insert_with(|| {
+                let rect = size.to_rect().inset(-border_width / 2.).to_rounded_rect(2.);
+                let progress_rect = Size::new(size.width * progress, size.height)
+                    .to_rect()
+                    .inset(-border_width / 2.)
+                    .to_rounded_rect(2.);
+                (rect, progress_rect)
+            })
+        } else {
+            let rect = size.to_rect().inset(-border_width / 2.).to_rounded_rect(2.);
+            let (x0, x1) = self.indeterminate_span(size.width);
+            let progress_rect = Rect::new(x0, 0., x1, size.height)
+                .inset(-border_width / 2.)
+                .to_rounded_rect(2.);
+            (rect, progress_rect)
+        };
 
         fill_lin_gradient(
             scene,
@@ -164,15 +247,6 @@ impl Widget for ProgressBar {
 
         stroke(scene, &rect, theme::BORDER_DARK, border_width);
 
-        let progress_rect_size = Size::new(
-            ctx.size().width * self.progress.unwrap_or(1.),
-            ctx.size().height,
-        );
-        let progress_rect = progress_rect_size
-            .to_rect()
-            .inset(-border_width / 2.)
-            .to_rounded_rect(2.);
-
         fill_lin_gradient(
             scene,
             &progress_rect,
@@ -219,12 +293,68 @@ mod tests {
     #[test]
     fn indeterminate_progressbar() {
         let [progressbar_id] = widget_ids();
-        let widget = ProgressBar::new(None).with_id(progressbar_id);
+        let widget = ProgressBar::indeterminate().with_id(progressbar_id);
 
         let mut harness = TestHarness::create(widget);
 
         assert_debug_snapshot!(harness.root_widget());
-        assert_render_snapshot!(harness, "indeterminate_progressbar");
+        // The segment moves, so we check its position rather than using a render snapshot.
+        let t = |harness: &TestHarness| {
+            harness
+                .get_widget(progressbar_id)
+                .downcast::<ProgressBar>()
+                .unwrap()
+                .t
+        };
+        assert_eq!(t(&harness), 0.);
+        harness.animate_ms(750);
+        assert!((t(&harness) - 0.5).abs() < 1e-6);
+        // The animation loops.
+        harness.animate_ms(1500);
+        assert!((t(&harness) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn indeterminate_segment_moves_across_bar() {
+        let mut bar = ProgressBar::indeterminate();
+        assert_eq!(bar.indeterminate_span(100.), (0., 0.));
+        bar.t = 0.5;
+        assert_eq!(bar.indeterminate_span(100.), (35., 65.));
+        bar.t = 1.;
+        assert_eq!(bar.indeterminate_span(100.), (100., 100.));
+    }
+
+    #[test]
+    fn set_progress_switches_mode() {
+        let [progressbar_id] = widget_ids();
+        let widget = ProgressBar::new(Some(0.5)).with_id(progressbar_id);
+        let mut harness = TestHarness::create(widget);
+        let t = |harness: &TestHarness| {
+            harness
+                .get_widget(progressbar_id)
+                .downcast::<ProgressBar>()
+                .unwrap()
+                .t
+        };
+
+        // Determinate progress bars aren't animated.
+        harness.animate_ms(750);
+        assert_eq!(t(&harness), 0.);
+
+        harness.edit_widget(progressbar_id, |mut bar| {
+            let mut bar = bar.downcast::<ProgressBar>();
+            ProgressBar::set_progress(&mut bar, None);
+        });
+        harness.animate_ms(750);
+        assert!((t(&harness) - 0.5).abs() < 1e-6);
+
+        harness.edit_widget(progressbar_id, |mut bar| {
+            let mut bar = bar.downcast::<ProgressBar>();
+            ProgressBar::set_progress(&mut bar, Some(0.25));
+        });
+        let stopped_at = t(&harness);
+        harness.animate_ms(300);
+        assert_eq!(t(&harness), stopped_at);
     }
 
     #[test]
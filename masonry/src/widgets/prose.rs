@@ -3,6 +3,8 @@
 
 #![warn(missing_docs)]
 
+use std::ops::Range;
+
 use accesskit::{Node, Role};
 use smallvec::{SmallVec, smallvec};
 use tracing::{Span, trace_span};
@@ -11,10 +13,10 @@ use vello::kurbo::{Point, Rect, Size};
 
 use crate::core::{
     AccessCtx, AccessEvent, BoxConstraints, EventCtx, LayoutCtx, PaintCtx, PointerEvent,
-    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
-    WidgetId, WidgetMut, WidgetPod,
+    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, RichText, TextEvent, Update, UpdateCtx,
+    Widget, WidgetId, WidgetMut, WidgetPod,
 };
-use crate::widgets::{Padding, TextArea};
+use crate::widgets::{HighlightStyle, Padding, TextArea};
 
 /// Added padding between each horizontal edge of the widget
 /// and the text in logical pixels.
@@ -54,6 +56,14 @@ impl Prose {
         Self::from_text_area(TextArea::new_immutable(text))
     }
 
+    /// Create a new `Prose` displaying rich text.
+    ///
+    /// The spans of `text` are applied on top of the text area's global styles.
+    pub fn rich(text: RichText) -> Self {
+        let (text, spans) = text.into_parts();
+        Self::from_text_area(TextArea::new_immutable(&text).with_spans(spans))
+    }
+
     /// Create a new `Prose` from a styled text area.
     pub fn from_text_area(text: TextArea<false>) -> Self {
         let text = text.with_padding_if_default(PROSE_PADDING);
@@ -108,6 +118,22 @@ impl Prose {
         this.widget.clip = clip;
         this.ctx.request_layout();
     }
+
+    /// Fill in a background behind byte ranges of the text, such as the matches of a search.
+    ///
+    /// See [`TextArea::with_highlights`] for details.
+    pub fn set_highlights(
+        this: &mut WidgetMut<'_, Self>,
+        ranges: impl Into<Vec<Range<usize>>>,
+        style: HighlightStyle,
+    ) {
+        TextArea::set_highlights(&mut Self::text_mut(this), ranges, style);
+    }
+
+    /// Remove all of the highlights, undoing [`set_highlights`](Self::set_highlights).
+    pub fn clear_highlights(this: &mut WidgetMut<'_, Self>) {
+        TextArea::clear_highlights(&mut Self::text_mut(this));
+    }
 }
 
 // --- MARK: IMPL WIDGET ---
@@ -0,0 +1,302 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A radio button widget.
+
+use accesskit::{Node, Role, Toggled};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace, trace_span};
+use vello::Scene;
+use vello::kurbo::{Affine, Circle, Point, Size};
+use vello::peniko::Fill;
+use winit::keyboard::{Key, NamedKey};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, ArcStr, BoxConstraints, EventCtx, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RadioButtonAction, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::theme;
+use crate::util::{UnitPoint, fill_lin_gradient, stroke};
+use crate::widgets::Label;
+
+/// A radio button, for choosing one of several mutually exclusive options.
+///
+/// A `RadioButton` only knows whether it is itself selected.
+/// Deselecting the other buttons in the same group is the responsibility of the code
+/// which creates them; in Xilem, this is handled by the `radio_group` view.
+///
+/// This widget emits [`RadioButtonAction::Selected`] when the user clicks it or presses Space
+/// whilst it is focused and not already selected.
+/// The arrow keys emit [`RadioButtonAction::SelectNext`] and [`RadioButtonAction::SelectPrevious`].
+pub struct RadioButton {
+    checked: bool,
+    label: WidgetPod<Label>,
+}
+
+impl RadioButton {
+    /// Create a new `RadioButton` with a text label.
+    pub fn new(checked: bool, text: impl Into<ArcStr>) -> Self {
+        Self::from_label(checked, Label::new(text))
+    }
+
+    /// Create a new `RadioButton` with the given label.
+    pub fn from_label(checked: bool, label: Label) -> Self {
+        Self {
+            checked,
+            label: WidgetPod::new(label),
+        }
+    }
+
+    /// Whether this radio button is selected.
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    fn select(&mut self, ctx: &mut EventCtx) {
+        if !self.checked {
+            self.checked = true;
+            ctx.submit_action(Action::RadioButton(RadioButtonAction::Selected));
+            // Checked state impacts appearance and accessibility node
+            ctx.request_render();
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl RadioButton {
+    /// Set whether this radio button is selected.
+    pub fn set_checked(this: &mut WidgetMut<'_, Self>, checked: bool) {
+        this.widget.checked = checked;
+        // Checked state impacts appearance and accessibility node
+        this.ctx.request_render();
+    }
+
+    /// Set the text.
+    ///
+    /// We enforce this to be an `ArcStr` to make the allocation explicit.
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, new_text: ArcStr) {
+        Label::set_text(&mut Self::label_mut(this), new_text);
+    }
+
+    /// Get a mutable reference to the label.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for RadioButton {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::PointerDown(_, _) => {
+                if !ctx.is_disabled() {
+                    ctx.capture_pointer();
+                    ctx.request_paint_only();
+                    trace!("RadioButton {:?} pressed", ctx.widget_id());
+                }
+            }
+            PointerEvent::PointerUp(_, _) => {
+                if ctx.is_pointer_capture_target() && ctx.is_hovered() && !ctx.is_disabled() {
+                    ctx.request_focus();
+                    self.select(ctx);
+                    trace!("RadioButton {:?} released", ctx.widget_id());
+                }
+                ctx.request_paint_only();
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::KeyboardKey(key_event, _) = event else {
+            return;
+        };
+        if !key_event.state.is_pressed() || ctx.is_disabled() {
+            return;
+        }
+        match &key_event.logical_key {
+            Key::Named(NamedKey::Space) => self.select(ctx),
+            Key::Named(NamedKey::ArrowDown | NamedKey::ArrowRight) => {
+                ctx.submit_action(Action::RadioButton(RadioButtonAction::SelectNext));
+            }
+            Key::Named(NamedKey::ArrowUp | NamedKey::ArrowLeft) => {
+                ctx.submit_action(Action::RadioButton(RadioButtonAction::SelectPrevious));
+            }
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.target() == ctx.widget_id() && !ctx.is_disabled() {
+            match event.action {
+                accesskit::Action::Click => self.select(ctx),
+                _ => {}
+            }
+        }
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let x_padding = theme::WIDGET_CONTROL_COMPONENT_PADDING;
+        let indicator_size = theme::BASIC_WIDGET_HEIGHT;
+
+        let label_size = ctx.run_layout(&mut self.label, bc);
+        ctx.place_child(&mut self.label, (indicator_size + x_padding, 0.0).into());
+
+        let desired_size = Size::new(
+            indicator_size + x_padding + label_size.width,
+            indicator_size.max(label_size.height),
+        );
+        let our_size = bc.constrain(desired_size);
+        let baseline =
+            ctx.child_baseline_offset(&self.label) + (our_size.height - label_size.height);
+        ctx.set_baseline_offset(baseline);
+        our_size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let indicator_size = theme::BASIC_WIDGET_HEIGHT;
+        let border_width = 1.;
+        let center = Point::new(indicator_size / 2., indicator_size / 2.);
+
+        let circle = Circle::new(center, (indicator_size - border_width) / 2.);
+        fill_lin_gradient(
+            scene,
+            &circle,
+            [theme::BACKGROUND_LIGHT, theme::BACKGROUND_DARK],
+            UnitPoint::TOP,
+            UnitPoint::BOTTOM,
+        );
+
+        let border_color = if ctx.is_focus_target() {
+            theme::PRIMARY_LIGHT
+        } else if ctx.is_hovered() && !ctx.is_disabled() {
+            theme::BORDER_LIGHT
+        } else {
+            theme::BORDER_DARK
+        };
+        stroke(scene, &circle, border_color, border_width);
+
+        if self.checked {
+            // Paint the dot
+            let brush = if ctx.is_disabled() {
+                theme::DISABLED_TEXT_COLOR
+            } else {
+                theme::TEXT_COLOR
+            };
+            let dot = Circle::new(center, indicator_size / 4.);
+            scene.fill(Fill::NonZero, Affine::IDENTITY, brush, None, &dot);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::RadioButton
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.add_action(accesskit::Action::Click);
+        if self.checked {
+            node.set_toggled(Toggled::True);
+        } else {
+            node.set_toggled(Toggled::False);
+        }
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.label.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("RadioButton", id = ctx.widget_id().trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        if self.checked {
+            Some("(o)".to_string())
+        } else {
+            Some("( )".to_string())
+        }
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+    use crate::widgets::Flex;
+
+    #[test]
+    fn click_selects() {
+        let [first_id, second_id] = widget_ids();
+        let widget = Flex::column()
+            .with_child(RadioButton::new(true, "First").with_id(first_id))
+            .with_child(RadioButton::new(false, "Second").with_id(second_id));
+        let mut harness = TestHarness::create(widget);
+        let is_checked = |harness: &TestHarness, id| {
+            harness
+                .get_widget(id)
+                .downcast::<RadioButton>()
+                .unwrap()
+                .is_checked()
+        };
+
+        // Clicking the selected button does nothing.
+        harness.mouse_click_on(first_id);
+        assert_eq!(harness.pop_action(), None);
+
+        harness.mouse_click_on(second_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::RadioButton(RadioButtonAction::Selected), second_id))
+        );
+        assert!(is_checked(&harness, second_id));
+        assert_eq!(harness.focused_widget_id(), Some(second_id));
+        // The other button is only deselected by the owner of the group.
+        assert!(is_checked(&harness, first_id));
+
+        harness.edit_widget(first_id, |mut radio| {
+            let mut radio = radio.downcast::<RadioButton>();
+            RadioButton::set_checked(&mut radio, false);
+        });
+        assert!(!is_checked(&harness, first_id));
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use accesskit::{Node, Role};
-use smallvec::{SmallVec, smallvec};
+use smallvec::SmallVec;
 use tracing::{Span, trace_span};
 use vello::Scene;
 use vello::kurbo::Point;
@@ -13,24 +13,36 @@ use crate::core::{
     WidgetMut, WidgetPod,
 };
 use crate::kurbo::Size;
+use crate::widgets::{Modal, ModalOptions};
 
 // TODO: This is a hack to provide an accessibility node with a Window type.
 // This should eventually be removed.
 pub struct RootWidget<W: ?Sized> {
     pub(crate) pod: WidgetPod<W>,
+    /// Overlays shown above the child, in increasing z-order.
+    overlays: Vec<WidgetPod<Modal<dyn Widget>>>,
 }
 
 impl<W: Widget> RootWidget<W> {
     pub fn new(widget: W) -> Self {
         Self {
             pod: WidgetPod::new(widget),
+            overlays: Vec::new(),
         }
     }
 }
 
 impl<W: Widget + FromDynWidget + ?Sized> RootWidget<W> {
     pub fn from_pod(pod: WidgetPod<W>) -> Self {
-        Self { pod }
+        Self {
+            pod,
+            overlays: Vec::new(),
+        }
+    }
+
+    /// The number of overlays currently shown, see [`push_overlay`](Self::push_overlay).
+    pub fn overlay_count(&self) -> usize {
+        self.overlays.len()
     }
 }
 
@@ -38,6 +50,32 @@ impl<W: Widget + FromDynWidget + ?Sized> RootWidget<W> {
     pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
         this.ctx.get_mut(&mut this.widget.pod)
     }
+
+    /// Show `widget` in a [`Modal`] covering the whole window, above the child and any
+    /// previously pushed overlays.
+    ///
+    /// Whilst the overlay is shown, the widgets beneath it can't be clicked or focused.
+    pub fn push_overlay(
+        this: &mut WidgetMut<'_, Self>,
+        widget: WidgetPod<dyn Widget>,
+        options: ModalOptions,
+    ) {
+        let overlay = WidgetPod::new(Modal::from_pod(widget, options));
+        this.widget.overlays.push(overlay);
+        this.ctx.children_changed();
+    }
+
+    /// Remove the topmost overlay added with [`push_overlay`](Self::push_overlay).
+    ///
+    /// Focus is restored to the widget which had it before the overlay was pushed.
+    /// Does nothing if there are no overlays.
+    pub fn pop_overlay(this: &mut WidgetMut<'_, Self>) {
+        let Some(mut overlay) = this.widget.overlays.pop() else {
+            return;
+        };
+        Modal::set_open(&mut this.ctx.get_mut(&mut overlay), false);
+        this.ctx.remove_child(overlay);
+    }
 }
 
 impl<W: Widget + FromDynWidget + ?Sized> Widget for RootWidget<W> {
@@ -65,6 +103,9 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for RootWidget<W> {
 
     fn register_children(&mut self, ctx: &mut RegisterCtx) {
         ctx.register_child(&mut self.pod);
+        for overlay in &mut self.overlays {
+            ctx.register_child(overlay);
+        }
     }
 
     fn layout(
@@ -75,6 +116,12 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for RootWidget<W> {
     ) -> Size {
         let size = ctx.run_layout(&mut self.pod, bc);
         ctx.place_child(&mut self.pod, Point::ORIGIN);
+
+        let overlay_bc = BoxConstraints::tight(size);
+        for overlay in &mut self.overlays {
+            ctx.run_layout(overlay, &overlay_bc);
+            ctx.place_child(overlay, Point::ORIGIN);
+        }
         size
     }
 
@@ -93,7 +140,9 @@ impl<W: Widget + FromDynWidget + ?Sized> Widget for RootWidget<W> {
     }
 
     fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
-        smallvec![self.pod.id()]
+        std::iter::once(self.pod.id())
+            .chain(self.overlays.iter().map(WidgetPod::id))
+            .collect()
     }
 
     fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
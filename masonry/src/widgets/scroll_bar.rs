@@ -7,25 +7,63 @@ use accesskit::{Node, Role};
 use smallvec::SmallVec;
 use tracing::{Span, trace_span};
 use vello::Scene;
-use vello::kurbo::Rect;
+use vello::kurbo::{Affine, Rect};
+use vello::peniko::{Brush, Color, Fill};
 
 use crate::core::{
     AccessCtx, AccessEvent, AllowRawMut, BoxConstraints, EventCtx, LayoutCtx, PaintCtx,
     PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetRef, find_widget_at_pos,
 };
 use crate::kurbo::{Point, Size};
 use crate::theme;
-use crate::util::{fill_color, stroke};
+use crate::util::stroke;
 use crate::widgets::Axis;
 
 // TODO
-// - Fade scrollbars? Find out how Linux/MacOS/Windows do it
 // - Rename cursor to oval/rect/bar/grabber/grabbybar
 // - Rename progress to something more descriptive
 // - Document names
 // - Document invariants
 
+/// How a [`ScrollBar`] is drawn.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScrollBarStyle {
+    /// The brush filling the whole length of the scrollbar, behind the thumb.
+    ///
+    /// This defaults to transparent.
+    pub track: Brush,
+    /// The brush filling the thumb, which shows the visible part of the content.
+    pub thumb: Brush,
+    /// The brush filling the thumb whilst the scrollbar is hovered or being dragged.
+    ///
+    /// This defaults to the same as `thumb`.
+    pub thumb_hover: Brush,
+    /// The thickness of the thumb, in logical pixels.
+    pub width: f64,
+    /// The radius of the corners of the track and the thumb.
+    pub radius: f64,
+}
+
+impl Default for ScrollBarStyle {
+    fn default() -> Self {
+        Self {
+            track: Color::TRANSPARENT.into(),
+            thumb: theme::SCROLLBAR_COLOR.into(),
+            thumb_hover: theme::SCROLLBAR_COLOR.into(),
+            width: theme::SCROLLBAR_WIDTH,
+            radius: theme::SCROLLBAR_RADIUS,
+        }
+    }
+}
+
+impl ScrollBarStyle {
+    /// The total thickness of a scrollbar with this style, including its padding.
+    pub fn thickness(&self) -> f64 {
+        self.width + theme::SCROLLBAR_PAD * 2.0
+    }
+}
+
 /// A scrollbar.
 ///
 #[doc = crate::include_screenshot!("widget/screenshots/masonry__widget__scroll_bar__tests__scrollbar_default.png", "Vertical scrollbar.")]
@@ -36,6 +74,12 @@ pub struct ScrollBar {
     pub(crate) portal_size: f64,
     pub(crate) content_size: f64,
     grab_anchor: Option<f64>,
+    style: ScrollBarStyle,
+    /// How opaque the scrollbar is drawn, from 0.0 (hidden) to 1.0.
+    ///
+    /// This is used by [`Portal`](crate::widgets::Portal) to fade out overlay scrollbars.
+    /// Whilst it is 0.0, the scrollbar doesn't get pointer events.
+    pub(crate) opacity: f64,
 }
 
 // --- MARK: BUILDERS ---
@@ -48,15 +92,28 @@ impl ScrollBar {
             portal_size,
             content_size,
             grab_anchor: None,
+            style: ScrollBarStyle::default(),
+            opacity: 1.0,
         }
     }
 
+    /// Builder-style method to set how the scrollbar is drawn.
+    pub fn with_style(mut self, style: ScrollBarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
     /// Returns how far the scrollbar is from its initial point.
     ///
     /// Values range from 0.0 (beginning) to 1.0 (end).
     pub fn cursor_progress(&self) -> f64 {
         self.cursor_progress
     }
+
+    /// How the scrollbar is drawn.
+    pub fn style(&self) -> &ScrollBarStyle {
+        &self.style
+    }
 }
 
 impl ScrollBar {
@@ -118,6 +175,13 @@ impl ScrollBar {
         this.widget.content_size = content_size;
         this.ctx.request_render();
     }
+
+    /// Set how the scrollbar is drawn.
+    pub fn set_style(this: &mut WidgetMut<'_, Self>, style: ScrollBarStyle) {
+        this.widget.style = style;
+        // The width may have changed.
+        this.ctx.request_layout();
+    }
 }
 
 // --- MARK: IMPL WIDGET ---
@@ -187,7 +251,11 @@ impl Widget for ScrollBar {
 
     fn register_children(&mut self, _ctx: &mut RegisterCtx) {}
 
-    fn update(&mut self, _ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, _event: &Update) {}
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::HoveredChanged(_) = event {
+            ctx.request_render();
+        }
+    }
 
     fn layout(
         &mut self,
@@ -197,18 +265,17 @@ impl Widget for ScrollBar {
     ) -> Size {
         // TODO - handle resize
 
-        let scrollbar_width = theme::SCROLLBAR_WIDTH;
-        let cursor_padding = theme::SCROLLBAR_PAD;
         self.axis
-            .pack(
-                self.axis.major(bc.max()),
-                scrollbar_width + cursor_padding * 2.0,
-            )
+            .pack(self.axis.major(bc.max()), self.style.thickness())
             .into()
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
-        let radius = theme::SCROLLBAR_RADIUS;
+        if self.opacity <= 0.0 {
+            return;
+        }
+        let alpha = self.opacity as f32;
+        let radius = self.style.radius;
         let edge_width = theme::SCROLLBAR_EDGE_WIDTH;
         let cursor_padding = theme::SCROLLBAR_PAD;
         let cursor_min_length = theme::SCROLLBAR_MIN_SIZE;
@@ -219,11 +286,21 @@ impl Widget for ScrollBar {
             .inset((-inset_x, -inset_y))
             .to_rounded_rect(radius);
 
-        fill_color(scene, &cursor_rect, theme::SCROLLBAR_COLOR);
+        let track_rect = ctx.size().to_rect().to_rounded_rect(radius);
+        let track = self.style.track.clone().multiply_alpha(alpha);
+        scene.fill(Fill::NonZero, Affine::IDENTITY, &track, None, &track_rect);
+
+        let thumb = if ctx.is_hovered() || self.grab_anchor.is_some() {
+            &self.style.thumb_hover
+        } else {
+            &self.style.thumb
+        };
+        let thumb = thumb.clone().multiply_alpha(alpha);
+        scene.fill(Fill::NonZero, Affine::IDENTITY, &thumb, None, &cursor_rect);
         stroke(
             scene,
             &cursor_rect,
-            theme::SCROLLBAR_BORDER_COLOR,
+            theme::SCROLLBAR_BORDER_COLOR.multiply_alpha(alpha),
             edge_width,
         );
     }
@@ -232,20 +309,38 @@ impl Widget for ScrollBar {
         Role::ScrollBar
     }
 
-    fn accessibility(
-        &mut self,
-        _ctx: &mut AccessCtx,
-        _props: &PropertiesRef<'_>,
-        _node: &mut Node,
-    ) {
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
         // TODO
         // Use set_scroll_x/y_min/max?
+        if self.opacity <= 0.0 {
+            node.set_hidden();
+        }
     }
 
     fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
         SmallVec::new()
     }
 
+    fn find_widget_at_pos<'c>(
+        &'c self,
+        ctx: QueryCtx<'c>,
+        props: PropertiesRef<'c>,
+        pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        // A faded out scrollbar lets the pointer through to the content beneath it.
+        if self.opacity <= 0.0 {
+            return None;
+        }
+        find_widget_at_pos(
+            &WidgetRef {
+                widget: self,
+                properties: props,
+                ctx,
+            },
+            pos,
+        )
+    }
+
     fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
         trace_span!("ScrollBar", id = ctx.widget_id().trace())
     }
@@ -7,7 +7,7 @@ use std::any::TypeId;
 
 use accesskit::{Node, Role};
 use smallvec::{SmallVec, smallvec};
-use tracing::{Span, trace_span, warn};
+use tracing::{Span, debug, trace_span, warn};
 use vello::Scene;
 use vello::kurbo::{Affine, RoundedRectRadii};
 use vello::peniko::{Brush, Fill};
@@ -61,11 +61,15 @@ pub struct Padding {
 /// and width as possible given the parent's constraints. If height or width is not set,
 /// it will be treated as zero.
 ///
+/// An [aspect ratio](Self::aspect_ratio) can be set to keep the box's proportions whatever
+/// space it is given.
+///
 #[doc = crate::include_screenshot!("widget/screenshots/masonry__widget__sized_box__tests__label_box_with_outer_padding.png", "Box with blue border, pink background and a child label.")]
 pub struct SizedBox {
     child: Option<WidgetPod<dyn Widget>>,
     width: Option<f64>,
     height: Option<f64>,
+    aspect_ratio: Option<f64>,
     background: Option<Brush>,
     border: Option<BorderStyle>,
     corner_radius: RoundedRectRadii,
@@ -187,6 +191,7 @@ impl SizedBox {
             child: Some(WidgetPod::new(child).erased()),
             width: None,
             height: None,
+            aspect_ratio: None,
             background: None,
             border: None,
             corner_radius: RoundedRectRadii::from_single_radius(0.0),
@@ -200,6 +205,7 @@ impl SizedBox {
             child: Some(WidgetPod::new_with_id(child, id).erased()),
             width: None,
             height: None,
+            aspect_ratio: None,
             background: None,
             border: None,
             corner_radius: RoundedRectRadii::from_single_radius(0.0),
@@ -213,6 +219,7 @@ impl SizedBox {
             child: Some(child),
             width: None,
             height: None,
+            aspect_ratio: None,
             background: None,
             border: None,
             corner_radius: RoundedRectRadii::from_single_radius(0.0),
@@ -230,6 +237,7 @@ impl SizedBox {
             child: None,
             width: None,
             height: None,
+            aspect_ratio: None,
             background: None,
             border: None,
             corner_radius: RoundedRectRadii::from_single_radius(0.0),
@@ -249,6 +257,23 @@ impl SizedBox {
         self
     }
 
+    /// Keep the container's width divided by its height equal to `aspect_ratio`.
+    ///
+    /// If only one axis has a fixed size, either set on this box or from tight constraints,
+    /// the other axis is derived from it.
+    /// If neither does, the child's preferred size is grown to the aspect ratio.
+    /// If both do, the aspect ratio is ignored.
+    /// In every case, the size stays within the constraints given by the parent,
+    /// even if that means the aspect ratio isn't kept.
+    ///
+    /// ## Panics
+    ///
+    /// If `aspect_ratio` is not a positive finite value.
+    pub fn aspect_ratio(mut self, aspect_ratio: f64) -> Self {
+        self.aspect_ratio = Some(validate_aspect_ratio(aspect_ratio));
+        self
+    }
+
     /// Expand container to fit the parent.
     ///
     /// Only call this method if you want your widget to occupy all available
@@ -366,6 +391,24 @@ impl SizedBox {
         this.ctx.request_layout();
     }
 
+    /// Keep the container's width divided by its height equal to `aspect_ratio`.
+    ///
+    /// See [`SizedBox::aspect_ratio`] for details.
+    ///
+    /// ## Panics
+    ///
+    /// If `aspect_ratio` is not a positive finite value.
+    pub fn set_aspect_ratio(this: &mut WidgetMut<'_, Self>, aspect_ratio: f64) {
+        this.widget.aspect_ratio = Some(validate_aspect_ratio(aspect_ratio));
+        this.ctx.request_layout();
+    }
+
+    /// Stop keeping the container's aspect ratio.
+    pub fn unset_aspect_ratio(this: &mut WidgetMut<'_, Self>) {
+        this.widget.aspect_ratio = None;
+        this.ctx.request_layout();
+    }
+
     /// Set the background for this widget.
     ///
     /// This can be passed anything which can be represented by a [`Brush`];
@@ -453,6 +496,47 @@ impl SizedBox {
             Size::new(max_width, max_height),
         )
     }
+
+    /// The size of this box which keeps `aspect_ratio` within `bc`, or `None` if both axes are fixed.
+    ///
+    /// `insets` is the space taken up by the border and padding.
+    fn aspect_ratio_size(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        aspect_ratio: f64,
+        insets: Size,
+    ) -> Option<Size> {
+        let is_fixed = |min: f64, max: f64| min == max && max.is_finite();
+        let width_fixed = is_fixed(bc.min().width, bc.max().width);
+        let height_fixed = is_fixed(bc.min().height, bc.max().height);
+        let width = match (width_fixed, height_fixed) {
+            (true, true) => {
+                debug!("SizedBox aspect ratio is ignored, as both axes have a fixed size.");
+                return None;
+            }
+            (true, false) => bc.max().width,
+            (false, true) => bc.max().height * aspect_ratio,
+            (false, false) => {
+                let preferred = match self.child.as_mut() {
+                    Some(child) => ctx.run_layout(child, &bc.shrink(insets)) + insets,
+                    None => Size::ZERO,
+                };
+                // The smallest size with the aspect ratio which contains the preferred size.
+                preferred.width.max(preferred.height * aspect_ratio)
+            }
+        };
+        let width = if width.is_finite() { width } else { 0.0 };
+        Some(bc.constrain_aspect_ratio(aspect_ratio.recip(), width))
+    }
+}
+
+fn validate_aspect_ratio(aspect_ratio: f64) -> f64 {
+    if aspect_ratio.is_finite() && aspect_ratio > 0.0 {
+        aspect_ratio
+    } else {
+        panic!("Invalid `aspect_ratio` {aspect_ratio}, expected a positive finite value.")
+    }
 }
 
 // --- MARK: IMPL WIDGET ---
@@ -503,15 +587,23 @@ impl Widget for SizedBox {
             None => 0.0,
         };
 
-        let child_bc = self.child_constraints(bc);
-        let child_bc = child_bc.shrink((2.0 * border_width, 2.0 * border_width));
-        let origin = Point::new(border_width, border_width);
-
-        // Shrink constraints by padding inset
         let padding_size = Size::new(
             self.padding.leading + self.padding.trailing,
             self.padding.top + self.padding.bottom,
         );
+
+        let mut box_bc = self.child_constraints(bc);
+        if let Some(aspect_ratio) = self.aspect_ratio {
+            let insets = padding_size + Size::new(2.0 * border_width, 2.0 * border_width);
+            if let Some(size) = self.aspect_ratio_size(ctx, &box_bc, aspect_ratio, insets) {
+                box_bc = BoxConstraints::tight(size);
+            }
+        }
+
+        let child_bc = box_bc.shrink((2.0 * border_width, 2.0 * border_width));
+        let origin = Point::new(border_width, border_width);
+
+        // Shrink constraints by padding inset
         let child_bc = child_bc.shrink(padding_size);
         let origin = origin + (self.padding.leading, self.padding.top);
 
@@ -525,7 +617,9 @@ impl Widget for SizedBox {
                     size.height + 2.0 * border_width,
                 ) + padding_size;
             }
-            None => size = bc.constrain((self.width.unwrap_or(0.0), self.height.unwrap_or(0.0))),
+            None => {
+                size = box_bc.constrain((self.width.unwrap_or(0.0), self.height.unwrap_or(0.0)));
+            }
         };
 
         // TODO - figure out paint insets
@@ -609,8 +703,8 @@ mod tests {
     use vello::peniko::Gradient;
 
     use super::*;
-    use crate::testing::TestHarness;
-    use crate::widgets::Label;
+    use crate::testing::{ModularWidget, TestHarness, widget_ids};
+    use crate::widgets::{Flex, Label, Portal};
     use crate::{assert_render_snapshot, palette};
 
     // TODO - Add WidgetMut tests
@@ -632,6 +726,89 @@ mod tests {
         assert_eq!(child_bc.max(), Size::new(400., 200.,));
     }
 
+    /// A harness in which `widget` is laid out with the given constraints.
+    fn harness_with_constraints(
+        widget: impl Widget,
+        id: WidgetId,
+        bc: BoxConstraints,
+    ) -> TestHarness {
+        let parent = ModularWidget::new(WidgetPod::new_with_id(widget, id))
+            .register_children_fn(|child, ctx| {
+                ctx.register_child(child);
+            })
+            .layout_fn(move |child, ctx, _, _| {
+                ctx.run_layout(child, &bc);
+                ctx.place_child(child, Point::ZERO);
+                Size::new(400.0, 400.0)
+            })
+            .children_fn(|child| smallvec![child.id()]);
+        TestHarness::create_with_size(parent, Size::new(400.0, 400.0))
+    }
+
+    #[test]
+    fn aspect_ratio_from_fixed_width() {
+        let [box_id] = widget_ids();
+        let widget = SizedBox::new(Label::new("hello")).aspect_ratio(16.0 / 9.0);
+        let bc = BoxConstraints::new(Size::new(320.0, 0.0), Size::new(320.0, 400.0));
+        let harness = harness_with_constraints(widget, box_id, bc);
+
+        assert_eq!(
+            harness.get_widget(box_id).ctx().size(),
+            Size::new(320.0, 180.0)
+        );
+    }
+
+    #[test]
+    fn aspect_ratio_in_unbounded_portal() {
+        let [portal_id, fixed_height_id, wide_child_id, tall_child_id] = widget_ids();
+        let column = Flex::column()
+            .with_child_id(
+                SizedBox::empty().height(90.0).aspect_ratio(16.0 / 9.0),
+                fixed_height_id,
+            )
+            .with_child_id(
+                SizedBox::new(SizedBox::empty().width(320.0).height(50.0)).aspect_ratio(16.0 / 9.0),
+                wide_child_id,
+            )
+            .with_child_id(
+                SizedBox::new(SizedBox::empty().width(100.0).height(180.0))
+                    .aspect_ratio(16.0 / 9.0),
+                tall_child_id,
+            );
+        // The portal passes on its unbounded width to its content.
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(f64::INFINITY, 400.0));
+        let harness = harness_with_constraints(Portal::new(column), portal_id, bc);
+
+        let size = |id| harness.get_widget(id).ctx().size();
+        // The width is derived from the fixed height.
+        assert_eq!(size(fixed_height_id), Size::new(160.0, 90.0));
+        // The child's preferred size is grown to the aspect ratio.
+        assert_eq!(size(wide_child_id), Size::new(320.0, 180.0));
+        assert_eq!(size(tall_child_id), Size::new(320.0, 180.0));
+    }
+
+    #[test]
+    fn aspect_ratio_ignored_when_both_fixed() {
+        let [box_id] = widget_ids();
+        let widget = SizedBox::empty()
+            .width(100.0)
+            .height(100.0)
+            .aspect_ratio(2.0);
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(400.0, 400.0));
+        let harness = harness_with_constraints(widget, box_id, bc);
+
+        assert_eq!(
+            harness.get_widget(box_id).ctx().size(),
+            Size::new(100.0, 100.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid `aspect_ratio`")]
+    fn zero_aspect_ratio() {
+        let _ = SizedBox::empty().aspect_ratio(0.0);
+    }
+
     #[test]
     fn empty_box() {
         let widget = SizedBox::empty()
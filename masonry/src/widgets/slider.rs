@@ -0,0 +1,542 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A slider widget.
+
+use accesskit::{Node, Role};
+use smallvec::SmallVec;
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Circle, Point, Rect, Size};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, LayoutCtx, PaintCtx, PointerButton,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, SliderAction, TextEvent,
+    Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+};
+use crate::theme;
+use crate::util::{UnitPoint, fill_lin_gradient, stroke};
+use crate::widgets::{Axis, WheelAdjust};
+
+/// The radius of the draggable thumb.
+const THUMB_RADIUS: f64 = 7.;
+
+/// The thickness of the track the thumb moves along.
+const TRACK_THICKNESS: f64 = 4.;
+
+/// The number of steps used by the keyboard and mouse wheel when no step is set.
+const DEFAULT_STEPS: f64 = 100.;
+
+/// How many steps Page Up and Page Down move by.
+const PAGE_STEPS: f64 = 10.;
+
+/// A slider, for picking a number from a continuous range.
+///
+/// The value can be changed by dragging the thumb, using the arrow keys, Page Up, Page Down,
+/// Home and End whilst focused, with the mouse wheel (see [`WheelAdjust`]), or by assistive technology.
+///
+/// This widget emits [`SliderAction::ValueChanged`] whenever the user changes the value, and
+/// [`SliderAction::DragFinished`] when the user releases the thumb after dragging it.
+pub struct Slider {
+    min: f64,
+    max: f64,
+    value: f64,
+    /// If set, the value is snapped to `min + n * step` for some integer `n`.
+    step: Option<f64>,
+    axis: Axis,
+    wheel: WheelAdjust,
+}
+
+// --- MARK: BUILDERS ---
+impl Slider {
+    /// Create a new horizontal `Slider` showing `value` in the range `min..=max`.
+    ///
+    /// The value is clamped to the range.
+    pub fn new(min: f64, max: f64, value: f64) -> Self {
+        let mut slider = Self {
+            min,
+            max,
+            value,
+            step: None,
+            axis: Axis::Horizontal,
+            wheel: WheelAdjust::new(0., min, max),
+        };
+        slider.update_wheel();
+        slider.value = slider.snap(value);
+        slider
+    }
+
+    /// Builder-style method to set the step the value snaps to.
+    ///
+    /// This is also the amount the arrow keys and mouse wheel adjust the value by.
+    /// Without a step, the value is continuous, and the keys move by a hundredth of the range.
+    ///
+    /// To modify this on an active slider, use [`set_step`](Self::set_step).
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self.update_wheel();
+        self.value = self.snap(self.value);
+        self
+    }
+
+    /// Builder-style method to set the direction the slider moves along.
+    ///
+    /// Vertical sliders have their minimum at the bottom.
+    ///
+    /// To modify this on an active slider, use [`set_axis`](Self::set_axis).
+    pub fn with_axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// The current value of the slider.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+// --- MARK: HELPERS ---
+impl Slider {
+    /// The amount the value is adjusted by a single key press or wheel notch.
+    fn key_step(&self) -> f64 {
+        self.step.unwrap_or((self.max - self.min) / DEFAULT_STEPS)
+    }
+
+    fn update_wheel(&mut self) {
+        self.wheel.set_range(self.min, self.max);
+        self.wheel.set_step(self.key_step());
+        self.wheel.set_fine_step(self.key_step() / 10.);
+    }
+
+    /// Clamp `value` to the range, and snap it to the step.
+    fn snap(&self, value: f64) -> f64 {
+        let value = match self.step {
+            Some(step) if step > 0. => self.min + ((value - self.min) / step).round() * step,
+            _ => value,
+        };
+        value.clamp(self.min, self.max.max(self.min))
+    }
+
+    /// The value a key press should change the value to, if the key adjusts the slider.
+    fn value_for_key(&self, key: &Key) -> Option<f64> {
+        let step = self.key_step();
+        let value = match key {
+            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowUp) => self.value + step,
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowDown) => self.value - step,
+            Key::Named(NamedKey::PageUp) => self.value + step * PAGE_STEPS,
+            Key::Named(NamedKey::PageDown) => self.value - step * PAGE_STEPS,
+            Key::Named(NamedKey::Home) => self.min,
+            Key::Named(NamedKey::End) => self.max,
+            _ => return None,
+        };
+        Some(self.snap(value))
+    }
+
+    /// The position of the value along the track, from 0 (at `min`) to 1 (at `max`).
+    fn fraction(&self) -> f64 {
+        let range = self.max - self.min;
+        if range > 0. {
+            ((self.value - self.min) / range).clamp(0., 1.)
+        } else {
+            0.
+        }
+    }
+
+    /// The length along the major axis which the centre of the thumb can move through.
+    fn travel(&self, size: Size) -> f64 {
+        (self.axis.major(size) - 2. * THUMB_RADIUS).max(0.)
+    }
+
+    /// The centre of the thumb, for a slider of the given `size`.
+    fn thumb_center(&self, size: Size) -> Point {
+        let fraction = match self.axis {
+            Axis::Horizontal => self.fraction(),
+            Axis::Vertical => 1. - self.fraction(),
+        };
+        let major = THUMB_RADIUS + fraction * self.travel(size);
+        let minor = self.axis.minor(size) / 2.;
+        self.axis.pack(major, minor).into()
+    }
+
+    /// The value under `pos`, for a slider of the given `size`.
+    fn value_at(&self, size: Size, pos: Point) -> f64 {
+        let travel = self.travel(size);
+        if travel <= 0. {
+            return self.value;
+        }
+        let fraction = ((self.axis.major_pos(pos) - THUMB_RADIUS) / travel).clamp(0., 1.);
+        let fraction = match self.axis {
+            Axis::Horizontal => fraction,
+            Axis::Vertical => 1. - fraction,
+        };
+        self.snap(self.min + fraction * (self.max - self.min))
+    }
+
+    /// Change the value in response to user input, submitting an action if it changed.
+    fn change_value(&mut self, ctx: &mut EventCtx, value: f64) {
+        let value = self.snap(value);
+        if value != self.value {
+            self.value = value;
+            ctx.submit_action(Action::Slider(SliderAction::ValueChanged(value)));
+            ctx.request_render();
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl Slider {
+    /// Set the value shown by the slider.
+    ///
+    /// The value is clamped to the range, and snapped to the step.
+    /// This doesn't emit an action.
+    pub fn set_value(this: &mut WidgetMut<'_, Self>, value: f64) {
+        this.widget.value = this.widget.snap(value);
+        this.ctx.request_render();
+    }
+
+    /// Set the range of the slider.
+    ///
+    /// The current value is clamped to the new range.
+    pub fn set_range(this: &mut WidgetMut<'_, Self>, min: f64, max: f64) {
+        this.widget.min = min;
+        this.widget.max = max;
+        this.widget.update_wheel();
+        this.widget.value = this.widget.snap(this.widget.value);
+        this.ctx.request_render();
+    }
+
+    /// Set the step the value snaps to, or `None` for a continuous slider.
+    ///
+    /// The runtime equivalent of [`with_step`](Self::with_step).
+    pub fn set_step(this: &mut WidgetMut<'_, Self>, step: Option<f64>) {
+        this.widget.step = step;
+        this.widget.update_wheel();
+        this.widget.value = this.widget.snap(this.widget.value);
+        this.ctx.request_render();
+    }
+
+    /// Set the direction the slider moves along.
+    ///
+    /// The runtime equivalent of [`with_axis`](Self::with_axis).
+    pub fn set_axis(this: &mut WidgetMut<'_, Self>, axis: Axis) {
+        this.widget.axis = axis;
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for Slider {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let Some(value) = self.wheel.on_pointer_event(ctx, event, self.value) {
+            self.change_value(ctx, value);
+            return;
+        }
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::PointerDown(PointerButton::Primary, _) => {
+                ctx.capture_pointer();
+                ctx.request_focus();
+                let value = self.value_at(ctx.size(), event.local_position(ctx));
+                self.change_value(ctx, value);
+                // The thumb is drawn differently whilst dragged.
+                ctx.request_paint_only();
+            }
+            PointerEvent::PointerMove(_) => {
+                if ctx.is_pointer_capture_target() {
+                    let value = self.value_at(ctx.size(), event.local_position(ctx));
+                    self.change_value(ctx, value);
+                }
+            }
+            PointerEvent::PointerUp(PointerButton::Primary, _) => {
+                if ctx.is_pointer_capture_target() {
+                    ctx.submit_action(Action::Slider(SliderAction::DragFinished));
+                    ctx.request_paint_only();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if let TextEvent::KeyboardKey(key_event, _) = event {
+            if !key_event.state.is_pressed() || ctx.is_disabled() {
+                return;
+            }
+            if let Some(value) = self.value_for_key(&key_event.logical_key) {
+                self.change_value(ctx, value);
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if ctx.target() != ctx.widget_id() || ctx.is_disabled() {
+            return;
+        }
+        let value = match (event.action, &event.data) {
+            (accesskit::Action::SetValue, Some(accesskit::ActionData::NumericValue(value))) => {
+                *value
+            }
+            (accesskit::Action::Increment, _) => self.value + self.key_step(),
+            (accesskit::Action::Decrement, _) => self.value - self.key_step(),
+            _ => return,
+        };
+        self.change_value(ctx, value);
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx) {}
+
+    fn layout(
+        &mut self,
+        _ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let (width, height) = self
+            .axis
+            .pack(theme::WIDE_WIDGET_WIDTH, theme::BASIC_WIDGET_HEIGHT);
+        bc.constrain(Size::new(width, height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.size();
+        let border_width = 1.;
+        let thumb_center = self.thumb_center(size);
+
+        // The track runs between the centres of the thumb at each end of the range.
+        let minor_center = self.axis.minor(size) / 2.;
+        let (x0, y0) = self
+            .axis
+            .pack(THUMB_RADIUS, minor_center - TRACK_THICKNESS / 2.);
+        let (x1, y1) = self.axis.pack(
+            self.axis.major(size) - THUMB_RADIUS,
+            minor_center + TRACK_THICKNESS / 2.,
+        );
+        let track = Rect::new(x0, y0, x1, y1);
+        fill_lin_gradient(
+            scene,
+            &track.to_rounded_rect(TRACK_THICKNESS / 2.),
+            [theme::BACKGROUND_LIGHT, theme::BACKGROUND_DARK],
+            UnitPoint::TOP,
+            UnitPoint::BOTTOM,
+        );
+        stroke(
+            scene,
+            &track.to_rounded_rect(TRACK_THICKNESS / 2.),
+            theme::BORDER_DARK,
+            border_width,
+        );
+
+        // The part of the track from the minimum to the thumb is filled.
+        let filled = match self.axis {
+            Axis::Horizontal => Rect::new(track.x0, track.y0, thumb_center.x, track.y1),
+            Axis::Vertical => Rect::new(track.x0, thumb_center.y, track.x1, track.y1),
+        };
+        let (filled_start, filled_end) = if ctx.is_disabled() {
+            (
+                theme::DISABLED_FOREGROUND_LIGHT,
+                theme::DISABLED_FOREGROUND_DARK,
+            )
+        } else {
+            (theme::PRIMARY_LIGHT, theme::PRIMARY_DARK)
+        };
+        fill_lin_gradient(
+            scene,
+            &filled.to_rounded_rect(TRACK_THICKNESS / 2.),
+            [filled_start, filled_end],
+            UnitPoint::TOP,
+            UnitPoint::BOTTOM,
+        );
+
+        let thumb = Circle::new(thumb_center, THUMB_RADIUS - border_width / 2.);
+        let (thumb_start, thumb_end) = if ctx.is_disabled() {
+            (theme::DISABLED_BUTTON_LIGHT, theme::DISABLED_BUTTON_DARK)
+        } else if ctx.is_pointer_capture_target() {
+            (theme::FOREGROUND_DARK, theme::FOREGROUND_LIGHT)
+        } else {
+            (theme::FOREGROUND_LIGHT, theme::FOREGROUND_DARK)
+        };
+        fill_lin_gradient(
+            scene,
+            &thumb,
+            [thumb_start, thumb_end],
+            UnitPoint::TOP,
+            UnitPoint::BOTTOM,
+        );
+        let thumb_border = if ctx.is_focus_target() {
+            theme::PRIMARY_LIGHT
+        } else if ctx.is_hovered() && !ctx.is_disabled() {
+            theme::BORDER_LIGHT
+        } else {
+            theme::BORDER_DARK
+        };
+        stroke(scene, &thumb, thumb_border, border_width);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Slider
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.set_numeric_value(self.value);
+        node.set_min_numeric_value(self.min);
+        node.set_max_numeric_value(self.max);
+        if let Some(step) = self.step {
+            node.set_numeric_value_step(step);
+        }
+        node.set_orientation(match self.axis {
+            Axis::Horizontal => accesskit::Orientation::Horizontal,
+            Axis::Vertical => accesskit::Orientation::Vertical,
+        });
+        node.add_action(accesskit::Action::SetValue);
+        node.add_action(accesskit::Action::Increment);
+        node.add_action(accesskit::Action::Decrement);
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        SmallVec::new()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Slider", id = ctx.widget_id().trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.value.to_string())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{InputFuzzer, TestHarness, TestWidgetExt, widget_ids};
+
+    #[test]
+    fn value_is_clamped_and_snapped() {
+        let slider = Slider::new(0., 10., 12.);
+        assert_eq!(slider.value(), 10.);
+
+        let slider = Slider::new(0., 10., 3.3).with_step(0.5);
+        assert_eq!(slider.value(), 3.5);
+    }
+
+    #[test]
+    fn keys_adjust_value() {
+        let slider = Slider::new(0., 10., 5.).with_step(1.);
+        let key = |named| slider.value_for_key(&Key::Named(named));
+        assert_eq!(key(NamedKey::ArrowRight), Some(6.));
+        assert_eq!(key(NamedKey::ArrowDown), Some(4.));
+        assert_eq!(key(NamedKey::PageUp), Some(10.));
+        assert_eq!(key(NamedKey::Home), Some(0.));
+        assert_eq!(key(NamedKey::End), Some(10.));
+        assert_eq!(key(NamedKey::Enter), None);
+
+        // Without a step, keys move by a hundredth of the range.
+        let slider = Slider::new(0., 200., 100.);
+        assert_eq!(
+            slider.value_for_key(&Key::Named(NamedKey::ArrowLeft)),
+            Some(98.)
+        );
+    }
+
+    #[test]
+    fn drag_changes_value() {
+        let [slider_id] = widget_ids();
+        let slider = Slider::new(0., 100., 0.).with_step(1.).with_id(slider_id);
+        let width = 100. + 2. * THUMB_RADIUS;
+        let mut harness =
+            TestHarness::create_with_size(slider, Size::new(width, theme::BASIC_WIDGET_HEIGHT));
+
+        harness.mouse_move((THUMB_RADIUS + 25., 5.));
+        harness.mouse_button_press(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::Slider(SliderAction::ValueChanged(25.)), slider_id))
+        );
+        assert_eq!(harness.focused_widget_id(), Some(slider_id));
+
+        // Dragging past the end clamps the value.
+        harness.mouse_move((width + 50., 5.));
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::Slider(SliderAction::ValueChanged(100.)), slider_id))
+        );
+
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::Slider(SliderAction::DragFinished), slider_id))
+        );
+        assert_eq!(harness.pop_action(), None);
+    }
+
+    #[test]
+    fn vertical_slider_has_minimum_at_bottom() {
+        let slider = Slider::new(0., 1., 0.).with_axis(Axis::Vertical);
+        let size = Size::new(theme::BASIC_WIDGET_HEIGHT, 100.);
+        assert_eq!(slider.thumb_center(size).y, 100. - THUMB_RADIUS);
+        assert_eq!(slider.value_at(size, Point::new(0., 0.)), 1.);
+    }
+
+    #[test]
+    fn edit_slider() {
+        let [slider_id] = widget_ids();
+        let slider = Slider::new(0., 10., 5.).with_id(slider_id);
+        let mut harness = TestHarness::create(slider);
+
+        harness.edit_root_widget(|mut slider| {
+            let mut slider = slider.downcast::<Slider>();
+            Slider::set_range(&mut slider, 0., 4.);
+            Slider::set_step(&mut slider, Some(3.));
+        });
+        let slider = harness.get_widget(slider_id);
+        assert_eq!(slider.downcast::<Slider>().unwrap().value(), 3.);
+        // Setting the value programmatically doesn't emit an action.
+        assert_eq!(harness.pop_action(), None);
+    }
+
+    #[test]
+    fn fuzz_input() {
+        let slider = Slider::new(0., 10., 5.).with_step(0.5);
+        let mut harness = TestHarness::create_with_size(slider, Size::new(200., 40.));
+
+        InputFuzzer::new(0x511D).run(&mut harness);
+
+        // However the slider was dragged, its value stays in range and on a step.
+        let value = harness.root_widget().downcast::<Slider>().unwrap().value();
+        assert!((0. ..=10.).contains(&value), "{value} is out of range");
+        assert_eq!(value % 0.5, 0., "{value} isn't on a step");
+    }
+}
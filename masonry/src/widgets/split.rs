@@ -3,43 +3,60 @@
 
 //! A widget which splits an area in two, with a settable ratio, and optional draggable resizing.
 
+use std::time::{Duration, Instant};
+
 use accesskit::{Node, Role};
 use smallvec::{SmallVec, smallvec};
 use tracing::{Span, trace_span, warn};
 use vello::Scene;
+use vello::kurbo::Affine;
+use vello::peniko::{Brush, Fill};
 
 use crate::core::{
-    AccessCtx, AccessEvent, BoxConstraints, EventCtx, LayoutCtx, PaintCtx, PointerButton,
-    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Widget, WidgetId,
-    WidgetMut, WidgetPod,
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, SplitChange,
+    TextEvent, Widget, WidgetId, WidgetMut, WidgetPod,
 };
-use crate::kurbo::{Line, Point, Rect, Size};
-use crate::peniko::Color;
+use crate::kurbo::{Line, Point, Rect, Size, Vec2};
 use crate::theme;
-use crate::util::{fill_color, stroke};
+use crate::util::stroke;
 use crate::widgets::flex::Axis;
 use cursor_icon::CursorIcon;
 
-// TODO - Have child widget type as generic argument
+/// The longest time between two clicks on the splitter bar for them to count as a double-click.
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
 
 /// A container containing two other widgets, splitting the area either horizontally or vertically.
 ///
+/// When [draggable](Self::draggable), the user can move the splitter bar between the
+/// children, which submits [`Action::SplitResized`], and double-click it to restore the
+/// split point set by the app.
+///
 #[doc = crate::include_screenshot!("widget/screenshots/masonry__widget__split__tests__columns.png", "Split panel with two labels.")]
-pub struct Split {
+pub struct Split<ChildA: ?Sized = dyn Widget, ChildB: ?Sized = dyn Widget> {
     split_axis: Axis,
     split_point_chosen: f64,
     split_point_effective: f64,
+    /// The size of the first child along the split axis, if it is fixed rather than
+    /// a fraction of the available space.
+    first_pane_size: Option<f64>,
+    /// The split point and first child size set by the app, which a double-click restores.
+    reset_split: (f64, Option<f64>),
     min_size: (f64, f64), // Integers only
     bar_size: f64,        // Integers only
     min_bar_area: f64,    // Integers only
     solid: bool,
+    /// The brush to paint the splitter bar with, instead of the default colors.
+    bar_brush: Option<Brush>,
     draggable: bool,
     /// Offset from the split point (bar center) to the actual mouse position when the
     /// bar was clicked. This is used to ensure a click without mouse move is a no-op,
     /// instead of re-centering the bar on the mouse.
     click_offset: f64,
-    child1: WidgetPod<dyn Widget>,
-    child2: WidgetPod<dyn Widget>,
+    /// The time when the splitter bar was last clicked, used to detect double-clicks.
+    last_click_time: Option<Instant>,
+    child1: WidgetPod<ChildA>,
+    child2: WidgetPod<ChildB>,
 }
 
 // --- MARK: BUILDERS ---
@@ -49,19 +66,11 @@ impl Split {
     /// Horizontal split axis means that the children are left and right.
     /// Vertical split axis means that the children are up and down.
     fn new(split_axis: Axis, child1: impl Widget + 'static, child2: impl Widget + 'static) -> Self {
-        Self {
+        Self::from_pods(
             split_axis,
-            split_point_chosen: 0.5,
-            split_point_effective: 0.5,
-            min_size: (0.0, 0.0),
-            bar_size: 6.0,
-            min_bar_area: 6.0,
-            solid: false,
-            draggable: false,
-            click_offset: 0.0,
-            child1: WidgetPod::new(child1).erased(),
-            child2: WidgetPod::new(child2).erased(),
-        }
+            WidgetPod::new(child1).erased(),
+            WidgetPod::new(child2).erased(),
+        )
     }
 
     /// Create a new split panel, with the horizontal axis split in two by a vertical bar.
@@ -75,6 +84,37 @@ impl Split {
     pub fn rows(child1: impl Widget + 'static, child2: impl Widget + 'static) -> Self {
         Self::new(Axis::Vertical, child1, child2)
     }
+}
+
+impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
+    /// Create a new split panel from the children in `child1` and `child2`, with
+    /// `split_axis` being split in two.
+    ///
+    /// [`Axis::Horizontal`] lays the children out left and right, like [`columns`](Split::columns),
+    /// and [`Axis::Vertical`] lays them out up and down, like [`rows`](Split::rows).
+    pub fn from_pods(
+        split_axis: Axis,
+        child1: WidgetPod<ChildA>,
+        child2: WidgetPod<ChildB>,
+    ) -> Self {
+        Self {
+            split_axis,
+            split_point_chosen: 0.5,
+            split_point_effective: 0.5,
+            first_pane_size: None,
+            reset_split: (0.5, None),
+            min_size: (0.0, 0.0),
+            bar_size: 6.0,
+            min_bar_area: 6.0,
+            solid: false,
+            bar_brush: None,
+            draggable: false,
+            click_offset: 0.0,
+            last_click_time: None,
+            child1,
+            child2,
+        }
+    }
 
     /// Builder-style method to set the split point as a fraction of the split axis.
     ///
@@ -86,6 +126,28 @@ impl Split {
             "split_point must be in the range [0.0-1.0]!"
         );
         self.split_point_chosen = split_point;
+        self.reset_split.0 = split_point;
+        self
+    }
+
+    /// Builder-style method to give the first child a fixed size along the split axis,
+    /// rather than a fraction of the available space.
+    ///
+    /// When the split panel is resized, all of the change goes to the second child.
+    /// This is useful for e.g. a sidebar.
+    /// Dragging the splitter bar changes this size.
+    /// The size is still limited by the [minimum sizes](Self::min_size).
+    ///
+    /// The value must be greater than or equal to `0.0`.
+    /// If this is `None` (the default), the [split point](Self::split_point) is used instead.
+    pub fn first_pane_size(mut self, size: impl Into<Option<f64>>) -> Self {
+        let size = size.into();
+        assert!(
+            size.is_none_or(|size| size >= 0.0),
+            "first_pane_size must be 0.0 or greater!"
+        );
+        self.first_pane_size = size;
+        self.reset_split.1 = size;
         self
     }
 
@@ -130,6 +192,9 @@ impl Split {
     }
 
     /// Builder-style method to set whether the split point can be changed by dragging.
+    ///
+    /// Each change submits [`Action::SplitResized`].
+    /// Double-clicking the splitter bar restores the split point set by the app.
     pub fn draggable(mut self, draggable: bool) -> Self {
         self.draggable = draggable;
         self
@@ -142,10 +207,19 @@ impl Split {
         self.solid = solid;
         self
     }
+
+    /// Builder-style method to set the brush the splitter bar is painted with.
+    ///
+    /// If this is `None` (the default), the bar is painted with a color from the theme,
+    /// which is lighter if the bar is draggable.
+    pub fn bar_brush(mut self, brush: impl Into<Option<Brush>>) -> Self {
+        self.bar_brush = brush.into();
+        self
+    }
 }
 
 // --- MARK: INTERNALS ---
-impl Split {
+impl<ChildA: ?Sized, ChildB: ?Sized> Split<ChildA, ChildB> {
     /// Returns the size of the splitter bar area.
     #[inline]
     fn bar_area(&self) -> f64 {
@@ -219,21 +293,63 @@ impl Split {
         (min_limit, max_limit)
     }
 
-    /// Set a new chosen split point.
+    /// Returns the size of the area shared by the children, given the specified total size.
+    fn reduced_size(&self, size: Size) -> Size {
+        let bar_area = self.bar_area();
+        Size::new(
+            (size.width - bar_area).max(0.),
+            (size.height - bar_area).max(0.),
+        )
+    }
+
+    /// Returns the split point to lay the children out with, as a fraction of `reduced_size`.
+    fn effective_split_point(&self, reduced_size: Size) -> f64 {
+        let (min_limit, max_limit) = self.split_side_limits(reduced_size);
+        let reduced_axis_size = self.split_axis.major(reduced_size);
+        if reduced_axis_size.is_infinite() || reduced_axis_size <= f64::EPSILON {
+            0.5
+        } else {
+            let split_point = match self.first_pane_size {
+                Some(first_pane_size) => first_pane_size / reduced_axis_size,
+                None => self.split_point_chosen,
+            };
+            split_point.clamp(min_limit / reduced_axis_size, max_limit / reduced_axis_size)
+        }
+    }
+
+    /// Returns the split point which the children will be laid out with, given the
+    /// specified total size.
+    fn split_change(&self, size: Size) -> SplitChange {
+        let reduced_size = self.reduced_size(size);
+        let fraction = self.effective_split_point(reduced_size);
+        SplitChange {
+            fraction,
+            first_pane_size: (self.split_axis.major(reduced_size) * fraction).floor(),
+        }
+    }
+
+    /// Set a new chosen split point, so that the center of the splitter bar is at `mouse_pos`.
     fn update_split_point(&mut self, size: Size, mouse_pos: Point) {
-        let (min_limit, max_limit) = self.split_side_limits(size);
-        self.split_point_chosen = match self.split_axis {
-            Axis::Horizontal => mouse_pos.x.clamp(min_limit, max_limit) / size.width,
-            Axis::Vertical => mouse_pos.y.clamp(min_limit, max_limit) / size.height,
+        let reduced_size = self.reduced_size(size);
+        let reduced_axis_size = self.split_axis.major(reduced_size);
+        if reduced_axis_size <= f64::EPSILON {
+            return;
+        }
+        let (min_limit, max_limit) = self.split_side_limits(reduced_size);
+        let first_pane_size = (self.split_axis.major_pos(mouse_pos) - self.bar_area() / 2.0)
+            .clamp(min_limit, max_limit);
+        self.split_point_chosen = first_pane_size / reduced_axis_size;
+        if self.first_pane_size.is_some() {
+            self.first_pane_size = Some(first_pane_size);
         }
     }
 
-    /// Returns the color of the splitter bar.
-    fn bar_color(&self) -> Color {
-        if self.draggable {
-            theme::BORDER_LIGHT
-        } else {
-            theme::BORDER_DARK
+    /// Returns the brush to paint the splitter bar with.
+    fn effective_bar_brush(&self) -> Brush {
+        match &self.bar_brush {
+            Some(brush) => brush.clone(),
+            None if self.draggable => theme::BORDER_LIGHT.into(),
+            None => theme::BORDER_DARK.into(),
         }
     }
 
@@ -251,8 +367,13 @@ impl Split {
                 Point::new(size.width, edge2 - padding.floor()),
             ),
         };
-        let splitter_color = self.bar_color();
-        fill_color(scene, &rect, splitter_color);
+        scene.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            &self.effective_bar_brush(),
+            None,
+            &rect,
+        );
     }
 
     fn paint_stroked_bar(&mut self, ctx: &mut PaintCtx, scene: &mut Scene) {
@@ -285,16 +406,39 @@ impl Split {
                 ),
             ),
         };
-        let splitter_color = self.bar_color();
-        stroke(scene, &line1, splitter_color, line_width);
-        stroke(scene, &line2, splitter_color, line_width);
+        let brush = self.effective_bar_brush();
+        stroke(scene, &line1, &brush, line_width);
+        stroke(scene, &line2, &brush, line_width);
     }
 }
 
 // FIXME - Add unit tests for WidgetMut<Split>
 
 // --- MARK: WIDGETMUT ---
-impl Split {
+impl<ChildA, ChildB> Split<ChildA, ChildB>
+where
+    ChildA: Widget + FromDynWidget + ?Sized,
+    ChildB: Widget + FromDynWidget + ?Sized,
+{
+    /// Get a mutable reference to the first child.
+    pub fn child1_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, ChildA> {
+        this.ctx.get_mut(&mut this.widget.child1)
+    }
+
+    /// Get a mutable reference to the second child.
+    pub fn child2_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, ChildB> {
+        this.ctx.get_mut(&mut this.widget.child2)
+    }
+
+    /// Set the axis which is split in two.
+    ///
+    /// Horizontal split axis means that the children are left and right.
+    /// Vertical split axis means that the children are up and down.
+    pub fn set_split_axis(this: &mut WidgetMut<'_, Self>, split_axis: Axis) {
+        this.widget.split_axis = split_axis;
+        this.ctx.request_layout();
+    }
+
     /// Set the split point as a fraction of the split axis.
     ///
     /// The value must be between `0.0` and `1.0`, inclusive.
@@ -305,6 +449,20 @@ impl Split {
             "split_point must be in the range [0.0-1.0]!"
         );
         this.widget.split_point_chosen = split_point;
+        this.widget.reset_split.0 = split_point;
+        this.ctx.request_layout();
+    }
+
+    /// Give the first child a fixed size along the split axis, or `None` to use the split point.
+    ///
+    /// See [`first_pane_size`](Self::first_pane_size) for more details.
+    pub fn set_first_pane_size(this: &mut WidgetMut<'_, Self>, size: Option<f64>) {
+        assert!(
+            size.is_none_or(|size| size >= 0.0),
+            "first_pane_size must be 0.0 or greater!"
+        );
+        this.widget.first_pane_size = size;
+        this.widget.reset_split.1 = size;
         this.ctx.request_layout();
     }
 
@@ -364,10 +522,22 @@ impl Split {
         // Bar solidity impacts appearance, but not accessibility node
         this.ctx.request_paint_only();
     }
+
+    /// Set the brush the splitter bar is painted with, or `None` to use the default colors.
+    ///
+    /// The runtime equivalent of [`bar_brush`](Self::bar_brush).
+    pub fn set_bar_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Option<Brush>>) {
+        this.widget.bar_brush = brush.into();
+        this.ctx.request_paint_only();
+    }
 }
 
 // --- MARK: IMPL WIDGET ---
-impl Widget for Split {
+impl<ChildA, ChildB> Widget for Split<ChildA, ChildB>
+where
+    ChildA: Widget + FromDynWidget + ?Sized,
+    ChildB: Widget + FromDynWidget + ?Sized,
+{
     fn on_pointer_event(
         &mut self,
         ctx: &mut EventCtx,
@@ -383,25 +553,41 @@ impl Widget for Split {
                         ctx.set_handled();
                         ctx.capture_pointer();
                         // Save the delta between the mouse click position and the split point
-                        self.click_offset = match self.split_axis {
-                            Axis::Horizontal => state.position.x,
-                            Axis::Vertical => state.position.y,
-                        } - self.bar_position(ctx.size());
+                        self.click_offset = self.split_axis.major_pos(local_mouse_pos)
+                            - self.bar_position(ctx.size());
+
+                        let now = Instant::now();
+                        let is_double_click = self
+                            .last_click_time
+                            .is_some_and(|time| now.duration_since(time) < DOUBLE_CLICK_TIME);
+                        if is_double_click {
+                            self.last_click_time = None;
+                            (self.split_point_chosen, self.first_pane_size) = self.reset_split;
+                            ctx.submit_action(Action::SplitResized(self.split_change(ctx.size())));
+                            ctx.request_layout();
+                        } else {
+                            self.last_click_time = Some(now);
+                        }
                     }
                 }
                 PointerEvent::PointerMove(state) => {
                     if ctx.is_pointer_capture_target() {
                         // If widget has pointer capture, assume always it's hovered
+                        let mouse_pos = Point::new(state.position.x, state.position.y);
+                        let local_mouse_pos = mouse_pos - ctx.window_origin().to_vec2();
                         let effective_pos = match self.split_axis {
-                            Axis::Horizontal => {
-                                Point::new(state.position.x - self.click_offset, state.position.y)
-                            }
-                            Axis::Vertical => {
-                                Point::new(state.position.x, state.position.y - self.click_offset)
-                            }
+                            Axis::Horizontal => local_mouse_pos - Vec2::new(self.click_offset, 0.0),
+                            Axis::Vertical => local_mouse_pos - Vec2::new(0.0, self.click_offset),
                         };
+                        let old_change = self.split_change(ctx.size());
                         self.update_split_point(ctx.size(), effective_pos);
-                        ctx.request_layout();
+                        let new_change = self.split_change(ctx.size());
+                        if new_change != old_change {
+                            // A click which drags the bar doesn't start a double-click.
+                            self.last_click_time = None;
+                            ctx.submit_action(Action::SplitResized(new_change));
+                            ctx.request_layout();
+                        }
                     }
                 }
                 _ => {}
@@ -451,22 +637,10 @@ impl Widget for Split {
 
         let mut my_size = bc.max();
         let bar_area = self.bar_area();
-        let reduced_size = Size::new(
-            (my_size.width - bar_area).max(0.),
-            (my_size.height - bar_area).max(0.),
-        );
+        let reduced_size = self.reduced_size(my_size);
 
         // Update our effective split point to respect our constraints
-        self.split_point_effective = {
-            let (min_limit, max_limit) = self.split_side_limits(reduced_size);
-            let reduced_axis_size = self.split_axis.major(reduced_size);
-            if reduced_axis_size.is_infinite() || reduced_axis_size <= f64::EPSILON {
-                0.5
-            } else {
-                self.split_point_chosen
-                    .clamp(min_limit / reduced_axis_size, max_limit / reduced_axis_size)
-            }
-        };
+        self.split_point_effective = self.effective_split_point(reduced_size);
 
         // TODO - The minimum height / width should really be zero here.
 
@@ -584,8 +758,32 @@ mod tests {
 
     use super::*;
     use crate::assert_render_snapshot;
-    use crate::testing::TestHarness;
-    use crate::widgets::Label;
+    use crate::testing::{InputFuzzer, TestHarness};
+    use crate::widgets::{Label, SizedBox, Textbox};
+
+    /// The width of the first child of the split panel in `harness`.
+    fn first_child_width(harness: &TestHarness) -> f64 {
+        harness.root_widget().children()[0].ctx().size().width
+    }
+
+    /// The split point submitted since the last call, if any.
+    fn split_change(harness: &mut TestHarness) -> Option<SplitChange> {
+        std::iter::from_fn(|| harness.pop_action())
+            .filter_map(|(action, _)| match action {
+                Action::SplitResized(change) => Some(change),
+                _ => None,
+            })
+            .last()
+    }
+
+    /// Assert that `size` is `expected`, give or take the rounding of the split point to whole pixels.
+    #[track_caller]
+    fn assert_near(size: f64, expected: f64) {
+        assert!(
+            (size - expected).abs() <= 1.0,
+            "expected a size of about {expected}, got {size}"
+        );
+    }
 
     #[test]
     fn columns() {
@@ -615,8 +813,60 @@ mod tests {
         assert_render_snapshot!(harness, "rows");
     }
 
-    // FIXME - test moving the split point by mouse
-    // test draggable and min_bar_area
+    #[test]
+    fn drag_bar() {
+        let widget = Split::columns(SizedBox::empty(), SizedBox::empty())
+            .min_size(50.0, 30.0)
+            .draggable(true);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200.0, 100.0));
+        // The children share the 194 pixels which aren't covered by the 6 pixel bar.
+        assert_eq!(first_child_width(&harness), 97.0);
+
+        // Pressing the bar without moving doesn't move it.
+        harness.mouse_move((101.0, 50.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        assert_eq!(split_change(&mut harness), None);
+
+        harness.mouse_move((124.0, 50.0));
+        let change = split_change(&mut harness).expect("dragging should submit the split point");
+        assert_near(change.first_pane_size, 120.0);
+        assert_near(change.fraction * 194.0, 120.0);
+        assert_near(first_child_width(&harness), 120.0);
+
+        // The bar can't be dragged past the minimum sizes.
+        harness.mouse_move((10.0, 50.0));
+        assert_near(split_change(&mut harness).unwrap().first_pane_size, 50.0);
+        assert_near(first_child_width(&harness), 50.0);
+        harness.mouse_move((199.0, 50.0));
+        assert_near(split_change(&mut harness).unwrap().first_pane_size, 164.0);
+        harness.mouse_button_release(PointerButton::Primary);
+
+        // Double-clicking the bar restores the split point set by the app.
+        harness.mouse_move((167.0, 50.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        let change = split_change(&mut harness).unwrap();
+        assert_eq!(change.fraction, 0.5);
+        assert_eq!(first_child_width(&harness), 97.0);
+    }
+
+    #[test]
+    fn fixed_first_pane() {
+        let first_width = |width: f64| {
+            let widget = Split::columns(SizedBox::empty(), SizedBox::empty()).first_pane_size(80.0);
+            let harness = TestHarness::create_with_size(widget, Size::new(width, 100.0));
+            first_child_width(&harness)
+        };
+        // The second child gets all of the extra space.
+        assert_near(first_width(200.0), 80.0);
+        assert_near(first_width(400.0), 80.0);
+        // The first child still shrinks if there isn't room for it.
+        assert_near(first_width(50.0), 44.0);
+    }
+
+    // FIXME - test min_bar_area
 
     #[test]
     fn edit_splitter() {
@@ -654,4 +904,15 @@ mod tests {
         // We don't use assert_eq because we don't want rich assert
         assert!(image_1 == image_2);
     }
+
+    #[test]
+    fn fuzz_input() {
+        let widget = Split::columns(Textbox::new("Hello"), Label::new("World"))
+            .min_size(20.0, 20.0)
+            .draggable(true);
+
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200.0, 100.0));
+
+        InputFuzzer::new(0x5B17).run(&mut harness);
+    }
 }
@@ -0,0 +1,266 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which sets where its child comes in the Tab order.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+
+/// A widget which sets the position of its child in the order Tab moves focus in.
+///
+/// Widgets with a positive index are focused first, in ascending order of index,
+/// followed by those with no index or an index of 0, in tree order.
+/// Widgets with a negative index are skipped by Tab, but can still be focused
+/// by clicking them or by requesting focus.
+///
+/// The index applies to all of the child's descendants which accept focus, unless
+/// they are inside another `TabIndex`.
+/// See [`set_tab_index`](EventCtx::set_tab_index) for setting the index of a widget directly.
+pub struct TabIndex<W: ?Sized> {
+    child: WidgetPod<W>,
+    index: i32,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> TabIndex<W> {
+    /// Create a new `TabIndex` which gives `child` the tab index `index`.
+    pub fn new(child: W, index: i32) -> Self {
+        Self::from_pod(WidgetPod::new(child), index)
+    }
+}
+
+impl<W: Widget + ?Sized> TabIndex<W> {
+    /// Create a new `TabIndex` which gives the child in `child` the tab index `index`.
+    pub fn from_pod(child: WidgetPod<W>, index: i32) -> Self {
+        Self { child, index }
+    }
+
+    /// The tab index given to the child.
+    pub fn index(&self) -> i32 {
+        self.index
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> TabIndex<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the tab index given to the child.
+    pub fn set_index(this: &mut WidgetMut<'_, Self>, index: i32) {
+        this.widget.index = index;
+        this.ctx.set_tab_index(Some(index));
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for TabIndex<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            // The index is applied to the child by the focus chain pass.
+            ctx.set_tab_index(Some(self.index));
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("TabIndex", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{
+        ModularWidget, Record, Recording, TestHarness, TestWidgetExt, widget_ids,
+    };
+    use crate::widgets::Flex;
+
+    fn focusable() -> ModularWidget<()> {
+        ModularWidget::new(()).accepts_focus(true)
+    }
+
+    /// The widgets which are focused by pressing Tab `count` times, starting with no focus.
+    fn tab_order(harness: &mut TestHarness, count: usize) -> Vec<Option<WidgetId>> {
+        harness.focus_on(None);
+        (0..count)
+            .map(|_| {
+                harness.focus_next(true);
+                harness.focused_widget_id()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn mixed_indices() {
+        let [a, b, c, d, e] = widget_ids();
+        let widget = Flex::column()
+            .with_child_id(focusable(), a)
+            .with_child(TabIndex::from_pod(
+                WidgetPod::new_with_id(focusable(), b),
+                2,
+            ))
+            .with_child_id(focusable(), c)
+            .with_child(TabIndex::from_pod(
+                WidgetPod::new_with_id(focusable(), d),
+                1,
+            ))
+            .with_child(TabIndex::from_pod(
+                WidgetPod::new_with_id(focusable(), e),
+                -1,
+            ));
+
+        let mut harness = TestHarness::create(widget);
+        // Positive indices come first, then the others in tree order, skipping negative indices.
+        assert_eq!(
+            tab_order(&mut harness, 5),
+            [Some(d), Some(b), Some(a), Some(c), Some(d)]
+        );
+
+        // Shift+Tab goes through the same order in reverse.
+        harness.focus_next(false);
+        assert_eq!(harness.focused_widget_id(), Some(c));
+
+        // A widget which is skipped by Tab can still be focused directly.
+        harness.focus_on(Some(e));
+        assert_eq!(harness.focused_widget_id(), Some(e));
+    }
+
+    #[test]
+    fn index_applies_to_descendants() {
+        let [a, b, c, d] = widget_ids();
+        let group = Flex::column()
+            .with_child_id(focusable(), b)
+            .with_child(TabIndex::from_pod(
+                WidgetPod::new_with_id(focusable(), c),
+                1,
+            ))
+            .with_child_id(focusable(), d);
+        let widget = Flex::row()
+            .with_child_id(focusable(), a)
+            .with_child(TabIndex::new(group, 3));
+
+        let mut harness = TestHarness::create(widget);
+        // The innermost index wins; equal indices are in tree order.
+        assert_eq!(
+            tab_order(&mut harness, 4),
+            [Some(c), Some(b), Some(d), Some(a)]
+        );
+
+        // Changing the index of the group moves all of its widgets without one of their own.
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            let mut tab_index = Flex::child_mut(&mut flex, 1).unwrap();
+            TabIndex::<Flex>::set_index(&mut tab_index.downcast(), 0);
+        });
+        assert_eq!(
+            tab_order(&mut harness, 4),
+            [Some(c), Some(a), Some(b), Some(d)]
+        );
+    }
+
+    #[test]
+    fn programmatic_focus_fires_focus_changed() {
+        let [a, b] = widget_ids();
+        let recording_a = Recording::default();
+        let recording_b = Recording::default();
+        let widget = Flex::column()
+            .with_child_id(focusable().record(&recording_a), a)
+            .with_child_id(focusable().record(&recording_b), b);
+        let mut harness = TestHarness::create(widget);
+        let focus_changes = |recording: &Recording| {
+            recording
+                .drain()
+                .into_iter()
+                .filter_map(|record| match record {
+                    Record::U(Update::FocusChanged(focused)) => Some(focused),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        recording_a.clear();
+        recording_b.clear();
+
+        // Focus requested by a widget is reported like focus moved by Tab.
+        harness.edit_widget(b, |mut widget| widget.ctx.request_focus());
+        assert_eq!(harness.focused_widget_id(), Some(b));
+        assert_eq!(focus_changes(&recording_a), []);
+        assert_eq!(focus_changes(&recording_b), [true]);
+
+        harness.focus_next(true);
+        assert_eq!(harness.focused_widget_id(), Some(a));
+        assert_eq!(focus_changes(&recording_a), [true]);
+        assert_eq!(focus_changes(&recording_b), [false]);
+    }
+}
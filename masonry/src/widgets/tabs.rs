@@ -0,0 +1,822 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A row of tabs, each of which shows a different child.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Insets, Line, Point, Rect, Size};
+use winit::keyboard::{Key, NamedKey};
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, ArcStr, BoxConstraints, EventCtx, LayoutCtx, PaintCtx,
+    PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent,
+    Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::util::{fill_color, stroke};
+use crate::widgets::Label;
+
+/// The padding around the header of each tab.
+const HEADER_INSETS: Insets = Insets::uniform_xy(10., 6.);
+/// The thickness of the line under the header of the selected tab.
+const INDICATOR_WIDTH: f64 = 2.;
+
+/// A row of tab headers, with the content of the selected tab shown below them.
+///
+/// Each tab has a header, which is usually a [`Label`], and content, which can be any widget.
+/// Clicking a header selects its tab. Whilst the tabs are focused, the Left and Right arrow
+/// keys select the previous or next tab (wrapping around at either end), and Home and End
+/// select the first or last tab.
+///
+/// The content of the other tabs is [stashed](crate::doc::doc_06_masonry_concepts#stashed)
+/// rather than removed, so it keeps its state, such as the scroll position of a portal or the
+/// selection in a text area, for when its tab is selected again.
+///
+/// For accessibility, this widget is a tab list, and each header and content widget is
+/// wrapped in a [`TabHeader`] and a [`TabPanel`] respectively.
+///
+/// This widget emits [`Action::TabSelected`] when the user selects a different tab.
+pub struct Tabs {
+    tabs: Vec<Tab>,
+    /// The index of the selected tab.
+    ///
+    /// If this is out of range, no content is shown.
+    selected: usize,
+    /// The area of each header from the last layout, in local coordinates.
+    header_rects: Vec<Rect>,
+}
+
+struct Tab {
+    header: WidgetPod<TabHeader>,
+    panel: WidgetPod<TabPanel>,
+}
+
+impl Tab {
+    fn new(header: WidgetPod<dyn Widget>, content: WidgetPod<dyn Widget>, selected: bool) -> Self {
+        let header_id = WidgetId::next();
+        let panel_id = WidgetId::next();
+        let header = TabHeader {
+            child: header,
+            selected,
+            panel: panel_id,
+        };
+        let panel = TabPanel {
+            child: content,
+            header: header_id,
+        };
+        Self {
+            header: WidgetPod::new_with_id(header, header_id),
+            panel: WidgetPod::new_with_id(panel, panel_id),
+        }
+    }
+}
+
+// --- MARK: BUILDERS ---
+impl Tabs {
+    /// Create a new `Tabs` with no tabs, in which the first tab to be added is selected.
+    pub fn new() -> Self {
+        Self {
+            tabs: Vec::new(),
+            selected: 0,
+            header_rects: Vec::new(),
+        }
+    }
+
+    /// Builder-style method to add a tab with a text label as its header.
+    pub fn with_tab(self, label: impl Into<ArcStr>, content: impl Widget) -> Self {
+        self.with_tab_pod(
+            WidgetPod::new(Label::new(label)).erased(),
+            WidgetPod::new(content).erased(),
+        )
+    }
+
+    /// Builder-style method to add a tab with any widget as its header.
+    pub fn with_tab_pod(
+        mut self,
+        header: WidgetPod<dyn Widget>,
+        content: WidgetPod<dyn Widget>,
+    ) -> Self {
+        let selected = self.tabs.len() == self.selected;
+        self.tabs.push(Tab::new(header, content, selected));
+        self
+    }
+
+    /// Builder-style method to set the index of the selected tab, which defaults to 0.
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        for (idx, tab) in self.tabs.iter_mut().enumerate() {
+            if let Some(header) = tab.header.inner_mut() {
+                header.selected = idx == selected;
+            }
+        }
+        self
+    }
+
+    /// The index of the selected tab.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// The number of tabs.
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Whether there are no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// The index of the tab whose header is at `pos`, in local coordinates.
+    fn tab_at(&self, pos: Point) -> Option<usize> {
+        self.header_rects.iter().position(|rect| rect.contains(pos))
+    }
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The tab to select when `key` is pressed whilst the tab at `selected` is selected, out of `len`.
+fn tab_for_key(key: &Key, selected: usize, len: usize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let selected = selected.min(len - 1);
+    match key {
+        Key::Named(NamedKey::ArrowLeft) => Some((selected + len - 1) % len),
+        Key::Named(NamedKey::ArrowRight) => Some((selected + 1) % len),
+        Key::Named(NamedKey::Home) => Some(0),
+        Key::Named(NamedKey::End) => Some(len - 1),
+        _ => None,
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl Tabs {
+    /// Add a tab with a text label as its header, after the existing tabs.
+    pub fn add_tab(this: &mut WidgetMut<'_, Self>, label: impl Into<ArcStr>, content: impl Widget) {
+        let idx = this.widget.tabs.len();
+        Self::insert_tab_pod(
+            this,
+            idx,
+            WidgetPod::new(Label::new(label)).erased(),
+            WidgetPod::new(content).erased(),
+        );
+    }
+
+    /// Insert a tab with any widget as its header, at `idx`.
+    ///
+    /// The tab which was selected stays selected.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is greater than the number of tabs.
+    pub fn insert_tab_pod(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        header: WidgetPod<dyn Widget>,
+        content: WidgetPod<dyn Widget>,
+    ) {
+        if idx <= this.widget.selected && this.widget.selected < this.widget.tabs.len() {
+            this.widget.selected += 1;
+        }
+        let selected = idx == this.widget.selected;
+        this.widget
+            .tabs
+            .insert(idx, Tab::new(header, content, selected));
+        this.ctx.children_changed();
+    }
+
+    /// Remove the tab at `idx`.
+    ///
+    /// If it was selected, the tab after it (or the new last tab) is selected instead,
+    /// without emitting an action.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is out of range.
+    pub fn remove_tab(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let tab = this.widget.tabs.remove(idx);
+        this.ctx.remove_child(tab.header);
+        this.ctx.remove_child(tab.panel);
+        let len = this.widget.tabs.len();
+        if idx < this.widget.selected {
+            this.widget.selected -= 1;
+        } else if idx == this.widget.selected && len > 0 {
+            this.widget.selected = idx.min(len - 1);
+            Self::update_headers(this);
+        }
+        this.ctx.request_layout();
+    }
+
+    /// Move the tab at index `from` to index `to`, shifting the tabs in between.
+    ///
+    /// Unlike removing and re-adding it, this preserves the state of the tab's widgets.
+    /// The tab which was selected stays selected.
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` is out of bounds.
+    pub fn move_tab(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let tab = this.widget.tabs.remove(from);
+        this.widget.tabs.insert(to, tab);
+        let selected = &mut this.widget.selected;
+        if *selected == from {
+            *selected = to;
+        } else if from < *selected && *selected <= to {
+            *selected -= 1;
+        } else if to <= *selected && *selected < from {
+            *selected += 1;
+        }
+        this.ctx.children_changed();
+    }
+
+    /// Set the index of the selected tab.
+    ///
+    /// This doesn't emit an action.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, selected: usize) {
+        if selected == this.widget.selected {
+            return;
+        }
+        this.widget.selected = selected;
+        Self::update_headers(this);
+        this.ctx.request_layout();
+    }
+
+    /// Replace the header of the tab at `idx` with a label showing `label`.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is out of range, or the tab's header isn't a [`Label`].
+    pub fn set_label(this: &mut WidgetMut<'_, Self>, idx: usize, label: impl Into<ArcStr>) {
+        let mut header = Self::header_mut(this, idx);
+        let mut child = TabHeader::child_mut(&mut header);
+        Label::set_text(&mut child.downcast(), label);
+    }
+
+    /// Get a mutable reference to the header of the tab at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is out of range.
+    pub fn header_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        idx: usize,
+    ) -> WidgetMut<'t, TabHeader> {
+        this.ctx.get_mut(&mut this.widget.tabs[idx].header)
+    }
+
+    /// Get a mutable reference to the panel containing the content of the tab at `idx`.
+    ///
+    /// This can be used whilst the tab isn't selected.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is out of range.
+    pub fn panel_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, TabPanel> {
+        this.ctx.get_mut(&mut this.widget.tabs[idx].panel)
+    }
+
+    /// Tell each header whether its tab is selected.
+    fn update_headers(this: &mut WidgetMut<'_, Self>) {
+        let selected = this.widget.selected;
+        for (idx, tab) in this.widget.tabs.iter_mut().enumerate() {
+            // Tabs which were just inserted aren't in the widget tree yet.
+            if let Some(header) = tab.header.inner_mut() {
+                header.selected = idx == selected;
+                continue;
+            }
+            let mut header = this.ctx.get_mut(&mut tab.header);
+            if header.widget.selected != (idx == selected) {
+                TabHeader::set_selected(&mut header, idx == selected);
+            }
+        }
+    }
+
+    /// Select the tab at `idx` on behalf of the user.
+    fn choose(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        if idx != this.widget.selected && idx < this.widget.tabs.len() {
+            Self::set_selected(this, idx);
+            this.ctx.submit_action(Action::TabSelected(idx));
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for Tabs {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::PointerDown(PointerButton::Primary, state) = event {
+            if ctx.is_disabled() {
+                return;
+            }
+            let pos = Point::new(state.position.x, state.position.y);
+            let local_pos = pos - ctx.window_origin().to_vec2();
+            if let Some(idx) = self.tab_at(local_pos) {
+                ctx.request_focus();
+                ctx.mutate_self_later(move |mut this| {
+                    Self::choose(&mut this.downcast(), idx);
+                });
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::KeyboardKey(key_event, _) = event else {
+            return;
+        };
+        // Keys pressed in the content of a tab also reach this widget.
+        if !key_event.state.is_pressed() || !ctx.is_focus_target() || ctx.is_disabled() {
+            return;
+        }
+        if let Some(idx) = tab_for_key(&key_event.logical_key, self.selected, self.tabs.len()) {
+            ctx.mutate_self_later(move |mut this| {
+                Self::choose(&mut this.downcast(), idx);
+            });
+            ctx.set_handled();
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action != accesskit::Action::Click || ctx.is_disabled() {
+            return;
+        }
+        let target = ctx.target();
+        if let Some(idx) = self.tabs.iter().position(|tab| tab.header.id() == target) {
+            ctx.mutate_self_later(move |mut this| {
+                Self::choose(&mut this.downcast(), idx);
+            });
+            ctx.set_handled();
+        }
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::FocusChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        for tab in &mut self.tabs {
+            ctx.register_child(&mut tab.header);
+            ctx.register_child(&mut tab.panel);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let header_bc = BoxConstraints::new(Size::ZERO, Size::new(f64::INFINITY, bc.max().height));
+        let header_sizes: Vec<Size> = self
+            .tabs
+            .iter_mut()
+            .map(|tab| ctx.run_layout(&mut tab.header, &header_bc))
+            .collect();
+        let bar_height = header_sizes
+            .iter()
+            .map(|size| size.height)
+            .fold(0., f64::max);
+
+        self.header_rects.clear();
+        let mut x = 0.;
+        for (tab, size) in self.tabs.iter_mut().zip(&header_sizes) {
+            ctx.place_child(&mut tab.header, Point::new(x, 0.));
+            self.header_rects
+                .push(Rect::new(x, 0., x + size.width, bar_height));
+            x += size.width;
+        }
+
+        let content_bc = bc.shrink((0., bar_height));
+        let mut content_size = Size::ZERO;
+        for (idx, tab) in self.tabs.iter_mut().enumerate() {
+            let shown = idx == self.selected;
+            ctx.set_stashed(&mut tab.panel, !shown);
+            if shown {
+                content_size = ctx.run_layout(&mut tab.panel, &content_bc);
+                ctx.place_child(&mut tab.panel, Point::new(0., bar_height));
+            } else {
+                ctx.skip_layout(&mut tab.panel);
+            }
+        }
+
+        bc.constrain(Size::new(
+            x.max(content_size.width),
+            bar_height + content_size.height,
+        ))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let Some(bar_height) = self.header_rects.first().map(Rect::height) else {
+            return;
+        };
+        let theme = ctx.theme();
+        let line_y = bar_height - 0.5;
+        stroke(
+            scene,
+            &Line::new((0., line_y), (ctx.size().width, line_y)),
+            theme.border_dark,
+            1.,
+        );
+
+        let Some(rect) = self.header_rects.get(self.selected) else {
+            return;
+        };
+        let indicator_color = if ctx.is_disabled() {
+            theme.disabled_text_color
+        } else {
+            theme.accent_color
+        };
+        let indicator = Rect::new(rect.x0, rect.y1 - INDICATOR_WIDTH, rect.x1, rect.y1);
+        fill_color(scene, &indicator, indicator_color);
+        if ctx.is_focus_target() {
+            stroke(scene, &rect.inset(-1.), theme.accent_color, 1.);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::TabList
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        self.tabs
+            .iter()
+            .flat_map(|tab| [tab.header.id(), tab.panel.id()])
+            .collect()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Tabs", id = ctx.widget_id().trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(format!("selected: {}", self.selected))
+    }
+}
+
+// --- MARK: TAB HEADER ---
+/// The header of a tab in [`Tabs`], which wraps e.g. the tab's label.
+///
+/// This is a tab for accessibility, and is highlighted whilst hovered.
+pub struct TabHeader {
+    child: WidgetPod<dyn Widget>,
+    selected: bool,
+    /// The panel containing the tab's content.
+    panel: WidgetId,
+}
+
+impl TabHeader {
+    /// Whether this header's tab is selected.
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    /// Get a mutable reference to the header widget, e.g. the tab's label.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    fn set_selected(this: &mut WidgetMut<'_, Self>, selected: bool) {
+        this.widget.selected = selected;
+        this.ctx.request_render();
+    }
+}
+
+impl Widget for TabHeader {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+        // Clicks are handled by the parent `Tabs`.
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::HoveredChanged(_) = event {
+            ctx.request_paint_only();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let padding = Size::new(HEADER_INSETS.x_value(), HEADER_INSETS.y_value());
+        let child_size = ctx.run_layout(&mut self.child, &bc.shrink(padding).loosen());
+        ctx.place_child(
+            &mut self.child,
+            Point::new(HEADER_INSETS.x0, HEADER_INSETS.y0),
+        );
+        bc.constrain(child_size + padding)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if ctx.is_hovered() && !self.selected && !ctx.is_disabled() {
+            fill_color(scene, &ctx.size().to_rect(), ctx.theme().border_dark);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Tab
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.add_action(accesskit::Action::Click);
+        node.set_selected(self.selected);
+        node.set_controls(vec![self.panel.into()]);
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("TabHeader", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TAB PANEL ---
+/// The content of a tab in [`Tabs`].
+///
+/// This is a tab panel for accessibility, labelled by the tab's [`TabHeader`].
+pub struct TabPanel {
+    child: WidgetPod<dyn Widget>,
+    /// The header of the tab.
+    header: WidgetId,
+}
+
+impl TabPanel {
+    /// Get a mutable reference to the content widget.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+impl Widget for TabPanel {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::TabPanel
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.set_labelled_by(vec![self.header.into()]);
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("TabPanel", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, TestWidgetExt, widget_ids};
+    use crate::widgets::TextArea;
+
+    fn tab_ids(harness: &TestHarness, tabs_id: WidgetId) -> Vec<(WidgetId, WidgetId)> {
+        let tabs = harness.get_widget(tabs_id);
+        let tabs = tabs.downcast::<Tabs>().unwrap();
+        tabs.tabs
+            .iter()
+            .map(|tab| (tab.header.id(), tab.panel.id()))
+            .collect()
+    }
+
+    fn selected(harness: &TestHarness, tabs_id: WidgetId) -> usize {
+        harness
+            .get_widget(tabs_id)
+            .downcast::<Tabs>()
+            .unwrap()
+            .selected()
+    }
+
+    #[test]
+    fn click_selects_tab() {
+        let [tabs_id] = widget_ids();
+        let widget = Tabs::new()
+            .with_tab("One", Label::new("First"))
+            .with_tab("Two", Label::new("Second"))
+            .with_tab("Three", Label::new("Third"))
+            .with_id(tabs_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(300., 200.));
+        let ids = tab_ids(&harness, tabs_id);
+        let is_stashed = |harness: &TestHarness, id| harness.get_widget(id).ctx().is_stashed();
+        assert!(!is_stashed(&harness, ids[0].1));
+        assert!(is_stashed(&harness, ids[1].1));
+        assert!(is_stashed(&harness, ids[2].1));
+
+        harness.mouse_click_on(ids[2].0);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::TabSelected(2), tabs_id))
+        );
+        assert_eq!(selected(&harness, tabs_id), 2);
+        assert_eq!(harness.focused_widget_id(), Some(tabs_id));
+        assert!(is_stashed(&harness, ids[0].1));
+        assert!(!is_stashed(&harness, ids[2].1));
+        let header = harness.get_widget(ids[2].0);
+        assert!(header.downcast::<TabHeader>().unwrap().is_selected());
+
+        // Clicking the selected tab doesn't emit an action.
+        harness.mouse_click_on(ids[2].0);
+        assert_eq!(harness.pop_action(), None);
+
+        harness.process_access_event(ids[1].0, accesskit::Action::Click, None);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::TabSelected(1), tabs_id))
+        );
+        assert_eq!(selected(&harness, tabs_id), 1);
+    }
+
+    #[test]
+    fn switching_tabs_keeps_state() {
+        let [tabs_id, area_id] = widget_ids();
+        let widget = Tabs::new()
+            .with_tab("Edit", TextArea::new_editable("Some text").with_id(area_id))
+            .with_tab("Preview", Label::new("Preview"))
+            .with_id(tabs_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(300., 200.));
+        harness.edit_widget(area_id, |mut area| {
+            TextArea::select_byte_range(&mut area.downcast::<TextArea<true>>(), 2, 6);
+        });
+
+        let set_selected = |harness: &mut TestHarness, selected| {
+            harness.edit_widget(tabs_id, |mut tabs| {
+                Tabs::set_selected(&mut tabs.downcast(), selected);
+            });
+        };
+        let panel_id = tab_ids(&harness, tabs_id)[0].1;
+        set_selected(&mut harness, 1);
+        assert!(harness.get_widget(panel_id).ctx().is_stashed());
+        set_selected(&mut harness, 0);
+        assert!(!harness.get_widget(panel_id).ctx().is_stashed());
+        // Setting the selected tab isn't a user action.
+        assert_eq!(harness.pop_action(), None);
+
+        let area = harness.get_widget(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.selection_range(), 2..6);
+    }
+
+    #[test]
+    fn adding_and_removing_keeps_selection() {
+        let [tabs_id] = widget_ids();
+        let widget = Tabs::new()
+            .with_tab("A", Label::new("A"))
+            .with_tab("B", Label::new("B"))
+            .with_selected(1)
+            .with_id(tabs_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(300., 200.));
+        let selected_panel = tab_ids(&harness, tabs_id)[1].1;
+
+        harness.edit_widget(tabs_id, |mut tabs| {
+            let mut tabs = tabs.downcast::<Tabs>();
+            Tabs::insert_tab_pod(
+                &mut tabs,
+                0,
+                WidgetPod::new(Label::new("New")).erased(),
+                WidgetPod::new(Label::new("New")).erased(),
+            );
+        });
+        assert_eq!(selected(&harness, tabs_id), 2);
+        assert!(!harness.get_widget(selected_panel).ctx().is_stashed());
+        assert!(
+            harness
+                .get_widget(tab_ids(&harness, tabs_id)[0].1)
+                .ctx()
+                .is_stashed()
+        );
+
+        // Removing the selected tab selects the one after it, or the last tab.
+        harness.edit_widget(tabs_id, |mut tabs| {
+            Tabs::remove_tab(&mut tabs.downcast(), 2);
+        });
+        assert_eq!(selected(&harness, tabs_id), 1);
+        let ids = tab_ids(&harness, tabs_id);
+        assert!(!harness.get_widget(ids[1].1).ctx().is_stashed());
+        let header = harness.get_widget(ids[1].0);
+        assert!(header.downcast::<TabHeader>().unwrap().is_selected());
+    }
+
+    #[test]
+    fn keyboard_selection() {
+        let right = Key::Named(NamedKey::ArrowRight);
+        let left = Key::Named(NamedKey::ArrowLeft);
+        assert_eq!(tab_for_key(&right, 0, 3), Some(1));
+        // The arrow keys wrap around at either end.
+        assert_eq!(tab_for_key(&right, 2, 3), Some(0));
+        assert_eq!(tab_for_key(&left, 0, 3), Some(2));
+        assert_eq!(tab_for_key(&Key::Named(NamedKey::Home), 2, 3), Some(0));
+        assert_eq!(tab_for_key(&Key::Named(NamedKey::End), 0, 3), Some(2));
+        assert_eq!(tab_for_key(&Key::Named(NamedKey::Tab), 0, 3), None);
+        assert_eq!(tab_for_key(&right, 0, 0), None);
+    }
+}
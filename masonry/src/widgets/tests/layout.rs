@@ -3,9 +3,11 @@
 
 //! Tests related to layout.
 
+use dpi::PhysicalSize;
 use vello::kurbo::{Insets, Size};
 
-use crate::testing::{ModularWidget, TestHarness, TestWidgetExt, widget_ids};
+use crate::core::{Update, WindowEvent};
+use crate::testing::{ModularWidget, Record, Recording, TestHarness, TestWidgetExt, widget_ids};
 use crate::widgets::{Flex, SizedBox};
 
 #[test]
@@ -69,6 +71,69 @@ fn layout_insets() {
     assert_eq!(parent_paint_rect.y1, BOX_WIDTH + 20.0);
 }
 
+#[test]
+fn size_changed_update() {
+    fn size_changes(record: &Recording) -> Vec<Size> {
+        record
+            .drain()
+            .into_iter()
+            .filter_map(|record| match record {
+                Record::U(Update::SizeChanged(size)) => Some(size),
+                _ => None,
+            })
+            .collect()
+    }
+
+    let record = Recording::default();
+    let widget = SizedBox::empty().record(&record);
+    let mut harness = TestHarness::create_with_size(widget, Size::new(100., 100.));
+    record.clear();
+
+    harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(200, 150)));
+    let _ = harness.render();
+    let records = record.drain();
+    let changed_idx = records
+        .iter()
+        .position(|record| matches!(record, Record::U(Update::SizeChanged(size)) if *size == Size::new(200., 150.)))
+        .expect("SizeChanged should have been sent");
+    let paint_idx = records
+        .iter()
+        .position(|record| matches!(record, Record::Paint))
+        .expect("widget should have been painted");
+    assert!(
+        changed_idx < paint_idx,
+        "SizeChanged should be sent before paint"
+    );
+    assert!(!harness.root_widget().ctx().size_changed_since_last_paint());
+
+    // Resizing to the same size doesn't send an update.
+    harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(200, 150)));
+    assert_eq!(size_changes(&record), []);
+}
+
+#[test]
+fn size_changed_not_sent_on_first_layout() {
+    let record = Recording::default();
+    let mut harness = TestHarness::create(Flex::column());
+
+    harness.edit_root_widget(|mut flex| {
+        let mut flex = flex.downcast::<Flex>();
+        Flex::add_child(&mut flex, SizedBox::empty().width(20.).record(&record));
+    });
+
+    let records = record.drain();
+    assert!(
+        records
+            .iter()
+            .any(|r| matches!(r, Record::U(Update::WidgetAdded)))
+    );
+    assert!(
+        !records
+            .iter()
+            .any(|r| matches!(r, Record::U(Update::SizeChanged(_))))
+    );
+}
+
 // TODO - insets + flex
 // TODO - viewport
 // TODO - insets + viewport
@@ -9,9 +9,20 @@ use std::rc::Rc;
 use smallvec::smallvec;
 
 use crate::testing::{ModularWidget, ReplaceChild, TestHarness, TestWidgetExt as _, widget_ids};
-use crate::widgets::Flex;
+use crate::widgets::{Flex, Textbox};
 use crate::*;
 
+#[test]
+fn request_focus_from_widget_mut() {
+    let textbox = Textbox::new("hello");
+    let area_id = textbox.area_pod().id();
+    let mut harness = TestHarness::create(Flex::column().with_child(textbox));
+    assert_eq!(harness.focused_widget_id(), None);
+
+    harness.edit_widget(area_id, |mut area| area.ctx.request_focus());
+    assert_eq!(harness.focused_widget_id(), Some(area_id));
+}
+
 #[cfg(FALSE)]
 const REQUEST_FOCUS: Selector<()> = Selector::new("masonry-test.request-focus");
 
@@ -5,13 +5,13 @@
 
 use std::f64::consts::PI;
 
-use vello::kurbo::{Affine, Vec2};
+use vello::kurbo::{Affine, Point, Size, Vec2};
 use vello::peniko::color::palette;
 
 use crate::assert_render_snapshot;
-use crate::core::{PointerButton, Widget, WidgetPod};
-use crate::testing::TestHarness;
-use crate::widgets::{Alignment, Button, ChildAlignment, Label, SizedBox, ZStack};
+use crate::core::{Action, PointerButton, Widget, WidgetPod};
+use crate::testing::{TestHarness, widget_ids};
+use crate::widgets::{Alignment, Button, Canvas, ChildAlignment, Label, SizedBox, ZStack};
 
 fn blue_box(inner: impl Widget) -> Box<SizedBox> {
     Box::new(
@@ -57,3 +57,39 @@ fn transforms_pointer_events() {
     harness.mouse_button_press(PointerButton::Primary);
     assert_render_snapshot!(harness, "transforms_pointer_events");
 }
+
+#[test]
+fn transforms_scaled_pointer_position() {
+    let [canvas_id] = widget_ids();
+    let canvas = Canvas::new(|_, _, _| {})
+        .with_preferred_size(Size::new(40., 30.))
+        .with_pointer_events(true);
+    let transformed_widget = WidgetPod::new_with_id_and_transform(
+        Box::new(canvas),
+        canvas_id,
+        Affine::scale(2.0).then_translate(Vec2::new(10.0, 20.0)),
+    )
+    .erased();
+    let widget = ZStack::new().with_child_pod(transformed_widget, ChildAlignment::ParentAligned);
+
+    let mut harness = TestHarness::create(widget);
+    // The canvas is laid out at its untransformed size.
+    assert_eq!(
+        harness.get_widget(canvas_id).ctx().size(),
+        Size::new(40., 30.)
+    );
+
+    // This is outside the untransformed canvas, but inside the scaled one.
+    harness.mouse_move((10.0 + 70.0, 20.0 + 50.0));
+    harness.mouse_button_press(PointerButton::Primary);
+
+    let mut last_position = None;
+    while let Some((action, id)) = harness.pop_action() {
+        assert_eq!(id, canvas_id);
+        let Action::CanvasPointer(event) = action else {
+            panic!("Unexpected action {action:?}");
+        };
+        last_position = Some(event.position);
+    }
+    assert_eq!(last_position, Some(Point::new(35., 25.)));
+}
@@ -3,30 +3,389 @@
 
 #![warn(missing_docs)]
 
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::mem::Discriminant;
-use std::time::Instant;
+use std::ops::Range;
+use std::time::{Duration, Instant};
 
 use accesskit::{Node, NodeId, Role};
-use parley::PlainEditor;
 use parley::editor::{Generation, SplitString};
-use parley::layout::Alignment;
+use parley::layout::{Alignment, AlignmentOptions, Layout, Line as LayoutLine};
+use parley::{
+    Affinity, Cursor, FontContext, FontFamily, FontStack, GenericFamily, LayoutContext,
+    PlainEditor, Selection,
+};
 use smallvec::SmallVec;
 use tracing::{Span, trace_span};
+use unicode_segmentation::UnicodeSegmentation;
 use vello::Scene;
-use vello::kurbo::{Affine, Point, Rect, Size, Vec2};
-use vello::peniko::{Brush, Fill};
-use winit::keyboard::{Key, NamedKey};
+use vello::kurbo::{Affine, BezPath, Line, Point, Rect, Size, Stroke, Vec2};
+use vello::peniko::{Brush, Color, Fill};
+use winit::keyboard::{Key, ModifiersState, NamedKey};
 
 use crate::core::{
-    AccessCtx, AccessEvent, BoxConstraints, BrushIndex, EventCtx, LayoutCtx, PaintCtx,
-    PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
-    StyleProperty, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, default_styles,
-    render_text,
+    AccessCtx, AccessEvent, BoxConstraints, BrushIndex, DEFAULT_LINE_HEIGHT, EventCtx, LayoutCtx,
+    PaintCtx, PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
+    SelectionChange, StyleAttribute, StyleProperty, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, default_styles, push_spans, render_text, span_brushes,
 };
 use crate::widgets::Padding;
 use crate::{palette, theme};
 use cursor_icon::CursorIcon;
 
+/// The longest time between two clicks for them to count as a double (or triple) click.
+///
+/// The long-term plan is for this to be provided by the platform (i.e. winit).
+const MULTI_CLICK_TIME: Duration = Duration::from_millis(500);
+
+/// The furthest distance (in logical pixels) the pointer can move between two clicks
+/// for them to count as a double (or triple) click.
+const MULTI_CLICK_DISTANCE: f64 = 4.0;
+
+/// The furthest distance (in logical pixels) the pointer can move whilst pressed on a link
+/// for releasing it to follow the link, rather than finish selecting text.
+const LINK_DRAG_DISTANCE: f64 = 4.0;
+
+/// How many times the font size range is halved when searching for a size at which the text fits.
+const AUTO_FIT_ITERATIONS: u32 = 8;
+
+/// The width of the caret, in logical pixels.
+const CARET_WIDTH: f32 = 1.5;
+
+/// The space between the line numbers in the gutter and the text, in logical pixels.
+const GUTTER_GAP: f64 = 8.0;
+
+/// What a [`TextArea`] does when the Tab key is pressed whilst it is focused.
+///
+/// Tab always moves focus in text areas which are not user editable.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabAction {
+    /// Leave the key unhandled, so that focus moves to the next widget
+    /// (or the previous widget, if Shift is held).
+    #[default]
+    MoveFocus,
+    /// Insert a tab character.
+    ///
+    /// Shift+Tab removes a tab character from the start of the current line.
+    InsertTab,
+    /// Insert the given number of spaces.
+    ///
+    /// Shift+Tab removes up to that many spaces from the start of the current line.
+    InsertSpaces(u8),
+}
+
+impl TabAction {
+    /// The text to insert when Tab is pressed, or `None` if focus should move instead.
+    fn indent(self) -> Option<String> {
+        match self {
+            Self::MoveFocus => None,
+            Self::InsertTab => Some("\t".into()),
+            Self::InsertSpaces(n) => Some(" ".repeat(n.into())),
+        }
+    }
+
+    /// The range of `text` to remove when Shift+Tab is pressed with the cursor at byte `cursor`.
+    ///
+    /// Returns `None` if there is no indentation to remove at the start of the cursor's line.
+    fn dedent_range(self, text: &str, cursor: usize) -> Option<Range<usize>> {
+        let line_start = text[..cursor].rfind('\n').map_or(0, |idx| idx + 1);
+        let line = &text[line_start..];
+        let len = match self {
+            Self::MoveFocus => 0,
+            Self::InsertTab => usize::from(line.starts_with('\t')),
+            Self::InsertSpaces(n) => line
+                .bytes()
+                .take(n.into())
+                .take_while(|byte| *byte == b' ')
+                .count(),
+        };
+        (len > 0).then(|| line_start..line_start + len)
+    }
+}
+
+/// What a [`TextArea`] asks its scrolling ancestors to reveal after its text is edited.
+///
+/// Moving the caret without editing the text always reveals the caret.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAnchor {
+    /// The caret, which is at the end of any inserted text.
+    #[default]
+    CaretEnd,
+    /// The start of the edited text, such as the start of a paste.
+    SelectionStart,
+    /// The whole text area.
+    Widget,
+}
+
+/// The base direction of the text in a [`TextArea`].
+///
+/// Text within a paragraph is always reordered for display by the Unicode bidirectional
+/// algorithm, so that e.g. Hebrew words read right to left within English text.
+/// The base direction instead decides which side [`Start`](Alignment::Start) and
+/// [`End`](Alignment::End) alignment refer to, and so which side text with no strongly
+/// directional characters (such as numbers, punctuation or no text at all) sits on.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The direction of the first strongly directional character in the text,
+    /// or left to right if there is none.
+    #[default]
+    Auto,
+    /// Left to right, as for English.
+    Ltr,
+    /// Right to left, as for Arabic and Hebrew.
+    Rtl,
+}
+
+/// Whether `c` is a strongly right-to-left character, such as a Hebrew or Arabic letter.
+///
+/// This only checks the main right-to-left blocks, which is enough to tell whether
+/// text needs bidi-aware word navigation.
+fn is_rtl_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{08FF}'
+            | '\u{200F}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+    )
+}
+
+/// Whether a segment from [`split_word_bound_indices`](UnicodeSegmentation::split_word_bound_indices)
+/// is a word, rather than whitespace, punctuation or emoji.
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
+/// The byte index of the end of the word containing or following `idx` in `text`.
+///
+/// Returns the end of the text if there are no more words.
+fn next_word_end(text: &str, idx: usize) -> usize {
+    text.split_word_bound_indices()
+        .map(|(start, segment)| (start + segment.len(), segment))
+        .find(|(end, segment)| *end > idx && is_word(segment))
+        .map_or(text.len(), |(end, _)| end)
+}
+
+/// The byte index of the start of the word containing or preceding `idx` in `text`.
+///
+/// Returns zero if there are no earlier words.
+fn prev_word_start(text: &str, idx: usize) -> usize {
+    text.split_word_bound_indices()
+        .rev()
+        .find(|(start, segment)| *start < idx && is_word(segment))
+        .map_or(0, |(start, _)| start)
+}
+
+/// Find the edit which turned `old` into `new`.
+///
+/// Returns the byte range of `old` which was replaced, and the length of the text which replaced it,
+/// or `None` if the texts are the same.
+/// Where the edit is ambiguous (e.g. adding a letter to a run of the same letter),
+/// it is assumed to be as late as possible.
+fn text_edit(old: &str, new: &str) -> Option<(Range<usize>, usize)> {
+    if old == new {
+        return None;
+    }
+    let prefix = old
+        .char_indices()
+        .zip(new.chars())
+        .find(|((_, old_char), new_char)| old_char != new_char)
+        .map_or(old.len().min(new.len()), |((idx, _), _)| idx);
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let suffix = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(old_char, new_char)| old_char == new_char)
+        .map(|(old_char, _)| old_char.len_utf8())
+        .scan(0, |len, char_len| {
+            *len += char_len;
+            Some(*len)
+        })
+        .take_while(|len| *len <= max_suffix)
+        .last()
+        .unwrap_or(0);
+    Some((prefix..old.len() - suffix, new.len() - prefix - suffix))
+}
+
+/// Move `range` to cover the same text after `replaced` was replaced by `inserted_len` bytes.
+///
+/// Text inserted at the end of the range extends it, and text inserted at its start doesn't.
+/// Where the replaced text overlaps the range, the range shrinks to exclude it.
+fn move_range(range: Range<usize>, replaced: Range<usize>, inserted_len: usize) -> Range<usize> {
+    let moved = |idx: usize| idx - replaced.len() + inserted_len;
+    let start = if range.start < replaced.start {
+        range.start
+    } else if range.start >= replaced.end {
+        moved(range.start)
+    } else {
+        replaced.start + inserted_len
+    };
+    let end = if range.end >= replaced.end {
+        moved(range.end)
+    } else if range.end <= replaced.start {
+        range.end
+    } else {
+        replaced.start
+    };
+    start..end.max(start)
+}
+
+/// Lay out `text` for the line number gutter, in a monospace font with the text's other styles.
+fn gutter_layout(
+    fctx: &mut FontContext,
+    lctx: &mut LayoutContext<BrushIndex>,
+    styles: &[StyleProperty],
+    text: &str,
+) -> Layout<BrushIndex> {
+    // TODO: Should we use a different scale?
+    let mut builder = lctx.ranged_builder(fctx, text, 1.0);
+    for prop in styles {
+        builder.push_default(prop.clone());
+    }
+    builder.push_default(GenericFamily::Monospace.into());
+    let mut layout = builder.build(text);
+    layout.break_all_lines(None);
+    layout
+}
+
+/// The number of decimal digits in `n`.
+fn digit_count(n: usize) -> usize {
+    n.max(1).ilog10() as usize + 1
+}
+
+/// The visual lines of `layout` which `range` is on, with the horizontal extent of the
+/// range on each of them.
+fn range_line_extents(
+    layout: &Layout<BrushIndex>,
+    range: Range<usize>,
+) -> impl Iterator<Item = (LayoutLine<'_, BrushIndex>, f64, f64)> {
+    layout.lines().filter_map(move |line| {
+        let line_range = line.text_range();
+        let start = range.start.max(line_range.start);
+        let end = range.end.min(line_range.end);
+        if start >= end {
+            return None;
+        }
+        // The affinities keep both cursors on this line, even at its edges.
+        let x0 = Cursor::from_byte_index(layout, start, Affinity::Downstream)
+            .geometry(layout, 0.)
+            .x0;
+        let x1 = Cursor::from_byte_index(layout, end, Affinity::Upstream)
+            .geometry(layout, 0.)
+            .x0;
+        Some((line, x0.min(x1), x0.max(x1)))
+    })
+}
+
+/// The lines to draw a decoration covering `range` along, one for each visual line of
+/// `layout` which the range is on.
+fn decoration_lines(layout: &Layout<BrushIndex>, range: Range<usize>) -> Vec<Line> {
+    range_line_extents(layout, range)
+        .map(|(line, x0, x1)| {
+            let metrics = line.metrics();
+            let y = f64::from(metrics.baseline + metrics.descent / 2.);
+            Line::new((x0, y), (x1, y))
+        })
+        .collect()
+}
+
+/// The rectangles to fill behind `range`, one for each visual line of `layout` which the
+/// range is on.
+fn highlight_rects(layout: &Layout<BrushIndex>, range: Range<usize>) -> Vec<Rect> {
+    range_line_extents(layout, range)
+        .map(|(line, x0, x1)| {
+            let metrics = line.metrics();
+            Rect::new(
+                x0,
+                f64::from(metrics.min_coord),
+                x1,
+                f64::from(metrics.max_coord),
+            )
+        })
+        .collect()
+}
+
+/// The byte ranges of the http and https URLs in `text`.
+///
+/// A URL must start a word, and runs until whitespace, except for any punctuation at its end,
+/// which is more likely to belong to the surrounding sentence.
+fn find_links(text: &str) -> Vec<Range<usize>> {
+    let mut links = Vec::new();
+    let mut search_from = 0;
+    while let Some(found) = text[search_from..].find("http") {
+        let start = search_from + found;
+        let starts_word = text[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        let scheme = ["https://", "http://"]
+            .into_iter()
+            .find(|scheme| text[start..].starts_with(scheme));
+        let Some(scheme) = scheme.filter(|_| starts_word) else {
+            search_from = start + "http".len();
+            continue;
+        };
+        let path_start = start + scheme.len();
+        let end = text[path_start..]
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+            .map_or(text.len(), |len| path_start + len);
+        let path =
+            text[path_start..end].trim_end_matches(['.', ',', ';', ':', '!', '?', '\'', ')', ']']);
+        if !path.is_empty() {
+            links.push(start..path_start + path.len());
+        }
+        search_from = end;
+    }
+    links
+}
+
+/// Draw `decoration` along `line`.
+fn paint_decoration(scene: &mut Scene, transform: Affine, decoration: &TextDecoration, line: Line) {
+    const WIDTH: f64 = 1.;
+    match decoration.kind {
+        DecorationKind::Straight => {
+            scene.stroke(
+                &Stroke::new(WIDTH),
+                transform,
+                decoration.color,
+                None,
+                &line,
+            );
+        }
+        DecorationKind::Dotted => {
+            let stroke = Stroke::new(WIDTH).with_dashes(0., [WIDTH, WIDTH * 2.]);
+            scene.stroke(&stroke, transform, decoration.color, None, &line);
+        }
+        DecorationKind::Wavy => {
+            const WAVELENGTH: f64 = 4.;
+            const AMPLITUDE: f64 = 1.5;
+            let mut path = BezPath::new();
+            let y = line.p0.y;
+            let mut x = line.p0.x;
+            let mut up = true;
+            path.move_to((x, y));
+            while x < line.p1.x {
+                let next = (x + WAVELENGTH / 2.).min(line.p1.x);
+                let peak = if up { y - AMPLITUDE } else { y + AMPLITUDE };
+                path.quad_to(((x + next) / 2., peak), (next, y));
+                x = next;
+                up = !up;
+            }
+            scene.stroke(
+                &Stroke::new(WIDTH),
+                transform,
+                decoration.color,
+                None,
+                &path,
+            );
+        }
+    }
+}
+
 /// `TextArea` implements the core of interactive text.
 ///
 /// It is used to implement [`Textbox`](super::Textbox) and [`Prose`](super::Prose).
@@ -44,11 +403,19 @@ use cursor_icon::CursorIcon;
 ///
 /// - `TextEntered`, which is sent when the enter key is pressed
 /// - `TextChanged`, which is sent whenever the text is changed
+/// - `TextSelectionChanged`, which is sent whenever the selection or caret moves.
+///   Whilst the user is dragging out a selection, this is only sent once they release the pointer.
 ///
 /// The exact semantics of how much horizontal space this widget takes up has not been determined.
 /// In particular, this has consequences when the alignment is set.
-// TODO: RichTextBox 👀
-// TODO: Support for links - https://github.com/linebender/xilem/issues/360
+///
+/// Styles can be applied to ranges of the text using [`with_spans`](Self::with_spans).
+/// The spans move with the text they cover as it is edited.
+/// The user can't currently change the spans, so this is mostly useful for read-only rich text.
+/// Similarly, ranges can be underlined using [`with_decorations`](Self::with_decorations).
+///
+/// URLs in the text can be made clickable using [`with_autolink`](Self::with_autolink),
+/// and lines can be numbered in a gutter using [`with_line_numbers`](Self::with_line_numbers).
 pub struct TextArea<const USER_EDITABLE: bool> {
     // TODO: Placeholder text?
     /// The underlying `PlainEditor`, which provides a high-level interface for us to dispatch into.
@@ -63,8 +430,71 @@ pub struct TextArea<const USER_EDITABLE: bool> {
     /// Used to detect double/triple clicks.
     /// The long-term plan is for this to be provided by the platform (i.e. winit), as that has more context.
     last_click_time: Option<Instant>,
+    /// Where this element was last clicked, in local coordinates.
+    ///
+    /// A click sequence is reset if the pointer moves too far between clicks.
+    last_click_pos: Option<Point>,
     /// How many clicks have occurred in this click sequence.
     click_count: u32,
+    /// The word or line which was selected by the latest double or triple click.
+    ///
+    /// Whilst dragging after such a click, the selection is extended by whole words (or lines),
+    /// always including this range.
+    granular_anchor: Option<Range<usize>>,
+    /// The selection which was last reported in a `TextSelectionChanged` action.
+    reported_selection: Range<usize>,
+    /// Whether the user is dragging out a selection whilst this text area is disabled.
+    ///
+    /// Disabled widgets can't capture the pointer, so we track the drag ourselves.
+    disabled_drag: bool,
+    /// Whether the user can select text whilst this text area is disabled.
+    ///
+    /// Can be set using [`set_selectable_when_disabled`](Self::set_selectable_when_disabled).
+    selectable_when_disabled: bool,
+
+    /// Styles applied to byte ranges of the text, on top of the global styles.
+    ///
+    /// Can be set using [`set_spans`](Self::set_spans).
+    spans: Vec<(Range<usize>, StyleAttribute)>,
+    /// Lines drawn under byte ranges of the text.
+    ///
+    /// Can be set using [`set_decorations`](Self::set_decorations).
+    decorations: Vec<TextDecoration>,
+    /// Byte ranges of the text which have a background filled in behind them.
+    ///
+    /// Can be set using [`set_highlights`](Self::set_highlights).
+    highlights: Vec<Range<usize>>,
+    /// How `highlights` are painted.
+    highlight_style: HighlightStyle,
+    /// The text which `spans` and `decorations` refer to.
+    ///
+    /// This is compared against the editor's text to find edits, which the ranges are moved by.
+    /// It is only kept up to date whilst there are spans or decorations, or `autolink` is enabled.
+    spans_text: String,
+    /// Whether http and https URLs in the text are styled as links which can be clicked.
+    ///
+    /// Can be set using [`set_autolink`](Self::set_autolink).
+    autolink: bool,
+    /// The byte ranges of the URLs found in the text when `autolink` is enabled.
+    ///
+    /// These are found again in each layout.
+    links: Vec<Range<usize>>,
+    /// The link which the primary button was pressed on, and where it was pressed.
+    ///
+    /// This is cleared if the pointer moves too far whilst pressed, as the user is selecting text.
+    pressed_link: Option<(Range<usize>, Point)>,
+    /// The text laid out with `spans` applied, which is displayed instead of the editor's layout
+    /// whilst there are spans.
+    ///
+    /// The editor's layout doesn't support ranged styles, so it is still used for hit testing,
+    /// IME and accessibility.
+    styled_layout: Option<Layout<BrushIndex>>,
+    /// The alignment of the text, which we need to apply to `styled_layout`.
+    alignment: Alignment,
+    /// The base direction, which flips the alignment if it differs from the direction of the text.
+    base_direction: Direction,
+    /// Whether the text was right to left when it was last laid out.
+    text_is_rtl: bool,
 
     /// Whether to wrap words in this area.
     ///
@@ -78,18 +508,76 @@ pub struct TextArea<const USER_EDITABLE: bool> {
     /// We store this to avoid redoing work in layout and to set the
     /// width when `word_wrap` is re-enabled.
     last_available_width: Option<f32>,
+    /// The smallest and largest font sizes to choose from so that the text fits on one line.
+    ///
+    /// When this is set, word wrapping is disabled.
+    /// Can be set using [`set_auto_fit`](Self::set_auto_fit).
+    auto_fit: Option<(f32, f32)>,
+    /// The font size chosen by auto-fit, and the text and width it was chosen for.
+    ///
+    /// We store this to avoid searching for a size again when neither has changed.
+    fitted: Option<FittedSize>,
+    /// The counts of the text, and the generation of `editor` which they were counted for.
+    ///
+    /// These are only counted when requested, as segmenting the text on every edit would be
+    /// wasted work for most text areas.
+    text_stats: Cell<Option<(Generation, TextStats)>>,
 
     /// The brush for drawing this label's text.
     ///
+    /// When this is `None`, the [theme's text color](theme::Theme::text_color) is used.
     /// Requires a new paint if edited whilst `disabled_brush` is not being used.
     /// Can be set using [`set_brush`](Self::set_brush).
-    brush: Brush,
+    brush: Option<Brush>,
     /// The brush to use whilst this widget is disabled.
     ///
     /// When this is `None`, `brush` will be used.
     /// Requires a new paint if edited whilst this widget is disabled.
     /// /// Can be set using [`set_disabled_brush`](Self::set_disabled_brush).
     disabled_brush: Option<Brush>,
+    /// The brush used to fill the selection whilst this text area is focused.
+    ///
+    /// Can be set using [`set_selection_brush`](Self::set_selection_brush).
+    selection_brush: Brush,
+    /// The brush used to fill the selection whilst this text area is not focused.
+    ///
+    /// When this is `None`, the selection is hidden whilst unfocused.
+    /// Can be set using [`set_inactive_selection_brush`](Self::set_inactive_selection_brush).
+    inactive_selection_brush: Option<Brush>,
+    /// The brush used to draw the caret.
+    ///
+    /// When this is `None`, the [theme's caret color](theme::Theme::caret_color) is used.
+    /// Can be set using [`set_caret_brush`](Self::set_caret_brush).
+    caret_brush: Option<Brush>,
+    /// What to do when the Tab key is pressed.
+    ///
+    /// Only used when `USER_EDITABLE` is true.
+    /// Can be set using [`set_tab_action`](Self::set_tab_action).
+    tab_action: TabAction,
+    /// What to reveal in scrolling ancestors after an edit.
+    ///
+    /// Can be set using [`set_scroll_anchor`](Self::set_scroll_anchor).
+    scroll_anchor: ScrollAnchor,
+    /// Whether typed characters replace the grapheme after the caret, rather than being inserted.
+    ///
+    /// Only used when `USER_EDITABLE` is true.
+    /// Can be set using [`set_overtype`](Self::set_overtype).
+    overtype: bool,
+    /// Whether the Insert key toggles `overtype`.
+    ///
+    /// Can be set using [`set_overtype_toggle`](Self::set_overtype_toggle).
+    allow_overtype_toggle: bool,
+    /// Which characters the user can insert, if restricted.
+    ///
+    /// Only used when `USER_EDITABLE` is true.
+    /// Can be set using [`set_allowed_chars`](Self::set_allowed_chars).
+    allowed_chars: Option<Box<dyn Fn(char) -> bool>>,
+    /// How long the user must stop editing for before `TextChanged` is submitted, if debounced.
+    ///
+    /// Can be set using [`set_change_debounce`](Self::set_change_debounce).
+    change_debounce: Option<Duration>,
+    /// How long it has been since the last edit whose `TextChanged` hasn't been submitted yet.
+    pending_change: Option<Duration>,
     /// Whether to hint whilst drawing the text.
     ///
     /// Should be disabled whilst an animation involving this text is ongoing.
@@ -103,6 +591,156 @@ pub struct TextArea<const USER_EDITABLE: bool> {
     /// Can be set using [`set_padding`](Self::set_padding).
     /// Immediate parent widgets should use [`with_padding_if_default`](Self::with_padding_if_default).
     padding: Padding,
+    /// Whether to show line numbers in a gutter before the text.
+    ///
+    /// Can be set using [`set_line_numbers`](Self::set_line_numbers).
+    line_numbers: bool,
+    /// Which lines are numbered, if `line_numbers` is true.
+    ///
+    /// Can be set using [`set_line_numbering`](Self::set_line_numbering).
+    line_numbering: LineNumbering,
+    /// The brush used to paint the line numbers, or `None` to use the theme's disabled text color.
+    ///
+    /// Can be set using [`set_gutter_brush`](Self::set_gutter_brush).
+    gutter_brush: Option<Brush>,
+    /// The width of the gutter, including the gap before the text, as of the last layout.
+    ///
+    /// This is zero if line numbers are off.
+    gutter_width: f64,
+    /// The laid out line numbers, with the top of each relative to the top of the text.
+    gutter_numbers: Vec<(f64, Layout<BrushIndex>)>,
+}
+
+/// Which lines of a [`TextArea`] are numbered in its gutter.
+///
+/// See [`TextArea::with_line_numbers`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineNumbering {
+    /// Number the lines as separated by newlines.
+    ///
+    /// A line which is wrapped is only numbered next to its first visual line.
+    #[default]
+    Logical,
+    /// Number each visual line, including lines created by word wrapping.
+    Visual,
+}
+
+/// A line drawn under a range of the text in a [`TextArea`], such as a spelling error marker.
+///
+/// See [`TextArea::with_decorations`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextDecoration {
+    /// The byte range of the text which is underlined.
+    pub range: Range<usize>,
+    /// The style of the line.
+    pub kind: DecorationKind,
+    /// The color of the line.
+    pub color: Color,
+}
+
+/// How the highlighted ranges of a [`TextArea`] are painted.
+///
+/// See [`TextArea::with_highlights`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HighlightStyle {
+    /// The color filled in behind the highlighted text.
+    pub background: Color,
+    /// The radius of the corners of each highlighted rectangle.
+    pub corner_radius: f64,
+}
+
+impl Default for HighlightStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgba8(0xFF, 0xD7, 0x00, 0x66),
+            corner_radius: 2.0,
+        }
+    }
+}
+
+/// The style of the line drawn for a [`TextDecoration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecorationKind {
+    /// A wavy line, as commonly used for spelling errors.
+    Wavy,
+    /// A straight line.
+    Straight,
+    /// A dotted line.
+    Dotted,
+}
+
+/// The position of a visual line of text in a [`TextArea`], from [`TextArea::line_metrics`].
+///
+/// All values are in the text area's local coordinates, so include its padding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineMetric {
+    /// The top of the line.
+    pub top: f64,
+    /// The baseline of the line.
+    pub baseline: f64,
+    /// The height of the line.
+    pub height: f64,
+}
+
+/// The selection in a [`TextArea`], from [`TextArea::selection`].
+///
+/// The offsets are in bytes, and are always on grapheme cluster boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextSelection {
+    /// The offset where the selection was started, which stays put whilst it is extended.
+    pub anchor: usize,
+    /// The offset of the end of the selection which moves, where the caret is drawn.
+    ///
+    /// This is before `anchor` if the selection was made backwards.
+    pub focus: usize,
+    /// Whether nothing is selected, so `anchor` and `focus` are the position of the caret.
+    pub is_collapsed: bool,
+}
+
+/// Counts of the contents of the text in a [`TextArea`], from [`TextArea::text_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextStats {
+    /// The number of user-perceived characters, i.e. extended [grapheme clusters].
+    ///
+    /// For example, an emoji made up of several code points counts once.
+    ///
+    /// [grapheme clusters]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+    pub graphemes: usize,
+    /// The number of words, found using Unicode [word boundaries].
+    ///
+    /// Punctuation, whitespace and emoji aren't words.
+    /// As this doesn't use a dictionary, each CJK ideograph counts as a separate word.
+    ///
+    /// [word boundaries]: https://www.unicode.org/reports/tr29/#Word_Boundaries
+    pub words: usize,
+    /// The length of the text in bytes.
+    pub bytes: usize,
+    /// The number of lines, as separated by newlines.
+    ///
+    /// Unlike [`TextArea::line_count`], this doesn't count lines created by word wrapping.
+    /// Empty text has one line.
+    pub lines: usize,
+}
+
+impl TextStats {
+    /// Count the contents of `text`.
+    pub fn new(text: &str) -> Self {
+        Self {
+            graphemes: text.graphemes(true).count(),
+            words: text.unicode_words().count(),
+            bytes: text.len(),
+            lines: text.split('\n').count(),
+        }
+    }
+}
+
+/// The font size chosen for a [`TextArea`] with auto-fit enabled.
+struct FittedSize {
+    /// The text which the size was chosen for.
+    text: String,
+    /// The available width which the size was chosen for.
+    available_width: Option<f32>,
+    font_size: f32,
 }
 
 // --- MARK: BUILDERS ---
@@ -137,19 +775,55 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         let mut editor = PlainEditor::new(theme::TEXT_SIZE_NORMAL);
         default_styles(editor.edit_styles());
         editor.set_text(text);
+        let reported_selection = editor.raw_selection().text_range();
         Self {
             editor,
             rendered_generation: Generation::default(),
             last_click_time: None,
+            last_click_pos: None,
             click_count: 0,
+            granular_anchor: None,
+            reported_selection,
+            disabled_drag: false,
+            selectable_when_disabled: true,
+            spans: Vec::new(),
+            decorations: Vec::new(),
+            highlights: Vec::new(),
+            highlight_style: HighlightStyle::default(),
+            spans_text: String::new(),
+            autolink: false,
+            links: Vec::new(),
+            pressed_link: None,
+            styled_layout: None,
+            alignment: Alignment::default(),
+            base_direction: Direction::Auto,
+            text_is_rtl: false,
             word_wrap: true,
             last_available_width: None,
-            brush: theme::TEXT_COLOR.into(),
+            auto_fit: None,
+            fitted: None,
+            text_stats: Cell::new(None),
+            brush: None,
             disabled_brush: Some(theme::DISABLED_TEXT_COLOR.into()),
+            selection_brush: palette::css::STEEL_BLUE.into(),
+            inactive_selection_brush: None,
+            caret_brush: None,
+            tab_action: TabAction::MoveFocus,
+            scroll_anchor: ScrollAnchor::CaretEnd,
+            overtype: false,
+            allow_overtype_toggle: false,
+            allowed_chars: None,
+            change_debounce: None,
+            pending_change: None,
             hint: true,
             // We use -0.0 to mark the default padding.
             // This allows parent views to overwrite it only if another source didn't configure it.
             padding: Padding::UNSET,
+            line_numbers: false,
+            line_numbering: LineNumbering::Logical,
+            gutter_brush: None,
+            gutter_width: 0.0,
+            gutter_numbers: Vec::new(),
         }
     }
 
@@ -162,6 +836,44 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self.editor.text()
     }
 
+    /// The byte range of the selection, which is empty if there is only a caret.
+    pub fn selection_range(&self) -> Range<usize> {
+        self.editor.raw_selection().text_range()
+    }
+
+    /// The current selection, including which end of it the caret is at.
+    ///
+    /// This is useful for e.g. a status bar showing the caret position.
+    pub fn selection(&self) -> TextSelection {
+        let selection = self.editor.raw_selection();
+        TextSelection {
+            anchor: selection.anchor().index(),
+            focus: selection.focus().index(),
+            is_collapsed: selection.is_collapsed(),
+        }
+    }
+
+    /// The selected text, or an empty string if nothing is selected.
+    ///
+    /// This is also empty whilst the user is composing text with an IME.
+    pub fn selected_text(&self) -> &str {
+        self.editor.selected_text().unwrap_or("")
+    }
+
+    /// Whether the user has edited the text since the last `TextChanged` was submitted.
+    ///
+    /// This can only be true when [`with_change_debounce`](Self::with_change_debounce) is used.
+    pub fn has_pending_change(&self) -> bool {
+        self.pending_change.is_some()
+    }
+
+    /// Whether this text area is in overtype mode.
+    ///
+    /// See [`with_overtype`](Self::with_overtype).
+    pub fn is_overtype(&self) -> bool {
+        self.overtype
+    }
+
     /// Set a style property for the new text area.
     ///
     /// Style properties set by this method include [text size](parley::StyleProperty::FontSize),
@@ -193,6 +905,59 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         (self, old)
     }
 
+    /// Set the font families used for the new text area, in order of preference.
+    ///
+    /// Fonts are chosen for each character, rather than for the whole text: each character is
+    /// drawn with the first family in `families` which contains a glyph for it.
+    /// This means that text which mixes scripts can use e.g. a Latin font for its Latin runs,
+    /// a CJK font for its Chinese runs, and an emoji font for its emoji.
+    /// Characters which none of `families` support use Parley's own fallback, which picks
+    /// a font installed on the system based on the script and locale of the text.
+    ///
+    /// This is a shorthand for setting a [`FontStack::List`] with [`with_style`](Self::with_style),
+    /// so it overwrites any previous font stack.
+    ///
+    /// To modify this on an active text area, use [`set_font_fallbacks`](Self::set_font_fallbacks).
+    pub fn with_font_fallbacks(self, families: Vec<FontFamily<'static>>) -> Self {
+        self.with_style(FontStack::List(Cow::Owned(families)))
+    }
+
+    /// Set the height of each line of the new text area, as a multiple of the font size.
+    ///
+    /// For example, `Some(2.0)` double-spaces the text.
+    /// This is a multiplier rather than a size in pixels, so it scales with the font size,
+    /// including sizes chosen by [auto-fit](Self::with_auto_fit).
+    /// `None` restores the default of 1.2 times the font size.
+    ///
+    /// The extra height is added around the text on each line, so this also moves the
+    /// baselines which the caret, selection and decorations are drawn from.
+    ///
+    /// This is a shorthand for setting [`LineHeight`](parley::StyleProperty::LineHeight)
+    /// with [`with_style`](Self::with_style).
+    ///
+    /// To modify this on an active text area, use [`set_line_height`](Self::set_line_height).
+    pub fn with_line_height(self, line_height: Option<f32>) -> Self {
+        self.with_style(StyleProperty::LineHeight(
+            line_height.unwrap_or(DEFAULT_LINE_HEIGHT),
+        ))
+    }
+
+    /// Set the extra space added after each character of the new text area, in logical pixels.
+    ///
+    /// Positive values spread the text out, which can suit headings, and negative values
+    /// pull it together.
+    /// The default is 0, which uses the spacing designed into the font.
+    /// The caret, selection and clicks all follow the spaced out text.
+    ///
+    /// This is a shorthand for setting [`LetterSpacing`](parley::StyleProperty::LetterSpacing)
+    /// with [`with_style`](Self::with_style).
+    ///
+    /// To modify this on an active text area, use [`set_letter_spacing`](Self::set_letter_spacing).
+    #[doc(alias = "tracking")]
+    pub fn with_letter_spacing(self, letter_spacing: f32) -> Self {
+        self.with_style(StyleProperty::LetterSpacing(letter_spacing))
+    }
+
     /// Control [word wrapping](https://en.wikipedia.org/wiki/Line_wrap_and_word_wrap) for the new text area.
     ///
     /// When enabled, the text will be laid out to fit within the available width.
@@ -208,25 +973,162 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self
     }
 
+    /// Set whether the user can select text by dragging whilst this text area is disabled.
+    ///
+    /// This allows users to select text such as error messages shown in disabled fields.
+    /// The text can't be edited whilst disabled either way.
+    /// Defaults to true.
+    ///
+    /// To modify this on an active text area, use
+    /// [`set_selectable_when_disabled`](Self::set_selectable_when_disabled).
+    pub fn with_selectable_when_disabled(mut self, selectable: bool) -> Self {
+        self.selectable_when_disabled = selectable;
+        self
+    }
+
+    /// Shrink the font size so that the text fits on one line, between `min_size` and `max_size`.
+    ///
+    /// The text is laid out at `max_size` if it fits in the available width.
+    /// Otherwise, the largest size at which it fits is used, down to `min_size`;
+    /// text which doesn't fit at `min_size` will flow past the available area.
+    /// This overrides any [`FontSize`](parley::StyleProperty::FontSize) style.
+    ///
+    /// Auto-fit disables [word wrapping](Self::with_word_wrap), as wrapped text always fits.
+    ///
+    /// To modify this on an active text area, use [`set_auto_fit`](Self::set_auto_fit).
+    pub fn with_auto_fit(mut self, min_size: f32, max_size: f32) -> Self {
+        self.auto_fit = Some((min_size, max_size));
+        self
+    }
+
     /// Set the [alignment](https://en.wikipedia.org/wiki/Typographic_alignment) of the text.
     ///
     /// Text alignment might have unexpected results when the text area has no horizontal constraints.
     ///
+    /// [`Justified`](Alignment::Justified) text is always wrapped to the available width,
+    /// even if [word wrapping](Self::with_word_wrap) is disabled, as only wrapped lines
+    /// are spread out to fill the width.
+    /// If the available width is infinite, justified text is aligned to the start instead.
+    ///
     /// To modify this on an active text area, use [`set_alignment`](Self::set_alignment).
     // TODO: Document behaviour based on provided minimum constraint?
     pub fn with_alignment(mut self, alignment: Alignment) -> Self {
-        self.editor.set_alignment(alignment);
+        self.alignment = alignment;
+        self.editor
+            .set_alignment(self.layout_alignment(self.last_available_width));
+        self
+    }
+
+    /// Set the base direction of the new text area, which defaults to [`Direction::Auto`].
+    ///
+    /// This decides which side the text is aligned to for [`Start`](Alignment::Start) and
+    /// [`End`](Alignment::End) alignment, and which side the leading padding is on.
+    /// It matters most for text which has no strongly directional characters, such as
+    /// a number or an empty text area, which are otherwise laid out left to right.
+    /// Runs of right-to-left text within the text are reordered either way.
+    ///
+    /// To modify this on an active text area, use [`set_base_direction`](Self::set_base_direction).
+    pub fn with_base_direction(mut self, direction: Direction) -> Self {
+        self.base_direction = direction;
+        self.editor
+            .set_alignment(self.layout_alignment(self.last_available_width));
+        self
+    }
+
+    /// Apply styles to byte ranges of the text, on top of the global styles.
+    ///
+    /// Later spans take precedence where they overlap.
+    /// Ranges which fall outside of the text, or not on `char` boundaries, are ignored.
+    ///
+    /// To modify this on an active text area, use [`set_spans`](Self::set_spans).
+    pub fn with_spans(mut self, spans: impl Into<Vec<(Range<usize>, StyleAttribute)>>) -> Self {
+        self.move_spans();
+        self.spans = spans.into();
+        self
+    }
+
+    /// The styled ranges of the text.
+    ///
+    /// These will have moved from where they were set if the text has since been edited.
+    pub fn spans(&self) -> &[(Range<usize>, StyleAttribute)] {
+        &self.spans
+    }
+
+    /// Draw lines under byte ranges of the text, such as wavy underlines for spelling errors.
+    ///
+    /// Like spans, decorations move with the text they cover as it is edited, and are
+    /// removed if all of their text is deleted.
+    /// Decorations which cover several lines are drawn under each of them.
+    ///
+    /// To modify this on an active text area, use [`set_decorations`](Self::set_decorations).
+    pub fn with_decorations(mut self, decorations: impl Into<Vec<TextDecoration>>) -> Self {
+        self.move_spans();
+        self.decorations = decorations.into();
+        self
+    }
+
+    /// The decorated ranges of the text.
+    ///
+    /// These will have moved from where they were set if the text has since been edited.
+    pub fn decorations(&self) -> &[TextDecoration] {
+        &self.decorations
+    }
+
+    /// Fill in a background behind byte ranges of the text, such as the matches of a search.
+    ///
+    /// This doesn't change the text or the selection, which is drawn on top of the highlights.
+    /// Like decorations, highlights move with the text they cover as it is edited, and are
+    /// removed if all of their text is deleted.
+    /// Highlights which cover several lines have a rectangle on each of them.
+    ///
+    /// To modify this on an active text area, use [`set_highlights`](Self::set_highlights).
+    pub fn with_highlights(
+        mut self,
+        ranges: impl Into<Vec<Range<usize>>>,
+        style: HighlightStyle,
+    ) -> Self {
+        self.move_spans();
+        self.highlights = ranges.into();
+        self.highlight_style = style;
+        self
+    }
+
+    /// The highlighted ranges of the text.
+    ///
+    /// These will have moved from where they were set if the text has since been edited.
+    pub fn highlights(&self) -> &[Range<usize>] {
+        &self.highlights
+    }
+
+    /// Builder-style method to set whether http and https URLs in the text are links.
+    ///
+    /// Links are underlined and drawn in the [accent color](theme::ACCENT_COLOR).
+    /// When the primary button is pressed and released on the same link, this widget submits
+    /// a [`LinkClicked`](crate::core::Action::LinkClicked) action with its URL.
+    /// If the pointer moves whilst pressed, text is selected as usual, and the link isn't followed.
+    ///
+    /// To modify this on an active text area, use [`set_autolink`](Self::set_autolink).
+    pub fn with_autolink(mut self, autolink: bool) -> Self {
+        self.autolink = autolink;
         self
     }
 
+    /// Whether http and https URLs in the text are links.
+    ///
+    /// See [`with_autolink`](Self::with_autolink) for details.
+    pub fn autolink(&self) -> bool {
+        self.autolink
+    }
+
     /// Set the brush used to paint the text in this text area.
     ///
     /// In most cases, this will be the text's color, but gradients and images are also supported.
+    /// By default, the [theme's text color](theme::Theme::text_color) is used.
     ///
     /// To modify this on an active text area, use [`set_brush`](Self::set_brush).
     #[doc(alias = "with_color")]
     pub fn with_brush(mut self, brush: impl Into<Brush>) -> Self {
-        self.brush = brush.into();
+        self.brush = Some(brush.into());
         self
     }
 
@@ -241,47 +1143,187 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self
     }
 
-    /// Set whether [hinting](https://en.wikipedia.org/wiki/Font_hinting) will be used for this text area.
+    /// Set the brush used to fill the selected text's background whilst this text area is focused.
     ///
-    /// Hinting is a process where text is drawn "snapped" to pixel boundaries to improve fidelity.
-    /// The default is true, i.e. hinting is enabled by default.
+    /// To modify this on an active text area, use [`set_selection_brush`](Self::set_selection_brush).
+    #[doc(alias = "with_selection_color")]
+    pub fn with_selection_brush(mut self, brush: impl Into<Brush>) -> Self {
+        self.selection_brush = brush.into();
+        self
+    }
+
+    /// Set the brush used to fill the selected text's background whilst this text area isn't focused.
     ///
-    /// This should be set to false if the text area will be animated at creation.
-    /// The kinds of relevant animations include changing variable font parameters,
-    /// translating or scaling.
-    /// Failing to do so will likely lead to an unpleasant shimmering effect, as different parts of the
-    /// text "snap" at different times.
+    /// If this is `None`, which is the default, the selection is only shown whilst focused.
+    /// [`SELECTED_TEXT_INACTIVE_BACKGROUND_COLOR`](theme::SELECTED_TEXT_INACTIVE_BACKGROUND_COLOR)
+    /// is a suitable brush for text areas which should keep showing their selection.
     ///
-    /// To modify this on an active text area, use [`set_hint`](Self::set_hint).
-    /// You should do so as an animation starts and ends.
-    // TODO: Should we tell each widget if smooth scrolling is ongoing so they can disable their hinting?
-    // Alternatively, we should automate disabling hinting at the Vello layer when composing.
-    pub fn with_hint(mut self, hint: bool) -> Self {
-        self.hint = hint;
+    /// To modify this on an active text area, use [`set_inactive_selection_brush`](Self::set_inactive_selection_brush).
+    #[doc(alias = "with_inactive_selection_color")]
+    pub fn with_inactive_selection_brush(mut self, brush: impl Into<Option<Brush>>) -> Self {
+        self.inactive_selection_brush = brush.into();
         self
     }
 
-    /// Set the padding around the text.
+    /// Set the brush used to draw the caret.
     ///
-    /// This is the area outside the tight bound on the text where pointer events will be detected.
+    /// This should contrast against both the text and the [selection](Self::with_selection_brush).
+    /// By default, the [theme's caret color](theme::Theme::caret_color) is used.
     ///
-    /// To modify this on an active text area, use [`set_padding`](Self::set_padding).
-    pub fn with_padding(mut self, padding: impl Into<Padding>) -> Self {
-        self.padding = padding.into();
+    /// To modify this on an active text area, use [`set_caret_brush`](Self::set_caret_brush).
+    #[doc(alias = "with_caret_color")]
+    pub fn with_caret_brush(mut self, brush: impl Into<Brush>) -> Self {
+        self.caret_brush = Some(brush.into());
         self
     }
 
-    /// Adds `padding` unless [`with_padding`](Self::with_padding) was previously called.
+    /// Set what happens when the Tab key is pressed in this text area.
     ///
-    /// This is expected to be called when creating parent widgets.
-    pub fn with_padding_if_default(mut self, padding: Padding) -> Self {
-        if self.padding.is_unset() {
-            self.padding = padding;
-        }
+    /// By default, Tab moves focus to the next widget.
+    /// This has no effect if the text area is not user editable.
+    ///
+    /// To modify this on an active text area, use [`set_tab_action`](Self::set_tab_action).
+    pub fn with_tab_action(mut self, tab_action: TabAction) -> Self {
+        self.tab_action = tab_action;
         self
     }
 
-    /// Shared logic between `with_style` and `insert_style`
+    /// Set what is scrolled into view after the text is edited, by the user or through
+    /// [`insert_at_cursor`](Self::insert_at_cursor) and [`delete_selection`](Self::delete_selection).
+    ///
+    /// By default, the caret is revealed, so that e.g. the end of a large paste is shown.
+    ///
+    /// To modify this on an active text area, use [`set_scroll_anchor`](Self::set_scroll_anchor).
+    pub fn with_scroll_anchor(mut self, scroll_anchor: ScrollAnchor) -> Self {
+        self.scroll_anchor = scroll_anchor;
+        self
+    }
+
+    /// Set whether this text area starts in overtype mode.
+    ///
+    /// In overtype mode, each character the user types replaces the grapheme after the
+    /// caret, which is covered by a block caret.
+    /// At the end of a line, or whilst text is selected, typing inserts as normal.
+    /// This has no effect if the text area is not user editable.
+    ///
+    /// To modify this on an active text area, use [`set_overtype`](Self::set_overtype).
+    pub fn with_overtype(mut self, overtype: bool) -> Self {
+        self.overtype = overtype;
+        self
+    }
+
+    /// Set whether the user can switch overtype mode on and off by pressing Insert.
+    ///
+    /// This is off by default.
+    ///
+    /// To modify this on an active text area, use [`set_overtype_toggle`](Self::set_overtype_toggle).
+    pub fn with_overtype_toggle(mut self, allow_overtype_toggle: bool) -> Self {
+        self.allow_overtype_toggle = allow_overtype_toggle;
+        self
+    }
+
+    /// Only allow the user to insert characters for which `allowed` returns true.
+    ///
+    /// Disallowed characters are silently removed from typed, committed (by an IME) or
+    /// assistive technology-provided text, and the remaining characters are inserted.
+    /// Deleting text and moving the cursor are not affected.
+    /// Text set by the app (such as using [`reset_text`](Self::reset_text)) is not filtered.
+    ///
+    /// To modify this on an active text area, use [`set_allowed_chars`](Self::set_allowed_chars).
+    pub fn with_allowed_chars(mut self, allowed: impl Fn(char) -> bool + 'static) -> Self {
+        self.allowed_chars = Some(Box::new(allowed));
+        self
+    }
+
+    /// Only submit [`TextChanged`](crate::core::Action::TextChanged) once the user has stopped
+    /// editing for `debounce`.
+    ///
+    /// The text itself is still updated (and redrawn) on every edit; only the action is delayed,
+    /// and it carries the latest text.
+    /// Any pending change is submitted straight away when the text area loses focus, or before
+    /// [`TextEntered`](crate::core::Action::TextEntered), so that no edit is dropped.
+    ///
+    /// To modify this on an active text area, use [`set_change_debounce`](Self::set_change_debounce).
+    pub fn with_change_debounce(mut self, debounce: impl Into<Option<Duration>>) -> Self {
+        self.change_debounce = debounce.into();
+        self
+    }
+
+    /// Set whether [hinting](https://en.wikipedia.org/wiki/Font_hinting) will be used for this text area.
+    ///
+    /// Hinting is a process where text is drawn "snapped" to pixel boundaries to improve fidelity.
+    /// The default is true, i.e. hinting is enabled by default.
+    ///
+    /// This should be set to false if the text area will be animated at creation.
+    /// The kinds of relevant animations include changing variable font parameters,
+    /// translating or scaling.
+    /// Failing to do so will likely lead to an unpleasant shimmering effect, as different parts of the
+    /// text "snap" at different times.
+    ///
+    /// To modify this on an active text area, use [`set_hint`](Self::set_hint).
+    /// You should do so as an animation starts and ends.
+    // TODO: Should we tell each widget if smooth scrolling is ongoing so they can disable their hinting?
+    // Alternatively, we should automate disabling hinting at the Vello layer when composing.
+    pub fn with_hint(mut self, hint: bool) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Set the padding around the text.
+    ///
+    /// This is the area outside the tight bound on the text where pointer events will be detected.
+    ///
+    /// To modify this on an active text area, use [`set_padding`](Self::set_padding).
+    pub fn with_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Show line numbers in a gutter before the text, such as for viewing code or logs.
+    ///
+    /// The gutter is on the leading side of the text, inside the padding, and is as wide
+    /// as the number of digits in the last line number.
+    /// The numbers are drawn right-aligned in a monospace font, using the text's font size.
+    /// Clicking in the gutter doesn't place the caret.
+    ///
+    /// By default, each line as separated by newlines is numbered; use
+    /// [`with_line_numbering`](Self::with_line_numbering) to number visual lines instead.
+    ///
+    /// To modify this on an active text area, use [`set_line_numbers`](Self::set_line_numbers).
+    pub fn with_line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Set which lines are numbered when [line numbers](Self::with_line_numbers) are shown.
+    ///
+    /// To modify this on an active text area, use [`set_line_numbering`](Self::set_line_numbering).
+    pub fn with_line_numbering(mut self, line_numbering: LineNumbering) -> Self {
+        self.line_numbering = line_numbering;
+        self
+    }
+
+    /// Set the brush used to paint the [line numbers](Self::with_line_numbers).
+    ///
+    /// By default, they are painted with the [theme's disabled text color](crate::theme::Theme::disabled_text_color).
+    ///
+    /// To modify this on an active text area, use [`set_gutter_brush`](Self::set_gutter_brush).
+    pub fn with_gutter_brush(mut self, brush: impl Into<Brush>) -> Self {
+        self.gutter_brush = Some(brush.into());
+        self
+    }
+
+    /// Adds `padding` unless [`with_padding`](Self::with_padding) was previously called.
+    ///
+    /// This is expected to be called when creating parent widgets.
+    pub fn with_padding_if_default(mut self, padding: Padding) -> Self {
+        if self.padding.is_unset() {
+            self.padding = padding;
+        }
+        self
+    }
+
+    /// Shared logic between `with_style` and `insert_style`
     #[track_caller]
     fn insert_style_inner(&mut self, property: StyleProperty) -> Option<StyleProperty> {
         if let StyleProperty::Brush(idx @ BrushIndex(1..))
@@ -301,825 +1343,3436 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
 
 // --- MARK: HELPERS ---
 impl<const EDITABLE: bool> TextArea<EDITABLE> {
-    /// Get the IME area from the editor, accounting for padding.
+    /// Move the caret for one of the arrow keys, Home or End, extending the selection
+    /// instead if Shift is held.
     ///
-    /// This should only be called when the editor layout is available.
-    fn ime_area(&self) -> Rect {
-        debug_assert!(
-            self.editor.try_layout().is_some(),
-            "TextArea::ime_area should only be called when the editor layout is available"
-        );
-        let is_rtl = self
-            .editor
-            .try_layout()
-            .map(|layout| layout.is_rtl())
-            .unwrap_or(false);
-        self.editor.ime_cursor_area() + Vec2::new(self.padding.get_left(is_rtl), self.padding.top)
+    /// The arrow keys move by grapheme cluster, or by word with Ctrl (Option on macOS).
+    /// Left and Right move in visual order, so they move the caret leftwards and rightwards
+    /// through runs of right-to-left text too.
+    /// Home and End move to the start or end of the line, or of the text with Ctrl
+    /// (Cmd on macOS). On macOS, Cmd with the arrow keys also moves to the start or end
+    /// of the line or text.
+    fn navigate(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        key: NamedKey,
+        modifiers: ModifiersState,
+    ) {
+        let shift = modifiers.shift_key();
+        let (word_mod, text_mod) = if cfg!(target_os = "macos") {
+            (modifiers.alt_key(), modifiers.super_key())
+        } else {
+            (modifiers.control_key(), modifiers.control_key())
+        };
+
+        if word_mod
+            && matches!(key, NamedKey::ArrowLeft | NamedKey::ArrowRight)
+            && self.text().chars().any(is_rtl_char)
+        {
+            // Our word boundaries are in logical order, so they would jump the caret back and
+            // forth across runs of right-to-left text; parley moves by words in visual order.
+            let mut drv = self.editor.driver(fctx, lctx);
+            match (key, shift) {
+                (NamedKey::ArrowLeft, true) => drv.select_word_left(),
+                (NamedKey::ArrowLeft, false) => drv.move_word_left(),
+                (_, true) => drv.select_word_right(),
+                (_, false) => drv.move_word_right(),
+            }
+            return;
+        }
+        if word_mod && matches!(key, NamedKey::ArrowLeft | NamedKey::ArrowRight) {
+            let text = self.text().to_string();
+            let selection = self.editor.raw_selection();
+            let (anchor, focus) = (selection.anchor().index(), selection.focus().index());
+            let target = if key == NamedKey::ArrowLeft {
+                prev_word_start(&text, focus)
+            } else {
+                next_word_end(&text, focus)
+            };
+            let anchor = if shift { anchor } else { target };
+            self.editor
+                .driver(fctx, lctx)
+                .select_byte_range(anchor, target);
+            return;
+        }
+
+        // On macOS, Cmd with the arrow keys moves to the start or end of the line or text.
+        let mac_cmd = cfg!(target_os = "macos") && modifiers.super_key();
+        let (key, text_mod) = match key {
+            NamedKey::ArrowLeft if mac_cmd => (NamedKey::Home, false),
+            NamedKey::ArrowRight if mac_cmd => (NamedKey::End, false),
+            NamedKey::ArrowUp if mac_cmd => (NamedKey::Home, true),
+            NamedKey::ArrowDown if mac_cmd => (NamedKey::End, true),
+            key => (key, text_mod),
+        };
+
+        let mut drv = self.editor.driver(fctx, lctx);
+        match key {
+            NamedKey::ArrowLeft => {
+                if shift {
+                    drv.select_left();
+                } else {
+                    drv.move_left();
+                }
+            }
+            NamedKey::ArrowRight => {
+                if shift {
+                    drv.select_right();
+                } else {
+                    drv.move_right();
+                }
+            }
+            NamedKey::ArrowUp => {
+                if shift {
+                    drv.select_up();
+                } else {
+                    drv.move_up();
+                }
+            }
+            NamedKey::ArrowDown => {
+                if shift {
+                    drv.select_down();
+                } else {
+                    drv.move_down();
+                }
+            }
+            NamedKey::Home => {
+                if text_mod {
+                    if shift {
+                        drv.select_to_text_start();
+                    } else {
+                        drv.move_to_text_start();
+                    }
+                } else if shift {
+                    drv.select_to_line_start();
+                } else {
+                    drv.move_to_line_start();
+                }
+            }
+            NamedKey::End => {
+                if text_mod {
+                    if shift {
+                        drv.select_to_text_end();
+                    } else {
+                        drv.move_to_text_end();
+                    }
+                } else if shift {
+                    drv.select_to_line_end();
+                } else {
+                    drv.move_to_line_end();
+                }
+            }
+            _ => {}
+        }
     }
-}
 
-// --- MARK: WIDGETMUT ---
-impl<const EDITABLE: bool> TextArea<EDITABLE> {
-    /// Set font styling for an active text area.
+    /// The font size chosen by [auto-fit](Self::with_auto_fit) in the last layout.
     ///
-    /// Style properties set by this method include [text size](parley::StyleProperty::FontSize),
-    /// [font family](parley::StyleProperty::FontStack), [font weight](parley::StyleProperty::FontWeight),
-    /// and [variable font parameters](parley::StyleProperty::FontVariations).
-    /// The styles inserted here apply to the entire text; we currently do not
-    /// support inline rich text.
-    ///
-    /// Setting [`StyleProperty::Brush`](parley::StyleProperty::Brush) is not supported.
-    /// Use [`set_brush`](Self::set_brush) instead.
-    /// This is also not additive for [font stacks](parley::StyleProperty::FontStack), and
-    /// instead overwrites any previous font stack.
-    ///
-    /// This is the runtime equivalent of [`with_style`](Self::with_style).
-    #[track_caller]
-    pub fn insert_style(
-        this: &mut WidgetMut<'_, Self>,
-        property: impl Into<StyleProperty>,
-    ) -> Option<StyleProperty> {
-        let old = this.widget.insert_style_inner(property.into());
+    /// Returns `None` if auto-fit is disabled, or the text area hasn't been laid out since
+    /// it was enabled.
+    pub fn fitted_font_size(&self) -> Option<f32> {
+        self.fitted.as_ref().map(|fitted| fitted.font_size)
+    }
 
-        this.ctx.request_layout();
-        old
+    /// Counts of the graphemes, words, bytes and lines in the text.
+    ///
+    /// These are counted on the first call after the text changes, and cached until it
+    /// next changes, so this is cheap to call repeatedly.
+    pub fn text_stats(&self) -> TextStats {
+        let generation = self.editor.generation();
+        if let Some((counted_generation, stats)) = self.text_stats.take() {
+            if counted_generation == generation {
+                self.text_stats.set(Some((counted_generation, stats)));
+                return stats;
+            }
+        }
+        let text: String = self.text().into_iter().collect();
+        let stats = TextStats::new(&text);
+        self.text_stats.set(Some((generation, stats)));
+        stats
     }
 
-    /// [Retain](std::vec::Vec::retain) only the styles for which `f` returns true.
+    /// The number of user-perceived characters ([grapheme clusters]) in the text.
     ///
-    /// Styles which are removed return to Parley's default values.
-    /// In most cases, these are the defaults for this widget.
+    /// See [`text_stats`](Self::text_stats) for how this is cached.
     ///
-    /// Of note, behaviour is unspecified for unsetting the [`FontSize`](parley::StyleProperty::FontSize).
-    pub fn retain_styles(this: &mut WidgetMut<'_, Self>, f: impl FnMut(&StyleProperty) -> bool) {
-        this.widget.editor.edit_styles().retain(f);
+    /// [grapheme clusters]: TextStats::graphemes
+    pub fn char_count(&self) -> usize {
+        self.text_stats().graphemes
+    }
 
-        this.ctx.request_layout();
+    /// The number of words in the text, as counted by [`TextStats::words`].
+    ///
+    /// See [`text_stats`](Self::text_stats) for how this is cached.
+    pub fn word_count(&self) -> usize {
+        self.text_stats().words
     }
 
-    /// Remove the style with the discriminant `property`.
+    /// The number of visual lines in the text, including lines created by word wrapping.
     ///
-    /// Styles which are removed return to Parley's default values.
-    /// In most cases, these are the defaults for this widget.
+    /// Returns `None` if the text layout is out of date, i.e. the text area hasn't been
+    /// laid out since it was created or its text or styles were last changed.
+    pub fn line_count(&self) -> Option<usize> {
+        self.current_layout().map(Layout::len)
+    }
+
+    /// The position of each visual line in the text, for example to draw line numbers
+    /// alongside the text.
     ///
-    /// To get the discriminant requires constructing a valid `StyleProperty` for the
-    /// the desired property and passing it to [`core::mem::discriminant`].
-    /// Getting this discriminant is usually possible in a `const` context.
+    /// Returns `None` if the text layout is out of date, like [`line_count`](Self::line_count).
+    pub fn line_metrics(&self) -> Option<Vec<LineMetric>> {
+        let layout = self.current_layout()?;
+        let top = self.padding.top;
+        let metrics = layout
+            .lines()
+            .map(|line| {
+                let metrics = line.metrics();
+                LineMetric {
+                    top: top + f64::from(metrics.min_coord),
+                    baseline: top + f64::from(metrics.baseline),
+                    height: f64::from(metrics.max_coord) - f64::from(metrics.min_coord),
+                }
+            })
+            .collect();
+        Some(metrics)
+    }
+
+    /// The byte offset in the text of the cursor position nearest to `pos`, which is in this
+    /// widget's local coordinates.
     ///
-    /// Of note, behaviour is unspecified for unsetting the [`FontSize`](parley::StyleProperty::FontSize).
-    pub fn remove_style(
-        this: &mut WidgetMut<'_, Self>,
-        property: Discriminant<StyleProperty>,
-    ) -> Option<StyleProperty> {
-        let old = this.widget.editor.edit_styles().remove(property);
+    /// Points outside of the text map to the nearest position in the text, so for example
+    /// points below the last line map to a position on the last line.
+    /// Returns `None` if the text layout is out of date, like [`line_count`](Self::line_count).
+    pub fn offset_at_point(&self, pos: Point) -> Option<usize> {
+        let layout = self.current_layout()?;
+        let pos = pos - self.text_origin(layout);
+        Some(Cursor::from_point(layout, pos.x as f32, pos.y as f32).index())
+    }
 
-        this.ctx.request_layout();
-        old
+    /// The area of a caret at the cursor position at `offset` (in bytes) in the text,
+    /// in this widget's local coordinates.
+    ///
+    /// This is the inverse of [`offset_at_point`](Self::offset_at_point).
+    /// Returns `None` if the text layout is out of date, like [`line_count`](Self::line_count).
+    pub fn rect_for_offset(&self, offset: usize) -> Option<Rect> {
+        let layout = self.current_layout()?;
+        let cursor = Cursor::from_byte_index(layout, offset, Affinity::Downstream);
+        Some(cursor.geometry(layout, CARET_WIDTH) + self.text_origin(layout))
     }
 
-    /// Set the text displayed in this widget.
+    /// Whether lines are broken at the available width.
     ///
-    /// This is likely to be disruptive if the user is focused on this widget,
-    /// as it does not retain selections, and may cause undesirable interactions with IME.
-    pub fn reset_text(this: &mut WidgetMut<'_, Self>, new_text: &str) {
-        // If the IME is currently composing, we need to clear the compose first. This is quite
-        // disruptive, but we've warned about that. The platform's state is not reset, and the
-        // preedit will show up again when the platform updates it.
-        if this.widget.editor.is_composing() {
-            let (fctx, lctx) = this.ctx.text_contexts();
-            this.widget.editor.driver(fctx, lctx).clear_compose();
+    /// Justified text is wrapped even without word wrapping, as otherwise there would be
+    /// no lines to spread out.
+    fn wraps_to_width(&self) -> bool {
+        (self.word_wrap || matches!(self.alignment, Alignment::Justified))
+            && self.auto_fit.is_none()
+    }
+
+    /// The alignment to lay the text out with, given the available width.
+    ///
+    /// This is the chosen alignment, except that justified text can't fill an infinite width,
+    /// and that start and end are swapped if the base direction differs from the text's.
+    fn layout_alignment(&self, available_width: Option<f32>) -> Alignment {
+        let alignment = match self.alignment {
+            Alignment::Justified if available_width.is_none() => Alignment::Start,
+            alignment => alignment,
+        };
+        if self.is_rtl(self.text_is_rtl) == self.text_is_rtl {
+            return alignment;
         }
-        this.widget.editor.set_text(new_text);
+        match alignment {
+            Alignment::Start => Alignment::End,
+            Alignment::End => Alignment::Start,
+            alignment => alignment,
+        }
+    }
 
-        let (fctx, lctx) = this.ctx.text_contexts();
-        this.widget.editor.driver(fctx, lctx).move_to_text_end();
+    /// Whether the text is laid out right to left, given whether the text itself is.
+    fn is_rtl(&self, text_is_rtl: bool) -> bool {
+        match self.base_direction {
+            Direction::Auto => text_is_rtl,
+            Direction::Ltr => false,
+            Direction::Rtl => true,
+        }
+    }
 
-        this.ctx.request_layout();
+    /// The position of the top left of the text layout, in this widget's local coordinates.
+    fn text_origin(&self, layout: &Layout<BrushIndex>) -> Vec2 {
+        let padding = self.text_padding();
+        Vec2::new(padding.get_left(self.is_rtl(layout.is_rtl())), padding.top)
     }
 
-    /// Control [word wrapping](https://en.wikipedia.org/wiki/Line_wrap_and_word_wrap) for the text area.
-    ///
-    /// When enabled, the text will be laid out to fit within the available width.
-    /// If word wrapping is disabled, the text will likely flow past the available area.
-    /// Note that parent widgets will often clip this, so the overflow will not be visible.
-    ///
-    /// This widget does not currently support scrolling to the cursor,
-    /// so it is recommended to leave word wrapping enabled.
-    ///
-    /// The runtime equivalent of [`with_word_wrap`](Self::with_word_wrap).
-    pub fn set_word_wrap(this: &mut WidgetMut<'_, Self>, wrap_words: bool) {
-        this.widget.word_wrap = wrap_words;
-        let width = if wrap_words {
-            this.widget.last_available_width
+    /// The space around the text, which is the padding with the gutter added to its leading side.
+    fn text_padding(&self) -> Padding {
+        Padding {
+            leading: self.padding.leading + self.gutter_width,
+            ..self.padding
+        }
+    }
+
+    /// Whether `pos`, in this widget's local coordinates, is in the line number gutter.
+    fn gutter_contains(&self, pos: Point, is_rtl: bool, width: f64) -> bool {
+        let start = if is_rtl {
+            width - self.padding.leading - self.gutter_width
         } else {
-            None
+            self.padding.leading
         };
-        this.widget.editor.set_width(width);
-        this.ctx.request_layout();
+        (start..start + self.gutter_width).contains(&pos.x)
     }
 
-    /// Set the [alignment](https://en.wikipedia.org/wiki/Typographic_alignment) of the text.
-    ///
-    /// Text alignment might have unexpected results when the text area has no horizontal constraints.
-    ///
-    /// The runtime equivalent of [`with_alignment`](Self::with_alignment).
-    pub fn set_alignment(this: &mut WidgetMut<'_, Self>, alignment: Alignment) {
-        this.widget.editor.set_alignment(alignment);
-
-        this.ctx.request_layout();
+    /// Whether the text layout is out of date, because the text or styles have changed
+    /// since it was last built, so it must be rebuilt before the text area is painted.
+    #[cfg(test)]
+    pub(crate) fn needs_rebuild(&self) -> bool {
+        self.editor.try_layout().is_none()
     }
 
-    #[doc(alias = "set_color")]
-    /// Set the brush used to paint the text in this text area.
-    ///
-    /// In most cases, this will be the text's color, but gradients and images are also supported.
+    /// Rebuild the text layout if it is out of date, outside of the layout pass.
     ///
-    /// The runtime equivalent of [`with_brush`](Self::with_brush).
-    pub fn set_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Brush>) {
-        let brush = brush.into();
-        this.widget.brush = brush;
+    /// This doesn't apply the spans or the available width, which are only
+    /// updated in layout.
+    #[cfg(test)]
+    pub(crate) fn rebuild_layout(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+    ) {
+        self.editor.refresh_layout(fctx, lctx);
+    }
 
-        // We need to repaint unless the disabled brush is currently being used.
-        if this.widget.disabled_brush.is_none() || !this.ctx.is_disabled() {
-            this.ctx.request_paint_only();
-        }
+    /// The text layout which was last painted, if it is up to date.
+    fn current_layout(&self) -> Option<&Layout<BrushIndex>> {
+        let layout = self.editor.try_layout()?;
+        Some(self.styled_layout.as_ref().unwrap_or(layout))
     }
 
-    /// Set the brush used to paint this text area whilst it is disabled.
-    ///
-    /// If this is `None`, the [normal brush](Self::set_brush) will be used.
-    ///
-    /// The runtime equivalent of [`with_disabled_brush`](Self::with_disabled_brush).
-    pub fn set_disabled_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Option<Brush>>) {
-        let brush = brush.into();
-        this.widget.disabled_brush = brush;
+    /// The link under `pos`, in this widget's local coordinates.
+    fn link_at(&self, pos: Point) -> Option<Range<usize>> {
+        let layout = self.current_layout()?;
+        let pos = pos - self.text_origin(layout);
+        self.links
+            .iter()
+            .find(|link| {
+                range_line_extents(layout, (*link).clone()).any(|(line, x0, x1)| {
+                    let metrics = line.metrics();
+                    let (top, bottom) = (metrics.min_coord, metrics.max_coord);
+                    (x0..x1).contains(&pos.x)
+                        && (f64::from(top)..f64::from(bottom)).contains(&pos.y)
+                })
+            })
+            .cloned()
+    }
 
-        if this.ctx.is_disabled() {
-            this.ctx.request_paint_only();
+    /// The spans applied to the text, followed by the styles of any links.
+    fn styled_spans(&self) -> Cow<'_, [(Range<usize>, StyleAttribute)]> {
+        if self.links.is_empty() {
+            return Cow::Borrowed(&self.spans);
         }
+        let link_styles = self.links.iter().flat_map(|link| {
+            [
+                (
+                    link.clone(),
+                    StyleAttribute::Brush(theme::ACCENT_COLOR.into()),
+                ),
+                (link.clone(), StyleAttribute::Underline(true)),
+            ]
+        });
+        Cow::Owned(self.spans.iter().cloned().chain(link_styles).collect())
     }
 
-    /// Set whether [hinting](https://en.wikipedia.org/wiki/Font_hinting) will be used for this text area.
+    /// Set the font size to the largest in `range` at which the text fits in `available_width`.
     ///
-    /// The runtime equivalent of [`with_hint`](Self::with_hint).
-    /// For full documentation, see that method.
-    pub fn set_hint(this: &mut WidgetMut<'_, Self>, hint: bool) {
-        this.widget.hint = hint;
-        this.ctx.request_paint_only();
+    /// This does nothing if the size has already been chosen for the current text and width.
+    fn fit_font_size(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        (min_size, max_size): (f32, f32),
+        available_width: Option<f32>,
+    ) {
+        let text = self.editor.text().to_string();
+        if let Some(fitted) = &self.fitted {
+            if fitted.text == text && fitted.available_width == available_width {
+                return;
+            }
+        }
+        let mut width_at = |font_size: f32| {
+            self.editor
+                .edit_styles()
+                .insert(StyleProperty::FontSize(font_size));
+            self.editor.layout(fctx, lctx).full_width()
+        };
+        let font_size = match available_width {
+            Some(available_width) if width_at(max_size) > available_width => {
+                // `low` fits (or is the minimum), and `high` doesn't fit.
+                let (mut low, mut high) = (min_size, max_size);
+                for _ in 0..AUTO_FIT_ITERATIONS {
+                    let mid = (low + high) / 2.;
+                    if width_at(mid) <= available_width {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                low
+            }
+            _ => max_size,
+        };
+        self.editor
+            .edit_styles()
+            .insert(StyleProperty::FontSize(font_size));
+        self.fitted = Some(FittedSize {
+            text,
+            available_width,
+            font_size,
+        });
     }
 
-    /// Set the padding around the text.
-    ///
-    /// This is the area outside the tight bound on the text where pointer events will be detected.
+    /// Lay out the text in the space left inside `bc` by the padding and gutter,
+    /// returning the size of the text.
+    fn layout_text(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints) -> Size {
+        // Shrink constraints by padding inset
+        let padding = self.text_padding();
+        let padding_size = Size::new(
+            padding.leading + padding.trailing,
+            padding.top + padding.bottom,
+        );
+        let sub_bc = bc.shrink(padding_size);
+
+        let available_width = if bc.max().width.is_finite() {
+            Some((sub_bc.max().width) as f32)
+        } else {
+            None
+        };
+        let word_wrap = self.wraps_to_width();
+        let max_advance = if word_wrap { available_width } else { None };
+        if self.last_available_width != available_width && word_wrap {
+            self.editor.set_width(max_advance);
+        }
+        if self.base_direction != Direction::Auto {
+            // Whether the alignment is flipped depends on the direction of the text itself.
+            let (fctx, lctx) = ctx.text_contexts();
+            let text_is_rtl = self.editor.layout(fctx, lctx).is_rtl();
+            if text_is_rtl != self.text_is_rtl {
+                self.text_is_rtl = text_is_rtl;
+                self.editor
+                    .set_alignment(self.layout_alignment(available_width));
+            }
+        }
+        let alignment = self.layout_alignment(available_width);
+        if matches!(self.alignment, Alignment::Justified) {
+            if available_width.is_none() {
+                tracing::warn!(
+                    "TextArea has justified text but an infinite width, so the text is aligned to the start instead."
+                );
+            }
+            if self.last_available_width.is_some() != available_width.is_some() {
+                self.editor.set_alignment(alignment);
+            }
+        }
+        self.last_available_width = available_width;
+        if let Some(range) = self.auto_fit {
+            let (fctx, lctx) = ctx.text_contexts();
+            self.fit_font_size(fctx, lctx, range, available_width);
+        }
+        // TODO: Use the minimum width in the bc for alignment
+
+        let new_generation = self.editor.generation();
+        if new_generation != self.rendered_generation {
+            self.rendered_generation = new_generation;
+        }
+
+        if !self.spans.is_empty()
+            || !self.decorations.is_empty()
+            || !self.highlights.is_empty()
+            || self.autolink
+        {
+            self.move_spans();
+        }
+        self.links = if self.autolink {
+            find_links(&self.spans_text)
+        } else {
+            Vec::new()
+        };
+        if self.spans.is_empty() && self.links.is_empty() {
+            self.styled_layout = None;
+        } else {
+            let (fctx, lctx) = ctx.text_contexts();
+            // TODO: Should we use a different scale?
+            let mut builder = lctx.ranged_builder(fctx, &self.spans_text, 1.0);
+            for prop in self.editor.edit_styles().inner().values() {
+                builder.push_default(prop.to_owned());
+            }
+            push_spans(&mut builder, &self.spans_text, &self.styled_spans());
+            let mut layout = builder.build(&self.spans_text);
+            layout.break_all_lines(max_advance);
+            layout.align(max_advance, alignment, AlignmentOptions::default());
+            self.styled_layout = Some(layout);
+        }
+
+        let (fctx, lctx) = ctx.text_contexts();
+        let layout = self.editor.layout(fctx, lctx);
+        let layout = self.styled_layout.as_ref().unwrap_or(layout);
+        let text_width = max_advance.unwrap_or(layout.full_width());
+        Size::new(text_width.into(), layout.height().into())
+    }
+
+    /// The number of lines which are numbered in the gutter, according to the
+    /// [`LineNumbering`].
+    fn numbered_line_count(&self) -> usize {
+        match self.line_numbering {
+            LineNumbering::Logical => self.text_stats().lines,
+            LineNumbering::Visual => self.line_count().unwrap_or(1),
+        }
+    }
+
+    /// Lay out the number of each numbered line, with the top of each number relative to
+    /// the top of the text.
+    fn line_number_layouts(
+        &self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        styles: &[StyleProperty],
+    ) -> Vec<(f64, Layout<BrushIndex>)> {
+        let Some(layout) = self.current_layout() else {
+            return Vec::new();
+        };
+        let text = self.editor.text().to_string();
+        let mut numbers = Vec::new();
+        for line in layout.lines() {
+            let start = line.text_range().start;
+            // With logical numbering, lines which continue a wrapped line aren't numbered.
+            if self.line_numbering == LineNumbering::Logical
+                && start != 0
+                && !text[..start].ends_with('\n')
+            {
+                continue;
+            }
+            let number = gutter_layout(fctx, lctx, styles, &(numbers.len() + 1).to_string());
+            let number_baseline = number
+                .lines()
+                .next()
+                .map_or(0.0, |line| line.metrics().baseline);
+            let top = f64::from(line.metrics().baseline) - f64::from(number_baseline);
+            numbers.push((top, number));
+        }
+        numbers
+    }
+
+    /// The word (for a double click) or line (for a triple click) at `pos`,
+    /// according to the current click count.
     ///
-    /// The runtime equivalent of [`with_padding`](Self::with_padding).
-    pub fn set_padding(this: &mut WidgetMut<'_, Self>, padding: impl Into<Padding>) {
-        this.widget.padding = padding.into();
-        // TODO: We could reset the width available to the editor here directly.
-        // Determine whether there's any advantage to that
-        this.ctx.request_layout();
+    /// Returns `None` when not in a multi-click sequence.
+    fn granular_range_at(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        pos: Point,
+    ) -> Option<Range<usize>> {
+        let layout = self.editor.layout(fctx, lctx);
+        let (x, y) = (pos.x as f32, pos.y as f32);
+        match self.click_count {
+            2 => Some(Selection::word_from_point(layout, x, y).text_range()),
+            3 => Some(Selection::line_from_point(layout, x, y).text_range()),
+            _ => None,
+        }
     }
 
-    /// Set the selection to the given byte range.
+    /// Get the IME area from the editor, accounting for padding.
     ///
-    /// No-op if either index is not a char boundary.
-    pub fn select_byte_range(this: &mut WidgetMut<'_, Self>, start: usize, end: usize) {
-        let (fctx, lctx) = this.ctx.text_contexts();
-        this.widget
+    /// This should only be called when the editor layout is available.
+    fn ime_area(&self) -> Rect {
+        debug_assert!(
+            self.editor.try_layout().is_some(),
+            "TextArea::ime_area should only be called when the editor layout is available"
+        );
+        let is_rtl = self
             .editor
-            .driver(fctx, lctx)
-            .select_byte_range(start, end);
-        this.ctx.request_render();
+            .try_layout()
+            .is_some_and(|layout| self.is_rtl(layout.is_rtl()));
+        let padding = self.text_padding();
+        self.editor.ime_cursor_area() + Vec2::new(padding.get_left(is_rtl), padding.top)
     }
 
-    /// Set the selection to the first instance of the given text.
+    /// The area covered by the caret, in this widget's local coordinates.
     ///
-    /// This is mostly useful for testing.
+    /// If the text layout is out of date (i.e. the text has been edited since the last
+    /// layout pass), this returns a zero-sized rectangle at the text origin.
+    pub fn caret_rect(&self) -> Rect {
+        let is_rtl = self
+            .editor
+            .try_layout()
+            .is_some_and(|layout| self.is_rtl(layout.is_rtl()));
+        let padding = self.text_padding();
+        let origin = Vec2::new(padding.get_left(is_rtl), padding.top);
+        self.editor
+            .cursor_geometry(CARET_WIDTH)
+            .unwrap_or(Rect::ZERO)
+            + origin
+    }
+
+    /// Ask any scrolling ancestors to reveal the caret.
     ///
-    /// No-op if the text isn't found.
-    pub fn select_text(this: &mut WidgetMut<'_, Self>, text: &str) {
-        let Some(start) = this.widget.text().to_string().find(text) else {
-            return;
-        };
-        let end = start + text.len();
-        Self::select_byte_range(this, start, end);
+    /// This only scrolls by as much as needed to show the caret, rather than the whole text area,
+    /// which would be jarring for tall text areas.
+    fn scroll_to_caret(&mut self, ctx: &mut EventCtx) {
+        // The layout will be out of date if the text was edited, so refresh it to
+        // get the caret's new position.
+        let (fctx, lctx) = ctx.text_contexts();
+        self.editor.refresh_layout(fctx, lctx);
+        ctx.request_scroll_to(self.caret_rect());
     }
-}
 
-// --- MARK: IMPL WIDGET ---
-impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
-    fn on_pointer_event(
+    /// The area to reveal after an edit which started at byte `edit_start`, according to
+    /// the [`ScrollAnchor`], or `None` if the whole text area should be revealed.
+    fn edit_scroll_target(
         &mut self,
-        ctx: &mut EventCtx,
-        _props: &mut PropertiesMut<'_>,
-        event: &PointerEvent,
-    ) {
-        if self.editor.is_composing() {
-            return;
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        edit_start: usize,
+    ) -> Option<Rect> {
+        // The layout will be out of date, so refresh it to get the edited text's position.
+        self.editor.refresh_layout(fctx, lctx);
+        match self.scroll_anchor {
+            ScrollAnchor::CaretEnd => Some(self.caret_rect()),
+            ScrollAnchor::SelectionStart => {
+                // Deleting backwards starts the edit before the previous selection.
+                let start = edit_start.min(self.editor.raw_selection().text_range().start);
+                let layout = self.editor.try_layout()?;
+                let cursor = Cursor::from_byte_index(layout, start, Affinity::Downstream);
+                Some(cursor.geometry(layout, CARET_WIDTH) + self.text_origin(layout))
+            }
+            ScrollAnchor::Widget => None,
         }
+    }
 
+    /// Ask any scrolling ancestors to reveal the area given by the [`ScrollAnchor`],
+    /// after an edit which started at byte `edit_start`.
+    fn scroll_after_edit(&mut self, ctx: &mut EventCtx, edit_start: usize) {
         let (fctx, lctx) = ctx.text_contexts();
-        let is_rtl = self.editor.layout(fctx, lctx).is_rtl();
-        let padding = Vec2::new(self.padding.get_left(is_rtl), self.padding.top);
-        match event {
-            PointerEvent::PointerDown(button, _) => {
-                if !ctx.is_disabled() && *button == PointerButton::Primary {
-                    let now = Instant::now();
-                    if let Some(last) = self.last_click_time.take() {
-                        if now.duration_since(last).as_secs_f64() < 0.25 {
-                            self.click_count = (self.click_count + 1) % 4;
-                        } else {
-                            self.click_count = 1;
-                        }
-                    } else {
-                        self.click_count = 1;
-                    }
-                    self.last_click_time = Some(now);
-                    let click_count = self.click_count;
-                    let cursor_pos = event.local_position(ctx) - padding;
-                    let (fctx, lctx) = ctx.text_contexts();
-                    let mut drv = self.editor.driver(fctx, lctx);
-                    match click_count {
-                        2 => drv.select_word_at_point(cursor_pos.x as f32, cursor_pos.y as f32),
-                        3 => drv.select_line_at_point(cursor_pos.x as f32, cursor_pos.y as f32),
-                        _ => drv.move_to_point(cursor_pos.x as f32, cursor_pos.y as f32),
-                    }
-                    let new_generation = self.editor.generation();
-                    if new_generation != self.rendered_generation {
-                        ctx.request_render();
-                        ctx.set_ime_area(self.ime_area());
-                        self.rendered_generation = new_generation;
-                    }
-                    ctx.request_focus();
-                    ctx.capture_pointer();
-                }
+        match self.edit_scroll_target(fctx, lctx, edit_start) {
+            Some(rect) => ctx.request_scroll_to(rect),
+            None => ctx.request_scroll_to_this(),
+        }
+    }
+
+    /// Remove the characters which the user isn't allowed to insert from `text`.
+    /// Move the spans and decorations to follow any edits made to the text since they were
+    /// last moved.
+    fn move_spans(&mut self) {
+        let text = self.editor.text().to_string();
+        if let Some((replaced, inserted_len)) = text_edit(&self.spans_text, &text) {
+            for (range, _) in &mut self.spans {
+                *range = move_range(range.clone(), replaced.clone(), inserted_len);
             }
-            PointerEvent::PointerMove(_) => {
-                if !ctx.is_disabled() && ctx.is_pointer_capture_target() {
-                    let cursor_pos = event.local_position(ctx) - padding;
-                    let (fctx, lctx) = ctx.text_contexts();
-                    self.editor
-                        .driver(fctx, lctx)
-                        .extend_selection_to_point(cursor_pos.x as f32, cursor_pos.y as f32);
-                    let new_generation = self.editor.generation();
-                    if new_generation != self.rendered_generation {
-                        ctx.request_render();
-                        ctx.set_ime_area(self.ime_area());
-                        self.rendered_generation = new_generation;
-                    }
-                }
+            self.spans.retain(|(range, _)| !range.is_empty());
+            for decoration in &mut self.decorations {
+                decoration.range =
+                    move_range(decoration.range.clone(), replaced.clone(), inserted_len);
             }
-            _ => {}
+            self.decorations
+                .retain(|decoration| !decoration.range.is_empty());
+            for range in &mut self.highlights {
+                *range = move_range(range.clone(), replaced.clone(), inserted_len);
+            }
+            self.highlights.retain(|range| !range.is_empty());
         }
+        self.spans_text = text;
     }
 
-    fn on_text_event(
+    /// Submit a `TextSelectionChanged` action if the selection has changed since it was
+    /// last reported.
+    fn report_selection(&mut self, ctx: &mut EventCtx) {
+        if !EDITABLE {
+            return;
+        }
+        let selection = self.editor.raw_selection().text_range();
+        if selection != self.reported_selection {
+            self.reported_selection = selection.clone();
+            ctx.submit_action(crate::core::Action::TextSelectionChanged(SelectionChange {
+                start: selection.start,
+                end: selection.end,
+                programmatic: false,
+            }));
+        }
+    }
+
+    /// The byte range of the grapheme which the next typed character will replace.
+    ///
+    /// This is `None` unless this text area is in overtype mode with only a caret,
+    /// which isn't at the end of a line.
+    fn overtype_range(&self) -> Option<Range<usize>> {
+        if !EDITABLE || !self.overtype || self.editor.is_composing() {
+            return None;
+        }
+        let selection = self.editor.raw_selection();
+        if !selection.is_collapsed() {
+            return None;
+        }
+        let caret = selection.focus().index();
+        let text = self.text().to_string();
+        let grapheme = text[caret..].graphemes(true).next()?;
+        if grapheme.ends_with('\n') {
+            return None;
+        }
+        Some(caret..caret + grapheme.len())
+    }
+
+    /// The start of the grapheme cluster containing the byte `index` of the text.
+    ///
+    /// Indices which aren't char boundaries, or are past the end of the text, are unchanged.
+    fn grapheme_floor(&self, index: usize) -> usize {
+        let text = self.text().to_string();
+        if index >= text.len() || !text.is_char_boundary(index) {
+            return index;
+        }
+        text.grapheme_indices(true)
+            .map(|(start, _)| start)
+            .take_while(|&start| start <= index)
+            .last()
+            .unwrap_or(0)
+    }
+
+    /// Insert `text` typed by the user, replacing the next grapheme in overtype mode.
+    ///
+    /// Returns whether the text was edited.
+    fn type_filtered(
         &mut self,
-        ctx: &mut EventCtx,
-        _props: &mut PropertiesMut<'_>,
-        event: &TextEvent,
-    ) {
-        match event {
-            TextEvent::KeyboardKey(key_event, modifiers_state) => {
-                if !key_event.state.is_pressed() || self.editor.is_composing() {
-                    return;
-                }
-                #[allow(unused)]
-                let (shift, action_mod) = (
-                    modifiers_state.shift_key(),
-                    if cfg!(target_os = "macos") {
-                        modifiers_state.super_key()
-                    } else {
-                        modifiers_state.control_key()
-                    },
-                );
-                let (fctx, lctx) = ctx.text_contexts();
-                // Whether the text was changed.
-                let mut edited = false;
-                // Ideally we'd use key_without_modifiers, but that's broken
-                match &key_event.logical_key {
-                    // Cut
-                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
-                    Key::Character(x)
-                        if EDITABLE && action_mod && x.as_str().eq_ignore_ascii_case("x") =>
-                    {
-                        edited = true;
-                        // TODO: use clipboard_rs::{Clipboard, ClipboardContext};
-                        // if let Some(text) = self.editor.selected_text() {
-                        //     let cb = ClipboardContext::new().unwrap();
-                        //     cb.set_text(text.to_owned()).ok();
-                        //     self.editor.drive(fcx, lcx, |drv| drv.delete_selection());
-                        // }
-                        // edited = true;
-                    }
-                    // Copy
-                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
-                    Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("c") => {
-                        // TODO: use clipboard_rs::{Clipboard, ClipboardContext};
-                        // if let Some(text) = self.editor.selected_text() {
-                        //     let cb = ClipboardContext::new().unwrap();
-                        //     cb.set_text(text.to_owned()).ok();
-                        // }
-                    }
-                    // Paste
-                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
-                    Key::Character(v)
-                        if EDITABLE && action_mod && v.as_str().eq_ignore_ascii_case("v") =>
-                    {
-                        edited = true;
-                        // TODO: use clipboard_rs::{Clipboard, ClipboardContext};
-                        // let cb = ClipboardContext::new().unwrap();
-                        // let text = cb.get_text().unwrap_or_default();
-                        // self.editor.drive(fcx, lcx, |drv| drv.insert_or_replace_selection(&text));
-                        // edited = true;
-                    }
-                    Key::Character(a) if action_mod && a.as_str().eq_ignore_ascii_case("a") => {
-                        let mut drv = self.editor.driver(fctx, lctx);
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        text: &str,
+    ) -> bool {
+        if let Some(range) = self.overtype_range() {
+            // Nothing is replaced if none of the text is allowed.
+            if !self.filter_input(text).is_empty() {
+                self.editor
+                    .driver(fctx, lctx)
+                    .select_byte_range(range.start, range.end);
+            }
+        }
+        self.insert_filtered(fctx, lctx, text)
+    }
 
-                        if shift {
-                            drv.collapse_selection();
-                        } else {
-                            drv.select_all();
-                        }
-                    }
-                    Key::Named(NamedKey::ArrowLeft) => {
-                        let mut drv = self.editor.driver(fctx, lctx);
-                        if action_mod {
-                            if shift {
-                                drv.select_word_left();
-                            } else {
-                                drv.move_word_left();
-                            }
-                        } else if shift {
-                            drv.select_left();
-                        } else {
-                            drv.move_left();
+    fn filter_input<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match &self.allowed_chars {
+            Some(allowed) if !text.chars().all(allowed) => {
+                Cow::Owned(text.chars().filter(|c| allowed(*c)).collect())
+            }
+            _ => Cow::Borrowed(text),
+        }
+    }
+
+    /// Submit `TextChanged` for an edit, or restart the debounce timer if one is set.
+    fn text_changed(&mut self, ctx: &mut EventCtx) {
+        if self.change_debounce.is_some() {
+            if self.pending_change.is_none() {
+                ctx.request_anim_frame();
+            }
+            self.pending_change = Some(Duration::ZERO);
+        } else {
+            let text = self.text().into_iter().collect();
+            ctx.submit_action(crate::core::Action::TextChanged(text));
+        }
+    }
+
+    /// The latest text, if there is a debounced `TextChanged` which hasn't been submitted yet.
+    fn take_pending_change(&mut self) -> Option<String> {
+        self.pending_change
+            .take()
+            .map(|_| self.text().into_iter().collect())
+    }
+
+    /// Replace the selection with `text`, after removing any characters which aren't allowed.
+    ///
+    /// If every character of `text` was removed, the selection is left as-is, so that typing
+    /// a disallowed character doesn't delete the selected text.
+    /// Returns whether the text was edited.
+    fn insert_filtered(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        text: &str,
+    ) -> bool {
+        let filtered = self.filter_input(text);
+        if filtered.is_empty() && !text.is_empty() {
+            return false;
+        }
+        self.editor
+            .driver(fctx, lctx)
+            .insert_or_replace_selection(&filtered);
+        true
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<const EDITABLE: bool> TextArea<EDITABLE> {
+    /// Set font styling for an active text area.
+    ///
+    /// Style properties set by this method include [text size](parley::StyleProperty::FontSize),
+    /// [font family](parley::StyleProperty::FontStack), [font weight](parley::StyleProperty::FontWeight),
+    /// and [variable font parameters](parley::StyleProperty::FontVariations).
+    /// The styles inserted here apply to the entire text; we currently do not
+    /// support inline rich text.
+    ///
+    /// Setting [`StyleProperty::Brush`](parley::StyleProperty::Brush) is not supported.
+    /// Use [`set_brush`](Self::set_brush) instead.
+    /// This is also not additive for [font stacks](parley::StyleProperty::FontStack), and
+    /// instead overwrites any previous font stack.
+    ///
+    /// This is the runtime equivalent of [`with_style`](Self::with_style).
+    #[track_caller]
+    pub fn insert_style(
+        this: &mut WidgetMut<'_, Self>,
+        property: impl Into<StyleProperty>,
+    ) -> Option<StyleProperty> {
+        let old = this.widget.insert_style_inner(property.into());
+        this.widget.fitted = None;
+
+        this.ctx.request_layout();
+        old
+    }
+
+    /// [Retain](std::vec::Vec::retain) only the styles for which `f` returns true.
+    ///
+    /// Styles which are removed return to Parley's default values.
+    /// In most cases, these are the defaults for this widget.
+    ///
+    /// Of note, behaviour is unspecified for unsetting the [`FontSize`](parley::StyleProperty::FontSize).
+    pub fn retain_styles(this: &mut WidgetMut<'_, Self>, f: impl FnMut(&StyleProperty) -> bool) {
+        this.widget.editor.edit_styles().retain(f);
+        this.widget.fitted = None;
+
+        this.ctx.request_layout();
+    }
+
+    /// Remove the style with the discriminant `property`.
+    ///
+    /// Styles which are removed return to Parley's default values.
+    /// In most cases, these are the defaults for this widget.
+    ///
+    /// To get the discriminant requires constructing a valid `StyleProperty` for the
+    /// the desired property and passing it to [`core::mem::discriminant`].
+    /// Getting this discriminant is usually possible in a `const` context.
+    ///
+    /// Of note, behaviour is unspecified for unsetting the [`FontSize`](parley::StyleProperty::FontSize).
+    pub fn remove_style(
+        this: &mut WidgetMut<'_, Self>,
+        property: Discriminant<StyleProperty>,
+    ) -> Option<StyleProperty> {
+        let old = this.widget.editor.edit_styles().remove(property);
+        this.widget.fitted = None;
+
+        this.ctx.request_layout();
+        old
+    }
+
+    /// Set the font families used for this text area, in order of preference.
+    ///
+    /// See [`with_font_fallbacks`](Self::with_font_fallbacks) for how these are used.
+    pub fn set_font_fallbacks(this: &mut WidgetMut<'_, Self>, families: Vec<FontFamily<'static>>) {
+        Self::insert_style(this, FontStack::List(Cow::Owned(families)));
+    }
+
+    /// Set the height of each line of this text area, as a multiple of the font size.
+    ///
+    /// `None` restores the default of 1.2 times the font size.
+    /// See [`with_line_height`](Self::with_line_height) for more details.
+    pub fn set_line_height(this: &mut WidgetMut<'_, Self>, line_height: Option<f32>) {
+        Self::insert_style(
+            this,
+            StyleProperty::LineHeight(line_height.unwrap_or(DEFAULT_LINE_HEIGHT)),
+        );
+    }
+
+    /// Set the extra space added after each character of this text area, in logical pixels.
+    ///
+    /// See [`with_letter_spacing`](Self::with_letter_spacing) for more details.
+    pub fn set_letter_spacing(this: &mut WidgetMut<'_, Self>, letter_spacing: f32) {
+        Self::insert_style(this, StyleProperty::LetterSpacing(letter_spacing));
+    }
+
+    /// Set the text displayed in this widget.
+    ///
+    /// This is likely to be disruptive if the user is focused on this widget,
+    /// as it does not retain selections, and may cause undesirable interactions with IME.
+    pub fn reset_text(this: &mut WidgetMut<'_, Self>, new_text: &str) {
+        // If the IME is currently composing, we need to clear the compose first. This is quite
+        // disruptive, but we've warned about that. The platform's state is not reset, and the
+        // preedit will show up again when the platform updates it.
+        if this.widget.editor.is_composing() {
+            let (fctx, lctx) = this.ctx.text_contexts();
+            this.widget.editor.driver(fctx, lctx).clear_compose();
+        }
+        this.widget.editor.set_text(new_text);
+        // Other edits go through the editor's driver, which changes its generation straight away.
+        this.widget.text_stats.set(None);
+        // The app has replaced the user's edits, so there's no change left to report.
+        this.widget.pending_change = None;
+        if !this.widget.spans.is_empty()
+            || !this.widget.decorations.is_empty()
+            || !this.widget.highlights.is_empty()
+        {
+            // The ranges are kept in place, rather than being moved as though the text was edited.
+            this.widget.spans_text = new_text.to_string();
+        }
+
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget.editor.driver(fctx, lctx).move_to_text_end();
+        // The app knows where the caret is after resetting the text, so we don't report it.
+        this.widget.reported_selection = this.widget.editor.raw_selection().text_range();
+
+        this.ctx.request_layout();
+    }
+
+    /// Control [word wrapping](https://en.wikipedia.org/wiki/Line_wrap_and_word_wrap) for the text area.
+    ///
+    /// When enabled, the text will be laid out to fit within the available width.
+    /// If word wrapping is disabled, the text will likely flow past the available area.
+    /// Note that parent widgets will often clip this, so the overflow will not be visible.
+    ///
+    /// This widget does not currently support scrolling to the cursor,
+    /// so it is recommended to leave word wrapping enabled.
+    ///
+    /// The runtime equivalent of [`with_word_wrap`](Self::with_word_wrap).
+    pub fn set_word_wrap(this: &mut WidgetMut<'_, Self>, wrap_words: bool) {
+        this.widget.word_wrap = wrap_words;
+        let width = if this.widget.wraps_to_width() {
+            this.widget.last_available_width
+        } else {
+            None
+        };
+        this.widget.editor.set_width(width);
+        this.ctx.request_layout();
+    }
+
+    /// Set whether the user can select text by dragging whilst this text area is disabled.
+    ///
+    /// The runtime equivalent of [`with_selectable_when_disabled`](Self::with_selectable_when_disabled).
+    pub fn set_selectable_when_disabled(this: &mut WidgetMut<'_, Self>, selectable: bool) {
+        this.widget.selectable_when_disabled = selectable;
+        if !selectable {
+            this.widget.disabled_drag = false;
+        }
+    }
+
+    /// Shrink the font size so that the text fits on one line, between the given sizes.
+    ///
+    /// If this is `None`, the text is no longer resized, and keeps the font size chosen
+    /// most recently.
+    ///
+    /// The runtime equivalent of [`with_auto_fit`](Self::with_auto_fit).
+    pub fn set_auto_fit(this: &mut WidgetMut<'_, Self>, auto_fit: Option<(f32, f32)>) {
+        this.widget.auto_fit = auto_fit;
+        this.widget.fitted = None;
+        let width = if this.widget.wraps_to_width() {
+            this.widget.last_available_width
+        } else {
+            None
+        };
+        this.widget.editor.set_width(width);
+        this.ctx.request_layout();
+    }
+
+    /// Set the [alignment](https://en.wikipedia.org/wiki/Typographic_alignment) of the text.
+    ///
+    /// Text alignment might have unexpected results when the text area has no horizontal constraints.
+    /// See [`with_alignment`](Self::with_alignment) for how justified text is wrapped.
+    ///
+    /// The runtime equivalent of [`with_alignment`](Self::with_alignment).
+    pub fn set_alignment(this: &mut WidgetMut<'_, Self>, alignment: Alignment) {
+        this.widget.alignment = alignment;
+        let layout_alignment = this
+            .widget
+            .layout_alignment(this.widget.last_available_width);
+        this.widget.editor.set_alignment(layout_alignment);
+        // Justifying the text can change whether it is wrapped.
+        let width = if this.widget.wraps_to_width() {
+            this.widget.last_available_width
+        } else {
+            None
+        };
+        this.widget.editor.set_width(width);
+
+        this.ctx.request_layout();
+    }
+
+    /// Set the base direction of the text.
+    ///
+    /// The runtime equivalent of [`with_base_direction`](Self::with_base_direction).
+    pub fn set_base_direction(this: &mut WidgetMut<'_, Self>, direction: Direction) {
+        this.widget.base_direction = direction;
+        let layout_alignment = this
+            .widget
+            .layout_alignment(this.widget.last_available_width);
+        this.widget.editor.set_alignment(layout_alignment);
+        this.ctx.request_layout();
+    }
+
+    /// Replace the styles applied to byte ranges of the text.
+    ///
+    /// The runtime equivalent of [`with_spans`](Self::with_spans).
+    pub fn set_spans(
+        this: &mut WidgetMut<'_, Self>,
+        spans: impl Into<Vec<(Range<usize>, StyleAttribute)>>,
+    ) {
+        this.widget.move_spans();
+        this.widget.spans = spans.into();
+        this.ctx.request_layout();
+    }
+
+    /// Replace the lines drawn under byte ranges of the text.
+    ///
+    /// The runtime equivalent of [`with_decorations`](Self::with_decorations).
+    pub fn set_decorations(
+        this: &mut WidgetMut<'_, Self>,
+        decorations: impl Into<Vec<TextDecoration>>,
+    ) {
+        this.widget.move_spans();
+        this.widget.decorations = decorations.into();
+        this.ctx.request_paint_only();
+    }
+
+    /// Replace the ranges of the text which have a background filled in behind them.
+    ///
+    /// The runtime equivalent of [`with_highlights`](Self::with_highlights).
+    pub fn set_highlights(
+        this: &mut WidgetMut<'_, Self>,
+        ranges: impl Into<Vec<Range<usize>>>,
+        style: HighlightStyle,
+    ) {
+        this.widget.move_spans();
+        this.widget.highlights = ranges.into();
+        this.widget.highlight_style = style;
+        Self::request_highlight_paint(this);
+    }
+
+    /// Remove all of the highlights, undoing [`set_highlights`](Self::set_highlights).
+    pub fn clear_highlights(this: &mut WidgetMut<'_, Self>) {
+        if this.widget.highlights.is_empty() {
+            return;
+        }
+        this.widget.highlights.clear();
+        Self::request_highlight_paint(this);
+    }
+
+    /// Request that changed highlights are painted.
+    ///
+    /// The highlights don't affect layout, so this only repaints, unless the text has
+    /// changed since it was laid out, in which case the layout pass will paint them.
+    fn request_highlight_paint(this: &mut WidgetMut<'_, Self>) {
+        if this.widget.editor.try_layout().is_some() {
+            this.ctx.request_paint_only();
+        } else {
+            this.ctx.request_layout();
+        }
+    }
+
+    /// Set whether http and https URLs in the text are links.
+    ///
+    /// The runtime equivalent of [`with_autolink`](Self::with_autolink).
+    pub fn set_autolink(this: &mut WidgetMut<'_, Self>, autolink: bool) {
+        this.widget.autolink = autolink;
+        this.widget.pressed_link = None;
+        this.ctx.request_layout();
+    }
+
+    #[doc(alias = "set_color")]
+    /// Set the brush used to paint the text in this text area.
+    ///
+    /// In most cases, this will be the text's color, but gradients and images are also supported.
+    ///
+    /// The runtime equivalent of [`with_brush`](Self::with_brush).
+    pub fn set_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Brush>) {
+        this.widget.brush = Some(brush.into());
+
+        // We need to repaint unless the disabled brush is currently being used.
+        if this.widget.disabled_brush.is_none() || !this.ctx.is_disabled() {
+            this.ctx.request_paint_only();
+        }
+    }
+
+    /// Paint the text with the [theme's text color](crate::theme::Theme::text_color) again,
+    /// undoing [`set_brush`](Self::set_brush).
+    pub fn clear_brush(this: &mut WidgetMut<'_, Self>) {
+        this.widget.brush = None;
+
+        // We need to repaint unless the disabled brush is currently being used.
+        if this.widget.disabled_brush.is_none() || !this.ctx.is_disabled() {
+            this.ctx.request_paint_only();
+        }
+    }
+
+    /// Set the brush used to paint this text area whilst it is disabled.
+    ///
+    /// If this is `None`, the [normal brush](Self::set_brush) will be used.
+    ///
+    /// The runtime equivalent of [`with_disabled_brush`](Self::with_disabled_brush).
+    pub fn set_disabled_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Option<Brush>>) {
+        let brush = brush.into();
+        this.widget.disabled_brush = brush;
+
+        if this.ctx.is_disabled() {
+            this.ctx.request_paint_only();
+        }
+    }
+
+    /// Set the brush used to fill the selected text's background whilst this text area is focused.
+    ///
+    /// The runtime equivalent of [`with_selection_brush`](Self::with_selection_brush).
+    #[doc(alias = "set_selection_color")]
+    pub fn set_selection_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Brush>) {
+        this.widget.selection_brush = brush.into();
+        this.ctx.request_paint_only();
+    }
+
+    /// Set the brush used to fill the selected text's background whilst this text area isn't focused.
+    ///
+    /// The runtime equivalent of [`with_inactive_selection_brush`](Self::with_inactive_selection_brush).
+    #[doc(alias = "set_inactive_selection_color")]
+    pub fn set_inactive_selection_brush(
+        this: &mut WidgetMut<'_, Self>,
+        brush: impl Into<Option<Brush>>,
+    ) {
+        this.widget.inactive_selection_brush = brush.into();
+        this.ctx.request_paint_only();
+    }
+
+    /// Set the brush used to draw the caret.
+    ///
+    /// The runtime equivalent of [`with_caret_brush`](Self::with_caret_brush).
+    #[doc(alias = "set_caret_color")]
+    pub fn set_caret_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Brush>) {
+        this.widget.caret_brush = Some(brush.into());
+        this.ctx.request_paint_only();
+    }
+
+    /// Set whether [hinting](https://en.wikipedia.org/wiki/Font_hinting) will be used for this text area.
+    ///
+    /// The runtime equivalent of [`with_hint`](Self::with_hint).
+    /// For full documentation, see that method.
+    pub fn set_hint(this: &mut WidgetMut<'_, Self>, hint: bool) {
+        this.widget.hint = hint;
+        this.ctx.request_paint_only();
+    }
+
+    /// Set the padding around the text.
+    ///
+    /// This is the area outside the tight bound on the text where pointer events will be detected.
+    ///
+    /// The runtime equivalent of [`with_padding`](Self::with_padding).
+    pub fn set_padding(this: &mut WidgetMut<'_, Self>, padding: impl Into<Padding>) {
+        this.widget.padding = padding.into();
+        // TODO: We could reset the width available to the editor here directly.
+        // Determine whether there's any advantage to that
+        this.ctx.request_layout();
+    }
+
+    /// Set whether to show line numbers in a gutter before the text.
+    ///
+    /// The runtime equivalent of [`with_line_numbers`](Self::with_line_numbers).
+    pub fn set_line_numbers(this: &mut WidgetMut<'_, Self>, line_numbers: bool) {
+        this.widget.line_numbers = line_numbers;
+        this.ctx.request_layout();
+    }
+
+    /// Set which lines are numbered when line numbers are shown.
+    ///
+    /// The runtime equivalent of [`with_line_numbering`](Self::with_line_numbering).
+    pub fn set_line_numbering(this: &mut WidgetMut<'_, Self>, line_numbering: LineNumbering) {
+        this.widget.line_numbering = line_numbering;
+        this.ctx.request_layout();
+    }
+
+    /// Set the brush used to paint the line numbers.
+    ///
+    /// The runtime equivalent of [`with_gutter_brush`](Self::with_gutter_brush).
+    pub fn set_gutter_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Brush>) {
+        this.widget.gutter_brush = Some(brush.into());
+        this.ctx.request_paint_only();
+    }
+
+    /// Set what happens when the Tab key is pressed in this text area.
+    ///
+    /// The runtime equivalent of [`with_tab_action`](Self::with_tab_action).
+    pub fn set_tab_action(this: &mut WidgetMut<'_, Self>, tab_action: TabAction) {
+        this.widget.tab_action = tab_action;
+    }
+
+    /// Set what is scrolled into view after the text is edited.
+    ///
+    /// The runtime equivalent of [`with_scroll_anchor`](Self::with_scroll_anchor).
+    pub fn set_scroll_anchor(this: &mut WidgetMut<'_, Self>, scroll_anchor: ScrollAnchor) {
+        this.widget.scroll_anchor = scroll_anchor;
+    }
+
+    /// Set whether this text area is in overtype mode.
+    ///
+    /// The runtime equivalent of [`with_overtype`](Self::with_overtype).
+    pub fn set_overtype(this: &mut WidgetMut<'_, Self>, overtype: bool) {
+        if this.widget.overtype != overtype {
+            this.widget.overtype = overtype;
+            // The caret's shape depends on the mode.
+            this.ctx.request_render();
+        }
+    }
+
+    /// Set whether the user can switch overtype mode on and off by pressing Insert.
+    ///
+    /// The runtime equivalent of [`with_overtype_toggle`](Self::with_overtype_toggle).
+    pub fn set_overtype_toggle(this: &mut WidgetMut<'_, Self>, allow_overtype_toggle: bool) {
+        this.widget.allow_overtype_toggle = allow_overtype_toggle;
+    }
+
+    /// Only allow the user to insert characters for which `allowed` returns true.
+    ///
+    /// The existing text is not changed.
+    ///
+    /// The runtime equivalent of [`with_allowed_chars`](Self::with_allowed_chars).
+    pub fn set_allowed_chars(
+        this: &mut WidgetMut<'_, Self>,
+        allowed: impl Fn(char) -> bool + 'static,
+    ) {
+        this.widget.allowed_chars = Some(Box::new(allowed));
+    }
+
+    /// Allow the user to insert any character, undoing [`set_allowed_chars`](Self::set_allowed_chars).
+    pub fn clear_allowed_chars(this: &mut WidgetMut<'_, Self>) {
+        this.widget.allowed_chars = None;
+    }
+
+    /// Set how long the user must stop editing for before `TextChanged` is submitted.
+    ///
+    /// If debouncing is turned off whilst a change is pending, it is submitted straight away.
+    ///
+    /// The runtime equivalent of [`with_change_debounce`](Self::with_change_debounce).
+    pub fn set_change_debounce(
+        this: &mut WidgetMut<'_, Self>,
+        debounce: impl Into<Option<Duration>>,
+    ) {
+        this.widget.change_debounce = debounce.into();
+        if this.widget.change_debounce.is_none() && this.widget.pending_change.take().is_some() {
+            let text = this.widget.text().into_iter().collect();
+            this.ctx
+                .submit_action(crate::core::Action::TextChanged(text));
+        }
+    }
+
+    /// Set the selection to the given byte range.
+    ///
+    /// No-op if either index is not a char boundary.
+    /// Indices inside a grapheme cluster are moved back to its start, so that the selection
+    /// never splits a user-perceived character.
+    ///
+    /// If this text area is editable and the selection changes, this emits a
+    /// `TextSelectionChanged` action marked as programmatic.
+    pub fn select_byte_range(this: &mut WidgetMut<'_, Self>, start: usize, end: usize) {
+        let start = this.widget.grapheme_floor(start);
+        let end = this.widget.grapheme_floor(end);
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget
+            .editor
+            .driver(fctx, lctx)
+            .select_byte_range(start, end);
+        Self::report_programmatic_selection(this);
+        this.ctx.request_render();
+    }
+
+    /// Replace the selection with `text`, as though the user had typed it.
+    ///
+    /// Characters which aren't [allowed](Self::set_allowed_chars) are removed first.
+    /// The caret is left at the end of the inserted text.
+    ///
+    /// Like an edit by the user, this submits `TextChanged` (after the
+    /// [debounce](Self::set_change_debounce), if set), and `TextSelectionChanged`
+    /// marked as programmatic.
+    /// Returns whether the text was edited.
+    pub fn insert_at_cursor(this: &mut WidgetMut<'_, Self>, text: &str) -> bool {
+        let edit_start = this.widget.editor.raw_selection().text_range().start;
+        let (fctx, lctx) = this.ctx.text_contexts();
+        // As in `reset_text`, this discards any preedit text.
+        this.widget.editor.driver(fctx, lctx).clear_compose();
+        let edited = this.widget.insert_filtered(fctx, lctx, text);
+        if edited {
+            Self::finish_programmatic_edit(this, edit_start);
+        }
+        edited
+    }
+
+    /// Delete the selected text, as though the user had pressed Backspace.
+    ///
+    /// This reports the edit in the same way as [`insert_at_cursor`](Self::insert_at_cursor).
+    /// Returns the deleted text, which is empty if nothing was selected.
+    pub fn delete_selection(this: &mut WidgetMut<'_, Self>) -> String {
+        let deleted = this
+            .widget
+            .editor
+            .selected_text()
+            .unwrap_or_default()
+            .to_string();
+        if deleted.is_empty() {
+            return deleted;
+        }
+        let edit_start = this.widget.editor.raw_selection().text_range().start;
+        let (fctx, lctx) = this.ctx.text_contexts();
+        let mut driver = this.widget.editor.driver(fctx, lctx);
+        driver.clear_compose();
+        driver.delete_selection();
+        Self::finish_programmatic_edit(this, edit_start);
+        deleted
+    }
+
+    /// Delete all of the text, as though the user had selected it all and pressed Backspace.
+    ///
+    /// This reports the edit in the same way as [`insert_at_cursor`](Self::insert_at_cursor).
+    /// Returns whether there was any text to delete.
+    pub fn clear(this: &mut WidgetMut<'_, Self>) -> bool {
+        let len = this.widget.text().into_iter().map(str::len).sum();
+        Self::select_byte_range(this, 0, len);
+        !Self::delete_selection(this).is_empty()
+    }
+
+    /// Report an edit made through `WidgetMut`, which started at byte `edit_start`,
+    /// in the same way as one made by the user.
+    fn finish_programmatic_edit(this: &mut WidgetMut<'_, Self>, edit_start: usize) {
+        if this.widget.change_debounce.is_some() {
+            if this.widget.pending_change.is_none() {
+                this.ctx.request_anim_frame();
+            }
+            this.widget.pending_change = Some(Duration::ZERO);
+        } else {
+            let text = this.widget.text().into_iter().collect();
+            this.ctx
+                .submit_action(crate::core::Action::TextChanged(text));
+        }
+        Self::report_programmatic_selection(this);
+        let (fctx, lctx) = this.ctx.text_contexts();
+        match this.widget.edit_scroll_target(fctx, lctx, edit_start) {
+            Some(rect) => this.ctx.request_scroll_to(rect),
+            None => this.ctx.request_scroll_to_this(),
+        }
+        this.ctx.request_layout();
+    }
+
+    /// Submit `TextSelectionChanged` if the selection was moved through `WidgetMut`.
+    fn report_programmatic_selection(this: &mut WidgetMut<'_, Self>) {
+        let selection = this.widget.editor.raw_selection().text_range();
+        if EDITABLE && selection != this.widget.reported_selection {
+            this.widget.reported_selection = selection.clone();
+            this.ctx
+                .submit_action(crate::core::Action::TextSelectionChanged(SelectionChange {
+                    start: selection.start,
+                    end: selection.end,
+                    programmatic: true,
+                }));
+        }
+        // The caret moved, so the platform's IME candidate window should follow it.
+        let ime_area = this.widget.ime_area();
+        this.ctx.set_ime_area(ime_area);
+    }
+
+    /// Set the selection to the first instance of the given text.
+    ///
+    /// This is mostly useful for testing.
+    ///
+    /// No-op if the text isn't found.
+    pub fn select_text(this: &mut WidgetMut<'_, Self>, text: &str) {
+        let Some(start) = this.widget.text().to_string().find(text) else {
+            return;
+        };
+        let end = start + text.len();
+        Self::select_byte_range(this, start, end);
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if self.editor.is_composing() {
+            return;
+        }
+
+        let (fctx, lctx) = ctx.text_contexts();
+        let text_is_rtl = self.editor.layout(fctx, lctx).is_rtl();
+        let is_rtl = self.is_rtl(text_is_rtl);
+        let text_padding = self.text_padding();
+        let padding = Vec2::new(text_padding.get_left(is_rtl), text_padding.top);
+        match event {
+            PointerEvent::PointerDown(button, _) => {
+                if *button == PointerButton::Primary && !ctx.is_disabled() {
+                    let pos = event.local_position(ctx);
+                    self.pressed_link = self.link_at(pos).map(|link| (link, pos));
+                }
+                let can_select = !ctx.is_disabled() || self.selectable_when_disabled;
+                let in_gutter =
+                    self.gutter_contains(event.local_position(ctx), is_rtl, ctx.size().width);
+                if can_select && *button == PointerButton::Primary && !in_gutter {
+                    let now = Instant::now();
+                    let cursor_pos = event.local_position(ctx) - padding;
+                    let is_repeat = match (self.last_click_time, self.last_click_pos) {
+                        (Some(time), Some(pos)) => {
+                            now.duration_since(time) < MULTI_CLICK_TIME
+                                && pos.distance(cursor_pos) <= MULTI_CLICK_DISTANCE
                         }
+                        _ => false,
+                    };
+                    // Clicks cycle between caret placement, word selection and line selection.
+                    self.click_count = if is_repeat {
+                        self.click_count % 3 + 1
+                    } else {
+                        1
+                    };
+                    self.last_click_time = Some(now);
+                    self.last_click_pos = Some(cursor_pos);
+
+                    let (fctx, lctx) = ctx.text_contexts();
+                    self.granular_anchor = self.granular_range_at(fctx, lctx, cursor_pos);
+                    let mut drv = self.editor.driver(fctx, lctx);
+                    match &self.granular_anchor {
+                        Some(range) => drv.select_byte_range(range.start, range.end),
+                        None => drv.move_to_point(cursor_pos.x as f32, cursor_pos.y as f32),
                     }
-                    Key::Named(NamedKey::ArrowRight) => {
-                        let mut drv = self.editor.driver(fctx, lctx);
-                        if action_mod {
-                            if shift {
-                                drv.select_word_right();
+                    let new_generation = self.editor.generation();
+                    if new_generation != self.rendered_generation {
+                        ctx.request_render();
+                        ctx.set_ime_area(self.ime_area());
+                        self.rendered_generation = new_generation;
+                    }
+                    if ctx.is_disabled() {
+                        // Disabled widgets can't be focused, nor capture the pointer.
+                        self.disabled_drag = true;
+                    } else {
+                        ctx.request_focus();
+                        ctx.capture_pointer();
+                    }
+                    self.report_selection(ctx);
+                }
+            }
+            PointerEvent::PointerUp(_, _) => {
+                self.disabled_drag = false;
+                if let Some((link, _)) = self.pressed_link.take() {
+                    // The link is only followed if the press and release are on the same link.
+                    if self.link_at(event.local_position(ctx)).as_ref() == Some(&link) {
+                        let url = self.spans_text[link].to_string();
+                        ctx.submit_action(crate::core::Action::LinkClicked(url));
+                    }
+                }
+                // Changes whilst dragging are reported once the drag finishes.
+                self.report_selection(ctx);
+            }
+            PointerEvent::PointerLeave(_) => {
+                self.disabled_drag = false;
+            }
+            PointerEvent::PointerMove(state) => {
+                if let Some((_, pressed_at)) = &self.pressed_link {
+                    // Dragging from a link selects text, rather than following the link.
+                    if pressed_at.distance(event.local_position(ctx)) > LINK_DRAG_DISTANCE {
+                        self.pressed_link = None;
+                    }
+                }
+                let is_dragging = if ctx.is_disabled() {
+                    // Without pointer capture, we don't see the button being released
+                    // if the pointer has moved outside of this widget.
+                    self.disabled_drag &= state.buttons.contains(PointerButton::Primary);
+                    self.disabled_drag
+                } else {
+                    ctx.is_pointer_capture_target()
+                };
+                if is_dragging {
+                    let cursor_pos = event.local_position(ctx) - padding;
+                    let (fctx, lctx) = ctx.text_contexts();
+                    let target = self.granular_range_at(fctx, lctx, cursor_pos);
+                    let mut drv = self.editor.driver(fctx, lctx);
+                    match (&self.granular_anchor, target) {
+                        (Some(anchor), Some(target)) => {
+                            // The anchor stays selected, and the focus moves to the far end of the target.
+                            if target.start < anchor.start {
+                                drv.select_byte_range(anchor.end, target.start);
                             } else {
-                                drv.move_word_right();
+                                drv.select_byte_range(anchor.start, target.end.max(anchor.end));
                             }
-                        } else if shift {
-                            drv.select_right();
-                        } else {
-                            drv.move_right();
                         }
+                        _ => {
+                            drv.extend_selection_to_point(cursor_pos.x as f32, cursor_pos.y as f32);
+                        }
+                    }
+                    let new_generation = self.editor.generation();
+                    if new_generation != self.rendered_generation {
+                        ctx.request_render();
+                        ctx.set_ime_area(self.ime_area());
+                        self.rendered_generation = new_generation;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        match event {
+            TextEvent::KeyboardKey(key_event, modifiers_state) => {
+                if !key_event.state.is_pressed() || self.editor.is_composing() {
+                    return;
+                }
+                #[allow(unused)]
+                let (shift, action_mod) = (
+                    modifiers_state.shift_key(),
+                    if cfg!(target_os = "macos") {
+                        modifiers_state.super_key()
+                    } else {
+                        modifiers_state.control_key()
+                    },
+                );
+                let edit_start = self.editor.raw_selection().text_range().start;
+                let (fctx, lctx) = ctx.text_contexts();
+                // Whether the text was changed.
+                let mut edited = false;
+                // Ideally we'd use key_without_modifiers, but that's broken
+                match &key_event.logical_key {
+                    // Cut
+                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+                    Key::Character(x)
+                        if EDITABLE && action_mod && x.as_str().eq_ignore_ascii_case("x") =>
+                    {
+                        edited = true;
+                        // TODO: use clipboard_rs::{Clipboard, ClipboardContext};
+                        // if let Some(text) = self.editor.selected_text() {
+                        //     let cb = ClipboardContext::new().unwrap();
+                        //     cb.set_text(text.to_owned()).ok();
+                        //     self.editor.drive(fcx, lcx, |drv| drv.delete_selection());
+                        // }
+                        // edited = true;
+                    }
+                    // Copy
+                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+                    Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("c") => {
+                        // TODO: use clipboard_rs::{Clipboard, ClipboardContext};
+                        // if let Some(text) = self.editor.selected_text() {
+                        //     let cb = ClipboardContext::new().unwrap();
+                        //     cb.set_text(text.to_owned()).ok();
+                        // }
+                    }
+                    // Paste
+                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+                    Key::Character(v)
+                        if EDITABLE && action_mod && v.as_str().eq_ignore_ascii_case("v") =>
+                    {
+                        edited = true;
+                        // TODO: use clipboard_rs::{Clipboard, ClipboardContext};
+                        // let cb = ClipboardContext::new().unwrap();
+                        // let text = cb.get_text().unwrap_or_default();
+                        // self.editor.drive(fcx, lcx, |drv| drv.insert_or_replace_selection(&text));
+                        // edited = true;
                     }
-                    Key::Named(NamedKey::ArrowUp) => {
+                    Key::Character(a) if action_mod && a.as_str().eq_ignore_ascii_case("a") => {
                         let mut drv = self.editor.driver(fctx, lctx);
+
                         if shift {
-                            drv.select_up();
+                            drv.collapse_selection();
                         } else {
-                            drv.move_up();
+                            drv.select_all();
                         }
                     }
-                    Key::Named(NamedKey::ArrowDown) => {
+                    Key::Named(
+                        key @ (NamedKey::ArrowLeft
+                        | NamedKey::ArrowRight
+                        | NamedKey::ArrowUp
+                        | NamedKey::ArrowDown
+                        | NamedKey::Home
+                        | NamedKey::End),
+                    ) => {
+                        self.navigate(fctx, lctx, *key, *modifiers_state);
+                    }
+                    Key::Named(NamedKey::Delete) if EDITABLE => {
                         let mut drv = self.editor.driver(fctx, lctx);
-                        if shift {
-                            drv.select_down();
+                        if action_mod {
+                            drv.delete_word();
                         } else {
-                            drv.move_down();
+                            drv.delete();
                         }
+
+                        edited = true;
                     }
-                    Key::Named(NamedKey::Home) => {
+                    Key::Named(NamedKey::Backspace) if EDITABLE => {
                         let mut drv = self.editor.driver(fctx, lctx);
                         if action_mod {
-                            if shift {
-                                drv.select_to_text_start();
-                            } else {
-                                drv.move_to_text_start();
+                            drv.backdelete_word();
+                        } else {
+                            drv.backdelete();
+                        }
+
+                        edited = true;
+                    }
+                    Key::Named(NamedKey::Space) if EDITABLE => {
+                        edited = self.type_filtered(fctx, lctx, " ");
+                    }
+                    Key::Named(NamedKey::Insert) if EDITABLE && self.allow_overtype_toggle => {
+                        self.overtype = !self.overtype;
+                        // The caret's shape depends on the mode.
+                        ctx.request_render();
+                    }
+                    Key::Named(NamedKey::Enter) => {
+                        // TODO: Multiline?
+                        let multiline = false;
+                        if multiline {
+                            let (fctx, lctx) = ctx.text_contexts();
+                            edited = self.insert_filtered(fctx, lctx, "\n");
+                        } else {
+                            if let Some(text) = self.take_pending_change() {
+                                ctx.submit_action(crate::core::Action::TextChanged(text));
                             }
-                        } else if shift {
-                            drv.select_to_line_start();
+                            ctx.submit_action(crate::core::Action::TextEntered(
+                                self.text().to_string(),
+                            ));
+                        }
+                    }
+
+                    Key::Named(NamedKey::Tab) if EDITABLE && shift => {
+                        let tab_action = self.tab_action;
+                        if tab_action == TabAction::MoveFocus {
+                            // Let the framework move focus backwards.
+                            return;
+                        }
+                        let text = self.text().to_string();
+                        let selection = self.editor.raw_selection();
+                        let (anchor, focus) =
+                            (selection.anchor().index(), selection.focus().index());
+                        if let Some(range) = tab_action.dedent_range(&text, focus) {
+                            // Keep the selection on the same text, clamping it to the start of the line
+                            let adjust = |idx: usize| {
+                                if idx <= range.start {
+                                    idx
+                                } else {
+                                    idx.saturating_sub(range.len()).max(range.start)
+                                }
+                            };
+                            let (anchor, focus) = (adjust(anchor), adjust(focus));
+                            let mut drv = self.editor.driver(fctx, lctx);
+                            drv.select_byte_range(range.start, range.end);
+                            drv.delete_selection();
+                            drv.select_byte_range(anchor, focus);
+                            edited = true;
+                        }
+                    }
+                    Key::Named(NamedKey::Tab) if EDITABLE => {
+                        let Some(indent) = self.tab_action.indent() else {
+                            // Let the framework move focus forwards.
+                            return;
+                        };
+                        edited = self.insert_filtered(fctx, lctx, &indent);
+                    }
+                    Key::Named(NamedKey::Tab) => {
+                        // Intentionally do nothing so that tabbing from a textbox/Prose works.
+                        // Editable text areas can insert tabs instead, see `TabAction`.
+                        return;
+                    }
+                    // Leave other shortcuts, such as Ctrl+S, to hotkeys.
+                    // Ctrl+Alt is how AltGr is reported on Windows, so that still types text.
+                    _ if (modifiers_state.control_key() || modifiers_state.super_key())
+                        && !modifiers_state.alt_key() =>
+                    {
+                        return;
+                    }
+                    _ if EDITABLE => match &key_event.text {
+                        Some(text) => {
+                            edited = self.type_filtered(fctx, lctx, text);
+                        }
+                        None => {
+                            // Do nothing, don't set as handled.
+                            return;
+                        }
+                    },
+                    _ => {
+                        // Do nothing, don't set as handled.
+                        return;
+                    }
+                }
+                ctx.set_handled();
+                let new_generation = self.editor.generation();
+                if new_generation != self.rendered_generation {
+                    if edited {
+                        self.text_changed(ctx);
+                        ctx.request_layout();
+                    } else {
+                        ctx.request_render();
+                        ctx.set_ime_area(self.ime_area());
+                    }
+                    if edited {
+                        self.scroll_after_edit(ctx, edit_start);
+                    } else {
+                        self.scroll_to_caret(ctx);
+                    }
+                    self.rendered_generation = new_generation;
+                }
+                self.report_selection(ctx);
+            }
+            // TODO: Set our highlighting colour to a lighter blue as window unfocused
+            TextEvent::WindowFocusChange(_) => {}
+            TextEvent::Ime(e) => {
+                // TODO: Handle the cursor movement things from https://github.com/rust-windowing/winit/pull/3824
+                let edit_start = self.editor.raw_selection().text_range().start;
+                let (fctx, lctx) = ctx.text_contexts();
+
+                // Whether the returned text has changed.
+                // We don't send a TextChanged when the preedit changes
+                let mut edited = false;
+                match e {
+                    winit::event::Ime::Disabled => {
+                        self.editor.driver(fctx, lctx).clear_compose();
+                    }
+                    winit::event::Ime::Preedit(text, cursor) => {
+                        if text.is_empty() {
+                            self.editor.driver(fctx, lctx).clear_compose();
                         } else {
-                            drv.move_to_line_start();
+                            self.editor.driver(fctx, lctx).set_compose(text, *cursor);
+                            edited = true;
                         }
                     }
-                    Key::Named(NamedKey::End) => {
-                        let mut drv = self.editor.driver(fctx, lctx);
-                        if action_mod {
-                            if shift {
-                                drv.select_to_text_end();
-                            } else {
-                                drv.move_to_text_end();
-                            }
-                        } else if shift {
-                            drv.select_to_line_end();
-                        } else {
-                            drv.move_to_line_end();
-                        }
+                    winit::event::Ime::Commit(text) => {
+                        edited = self.insert_filtered(fctx, lctx, text);
+                    }
+                    winit::event::Ime::Enabled => {}
+                }
+
+                ctx.set_handled();
+                if edited {
+                    self.text_changed(ctx);
+                }
+
+                let new_generation = self.editor.generation();
+                if new_generation != self.rendered_generation {
+                    ctx.request_layout();
+                    if edited {
+                        self.scroll_after_edit(ctx, edit_start);
+                    } else {
+                        self.scroll_to_caret(ctx);
+                    }
+                    self.rendered_generation = new_generation;
+                }
+                self.report_selection(ctx);
+            }
+            TextEvent::ModifierChange(_) => {}
+            TextEvent::Hotkey(_) => {}
+        }
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn accepts_text_input(&self) -> bool {
+        EDITABLE
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        match event.action {
+            accesskit::Action::SetTextSelection => {
+                if self.editor.is_composing() {
+                    return;
+                }
+
+                if let Some(accesskit::ActionData::SetTextSelection(selection)) = &event.data {
+                    let (fctx, lctx) = ctx.text_contexts();
+                    self.editor
+                        .driver(fctx, lctx)
+                        .select_from_accesskit(selection);
+                    let new_generation = self.editor.generation();
+                    if new_generation != self.rendered_generation {
+                        ctx.request_render();
+                        ctx.set_ime_area(self.ime_area());
+                        self.rendered_generation = new_generation;
+                    }
+                    self.report_selection(ctx);
+                }
+            }
+            accesskit::Action::SetValue | accesskit::Action::ReplaceSelectedText => {
+                if !EDITABLE || ctx.is_disabled() {
+                    return;
+                }
+                let Some(accesskit::ActionData::Value(text)) = &event.data else {
+                    return;
+                };
+                let (fctx, lctx) = ctx.text_contexts();
+                let mut driver = self.editor.driver(fctx, lctx);
+                // As in `reset_text`, this discards any preedit text.
+                driver.clear_compose();
+                if event.action == accesskit::Action::SetValue {
+                    // Replace the whole text, even if none of the new value is allowed.
+                    driver.select_all();
+                    driver.delete_selection();
+                }
+                // This leaves the cursor at the end of the inserted text.
+                self.insert_filtered(fctx, lctx, text);
+
+                let new_generation = self.editor.generation();
+                if new_generation != self.rendered_generation {
+                    self.text_changed(ctx);
+                    ctx.request_layout();
+                    self.rendered_generation = new_generation;
+                }
+                self.report_selection(ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let (Some(elapsed), Some(debounce)) = (self.pending_change, self.change_debounce) else {
+            return;
+        };
+        let elapsed = elapsed + Duration::from_nanos(interval);
+        if elapsed >= debounce {
+            self.pending_change = None;
+            let text = self.text().into_iter().collect();
+            ctx.submit_action(crate::core::Action::TextChanged(text));
+        } else {
+            self.pending_change = Some(elapsed);
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::FocusChanged(focused) => {
+                if !focused {
+                    if let Some(text) = self.take_pending_change() {
+                        ctx.submit_action(crate::core::Action::TextChanged(text));
                     }
-                    Key::Named(NamedKey::Delete) if EDITABLE => {
-                        let mut drv = self.editor.driver(fctx, lctx);
-                        if action_mod {
-                            drv.delete_word();
-                        } else {
-                            drv.delete();
-                        }
+                }
+                ctx.request_render();
+            }
+            Update::DisabledChanged(_) => {
+                self.disabled_drag = false;
+                // We might need to use the disabled brush, and stop displaying the selection.
+                ctx.request_render();
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let text_size = if self.line_numbers {
+            let styles: Vec<StyleProperty> = self
+                .editor
+                .edit_styles()
+                .inner()
+                .values()
+                .cloned()
+                .collect();
+            // The gutter fits the digits of the last line number, but with visual numbering
+            // that depends on how the text wraps in the width left by the gutter, so this
+            // is repeated until the gutter is wide enough.
+            let mut digits = digit_count(self.text_stats().lines);
+            loop {
+                let (fctx, lctx) = ctx.text_contexts();
+                let widest = gutter_layout(fctx, lctx, &styles, &"0".repeat(digits));
+                self.gutter_width = f64::from(widest.full_width()) + GUTTER_GAP;
+                let text_size = self.layout_text(ctx, bc);
+                let needed = digit_count(self.numbered_line_count());
+                if needed <= digits {
+                    let (fctx, lctx) = ctx.text_contexts();
+                    self.gutter_numbers = self.line_number_layouts(fctx, lctx, &styles);
+                    break text_size;
+                }
+                digits = needed;
+            }
+        } else {
+            self.gutter_width = 0.0;
+            self.gutter_numbers.clear();
+            self.layout_text(ctx, bc)
+        };
+        ctx.set_ime_area(self.ime_area());
+
+        let padding = self.text_padding();
+        let area_size = Size {
+            height: text_size.height + padding.top + padding.bottom,
+            width: text_size.width + padding.leading + padding.trailing,
+        };
+        bc.constrain(area_size)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let layout = if let Some(layout) = self.editor.try_layout() {
+            layout
+        } else {
+            debug_panic!("Widget `layout` should have happened before paint");
+            let (fctx, lctx) = ctx.text_contexts();
+            // The `layout` method takes `&mut self`, so we get borrow-checker errors if we return it from this block.
+            self.editor.refresh_layout(fctx, lctx);
+            self.editor.try_layout().unwrap()
+        };
+        let layout = self.styled_layout.as_ref().unwrap_or(layout);
+        let is_rtl = self.is_rtl(layout.is_rtl());
+        let padding = self.text_padding();
+        let origin = Vec2::new(padding.get_left(is_rtl), padding.top);
+        let transform = Affine::translate(origin);
+        for range in &self.highlights {
+            for rect in highlight_rects(layout, range.clone()) {
+                let rect = rect.to_rounded_rect(self.highlight_style.corner_radius);
+                scene.fill(
+                    Fill::NonZero,
+                    transform,
+                    self.highlight_style.background,
+                    None,
+                    &rect,
+                );
+            }
+        }
+        let is_focused = ctx.is_focus_target();
+        // TODO: If window not focused, use the inactive brush
+        let selection_brush = if is_focused {
+            Some(&self.selection_brush)
+        } else {
+            self.inactive_selection_brush.as_ref()
+        };
+        if let Some(selection_brush) = selection_brush {
+            let selection = if self.styled_layout.is_some() {
+                self.editor.raw_selection().geometry(layout)
+            } else {
+                self.editor.selection_geometry()
+            };
+            for rect in selection.iter() {
+                scene.fill(Fill::NonZero, transform, selection_brush, None, &rect);
+            }
+        }
+        if is_focused {
+            let caret_brush = self
+                .caret_brush
+                .clone()
+                .unwrap_or_else(|| ctx.theme().caret_color.into());
+            let cursor = if self.styled_layout.is_some() {
+                Some(
+                    self.editor
+                        .raw_selection()
+                        .focus()
+                        .geometry(layout, CARET_WIDTH),
+                )
+            } else {
+                self.editor.cursor_geometry(CARET_WIDTH)
+            };
+            match (cursor, self.overtype_range()) {
+                // In overtype mode, a translucent block covers the grapheme which will be replaced.
+                (Some(cursor), Some(range)) => {
+                    let end = Cursor::from_byte_index(layout, range.end, Affinity::Upstream)
+                        .geometry(layout, 0.)
+                        .x0;
+                    let block =
+                        Rect::new(cursor.x0.min(end), cursor.y0, cursor.x0.max(end), cursor.y1);
+                    let brush = caret_brush.multiply_alpha(0.5);
+                    scene.fill(Fill::NonZero, transform, &brush, None, &block);
+                }
+                (Some(cursor), None) => {
+                    scene.fill(Fill::NonZero, transform, &caret_brush, None, &cursor);
+                }
+                (None, _) => {}
+            }
+        }
+
+        let spans = self.styled_spans();
+        let brushes: Vec<Brush> = match &self.disabled_brush {
+            // Spans don't change the color of disabled text.
+            Some(disabled_brush) if ctx.is_disabled() => {
+                vec![disabled_brush.clone(); 1 + span_brushes(&spans).count()]
+            }
+            _ => std::iter::once(
+                self.brush
+                    .clone()
+                    .unwrap_or_else(|| ctx.theme().text_color.into()),
+            )
+            .chain(span_brushes(&spans).cloned())
+            .collect(),
+        };
+        render_text(scene, transform, layout, &brushes, self.hint);
+
+        for decoration in &self.decorations {
+            for line in decoration_lines(layout, decoration.range.clone()) {
+                paint_decoration(scene, transform, decoration, line);
+            }
+        }
+
+        if !self.gutter_numbers.is_empty() {
+            let brush = self
+                .gutter_brush
+                .clone()
+                .unwrap_or_else(|| ctx.theme().disabled_text_color.into());
+            // The numbers are right-aligned, next to the gap before the text.
+            let right = if is_rtl {
+                ctx.size().width - self.padding.leading
+            } else {
+                self.padding.leading + self.gutter_width - GUTTER_GAP
+            };
+            for (top, number) in &self.gutter_numbers {
+                let x = right - f64::from(number.full_width());
+                let transform = Affine::translate((x, origin.y + top));
+                render_text(
+                    scene,
+                    transform,
+                    number,
+                    std::slice::from_ref(&brush),
+                    self.hint,
+                );
+            }
+        }
+    }
+
+    fn get_cursor(&self, ctx: &QueryCtx, pos: Point) -> CursorIcon {
+        let Some(layout) = self.current_layout() else {
+            // Before the text has been laid out, we can't tell what is under the pointer.
+            return CursorIcon::Text;
+        };
+        let local_pos = pos - ctx.window_origin().to_vec2();
+        let is_rtl = self.is_rtl(layout.is_rtl());
+        let size = ctx.size();
+        let padding = self.text_padding();
+        let text_rect = Rect::new(
+            padding.get_left(is_rtl),
+            padding.top,
+            size.width - padding.get_right(is_rtl),
+            size.height - padding.bottom,
+        );
+        if !text_rect.contains(local_pos) {
+            CursorIcon::Default
+        } else if !ctx.is_disabled() && self.link_at(local_pos).is_some() {
+            CursorIcon::Pointer
+        } else {
+            CursorIcon::Text
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        if EDITABLE {
+            Role::TextInput
+            // TODO: Role::MultilineTextInput
+        } else {
+            Role::Document
+        }
+    }
+
+    fn accessibility(&mut self, ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        if EDITABLE {
+            node.add_action(accesskit::Action::SetValue);
+            node.add_action(accesskit::Action::ReplaceSelectedText);
+        } else {
+            node.set_read_only();
+        }
+        let (fctx, lctx) = ctx.text_contexts();
+        let layout = self.editor.layout(fctx, lctx);
+        let is_rtl = self.is_rtl(layout.is_rtl());
+        let origin = ctx.window_origin();
+        let padding = self.text_padding();
+        self.editor
+            .try_accessibility(
+                ctx.tree_update,
+                node,
+                || NodeId::from(WidgetId::next()),
+                origin.x + padding.get_left(is_rtl),
+                origin.y + padding.top,
+            )
+            .expect("We just performed a layout");
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        SmallVec::new()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Textbox", id = ctx.widget_id().trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.editor.text().chars().take(100).collect())
+    }
+}
+
+// TODO: What other tests can we have? Some options:
+// - Clicking in the right place changes the selection as expected?
+// - Keyboard actions have expected results?
+
+#[cfg(test)]
+mod tests {
+    use vello::kurbo::Size;
+
+    use parley::PositionedLayoutItem;
+
+    use super::*;
+    use crate::core::Action;
+    use crate::testing::TestHarness;
+    // Tests of alignment happen in Prose.
+
+    #[test]
+    fn edit_wordwrap() {
+        let base_with_wrapping = {
+            let area = TextArea::new_immutable("String which will wrap").with_word_wrap(true);
+
+            let mut harness = TestHarness::create_with_size(area, Size::new(60.0, 40.0));
+
+            harness.render()
+        };
+
+        {
+            let area = TextArea::new_immutable("String which will wrap").with_word_wrap(false);
+
+            let mut harness = TestHarness::create_with_size(area, Size::new(60.0, 40.0));
+
+            let without_wrapping = harness.render();
+
+            // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel
+            // This means that the not equal comparison won't work, so we skip it.
+            // We should have a more principled solution here (or even better, get render tests working on windows)
+            if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+                // We don't use assert_eq because we don't want rich assert
+                assert!(
+                    base_with_wrapping != without_wrapping,
+                    "Word wrapping being disabled should be obvious"
+                );
+            }
+
+            harness.edit_root_widget(|mut root| {
+                let mut area = root.downcast::<TextArea<false>>();
+                TextArea::set_word_wrap(&mut area, true);
+            });
+
+            let with_enabled_wrap = harness.render();
+
+            // We don't use assert_eq because we don't want rich assert
+            assert!(
+                base_with_wrapping == with_enabled_wrap,
+                "Updating the word wrap should correctly update"
+            );
+        };
+    }
+
+    #[test]
+    fn edit_textarea() {
+        let base_target = {
+            let area = TextArea::new_immutable("Test string").with_brush(palette::css::AZURE);
+
+            let mut harness = TestHarness::create_with_size(area, Size::new(200.0, 20.0));
+
+            harness.render()
+        };
+
+        {
+            let area = TextArea::new_immutable("Different string").with_brush(palette::css::AZURE);
+
+            let mut harness = TestHarness::create_with_size(area, Size::new(200.0, 20.0));
+
+            harness.edit_root_widget(|mut root| {
+                let mut area = root.downcast::<TextArea<false>>();
+                TextArea::reset_text(&mut area, "Test string");
+            });
+
+            let with_updated_text = harness.render();
+
+            // We don't use assert_eq because we don't want rich assert
+            assert!(
+                base_target == with_updated_text,
+                "Updating the text should match with base text"
+            );
+
+            harness.edit_root_widget(|mut root| {
+                let mut area = root.downcast::<TextArea<false>>();
+                TextArea::set_brush(&mut area, palette::css::BROWN);
+            });
+
+            let with_updated_brush = harness.render();
 
-                        edited = true;
-                    }
-                    Key::Named(NamedKey::Backspace) if EDITABLE => {
-                        let mut drv = self.editor.driver(fctx, lctx);
-                        if action_mod {
-                            drv.backdelete_word();
-                        } else {
-                            drv.backdelete();
-                        }
+            // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel
+            // This means that the not equal comparison won't work, so we skip it.
+            if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+                // We don't use assert_eq because we don't want rich assert
+                assert!(
+                    base_target != with_updated_brush,
+                    "Updating the brush should have a visible change"
+                );
+            }
+        };
+    }
 
-                        edited = true;
-                    }
-                    Key::Named(NamedKey::Space) if EDITABLE => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(" ");
-                        edited = true;
-                    }
-                    Key::Named(NamedKey::Enter) => {
-                        // TODO: Multiline?
-                        let multiline = false;
-                        if multiline {
-                            let (fctx, lctx) = ctx.text_contexts();
-                            self.editor
-                                .driver(fctx, lctx)
-                                .insert_or_replace_selection("\n");
-                            edited = true;
-                        } else {
-                            ctx.submit_action(crate::core::Action::TextEntered(
-                                self.text().to_string(),
-                            ));
-                        }
-                    }
+    fn click(harness: &mut TestHarness) {
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+    }
 
-                    Key::Named(NamedKey::Tab) => {
-                        // Intentionally do nothing so that tabbing from a textbox/Prose works.
-                        // Note that this doesn't allow input of the tab character; we need to be more clever here at some point
-                        return;
-                    }
-                    _ if EDITABLE => match &key_event.text {
-                        Some(text) => {
-                            self.editor
-                                .driver(fctx, lctx)
-                                .insert_or_replace_selection(text);
-                            edited = true;
-                        }
-                        None => {
-                            // Do nothing, don't set as handled.
-                            return;
-                        }
-                    },
-                    _ => {
-                        // Do nothing, don't set as handled.
-                        return;
-                    }
-                }
-                ctx.set_handled();
-                let new_generation = self.editor.generation();
-                if new_generation != self.rendered_generation {
-                    if edited {
-                        ctx.submit_action(crate::core::Action::TextChanged(
-                            self.text().into_iter().collect(),
-                        ));
-                        ctx.request_layout();
-                    } else {
-                        ctx.request_render();
-                        ctx.set_ime_area(self.ime_area());
-                    }
-                    self.rendered_generation = new_generation;
-                }
+    fn selected_text(harness: &TestHarness) -> Option<String> {
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        area.editor.selected_text().map(str::to_string)
+    }
+
+    #[test]
+    fn selection_and_selected_text() {
+        let area = TextArea::new_editable("hello ne\u{301}e world");
+        let mut harness = TestHarness::create(area);
+        let select = |harness: &mut TestHarness, start, end| {
+            harness.edit_root_widget(|mut area| {
+                let mut area = area.downcast::<TextArea<true>>();
+                TextArea::select_byte_range(&mut area, start, end);
+            });
+            let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+            (area.selection(), area.selected_text().to_string())
+        };
+
+        let forward = TextSelection {
+            anchor: 0,
+            focus: 5,
+            is_collapsed: false,
+        };
+        assert_eq!(select(&mut harness, 0, 5), (forward, "hello".into()));
+
+        // The selected text is the same whichever way round the selection was made.
+        let backward = TextSelection {
+            anchor: 5,
+            focus: 0,
+            is_collapsed: false,
+        };
+        assert_eq!(select(&mut harness, 5, 0), (backward, "hello".into()));
+
+        let caret = TextSelection {
+            anchor: 3,
+            focus: 3,
+            is_collapsed: true,
+        };
+        assert_eq!(select(&mut harness, 3, 3), (caret, String::new()));
+
+        // The combining accent at 8 is part of the grapheme starting at 7.
+        let (selection, text) = select(&mut harness, 6, 8);
+        assert_eq!((selection.anchor, selection.focus), (6, 7));
+        assert_eq!(text, "n");
+    }
+
+    #[test]
+    fn multi_click_selection() {
+        let area = TextArea::new_editable("hello world\nsecond line").with_word_wrap(false);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+        let start_of_text = Point::new(5.0, 5.0);
+
+        // A single click only moves the cursor.
+        harness.mouse_move(start_of_text);
+        click(&mut harness);
+        assert_eq!(selected_text(&harness), None);
+
+        // A double click selects the word.
+        click(&mut harness);
+        assert_eq!(selected_text(&harness).as_deref(), Some("hello"));
+
+        // A triple click selects the line.
+        click(&mut harness);
+        let line = selected_text(&harness).unwrap();
+        assert!(line.starts_with("hello world"), "{line:?}");
+        assert!(!line.contains("second"), "{line:?}");
+
+        // A fourth click starts the cycle again.
+        click(&mut harness);
+        assert_eq!(selected_text(&harness), None);
+    }
+
+    #[test]
+    fn drag_selection_when_disabled() {
+        let area = TextArea::new_editable("hello world").with_word_wrap(false);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+        harness.edit_root_widget(|mut area| area.ctx.set_disabled(true));
+
+        harness.mouse_move((5.0, 5.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((300.0, 5.0));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(selected_text(&harness).as_deref(), Some("hello world"));
+
+        // Disabling selection also stops new selections from being made.
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::set_selectable_when_disabled(&mut area, false);
+            TextArea::select_byte_range(&mut area, 0, 0);
+        });
+        harness.mouse_move((5.0, 5.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((300.0, 5.0));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(selected_text(&harness), None);
+    }
+
+    #[test]
+    fn multi_click_requires_nearby_clicks() {
+        let area = TextArea::new_editable("hello world").with_word_wrap(false);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+
+        harness.mouse_move((5.0, 5.0));
+        click(&mut harness);
+        harness.mouse_move((300.0, 5.0));
+        click(&mut harness);
+        assert_eq!(selected_text(&harness), None);
+    }
+
+    #[test]
+    fn double_click_drag_extends_by_word() {
+        let area = TextArea::new_editable("hello world again").with_word_wrap(false);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+
+        harness.mouse_move((5.0, 5.0));
+        click(&mut harness);
+        harness.mouse_button_press(PointerButton::Primary);
+        assert_eq!(selected_text(&harness).as_deref(), Some("hello"));
+
+        // Dragging into the middle of the next word selects all of it.
+        let middle_of_world = {
+            let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+            let layout = area.editor.try_layout().unwrap();
+            Cursor::from_byte_index(layout, 8, Affinity::Downstream)
+                .geometry(layout, 1.0)
+                .x0
+        };
+        harness.mouse_move((middle_of_world, 5.0));
+        assert_eq!(selected_text(&harness).as_deref(), Some("hello world"));
+
+        // Dragging back before the anchor keeps the original word selected.
+        harness.mouse_move((1.0, 5.0));
+        assert_eq!(selected_text(&harness).as_deref(), Some("hello"));
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    #[test]
+    fn keyboard_navigation_in_mixed_script() {
+        // The combining accent and the family emoji are each part of a single grapheme.
+        let text = "Hi 世界 ne\u{301}e 👩\u{200d}👩\u{200d}👧 wörld\nsecond line";
+        let area = TextArea::new_editable(text).with_word_wrap(false);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+        let (word_mod, text_mod) = if cfg!(target_os = "macos") {
+            (ModifiersState::ALT, ModifiersState::SUPER)
+        } else {
+            (ModifiersState::CONTROL, ModifiersState::CONTROL)
+        };
+        let shift = ModifiersState::SHIFT;
+        harness.edit_root_widget(|mut area| {
+            TextArea::select_byte_range(&mut area.downcast::<TextArea<true>>(), 0, 0);
+        });
+        // Press `key` with `modifiers`, returning the new anchor and focus.
+        let mut press = |key, modifiers| {
+            harness.edit_root_widget(|mut area| {
+                let mut area = area.downcast::<TextArea<true>>();
+                let (fctx, lctx) = area.ctx.text_contexts();
+                area.widget.navigate(fctx, lctx, key, modifiers);
+            });
+            let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+            let selection = area.editor.raw_selection();
+            (selection.anchor().index(), selection.focus().index())
+        };
+
+        // Each ideograph is a word, and the emoji is skipped like punctuation.
+        for end in [2, 6, 9, 15, 41, 48] {
+            assert_eq!(press(NamedKey::ArrowRight, word_mod), (end, end));
+        }
+        for start in [42, 35, 10, 6] {
+            assert_eq!(press(NamedKey::ArrowLeft, word_mod), (start, start));
+        }
+
+        press(NamedKey::ArrowLeft, ModifiersState::empty());
+        assert_eq!(press(NamedKey::ArrowRight, ModifiersState::empty()), (6, 6));
+        for idx in [9, 10, 11, 14, 15, 16, 34] {
+            assert_eq!(
+                press(NamedKey::ArrowRight, ModifiersState::empty()),
+                (idx, idx)
+            );
+        }
+        assert_eq!(
+            press(NamedKey::ArrowLeft, ModifiersState::empty()),
+            (16, 16)
+        );
+
+        // Shift extends the selection from the anchor.
+        assert_eq!(press(NamedKey::ArrowRight, word_mod | shift), (16, 41));
+        assert_eq!(press(NamedKey::ArrowRight, word_mod | shift), (16, 48));
+        assert_eq!(press(NamedKey::ArrowRight, word_mod), (53, 53));
+
+        assert_eq!(press(NamedKey::Home, ModifiersState::empty()), (42, 42));
+        assert_eq!(press(NamedKey::End, shift), (42, 53));
+        assert_eq!(press(NamedKey::Home, text_mod), (0, 0));
+        assert_eq!(press(NamedKey::End, text_mod | shift), (0, 53));
+    }
+
+    #[test]
+    fn arrow_keys_move_visually_in_bidi_text() {
+        // The Hebrew word is displayed right to left, between two English words.
+        let text = "abc שלום def";
+        let area = TextArea::new_editable(text).with_word_wrap(false);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 40.0));
+        let word_mod = if cfg!(target_os = "macos") {
+            ModifiersState::ALT
+        } else {
+            ModifiersState::CONTROL
+        };
+        harness.edit_root_widget(|mut area| {
+            TextArea::select_byte_range(&mut area.downcast::<TextArea<true>>(), 0, 0);
+        });
+        let start_x = harness
+            .root_widget()
+            .downcast::<TextArea<true>>()
+            .unwrap()
+            .caret_rect()
+            .x0;
+        // Press `key` with `modifiers`, returning the new horizontal position of the caret.
+        let mut press = |key, modifiers| {
+            harness.edit_root_widget(|mut area| {
+                let mut area = area.downcast::<TextArea<true>>();
+                let (fctx, lctx) = area.ctx.text_contexts();
+                area.widget.navigate(fctx, lctx, key, modifiers);
+            });
+            let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+            area.caret_rect().x0
+        };
+
+        // Right never moves the caret leftwards, even across the Hebrew word.
+        let mut x = start_x;
+        for _ in text.chars() {
+            let next = press(NamedKey::ArrowRight, ModifiersState::empty());
+            assert!(next >= x, "caret moved left from {x} to {next}");
+            x = next;
+        }
+        let end_x = x;
+        assert!(end_x > start_x, "caret should have reached the end");
+        assert_eq!(
+            press(NamedKey::ArrowRight, ModifiersState::empty()),
+            end_x,
+            "caret should stop at the visual end"
+        );
+
+        // Moving by word goes back to the visual start without moving rightwards.
+        for _ in 0..6 {
+            let next = press(NamedKey::ArrowLeft, word_mod);
+            assert!(next <= x, "caret moved right from {x} to {next}");
+            x = next;
+        }
+        assert_eq!(x, start_x, "caret should have reached the start");
+    }
+
+    #[test]
+    fn selection_across_bidi_runs_is_disjoint() {
+        let text = "abc שלום def";
+        let area = TextArea::new_editable(text).with_word_wrap(false);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 40.0));
+        // "c ש": the Hebrew letter is at the far (right) end of its word.
+        harness.edit_root_widget(|mut area| {
+            TextArea::select_byte_range(&mut area.downcast::<TextArea<true>>(), 2, 6);
+        });
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        let mut rects = area.editor.selection_geometry();
+        rects.sort_by(|a, b| a.x0.total_cmp(&b.x0));
+        assert!(
+            rects.windows(2).any(|pair| pair[1].x0 > pair[0].x1 + 1.0),
+            "selection should have a gap for the unselected Hebrew letters: {rects:?}"
+        );
+    }
+
+    #[test]
+    fn base_direction_aligns_neutral_text() {
+        // Digits have no direction of their own, so are laid out left to right by default.
+        // Returns the position of the caret at the start of the text.
+        fn start_x(harness: &mut TestHarness) -> f64 {
+            harness.edit_root_widget(|mut area| {
+                TextArea::select_byte_range(&mut area.downcast::<TextArea<true>>(), 0, 0);
+            });
+            let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+            area.caret_rect().x0
+        }
+        let area = TextArea::new_editable("42");
+        let mut harness = TestHarness::create_with_size(area, Size::new(200.0, 40.0));
+        assert!(
+            start_x(&mut harness) < 20.0,
+            "text should start on the left"
+        );
+
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::set_base_direction(&mut area, Direction::Rtl);
+        });
+        assert!(
+            start_x(&mut harness) > 150.0,
+            "text should start on the right"
+        );
+
+        let area = TextArea::new_editable("42").with_base_direction(Direction::Rtl);
+        let mut harness = TestHarness::create_with_size(area, Size::new(200.0, 40.0));
+        assert!(
+            start_x(&mut harness) > 150.0,
+            "text should start on the right"
+        );
+    }
+
+    #[test]
+    fn overtype_replaces_next_grapheme() {
+        /// Type `text` with the caret at byte `caret`, returning the new text and selection.
+        fn type_at(harness: &mut TestHarness, caret: usize, text: &str) -> (String, Range<usize>) {
+            harness.edit_root_widget(|mut area| {
+                let mut area = area.downcast::<TextArea<true>>();
+                TextArea::select_byte_range(&mut area, caret, caret);
+                let (fctx, lctx) = area.ctx.text_contexts();
+                area.widget.type_filtered(fctx, lctx, text);
+            });
+            let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+            (area.text().to_string(), area.selection_range())
+        }
+
+        let area = TextArea::new_editable("ae\u{301}c\nd").with_overtype(true);
+        let mut harness = TestHarness::create(area);
+        // The accented e is a single grapheme, so it is replaced as a whole.
+        assert_eq!(type_at(&mut harness, 1, "x"), ("axc\nd".into(), 2..2));
+        assert_eq!(type_at(&mut harness, 2, "y"), ("axy\nd".into(), 3..3));
+        // At the end of a line, or of the text, characters are inserted.
+        assert_eq!(type_at(&mut harness, 3, "z"), ("axyz\nd".into(), 4..4));
+        assert_eq!(type_at(&mut harness, 6, "!"), ("axyz\nd!".into(), 7..7));
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::set_overtype(&mut area.downcast::<TextArea<true>>(), false);
+        });
+        assert_eq!(type_at(&mut harness, 0, "w"), ("waxyz\nd!".into(), 1..1));
+    }
+
+    #[test]
+    fn line_count_of_wrapped_text() {
+        let area = TextArea::new_immutable("String which will wrap").with_word_wrap(true);
+        let harness = TestHarness::create_with_size(area, Size::new(60.0, 400.0));
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        let layout = area.editor.try_layout().unwrap();
+        let line_count = area.line_count().unwrap();
+        assert!(line_count > 1, "the text should wrap");
+        assert_eq!(line_count, layout.lines().count());
+
+        let metrics = area.line_metrics().unwrap();
+        assert_eq!(metrics.len(), line_count);
+        for lines in metrics.windows(2) {
+            assert_eq!(lines[0].top + lines[0].height, lines[1].top);
+        }
+        for line in &metrics {
+            assert!(line.top < line.baseline && line.baseline < line.top + line.height);
+        }
+
+        let area = TextArea::new_immutable("String which will wrap").with_word_wrap(false);
+        let harness = TestHarness::create_with_size(area, Size::new(60.0, 400.0));
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        assert_eq!(area.line_count(), Some(1));
+    }
+
+    #[test]
+    fn justified_text_fills_width() {
+        let text = "aa bb cc dd ee ff gg hh ii jj kk ll mm nn oo pp";
+        // Justified text is wrapped even without word wrapping.
+        let area = TextArea::new_immutable(text)
+            .with_word_wrap(false)
+            .with_alignment(Alignment::Justified);
+        let harness = TestHarness::create_with_size(area, Size::new(100.0, 400.0));
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        let layout = area.current_layout().unwrap();
+        assert!(layout.len() > 2, "the text should wrap");
+        let right_edge = area.text_origin(layout).x + f64::from(area.last_available_width.unwrap());
+
+        // The end of the last word on each line, in local coordinates.
+        let line_ends = layout
+            .lines()
+            .map(|line| {
+                let range = line.text_range();
+                let end = range.start + text[range].trim_end().len();
+                area.rect_for_offset(end).unwrap().center().x
+            })
+            .collect::<Vec<_>>();
+        let (last, wrapped) = line_ends.split_last().unwrap();
+        for end in wrapped {
+            assert!(
+                (end - right_edge).abs() < 1.0,
+                "wrapped lines should reach {right_edge}, but one ends at {end}"
+            );
+        }
+        assert!(
+            *last < right_edge - 1.0,
+            "the last line shouldn't be spread out"
+        );
+    }
+
+    #[test]
+    fn text_stats_of_emoji_and_cjk() {
+        let text = "Hello, 世界! 👋🏽 I'm 🇬🇧\n日本語のテキスト";
+        let area = TextArea::new_editable(text);
+        // Each ideograph is a word, but the katakana run is a single word.
+        let expected = TextStats {
+            graphemes: 27,
+            words: 9,
+            bytes: 61,
+            lines: 2,
+        };
+        assert_eq!(area.text_stats(), expected);
+        assert_eq!(area.char_count(), 27);
+        assert_eq!(area.word_count(), 9);
+
+        // The cached counts are replaced after an edit.
+        let mut harness = TestHarness::create(area);
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::reset_text(&mut area, "naïve café 👨‍👩‍👧");
+        });
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        assert_eq!(
+            area.text_stats(),
+            TextStats {
+                graphemes: 12,
+                words: 2,
+                bytes: 31,
+                lines: 1,
             }
-            // TODO: Set our highlighting colour to a lighter blue as window unfocused
-            TextEvent::WindowFocusChange(_) => {}
-            TextEvent::Ime(e) => {
-                // TODO: Handle the cursor movement things from https://github.com/rust-windowing/winit/pull/3824
-                let (fctx, lctx) = ctx.text_contexts();
+        );
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::insert_at_cursor(&mut area, " ok");
+        });
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.word_count(), 3);
+    }
 
-                // Whether the returned text has changed.
-                // We don't send a TextChanged when the preedit changes
-                let mut edited = false;
-                match e {
-                    winit::event::Ime::Disabled => {
-                        self.editor.driver(fctx, lctx).clear_compose();
-                    }
-                    winit::event::Ime::Preedit(text, cursor) => {
-                        if text.is_empty() {
-                            self.editor.driver(fctx, lctx).clear_compose();
-                        } else {
-                            self.editor.driver(fctx, lctx).set_compose(text, *cursor);
-                            edited = true;
-                        }
-                    }
-                    winit::event::Ime::Commit(text) => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(text);
-                        edited = true;
-                    }
-                    winit::event::Ime::Enabled => {}
-                }
+    #[test]
+    fn offset_point_round_trip() {
+        let text = "hello world\nsecond line";
+        let area = TextArea::new_editable(text)
+            .with_word_wrap(false)
+            .with_padding(Padding::all(5.0));
+        let harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
 
-                ctx.set_handled();
-                if edited {
-                    let text = self.text().into_iter().collect();
-                    ctx.submit_action(crate::core::Action::TextChanged(text));
+        for offset in [0, 3, 6, 12, 18, text.len()] {
+            let rect = area.rect_for_offset(offset).unwrap();
+            assert_eq!(area.offset_at_point(rect.center()), Some(offset));
+        }
+        let first_line = area.rect_for_offset(0).unwrap();
+        assert!(first_line.x0 >= 5.0 && first_line.y0 >= 5.0);
+
+        // Points outside the text are clamped to it.
+        assert_eq!(area.offset_at_point(Point::new(-100.0, -100.0)), Some(0));
+        assert_eq!(
+            area.offset_at_point(Point::new(1000.0, 1000.0)),
+            Some(text.len())
+        );
+    }
+
+    #[test]
+    fn line_numbers_gutter() {
+        let text = "line\n".repeat(8) + "line";
+        let area = TextArea::new_editable(&text)
+            .with_padding(0.0)
+            .with_line_numbers(true);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 400.0));
+        let gutter = |harness: &TestHarness| {
+            let area = harness.root_widget();
+            let area = area.downcast::<TextArea<true>>().unwrap();
+            (area.gutter_width, area.gutter_numbers.len())
+        };
+        let (one_digit_width, numbers) = gutter(&harness);
+        assert_eq!(numbers, 9);
+        assert!(one_digit_width > GUTTER_GAP);
+
+        // The text starts after the gutter.
+        let area = harness.root_widget();
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        let start = area.rect_for_offset(0).unwrap();
+        assert!((start.center().x - one_digit_width).abs() <= f64::from(CARET_WIDTH));
+        let line_middle = start.center().y;
+
+        // Clicks in the gutter don't move the caret, but clicks in the text still do.
+        let selection = |harness: &TestHarness| {
+            let area = harness.root_widget();
+            area.downcast::<TextArea<true>>().unwrap().selection_range()
+        };
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::select_byte_range(&mut area, 2, 2);
+        });
+        harness.mouse_move((one_digit_width / 2.0, line_middle));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(selection(&harness), 2..2);
+        harness.mouse_move((one_digit_width + 1.0, line_middle));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(selection(&harness), 0..0);
+
+        // The gutter widens to fit the tenth line's number.
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::reset_text(&mut area, &(text.clone() + "\nline"));
+        });
+        let (two_digit_width, numbers) = gutter(&harness);
+        assert_eq!(numbers, 10);
+        assert!(two_digit_width > one_digit_width);
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::set_line_numbers(&mut area.downcast::<TextArea<true>>(), false);
+        });
+        assert_eq!(gutter(&harness), (0.0, 0));
+    }
+
+    #[test]
+    fn line_numbers_of_wrapped_text() {
+        let numbers = |numbering: LineNumbering| {
+            let area = TextArea::new_immutable("String which will wrap\nand another")
+                .with_word_wrap(true)
+                .with_line_numbers(true)
+                .with_line_numbering(numbering);
+            let harness = TestHarness::create_with_size(area, Size::new(90.0, 400.0));
+            let area = harness.root_widget();
+            let area = area.downcast::<TextArea<false>>().unwrap();
+            (area.gutter_numbers.len(), area.line_count().unwrap())
+        };
+        let (logical, line_count) = numbers(LineNumbering::Logical);
+        assert!(line_count > 2, "the text should wrap");
+        assert_eq!(logical, 2);
+        assert_eq!(numbers(LineNumbering::Visual), (line_count, line_count));
+    }
+
+    #[test]
+    fn caret_rect_is_offset_by_padding() {
+        let area = TextArea::new_editable("").with_padding(Padding::all(5.0));
+        let harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        let caret = area.caret_rect();
+        assert!(caret.y0 >= 5.0, "{caret:?}");
+        assert!(caret.height() > 0.0, "{caret:?}");
+        assert!(
+            (caret.center().x - 5.0).abs() <= f64::from(CARET_WIDTH),
+            "{caret:?}"
+        );
+    }
+
+    #[test]
+    fn typing_scrolls_to_caret() {
+        use crate::testing::{TestWidgetExt, widget_ids};
+        use crate::widgets::Portal;
+
+        let [portal_id, area_id] = widget_ids();
+        let text = "line\n".repeat(30);
+        let area = TextArea::new_editable(&text).with_id(area_id);
+        let portal = Portal::new(area).with_id(portal_id);
+        let mut harness = TestHarness::create_with_size(portal, Size::new(200.0, 50.0));
+
+        harness.edit_widget(area_id, |mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::select_byte_range(&mut area, text.len(), text.len());
+        });
+        harness.focus_on(Some(area_id));
+        harness.keyboard_type_chars("x");
+
+        let viewport_pos = |harness: &TestHarness| {
+            harness
+                .get_widget(portal_id)
+                .downcast::<Portal<TextArea<true>>>()
+                .unwrap()
+                .get_viewport_pos()
+        };
+        let caret = harness
+            .get_widget(area_id)
+            .downcast::<TextArea<true>>()
+            .unwrap()
+            .caret_rect();
+        // Only the caret is revealed, rather than the whole text area.
+        let viewport = Rect::from_origin_size(viewport_pos(&harness), Size::new(200.0, 50.0));
+        assert!(viewport.y0 > 0.0, "{viewport:?}");
+        assert!(viewport.contains(caret.origin()), "{viewport:?} {caret:?}");
+
+        // Typing at the start of the text scrolls back up.
+        harness.edit_widget(area_id, |mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::select_byte_range(&mut area, 0, 0);
+        });
+        harness.keyboard_type_chars("x");
+        assert_eq!(viewport_pos(&harness).y, 0.0);
+    }
+
+    #[test]
+    fn scroll_anchor_sets_scroll_target() {
+        use crate::testing::{Record, Recording, TestWidgetExt, widget_ids};
+        use crate::widgets::SizedBox;
+
+        // The container records the areas its descendants ask to be scrolled into view.
+        let scroll_targets = |recording: &Recording| {
+            recording
+                .drain()
+                .into_iter()
+                .filter_map(|record| match record {
+                    Record::U(Update::RequestPanToChild(rect)) => Some(rect),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for anchor in [
+            ScrollAnchor::CaretEnd,
+            ScrollAnchor::SelectionStart,
+            ScrollAnchor::Widget,
+        ] {
+            let [area_id] = widget_ids();
+            let recording = Recording::default();
+            let area = TextArea::new_editable("hello world").with_scroll_anchor(anchor);
+            let container = SizedBox::new_with_id(area, area_id).record(&recording);
+            let mut harness = TestHarness::create_with_size(container, Size::new(400.0, 100.0));
+            let expected_target = |harness: &TestHarness, edit_start: usize| {
+                let area = harness.get_widget(area_id);
+                let layout_rect = area.ctx().local_layout_rect();
+                let area = area.downcast::<TextArea<true>>().unwrap();
+                match anchor {
+                    ScrollAnchor::CaretEnd => area.caret_rect(),
+                    ScrollAnchor::SelectionStart => area.rect_for_offset(edit_start).unwrap(),
+                    ScrollAnchor::Widget => layout_rect,
                 }
+            };
+
+            // A keyboard edit.
+            harness.edit_widget(area_id, |mut area| {
+                let mut area = area.downcast::<TextArea<true>>();
+                TextArea::select_byte_range(&mut area, 5, 5);
+            });
+            harness.focus_on(Some(area_id));
+            recording.clear();
+            harness.keyboard_type_chars(",");
+            assert_eq!(
+                scroll_targets(&recording),
+                [expected_target(&harness, 5)],
+                "{anchor:?}"
+            );
+
+            // A programmatic insert, such as a paste.
+            harness.edit_widget(area_id, |mut area| {
+                let mut area = area.downcast::<TextArea<true>>();
+                TextArea::insert_at_cursor(&mut area, " big, wide");
+            });
+            let text = harness
+                .get_widget(area_id)
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .text()
+                .to_string();
+            assert_eq!(text, "hello, big, wide world");
+            assert_eq!(
+                scroll_targets(&recording),
+                [expected_target(&harness, 6)],
+                "{anchor:?}"
+            );
+        }
+    }
+
+    fn area_text(harness: &TestHarness) -> String {
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        area.text().to_string()
+    }
+
+    #[test]
+    fn access_set_value() {
+        let area = TextArea::new_editable("hello world");
+        let mut harness = TestHarness::create(area);
+        let area_id = harness.root_widget().id();
+
+        harness.process_access_event(
+            area_id,
+            accesskit::Action::SetValue,
+            Some(accesskit::ActionData::Value("goodbye".into())),
+        );
+        assert_eq!(area_text(&harness), "goodbye");
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::TextChanged("goodbye".into()), area_id))
+        );
+        // The caret is moved to the end of the new value.
+        assert_eq!(
+            harness.pop_action(),
+            Some((
+                Action::TextSelectionChanged(SelectionChange {
+                    start: 7,
+                    end: 7,
+                    programmatic: false,
+                }),
+                area_id
+            ))
+        );
+
+        // Events without a value are ignored.
+        harness.process_access_event(area_id, accesskit::Action::SetValue, None);
+        assert_eq!(area_text(&harness), "goodbye");
+        assert_eq!(harness.pop_action(), None);
+    }
+
+    #[test]
+    fn access_replace_selected_text() {
+        let area = TextArea::new_editable("hello world");
+        let mut harness = TestHarness::create(area);
+        let area_id = harness.root_widget().id();
 
-                let new_generation = self.editor.generation();
-                if new_generation != self.rendered_generation {
-                    ctx.request_layout();
-                    self.rendered_generation = new_generation;
-                }
-            }
-            TextEvent::ModifierChange(_) => {}
-        }
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::select_text(&mut area, "world");
+        });
+        harness.process_access_event(
+            area_id,
+            accesskit::Action::ReplaceSelectedText,
+            Some(accesskit::ActionData::Value("there".into())),
+        );
+        assert_eq!(area_text(&harness), "hello there");
+
+        // The cursor is collapsed to the end of the inserted text.
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        let selection = area.editor.raw_selection();
+        assert!(selection.is_collapsed());
+        assert_eq!(selection.focus().index(), "hello there".len());
     }
 
-    fn accepts_focus(&self) -> bool {
-        true
+    #[test]
+    fn selection_changes_reported() {
+        let area = TextArea::new_editable("hello world");
+        let mut harness = TestHarness::create(area);
+        let area_id = harness.root_widget().id();
+
+        let select = |harness: &mut TestHarness, start, end| {
+            harness.edit_root_widget(|mut area| {
+                let mut area = area.downcast::<TextArea<true>>();
+                TextArea::select_byte_range(&mut area, start, end);
+            });
+        };
+        select(&mut harness, 6, 11);
+        assert_eq!(
+            harness.pop_action(),
+            Some((
+                Action::TextSelectionChanged(SelectionChange {
+                    start: 6,
+                    end: 11,
+                    programmatic: true,
+                }),
+                area_id
+            ))
+        );
+        // Setting the same selection again isn't a change.
+        select(&mut harness, 6, 11);
+        assert_eq!(harness.pop_action(), None);
+
+        harness.process_access_event(
+            area_id,
+            accesskit::Action::ReplaceSelectedText,
+            Some(accesskit::ActionData::Value("there".into())),
+        );
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::TextChanged("hello there".into()), area_id))
+        );
+        assert_eq!(
+            harness.pop_action(),
+            Some((
+                Action::TextSelectionChanged(SelectionChange {
+                    start: 11,
+                    end: 11,
+                    programmatic: false,
+                }),
+                area_id
+            ))
+        );
+
+        // Resetting the text moves the caret without reporting it.
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::reset_text(&mut area, "goodbye");
+        });
+        assert_eq!(harness.pop_action(), None);
     }
 
-    fn accepts_text_input(&self) -> bool {
-        EDITABLE
+    #[test]
+    fn access_edits_ignored_when_read_only() {
+        let area = TextArea::new_immutable("hello world");
+        let mut harness = TestHarness::create(area);
+        let area_id = harness.root_widget().id();
+
+        harness.process_access_event(
+            area_id,
+            accesskit::Action::SetValue,
+            Some(accesskit::ActionData::Value("goodbye".into())),
+        );
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        assert_eq!(area.text().to_string(), "hello world");
+        assert_eq!(harness.pop_action(), None);
     }
 
-    fn on_access_event(
-        &mut self,
-        ctx: &mut EventCtx,
-        _props: &mut PropertiesMut<'_>,
-        event: &AccessEvent,
-    ) {
-        if event.action == accesskit::Action::SetTextSelection {
-            if self.editor.is_composing() {
-                return;
-            }
+    #[test]
+    fn spans_follow_edits() {
+        let area = TextArea::new_editable("hello world").with_spans([
+            (0..5, StyleAttribute::Brush(palette::css::RED.into())),
+            (6..11, StyleAttribute::Brush(palette::css::BLUE.into())),
+        ]);
+        let mut harness = TestHarness::create(area);
+        let area_id = harness.root_widget().id();
 
-            if let Some(accesskit::ActionData::SetTextSelection(selection)) = &event.data {
-                let (fctx, lctx) = ctx.text_contexts();
-                self.editor
-                    .driver(fctx, lctx)
-                    .select_from_accesskit(selection);
-                let new_generation = self.editor.generation();
-                if new_generation != self.rendered_generation {
-                    ctx.request_render();
-                    ctx.set_ime_area(self.ime_area());
-                    self.rendered_generation = new_generation;
+        // Insert into the middle of the first span.
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::select_byte_range(&mut area, 2, 2);
+        });
+        harness.process_access_event(
+            area_id,
+            accesskit::Action::ReplaceSelectedText,
+            Some(accesskit::ActionData::Value("XYZ".into())),
+        );
+        assert_eq!(area_text(&harness), "heXYZllo world");
+
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        let ranges: Vec<_> = area
+            .spans()
+            .iter()
+            .map(|(range, _)| range.clone())
+            .collect();
+        assert_eq!(ranges, [0..8, 9..14]);
+
+        // Count the glyphs painted with each brush, where brush 0 is the base brush.
+        let mut glyphs_per_brush = [0; 3];
+        let layout = area.styled_layout.as_ref().unwrap();
+        for line in layout.lines() {
+            for item in line.items() {
+                if let PositionedLayoutItem::GlyphRun(glyph_run) = item {
+                    glyphs_per_brush[glyph_run.style().brush.0] += glyph_run.glyphs().count();
                 }
             }
         }
+        assert_eq!(glyphs_per_brush, [1, 8, 5]);
     }
 
-    fn register_children(&mut self, _ctx: &mut RegisterCtx) {}
+    #[test]
+    fn find_links_in_text() {
+        assert_eq!(
+            find_links("See https://example.com/a, or http://x.org."),
+            [4..25, 30..42]
+        );
+        assert_eq!(find_links("(https://example.com)"), [1..20]);
+        // Links must start a word, and have something after the scheme.
+        assert!(find_links("xhttp://example.com http:// https").is_empty());
+    }
 
-    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
-        match event {
-            Update::FocusChanged(_) => {
-                ctx.request_render();
-            }
-            Update::DisabledChanged(_) => {
-                // We might need to use the disabled brush, and stop displaying the selection.
-                ctx.request_render();
+    #[test]
+    fn autolink_click() {
+        let area = TextArea::new_immutable("Visit https://example.com now")
+            .with_word_wrap(false)
+            .with_autolink(true);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        let on_link = area.rect_for_offset(10).unwrap().center();
+        let off_link = area.rect_for_offset(2).unwrap().center();
+
+        let clicked_link = |harness: &mut TestHarness| {
+            let mut url = None;
+            while let Some((action, _)) = harness.pop_action() {
+                if let Action::LinkClicked(clicked) = action {
+                    url = Some(clicked);
+                }
             }
-            _ => {}
-        }
-    }
+            url
+        };
 
-    fn layout(
-        &mut self,
-        ctx: &mut LayoutCtx,
-        _props: &mut PropertiesMut<'_>,
-        bc: &BoxConstraints,
-    ) -> Size {
-        // Shrink constraints by padding inset
-        let padding_size = Size::new(
-            self.padding.leading + self.padding.trailing,
-            self.padding.top + self.padding.bottom,
+        harness.mouse_move(off_link);
+        assert_eq!(harness.cursor_icon(), CursorIcon::Text);
+        click(&mut harness);
+        assert_eq!(clicked_link(&mut harness), None);
+
+        harness.mouse_move(on_link);
+        assert_eq!(harness.cursor_icon(), CursorIcon::Pointer);
+        click(&mut harness);
+        assert_eq!(
+            clicked_link(&mut harness).as_deref(),
+            Some("https://example.com")
         );
-        let sub_bc = bc.shrink(padding_size);
 
-        let available_width = if bc.max().width.is_finite() {
-            Some((sub_bc.max().width) as f32)
-        } else {
-            None
-        };
-        let max_advance = if self.word_wrap {
-            available_width
-        } else {
-            None
-        };
-        if self.last_available_width != available_width && self.word_wrap {
-            self.editor.set_width(max_advance);
-        }
-        self.last_available_width = available_width;
-        // TODO: Use the minimum width in the bc for alignment
+        // Dragging from the link selects text instead, even if released on the link.
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move(off_link);
+        harness.mouse_move(on_link);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(clicked_link(&mut harness), None);
 
-        let new_generation = self.editor.generation();
-        if new_generation != self.rendered_generation {
-            self.rendered_generation = new_generation;
-        }
+        // Without autolink, the URL is plain text.
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<false>>();
+            TextArea::set_autolink(&mut area, false);
+        });
+        harness.mouse_move(off_link);
+        harness.mouse_move(on_link);
+        assert_eq!(harness.cursor_icon(), CursorIcon::Text);
+        click(&mut harness);
+        assert_eq!(clicked_link(&mut harness), None);
+    }
 
-        let (fctx, lctx) = ctx.text_contexts();
-        let layout = self.editor.layout(fctx, lctx);
-        let text_width = max_advance.unwrap_or(layout.full_width());
-        let text_size = Size::new(text_width.into(), layout.height().into());
-        ctx.set_ime_area(self.ime_area());
+    #[test]
+    fn cursor_over_links_and_padding() {
+        let area = TextArea::new_immutable("Visit https://example.com now")
+            .with_word_wrap(false)
+            .with_autolink(true)
+            .with_padding(20.0);
+        let mut harness = TestHarness::create_with_size(area, Size::new(400.0, 100.0));
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        let on_link = area.rect_for_offset(10).unwrap().center();
+        let on_text = area.rect_for_offset(2).unwrap().center();
 
-        let area_size = Size {
-            height: text_size.height + padding_size.height,
-            width: text_size.width + padding_size.width,
-        };
-        bc.constrain(area_size)
+        harness.mouse_move(on_link);
+        assert_eq!(harness.cursor_icon(), CursorIcon::Pointer);
+        harness.mouse_move(on_text);
+        assert_eq!(harness.cursor_icon(), CursorIcon::Text);
+        harness.mouse_move((10.0, 10.0));
+        assert_eq!(harness.cursor_icon(), CursorIcon::Default);
     }
 
-    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
-        let layout = if let Some(layout) = self.editor.try_layout() {
-            layout
-        } else {
-            debug_panic!("Widget `layout` should have happened before paint");
-            let (fctx, lctx) = ctx.text_contexts();
-            // The `layout` method takes `&mut self`, so we get borrow-checker errors if we return it from this block.
-            self.editor.refresh_layout(fctx, lctx);
-            self.editor.try_layout().unwrap()
+    #[test]
+    fn decorations_split_across_lines() {
+        let decoration = |range| TextDecoration {
+            range,
+            kind: DecorationKind::Wavy,
+            color: palette::css::RED,
         };
-        let is_rtl = layout.is_rtl();
-        let origin = Vec2::new(self.padding.get_left(is_rtl), self.padding.top);
-        let transform = Affine::translate(origin);
-        if ctx.is_focus_target() {
-            for rect in self.editor.selection_geometry().iter() {
-                // TODO: If window not focused, use a different color
-                // TODO: Make configurable
-                scene.fill(
-                    Fill::NonZero,
-                    transform,
-                    palette::css::STEEL_BLUE,
-                    None,
-                    &rect,
-                );
-            }
-            if let Some(cursor) = self.editor.cursor_geometry(1.5) {
-                // TODO: Make configurable
-                scene.fill(Fill::NonZero, transform, palette::css::WHITE, None, &cursor);
-            };
+        let area = TextArea::new_editable("String which will wrap")
+            .with_word_wrap(true)
+            .with_decorations([decoration(3..15), decoration(0..3)]);
+        let mut harness = TestHarness::create_with_size(area, Size::new(60.0, 400.0));
+        let area_id = harness.root_widget().id();
+
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        let layout = area.editor.try_layout().unwrap();
+        let line_ranges: Vec<_> = layout.lines().map(|line| line.text_range()).collect();
+        assert!(line_ranges.len() > 1, "the text should wrap");
+
+        // The long decoration is split into a line for each visual line it covers.
+        let lines = decoration_lines(layout, 3..15);
+        let covered = line_ranges
+            .iter()
+            .filter(|line| line.start < 15 && line.end > 3)
+            .count();
+        assert!(covered > 1);
+        assert_eq!(lines.len(), covered);
+        for pair in lines.windows(2) {
+            assert!(pair[0].p0.y < pair[1].p0.y);
         }
+        assert_eq!(decoration_lines(layout, 0..3).len(), 1);
 
-        let brush = if ctx.is_disabled() {
-            self.disabled_brush
-                .clone()
-                .unwrap_or_else(|| self.brush.clone())
-        } else {
-            self.brush.clone()
-        };
-        render_text(scene, transform, layout, &[brush], self.hint);
+        // Decorations move with the text they cover.
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<true>>();
+            TextArea::select_byte_range(&mut area, 0, 0);
+        });
+        harness.process_access_event(
+            area_id,
+            accesskit::Action::ReplaceSelectedText,
+            Some(accesskit::ActionData::Value("A ".into())),
+        );
+        let area = harness.root_widget().downcast::<TextArea<true>>().unwrap();
+        let ranges: Vec<_> = area
+            .decorations()
+            .iter()
+            .map(|decoration| decoration.range.clone())
+            .collect();
+        assert_eq!(ranges, [5..17, 2..5]);
     }
 
-    fn get_cursor(&self, _ctx: &QueryCtx, _pos: Point) -> CursorIcon {
-        CursorIcon::Text
-    }
+    #[test]
+    fn highlights_split_across_lines() {
+        let area = TextArea::new_immutable("String which will wrap")
+            .with_word_wrap(true)
+            .with_highlights([3..15], HighlightStyle::default());
+        let mut harness = TestHarness::create_with_size(area, Size::new(60.0, 400.0));
 
-    fn accessibility_role(&self) -> Role {
-        if EDITABLE {
-            Role::TextInput
-            // TODO: Role::MultilineTextInput
-        } else {
-            Role::Document
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        let layout = area.editor.try_layout().unwrap();
+        let covered = layout
+            .lines()
+            .filter(|line| line.text_range().start < 15 && line.text_range().end > 3)
+            .count();
+        assert!(covered > 1, "the highlight should cover wrapped lines");
+        let rects = highlight_rects(layout, 3..15);
+        assert_eq!(rects.len(), covered);
+        for pair in rects.windows(2) {
+            assert!(pair[0].y1 <= pair[1].y0);
         }
-    }
 
-    fn accessibility(&mut self, ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
-        if !EDITABLE {
-            node.set_read_only();
-        }
-        let (fctx, lctx) = ctx.text_contexts();
-        let layout = self.editor.layout(fctx, lctx);
-        let is_rtl = layout.is_rtl();
-        let origin = ctx.window_origin();
-        self.editor
-            .try_accessibility(
-                ctx.tree_update,
-                node,
-                || NodeId::from(WidgetId::next()),
-                origin.x + self.padding.get_left(is_rtl),
-                origin.y + self.padding.top,
-            )
-            .expect("We just performed a layout");
-    }
+        // Changing the highlights only needs a repaint, and leaves the selection alone.
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<false>>();
+            TextArea::select_byte_range(&mut area, 0, 2);
+        });
+        harness.edit_root_widget(|mut area| {
+            let mut area = area.downcast::<TextArea<false>>();
+            TextArea::set_highlights(&mut area, [0..6, 13..18], HighlightStyle::default());
+            assert!(!area.ctx.widget_state.needs_layout);
+            assert!(area.ctx.widget_state.request_paint);
+        });
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        assert_eq!(area.highlights(), [0..6, 13..18]);
+        assert_eq!(area.selection_range(), 0..2);
 
-    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
-        SmallVec::new()
+        harness.edit_root_widget(|mut area| {
+            TextArea::clear_highlights(&mut area.downcast::<TextArea<false>>());
+        });
+        let area = harness.root_widget().downcast::<TextArea<false>>().unwrap();
+        assert!(area.highlights().is_empty());
     }
 
-    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
-        trace_span!("Textbox", id = ctx.widget_id().trace())
+    #[test]
+    fn text_edits_move_ranges() {
+        assert_eq!(text_edit("hello", "hello"), None);
+        assert_eq!(text_edit("hello", "help"), Some((3..5, 1)));
+        // Ambiguous edits are as late as possible.
+        assert_eq!(text_edit("hello", "helllo"), Some((4..4, 1)));
+        assert_eq!(text_edit("héllo", "hllo"), Some((1..3, 0)));
+
+        // Text inserted at the end of a range extends it, but not at the start.
+        assert_eq!(move_range(2..4, 4..4, 3), 2..7);
+        assert_eq!(move_range(2..4, 2..2, 3), 5..7);
+        // Replaced text is excluded from the range.
+        assert_eq!(move_range(2..6, 0..3, 1), 1..4);
+        assert_eq!(move_range(2..6, 4..8, 5), 2..4);
+        assert_eq!(move_range(2..6, 1..8, 0), 1..1);
     }
 
-    fn get_debug_text(&self) -> Option<String> {
-        Some(self.editor.text().chars().take(100).collect())
+    #[test]
+    fn tab_action_indent() {
+        assert_eq!(TabAction::MoveFocus.indent(), None);
+        assert_eq!(TabAction::InsertTab.indent().as_deref(), Some("\t"));
+        assert_eq!(TabAction::InsertSpaces(4).indent().as_deref(), Some("    "));
     }
-}
 
-// TODO: What other tests can we have? Some options:
-// - Clicking in the right place changes the selection as expected?
-// - Keyboard actions have expected results?
+    #[test]
+    fn tab_action_dedent_range() {
+        let text = "first\n\t  second\n      third";
+        // `MoveFocus` never consumes Shift+Tab, so it never dedents.
+        assert_eq!(TabAction::MoveFocus.dedent_range(text, 9), None);
+        assert_eq!(TabAction::InsertTab.dedent_range(text, 9), Some(6..7));
+        // Only spaces at the start of the line are removed.
+        assert_eq!(TabAction::InsertSpaces(4).dedent_range(text, 9), None);
+        assert_eq!(
+            TabAction::InsertSpaces(4).dedent_range(text, 20),
+            Some(16..20)
+        );
+        assert_eq!(TabAction::InsertTab.dedent_range(text, 3), None);
+    }
 
-#[cfg(test)]
-mod tests {
-    use vello::kurbo::Size;
+    #[test]
+    fn tab_action_builder_and_setter() {
+        let area = TextArea::new_editable("hello").with_tab_action(TabAction::InsertSpaces(2));
+        let mut harness = TestHarness::create(area);
+        let tab_action = |harness: &TestHarness| {
+            harness
+                .root_widget()
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .tab_action
+        };
+        assert_eq!(tab_action(&harness), TabAction::InsertSpaces(2));
 
-    use super::*;
-    use crate::testing::TestHarness;
-    // Tests of alignment happen in Prose.
+        harness.edit_root_widget(|mut root| {
+            let mut area = root.downcast::<TextArea<true>>();
+            TextArea::set_tab_action(&mut area, TabAction::MoveFocus);
+        });
+        assert_eq!(tab_action(&harness), TabAction::MoveFocus);
+    }
 
     #[test]
-    fn edit_wordwrap() {
-        let base_with_wrapping = {
-            let area = TextArea::new_immutable("String which will wrap").with_word_wrap(true);
+    fn tab_key_moves_focus_or_inserts_tab() {
+        use winit::keyboard::KeyCode;
 
-            let mut harness = TestHarness::create_with_size(area, Size::new(60.0, 40.0));
+        use crate::testing::widget_ids;
+        use crate::widgets::Flex;
 
-            harness.render()
+        let [first_id, second_id] = widget_ids();
+        let widget = Flex::column()
+            .with_child_id(
+                TextArea::new_editable("one").with_tab_action(TabAction::MoveFocus),
+                first_id,
+            )
+            .with_child_id(
+                TextArea::new_editable("two").with_tab_action(TabAction::InsertTab),
+                second_id,
+            );
+        let mut harness = TestHarness::create(widget);
+        let press_tab = |harness: &mut TestHarness| {
+            harness.process_text_event(TextEvent::key_down(
+                KeyCode::Tab,
+                NamedKey::Tab,
+                ModifiersState::empty(),
+            ))
+        };
+        let text = |harness: &TestHarness, id| {
+            harness
+                .get_widget(id)
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .text()
+                .to_string()
         };
 
-        {
-            let area = TextArea::new_immutable("String which will wrap").with_word_wrap(false);
-
-            let mut harness = TestHarness::create_with_size(area, Size::new(60.0, 40.0));
+        // `MoveFocus` leaves Tab to the framework, which focuses the next text area.
+        harness.focus_on(Some(first_id));
+        press_tab(&mut harness);
+        assert_eq!(harness.focused_widget_id(), Some(second_id));
+        assert_eq!(text(&harness, first_id), "one");
 
-            let without_wrapping = harness.render();
+        // `InsertTab` types a tab and keeps focus.
+        let handled = press_tab(&mut harness);
+        assert!(handled.is_handled());
+        assert_eq!(harness.focused_widget_id(), Some(second_id));
+        assert_eq!(text(&harness, second_id), "two\t");
+    }
 
-            // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel
-            // This means that the not equal comparison won't work, so we skip it.
-            // We should have a more principled solution here (or even better, get render tests working on windows)
-            if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
-                // We don't use assert_eq because we don't want rich assert
-                assert!(
-                    base_with_wrapping != without_wrapping,
-                    "Word wrapping being disabled should be obvious"
-                );
-            }
+    #[test]
+    fn font_fallbacks_builder_and_setter() {
+        use parley::GenericFamily;
 
+        let font_stack = |harness: &mut TestHarness| {
+            let mut font_stack = None;
             harness.edit_root_widget(|mut root| {
-                let mut area = root.downcast::<TextArea<false>>();
-                TextArea::set_word_wrap(&mut area, true);
+                let mut area = root.downcast::<TextArea<true>>();
+                font_stack =
+                    area.widget
+                        .editor
+                        .edit_styles()
+                        .inner()
+                        .values()
+                        .find_map(|property| match property {
+                            StyleProperty::FontStack(stack) => Some(stack.clone()),
+                            _ => None,
+                        });
             });
+            font_stack
+        };
 
-            let with_enabled_wrap = harness.render();
+        let latin = FontFamily::Named(Cow::Borrowed("Roboto"));
+        let cjk = FontFamily::Named(Cow::Borrowed("Noto Sans CJK SC"));
+        let emoji = FontFamily::Generic(GenericFamily::Emoji);
+        let area = TextArea::new_editable("Hello 你好 👋").with_font_fallbacks(vec![
+            latin.clone(),
+            cjk.clone(),
+            emoji.clone(),
+        ]);
+        let mut harness = TestHarness::create(area);
+        assert_eq!(
+            font_stack(&mut harness),
+            Some(FontStack::List(Cow::Owned(vec![
+                latin.clone(),
+                cjk.clone(),
+                emoji.clone()
+            ])))
+        );
 
-            // We don't use assert_eq because we don't want rich assert
-            assert!(
-                base_with_wrapping == with_enabled_wrap,
-                "Updating the word wrap should correctly update"
-            );
-        };
+        harness.edit_root_widget(|mut root| {
+            let mut area = root.downcast::<TextArea<true>>();
+            TextArea::set_font_fallbacks(&mut area, vec![emoji.clone(), latin.clone()]);
+        });
+        assert_eq!(
+            font_stack(&mut harness),
+            Some(FontStack::List(Cow::Owned(vec![emoji, latin])))
+        );
     }
 
     #[test]
-    fn edit_textarea() {
-        let base_target = {
-            let area = TextArea::new_immutable("Test string").with_brush(palette::css::AZURE);
+    fn selection_brushes() {
+        use crate::widgets::Textbox;
 
-            let mut harness = TestHarness::create_with_size(area, Size::new(200.0, 20.0));
+        let area = TextArea::new_editable("hello")
+            .with_selection_brush(palette::css::RED)
+            .with_caret_brush(palette::css::LIME);
+        let mut harness = TestHarness::create(Textbox::from_text_area(area));
 
-            harness.render()
+        let area_id = harness
+            .root_widget()
+            .downcast::<Textbox>()
+            .unwrap()
+            .area_pod()
+            .id();
+        let brushes = |harness: &TestHarness| {
+            let area = harness.get_widget(area_id);
+            let area = area.downcast::<TextArea<true>>().unwrap();
+            (
+                area.selection_brush.clone(),
+                area.inactive_selection_brush.clone(),
+                area.caret_brush.clone(),
+            )
         };
+        assert_eq!(
+            brushes(&harness),
+            (
+                palette::css::RED.into(),
+                None,
+                Some(palette::css::LIME.into())
+            )
+        );
 
-        {
-            let area = TextArea::new_immutable("Different string").with_brush(palette::css::AZURE);
-
-            let mut harness = TestHarness::create_with_size(area, Size::new(200.0, 20.0));
-
-            harness.edit_root_widget(|mut root| {
-                let mut area = root.downcast::<TextArea<false>>();
-                TextArea::reset_text(&mut area, "Test string");
-            });
-
-            let with_updated_text = harness.render();
-
-            // We don't use assert_eq because we don't want rich assert
-            assert!(
-                base_target == with_updated_text,
-                "Updating the text should match with base text"
-            );
-
-            harness.edit_root_widget(|mut root| {
-                let mut area = root.downcast::<TextArea<false>>();
-                TextArea::set_brush(&mut area, palette::css::BROWN);
-            });
-
-            let with_updated_brush = harness.render();
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            let mut area = Textbox::text_mut(&mut textbox);
+            TextArea::set_selection_brush(&mut area, palette::css::BLUE);
+            TextArea::set_inactive_selection_brush(&mut area, Brush::from(palette::css::GRAY));
+            TextArea::set_caret_brush(&mut area, palette::css::YELLOW);
+        });
+        assert_eq!(
+            brushes(&harness),
+            (
+                palette::css::BLUE.into(),
+                Some(palette::css::GRAY.into()),
+                Some(palette::css::YELLOW.into())
+            )
+        );
+    }
 
-            // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel
-            // This means that the not equal comparison won't work, so we skip it.
-            if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
-                // We don't use assert_eq because we don't want rich assert
-                assert!(
-                    base_target != with_updated_brush,
-                    "Updating the brush should have a visible change"
-                );
+    #[test]
+    fn debounced_text_changed() {
+        let area = TextArea::new_editable("").with_change_debounce(Duration::from_millis(100));
+        let mut harness = TestHarness::create(area);
+        let area_id = harness.root_widget().id();
+        let text_changes = |harness: &mut TestHarness| {
+            let mut changes = Vec::new();
+            while let Some((action, _)) = harness.pop_action() {
+                if let Action::TextChanged(text) = action {
+                    changes.push(text);
+                }
             }
+            changes
         };
+
+        harness.focus_on(Some(area_id));
+        harness.keyboard_type_chars("ab");
+        // The text is updated straight away, but the action waits for a pause.
+        assert_eq!(area_text(&harness), "ab");
+        assert!(text_changes(&mut harness).is_empty());
+        harness.animate_ms(60);
+        harness.keyboard_type_chars("c");
+        // The timer restarts on each edit.
+        harness.animate_ms(60);
+        assert!(text_changes(&mut harness).is_empty());
+        harness.animate_ms(60);
+        assert_eq!(text_changes(&mut harness), ["abc"]);
+
+        // A pending change is flushed when focus is lost.
+        harness.keyboard_type_chars("d");
+        harness.focus_on(None);
+        assert_eq!(text_changes(&mut harness), ["abcd"]);
+        harness.animate_ms(200);
+        assert!(text_changes(&mut harness).is_empty());
     }
 }
@@ -4,27 +4,50 @@
 #![warn(missing_docs)]
 
 use accesskit::{Node, Role};
-use smallvec::{SmallVec, smallvec};
+use smallvec::SmallVec;
 use tracing::{Span, trace_span};
 use vello::Scene;
-use vello::kurbo::{Affine, Insets, Point, Rect, Size, Stroke};
+use vello::kurbo::{Affine, Insets, Line, Point, Rect, Size, Stroke};
+use vello::peniko::Fill;
 
 use crate::core::{
-    AccessCtx, AccessEvent, BoxConstraints, EventCtx, LayoutCtx, PaintCtx, PointerEvent,
-    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
-    WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, AccessEvent, Action, ArcStr, BoxConstraints, EventCtx, LayoutCtx, MutateCtx,
+    PaintCtx, PointerButton, PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx,
+    TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
-use crate::peniko::Color;
-use crate::widgets::{Padding, TextArea};
+use crate::peniko::{Brush, Color};
+use crate::theme::{self, Theme};
+use crate::widgets::{Label, Padding, TextArea};
 
-/// Added padding between each horizontal edge of the widget
+/// The default padding between each edge of the outline
 /// and the text in logical pixels.
 ///
 /// This makes it so that the surrounding box isn't crowding out the text.
+/// It can be changed using [`Textbox::with_padding`].
 const TEXTBOX_PADDING: Padding = Padding::all(5.0);
 
-/// The margin added around textboxes to allow the boundaries to be visible inside the window edge.
-const TEXTBOX_MARGIN: Padding = Padding::horizontal(2.0);
+/// The default outline of textboxes, a 1 logical pixel white rectangle.
+const DEFAULT_TEXTBOX_BORDER: BorderStyle = BorderStyle {
+    color: Color::WHITE,
+    width: 1.0,
+    radius: 0.0,
+};
+
+/// The default margin added to each horizontal side of textboxes,
+/// to allow the boundaries to be visible inside the window edge.
+const DEFAULT_TEXTBOX_MARGIN: f64 = 2.0;
+
+/// The width of textboxes without a preferred width when the available width is unbounded,
+/// in logical pixels.
+const DEFAULT_PREFERRED_WIDTH: f64 = 400.0;
+
+/// The space between the outline and a prefix or suffix, in logical pixels.
+///
+/// The space between a prefix or suffix and the text is the text area's padding.
+const ADORNMENT_PADDING: f64 = 5.0;
+
+/// The width and height of the clear button, in logical pixels.
+const CLEAR_BUTTON_SIZE: f64 = 14.0;
 
 /// The textbox widget displays text which can be edited by the user,
 /// inside a surrounding box.
@@ -40,9 +63,108 @@ const TEXTBOX_MARGIN: Padding = Padding::horizontal(2.0);
 /// This is because `Textbox` largely serves as a wrapper around a [`TextArea`].
 pub struct Textbox {
     text: WidgetPod<TextArea<true>>,
+    /// The text shown before the editable text, which can't be edited or selected.
+    prefix: Option<WidgetPod<Label>>,
+    /// The text shown after the editable text, which can't be edited or selected.
+    suffix: Option<WidgetPod<Label>>,
 
     /// Whether to clip the contained text.
     clip: bool,
+    /// The margin on each horizontal side of the outline.
+    margin: f64,
+    /// The smallest width this textbox will be laid out at, including the margin.
+    min_width: f64,
+    /// The width this textbox will be laid out at when the available width is unbounded.
+    preferred_width: Option<f64>,
+    /// The smallest and largest font sizes to choose from so that the text fits on one line.
+    auto_fit: Option<(f32, f32)>,
+    /// The height of each line as a multiple of the font size, if it was set on this textbox
+    /// rather than on the text area it was created from.
+    line_height: Option<f32>,
+    /// The extra space after each character in logical pixels, if it was set on this textbox
+    /// rather than on the text area it was created from.
+    letter_spacing: Option<f32>,
+    /// The padding between the outline and the text, if it was set on this textbox
+    /// rather than on the text area it was created from.
+    padding: Option<Padding>,
+    /// The outline drawn around the text, if any.
+    border: Option<BorderStyle>,
+    /// The outline drawn instead of `border` whilst the text is focused, if any.
+    focus_border: Option<BorderStyle>,
+    /// The color filled in behind the text, if any.
+    background: Option<Color>,
+    /// The styles used whilst disabled, if they were set on this textbox
+    /// rather than left to the text area it was created from.
+    disabled_style: Option<DisabledStyle>,
+    /// Whether the disabled styles are used whilst disabled.
+    show_disabled: bool,
+    /// Whether the text area starts in overtype mode.
+    ///
+    /// The text area's mode can change afterwards if the user presses Insert.
+    overtype: bool,
+    /// Whether the Insert key toggles overtype mode.
+    allow_overtype_toggle: bool,
+    /// Whether to show a button which clears the text whilst there is any text.
+    clear_button: bool,
+    /// Whether the text was empty when last laid out.
+    text_is_empty: bool,
+    /// Where the clear button is, computed in layout.
+    clear_button_rect: Rect,
+}
+
+/// The outline drawn around a [`Textbox`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderStyle {
+    /// The color of the outline.
+    pub color: Color,
+    /// The width of the outline in logical pixels.
+    pub width: f64,
+    /// The radius of the outline's corners in logical pixels.
+    ///
+    /// If this is zero, the corners are square.
+    pub radius: f64,
+}
+
+impl BorderStyle {
+    /// The default outline of focused textboxes, a 2 logical pixel rectangle in the
+    /// [theme's accent color](theme::ACCENT_COLOR).
+    ///
+    /// It is thicker than the normal outline so that it stands out like a focus ring.
+    pub const FOCUS_RING: Self = Self {
+        color: theme::ACCENT_COLOR,
+        width: 2.0,
+        radius: 0.0,
+    };
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        DEFAULT_TEXTBOX_BORDER
+    }
+}
+
+/// The styles a [`Textbox`] is drawn with whilst it is disabled.
+///
+/// Each style which is `None` is left as the normal style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisabledStyle {
+    /// The outline drawn instead of the normal one.
+    pub border: Option<BorderStyle>,
+    /// The color filled in behind the text instead of the normal background.
+    pub background: Option<Color>,
+    /// The brush the text is painted with instead of the normal brush.
+    pub text_brush: Option<Brush>,
+}
+
+impl Default for DisabledStyle {
+    /// Only the text changes, to the [theme's disabled text color](theme::DISABLED_TEXT_COLOR).
+    fn default() -> Self {
+        Self {
+            border: None,
+            background: None,
+            text_brush: Some(theme::DISABLED_TEXT_COLOR.into()),
+        }
+    }
 }
 
 impl Textbox {
@@ -56,17 +178,69 @@ impl Textbox {
     /// Create a new `Textbox` from a styled text area.
     pub fn from_text_area(text: TextArea<true>) -> Self {
         let text = text.with_padding_if_default(TEXTBOX_PADDING);
-        Self {
-            text: WidgetPod::new(text),
-            clip: false,
-        }
+        Self::from_text_area_pod(WidgetPod::new(text))
     }
 
     /// Create a new `Textbox` from a styled text area in a [`WidgetPod`].
     ///
     /// Note that the default padding used for textbox will not apply.
     pub fn from_text_area_pod(text: WidgetPod<TextArea<true>>) -> Self {
-        Self { text, clip: false }
+        Self {
+            text,
+            prefix: None,
+            suffix: None,
+            clip: false,
+            margin: DEFAULT_TEXTBOX_MARGIN,
+            min_width: 0.,
+            preferred_width: None,
+            auto_fit: None,
+            line_height: None,
+            letter_spacing: None,
+            padding: None,
+            border: Some(DEFAULT_TEXTBOX_BORDER),
+            focus_border: Some(BorderStyle::FOCUS_RING),
+            background: None,
+            disabled_style: None,
+            show_disabled: true,
+            overtype: false,
+            allow_overtype_toggle: false,
+            clear_button: false,
+            text_is_empty: true,
+            clear_button_rect: Rect::ZERO,
+        }
+    }
+
+    /// Create a new `Textbox` in which the user can only enter the ASCII digits `0` to `9`.
+    ///
+    /// Other characters are removed from typed or pasted text.
+    /// See [`TextArea::with_allowed_chars`] for details.
+    pub fn numeric(text: &str) -> Self {
+        Self::from_text_area(
+            TextArea::new_editable(text).with_allowed_chars(|c| c.is_ascii_digit()),
+        )
+    }
+
+    /// Create a new `Textbox` in which the user can only enter ASCII characters.
+    ///
+    /// Other characters are removed from typed or pasted text.
+    /// See [`TextArea::with_allowed_chars`] for details.
+    pub fn ascii_only(text: &str) -> Self {
+        Self::from_text_area(TextArea::new_editable(text).with_allowed_chars(|c| c.is_ascii()))
+    }
+
+    /// Replace the selection with `text`, leaving the caret at the end of it.
+    ///
+    /// This is useful for inserting text from outside the textbox, such as from an
+    /// emoji picker. See [`TextArea::insert_at_cursor`] for details.
+    pub fn insert_at_cursor(this: &mut WidgetMut<'_, Self>, text: &str) -> bool {
+        TextArea::insert_at_cursor(&mut Self::text_mut(this), text)
+    }
+
+    /// Delete the selected text, returning it.
+    ///
+    /// See [`TextArea::delete_selection`] for details.
+    pub fn delete_selection(this: &mut WidgetMut<'_, Self>) -> String {
+        TextArea::delete_selection(&mut Self::text_mut(this))
     }
 
     /// Whether to clip the text to the drawn boundaries.
@@ -80,6 +254,249 @@ impl Textbox {
         self
     }
 
+    /// Show fixed text before the editable text, such as a currency symbol.
+    ///
+    /// The prefix is drawn inside the outline in a dimmer color than the text.
+    /// It can't be edited or selected, and the caret can't be placed in it.
+    ///
+    /// To modify this on active textbox, use [`set_prefix`](Self::set_prefix).
+    pub fn with_prefix(mut self, prefix: impl Into<ArcStr>) -> Self {
+        self.prefix = Some(adornment_label(prefix.into()));
+        self
+    }
+
+    /// Show fixed text after the editable text, such as a unit.
+    ///
+    /// The suffix is drawn inside the outline in a dimmer color than the text.
+    /// It can't be edited or selected, and the caret can't be placed in it.
+    ///
+    /// To modify this on active textbox, use [`set_suffix`](Self::set_suffix).
+    pub fn with_suffix(mut self, suffix: impl Into<ArcStr>) -> Self {
+        self.suffix = Some(adornment_label(suffix.into()));
+        self
+    }
+
+    /// Show a button at the trailing edge of the textbox which deletes all of the text,
+    /// as is common in search fields.
+    ///
+    /// The button is only shown whilst there is any text, but its space is always reserved
+    /// so that the text doesn't move when it appears.
+    /// Clearing the text is reported like any other edit, followed by [`Action::TextCleared`],
+    /// both from the [text area](Self::area_pod).
+    ///
+    /// To modify this on active textbox, use [`set_clear_button`](Self::set_clear_button).
+    pub fn with_clear_button(mut self, show_clear_button: bool) -> Self {
+        self.clear_button = show_clear_button;
+        self
+    }
+
+    /// Set the margin between each horizontal side of the outline and the edge of this widget.
+    ///
+    /// This defaults to 2 logical pixels, so that the outline is visible even when the
+    /// textbox is placed against the window edge.
+    /// It can be set to zero if a parent already provides padding.
+    ///
+    /// To modify this on active textbox, use [`set_margin`](Self::set_margin).
+    pub fn with_margin(mut self, margin: f64) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the minimum width of the textbox, including its margin.
+    ///
+    /// The textbox will be at least this wide even if its contents are shorter,
+    /// unless this is wider than the available space.
+    ///
+    /// To modify this on active textbox, use [`set_min_width`](Self::set_min_width).
+    pub fn with_min_width(mut self, min_width: f64) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Set the width of the textbox, including its margin, for when the available width is unbounded.
+    ///
+    /// If this is not set, the textbox will be 400 logical pixels wide in that case.
+    /// This has no effect when the available width is bounded.
+    ///
+    /// To modify this on active textbox, use [`set_preferred_width`](Self::set_preferred_width).
+    pub fn with_preferred_width(mut self, preferred_width: f64) -> Self {
+        self.preferred_width = Some(preferred_width);
+        self
+    }
+
+    /// Shrink the font size so that the text fits on one line, between `min_size` and `max_size`.
+    ///
+    /// This is useful for fixed-size fields showing text of varying lengths.
+    /// Auto-fit disables word wrapping.
+    /// See [`TextArea::with_auto_fit`] for details.
+    ///
+    /// To modify this on active textbox, use [`set_auto_fit`](Self::set_auto_fit).
+    pub fn with_auto_fit(mut self, min_size: f32, max_size: f32) -> Self {
+        self.auto_fit = Some((min_size, max_size));
+        self
+    }
+
+    /// Set the height of each line of text, as a multiple of the font size.
+    ///
+    /// This changes the height of the textbox when its text wraps onto several lines.
+    /// See [`TextArea::with_line_height`] for details.
+    ///
+    /// To modify this on active textbox, use [`set_line_height`](Self::set_line_height).
+    pub fn with_line_height(mut self, line_height: f32) -> Self {
+        self.line_height = Some(line_height);
+        self
+    }
+
+    /// Set the extra space added after each character, in logical pixels.
+    ///
+    /// Positive values spread the text out, and negative values pull it together.
+    /// See [`TextArea::with_letter_spacing`] for details.
+    ///
+    /// To modify this on active textbox, use [`set_letter_spacing`](Self::set_letter_spacing).
+    #[doc(alias = "tracking")]
+    pub fn with_letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = Some(letter_spacing);
+        self
+    }
+
+    /// Set the padding between the outline and the text.
+    ///
+    /// The padding can be different on each side.
+    /// Clicks in the padding move the caret to the nearest point in the text.
+    /// This defaults to 5 logical pixels on each side.
+    ///
+    /// To modify this on active textbox, use [`set_padding`](Self::set_padding).
+    pub fn with_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = Some(padding.into());
+        self
+    }
+
+    /// Set the outline drawn around the text, or `None` for no outline.
+    ///
+    /// This defaults to a 1 logical pixel white rectangle.
+    /// The outline is drawn inside the [margin](Self::with_margin).
+    ///
+    /// To modify this on active textbox, use [`set_border`](Self::set_border).
+    pub fn with_border(mut self, border: impl Into<Option<BorderStyle>>) -> Self {
+        self.border = border.into();
+        self
+    }
+
+    /// Set the outline drawn instead of the [normal one](Self::with_border) whilst
+    /// the text is focused.
+    ///
+    /// This defaults to [`BorderStyle::FOCUS_RING`].
+    /// If this is `None`, the normal outline is also drawn whilst focused.
+    ///
+    /// To modify this on active textbox, use [`set_focus_border`](Self::set_focus_border).
+    pub fn with_focus_border(mut self, focus_border: impl Into<Option<BorderStyle>>) -> Self {
+        self.focus_border = focus_border.into();
+        self
+    }
+
+    /// Set the color filled in behind the text, or `None` for a transparent background.
+    ///
+    /// The background covers the area inside the outline, with the same rounded corners.
+    ///
+    /// To modify this on active textbox, use [`set_background`](Self::set_background).
+    pub fn with_background(mut self, background: impl Into<Option<Color>>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Set the styles used whilst the textbox is [disabled](EventCtx::set_disabled).
+    ///
+    /// These replace the normal outline, background and text brush, which are restored
+    /// when the textbox is enabled again.
+    /// The outline is used whether or not the text is focused.
+    /// This defaults to [`DisabledStyle::default`], which only changes the text color.
+    /// Setting this replaces any [disabled brush](TextArea::with_disabled_brush) set on
+    /// the text area this was created from.
+    ///
+    /// To modify this on active textbox, use [`set_disabled_style`](Self::set_disabled_style).
+    pub fn with_disabled_style(mut self, disabled_style: DisabledStyle) -> Self {
+        self.disabled_style = Some(disabled_style);
+        self
+    }
+
+    /// Whether to use the [disabled styles](Self::with_disabled_style) whilst disabled.
+    ///
+    /// If this is false, the textbox looks the same whether or not it is disabled.
+    /// This defaults to true.
+    ///
+    /// To modify this on active textbox, use [`set_show_disabled`](Self::set_show_disabled).
+    pub fn with_show_disabled(mut self, show_disabled: bool) -> Self {
+        self.show_disabled = show_disabled;
+        self
+    }
+
+    /// Set whether the textbox starts in overtype mode, in which typed characters replace
+    /// the grapheme after the caret.
+    ///
+    /// See [`TextArea::with_overtype`] for details.
+    ///
+    /// To modify this on active textbox, use [`set_overtype`](Self::set_overtype).
+    pub fn with_overtype(mut self, overtype: bool) -> Self {
+        self.overtype = overtype;
+        self
+    }
+
+    /// Set whether the user can switch overtype mode on and off by pressing Insert.
+    ///
+    /// To modify this on active textbox, use [`set_overtype_toggle`](Self::set_overtype_toggle).
+    pub fn with_overtype_toggle(mut self, allow_overtype_toggle: bool) -> Self {
+        self.allow_overtype_toggle = allow_overtype_toggle;
+        self
+    }
+
+    /// The styles to use if the textbox is disabled, or `None` if it looks the same as normal.
+    fn active_disabled_style(&self, disabled: bool) -> Option<&DisabledStyle> {
+        if disabled && self.show_disabled {
+            self.disabled_style.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Whether the clear button is shown, depending on whether the textbox is disabled.
+    fn clear_button_visible(&self, disabled: bool) -> bool {
+        self.clear_button && !self.text_is_empty && !disabled
+    }
+
+    /// The outline to draw, depending on whether the text is focused and the textbox disabled.
+    fn current_border(&self, focused: bool, disabled: bool) -> Option<BorderStyle> {
+        if let Some(border) = self
+            .active_disabled_style(disabled)
+            .and_then(|style| style.border)
+        {
+            Some(border)
+        } else if focused {
+            self.focus_border.or(self.border)
+        } else {
+            self.border
+        }
+    }
+
+    /// The background to fill, depending on whether the textbox is disabled.
+    fn current_background(&self, disabled: bool) -> Option<Color> {
+        self.active_disabled_style(disabled)
+            .and_then(|style| style.background)
+            .or(self.background)
+    }
+
+    /// The disabled brush of the text area, which the text area itself applies.
+    ///
+    /// Unless disabled styles were set, this is the theme's disabled text color.
+    fn text_disabled_brush(&self, theme: &Theme) -> Option<Brush> {
+        if !self.show_disabled {
+            return None;
+        }
+        match &self.disabled_style {
+            Some(style) => style.text_brush.clone(),
+            None => Some(theme.disabled_text_color.into()),
+        }
+    }
+
     /// Read the underlying text area.
     ///
     /// Useful for getting its ID, as most actions from the textbox will be sent by the child.
@@ -88,6 +505,11 @@ impl Textbox {
     }
 }
 
+/// The label showing a prefix or suffix.
+fn adornment_label(text: ArcStr) -> WidgetPod<Label> {
+    WidgetPod::new(Label::new(text).with_brush(theme::PLACEHOLDER_COLOR))
+}
+
 // --- MARK: WIDGETMUT ---
 impl Textbox {
     /// Edit the underlying text area.
@@ -107,16 +529,202 @@ impl Textbox {
         this.widget.clip = clip;
         this.ctx.request_layout();
     }
+
+    /// Set the fixed text shown before the editable text, or `None` to remove it.
+    ///
+    /// The runtime equivalent of [`with_prefix`](Self::with_prefix).
+    pub fn set_prefix(this: &mut WidgetMut<'_, Self>, prefix: Option<ArcStr>) {
+        Self::set_adornment(&mut this.ctx, &mut this.widget.prefix, prefix);
+    }
+
+    /// Set the fixed text shown after the editable text, or `None` to remove it.
+    ///
+    /// The runtime equivalent of [`with_suffix`](Self::with_suffix).
+    pub fn set_suffix(this: &mut WidgetMut<'_, Self>, suffix: Option<ArcStr>) {
+        Self::set_adornment(&mut this.ctx, &mut this.widget.suffix, suffix);
+    }
+
+    fn set_adornment(
+        ctx: &mut MutateCtx<'_>,
+        adornment: &mut Option<WidgetPod<Label>>,
+        text: Option<ArcStr>,
+    ) {
+        match text {
+            Some(text) => {
+                if let Some(label) = adornment.as_mut() {
+                    Label::set_text(&mut ctx.get_mut(label), text);
+                } else {
+                    *adornment = Some(adornment_label(text));
+                    ctx.children_changed();
+                }
+            }
+            None => {
+                if let Some(label) = adornment.take() {
+                    ctx.remove_child(label);
+                }
+            }
+        }
+    }
+
+    /// Set whether to show a button which clears the text.
+    ///
+    /// The runtime equivalent of [`with_clear_button`](Self::with_clear_button).
+    pub fn set_clear_button(this: &mut WidgetMut<'_, Self>, show_clear_button: bool) {
+        this.widget.clear_button = show_clear_button;
+        this.ctx.request_layout();
+    }
+
+    /// Set the margin between each horizontal side of the outline and the edge of this widget.
+    ///
+    /// The runtime equivalent of [`with_margin`](Self::with_margin).
+    pub fn set_margin(this: &mut WidgetMut<'_, Self>, margin: f64) {
+        this.widget.margin = margin;
+        this.ctx.request_layout();
+    }
+
+    /// Set the minimum width of the textbox, including its margin.
+    ///
+    /// The runtime equivalent of [`with_min_width`](Self::with_min_width).
+    pub fn set_min_width(this: &mut WidgetMut<'_, Self>, min_width: f64) {
+        this.widget.min_width = min_width;
+        this.ctx.request_layout();
+    }
+
+    /// Set the width of the textbox for when the available width is unbounded.
+    ///
+    /// The runtime equivalent of [`with_preferred_width`](Self::with_preferred_width).
+    pub fn set_preferred_width(this: &mut WidgetMut<'_, Self>, preferred_width: Option<f64>) {
+        this.widget.preferred_width = preferred_width;
+        this.ctx.request_layout();
+    }
+
+    /// Shrink the font size so that the text fits on one line, between the given sizes.
+    ///
+    /// The runtime equivalent of [`with_auto_fit`](Self::with_auto_fit).
+    pub fn set_auto_fit(this: &mut WidgetMut<'_, Self>, auto_fit: Option<(f32, f32)>) {
+        this.widget.auto_fit = auto_fit;
+        TextArea::set_auto_fit(&mut Self::text_mut(this), auto_fit);
+    }
+
+    /// Set the height of each line of text, as a multiple of the font size.
+    ///
+    /// `None` restores the default of 1.2 times the font size.
+    ///
+    /// The runtime equivalent of [`with_line_height`](Self::with_line_height).
+    pub fn set_line_height(this: &mut WidgetMut<'_, Self>, line_height: Option<f32>) {
+        this.widget.line_height = line_height;
+        TextArea::set_line_height(&mut Self::text_mut(this), line_height);
+    }
+
+    /// Set the extra space added after each character, in logical pixels.
+    ///
+    /// The runtime equivalent of [`with_letter_spacing`](Self::with_letter_spacing).
+    pub fn set_letter_spacing(this: &mut WidgetMut<'_, Self>, letter_spacing: f32) {
+        this.widget.letter_spacing = Some(letter_spacing);
+        TextArea::set_letter_spacing(&mut Self::text_mut(this), letter_spacing);
+    }
+
+    /// Set the padding between the outline and the text.
+    ///
+    /// The runtime equivalent of [`with_padding`](Self::with_padding).
+    pub fn set_padding(this: &mut WidgetMut<'_, Self>, padding: impl Into<Padding>) {
+        let padding = padding.into();
+        this.widget.padding = Some(padding);
+        TextArea::set_padding(&mut Self::text_mut(this), padding);
+    }
+
+    /// Set the outline drawn around the text, or `None` for no outline.
+    ///
+    /// The runtime equivalent of [`with_border`](Self::with_border).
+    pub fn set_border(this: &mut WidgetMut<'_, Self>, border: impl Into<Option<BorderStyle>>) {
+        this.widget.border = border.into();
+        this.ctx.request_render();
+    }
+
+    /// Set the outline drawn instead of the normal one whilst the text is focused.
+    ///
+    /// The runtime equivalent of [`with_focus_border`](Self::with_focus_border).
+    pub fn set_focus_border(
+        this: &mut WidgetMut<'_, Self>,
+        focus_border: impl Into<Option<BorderStyle>>,
+    ) {
+        this.widget.focus_border = focus_border.into();
+        this.ctx.request_render();
+    }
+
+    /// Set the color filled in behind the text, or `None` for a transparent background.
+    ///
+    /// The runtime equivalent of [`with_background`](Self::with_background).
+    pub fn set_background(this: &mut WidgetMut<'_, Self>, background: impl Into<Option<Color>>) {
+        this.widget.background = background.into();
+        this.ctx.request_render();
+    }
+
+    /// Set the styles used whilst the textbox is disabled.
+    ///
+    /// The runtime equivalent of [`with_disabled_style`](Self::with_disabled_style).
+    pub fn set_disabled_style(this: &mut WidgetMut<'_, Self>, disabled_style: DisabledStyle) {
+        this.widget.disabled_style = Some(disabled_style);
+        let brush = this.widget.text_disabled_brush(this.ctx.theme());
+        TextArea::set_disabled_brush(&mut Self::text_mut(this), brush);
+        this.ctx.request_render();
+    }
+
+    /// Whether to use the disabled styles whilst disabled.
+    ///
+    /// The runtime equivalent of [`with_show_disabled`](Self::with_show_disabled).
+    pub fn set_show_disabled(this: &mut WidgetMut<'_, Self>, show_disabled: bool) {
+        this.widget.show_disabled = show_disabled;
+        let brush = this.widget.text_disabled_brush(this.ctx.theme());
+        TextArea::set_disabled_brush(&mut Self::text_mut(this), brush);
+        this.ctx.request_render();
+    }
+
+    /// Set whether the textbox is in overtype mode.
+    ///
+    /// The runtime equivalent of [`with_overtype`](Self::with_overtype).
+    pub fn set_overtype(this: &mut WidgetMut<'_, Self>, overtype: bool) {
+        this.widget.overtype = overtype;
+        TextArea::set_overtype(&mut Self::text_mut(this), overtype);
+    }
+
+    /// Set whether the user can switch overtype mode on and off by pressing Insert.
+    ///
+    /// The runtime equivalent of [`with_overtype_toggle`](Self::with_overtype_toggle).
+    pub fn set_overtype_toggle(this: &mut WidgetMut<'_, Self>, allow_overtype_toggle: bool) {
+        this.widget.allow_overtype_toggle = allow_overtype_toggle;
+        TextArea::set_overtype_toggle(&mut Self::text_mut(this), allow_overtype_toggle);
+    }
 }
 
 // --- MARK: IMPL WIDGET ---
 impl Widget for Textbox {
     fn on_pointer_event(
         &mut self,
-        _: &mut EventCtx,
+        ctx: &mut EventCtx,
         _props: &mut PropertiesMut<'_>,
-        _: &PointerEvent,
+        event: &PointerEvent,
     ) {
+        // Events in the text area's own bounds go to it, so this only gets clicks in
+        // the margin, on the prefix or suffix, or on the clear button.
+        let PointerEvent::PointerDown(PointerButton::Primary, state) = event else {
+            return;
+        };
+        if !self.clear_button_visible(ctx.is_disabled()) {
+            return;
+        }
+        let pos = Point::new(state.position.x, state.position.y);
+        let local_pos = pos - ctx.window_origin().to_vec2();
+        if self.clear_button_rect.contains(local_pos) {
+            // Clicking outside the text area would otherwise take focus away from it.
+            ctx.set_focus(self.text.id());
+            ctx.mutate_later(&mut self.text, |mut text| {
+                if TextArea::clear(&mut text) {
+                    text.ctx.submit_action(Action::TextCleared);
+                }
+            });
+            ctx.set_handled();
+        }
     }
 
     fn on_text_event(
@@ -137,9 +745,65 @@ impl Widget for Textbox {
 
     fn register_children(&mut self, ctx: &mut RegisterCtx) {
         ctx.register_child(&mut self.text);
+        for adornment in [&mut self.prefix, &mut self.suffix].into_iter().flatten() {
+            ctx.register_child(adornment);
+        }
     }
 
-    fn update(&mut self, _ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, _event: &Update) {}
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::ChildFocusChanged(_) | Update::DisabledChanged(_) = event {
+            // The outline and background depend on whether the text area is focused
+            // and whether the textbox is disabled.
+            ctx.request_render();
+        }
+        if let Update::WidgetAdded = event {
+            let set_disabled_brush = self.disabled_style.is_some() || !self.show_disabled;
+            let set_overtype = self.overtype || self.allow_overtype_toggle;
+            if self.auto_fit.is_some()
+                || self.line_height.is_some()
+                || self.letter_spacing.is_some()
+                || self.padding.is_some()
+                || set_disabled_brush
+                || set_overtype
+            {
+                let (auto_fit, line_height, padding) =
+                    (self.auto_fit, self.line_height, self.padding);
+                let letter_spacing = self.letter_spacing;
+                let (overtype, allow_overtype_toggle) = (self.overtype, self.allow_overtype_toggle);
+                let disabled_brush = self.text_disabled_brush(ctx.theme());
+                // The text area can't be reached before it is added to the tree.
+                ctx.mutate_later(&mut self.text, move |mut text| {
+                    if auto_fit.is_some() {
+                        TextArea::set_auto_fit(&mut text, auto_fit);
+                    }
+                    if line_height.is_some() {
+                        TextArea::set_line_height(&mut text, line_height);
+                    }
+                    if let Some(letter_spacing) = letter_spacing {
+                        TextArea::set_letter_spacing(&mut text, letter_spacing);
+                    }
+                    if let Some(padding) = padding {
+                        TextArea::set_padding(&mut text, padding);
+                    }
+                    if set_disabled_brush {
+                        TextArea::set_disabled_brush(&mut text, disabled_brush);
+                    }
+                    if set_overtype {
+                        TextArea::set_overtype(&mut text, overtype);
+                        TextArea::set_overtype_toggle(&mut text, allow_overtype_toggle);
+                    }
+                });
+            }
+        }
+        if let Update::ThemeChanged = event {
+            if self.disabled_style.is_none() && self.show_disabled {
+                let disabled_brush = self.text_disabled_brush(ctx.theme());
+                ctx.mutate_later(&mut self.text, move |mut text| {
+                    TextArea::set_disabled_brush(&mut text, disabled_brush);
+                });
+            }
+        }
+    }
 
     fn layout(
         &mut self,
@@ -147,35 +811,136 @@ impl Widget for Textbox {
         _props: &mut PropertiesMut<'_>,
         bc: &BoxConstraints,
     ) -> Size {
-        let margin = TEXTBOX_MARGIN;
+        let margin = Padding::horizontal(self.margin);
+        let (min_width, max_width) = if bc.is_width_bounded() {
+            (
+                self.min_width.max(bc.min().width).min(bc.max().width),
+                bc.max().width,
+            )
+        } else {
+            let preferred_width = self.preferred_width.unwrap_or(DEFAULT_PREFERRED_WIDTH);
+            let width = preferred_width.max(self.min_width).max(bc.min().width);
+            (width, width)
+        };
+        let bc = BoxConstraints::new(
+            Size::new(min_width, bc.min().height),
+            Size::new(max_width, bc.max().height),
+        );
+        // The prefix and suffix take their natural width, and the text gets the rest.
+        let adornment_bc =
+            BoxConstraints::new(Size::ZERO, Size::new(f64::INFINITY, bc.max().height));
+        let mut adornment_width = |adornment: &mut Option<WidgetPod<Label>>| {
+            adornment.as_mut().map_or(0.0, |label| {
+                ctx.run_layout(label, &adornment_bc).width + ADORNMENT_PADDING
+            })
+        };
+        let prefix_width = adornment_width(&mut self.prefix);
+        let suffix_width = adornment_width(&mut self.suffix);
+        let clear_button_width = if self.clear_button {
+            CLEAR_BUTTON_SIZE + ADORNMENT_PADDING
+        } else {
+            0.0
+        };
         // Shrink constraints by padding inset
-        let margin_size = Size::new(margin.leading + margin.trailing, margin.top + margin.bottom);
+        let margin_size = Size::new(
+            margin.leading + margin.trailing + prefix_width + suffix_width + clear_button_width,
+            margin.top + margin.bottom,
+        );
         let child_bc = bc.shrink(margin_size);
         // TODO: Set minimum to deal with alignment
         let size = ctx.run_layout(&mut self.text, &child_bc);
         // TODO: How do we handle RTL here?
-        ctx.place_child(&mut self.text, Point::new(margin.leading, margin.top));
+        let text_x = margin.leading + prefix_width;
+        ctx.place_child(&mut self.text, Point::new(text_x, margin.top));
+
+        // The prefix and suffix sit on the same baseline as the first line of text.
+        let baseline = margin.top + size.height - ctx.child_baseline_offset(&self.text);
+        let mut place_adornment = |adornment: &mut Option<WidgetPod<Label>>, x: f64| {
+            if let Some(label) = adornment {
+                let label_baseline =
+                    ctx.child_size(label).height - ctx.child_baseline_offset(label);
+                ctx.place_child(label, Point::new(x, baseline - label_baseline));
+            }
+        };
+        place_adornment(&mut self.prefix, margin.leading + ADORNMENT_PADDING);
+        place_adornment(&mut self.suffix, text_x + size.width);
+        // The clear button comes after the suffix, centred on the text vertically.
+        self.clear_button_rect = Rect::from_origin_size(
+            Point::new(
+                text_x + size.width + suffix_width,
+                margin.top + (size.height - CLEAR_BUTTON_SIZE) / 2.0,
+            ),
+            Size::new(CLEAR_BUTTON_SIZE, CLEAR_BUTTON_SIZE),
+        );
+        self.text_is_empty = ctx
+            .get_raw_ref(&mut self.text)
+            .widget()
+            .text()
+            .into_iter()
+            .all(str::is_empty);
+
         if self.clip {
-            ctx.set_clip_path(Rect::from_origin_size(Point::ORIGIN, size));
+            let clip_size = Size::new(
+                size.width + prefix_width + suffix_width + clear_button_width,
+                size.height,
+            );
+            ctx.set_clip_path(Rect::from_origin_size(Point::ORIGIN, clip_size));
         }
         size + margin_size
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
         let size = ctx.size();
+        let margin = Padding::horizontal(self.margin);
         let outline_rect = size.to_rect().inset(Insets::new(
-            -TEXTBOX_MARGIN.leading,
-            -TEXTBOX_MARGIN.top,
-            -TEXTBOX_MARGIN.trailing,
-            -TEXTBOX_MARGIN.bottom,
+            -margin.leading,
+            -margin.top,
+            -margin.trailing,
+            -margin.bottom,
         ));
-        scene.stroke(
-            &Stroke::new(1.0),
-            Affine::IDENTITY,
-            Color::WHITE,
-            None,
-            &outline_rect,
-        );
+        let disabled = ctx.is_disabled();
+        let border = self.current_border(ctx.has_focus_target(), disabled);
+        let radius = border.map_or(0.0, |border| border.radius);
+        let outline = outline_rect.to_rounded_rect(radius);
+
+        if let Some(background) = self.current_background(disabled) {
+            scene.fill(Fill::NonZero, Affine::IDENTITY, background, None, &outline);
+        }
+        if let Some(border) = border {
+            if radius > 0.0 {
+                scene.stroke(
+                    &Stroke::new(border.width),
+                    Affine::IDENTITY,
+                    border.color,
+                    None,
+                    &outline,
+                );
+            } else {
+                scene.stroke(
+                    &Stroke::new(border.width),
+                    Affine::IDENTITY,
+                    border.color,
+                    None,
+                    &outline_rect,
+                );
+            }
+        }
+        if self.clear_button_visible(disabled) {
+            let cross = self.clear_button_rect.inset(-3.0);
+            let stroke = Stroke::new(1.5);
+            for line in [
+                Line::new((cross.x0, cross.y0), (cross.x1, cross.y1)),
+                Line::new((cross.x0, cross.y1), (cross.x1, cross.y0)),
+            ] {
+                scene.stroke(
+                    &stroke,
+                    Affine::IDENTITY,
+                    theme::PLACEHOLDER_COLOR,
+                    None,
+                    &line,
+                );
+            }
+        }
     }
 
     fn accessibility_role(&self) -> Role {
@@ -191,7 +956,9 @@ impl Widget for Textbox {
     }
 
     fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
-        smallvec![self.text.id()]
+        let prefix = self.prefix.iter().map(WidgetPod::id);
+        let suffix = self.suffix.iter().map(WidgetPod::id);
+        prefix.chain([self.text.id()]).chain(suffix).collect()
     }
 
     fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
@@ -206,19 +973,35 @@ impl Widget for Textbox {
 // TODO - Add more tests
 #[cfg(test)]
 mod tests {
+    use parley::GenericFamily;
+    use smallvec::smallvec;
     use vello::kurbo::Size;
+    use winit::event::Ime;
 
     use super::*;
-    use crate::assert_render_snapshot;
     use crate::core::StyleProperty;
-    use crate::testing::TestHarness;
+    use crate::testing::{InputFuzzer, ModularWidget, TestHarness, widget_ids};
     use crate::widgets::TextArea;
+    use crate::{assert_render_snapshot, palette};
+
+    /// The id of the text area inside the harness's root `Textbox`.
+    fn area_id(harness: &TestHarness) -> WidgetId {
+        harness
+            .root_widget()
+            .downcast::<Textbox>()
+            .unwrap()
+            .area_pod()
+            .id()
+    }
 
     #[test]
     fn textbox_outline() {
+        // The focus border is covered by `focus_border_when_focused`, so that the selection
+        // snapshot below only shows the selection.
         let textbox = Textbox::from_text_area(
             TextArea::new_editable("Textbox contents").with_style(StyleProperty::FontSize(10.0)),
-        );
+        )
+        .with_focus_border(None);
         let mut harness = TestHarness::create_with_size(textbox, Size::new(150.0, 20.0));
 
         assert_render_snapshot!(harness, "textbox_outline");
@@ -235,4 +1018,589 @@ mod tests {
 
         assert_render_snapshot!(harness, "textbox_selection");
     }
+
+    /// The size of `textbox` when laid out with the given constraints.
+    fn layout_size(textbox: Textbox, bc: BoxConstraints) -> Size {
+        let [textbox_id] = widget_ids();
+        let parent = ModularWidget::new(WidgetPod::new_with_id(textbox, textbox_id))
+            .register_children_fn(|child, ctx| {
+                ctx.register_child(child);
+            })
+            .layout_fn(move |child, ctx, _, _| {
+                ctx.run_layout(child, &bc);
+                ctx.place_child(child, Point::ZERO);
+                Size::new(400.0, 400.0)
+            })
+            .children_fn(|child| smallvec![child.id()]);
+        let harness = TestHarness::create_with_size(parent, Size::new(400.0, 400.0));
+        harness.get_widget(textbox_id).ctx().size()
+    }
+
+    fn sized_textbox() -> Textbox {
+        Textbox::new("Text")
+            .with_min_width(200.0)
+            .with_preferred_width(300.0)
+    }
+
+    #[test]
+    fn textbox_width_bounded() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(350.0, 100.0));
+        // The preferred width doesn't apply when the width is bounded, but the minimum width does.
+        assert_eq!(layout_size(sized_textbox(), bc).width, 200.0);
+
+        // The minimum width is limited to the available width.
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(150.0, 100.0));
+        assert_eq!(layout_size(sized_textbox(), bc).width, 150.0);
+    }
+
+    #[test]
+    fn textbox_width_unbounded() {
+        let bc = BoxConstraints::UNBOUNDED;
+        assert_eq!(layout_size(sized_textbox(), bc).width, 300.0);
+        assert_eq!(
+            layout_size(sized_textbox().with_min_width(320.0), bc).width,
+            320.0
+        );
+
+        // Without a preferred width, the textbox falls back to a fixed width.
+        assert_eq!(
+            layout_size(Textbox::new("Text"), bc).width,
+            DEFAULT_PREFERRED_WIDTH
+        );
+        assert_eq!(
+            layout_size(Textbox::new("Text").with_min_width(450.0), bc).width,
+            450.0
+        );
+    }
+
+    #[test]
+    fn textbox_margin() {
+        // With loose constraints, the textbox is as wide as its contents (plus the margin).
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(350.0, 100.0));
+        let content_size = layout_size(Textbox::new("Text"), bc);
+        let no_margin_size = layout_size(Textbox::new("Text").with_margin(0.0), bc);
+        assert_eq!(
+            no_margin_size.width,
+            content_size.width - 2.0 * DEFAULT_TEXTBOX_MARGIN
+        );
+    }
+
+    #[test]
+    fn auto_fit_shrinks_long_text() {
+        let textbox =
+            Textbox::new("A string which is far too long to fit").with_auto_fit(4.0, 20.0);
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(100.0, 40.0));
+        let area_id = area_id(&harness);
+        let fitted_size = |harness: &TestHarness| {
+            harness
+                .get_widget(area_id)
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .fitted_font_size()
+                .unwrap()
+        };
+        let size = fitted_size(&harness);
+        assert!(size < 20.0);
+        assert!(size >= 4.0);
+
+        // Short text is shown at the largest size.
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            TextArea::reset_text(&mut Textbox::text_mut(&mut textbox), "Hi");
+        });
+        assert_eq!(fitted_size(&harness), 20.0);
+    }
+
+    #[test]
+    fn line_height_scales_wrapped_text() {
+        let text_height = |line_height: f32| {
+            let textbox = Textbox::new("String which will wrap").with_line_height(line_height);
+            let harness = TestHarness::create_with_size(textbox, Size::new(100.0, 400.0));
+            let area_id = area_id(&harness);
+            let area = harness.get_widget(area_id);
+            let metrics = area
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .line_metrics()
+                .unwrap();
+            assert!(metrics.len() > 1, "the text should wrap");
+            let last = metrics.last().unwrap();
+            last.top + last.height - metrics[0].top
+        };
+
+        let single = text_height(1.0);
+        let double = text_height(2.0);
+        assert!(
+            (double / single - 2.0).abs() < 0.1,
+            "doubling the line height should double the text height, got {single} and {double}"
+        );
+    }
+
+    #[test]
+    fn letter_spacing_widens_text() {
+        let bc = BoxConstraints::new(Size::ZERO, Size::new(350.0, 100.0));
+        let plain = layout_size(Textbox::new("Heading"), bc);
+        let unspaced = layout_size(Textbox::new("Heading").with_letter_spacing(0.0), bc);
+        let spaced = layout_size(Textbox::new("Heading").with_letter_spacing(4.0), bc);
+        assert_eq!(plain, unspaced);
+        assert!(
+            spaced.width > unspaced.width,
+            "letter spacing should widen the text, got {} and {}",
+            unspaced.width,
+            spaced.width
+        );
+        assert_eq!(spaced.height, unspaced.height);
+    }
+
+    #[test]
+    fn ime_area_follows_caret() {
+        let mut harness = TestHarness::create_with_size(Textbox::new(""), Size::new(200.0, 40.0));
+        let area_id = area_id(&harness);
+
+        // The area is reported as soon as the textbox is focused, before any input.
+        harness.focus_on(Some(area_id));
+        assert!(harness.has_ime_session());
+        let (start, _) = harness.ime_rect();
+        // The area is in window coordinates, so includes the margin and padding.
+        assert!(start.x >= DEFAULT_TEXTBOX_MARGIN);
+        assert!(start.y >= TEXTBOX_PADDING.top);
+
+        harness.keyboard_type_chars("hello");
+        let (after_typing, _) = harness.ime_rect();
+        assert!(after_typing.x > start.x);
+
+        // Moving the caret programmatically also moves the area.
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            TextArea::select_byte_range(&mut Textbox::text_mut(&mut textbox), 0, 0);
+        });
+        assert_eq!(harness.ime_rect().0, start);
+    }
+
+    #[test]
+    fn custom_padding_offsets_pointer() {
+        let textbox = Textbox::new("hello")
+            .with_margin(0.0)
+            .with_padding(Padding::new(10.0, 0.0, 0.0, 60.0));
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(300.0, 60.0));
+        let area_id = area_id(&harness);
+        let caret_x = |harness: &TestHarness| {
+            harness
+                .get_widget(area_id)
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .caret_rect()
+                .x0
+        };
+
+        // A click in the leading padding places the caret at the start of the text,
+        // which begins after the padding.
+        harness.mouse_move((30.0, 15.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        let start_x = caret_x(&harness);
+        assert!((start_x - 60.0).abs() < 2.0, "{start_x}");
+
+        // With the default padding, the same text would have ended before this point.
+        harness.mouse_move((62.0, 15.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert!(caret_x(&harness) < 65.0);
+    }
+
+    #[test]
+    fn prefix_offsets_text() {
+        let textbox = Textbox::new("100")
+            .with_margin(0.0)
+            .with_prefix("$")
+            .with_suffix("kg");
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(200.0, 40.0));
+        let (area_id, prefix_id, suffix_id) = {
+            let textbox = harness.root_widget();
+            let textbox = textbox.downcast::<Textbox>().unwrap();
+            (
+                textbox.area_pod().id(),
+                textbox.prefix.as_ref().unwrap().id(),
+                textbox.suffix.as_ref().unwrap().id(),
+            )
+        };
+        let area_rect = |harness: &TestHarness| {
+            let area = harness.get_widget(area_id);
+            Rect::from_origin_size(area.ctx().window_origin(), area.ctx().size())
+        };
+
+        // The editable text starts after the prefix, and the suffix fills the rest of the box.
+        let prefix_width = harness.get_widget(prefix_id).ctx().size().width;
+        let area = area_rect(&harness);
+        assert_eq!(area.x0, ADORNMENT_PADDING + prefix_width);
+        let suffix = harness.get_widget(suffix_id);
+        assert_eq!(suffix.ctx().window_origin().x, area.x1);
+        let suffix_end = area.x1 + suffix.ctx().size().width + ADORNMENT_PADDING;
+        let width = harness.root_widget().ctx().size().width;
+        assert!(
+            (suffix_end - width).abs() < 1e-6,
+            "the suffix should end at the outline, got {suffix_end} and {width}"
+        );
+
+        let click = |harness: &mut TestHarness, x: f64| {
+            harness.mouse_move((x, 15.0));
+            harness.mouse_button_press(PointerButton::Primary);
+            harness.mouse_button_release(PointerButton::Primary);
+        };
+        let selection = |harness: &TestHarness| {
+            let area = harness.get_widget(area_id);
+            area.downcast::<TextArea<true>>().unwrap().selection_range()
+        };
+        // Clicks are measured from the start of the editable text, not the start of the box.
+        click(&mut harness, area.x0 + TEXTBOX_PADDING.leading + 1.0);
+        assert_eq!(harness.focused_widget_id(), Some(area_id));
+        assert_eq!(selection(&harness), 0..0);
+        click(&mut harness, area.x1 - 1.0);
+        assert_eq!(selection(&harness), 3..3);
+
+        // Clicking the prefix doesn't move the caret into it.
+        click(&mut harness, ADORNMENT_PADDING + prefix_width / 2.0);
+        assert_eq!(selection(&harness), 3..3);
+
+        harness.edit_root_widget(|mut textbox| {
+            Textbox::set_prefix(&mut textbox.downcast::<Textbox>(), None);
+        });
+        assert_eq!(area_rect(&harness).x0, 0.0);
+    }
+
+    #[test]
+    fn clear_button() {
+        let textbox = Textbox::new("hello")
+            .with_margin(0.0)
+            .with_clear_button(true);
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(200.0, 40.0));
+        let area_id = area_id(&harness);
+        let button_rect = |harness: &TestHarness| {
+            let textbox = harness.root_widget();
+            let textbox = textbox.downcast::<Textbox>().unwrap();
+            textbox.clear_button_rect
+        };
+        let click = |harness: &mut TestHarness, pos: Point| {
+            harness.mouse_move(pos);
+            harness.mouse_button_press(PointerButton::Primary);
+            harness.mouse_button_release(PointerButton::Primary);
+        };
+
+        // The button is after the text area, so clicks in the text still place the caret.
+        let button = button_rect(&harness);
+        let area = harness.get_widget(area_id);
+        assert!(area.ctx().window_origin().x + area.ctx().size().width <= button.x0);
+        click(
+            &mut harness,
+            Point::new(TEXTBOX_PADDING.leading + 1.0, 15.0),
+        );
+        assert_eq!(harness.focused_widget_id(), Some(area_id));
+        let area = harness.get_widget(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert_eq!(area.selection_range(), 0..0);
+        while harness.pop_action().is_some() {}
+
+        click(&mut harness, button.center());
+        let area = harness.get_widget(area_id);
+        assert_eq!(area.downcast::<TextArea<true>>().unwrap().text(), "");
+        assert_eq!(
+            harness.focused_widget_id(),
+            Some(area_id),
+            "clearing the text shouldn't take focus away from it"
+        );
+        let actions: Vec<_> = std::iter::from_fn(|| harness.pop_action())
+            .map(|(action, _)| action)
+            .collect();
+        assert!(actions.contains(&Action::TextChanged(String::new())));
+        assert!(actions.contains(&Action::TextCleared));
+
+        // Once the text is empty, the button is hidden and clicking where it was does nothing.
+        assert!(
+            !harness
+                .root_widget()
+                .downcast::<Textbox>()
+                .unwrap()
+                .clear_button_visible(false)
+        );
+        click(&mut harness, button.center());
+        assert_eq!(harness.pop_action(), None);
+    }
+
+    #[test]
+    fn border_and_background() {
+        let textbox = Textbox::new("hello");
+        assert_eq!(textbox.border, Some(BorderStyle::default()));
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(150.0, 30.0));
+
+        let rounded = BorderStyle {
+            color: palette::css::BLUE,
+            width: 2.0,
+            radius: 6.0,
+        };
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            Textbox::set_border(&mut textbox, rounded);
+            Textbox::set_background(&mut textbox, palette::css::BLACK);
+        });
+        {
+            let textbox = harness.root_widget().downcast::<Textbox>().unwrap();
+            assert_eq!(textbox.border, Some(rounded));
+            assert_eq!(textbox.background, Some(palette::css::BLACK));
+        }
+        let _ = harness.render();
+
+        // The outline can be removed entirely.
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            Textbox::set_border(&mut textbox, None);
+        });
+        assert_eq!(
+            harness.root_widget().downcast::<Textbox>().unwrap().border,
+            None
+        );
+        let _ = harness.render();
+    }
+
+    #[test]
+    fn focus_border_when_focused() {
+        let textbox = Textbox::new("hello").with_margin(4.0);
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(150.0, 30.0));
+        let area_id = area_id(&harness);
+
+        let unfocused = harness.render();
+        harness.focus_on(Some(area_id));
+        assert!(harness.root_widget().ctx().has_focus_target());
+        let focused = harness.render();
+
+        let textbox = harness.root_widget().downcast::<Textbox>().unwrap();
+        assert_ne!(
+            textbox.current_border(true, false),
+            textbox.current_border(false, false)
+        );
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel,
+        // so the images can't differ.
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            // The focus border is wider than the normal one, and in a different color.
+            assert!(unfocused.get_pixel(3, 15) != focused.get_pixel(3, 15));
+        }
+    }
+
+    #[test]
+    fn disabled_style_when_disabled() {
+        let disabled_border = BorderStyle {
+            color: palette::css::GRAY,
+            width: 1.0,
+            radius: 4.0,
+        };
+        let textbox = Textbox::new("hello")
+            .with_background(palette::css::BLACK)
+            .with_disabled_style(DisabledStyle {
+                border: Some(disabled_border),
+                background: Some(palette::css::DARK_GRAY),
+                ..Default::default()
+            });
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(150.0, 30.0));
+        let current_style = |harness: &TestHarness| {
+            let textbox = harness.root_widget();
+            let disabled = textbox.ctx().is_disabled();
+            let textbox = textbox.downcast::<Textbox>().unwrap();
+            (
+                textbox.current_border(false, disabled),
+                textbox.current_background(disabled),
+            )
+        };
+        let normal_style = (Some(BorderStyle::default()), Some(palette::css::BLACK));
+        let disabled_style = (Some(disabled_border), Some(palette::css::DARK_GRAY));
+        assert_eq!(current_style(&harness), normal_style);
+        let enabled = harness.render();
+
+        harness.edit_root_widget(|mut textbox| textbox.ctx.set_disabled(true));
+        assert_eq!(current_style(&harness), disabled_style);
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel,
+        // so the images can't differ.
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            // We don't use assert_ne because we don't want rich assert
+            assert!(harness.render() != enabled);
+        }
+
+        // The normal styles are restored when enabled again.
+        harness.edit_root_widget(|mut textbox| textbox.ctx.set_disabled(false));
+        assert_eq!(current_style(&harness), normal_style);
+        assert!(harness.render() == enabled);
+
+        // Without `show_disabled`, the textbox looks the same whilst disabled.
+        harness.edit_root_widget(|mut textbox| {
+            textbox.ctx.set_disabled(true);
+            Textbox::set_show_disabled(&mut textbox.downcast::<Textbox>(), false);
+        });
+        assert_eq!(current_style(&harness), normal_style);
+        assert!(harness.render() == enabled);
+    }
+
+    #[test]
+    fn numeric_filters_input() {
+        let mut harness = TestHarness::create(Textbox::numeric(""));
+        let area_id = area_id(&harness);
+        harness.focus_on(Some(area_id));
+
+        // A paste is inserted in one go, in the same way as an IME commit.
+        harness.process_text_event(TextEvent::Ime(Ime::Commit("a1b2c3".into())));
+        let text = |harness: &TestHarness| {
+            harness
+                .get_widget(area_id)
+                .downcast::<TextArea<true>>()
+                .unwrap()
+                .text()
+                .to_string()
+        };
+        assert_eq!(text(&harness), "123");
+
+        // Input with no allowed characters is ignored.
+        harness.keyboard_type_chars("x");
+        assert_eq!(text(&harness), "123");
+        harness.keyboard_type_chars("4");
+        assert_eq!(text(&harness), "1234");
+    }
+
+    #[test]
+    fn fuzz_input() {
+        let textbox = Textbox::new("Textbox contents");
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(150.0, 40.0));
+
+        InputFuzzer::new(0x7E57_B0C5).run(&mut harness);
+    }
+
+    #[test]
+    fn overtype_set_on_text_area() {
+        let textbox = Textbox::new("hello").with_overtype(true);
+        let mut harness = TestHarness::create(textbox);
+        let area_id = area_id(&harness);
+        let is_overtype = |harness: &TestHarness| {
+            let area = harness.get_widget(area_id);
+            area.downcast::<TextArea<true>>().unwrap().is_overtype()
+        };
+        assert!(is_overtype(&harness));
+
+        harness.edit_root_widget(|mut textbox| {
+            Textbox::set_overtype(&mut textbox.downcast::<Textbox>(), false);
+        });
+        assert!(!is_overtype(&harness));
+    }
+
+    #[test]
+    fn insert_at_cursor() {
+        let mut harness = TestHarness::create(Textbox::new("hello world"));
+        let area_id = area_id(&harness);
+        let area_state = |harness: &TestHarness| {
+            let area = harness.get_widget(area_id);
+            let area = area.downcast::<TextArea<true>>().unwrap();
+            (area.text().to_string(), area.selection_range())
+        };
+
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            TextArea::select_byte_range(&mut Textbox::text_mut(&mut textbox), 5, 5);
+            assert!(Textbox::insert_at_cursor(&mut textbox, "👍"));
+        });
+        // The caret is after the four bytes of the emoji.
+        assert_eq!(area_state(&harness), ("hello👍 world".into(), 9..9));
+        let actions: Vec<_> = std::iter::from_fn(|| harness.pop_action())
+            .map(|(action, _)| action)
+            .collect();
+        assert!(actions.contains(&Action::TextChanged("hello👍 world".into())));
+
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            TextArea::select_byte_range(&mut Textbox::text_mut(&mut textbox), 0, 9);
+            assert_eq!(Textbox::delete_selection(&mut textbox), "hello👍");
+            // Nothing is selected any more.
+            assert_eq!(Textbox::delete_selection(&mut textbox), "");
+        });
+        assert_eq!(area_state(&harness), (" world".into(), 0..0));
+    }
+
+    #[test]
+    fn construction_builds_layout() {
+        let mut harness = TestHarness::create(Textbox::new("hello"));
+        let area_id = area_id(&harness);
+        let area = harness.get_widget(area_id);
+        let area = area.downcast::<TextArea<true>>().unwrap();
+        assert!(
+            !area.needs_rebuild(),
+            "the layout pass should build the text layout"
+        );
+        assert_eq!(area.line_count(), Some(1));
+
+        // A text area which isn't in the widget tree is only laid out when asked to be.
+        let mut area = TextArea::new_editable("hello\nworld");
+        assert!(area.needs_rebuild());
+        assert_eq!(area.line_count(), None);
+        harness.with_text_contexts(|fctx, lctx| area.rebuild_layout(fctx, lctx));
+        assert!(!area.needs_rebuild());
+        assert_eq!(area.line_count(), Some(2));
+    }
+
+    #[test]
+    fn set_text_invalidates_layout() {
+        let textbox = Textbox::new("hello");
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(200.0, 100.0));
+        let area_id = area_id(&harness);
+        let height = |harness: &TestHarness| harness.get_widget(area_id).ctx().size().height;
+        let one_line_height = height(&harness);
+
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            let mut area = Textbox::text_mut(&mut textbox);
+            TextArea::reset_text(&mut area, "hello\nworld");
+            assert!(
+                area.ctx.widget_state.needs_layout,
+                "changing the text should request layout"
+            );
+        });
+        {
+            let area = harness.get_widget(area_id);
+            let area = area.downcast::<TextArea<true>>().unwrap();
+            assert!(!area.needs_rebuild());
+            assert_eq!(area.line_count(), Some(2));
+        }
+        assert!(height(&harness) > one_line_height);
+        // Painting with an out of date layout would panic in debug builds.
+        let _ = harness.render();
+    }
+
+    #[test]
+    fn set_font_invalidates_layout() {
+        let textbox = Textbox::new("hello");
+        let mut harness = TestHarness::create_with_size(textbox, Size::new(200.0, 100.0));
+        let area_id = area_id(&harness);
+        let height = |harness: &TestHarness| harness.get_widget(area_id).ctx().size().height;
+        let small_height = height(&harness);
+
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            let mut area = Textbox::text_mut(&mut textbox);
+            TextArea::insert_style(&mut area, StyleProperty::FontSize(40.0));
+            assert!(
+                area.widget.needs_rebuild(),
+                "changing the font size should invalidate the text layout"
+            );
+            assert!(area.ctx.widget_state.needs_layout);
+        });
+        let area = harness.get_widget(area_id);
+        assert!(!area.downcast::<TextArea<true>>().unwrap().needs_rebuild());
+        assert!(height(&harness) > small_height);
+
+        harness.edit_root_widget(|mut textbox| {
+            let mut textbox = textbox.downcast::<Textbox>();
+            let mut area = Textbox::text_mut(&mut textbox);
+            TextArea::set_font_fallbacks(&mut area, vec![GenericFamily::Monospace.into()]);
+            assert!(
+                area.widget.needs_rebuild(),
+                "changing the font family should invalidate the text layout"
+            );
+        });
+        let area = harness.get_widget(area_id);
+        assert!(!area.downcast::<TextArea<true>>().unwrap().needs_rebuild());
+        let _ = harness.render();
+    }
 }
@@ -0,0 +1,239 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which sets the theme of its child.
+
+use std::sync::Arc;
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+use crate::theme::Theme;
+
+/// A widget which sets the [`Theme`] of its child and all of the child's descendants,
+/// except those inside another `Themed`.
+///
+/// Changing the theme restyles the existing widgets, rather than recreating them.
+/// See [`EventCtx::theme`] for more details.
+pub struct Themed<W: ?Sized> {
+    child: WidgetPod<W>,
+    theme: Arc<Theme>,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Themed<W> {
+    /// Create a new `Themed` around `child`.
+    pub fn new(child: W, theme: impl Into<Arc<Theme>>) -> Self {
+        Self::from_pod(WidgetPod::new(child), theme)
+    }
+}
+
+impl<W: Widget + ?Sized> Themed<W> {
+    /// Create a new `Themed` around the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>, theme: impl Into<Arc<Theme>>) -> Self {
+        Self {
+            child,
+            theme: theme.into(),
+        }
+    }
+
+    /// The theme of the child.
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Themed<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Change the theme of the child.
+    pub fn set_theme(this: &mut WidgetMut<'_, Self>, theme: impl Into<Arc<Theme>>) {
+        this.widget.theme = theme.into();
+        this.ctx.set_theme(Some(this.widget.theme.clone()));
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Themed<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        // The theme of this widget is inherited by all of its descendants.
+        if let Update::WidgetAdded = event {
+            ctx.set_theme(Some(self.theme.clone()));
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Themed", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use winit::window::Theme as SystemTheme;
+
+    use super::*;
+    use crate::core::{Action, PointerButton, WindowEvent};
+    use crate::testing::{Record, Recording, TestHarness, TestWidgetExt, widget_ids};
+    use crate::widgets::{Button, Flex, Label, SizedBox};
+
+    fn theme_changes(recording: &Recording) -> usize {
+        recording
+            .drain()
+            .into_iter()
+            .filter(|record| matches!(record, Record::U(Update::ThemeChanged)))
+            .count()
+    }
+
+    #[test]
+    fn theme_applies_to_subtree() {
+        let [inside, outside] = widget_ids();
+        let widget = Flex::column()
+            .with_child(Themed::new(
+                Label::new("Inside").with_id(inside),
+                Theme::light(),
+            ))
+            .with_child(Label::new("Outside").with_id(outside));
+        let harness = TestHarness::create(widget);
+
+        let text_color = |id| harness.get_widget(id).ctx().theme().text_color;
+        assert_eq!(text_color(inside), Theme::light().text_color);
+        assert_eq!(text_color(outside), Theme::dark().text_color);
+    }
+
+    #[test]
+    fn switch_theme_without_recreating_widgets() {
+        let [button_id] = widget_ids();
+        let recording = Recording::default();
+        let button = Button::new("Save").record(&recording).with_id(button_id);
+        let mut harness = TestHarness::create(Themed::new(button, Theme::dark()));
+        // The default theme is also dark, but it is a different theme value.
+        assert_eq!(theme_changes(&recording), 1);
+        let set_theme = |harness: &mut TestHarness, theme: Theme| {
+            harness.edit_root_widget(|mut themed| {
+                let mut themed = themed.downcast::<Themed<SizedBox>>();
+                Themed::set_theme(&mut themed, theme);
+            });
+        };
+
+        let dark = harness.render();
+        set_theme(&mut harness, Theme::light());
+        assert_eq!(theme_changes(&recording), 1);
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            assert!(harness.render() != dark, "The button should be restyled");
+        }
+        set_theme(&mut harness, Theme::dark());
+        assert!(
+            harness.render() == dark,
+            "The original style should be restored"
+        );
+
+        // The button stays pressed across a theme change, so it wasn't recreated.
+        harness.mouse_move_to(button_id);
+        harness.mouse_button_press(PointerButton::Primary);
+        set_theme(&mut harness, Theme::light());
+        assert_eq!(harness.pointer_capture_target_id(), Some(button_id));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::ButtonPressed(PointerButton::Primary), button_id))
+        );
+    }
+
+    #[test]
+    fn system_theme_change_reaches_every_widget() {
+        let [label_id] = widget_ids();
+        let recording = Recording::default();
+        let label = Label::new("Inside").record(&recording).with_id(label_id);
+        let mut harness = TestHarness::create(Themed::new(label, Theme::light()));
+        let _ = recording.drain();
+        assert_eq!(
+            harness.get_widget(label_id).ctx().system_theme(),
+            SystemTheme::Dark
+        );
+
+        // The label's theme is overridden, but it might still follow the system theme.
+        harness.process_window_event(WindowEvent::SystemThemeChanged(SystemTheme::Light));
+        assert_eq!(theme_changes(&recording), 1);
+        assert_eq!(
+            harness.get_widget(label_id).ctx().system_theme(),
+            SystemTheme::Light
+        );
+
+        // Reporting the same preference again does nothing.
+        harness.process_window_event(WindowEvent::SystemThemeChanged(SystemTheme::Light));
+        assert_eq!(theme_changes(&recording), 0);
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which reports animation frames.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+
+/// A widget which submits an [`Action::AnimationFrame`] for each animation frame
+/// whilst it is active.
+///
+/// This is for driving animations from outside of the widget tree, such as in Xilem's views,
+/// whilst otherwise behaving like its child.
+pub struct Ticker<W: ?Sized> {
+    child: WidgetPod<W>,
+    active: bool,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Ticker<W> {
+    /// Create a new inactive `Ticker` around `child`.
+    pub fn new(child: W) -> Self {
+        Self::from_pod(WidgetPod::new(child))
+    }
+}
+
+impl<W: Widget + ?Sized> Ticker<W> {
+    /// Create a new inactive `Ticker` around the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>) -> Self {
+        Self {
+            child,
+            active: false,
+        }
+    }
+
+    /// Builder-style method for setting whether the ticker submits animation frames.
+    pub fn with_active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Whether the ticker submits animation frames.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Ticker<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set whether the ticker submits animation frames.
+    pub fn set_active(this: &mut WidgetMut<'_, Self>, active: bool) {
+        if active && !this.widget.active {
+            this.ctx.request_anim_frame();
+        }
+        this.widget.active = active;
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Ticker<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        if self.active {
+            ctx.submit_action(Action::AnimationFrame(interval));
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            if self.active {
+                ctx.request_anim_frame();
+            }
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let insets = ctx.compute_insets_from_child(&self.child, size);
+        ctx.set_paint_insets(insets);
+        ctx.set_baseline_offset(ctx.child_baseline_offset(&self.child));
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Ticker", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Flex, Label};
+
+    #[test]
+    fn submits_frames_whilst_active() {
+        let [ticker_id] = widget_ids();
+        let widget = Flex::column().with_child_id(Ticker::new(Label::new("Hello")), ticker_id);
+
+        let mut harness = TestHarness::create(widget);
+        harness.animate_ms(16);
+        assert!(harness.pop_action().is_none());
+
+        harness.edit_widget(ticker_id, |mut ticker| {
+            Ticker::<Label>::set_active(&mut ticker.downcast(), true);
+        });
+        harness.animate_ms(16);
+        assert_eq!(
+            harness.pop_action(),
+            Some((Action::AnimationFrame(16_000_000), ticker_id))
+        );
+
+        harness.edit_widget(ticker_id, |mut ticker| {
+            Ticker::<Label>::set_active(&mut ticker.downcast(), false);
+        });
+        harness.animate_ms(16);
+        assert!(harness.pop_action().is_none());
+    }
+}
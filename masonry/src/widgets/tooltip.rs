@@ -0,0 +1,456 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which shows a tooltip when its child is hovered.
+
+use std::time::Duration;
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Point, Size, Vec2};
+
+use crate::core::{
+    AccessCtx, AccessEvent, ArcStr, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::theme;
+use crate::util::{fill_color, stroke};
+use crate::widgets::Label;
+
+/// The default time the pointer must rest on a [`Tooltip`]'s child before the tooltip is shown.
+pub const DEFAULT_TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+/// Where the tooltip is shown relative to the pointer, so that it doesn't cover the pointer.
+const POINTER_OFFSET: Vec2 = Vec2::new(0., 20.);
+
+/// The padding between the edge of the tooltip and its text.
+const TOOLTIP_PADDING: Vec2 = Vec2::new(6., 3.);
+
+/// A widget which shows a short text when the pointer rests on its child.
+///
+/// The tooltip is shown after the pointer has hovered the child for the [delay](Self::with_delay),
+/// in a layer above the rest of the window (see [`EventCtx::add_layer`]), so that it is not
+/// clipped by this widget's ancestors.
+/// It is placed just below the pointer, but within the window's bounds.
+/// It is dismissed when the pointer leaves the child, or when the user clicks or scrolls.
+/// After being dismissed by a click or scroll, it isn't shown again until the pointer
+/// leaves the child and comes back.
+pub struct Tooltip<W: ?Sized> {
+    child: WidgetPod<W>,
+    text: ArcStr,
+    delay: Duration,
+    /// The position of the pointer in window coordinates, when it last moved over the child.
+    pointer_pos: Point,
+    state: TooltipState,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TooltipState {
+    /// The child isn't hovered.
+    Idle,
+    /// The child is hovered, and the tooltip will be shown once the delay has elapsed.
+    Waiting { elapsed: Duration },
+    /// The tooltip is shown, in the layer with the given id.
+    Shown(WidgetId),
+    /// The tooltip was dismissed by a click or scroll whilst the child is hovered.
+    Dismissed,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Tooltip<W> {
+    /// Create a new `Tooltip` which shows `text` when `child` is hovered.
+    pub fn new(child: W, text: impl Into<ArcStr>) -> Self {
+        Self::from_pod(WidgetPod::new(child), text)
+    }
+}
+
+impl<W: Widget + ?Sized> Tooltip<W> {
+    /// Create a new `Tooltip` which shows `text` when the child in `child` is hovered.
+    pub fn from_pod(child: WidgetPod<W>, text: impl Into<ArcStr>) -> Self {
+        Self {
+            child,
+            text: text.into(),
+            delay: DEFAULT_TOOLTIP_DELAY,
+            pointer_pos: Point::ZERO,
+            state: TooltipState::Idle,
+        }
+    }
+
+    /// Set how long the pointer must rest on the child before the tooltip is shown.
+    ///
+    /// This defaults to [`DEFAULT_TOOLTIP_DELAY`].
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// The text shown in the tooltip.
+    pub fn text(&self) -> &ArcStr {
+        &self.text
+    }
+
+    /// Whether the tooltip is currently shown.
+    pub fn is_shown(&self) -> bool {
+        matches!(self.state, TooltipState::Shown(_))
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Tooltip<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the text shown in the tooltip.
+    ///
+    /// If the tooltip is currently shown, it is replaced with one showing the new text.
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, text: impl Into<ArcStr>) {
+        this.widget.text = text.into();
+        if let TooltipState::Shown(layer) = this.widget.state {
+            this.ctx.remove_layer(layer);
+            let position = this.widget.pointer_pos + POINTER_OFFSET;
+            let layer = this
+                .ctx
+                .add_layer(TooltipBubble::new(this.widget.text.clone()), position);
+            this.widget.state = TooltipState::Shown(layer);
+        }
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Set how long the pointer must rest on the child before the tooltip is shown.
+    pub fn set_delay(this: &mut WidgetMut<'_, Self>, delay: Duration) {
+        this.widget.delay = delay;
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Tooltip<W> {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::PointerMove(state) => {
+                self.pointer_pos = Point::new(state.position.x, state.position.y);
+            }
+            PointerEvent::PointerDown(_, _) | PointerEvent::MouseWheel(_, _) => {
+                if let TooltipState::Shown(layer) = self.state {
+                    ctx.remove_layer(layer);
+                }
+                if self.state != TooltipState::Idle {
+                    self.state = TooltipState::Dismissed;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let TooltipState::Waiting { elapsed } = self.state else {
+            return;
+        };
+        let elapsed = elapsed + Duration::from_nanos(interval);
+        if elapsed >= self.delay {
+            let position = self.pointer_pos + POINTER_OFFSET;
+            let layer = ctx.add_layer(TooltipBubble::new(self.text.clone()), position);
+            self.state = TooltipState::Shown(layer);
+        } else {
+            self.state = TooltipState::Waiting { elapsed };
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::ChildHoveredChanged(true) => {
+                if self.state == TooltipState::Idle {
+                    self.state = TooltipState::Waiting {
+                        elapsed: Duration::ZERO,
+                    };
+                    ctx.request_anim_frame();
+                }
+            }
+            Update::ChildHoveredChanged(false)
+            | Update::StashedChanged(true)
+            | Update::DisabledChanged(true) => {
+                if let TooltipState::Shown(layer) = self.state {
+                    ctx.remove_layer(layer);
+                }
+                self.state = TooltipState::Idle;
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let size = ctx.run_layout(&mut self.child, bc);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        let baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(baseline);
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.set_description(self.text.to_string());
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Tooltip", id = ctx.widget_id().trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(self.text.to_string())
+    }
+}
+
+// --- MARK: BUBBLE ---
+/// The content of the layer shown by a [`Tooltip`].
+struct TooltipBubble {
+    label: WidgetPod<Label>,
+}
+
+impl TooltipBubble {
+    fn new(text: ArcStr) -> Self {
+        Self {
+            label: WidgetPod::new(Label::new(text)),
+        }
+    }
+}
+
+impl Widget for TooltipBubble {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let padding = Size::new(TOOLTIP_PADDING.x * 2., TOOLTIP_PADDING.y * 2.);
+        let label_bc = bc.shrink(padding).loosen();
+        let label_size = ctx.run_layout(&mut self.label, &label_bc);
+        ctx.place_child(&mut self.label, TOOLTIP_PADDING.to_point());
+        bc.constrain(label_size + padding)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let border_width = 1.;
+        let rect = ctx
+            .size()
+            .to_rect()
+            .inset(-border_width / 2.)
+            .to_rounded_rect(theme::BUTTON_BORDER_RADIUS);
+        fill_color(scene, &rect, theme::BACKGROUND_DARK);
+        stroke(scene, &rect, theme::BORDER_LIGHT, border_width);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Tooltip
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        // The tooltip shouldn't take hover from the widget it describes.
+        false
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.label.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("TooltipBubble", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::PointerButton;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Button, Flex};
+
+    fn tooltip_button(button_id: WidgetId, text: &str) -> Tooltip<Button> {
+        Tooltip::from_pod(WidgetPod::new_with_id(Button::new("Save"), button_id), text)
+    }
+
+    fn layer_id(harness: &TestHarness, tooltip_id: WidgetId) -> Option<WidgetId> {
+        let tooltip = harness.get_widget(tooltip_id);
+        match tooltip.downcast::<Tooltip<Button>>().unwrap().state {
+            TooltipState::Shown(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn shown_after_delay() {
+        let [tooltip_id, button_id] = widget_ids();
+        let widget =
+            Flex::column().with_child_id(tooltip_button(button_id, "Save the file"), tooltip_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 100.));
+
+        harness.mouse_move_to(button_id);
+        harness.animate_ms(300);
+        assert_eq!(layer_id(&harness, tooltip_id), None);
+
+        harness.animate_ms(300);
+        let layer = layer_id(&harness, tooltip_id).expect("tooltip should be shown");
+        let button_center = harness.get_widget(button_id).ctx().bounding_rect().center();
+        let bubble_origin = harness.get_widget(layer).ctx().window_origin();
+        assert_eq!(bubble_origin.y, button_center.y + POINTER_OFFSET.y);
+        // The pointer is still over the button, rather than the tooltip.
+        assert!(harness.get_widget(button_id).ctx().is_hovered());
+
+        // Leaving the button removes the tooltip.
+        harness.mouse_move((190., 90.));
+        assert_eq!(layer_id(&harness, tooltip_id), None);
+        assert!(harness.try_get_widget(layer).is_none());
+    }
+
+    #[test]
+    fn removed_with_widget() {
+        let [tooltip_id, button_id] = widget_ids();
+        let tooltip = tooltip_button(button_id, "Save the file").with_delay(Duration::ZERO);
+        let widget = Flex::column().with_child_id(tooltip, tooltip_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 100.));
+
+        harness.mouse_move_to(button_id);
+        harness.animate_ms(16);
+        let layer = layer_id(&harness, tooltip_id).expect("tooltip should be shown");
+
+        // Removing the tooltip whilst it is shown also removes its layer.
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            Flex::remove_child(&mut flex, 0);
+        });
+        assert!(harness.try_get_widget(tooltip_id).is_none());
+        assert!(harness.try_get_widget(layer).is_none());
+    }
+
+    #[test]
+    fn dismissed_by_click() {
+        let [tooltip_id, button_id] = widget_ids();
+        let tooltip =
+            tooltip_button(button_id, "Save the file").with_delay(Duration::from_millis(100));
+        let widget = Flex::column().with_child_id(tooltip, tooltip_id);
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 100.));
+
+        harness.mouse_move_to(button_id);
+        harness.animate_ms(150);
+        assert!(layer_id(&harness, tooltip_id).is_some());
+
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(layer_id(&harness, tooltip_id), None);
+        // The tooltip isn't shown again until the pointer leaves the button.
+        harness.animate_ms(150);
+        assert_eq!(layer_id(&harness, tooltip_id), None);
+    }
+
+    #[test]
+    fn clamped_to_window() {
+        let [tooltip_id, button_id] = widget_ids();
+        let tooltip = tooltip_button(button_id, "A tooltip which is wider than the window")
+            .with_delay(Duration::ZERO);
+        // Place the button at the bottom right corner of the window.
+        let widget = Flex::column().with_flex_spacer(1.0).with_child(
+            Flex::row()
+                .with_flex_spacer(1.0)
+                .with_child_id(tooltip, tooltip_id),
+        );
+        let window_size = Size::new(200., 100.);
+        let mut harness = TestHarness::create_with_size(widget, window_size);
+
+        harness.mouse_move_to(button_id);
+        harness.animate_ms(16);
+        let layer = layer_id(&harness, tooltip_id).expect("tooltip should be shown");
+        let bubble_rect = harness.get_widget(layer).ctx().bounding_rect();
+        assert!(bubble_rect.x0 >= 0.);
+        assert!(bubble_rect.x1 <= window_size.width);
+        assert!(bubble_rect.y1 <= window_size.height);
+    }
+}
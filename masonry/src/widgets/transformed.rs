@@ -0,0 +1,222 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A widget which rotates, scales or otherwise transforms its child.
+
+use accesskit::{Node, Role};
+use smallvec::{SmallVec, smallvec};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, BoxConstraints, EventCtx, FromDynWidget, LayoutCtx, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Affine, Point, Size, Vec2};
+
+/// A widget which applies a 2d transform to its child, about the child's center.
+///
+/// The transform applies to how the child is painted, and to where it receives
+/// pointer events; positions in those events are in the child's untransformed coordinates.
+///
+/// By default, this widget takes up the child's untransformed size, so transforming the
+/// child doesn't move any of the widgets around it.
+/// Use [`with_transformed_bounds`](Self::with_transformed_bounds) to instead take up
+/// the size of the bounding box of the transformed child.
+///
+/// This replaces any transform set on the child's own [`WidgetPod`].
+pub struct Transformed<W: ?Sized> {
+    child: WidgetPod<W>,
+    transform: Affine,
+    transformed_bounds: bool,
+}
+
+// --- MARK: BUILDERS ---
+impl<W: Widget> Transformed<W> {
+    /// Create a new `Transformed` which doesn't transform `child`.
+    ///
+    /// Use the builder methods to add transforms.
+    pub fn new(child: W) -> Self {
+        Self::from_pod(WidgetPod::new(child))
+    }
+}
+
+impl<W: Widget + ?Sized> Transformed<W> {
+    /// Create a new `Transformed` which doesn't transform the child in `child`.
+    pub fn from_pod(child: WidgetPod<W>) -> Self {
+        Self {
+            child,
+            transform: Affine::IDENTITY,
+            transformed_bounds: false,
+        }
+    }
+
+    /// Builder-style method to then rotate the child by `radians` about its center.
+    pub fn rotate(mut self, radians: f64) -> Self {
+        self.transform = self.transform.then_rotate(radians);
+        self
+    }
+
+    /// Builder-style method to then scale the child by `factor` about its center.
+    pub fn scale(mut self, factor: f64) -> Self {
+        self.transform = self.transform.then_scale(factor);
+        self
+    }
+
+    /// Builder-style method to then move the child by `offset`.
+    pub fn translate(mut self, offset: impl Into<Vec2>) -> Self {
+        self.transform = self.transform.then_translate(offset.into());
+        self
+    }
+
+    /// Builder-style method to then apply `transform` to the child, about its center.
+    pub fn transform(mut self, transform: Affine) -> Self {
+        self.transform = transform * self.transform;
+        self
+    }
+
+    /// Builder-style method for setting whether this widget takes up the size of the
+    /// bounding box of the transformed child, rather than the child's untransformed size.
+    pub fn with_transformed_bounds(mut self, transformed_bounds: bool) -> Self {
+        self.transformed_bounds = transformed_bounds;
+        self
+    }
+
+    /// The transform applied to the child, about its center.
+    pub fn get_transform(&self) -> Affine {
+        self.transform
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl<W: Widget + FromDynWidget + ?Sized> Transformed<W> {
+    /// Get a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Set the transform applied to the child, about its center.
+    pub fn set_transform(this: &mut WidgetMut<'_, Self>, transform: Affine) {
+        this.widget.transform = transform;
+        // The child's layout is cached, so this is cheap.
+        this.ctx.request_layout();
+    }
+
+    /// Set whether this widget takes up the size of the bounding box of the transformed child.
+    ///
+    /// See also [`with_transformed_bounds`](Self::with_transformed_bounds).
+    pub fn set_transformed_bounds(this: &mut WidgetMut<'_, Self>, transformed_bounds: bool) {
+        this.widget.transformed_bounds = transformed_bounds;
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Transformed<W> {
+    fn on_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, _event: &Update) {}
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let child_size = ctx.run_layout(&mut self.child, bc);
+        let center = child_size.to_rect().center().to_vec2();
+        let mut transform = Affine::translate(center) * self.transform * Affine::translate(-center);
+
+        let size = if self.transformed_bounds {
+            let bounds = transform.transform_rect_bbox(child_size.to_rect());
+            transform = transform.then_translate(-bounds.origin().to_vec2());
+            bc.constrain(bounds.size())
+        } else {
+            child_size
+        };
+
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.set_child_transform(&mut self.child, transform);
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        smallvec![self.child.id()]
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("Transformed", id = ctx.widget_id().trace())
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Canvas, Flex};
+
+    fn canvas() -> Canvas {
+        Canvas::new(|_, _, _| {}).with_preferred_size(Size::new(40., 20.))
+    }
+
+    #[test]
+    fn bounds() {
+        let [transformed_id] = widget_ids();
+        let widget = Flex::column()
+            .with_child_id(Transformed::new(canvas()).rotate(FRAC_PI_2), transformed_id);
+
+        let mut harness = TestHarness::create(widget);
+        let size = harness.get_widget(transformed_id).ctx().size();
+        assert_eq!(size, Size::new(40., 20.));
+
+        harness.edit_widget(transformed_id, |mut transformed| {
+            Transformed::<Canvas>::set_transformed_bounds(&mut transformed.downcast(), true);
+        });
+        let size = harness.get_widget(transformed_id).ctx().size();
+        assert!((size - Size::new(20., 40.)).to_vec2().hypot() < 1e-9);
+    }
+}
@@ -0,0 +1,353 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A scrolling list which only has widgets for the rows near its viewport.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use accesskit::{Node, Role};
+use smallvec::SmallVec;
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, AccessEvent, Action, BoxConstraints, EventCtx, LayoutCtx, PaintCtx, PointerEvent,
+    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
+    WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Point, Size};
+
+/// How far the list scrolls for each unit of mouse wheel movement.
+const SCROLLING_SPEED: f64 = 10.0;
+
+/// A vertically scrolling list of rows of the same height, which only has widgets for the
+/// rows in or near its viewport.
+///
+/// This allows lists with very many rows, where creating a widget for each row would be
+/// too slow or use too much memory.
+///
+/// The list doesn't create the rows' widgets itself. Instead, it submits an
+/// [`Action::VirtualListRange`] with the range of rows it needs whenever that range changes,
+/// such as when the list is scrolled. The rows in that range should then be added with
+/// [`insert_row`](Self::insert_row), and rows outside of it can be removed with
+/// [`remove_row`](Self::remove_row).
+///
+/// The list takes up all of the available height, so should be given a finite height.
+pub struct VirtualList {
+    /// The widgets of the rows which currently have one, by index.
+    rows: BTreeMap<usize, WidgetPod<dyn Widget>>,
+    row_count: usize,
+    row_height: f64,
+    /// How many rows either side of the viewport need widgets.
+    overscan: usize,
+    /// How far the top of the viewport is from the top of the first row.
+    scroll_offset: f64,
+    /// The range of rows which were last reported as needing widgets.
+    needed_rows: Range<usize>,
+}
+
+// --- MARK: BUILDERS ---
+impl VirtualList {
+    /// Create a new `VirtualList` of `row_count` rows, each `row_height` tall.
+    pub fn new(row_count: usize, row_height: f64) -> Self {
+        Self {
+            rows: BTreeMap::new(),
+            row_count,
+            row_height,
+            overscan: 2,
+            scroll_offset: 0.,
+            needed_rows: 0..0,
+        }
+    }
+
+    /// Builder-style method for setting how many rows either side of the viewport
+    /// need widgets.
+    ///
+    /// These rows can be scrolled into view before new widgets are added for them.
+    /// The default is 2.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Builder-style method to add the widget for the row at `index`.
+    pub fn with_row(mut self, index: usize, widget: impl Widget) -> Self {
+        self.rows.insert(index, WidgetPod::new(widget).erased());
+        self
+    }
+
+    /// The number of rows in the list.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// The height of each row.
+    pub fn row_height(&self) -> f64 {
+        self.row_height
+    }
+
+    /// How far the list is scrolled from the top.
+    pub fn scroll_offset(&self) -> f64 {
+        self.scroll_offset
+    }
+
+    /// The range of rows which need widgets, as last reported in an
+    /// [`Action::VirtualListRange`].
+    pub fn needed_rows(&self) -> Range<usize> {
+        self.needed_rows.clone()
+    }
+
+    /// The indices of the rows which have widgets, in order.
+    pub fn row_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rows.keys().copied()
+    }
+}
+
+// --- MARK: WIDGETMUT ---
+impl VirtualList {
+    /// Add the widget for the row at `index`, replacing any existing one.
+    pub fn insert_row(this: &mut WidgetMut<'_, Self>, index: usize, widget: impl Widget) {
+        Self::insert_row_pod(this, index, WidgetPod::new(widget).erased());
+    }
+
+    /// Add the widget for the row at `index`, replacing any existing one.
+    pub fn insert_row_pod(
+        this: &mut WidgetMut<'_, Self>,
+        index: usize,
+        widget: WidgetPod<dyn Widget>,
+    ) {
+        if let Some(old) = this.widget.rows.insert(index, widget) {
+            this.ctx.remove_child(old);
+        }
+        this.ctx.children_changed();
+    }
+
+    /// Remove the widget for the row at `index`, if it has one.
+    pub fn remove_row(this: &mut WidgetMut<'_, Self>, index: usize) {
+        if let Some(widget) = this.widget.rows.remove(&index) {
+            this.ctx.remove_child(widget);
+            this.ctx.request_layout();
+        }
+    }
+
+    /// Get a mutable reference to the widget for the row at `index`, if it has one.
+    pub fn row_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        index: usize,
+    ) -> Option<WidgetMut<'t, dyn Widget>> {
+        let widget = this.widget.rows.get_mut(&index)?;
+        Some(this.ctx.get_mut(widget))
+    }
+
+    /// Set the number of rows in the list.
+    ///
+    /// Widgets for rows which are no longer in the list are not removed.
+    pub fn set_row_count(this: &mut WidgetMut<'_, Self>, row_count: usize) {
+        this.widget.row_count = row_count;
+        this.ctx.request_layout();
+    }
+
+    /// Set the height of each row.
+    pub fn set_row_height(this: &mut WidgetMut<'_, Self>, row_height: f64) {
+        this.widget.row_height = row_height;
+        this.ctx.request_layout();
+    }
+
+    /// Set how many rows either side of the viewport need widgets.
+    pub fn set_overscan(this: &mut WidgetMut<'_, Self>, overscan: usize) {
+        this.widget.overscan = overscan;
+        this.ctx.request_layout();
+    }
+
+    /// Scroll the list so that the top of the viewport is `offset` from the top of the list.
+    ///
+    /// This is clamped so that the viewport stays within the list.
+    pub fn set_scroll_offset(this: &mut WidgetMut<'_, Self>, offset: f64) {
+        this.widget.scroll_offset = offset;
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: IMPL WIDGET ---
+impl Widget for VirtualList {
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::MouseWheel(delta, _) = event {
+            let max_offset = (self.content_height() - ctx.size().height).max(0.);
+            let offset = (self.scroll_offset - delta.y * SCROLLING_SPEED).clamp(0., max_offset);
+            if offset != self.scroll_offset {
+                self.scroll_offset = offset;
+                ctx.record_wheel_scroll();
+                ctx.request_layout();
+                ctx.set_handled();
+            }
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &TextEvent,
+    ) {
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx) {
+        for row in self.rows.values_mut() {
+            ctx.register_child(row);
+        }
+    }
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _props: &mut PropertiesMut<'_>, _event: &Update) {}
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        _props: &mut PropertiesMut<'_>,
+        bc: &BoxConstraints,
+    ) -> Size {
+        let content_height = self.content_height();
+        let height = if bc.max().height.is_finite() {
+            bc.max().height
+        } else {
+            content_height
+        };
+        self.scroll_offset = self
+            .scroll_offset
+            .clamp(0., (content_height - height).max(0.));
+
+        let row_bc = BoxConstraints::new(
+            Size::new(bc.min().width, self.row_height),
+            Size::new(bc.max().width, self.row_height),
+        );
+        let mut width: f64 = 0.;
+        for (&index, row) in &mut self.rows {
+            let row_size = ctx.run_layout(row, &row_bc);
+            width = width.max(row_size.width);
+            let y = index as f64 * self.row_height - self.scroll_offset;
+            ctx.place_child(row, Point::new(0., y));
+        }
+        if bc.max().width.is_finite() {
+            width = bc.max().width;
+        }
+        let size = bc.constrain(Size::new(width, height));
+        ctx.set_clip_path(size.to_rect());
+
+        let needed_rows = self.rows_for_viewport(size.height);
+        if needed_rows != self.needed_rows {
+            self.needed_rows = needed_rows.clone();
+            ctx.submit_action(Action::VirtualListRange(needed_rows));
+        }
+        size
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::List
+    }
+
+    fn accessibility(&mut self, _ctx: &mut AccessCtx, _props: &PropertiesRef<'_>, node: &mut Node) {
+        node.set_size_of_set(self.row_count);
+    }
+
+    fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
+        self.rows.values().map(|row| row.id()).collect()
+    }
+
+    fn make_trace_span(&self, ctx: &QueryCtx<'_>) -> Span {
+        trace_span!("VirtualList", id = ctx.widget_id().trace())
+    }
+}
+
+impl VirtualList {
+    /// The height of all of the rows together.
+    fn content_height(&self) -> f64 {
+        self.row_count as f64 * self.row_height
+    }
+
+    /// The rows which need widgets for a viewport of the given height at the current offset.
+    fn rows_for_viewport(&self, viewport_height: f64) -> Range<usize> {
+        if self.row_height <= 0. {
+            return 0..self.row_count;
+        }
+        let first = (self.scroll_offset / self.row_height).floor() as usize;
+        let last = ((self.scroll_offset + viewport_height) / self.row_height).ceil() as usize;
+        let start = first.saturating_sub(self.overscan).min(self.row_count);
+        let end = last.saturating_add(self.overscan).min(self.row_count);
+        start..end
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use vello::kurbo::Vec2;
+
+    use super::*;
+    use crate::testing::{TestHarness, widget_ids};
+    use crate::widgets::{Label, SizedBox};
+
+    /// Add and remove rows to match the last range requested by the list, like a driver would.
+    fn update_rows(harness: &mut TestHarness, list_id: WidgetId) {
+        while let Some((action, id)) = harness.pop_action() {
+            let Action::VirtualListRange(needed) = action else {
+                panic!("Unexpected action {action:?}");
+            };
+            assert_eq!(id, list_id);
+            harness.edit_widget(list_id, |mut list| {
+                let mut list = list.downcast::<VirtualList>();
+                let existing: Vec<_> = list.widget.row_indices().collect();
+                for index in existing {
+                    if !needed.contains(&index) {
+                        VirtualList::remove_row(&mut list, index);
+                    }
+                }
+                for index in needed {
+                    if VirtualList::row_mut(&mut list, index).is_none() {
+                        VirtualList::insert_row(&mut list, index, Label::new(format!("{index}")));
+                    }
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn only_visible_rows_have_widgets() {
+        let [list_id] = widget_ids();
+        let list = WidgetPod::new_with_id(VirtualList::new(10_000, 20.), list_id);
+        let widget = SizedBox::new_pod(list.erased()).width(200.).height(100.);
+
+        let mut harness = TestHarness::create_with_size(widget, Size::new(200., 100.));
+        update_rows(&mut harness, list_id);
+        let list = harness.get_widget(list_id);
+        // Five rows are visible, with two more below them.
+        assert_eq!(list.children().len(), 7);
+        assert_eq!(list.downcast::<VirtualList>().unwrap().needed_rows(), 0..7);
+
+        harness.mouse_move((100., 50.));
+        harness.mouse_wheel(Vec2::new(0., -100.));
+        update_rows(&mut harness, list_id);
+        let list = harness.get_widget(list_id);
+        let list = list.downcast::<VirtualList>().unwrap();
+        assert_eq!(list.scroll_offset(), 1000.);
+        assert_eq!(list.needed_rows(), 48..57);
+        assert_eq!(
+            list.row_indices().collect::<Vec<_>>(),
+            (48..57).collect::<Vec<_>>()
+        );
+    }
+}
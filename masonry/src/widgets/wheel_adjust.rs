@@ -0,0 +1,254 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+//! Shared mouse wheel handling for widgets which adjust a value.
+
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+use winit::keyboard::ModifiersState;
+
+use crate::core::{EventCtx, PointerEvent};
+
+/// Wheel events closer together than this are considered part of a fast spin.
+const ACCELERATION_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How many consecutive fast events are needed to increase the step multiplier by one.
+const ACCELERATION_EVENTS: u32 = 4;
+
+/// The largest step multiplier reached by accelerating.
+const MAX_ACCELERATION: u32 = 5;
+
+/// How long after a [`Portal`](super::Portal) scrolled from the wheel that we consider
+/// the scroll gesture to still be ongoing.
+const SCROLL_GESTURE_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Helper for widgets whose value can be adjusted with the mouse wheel whilst hovered,
+/// such as sliders and steppers.
+///
+/// Widgets should call [`on_pointer_event`](Self::on_pointer_event) from their own
+/// [`on_pointer_event`](crate::core::Widget::on_pointer_event).
+///
+/// To avoid hijacking scrolling in lists which contain such widgets, wheel events are only
+/// claimed (and the value adjusted) when:
+///
+/// - The widget is focused, or a modifier key (Shift, Ctrl, Alt or Super) is held.
+/// - No [`Portal`](super::Portal) has recently scrolled in response to the wheel,
+///   i.e. a scroll gesture which started over other content isn't interrupted when the
+///   widget moves under the pointer.
+///
+/// Holding Shift adjusts by the fine step.
+/// Spinning the wheel quickly increases the step, up to five times.
+#[derive(Debug, Clone)]
+pub struct WheelAdjust {
+    step: f64,
+    fine_step: f64,
+    min: f64,
+    max: f64,
+    last_event: Option<Instant>,
+    fast_events: u32,
+}
+
+impl WheelAdjust {
+    /// Create a helper which adjusts a value in `min..=max` by `step` per wheel notch.
+    ///
+    /// The fine step defaults to a tenth of `step`.
+    pub fn new(step: f64, min: f64, max: f64) -> Self {
+        Self {
+            step,
+            fine_step: step / 10.,
+            min,
+            max,
+            last_event: None,
+            fast_events: 0,
+        }
+    }
+
+    /// Builder-style method to set the step used whilst Shift is held.
+    pub fn with_fine_step(mut self, fine_step: f64) -> Self {
+        self.fine_step = fine_step;
+        self
+    }
+
+    /// Set the step used per wheel notch.
+    pub fn set_step(&mut self, step: f64) {
+        self.step = step;
+    }
+
+    /// Set the step used whilst Shift is held.
+    pub fn set_fine_step(&mut self, fine_step: f64) {
+        self.fine_step = fine_step;
+    }
+
+    /// Set the range the adjusted value is clamped to.
+    pub fn set_range(&mut self, min: f64, max: f64) {
+        self.min = min;
+        self.max = max;
+    }
+
+    /// Handle a pointer event for a widget currently showing `value`.
+    ///
+    /// If `event` is a wheel event which this widget should claim, marks it as
+    /// [handled](EventCtx::set_handled) and returns the new value, clamped to the range.
+    /// The returned value may be equal to `value` (e.g. at the end of the range);
+    /// the event is still claimed, so that ancestors don't scroll unexpectedly.
+    ///
+    /// Returns `None` for all other events.
+    pub fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx,
+        event: &PointerEvent,
+        value: f64,
+    ) -> Option<f64> {
+        let PointerEvent::MouseWheel(delta, state) = event else {
+            return None;
+        };
+        if ctx.is_handled() || ctx.is_disabled() || delta.y == 0. {
+            return None;
+        }
+        let mods = state.mods.state();
+        let has_modifier = mods.intersects(
+            ModifiersState::SHIFT
+                | ModifiersState::CONTROL
+                | ModifiersState::ALT
+                | ModifiersState::SUPER,
+        );
+        let is_scrolling = ctx
+            .last_wheel_scroll()
+            .is_some_and(|last| last.elapsed() < SCROLL_GESTURE_TIMEOUT);
+        if !(ctx.is_focus_target() || has_modifier) || is_scrolling {
+            return None;
+        }
+
+        let now = Instant::now();
+        match self.last_event {
+            Some(last) if now.duration_since(last) < ACCELERATION_INTERVAL => {
+                self.fast_events += 1;
+            }
+            _ => self.fast_events = 0,
+        }
+        self.last_event = Some(now);
+        let multiplier = (1 + self.fast_events / ACCELERATION_EVENTS).min(MAX_ACCELERATION);
+
+        let step = if mods.shift_key() {
+            self.fine_step
+        } else {
+            self.step
+        };
+        // A positive delta is the wheel moving "up", which increases the value.
+        let delta = step * f64::from(multiplier) * delta.y.signum();
+
+        ctx.set_handled();
+        Some((value + delta).clamp(self.min, self.max))
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use winit::event::Modifiers;
+
+    use super::*;
+    use crate::core::PointerState;
+    use crate::dpi::LogicalPosition;
+    use crate::kurbo::Size;
+    use crate::testing::{ModularWidget, TestHarness, TestWidgetExt, widget_ids};
+    use crate::widgets::{Flex, Portal, SizedBox};
+
+    /// A widget with a value starting at 5, adjusted in `0..=10`.
+    fn adjustable() -> (ModularWidget<WheelAdjust>, Rc<Cell<f64>>) {
+        let value = Rc::new(Cell::new(5.));
+        let widget_value = value.clone();
+        let widget = ModularWidget::new(WheelAdjust::new(1., 0., 10.))
+            .accepts_focus(true)
+            .pointer_event_fn(move |wheel, ctx, _, event| {
+                if let Some(new_value) = wheel.on_pointer_event(ctx, event, widget_value.get()) {
+                    widget_value.set(new_value);
+                }
+            })
+            .layout_fn(|_, _, _, _| Size::new(50., 50.));
+        (widget, value)
+    }
+
+    fn wheel(harness: &mut TestHarness, y: f64, mods: ModifiersState) {
+        let mut state = PointerState::empty();
+        state.position = LogicalPosition::new(10., 10.);
+        state.mods = Modifiers::from(mods);
+        harness.process_pointer_event(PointerEvent::MouseWheel(LogicalPosition::new(0., y), state));
+    }
+
+    #[test]
+    fn unfocused_without_modifier_is_not_claimed() {
+        let [id] = widget_ids();
+        let (widget, value) = adjustable();
+        let mut harness = TestHarness::create(widget.with_id(id));
+        harness.mouse_move((10., 10.));
+
+        wheel(&mut harness, 1., ModifiersState::empty());
+        assert_eq!(value.get(), 5.);
+    }
+
+    #[test]
+    fn focused_or_modifier_is_claimed() {
+        let [id] = widget_ids();
+        let (widget, value) = adjustable();
+        let mut harness = TestHarness::create(widget.with_id(id));
+        harness.mouse_move((10., 10.));
+
+        wheel(&mut harness, 1., ModifiersState::CONTROL);
+        assert_eq!(value.get(), 6.);
+
+        // Shift gives the fine step.
+        wheel(&mut harness, -1., ModifiersState::SHIFT);
+        assert_eq!(value.get(), 5.9);
+
+        harness.focus_on(Some(id));
+        wheel(&mut harness, -1., ModifiersState::empty());
+        assert!(value.get() < 5.9);
+    }
+
+    #[test]
+    fn value_is_clamped() {
+        let [id] = widget_ids();
+        let (widget, value) = adjustable();
+        let mut harness = TestHarness::create(widget.with_id(id));
+        harness.mouse_move((10., 10.));
+        harness.focus_on(Some(id));
+
+        for _ in 0..30 {
+            wheel(&mut harness, 1., ModifiersState::empty());
+        }
+        assert_eq!(value.get(), 10.);
+    }
+
+    #[test]
+    fn unclaimed_wheel_scrolls_portal() {
+        let [id, portal_id] = widget_ids();
+        let (widget, value) = adjustable();
+        let content = Flex::column()
+            .with_child_id(widget, id)
+            .with_child(SizedBox::empty().height(1000.));
+        let portal = Portal::new(content).with_id(portal_id);
+        let mut harness = TestHarness::create_with_size(portal, Size::new(100., 100.));
+        harness.mouse_move((10., 10.));
+
+        // The portal scrolls, and the widget keeps its value.
+        wheel(&mut harness, -1., ModifiersState::empty());
+        assert_eq!(value.get(), 5.);
+        let scrolled = harness.get_widget(id).ctx().window_origin().y;
+        assert!(scrolled < 0., "portal should have scrolled");
+
+        // Focusing the widget mid-gesture doesn't interrupt scrolling.
+        harness.focus_on(Some(id));
+        wheel(&mut harness, 1., ModifiersState::empty());
+        assert_eq!(value.get(), 5.);
+    }
+}
@@ -3,41 +3,110 @@
 
 #![warn(missing_docs)]
 
+use std::time::Duration;
+
 use accesskit::{Node, Role};
 use smallvec::SmallVec;
 use tracing::trace_span;
 
 use crate::core::{
-    AccessCtx, BoxConstraints, LayoutCtx, PaintCtx, PropertiesMut, PropertiesRef, QueryCtx,
-    RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, BoxConstraints, ComposeCtx, LayoutCtx, PaintCtx, PropertiesMut, PropertiesRef,
+    QueryCtx, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
-use crate::kurbo::{Point, Size};
+use crate::kurbo::{Affine, Point, Size, Vec2};
+use crate::peniko::{Color, Fill};
 use crate::vello::Scene;
 
 struct Child {
     widget: WidgetPod<dyn Widget>,
     alignment: ChildAlignment,
+    transition: Transition,
+    /// How far the child is through its transition, from 0.0 (hidden) to 1.0 (shown).
+    visibility: f64,
+    /// The size of the child from the last layout, used to scale it about its center.
+    size: Size,
 }
 
 /// An option specifying how a child widget is aligned within a [`ZStack`].
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ChildAlignment {
     /// Specifies that the child should use the global alignment as specified by the parent [`ZStack`] widget.
     ParentAligned,
     /// Specifies that the child should override the global alignment specified by the parent [`ZStack`] widget.
     SelfAligned(Alignment),
+    /// Specifies that the child should be placed at the given offset from the top leading corner of the [`ZStack`].
+    ///
+    /// The offset isn't included in the size of the `ZStack`, which only takes the child's
+    /// size into account, so the child overflows the stack if the offset is too large.
+    Offset(Vec2),
+}
+
+/// How a child of a [`ZStack`] animates when it is added to or removed from the stack.
+///
+/// A child enters by animating linearly from the given `opacity` and `scale` to its normal
+/// appearance over `duration`, and leaves by animating back to them before it is removed.
+///
+/// The transform and opacity of a child with a transition are controlled by the `ZStack`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    /// How long the animation takes.
+    pub duration: Duration,
+    /// The opacity of the child when it is hidden, from 0.0 to 1.0.
+    pub opacity: f64,
+    /// The scale of the child when it is hidden, relative to its center.
+    pub scale: f64,
+}
+
+/// How the size of a [`ZStack`] is determined.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum ZStackSizing {
+    /// The `ZStack` is large enough to contain all of its children.
+    ///
+    /// Children are laid out with the loosened constraints of the `ZStack`.
+    /// If these are unbounded, the `ZStack` takes the size of its largest child.
+    #[default]
+    UnionOfChildren,
+    /// The `ZStack` takes the size of its first child, typically a background.
+    ///
+    /// The first child is laid out with the loosened constraints of the `ZStack`,
+    /// so it must have a finite size even if these constraints are unbounded.
+    /// The other children are then laid out with tight constraints of the first child's size,
+    /// so that they cover it exactly.
+    /// Children placed with [`ChildAlignment::Offset`] may still overflow the stack.
+    FirstChild,
+    /// The `ZStack` has the given size, clamped to its constraints.
+    ///
+    /// All children are laid out with a maximum size of the stack's size.
+    Fixed(Size),
 }
 
 /// A widget container that lays the child widgets on top of each other.
 ///
 /// The alignment of how the children are placed can be specified globally using [`with_alignment`][Self::with_alignment].
-/// Each child can additionally override the global alignment using [`ChildAlignment::SelfAligned`].
+/// Each child can additionally override the global alignment using [`ChildAlignment::SelfAligned`],
+/// or be placed at an explicit position using [`ChildAlignment::Offset`].
+///
+/// The size of the stack is determined by its [sizing policy](ZStackSizing).
+///
+/// Children can be given a [`Transition`], in which case they are animated when they
+/// are added, and linger whilst they are animated out when they are removed.
+///
+/// The stack can have a [background](Self::with_background), which fills its whole area
+/// behind all of its children, and can [clip](Self::with_clip) its children to that area.
 ///
 #[doc = crate::include_screenshot!("widget/screenshots/masonry__widget__zstack__tests__zstack_alignment_default.png", "Red foreground widget on top of blue background widget.")]
 #[derive(Default)]
 pub struct ZStack {
     children: Vec<Child>,
+    /// Children which have been removed, but are still animating out.
+    ///
+    /// These are painted above the other children, and aren't counted by child indices.
+    leaving: Vec<Child>,
     alignment: Alignment,
+    sizing: ZStackSizing,
+    background: Option<Color>,
+    corner_radius: f64,
+    clip: bool,
 }
 
 /// Alignment describes the position of a view laid on top of another view.
@@ -167,14 +236,94 @@ impl From<Alignment> for ChildAlignment {
     }
 }
 
+// --- MARK: IMPL TRANSITION ---
+
+impl Transition {
+    /// A transition which shows and hides the child instantly.
+    pub const NONE: Self = Self {
+        duration: Duration::ZERO,
+        opacity: 1.0,
+        scale: 1.0,
+    };
+
+    /// A transition which fades the child in and out over `duration`.
+    pub fn fade(duration: Duration) -> Self {
+        Self {
+            duration,
+            opacity: 0.0,
+            ..Self::NONE
+        }
+    }
+
+    /// A transition which scales the child from and to `scale` over `duration`.
+    pub fn scale(duration: Duration, scale: f64) -> Self {
+        Self {
+            duration,
+            scale,
+            ..Self::NONE
+        }
+    }
+
+    /// Changes the opacity of the child when it is hidden.
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Changes the scale of the child when it is hidden.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Whether this transition changes the appearance of the child at all.
+    fn has_effect(&self) -> bool {
+        self.opacity != 1.0 || self.scale != 1.0
+    }
+
+    /// Whether a child with this transition should be animated.
+    fn is_animated(&self) -> bool {
+        self.has_effect() && !self.duration.is_zero()
+    }
+
+    /// The change in visibility over `interval`.
+    fn step(&self, interval: Duration) -> f64 {
+        interval.as_secs_f64() / self.duration.as_secs_f64()
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
 impl Child {
     fn new(widget: WidgetPod<dyn Widget>, alignment: ChildAlignment) -> Self {
-        Self { widget, alignment }
+        Self {
+            widget,
+            alignment,
+            transition: Transition::NONE,
+            visibility: 1.0,
+            size: Size::ZERO,
+        }
     }
 
     fn update_alignment(&mut self, alignment: ChildAlignment) {
         self.alignment = alignment;
     }
+
+    /// Apply the opacity and scale for the child's current visibility.
+    fn apply_transition(&mut self, ctx: &mut ComposeCtx) {
+        if !self.transition.has_effect() {
+            return;
+        }
+        let lerp = |hidden: f64| hidden + (1.0 - hidden) * self.visibility;
+        let scale = lerp(self.transition.scale);
+        let center = self.size.to_rect().center();
+        ctx.set_child_opacity(&mut self.widget, lerp(self.transition.opacity));
+        ctx.set_child_transform(&mut self.widget, Affine::scale_about(scale, center));
+    }
 }
 
 // --- MARK: IMPL ZSTACK ---
@@ -185,11 +334,51 @@ impl ZStack {
     }
 
     /// Changes the alignment of the children.
+    ///
+    /// This is the alignment used by children with [`ChildAlignment::ParentAligned`].
+    #[doc(alias = "with_default_alignment")]
     pub fn with_alignment(mut self, alignment: impl Into<Alignment>) -> Self {
         self.alignment = alignment.into();
         self
     }
 
+    /// Changes how the size of the `ZStack` is determined.
+    ///
+    /// See [`ZStackSizing`] for details.
+    pub fn with_sizing(mut self, sizing: ZStackSizing) -> Self {
+        self.sizing = sizing;
+        self
+    }
+
+    /// Fills the whole stack with `background`, behind all of its children.
+    ///
+    /// The background doesn't make the stack handle pointer events; clicks on it
+    /// are still passed on to the stack's parent.
+    pub fn with_background(mut self, background: impl Into<Option<Color>>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Rounds the corners of the [background](Self::with_background) by `radius`.
+    ///
+    /// The children are only clipped to the rounded corners if [`with_clip`](Self::with_clip)
+    /// is set.
+    pub fn with_corner_radius(mut self, radius: f64) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Whether to clip the children to the bounds of the stack, including its
+    /// [rounded corners](Self::with_corner_radius).
+    ///
+    /// Parts of children outside the bounds aren't painted, and don't receive pointer events.
+    /// This is off by default, so children such as those placed with [`ChildAlignment::Offset`]
+    /// can overflow the stack.
+    pub fn with_clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
     /// Appends a child widget to the `ZStack`.
     /// The child are placed back to front, in the order they are added.
     pub fn with_child(self, child: impl Widget, alignment: impl Into<ChildAlignment>) -> Self {
@@ -214,7 +403,20 @@ impl ZStack {
         child: WidgetPod<dyn Widget>,
         alignment: impl Into<ChildAlignment>,
     ) -> Self {
-        let child = Child::new(child, alignment.into());
+        self.with_transitioned_child_pod(child, alignment, Transition::NONE)
+    }
+
+    /// Appends a child widget pod to the `ZStack`, which uses `transition` when it is removed.
+    ///
+    /// Children added using this builder are shown immediately, without animating in.
+    pub fn with_transitioned_child_pod(
+        mut self,
+        child: WidgetPod<dyn Widget>,
+        alignment: impl Into<ChildAlignment>,
+        transition: Transition,
+    ) -> Self {
+        let mut child = Child::new(child, alignment.into());
+        child.transition = transition;
         self.children.push(child);
         self
     }
@@ -254,17 +456,107 @@ impl ZStack {
         widget: WidgetPod<dyn Widget>,
         alignment: impl Into<ChildAlignment>,
     ) {
-        let child = Child::new(widget, alignment.into());
-        this.widget.children.push(child);
+        Self::insert_transitioned_child_pod(this, widget, alignment, Transition::NONE);
+    }
+
+    /// Add a child widget to the `ZStack`, which animates in using `transition`.
+    ///
+    /// The transition is also used when the child is removed.
+    pub fn insert_transitioned_child_pod(
+        this: &mut WidgetMut<'_, Self>,
+        widget: WidgetPod<dyn Widget>,
+        alignment: impl Into<ChildAlignment>,
+        transition: Transition,
+    ) {
+        let idx = this.widget.children.len();
+        Self::insert_transitioned_child_pod_at(this, idx, widget, alignment, transition);
+    }
+
+    /// Insert a child widget at the given index.
+    ///
+    /// Children are painted back to front, so the child is placed behind the children
+    /// which were at `idx` and after it.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is greater than the number of children.
+    pub fn insert_child(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        child: impl Widget,
+        alignment: impl Into<ChildAlignment>,
+    ) {
+        let child_pod: WidgetPod<dyn Widget> = WidgetPod::new(child).erased();
+        Self::insert_transitioned_child_pod_at(this, idx, child_pod, alignment, Transition::NONE);
+    }
+
+    /// Insert a child widget at the given index, which animates in using `transition`.
+    ///
+    /// See [`insert_child`][Self::insert_child] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is greater than the number of children.
+    pub fn insert_transitioned_child_pod_at(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        widget: WidgetPod<dyn Widget>,
+        alignment: impl Into<ChildAlignment>,
+        transition: Transition,
+    ) {
+        let mut child = Child::new(widget, alignment.into());
+        child.transition = transition;
+        if transition.is_animated() {
+            child.visibility = 0.0;
+            this.ctx.request_anim_frame();
+        }
+        this.widget.children.insert(idx, child);
         this.ctx.children_changed();
         this.ctx.request_layout();
     }
 
     /// Remove a child from the `ZStack`.
+    ///
+    /// If the child has a [`Transition`], it is animated out before it is removed
+    /// from the widget tree. It no longer counts towards child indices in the meantime.
     pub fn remove_child(this: &mut WidgetMut<'_, Self>, idx: usize) {
         let child = this.widget.children.remove(idx);
-        this.ctx.remove_child(child.widget);
-        this.ctx.request_layout();
+        if child.transition.is_animated() {
+            this.widget.leaving.push(child);
+            this.ctx.children_changed();
+            this.ctx.request_anim_frame();
+        } else {
+            this.ctx.remove_child(child.widget);
+            this.ctx.request_layout();
+        }
+    }
+
+    /// Move the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// Children are painted back to front, so this can be used to bring a child to the front.
+    /// Unlike removing and re-adding it, this preserves the state of the child widget.
+    ///
+    /// # Panics
+    ///
+    /// If `from` or `to` is out of bounds.
+    pub fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+        this.ctx.request_render();
+    }
+
+    /// Swap the children at indices `a` and `b`.
+    ///
+    /// See [`move_child`][Self::move_child] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` is out of bounds.
+    pub fn swap_children(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
+        this.widget.children.swap(a, b);
+        this.ctx.children_changed();
+        this.ctx.request_render();
     }
 
     /// Get a mutable reference to a child of the `ZStack`.
@@ -279,13 +571,51 @@ impl ZStack {
     /// Change the alignment of the `ZStack`.
     ///
     /// See also [`with_alignment`][Self::with_alignment].
+    #[doc(alias = "set_default_alignment")]
     pub fn set_alignment(this: &mut WidgetMut<'_, Self>, alignment: impl Into<Alignment>) {
         this.widget.alignment = alignment.into();
         this.ctx.request_layout();
     }
 
+    /// Change how the size of the `ZStack` is determined.
+    ///
+    /// See also [`with_sizing`][Self::with_sizing].
+    pub fn set_sizing(this: &mut WidgetMut<'_, Self>, sizing: ZStackSizing) {
+        this.widget.sizing = sizing;
+        this.ctx.request_layout();
+    }
+
+    /// Change the background filled behind the children, or remove it with `None`.
+    ///
+    /// See also [`with_background`][Self::with_background].
+    pub fn set_background(this: &mut WidgetMut<'_, Self>, background: impl Into<Option<Color>>) {
+        this.widget.background = background.into();
+        this.ctx.request_paint_only();
+    }
+
+    /// Change the radius of the corners of the background.
+    ///
+    /// See also [`with_corner_radius`][Self::with_corner_radius].
+    pub fn set_corner_radius(this: &mut WidgetMut<'_, Self>, radius: f64) {
+        this.widget.corner_radius = radius;
+        if this.widget.clip {
+            // The clip shape is set in layout.
+            this.ctx.request_layout();
+        } else {
+            this.ctx.request_paint_only();
+        }
+    }
+
+    /// Change whether the children are clipped to the bounds of the stack.
+    ///
+    /// See also [`with_clip`][Self::with_clip].
+    pub fn set_clip(this: &mut WidgetMut<'_, Self>, clip: bool) {
+        this.widget.clip = clip;
+        this.ctx.request_layout();
+    }
+
     /// Change the alignment of a child of the `ZStack`.
-    pub fn update_child_alignment(
+    pub fn set_child_alignment(
         this: &mut WidgetMut<'_, Self>,
         idx: usize,
         alignment: impl Into<ChildAlignment>,
@@ -294,10 +624,65 @@ impl ZStack {
         child.update_alignment(alignment.into());
         this.ctx.request_layout();
     }
+
+    /// Change the transition used when a child of the `ZStack` is removed.
+    ///
+    /// If the child is still animating in, it finishes doing so immediately.
+    pub fn set_child_transition(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        transition: Transition,
+    ) {
+        let child = &mut this.widget.children[idx];
+        if child.transition.has_effect() && !transition.has_effect() {
+            // The stack no longer controls the child's appearance, so reset it.
+            this.ctx.set_child_opacity(&mut child.widget, 1.0);
+            this.ctx
+                .set_child_transform(&mut child.widget, Affine::IDENTITY);
+        }
+        child.transition = transition;
+        child.visibility = 1.0;
+        this.ctx.request_compose();
+    }
 }
 
 // --- MARK: IMPL WIDGET---
 impl Widget for ZStack {
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let interval = Duration::from_nanos(interval);
+        let mut animating = false;
+        for child in &mut self.children {
+            if child.visibility < 1.0 {
+                child.visibility = (child.visibility + child.transition.step(interval)).min(1.0);
+                animating |= child.visibility < 1.0;
+            }
+        }
+        for child in &mut self.leaving {
+            child.visibility = (child.visibility - child.transition.step(interval)).max(0.0);
+        }
+
+        let (finished, leaving) = std::mem::take(&mut self.leaving)
+            .into_iter()
+            .partition::<Vec<_>, _>(|child| child.visibility <= 0.0);
+        self.leaving = leaving;
+        if !finished.is_empty() {
+            for child in finished {
+                ctx.remove_child(child.widget);
+            }
+            ctx.request_layout();
+        }
+
+        if animating || !self.leaving.is_empty() {
+            ctx.request_anim_frame();
+        }
+        ctx.request_compose();
+    }
+
     fn layout(
         &mut self,
         ctx: &mut LayoutCtx,
@@ -307,16 +692,46 @@ impl Widget for ZStack {
         // First pass: calculate the smallest bounds needed to layout the children.
         let mut max_size = bc.min();
         let loosened_bc = bc.loosen();
-        for child in &mut self.children {
-            let child_size = ctx.run_layout(&mut child.widget, &loosened_bc);
+        match self.sizing {
+            ZStackSizing::UnionOfChildren => {
+                for child in &mut self.children {
+                    let child_size = ctx.run_layout(&mut child.widget, &loosened_bc);
+
+                    max_size.width = child_size.width.max(max_size.width);
+                    max_size.height = child_size.height.max(max_size.height);
+                }
+                max_size = bc.constrain(max_size);
+            }
+            ZStackSizing::FirstChild => {
+                let mut children = self.children.iter_mut();
+                if let Some(first) = children.next() {
+                    let first_size = ctx.run_layout(&mut first.widget, &loosened_bc);
+                    max_size = bc.constrain(first_size);
+                }
+                let overlay_bc = BoxConstraints::tight(max_size);
+                for child in children {
+                    ctx.run_layout(&mut child.widget, &overlay_bc);
+                }
+            }
+            ZStackSizing::Fixed(size) => {
+                max_size = bc.constrain(size);
+                let child_bc = BoxConstraints::new(Size::ZERO, max_size);
+                for child in &mut self.children {
+                    ctx.run_layout(&mut child.widget, &child_bc);
+                }
+            }
+        }
 
-            max_size.width = child_size.width.max(max_size.width);
-            max_size.height = child_size.height.max(max_size.height);
+        let leaving_bc = BoxConstraints::new(Size::ZERO, max_size);
+        for child in &mut self.leaving {
+            ctx.run_layout(&mut child.widget, &leaving_bc);
         }
 
         // Second pass: place the children given the calculated max_size bounds.
-        for child in &mut self.children {
+        let parent_alignment = self.alignment;
+        for child in self.children.iter_mut().chain(&mut self.leaving) {
             let child_size = ctx.child_size(&child.widget);
+            child.size = child_size;
 
             let end = max_size - child_size;
             let end = Point::new(end.width, end.height);
@@ -325,7 +740,11 @@ impl Widget for ZStack {
 
             let child_alignment = match child.alignment {
                 ChildAlignment::SelfAligned(alignment) => alignment,
-                ChildAlignment::ParentAligned => self.alignment,
+                ChildAlignment::ParentAligned => parent_alignment,
+                ChildAlignment::Offset(offset) => {
+                    ctx.place_child(&mut child.widget, offset.to_point());
+                    continue;
+                }
             };
 
             let origin = match child_alignment {
@@ -343,20 +762,39 @@ impl Widget for ZStack {
             ctx.place_child(&mut child.widget, origin);
         }
 
+        if self.clip {
+            ctx.set_clip_shape(&max_size.to_rounded_rect(self.corner_radius));
+        } else {
+            ctx.clear_clip_path();
+        }
+
         max_size
     }
 
-    fn paint(&mut self, _ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+    fn compose(&mut self, ctx: &mut ComposeCtx) {
+        for child in self.children.iter_mut().chain(&mut self.leaving) {
+            child.apply_transition(ctx);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _props: &PropertiesRef<'_>, scene: &mut Scene) {
+        // Children are painted after their parent, so this is beneath all of them.
+        if let Some(background) = self.background {
+            let rect = ctx.size().to_rounded_rect(self.corner_radius);
+            scene.fill(Fill::NonZero, Affine::IDENTITY, background, None, &rect);
+        }
+    }
 
     fn register_children(&mut self, ctx: &mut RegisterCtx) {
-        for child in self.children.iter_mut().map(|x| &mut x.widget) {
-            ctx.register_child(child);
+        for child in self.children.iter_mut().chain(&mut self.leaving) {
+            ctx.register_child(&mut child.widget);
         }
     }
 
     fn children_ids(&self) -> SmallVec<[WidgetId; 16]> {
         self.children
             .iter()
+            .chain(&self.leaving)
             .map(|child| &child.widget)
             .map(|widget_pod| widget_pod.id())
             .collect()
@@ -386,8 +824,9 @@ mod tests {
 
     use super::*;
     use crate::assert_render_snapshot;
+    use crate::core::{Action, PointerButton};
     use crate::testing::TestHarness;
-    use crate::widgets::{Label, SizedBox};
+    use crate::widgets::{Button, CrossAxisAlignment, Flex, Label, MainAxisAlignment, SizedBox};
 
     #[test]
     fn zstack_alignments_parent_aligned() {
@@ -451,4 +890,338 @@ mod tests {
         let mut harness = TestHarness::create(widget);
         assert_render_snapshot!(harness, "zstack_alignments_self_aligned");
     }
+
+    #[test]
+    fn zstack_child_positions() {
+        let [badge_id, offset_id] = crate::testing::widget_ids();
+        let widget = ZStack::new()
+            .with_child(
+                SizedBox::empty().width(100.).height(80.),
+                ChildAlignment::ParentAligned,
+            )
+            .with_child_id(
+                SizedBox::empty().width(10.).height(10.),
+                badge_id,
+                Alignment::TopTrailing,
+            )
+            .with_child_id(
+                SizedBox::empty().width(10.).height(10.),
+                offset_id,
+                ChildAlignment::Offset(Vec2::new(30., 40.)),
+            );
+
+        // The root widget fills the window, so we size the window to the background.
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 80.));
+        let origin =
+            |harness: &TestHarness, id| harness.get_widget(id).ctx().local_layout_rect().origin();
+        assert_eq!(origin(&harness, badge_id), Point::new(90., 0.));
+        assert_eq!(origin(&harness, offset_id), Point::new(30., 40.));
+
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::set_child_alignment(&mut zstack, 1, Alignment::BottomLeading);
+        });
+        assert_eq!(origin(&harness, badge_id), Point::new(0., 70.));
+    }
+
+    #[test]
+    fn zstack_reorder_children() {
+        let [back_id, middle_id, front_id] = crate::testing::widget_ids();
+        let widget = ZStack::new()
+            .with_child_id(
+                SizedBox::empty().width(100.).height(80.),
+                back_id,
+                Alignment::TopLeading,
+            )
+            .with_child_id(
+                SizedBox::empty().width(10.).height(10.),
+                middle_id,
+                ChildAlignment::Offset(Vec2::new(30., 40.)),
+            )
+            .with_child_id(
+                SizedBox::empty().width(10.).height(10.),
+                front_id,
+                Alignment::BottomTrailing,
+            );
+
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 80.));
+        let child_ids = |harness: &TestHarness| {
+            harness
+                .root_widget()
+                .children()
+                .iter()
+                .map(|child| child.id())
+                .collect::<Vec<_>>()
+        };
+        let origin =
+            |harness: &TestHarness, id| harness.get_widget(id).ctx().local_layout_rect().origin();
+
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::move_child(&mut zstack, 0, 2);
+        });
+        assert_eq!(child_ids(&harness), [middle_id, front_id, back_id]);
+
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::swap_children(&mut zstack, 0, 2);
+        });
+        assert_eq!(child_ids(&harness), [back_id, front_id, middle_id]);
+
+        // The alignment of each child moves along with it.
+        assert_eq!(origin(&harness, back_id), Point::ZERO);
+        assert_eq!(origin(&harness, middle_id), Point::new(30., 40.));
+        assert_eq!(origin(&harness, front_id), Point::new(90., 70.));
+    }
+
+    #[test]
+    fn zstack_union_sizing() {
+        let [wide_id, tall_id] = crate::testing::widget_ids();
+        let widget = ZStack::new()
+            .with_child_id(
+                SizedBox::empty().width(100.).height(20.),
+                wide_id,
+                ChildAlignment::ParentAligned,
+            )
+            .with_child_id(
+                SizedBox::empty().width(30.).height(60.),
+                tall_id,
+                Alignment::BottomTrailing,
+            );
+
+        // The stack is the root, so we wrap it to avoid it being stretched to the window.
+        let mut harness = TestHarness::create(Flex::column().with_child(widget));
+        let zstack_id = harness.root_widget().children()[0].id();
+        assert_eq!(
+            harness.get_widget(zstack_id).ctx().size(),
+            Size::new(100., 60.)
+        );
+        let origin =
+            |harness: &TestHarness, id| harness.get_widget(id).ctx().local_layout_rect().origin();
+        assert_eq!(origin(&harness, wide_id), Point::new(0., 20.));
+        assert_eq!(origin(&harness, tall_id), Point::new(70., 0.));
+
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            let mut zstack = Flex::child_mut(&mut flex, 0).unwrap();
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::set_sizing(&mut zstack, ZStackSizing::Fixed(Size::new(50., 50.)));
+        });
+        assert_eq!(
+            harness.get_widget(zstack_id).ctx().size(),
+            Size::new(50., 50.)
+        );
+        assert_eq!(
+            harness.get_widget(wide_id).ctx().size(),
+            Size::new(50., 20.)
+        );
+    }
+
+    #[test]
+    fn zstack_first_child_sizing() {
+        let [overlay_id, small_id] = crate::testing::widget_ids();
+        let widget = ZStack::new()
+            .with_sizing(ZStackSizing::FirstChild)
+            .with_child(
+                SizedBox::empty().width(100.).height(80.),
+                ChildAlignment::ParentAligned,
+            )
+            .with_child_id(
+                SizedBox::empty().width(300.).height(300.),
+                overlay_id,
+                ChildAlignment::ParentAligned,
+            )
+            .with_child_id(
+                SizedBox::empty().width(20.).height(20.),
+                small_id,
+                ChildAlignment::ParentAligned,
+            );
+
+        // The stack is the root, so we wrap it to avoid it being stretched to the window.
+        let mut harness = TestHarness::create(Flex::column().with_child(widget));
+        // Overlays cover the first child exactly, whether they're larger or smaller.
+        let overlay_size = harness.get_widget(overlay_id).ctx().size();
+        assert_eq!(overlay_size, Size::new(100., 80.));
+        let small_size = harness.get_widget(small_id).ctx().size();
+        assert_eq!(small_size, Size::new(100., 80.));
+
+        harness.edit_root_widget(|mut flex| {
+            let mut flex = flex.downcast::<Flex>();
+            let mut zstack = Flex::child_mut(&mut flex, 0).unwrap();
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::set_sizing(&mut zstack, ZStackSizing::UnionOfChildren);
+        });
+        let overlay_size = harness.get_widget(overlay_id).ctx().size();
+        assert_eq!(overlay_size, Size::new(300., 300.));
+    }
+
+    #[test]
+    fn zstack_background_under_children() {
+        let size = Size::new(50.0, 50.0);
+        let render = |background: Option<Color>, child_size: f64| {
+            let child = SizedBox::empty()
+                .width(child_size)
+                .height(child_size)
+                .background(palette::css::BLUE);
+            let widget = ZStack::new()
+                .with_sizing(ZStackSizing::Fixed(size))
+                .with_background(background)
+                .with_child(child, ChildAlignment::ParentAligned);
+            let mut harness = TestHarness::create_with_size(widget, size);
+            harness.render()
+        };
+
+        // A child covering the whole stack hides the background beneath it.
+        assert!(render(Some(palette::css::RED), 50.0) == render(None, 50.0));
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel,
+        // so the images can't differ.
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            // Around a smaller child, the background fills the rest of the stack.
+            assert!(render(Some(palette::css::RED), 20.0) != render(None, 20.0));
+        }
+    }
+
+    #[test]
+    fn zstack_clip_overflowing_child() {
+        let [button_id] = crate::testing::widget_ids();
+        let harness = |clip: bool| {
+            // The child overflows the bottom trailing corner of the stack.
+            let child = SizedBox::new_with_id(Button::new("Go"), button_id)
+                .width(50.0)
+                .height(50.0)
+                .background(palette::css::BLUE);
+            let stack = ZStack::new()
+                .with_sizing(ZStackSizing::Fixed(Size::new(50.0, 50.0)))
+                .with_clip(clip)
+                .with_child(child, ChildAlignment::Offset(Vec2::new(25.0, 25.0)));
+            // The root widget fills the window, so the stack is placed in a flex.
+            let root = Flex::column()
+                .main_axis_alignment(MainAxisAlignment::Start)
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .with_child(stack);
+            TestHarness::create_with_size(root, Size::new(100.0, 100.0))
+        };
+        let click_outside_stack = |harness: &mut TestHarness| {
+            harness.mouse_move((60.0, 60.0));
+            harness.mouse_button_press(PointerButton::Primary);
+            harness.mouse_button_release(PointerButton::Primary);
+            harness.pop_action()
+        };
+
+        let mut unclipped = harness(false);
+        let mut clipped = harness(true);
+        assert_eq!(
+            click_outside_stack(&mut unclipped),
+            Some((Action::ButtonPressed(PointerButton::Primary), button_id))
+        );
+        assert_eq!(click_outside_stack(&mut clipped), None);
+
+        if !std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            assert!(
+                unclipped.render() != clipped.render(),
+                "The overflowing part of the child should not be painted"
+            );
+        }
+    }
+
+    #[test]
+    fn zstack_insert_and_move_children() {
+        let [a_id, b_id, c_id] = crate::testing::widget_ids();
+        let widget = ZStack::new()
+            .with_child_id(Label::new("A"), a_id, ChildAlignment::ParentAligned)
+            .with_child_id(Label::new("C"), c_id, ChildAlignment::ParentAligned);
+
+        let mut harness = TestHarness::create(widget);
+        let child_ids = |harness: &TestHarness| {
+            harness
+                .root_widget()
+                .children()
+                .iter()
+                .map(|child| child.id())
+                .collect::<Vec<_>>()
+        };
+
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            let child = WidgetPod::new_with_id(Label::new("B"), b_id);
+            ZStack::insert_transitioned_child_pod_at(
+                &mut zstack,
+                1,
+                child.erased(),
+                ChildAlignment::ParentAligned,
+                Transition::NONE,
+            );
+        });
+        assert_eq!(child_ids(&harness), [a_id, b_id, c_id]);
+
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::move_child(&mut zstack, 2, 0);
+        });
+        assert_eq!(child_ids(&harness), [c_id, a_id, b_id]);
+    }
+
+    #[test]
+    fn zstack_transition_lingers() {
+        let [back_id, fading_id] = crate::testing::widget_ids();
+        let widget = ZStack::new().with_child_id(
+            SizedBox::empty().width(100.).height(80.),
+            back_id,
+            ChildAlignment::ParentAligned,
+        );
+
+        let mut harness = TestHarness::create_with_size(widget, Size::new(100., 80.));
+        let child_ids = |harness: &TestHarness| {
+            harness
+                .root_widget()
+                .children()
+                .iter()
+                .map(|child| child.id())
+                .collect::<Vec<_>>()
+        };
+
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            let child = WidgetPod::new_with_id(SizedBox::empty().width(10.).height(10.), fading_id);
+            ZStack::insert_transitioned_child_pod(
+                &mut zstack,
+                child.erased(),
+                ChildAlignment::ParentAligned,
+                Transition::fade(Duration::from_millis(100)).with_scale(0.5),
+            );
+        });
+        harness.animate_ms(0);
+        // The child starts hidden, and scaled down about its center.
+        assert_eq!(
+            harness.get_widget(fading_id).ctx().transform(),
+            Affine::scale_about(0.5, Point::new(5., 5.))
+        );
+        harness.animate_ms(100);
+        assert_eq!(
+            harness.get_widget(fading_id).ctx().transform(),
+            Affine::IDENTITY
+        );
+
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::remove_child(&mut zstack, 1);
+        });
+        // The child is still in the tree whilst it animates out.
+        assert_eq!(child_ids(&harness), [back_id, fading_id]);
+        harness.animate_ms(0);
+        harness.animate_ms(60);
+        assert_eq!(child_ids(&harness), [back_id, fading_id]);
+        harness.animate_ms(30);
+        assert_eq!(child_ids(&harness), [back_id, fading_id]);
+        harness.animate_ms(20);
+        assert_eq!(child_ids(&harness), [back_id]);
+        assert!(harness.try_get_widget(fading_id).is_none());
+
+        // Children without a transition are removed immediately.
+        harness.edit_root_widget(|mut zstack| {
+            let mut zstack = zstack.downcast::<ZStack>();
+            ZStack::remove_child(&mut zstack, 0);
+        });
+        assert!(child_ids(&harness).is_empty());
+    }
 }
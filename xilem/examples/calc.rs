@@ -269,7 +269,7 @@ fn expanded_button(
     text: impl Into<Label>,
     callback: impl Fn(&mut Calculator) + Send + Sync + 'static,
 ) -> impl WidgetView<Calculator> {
-    sized_box(button(text, callback)).expand()
+    sized_box(button(text.into(), callback)).expand()
 }
 
 /// Returns an expanded button that triggers the calculator's operator handler,
@@ -24,7 +24,9 @@ fn big_button(
     label: impl Into<Label>,
     callback: impl Fn(&mut i32) + Send + Sync + 'static,
 ) -> impl WidgetView<i32> {
-    sized_box(button(label, callback)).width(40.).height(40.)
+    sized_box(button(label.into(), callback))
+        .width(40.)
+        .height(40.)
 }
 
 fn app_logic(data: &mut i32) -> impl WidgetView<i32> + use<> {
@@ -50,7 +52,8 @@ struct ExternalApp {
 
 impl ApplicationHandler<MasonryUserEvent> for ExternalApp {
     fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.masonry_state.handle_resumed(event_loop);
+        self.masonry_state
+            .handle_resumed(event_loop, self.app_driver.as_mut());
     }
 
     fn suspended(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
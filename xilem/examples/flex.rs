@@ -14,7 +14,9 @@ fn big_button(
     label: impl Into<Label>,
     callback: impl Fn(&mut i32) + Send + Sync + 'static,
 ) -> impl WidgetView<i32> {
-    sized_box(button(label, callback)).width(40.).height(40.)
+    sized_box(button(label.into(), callback))
+        .width(40.)
+        .height(40.)
 }
 
 fn app_logic(data: &mut i32) -> impl WidgetView<i32> + use<> {
@@ -7,7 +7,7 @@ use std::f64::consts::{PI, TAU};
 
 use winit::error::EventLoopError;
 use xilem::view::{GridExt as _, button, grid, label, sized_box, transformed};
-use xilem::{Affine, Color, EventLoop, Vec2, WidgetView, Xilem};
+use xilem::{Color, EventLoop, Vec2, WidgetView, Xilem};
 
 struct TransformsGame {
     rotation: f64,
@@ -49,7 +49,7 @@ impl TransformsGame {
             // In an actual app, you wouldn't use both `transformed` and `.transform`.
             // This is here to validate that Xilem's support for nested `Transformed`
             // values works as expected.
-            status.transform(Affine::translate(self.translation)),
+            transformed(status).translate(self.translation),
         )
         .rotate(self.rotation)
         .scale(self.scale);
@@ -3,9 +3,12 @@
 
 use std::sync::Arc;
 
-use masonry::app::{AppDriver, EventLoopProxy, MasonryState, MasonryUserEvent};
-use masonry::core::WidgetId;
+use masonry::app::{
+    AppDriver, DriverCtx, EventLoopProxy, MasonryState, MasonryUserEvent, RenderRoot,
+};
+use masonry::core::{Widget, WidgetId, WidgetRef};
 use masonry::widgets::RootWidget;
+use winit::window::Theme as SystemTheme;
 
 use crate::core::{DynMessage, Message, MessageResult, ProxyError, RawProxy, ViewId};
 use crate::{ViewCtx, WidgetView};
@@ -18,8 +21,11 @@ pub struct MasonryDriver<State, Logic, View, ViewState> {
     pub(crate) view_state: ViewState,
     // Fonts which will be registered on startup.
     pub(crate) fonts: Vec<Vec<u8>>,
+    pub(crate) on_system_theme_changed: Option<SystemThemeCallback<State>>,
 }
 
+pub(crate) type SystemThemeCallback<State> = Box<dyn FnMut(&mut State, SystemTheme)>;
+
 /// The `WidgetId` which async events should be sent to.
 pub const ASYNC_MARKER_WIDGET: WidgetId = WidgetId::reserved(0x1000);
 
@@ -109,23 +115,20 @@ where
             }
         };
         if rebuild {
-            let next_view = (self.logic)(&mut self.state);
-
-            masonry_ctx.render_root().edit_root_widget(|mut root| {
-                let mut root = root.downcast::<RootWidget<View::Widget>>();
-                next_view.rebuild(
-                    &self.current_view,
-                    &mut self.view_state,
-                    &mut self.ctx,
-                    RootWidget::child_mut(&mut root),
-                );
-                self.current_view = next_view;
-            });
+            self.rebuild(masonry_ctx);
         }
         if cfg!(debug_assertions) && rebuild && !masonry_ctx.content_changed() {
             tracing::debug!("Nothing changed as result of action");
         }
     }
+    fn on_system_theme_changed(&mut self, masonry_ctx: &mut DriverCtx<'_>, theme: SystemTheme) {
+        let Some(callback) = &mut self.on_system_theme_changed else {
+            return;
+        };
+        callback(&mut self.state, theme);
+        self.rebuild(masonry_ctx);
+    }
+
     fn on_start(&mut self, state: &mut MasonryState) {
         let root = state.get_root();
         // Register all provided fonts
@@ -135,5 +138,53 @@ where
             // because we don't have an easy way to return this to the application.
             drop(root.register_fonts(font));
         }
+        // Apply any focus requested whilst building the initial view.
+        if let Some(id) = self.ctx.take_focus_request() {
+            focus_within(root, id);
+        }
     }
 }
+
+impl<State, Logic, View> MasonryDriver<State, Logic, View, View::ViewState>
+where
+    Logic: FnMut(&mut State) -> View,
+    View: WidgetView<State>,
+{
+    /// Run the app logic, and update the widget tree to match the new view.
+    fn rebuild(&mut self, masonry_ctx: &mut DriverCtx<'_>) {
+        let next_view = (self.logic)(&mut self.state);
+
+        masonry_ctx.render_root().edit_root_widget(|mut root| {
+            let mut root = root.downcast::<RootWidget<View::Widget>>();
+            next_view.rebuild(
+                &self.current_view,
+                &mut self.view_state,
+                &mut self.ctx,
+                RootWidget::child_mut(&mut root),
+            );
+            self.current_view = next_view;
+        });
+        if let Some(id) = self.ctx.take_focus_request() {
+            focus_within(masonry_ctx.render_root(), id);
+        }
+    }
+}
+
+/// Focus the widget `id`, or its first descendant which accepts focus.
+fn focus_within(render_root: &mut RenderRoot, id: WidgetId) {
+    fn first_focusable(widget: WidgetRef<'_, dyn Widget>) -> Option<WidgetId> {
+        if widget.ctx().is_disabled() || widget.ctx().is_stashed() {
+            return None;
+        }
+        if widget.accepts_focus() {
+            return Some(widget.id());
+        }
+        widget.children().into_iter().find_map(first_focusable)
+    }
+
+    let Some(target) = render_root.get_widget(id).and_then(first_focusable) else {
+        tracing::warn!("Focus was requested for {id}, but it has no focusable widgets");
+        return;
+    };
+    render_root.edit_widget(target, |mut widget| widget.ctx.request_focus());
+}
@@ -133,17 +133,22 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use masonry::core::{FromDynWidget, Widget, WidgetId, WidgetMut, WidgetPod};
+use masonry::core::{ArcStr, FromDynWidget, Widget, WidgetId, WidgetMut, WidgetPod};
 use masonry::dpi::LogicalSize;
 use masonry::widgets::RootWidget;
-use view::{Transformed, transformed};
+use view::{
+    Autofocus, Clip, ClipShape, ContextMenu, Disabled, FocusHandle, FocusWith, Gestures, Hotkey,
+    Hotkeys, MenuItem, OnHover, Opacity, TabIndex, Tooltip, TransformedBox, autofocus, clip,
+    context_menu, focus_with, opacity, tab_index, tooltip, transformed_box,
+};
 use winit::error::EventLoopError;
-use winit::window::{Window, WindowAttributes};
+use winit::window::{Theme as SystemTheme, Window, WindowAttributes};
 
 use crate::core::{
     AsyncCtx, MessageResult, Mut, RawProxy, SuperElement, View, ViewElement, ViewId,
     ViewPathTracker, ViewSequence,
 };
+use crate::driver::SystemThemeCallback;
 pub use masonry::app::{EventLoop, EventLoopBuilder};
 pub use masonry::kurbo::{Affine, Vec2};
 pub use masonry::parley::Alignment as TextAlignment;
@@ -164,6 +169,7 @@ mod one_of;
 
 pub mod view;
 pub use any_view::AnyWidgetView;
+use driver::SystemThemeCallback;
 pub use driver::{ASYNC_MARKER_WIDGET, MasonryDriver, MasonryProxy, async_action};
 
 /// Runtime builder.
@@ -175,6 +181,7 @@ pub struct Xilem<State, Logic> {
     background_color: Color,
     // Font data to include in loading.
     fonts: Vec<Vec<u8>>,
+    on_system_theme_changed: Option<SystemThemeCallback<State>>,
 }
 
 impl<State, Logic, View> Xilem<State, Logic>
@@ -190,6 +197,7 @@ where
             runtime,
             background_color: Color::BLACK,
             fonts: Vec::new(),
+            on_system_theme_changed: None,
         }
     }
 
@@ -207,6 +215,48 @@ where
         self
     }
 
+    /// Call `callback` when the platform's light or dark preference changes.
+    ///
+    /// This is also called with the initial preference when the window is created,
+    /// before its first frame is painted.
+    /// The app logic is run again after `callback`, so the preference can be stored
+    /// in the app state and used to choose a [theme](view::theme).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use winit::error::EventLoopError;
+    /// use winit::window::Theme as SystemTheme;
+    /// use xilem::view::{Theme, label, theme};
+    /// use xilem::{EventLoop, WidgetView, Xilem};
+    ///
+    /// struct AppState {
+    ///     dark_mode: bool,
+    /// }
+    ///
+    /// fn app_logic(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+    ///     let colors = if state.dark_mode { Theme::dark() } else { Theme::light() };
+    ///     theme(colors, label("Hello"))
+    /// }
+    ///
+    /// fn main() -> Result<(), EventLoopError> {
+    ///     let app = Xilem::new(AppState { dark_mode: true }, app_logic).on_system_theme_changed(
+    ///         |state: &mut AppState, system_theme| {
+    ///             state.dark_mode = system_theme == SystemTheme::Dark;
+    ///         },
+    ///     );
+    ///     app.run_windowed(EventLoop::with_user_event(), "Follows the system".into())?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn on_system_theme_changed(
+        mut self,
+        callback: impl FnMut(&mut State, SystemTheme) + 'static,
+    ) -> Self {
+        self.on_system_theme_changed = Some(Box::new(callback));
+        self
+    }
+
     // TODO: Make windows a specific view
     /// Run app with default window attributes.
     pub fn run_windowed(
@@ -261,6 +311,7 @@ where
             id_path: Vec::new(),
             proxy,
             runtime: self.runtime,
+            focus_request: None,
         };
         let (pod, view_state) = first_view.build(&mut ctx);
         let root_widget = RootWidget::from_pod(pod.into_widget_pod());
@@ -271,6 +322,7 @@ where
             ctx,
             view_state,
             fonts: self.fonts,
+            on_system_theme_changed: self.on_system_theme_changed,
         };
         (root_widget, driver)
     }
@@ -291,8 +343,7 @@ pub struct Pod<W: Widget + FromDynWidget + ?Sized> {
     pub id: WidgetId,
     /// The transform the widget will be created with.
     ///
-    /// If changing transforms of widgets, prefer to use [`transformed`]
-    /// (or [`WidgetView::transform`]).
+    /// If changing transforms of widgets, prefer to use [`transformed`](view::transformed).
     /// This has a protocol to ensure that multiple views changing the
     /// transform interoperate successfully.
     pub transform: Affine,
@@ -390,16 +441,250 @@ pub trait WidgetView<State, Action = ()>:
         Box::new(self)
     }
 
-    /// This widget with a 2d transform applied.
+    /// This widget with a 2d transform applied about its center.
+    ///
+    /// See [`transformed_box`] for details.
+    /// The return type also has builder methods for further transforms, which are
+    /// applied about the center too, as for [`rotate`](Self::rotate) and [`scale`](Self::scale).
+    fn transform(self, by: Affine) -> TransformedBox<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        transformed_box(self).transform(by)
+    }
+
+    /// This widget, rotated by `radians` radians about its center.
+    ///
+    /// See [`transformed_box`] for details.
+    /// The return type also has builder methods for further transforms.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// use xilem::{view::label, WidgetView};
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+    /// label("→").rotate(FRAC_PI_4)
+    /// # }
+    /// ```
+    fn rotate(self, radians: f64) -> TransformedBox<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        transformed_box(self).rotate(radians)
+    }
+
+    /// This widget, scaled by `factor` about its center.
+    ///
+    /// See [`transformed_box`] for details.
+    /// The return type also has builder methods for further transforms.
+    fn scale(self, factor: f64) -> TransformedBox<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        transformed_box(self).scale(factor)
+    }
+
+    /// This widget, painted with `alpha`, from 0.0 (invisible) to 1.0 (opaque).
+    ///
+    /// See [`opacity`] for details.
+    fn opacity(self, alpha: f64) -> Opacity<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        opacity(self, alpha)
+    }
+
+    /// This widget, clipped to `shape`.
+    ///
+    /// See [`clip`] for details.
+    fn clip(self, shape: ClipShape) -> Clip<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        clip(self, shape)
+    }
+
+    /// This widget, with its corners rounded by `radius`.
+    ///
+    /// See [`clip`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use xilem::{palette, view::{label, sized_box}, WidgetView};
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+    /// sized_box(label("Card")).background(palette::css::DARK_SLATE_GRAY).clip_rounded(8.)
+    /// # }
+    /// ```
+    fn clip_rounded(self, radius: f64) -> Clip<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        clip(self, ClipShape::rounded(radius))
+    }
+
+    /// This widget, with `text` shown in a tooltip when the pointer rests on it.
+    ///
+    /// See [`tooltip`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use xilem::{view::button, WidgetView};
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+    /// button("Save", |_| {}).tooltip("Save the current file")
+    /// # }
+    /// ```
+    fn tooltip(self, text: impl Into<ArcStr>) -> Tooltip<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        tooltip(self, text)
+    }
+
+    /// This widget, with a menu of `items` shown when it is right-clicked.
+    ///
+    /// See [`context_menu`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use xilem::{view::{MenuItem, label}, WidgetView};
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+    /// label("Right-click me").context_menu(vec![MenuItem::new("Copy")], |_, _| {})
+    /// # }
+    /// ```
+    fn context_menu<F>(self, items: Vec<MenuItem>, on_select: F) -> ContextMenu<Self, State, Action>
+    where
+        Self: Sized,
+        F: Fn(&mut State, &[usize]) -> Action + Send + Sync + 'static,
+    {
+        context_menu(self, items, on_select)
+    }
+
+    /// This widget, focused when it is first built.
+    ///
+    /// If it doesn't accept focus itself (such as a textbox), its first descendant
+    /// which does is focused instead.
+    /// See [`autofocus`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use xilem::{view::textbox, WidgetView};
+    ///
+    /// # fn view() -> impl WidgetView<String> + use<> {
+    /// textbox(String::new(), |query: &mut String, text| *query = text).focus_on_build()
+    /// # }
+    /// ```
+    fn focus_on_build(self) -> Autofocus<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        autofocus(self)
+    }
+
+    /// This widget, focused whenever focus is requested through `handle`.
+    ///
+    /// See [`focus_with`] for details.
+    fn focus_with(self, handle: FocusHandle) -> FocusWith<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        focus_with(self, handle)
+    }
+
+    /// This widget, at position `index` in the order Tab moves focus in.
+    ///
+    /// See [`tab_index`] for details.
+    fn tab_index(self, index: i32) -> TabIndex<Self, State, Action>
+    where
+        Self: Sized,
+    {
+        tab_index(self, index)
+    }
+
+    /// This widget, calling `callback` whenever `hotkey` is pressed.
+    ///
+    /// See [`hotkey`](view::hotkey) for details.
+    fn hotkey<F>(self, hotkey: Hotkey, callback: F) -> Hotkeys<Self, State, Action>
+    where
+        Self: Sized,
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        view::hotkey(self, hotkey, callback)
+    }
+
+    /// This widget, calling `callback` when it is double clicked.
+    ///
+    /// Further gesture callbacks can be chained onto the result.
+    /// See [`gestures`](view::gestures) for details.
+    fn on_double_click<F>(self, callback: F) -> Gestures<Self, State, Action>
+    where
+        Self: Sized,
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        view::gestures(self).on_double_click(callback)
+    }
+
+    /// This widget, calling `callback` when it is pressed and held.
+    ///
+    /// Further gesture callbacks can be chained onto the result.
+    /// See [`gestures`](view::gestures) for details.
+    fn on_long_press<F>(self, callback: F) -> Gestures<Self, State, Action>
+    where
+        Self: Sized,
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        view::gestures(self).on_long_press(callback)
+    }
+
+    /// This widget, calling `callback` as it is dragged.
+    ///
+    /// Further gesture callbacks can be chained onto the result.
+    /// See [`gestures`](view::gestures) for details.
+    fn on_drag<F>(self, callback: F) -> Gestures<Self, State, Action>
+    where
+        Self: Sized,
+        F: Fn(&mut State, masonry::kurbo::Point, Vec2, view::DragPhase) -> Action
+            + Send
+            + Sync
+            + 'static,
+    {
+        view::gestures(self).on_drag(callback)
+    }
+
+    /// This widget, calling `callback` when the pointer starts or stops hovering it.
+    ///
+    /// See [`on_hover`](view::on_hover) for details.
+    fn on_hover<F>(self, callback: F) -> OnHover<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&mut State, bool) -> Action + Send + Sync + 'static,
+    {
+        view::on_hover(self, callback)
+    }
+
+    /// This widget, disabled whilst `disabled` is true.
+    ///
+    /// See [`disabled`](view::disabled) for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use xilem::{view::button, WidgetView};
     ///
-    /// See [`transformed`] for similar functionality with a builder-API using this.
-    /// The return type is the same as for `transformed`, and so also has these
-    /// builder methods.
-    fn transform(self, by: Affine) -> Transformed<Self, State, Action>
+    /// # fn view<State: 'static>(saving: bool) -> impl WidgetView<State> + use<State> {
+    /// button("Save", |_| {})
+    ///     .disabled(saving)
+    ///     .tooltip("A save is already in progress")
+    /// # }
+    /// ```
+    fn disabled(self, disabled: bool) -> Disabled<Self, State, Action>
     where
         Self: Sized,
     {
-        transformed(self).transform(by)
+        view::disabled(self, disabled)
     }
 }
 
@@ -445,6 +730,8 @@ pub struct ViewCtx {
     id_path: Vec<ViewId>,
     proxy: Arc<dyn RawProxy>,
     runtime: tokio::runtime::Runtime,
+    /// The widget which should be focused once the current build or rebuild has finished.
+    focus_request: Option<WidgetId>,
 }
 
 impl ViewPathTracker for ViewCtx {
@@ -495,6 +782,19 @@ impl ViewCtx {
     pub fn runtime(&self) -> &tokio::runtime::Runtime {
         &self.runtime
     }
+
+    /// Request that the widget `id` be focused once the view tree has been (re)built.
+    ///
+    /// If that widget doesn't accept focus itself, its first descendant which does is focused instead.
+    /// This allows focusing the text area inside a textbox using the id of the textbox.
+    /// If several views request focus in the same rebuild, the last request wins.
+    pub fn request_focus(&mut self, id: WidgetId) {
+        self.focus_request = Some(id);
+    }
+
+    pub(crate) fn take_focus_request(&mut self) -> Option<WidgetId> {
+        self.focus_request.take()
+    }
 }
 
 impl AsyncCtx for ViewCtx {
@@ -502,3 +802,65 @@ impl AsyncCtx for ViewCtx {
         self.proxy.clone()
     }
 }
+
+/// The messages sent through the proxy of a [`ViewCtx::for_test`].
+#[cfg(test)]
+type TestMessages = std::sync::mpsc::Receiver<(Arc<[ViewId]>, crate::core::DynMessage)>;
+
+#[cfg(test)]
+impl ViewCtx {
+    /// Create a context for building and rebuilding views in tests.
+    ///
+    /// Messages which views send through its proxy (e.g. from async tasks) are
+    /// passed to the returned receiver, rather than to a running app.
+    pub(crate) fn for_test() -> (Self, TestMessages) {
+        Self::for_test_with_runtime(tokio::runtime::Runtime::new().unwrap())
+    }
+
+    /// Create a context like [`for_test`](Self::for_test), whose runtime's clock is paused.
+    ///
+    /// Tasks only run whilst the runtime is driven, e.g. using `block_on`.
+    /// Whilst nothing else is ready to run, the clock skips ahead to the next timer
+    /// (see [`tokio::time::pause`]), so waiting for any amount of time is instant.
+    pub(crate) fn for_test_with_paused_clock() -> (Self, TestMessages) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .unwrap();
+        Self::for_test_with_runtime(runtime)
+    }
+
+    fn for_test_with_runtime(runtime: tokio::runtime::Runtime) -> (Self, TestMessages) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let ctx = Self {
+            widget_map: WidgetMap::default(),
+            id_path: Vec::new(),
+            proxy: Arc::new(TestProxy(sender)),
+            runtime,
+            focus_request: None,
+        };
+        (ctx, receiver)
+    }
+}
+
+/// The proxy of a [`ViewCtx::for_test`].
+#[cfg(test)]
+#[derive(Debug)]
+struct TestProxy(std::sync::mpsc::Sender<(Arc<[ViewId]>, crate::core::DynMessage)>);
+
+#[cfg(test)]
+impl RawProxy for TestProxy {
+    fn send_message(
+        &self,
+        path: Arc<[ViewId]>,
+        message: crate::core::DynMessage,
+    ) -> Result<(), crate::core::ProxyError> {
+        self.0
+            .send((path, message))
+            .map_err(|err| crate::core::ProxyError::DriverFinished(err.0.1))
+    }
+    fn dyn_debug(&self) -> &dyn std::fmt::Debug {
+        self
+    }
+}
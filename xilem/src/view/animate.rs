@@ -0,0 +1,271 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker, ViewPathTracker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which animates a value towards `target` over `duration`, and shows the view
+/// returned by `view` for the current value.
+///
+/// `view` is only given the value, so anything else it uses from the app's state
+/// should be captured by the closure.
+///
+/// Whenever `target` changes, the value moves from where it currently is to the new target,
+/// following the curve given by `easing`. If this happens part way through an animation,
+/// the animation starts again from the current value, so the value never jumps.
+/// When the view is first built, the value starts at `target`.
+///
+/// Whilst animating, the app's view tree is rebuilt for each animation frame, and `view`
+/// is called again with the new value.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use xilem::view::{Easing, animate, progress_bar};
+/// # use xilem::WidgetView;
+///
+/// struct State {
+///     progress: f64,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<State> + use<> {
+/// animate(
+///     state.progress,
+///     Duration::from_millis(300),
+///     Easing::EaseInOut,
+///     |progress| progress_bar(Some(progress)),
+/// )
+/// # }
+/// ```
+pub fn animate<V, F, State, Action>(
+    target: f64,
+    duration: Duration,
+    easing: Easing,
+    view: F,
+) -> Animate<V, F, State, Action>
+where
+    F: Fn(f64) -> V + Send + Sync + 'static,
+    V: WidgetView<State, Action>,
+{
+    Animate {
+        target,
+        duration,
+        easing,
+        view,
+        phantom: PhantomData,
+    }
+}
+
+/// The curve an animated value follows from its start to its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// Move at a constant speed.
+    Linear,
+    /// Start slowly, then speed up.
+    EaseIn,
+    /// Start quickly, then slow down.
+    EaseOut,
+    /// Start slowly, speed up, then slow down again.
+    EaseInOut,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with the control points
+    /// `(x1, y1)` and `(x2, y2)`, as in CSS's `cubic-bezier(x1, y1, x2, y2)`.
+    ///
+    /// `x1` and `x2` are clamped to the range 0 to 1.
+    CubicBezier(f64, f64, f64, f64),
+}
+
+impl Easing {
+    /// How far along the curve the value is when `t` of the time has passed.
+    ///
+    /// `t` is clamped to the range 0 to 1, and the result is 0 at the start and 1 at the end.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => cubic_bezier(0.42, 0., 1., 1., t),
+            Self::EaseOut => cubic_bezier(0., 0., 0.58, 1., t),
+            Self::EaseInOut => cubic_bezier(0.42, 0., 0.58, 1., t),
+            Self::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+/// The y coordinate of the point with the given x coordinate on the cubic Bézier curve
+/// from `(0, 0)` to `(1, 1)` with control points `(x1, y1)` and `(x2, y2)`.
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    // One coordinate of the curve at parameter `s`.
+    let at = |p1: f64, p2: f64, s: f64| {
+        let r = 1. - s;
+        3. * r * r * s * p1 + 3. * r * s * s * p2 + s * s * s
+    };
+    let (x1, x2) = (x1.clamp(0., 1.), x2.clamp(0., 1.));
+    // With the control points' x coordinates in 0..=1, x increases with `s`,
+    // so we can bisect to find the `s` for `x`.
+    let (mut low, mut high) = (0., 1.);
+    for _ in 0..BEZIER_ITERATIONS {
+        let mid = (low + high) / 2.;
+        if at(x1, x2, mid) < x {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    at(y1, y2, (low + high) / 2.)
+}
+
+/// How many times the range of the curve parameter is halved when solving a cubic Bézier.
+const BEZIER_ITERATIONS: u32 = 24;
+
+/// The [`View`] created by [`animate`].
+///
+/// See `animate`'s docs for more details.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Animate<V, F, State, Action> {
+    target: f64,
+    duration: Duration,
+    easing: Easing,
+    view: F,
+    phantom: PhantomData<fn() -> (V, State, Action)>,
+}
+
+impl<V, F, State, Action> Animate<V, F, State, Action> {
+    /// The value after `elapsed` of an animation starting at `from`.
+    fn value_at(&self, from: f64, elapsed: Duration) -> f64 {
+        if elapsed >= self.duration {
+            return self.target;
+        }
+        let t = elapsed.as_secs_f64() / self.duration.as_secs_f64();
+        from + (self.target - from) * self.easing.apply(t)
+    }
+}
+
+/// The state used to implement `View` for [`Animate`].
+#[doc(hidden)] // Implementation detail, public because of trait visibility rules
+pub struct AnimateState<V, ViewState> {
+    /// The child view for the current value.
+    view: V,
+    view_state: ViewState,
+    /// The value at the start of the current animation.
+    from: f64,
+    /// The current value.
+    value: f64,
+    /// The time since the current animation started.
+    elapsed: Duration,
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, F, State, Action> ViewMarker for Animate<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for Animate<V, F, State, Action>
+where
+    F: Fn(f64) -> V + Send + Sync + 'static,
+    V: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Ticker<V::Widget>>;
+    type ViewState = AnimateState<V, V::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let view = (self.view)(self.target);
+        let (child, view_state) = ctx.with_id(CHILD_VIEW_ID, |ctx| view.build(ctx));
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.new_pod(widgets::Ticker::from_pod(child.into_widget_pod()))
+        });
+        let state = AnimateState {
+            view,
+            view_state,
+            from: self.target,
+            value: self.target,
+            elapsed: self.duration,
+        };
+        (pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.target != prev.target {
+            // Start again from the current value, so that interrupted animations don't jump.
+            state.from = state.value;
+            state.elapsed = Duration::ZERO;
+        }
+        state.value = self.value_at(state.from, state.elapsed);
+        widgets::Ticker::set_active(&mut element, state.elapsed < self.duration);
+
+        // The view function can't be compared, so the child is always rebuilt.
+        let view = (self.view)(state.value);
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            view.rebuild(
+                &state.view,
+                &mut state.view_state,
+                ctx,
+                widgets::Ticker::child_mut(&mut element),
+            );
+        });
+        state.view = view;
+    }
+
+    fn teardown(
+        &self,
+        state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            state.view.teardown(
+                &mut state.view_state,
+                ctx,
+                widgets::Ticker::child_mut(&mut element),
+            );
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                state
+                    .view
+                    .message(&mut state.view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    if let masonry::core::Action::AnimationFrame(interval) = *action {
+                        state.elapsed =
+                            (state.elapsed + Duration::from_nanos(interval)).min(self.duration);
+                        MessageResult::RequestRebuild
+                    } else {
+                        tracing::error!("Wrong action type in Animate::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in Animate::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Animate::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
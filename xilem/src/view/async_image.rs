@@ -0,0 +1,373 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use masonry::core::ObjectFit;
+use tokio::task::JoinHandle;
+use vello::peniko::{Blob, ImageFormat};
+
+use crate::core::one_of::Either;
+use crate::core::{
+    DynMessage, MessageProxy, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::view::{Image, Spinner, image, spinner};
+use crate::{ViewCtx, WidgetView};
+
+/// A view which loads and decodes the image file at `path` in the background, and then shows it.
+///
+/// Until the image has loaded, a [`spinner`] is shown, which can be changed using
+/// [`placeholder`](AsyncImage::placeholder).
+/// Once the image has loaded or failed to load, `on_load` is called with the result.
+/// If loading fails, the placeholder continues to be shown, so `on_load` can be used to
+/// record the error and show some fallback UI instead.
+///
+/// The image is read and decoded on the blocking thread pool of Xilem's async runtime.
+/// If `path` changes, the image is loaded again, and the previous load is cancelled.
+/// It is also cancelled if the view is removed before it has finished loading.
+///
+/// To load images from the network, fetch and decode them in a [`worker`](crate::view::worker)
+/// and show the result with [`image`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{async_image, label};
+/// # use xilem::WidgetView;
+///
+/// struct State {
+///     error: Option<String>,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<State> + use<> {
+/// async_image("resources/photo.png", |state: &mut State, result| {
+///     state.error = result.err().map(|err| err.to_string());
+/// })
+/// .placeholder(label("Loading..."))
+/// # }
+/// ```
+pub fn async_image<State, Action, F>(
+    path: impl Into<Arc<str>>,
+    on_load: F,
+) -> AsyncImage<Spinner, State, Action, F>
+where
+    F: Fn(&mut State, Result<vello::peniko::Image, ImageLoadError>) -> Action
+        + Send
+        + Sync
+        + 'static,
+{
+    AsyncImage {
+        path: path.into(),
+        on_load,
+        placeholder: Arc::new(spinner()),
+        object_fit: ObjectFit::default(),
+        phantom: PhantomData,
+    }
+}
+
+/// The reason an [`async_image`] couldn't be loaded.
+#[derive(Debug)]
+pub enum ImageLoadError {
+    /// The file couldn't be read.
+    Io(std::io::Error),
+    /// The file couldn't be decoded.
+    Decode(::image::ImageError),
+}
+
+impl fmt::Display for ImageLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "couldn't read image: {err}"),
+            Self::Decode(err) => write!(f, "couldn't decode image: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Decode(err) => Some(err),
+        }
+    }
+}
+
+/// The [`View`] created by [`async_image`].
+///
+/// See `async_image`'s docs for more details.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct AsyncImage<Placeholder, State, Action, F> {
+    path: Arc<str>,
+    on_load: F,
+    // This is an `Arc` so that the content view can share it.
+    placeholder: Arc<Placeholder>,
+    object_fit: ObjectFit,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Placeholder, State, Action, F> AsyncImage<Placeholder, State, Action, F> {
+    /// Set the view which is shown until the image has loaded.
+    pub fn placeholder<NewPlaceholder>(
+        self,
+        placeholder: NewPlaceholder,
+    ) -> AsyncImage<NewPlaceholder, State, Action, F>
+    where
+        NewPlaceholder: WidgetView<State, Action>,
+    {
+        AsyncImage {
+            path: self.path,
+            on_load: self.on_load,
+            placeholder: Arc::new(placeholder),
+            object_fit: self.object_fit,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Specify the object fit of the loaded image.
+    ///
+    /// See [`Image::fit`] for details.
+    pub fn fit(mut self, fill: ObjectFit) -> Self {
+        self.object_fit = fill;
+        self
+    }
+
+    /// The view which shows `loaded`, or the placeholder if it is `None`.
+    fn content(&self, loaded: Option<&vello::peniko::Image>) -> Content<Placeholder> {
+        match loaded {
+            Some(loaded) => Either::A(image(loaded).fit(self.object_fit)),
+            None => Either::B(self.placeholder.clone()),
+        }
+    }
+}
+
+type Content<Placeholder> = Either<Image, Arc<Placeholder>>;
+
+/// The state used to implement `View` for [`AsyncImage`].
+#[doc(hidden)] // Implementation detail, public because of trait visibility rules
+pub struct AsyncImageState<ContentState> {
+    content: ContentState,
+    /// The image which has been loaded from the current path, if any.
+    loaded: Option<vello::peniko::Image>,
+    /// The image which is currently shown by the content view, if any.
+    ///
+    /// This differs from `loaded` between the image loading and the next rebuild.
+    shown: Option<vello::peniko::Image>,
+    /// Incremented each time the path changes, so that the results of
+    /// previous loads are ignored.
+    generation: u64,
+    handle: JoinHandle<()>,
+}
+
+/// The message sent when an image has been loaded.
+#[derive(Debug)]
+struct ImageLoaded {
+    generation: u64,
+    result: Result<vello::peniko::Image, ImageLoadError>,
+}
+
+const CONTENT_VIEW_ID: ViewId = ViewId::new(0);
+const LOAD_VIEW_ID: ViewId = ViewId::new(1);
+
+/// Read and decode the image at `path`.
+///
+/// This blocks, so should be run on a blocking thread.
+fn load_image(path: &str) -> Result<vello::peniko::Image, ImageLoadError> {
+    let bytes = std::fs::read(path).map_err(ImageLoadError::Io)?;
+    let decoded = ::image::load_from_memory(&bytes)
+        .map_err(ImageLoadError::Decode)?
+        .into_rgba8();
+    let width = decoded.width();
+    let height = decoded.height();
+    Ok(vello::peniko::Image::new(
+        Blob::new(Arc::new(decoded.into_vec())),
+        ImageFormat::Rgba8,
+        width,
+        height,
+    ))
+}
+
+/// Start loading the image at `path` in the background.
+fn spawn_load(ctx: &mut ViewCtx, path: Arc<str>, generation: u64) -> JoinHandle<()> {
+    let id_path: Arc<[ViewId]> = ctx.with_id(LOAD_VIEW_ID, |ctx| ctx.view_path().into());
+    let proxy = MessageProxy::new(ctx.proxy.clone(), id_path);
+    ctx.runtime().spawn(async move {
+        let Ok(result) = tokio::task::spawn_blocking(move || load_image(&path)).await else {
+            // Loading panicked, or the runtime is shutting down.
+            return;
+        };
+        // If the view has been removed, there's nobody to tell.
+        drop(proxy.message(ImageLoaded { generation, result }));
+    })
+}
+
+impl<Placeholder, State, Action, F> ViewMarker for AsyncImage<Placeholder, State, Action, F> {}
+impl<Placeholder, State, Action, F> View<State, Action, ViewCtx>
+    for AsyncImage<Placeholder, State, Action, F>
+where
+    Placeholder: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+    F: Fn(&mut State, Result<vello::peniko::Image, ImageLoadError>) -> Action
+        + Send
+        + Sync
+        + 'static,
+{
+    type Element = <Content<Placeholder> as View<State, Action, ViewCtx>>::Element;
+    type ViewState =
+        AsyncImageState<<Content<Placeholder> as View<State, Action, ViewCtx>>::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (element, content) = ctx.with_id(CONTENT_VIEW_ID, |ctx| self.content(None).build(ctx));
+        let generation = 0;
+        let handle = spawn_load(ctx, self.path.clone(), generation);
+        let state = AsyncImageState {
+            content,
+            loaded: None,
+            shown: None,
+            generation,
+            handle,
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<Self::Element>,
+    ) {
+        if self.path != prev.path {
+            view_state.handle.abort();
+            view_state.generation = view_state.generation.wrapping_add(1);
+            view_state.loaded = None;
+            view_state.handle = spawn_load(ctx, self.path.clone(), view_state.generation);
+        }
+        let content = self.content(view_state.loaded.as_ref());
+        let prev_content = prev.content(view_state.shown.as_ref());
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            content.rebuild(&prev_content, &mut view_state.content, ctx, element);
+        });
+        view_state.shown.clone_from(&view_state.loaded);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<Self::Element>,
+    ) {
+        view_state.handle.abort();
+        let content = self.content(view_state.shown.as_ref());
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            content.teardown(&mut view_state.content, ctx, element);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CONTENT_VIEW_ID, rest)) => {
+                let content = self.content(view_state.shown.as_ref());
+                content.message(&mut view_state.content, rest, message, app_state)
+            }
+            Some((&LOAD_VIEW_ID, [])) => match message.downcast::<ImageLoaded>() {
+                Ok(loaded) if loaded.generation == view_state.generation => {
+                    let ImageLoaded { result, .. } = *loaded;
+                    view_state.loaded = result.as_ref().ok().cloned();
+                    MessageResult::Action((self.on_load)(app_state, result))
+                }
+                // The result of loading a previous path.
+                Ok(loaded) => MessageResult::Stale(loaded),
+                Err(message) => {
+                    tracing::error!("Wrong message type in AsyncImage::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in AsyncImage::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use masonry::testing::TestHarness;
+    use masonry::widgets;
+
+    use super::*;
+
+    /// The size of the loaded image, or the error message.
+    type Loaded = Option<Result<(u32, u32), String>>;
+
+    /// Build an `async_image` of `path`, deliver the result of loading it, and rebuild it.
+    ///
+    /// Returns the result passed to `on_load`, and whether the image is shown afterwards.
+    fn load(path: &Path) -> (Loaded, bool) {
+        let view = async_image(path.to_str().unwrap(), |loaded: &mut Loaded, result| {
+            *loaded = Some(
+                result
+                    .map(|image| (image.width, image.height))
+                    .map_err(|err| err.to_string()),
+            );
+        });
+        let (mut ctx, messages) = ViewCtx::for_test();
+        let (pod, mut state) = view.build(&mut ctx);
+        let mut harness = TestHarness::create(*pod.widget);
+        let shows_image = |harness: &TestHarness| {
+            let content = harness.root_widget().children()[0];
+            content.downcast::<widgets::Image>().is_some()
+        };
+        assert!(
+            !shows_image(&harness),
+            "The placeholder is shown whilst loading"
+        );
+
+        let (id_path, message) = messages.recv_timeout(Duration::from_secs(10)).unwrap();
+        let mut loaded = None;
+        let result = view.message(&mut state, &id_path, message, &mut loaded);
+        assert!(matches!(result, MessageResult::Action(())));
+        harness.edit_root_widget(|mut root| {
+            view.rebuild(&view, &mut state, &mut ctx, root.downcast());
+        });
+        (loaded, shows_image(&harness))
+    }
+
+    #[test]
+    fn shows_loaded_image() {
+        let path =
+            std::env::temp_dir().join(format!("xilem_async_image_{}.png", std::process::id()));
+        ::image::RgbaImage::new(2, 3).save(&path).unwrap();
+        let (loaded, shows_image) = load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, Some(Ok((2, 3))));
+        assert!(shows_image);
+    }
+
+    #[test]
+    fn keeps_placeholder_on_error() {
+        let path =
+            std::env::temp_dir().join(format!("xilem_async_image_{}.txt", std::process::id()));
+        std::fs::write(&path, "Not an image").unwrap();
+        let (loaded, shows_image) = load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let error = loaded.unwrap().unwrap_err();
+        assert!(error.starts_with("couldn't decode image"), "{error}");
+        assert!(!shows_image);
+    }
+}
@@ -1,64 +1,60 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
+use masonry::core::ArcStr;
 pub use masonry::core::PointerButton;
 use masonry::widgets;
+use vello::peniko::Brush;
 use xilem_core::ViewPathTracker;
 
 use crate::core::{DynMessage, Mut, View, ViewMarker};
-use crate::view::Label;
-use crate::{MessageResult, Pod, ViewCtx, ViewId};
+use crate::view::{Label, label};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
 
-/// A button which calls `callback` when the primary mouse button (normally left) is pressed.
+/// A button containing `content`, which calls `callback` when the primary mouse button
+/// (normally left) is pressed.
 ///
-/// # Examples
-/// To use button provide it with a button text and a closure.
-/// ```ignore
-/// use xilem::view::button;
+/// `content` can be text, which is shown in a [`label`], or any other view, such as a
+/// styled `label`, or a [`flex`](crate::view::flex) of an icon and some text.
 ///
-/// struct State {
-///     int: i32,
-/// }
-///
-/// impl State {
-///     fn increase(&mut self) {
-///         self.int += 1;
-///     }
-/// }
+/// # Examples
 ///
-/// button("Button", |state: &mut State| {
-///      state.increase();
-/// })
 /// ```
-///
-/// Create a `button` with a custom `label`.
-///
-/// ```ignore
-/// use xilem::view::{button, label};
+/// use xilem::FontWeight;
+/// use xilem::view::{button, flex, label};
+/// # use xilem::WidgetView;
 ///
 /// struct State {
 ///     int: i32,
 /// }
 ///
-/// impl State {
-///     fn increase(&mut self) {
-///         self.int += 1;
-///     }
-/// }
-///
-/// let label = label("Button").weight(FontWeight::BOLD);
-///
-/// button(label, |state: &mut State| {
-///     state.increase();
-/// })
+/// # fn view(state: &mut State) -> impl WidgetView<State> + use<> {
+/// flex((
+///     button("Button", |state: &mut State| {
+///         state.int += 1;
+///     }),
+///     button(label("Bold button").weight(FontWeight::BOLD), |state: &mut State| {
+///         state.int -= 1;
+///     }),
+/// ))
+/// # }
 /// ```
-pub fn button<State, Action>(
-    label: impl Into<Label>,
+pub fn button<State, Action, Marker, C>(
+    content: C,
     callback: impl Fn(&mut State) -> Action + Send + 'static,
-) -> Button<impl for<'a> Fn(&'a mut State, PointerButton) -> MessageResult<Action> + Send + 'static>
+) -> Button<
+    C::View,
+    impl for<'a> Fn(&'a mut State, PointerButton) -> MessageResult<Action> + Send + 'static,
+>
+where
+    C: ButtonContent<State, Action, Marker>,
 {
     Button {
-        label: label.into(),
+        child: content.into_view(),
+        hover_brush: None,
+        auto_repeat: None,
         callback: move |state: &mut State, button| match button {
             PointerButton::Primary => MessageResult::Action(callback(state)),
             _ => MessageResult::Nop,
@@ -66,92 +62,176 @@ pub fn button<State, Action>(
     }
 }
 
-/// A button which calls `callback` when pressed.
-pub fn button_any_pointer<State, Action>(
-    label: impl Into<Label>,
+/// A button containing `content`, which calls `callback` when pressed with any pointer button.
+///
+/// As with [`button`], `content` can be text or any other view.
+pub fn button_any_pointer<State, Action, Marker, C>(
+    content: C,
     callback: impl Fn(&mut State, PointerButton) -> Action + Send + 'static,
-) -> Button<impl for<'a> Fn(&'a mut State, PointerButton) -> MessageResult<Action> + Send + 'static>
+) -> Button<
+    C::View,
+    impl for<'a> Fn(&'a mut State, PointerButton) -> MessageResult<Action> + Send + 'static,
+>
+where
+    C: ButtonContent<State, Action, Marker>,
 {
     Button {
-        label: label.into(),
+        child: content.into_view(),
+        hover_brush: None,
+        auto_repeat: None,
         callback: move |state: &mut State, button| MessageResult::Action(callback(state, button)),
     }
 }
 
-/// The [`View`] created by [`button`] from a `label` and a callback.
+/// The contents of a [`button`]: either a view, or text which is shown in a [`label`].
+///
+/// The `Marker` parameter only exists to let both kinds of content be supported,
+/// and is inferred.
+pub trait ButtonContent<State, Action, Marker> {
+    /// The view shown inside the button.
+    type View: WidgetView<State, Action>;
+
+    /// Convert this content into the view shown inside the button.
+    fn into_view(self) -> Self::View;
+}
+
+#[doc(hidden)] // Implementation detail, public because of trait visibility rules
+pub struct ViewContent;
+
+#[doc(hidden)] // Implementation detail, public because of trait visibility rules
+pub struct TextContent;
+
+impl<State, Action, V> ButtonContent<State, Action, ViewContent> for V
+where
+    V: WidgetView<State, Action>,
+{
+    type View = V;
+
+    fn into_view(self) -> V {
+        self
+    }
+}
+
+impl<State, Action> ButtonContent<State, Action, TextContent> for &'static str {
+    type View = Label;
+
+    fn into_view(self) -> Label {
+        label(self)
+    }
+}
+
+impl<State, Action> ButtonContent<State, Action, TextContent> for String {
+    type View = Label;
+
+    fn into_view(self) -> Label {
+        label(self)
+    }
+}
+
+impl<State, Action> ButtonContent<State, Action, TextContent> for ArcStr {
+    type View = Label;
+
+    fn into_view(self) -> Label {
+        label(self)
+    }
+}
+
+/// The [`View`] created by [`button`] from a `child` and a callback.
 ///
 /// See `button` documentation for more context.
 #[must_use = "View values do nothing unless provided to Xilem."]
-pub struct Button<F> {
-    // N.B. This widget is *implemented* to handle any kind of view with an element
-    // type of `Label` even though it currently does not do so.
-    label: Label,
+pub struct Button<V, F> {
+    child: V,
+    hover_brush: Option<Brush>,
+    auto_repeat: Option<(Duration, Duration)>,
     callback: F,
 }
 
-const LABEL_VIEW_ID: ViewId = ViewId::new(0);
+impl<V, F> Button<V, F> {
+    /// Set the brush which fills the button whilst the pointer is over it.
+    pub fn hover_brush(mut self, brush: impl Into<Brush>) -> Self {
+        self.hover_brush = Some(brush.into());
+        self
+    }
+
+    /// Call the callback repeatedly whilst the button is held down, like the arrows of a spin box.
+    ///
+    /// The callback is called as soon as the button is pressed, then once it has been held
+    /// for `initial_delay`, and then every `interval` until it is released.
+    /// See [`masonry::widgets::Button::with_auto_repeat`] for details.
+    pub fn auto_repeat(mut self, initial_delay: Duration, interval: Duration) -> Self {
+        self.auto_repeat = Some((initial_delay, interval));
+        self
+    }
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
 
-impl<F> ViewMarker for Button<F> {}
-impl<F, State, Action> View<State, Action, ViewCtx> for Button<F>
+impl<V, F> ViewMarker for Button<V, F> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for Button<V, F>
 where
+    V: WidgetView<State, Action>,
     F: Fn(&mut State, PointerButton) -> MessageResult<Action> + Send + Sync + 'static,
+    State: 'static,
+    Action: 'static,
 {
-    type Element = Pod<widgets::Button>;
-    type ViewState = ();
+    type Element = Pod<widgets::Button<V::Widget>>;
+    type ViewState = V::ViewState;
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
-        let (child, ()) = ctx.with_id(LABEL_VIEW_ID, |ctx| {
-            View::<State, Action, _>::build(&self.label, ctx)
-        });
-        ctx.with_leaf_action_widget(|ctx| {
-            ctx.new_pod(widgets::Button::from_label_pod(child.into_widget_pod()))
-        })
+        let (child, child_state) = ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx));
+        let mut button = widgets::Button::from_child(child.into_widget_pod())
+            .with_hover_brush(self.hover_brush.clone());
+        if let Some((initial_delay, interval)) = self.auto_repeat {
+            button = button.with_auto_repeat(initial_delay, interval);
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.new_pod(button));
+        (pod, child_state)
     }
 
     fn rebuild(
         &self,
         prev: &Self,
-        state: &mut Self::ViewState,
+        view_state: &mut Self::ViewState,
         ctx: &mut ViewCtx,
         mut element: Mut<Self::Element>,
     ) {
-        ctx.with_id(LABEL_VIEW_ID, |ctx| {
-            View::<State, Action, _>::rebuild(
-                &self.label,
-                &prev.label,
-                state,
-                ctx,
-                widgets::Button::label_mut(&mut element),
-            );
+        if self.hover_brush != prev.hover_brush {
+            widgets::Button::set_hover_brush(&mut element, self.hover_brush.clone());
+        }
+        if self.auto_repeat != prev.auto_repeat {
+            widgets::Button::set_auto_repeat(&mut element, self.auto_repeat);
+        }
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::Button::child_mut(&mut element);
+            self.child.rebuild(&prev.child, view_state, ctx, child);
         });
     }
 
     fn teardown(
         &self,
-        _: &mut Self::ViewState,
+        view_state: &mut Self::ViewState,
         ctx: &mut ViewCtx,
         mut element: Mut<Self::Element>,
     ) {
-        ctx.with_id(LABEL_VIEW_ID, |ctx| {
-            View::<State, Action, _>::teardown(
-                &self.label,
-                &mut (),
-                ctx,
-                widgets::Button::label_mut(&mut element),
-            );
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::Button::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child);
         });
         ctx.teardown_leaf(element);
     }
 
     fn message(
         &self,
-        _: &mut Self::ViewState,
+        view_state: &mut Self::ViewState,
         id_path: &[ViewId],
         message: DynMessage,
         app_state: &mut State,
     ) -> MessageResult<Action> {
         match id_path.split_first() {
-            Some((&LABEL_VIEW_ID, rest)) => self.label.message(&mut (), rest, message, app_state),
+            Some((&CHILD_VIEW_ID, rest)) => {
+                self.child.message(view_state, rest, message, app_state)
+            }
             None => match message.downcast::<masonry::core::Action>() {
                 Ok(action) => {
                     if let masonry::core::Action::ButtonPressed(button) = *action {
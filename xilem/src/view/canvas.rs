@@ -0,0 +1,196 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+pub use masonry::core::CanvasPointerEvent;
+use masonry::core::PaintCtx;
+use masonry::kurbo::Size;
+use masonry::widgets;
+use vello::Scene;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId};
+
+type PointerCallback<State, Action> =
+    Box<dyn Fn(&mut State, &CanvasPointerEvent) -> Action + Send + Sync + 'static>;
+
+/// A view which paints using `painter`, for simple custom drawings such as sparklines and charts.
+///
+/// `painter` is given the size of the canvas, and anything it paints outside of that is clipped.
+/// The canvas fills the space given to it by its parent, unless a
+/// [preferred size](Canvas::preferred_size) is set.
+///
+/// As closures can't be compared, the canvas is repainted every time it is rebuilt.
+/// To avoid this, give it a [`key`](Canvas::key) which changes whenever the drawing should.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::canvas;
+/// use masonry::kurbo::{Affine, BezPath, Stroke};
+/// use xilem::palette;
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>(samples: Vec<f64>) -> impl WidgetView<State> + use<State> {
+/// let key = samples.clone();
+/// canvas(move |_, scene, size| {
+///     let mut path = BezPath::new();
+///     for (i, sample) in samples.iter().enumerate() {
+///         let x = i as f64 / samples.len() as f64 * size.width;
+///         let y = (1.0 - sample) * size.height;
+///         if i == 0 {
+///             path.move_to((x, y));
+///         } else {
+///             path.line_to((x, y));
+///         }
+///     }
+///     scene.stroke(&Stroke::new(1.0), Affine::IDENTITY, palette::css::WHITE, None, &path);
+/// })
+/// .key(key)
+/// # }
+/// ```
+pub fn canvas<State, Action, F>(painter: F) -> Canvas<F, (), State, Action>
+where
+    F: Fn(&mut PaintCtx, &mut Scene, Size) + Send + Sync + 'static,
+{
+    Canvas {
+        painter: Arc::new(painter),
+        key: None,
+        preferred_size: None,
+        on_pointer: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`canvas`].
+///
+/// See `canvas`'s docs for more details.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Canvas<F, K, State, Action> {
+    painter: Arc<F>,
+    key: Option<K>,
+    preferred_size: Option<Size>,
+    on_pointer: Option<PointerCallback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<F, K, State, Action> Canvas<F, K, State, Action> {
+    /// Only repaint the canvas on rebuild if `key` differs from the previous key.
+    ///
+    /// The key should capture everything the painter draws with.
+    pub fn key<NewKey>(self, key: NewKey) -> Canvas<F, NewKey, State, Action>
+    where
+        NewKey: PartialEq + Send + Sync + 'static,
+    {
+        Canvas {
+            painter: self.painter,
+            key: Some(key),
+            preferred_size: self.preferred_size,
+            on_pointer: self.on_pointer,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Set the size the canvas would like to be, which is still constrained by its parent.
+    pub fn preferred_size(mut self, size: impl Into<Size>) -> Self {
+        self.preferred_size = Some(size.into());
+        self
+    }
+
+    /// Call `on_pointer` with each pointer event the canvas receives.
+    ///
+    /// When the pointer is pressed on the canvas, it captures the pointer, so that
+    /// drags which leave the canvas are still reported.
+    pub fn on_pointer(
+        mut self,
+        on_pointer: impl Fn(&mut State, &CanvasPointerEvent) -> Action + Send + Sync + 'static,
+    ) -> Self {
+        self.on_pointer = Some(Box::new(on_pointer));
+        self
+    }
+}
+
+impl<F, K, State, Action> Canvas<F, K, State, Action>
+where
+    F: Fn(&mut PaintCtx, &mut Scene, Size) + Send + Sync + 'static,
+{
+    fn widget_painter(&self) -> impl FnMut(&mut PaintCtx, &mut Scene, Size) + 'static {
+        let painter = self.painter.clone();
+        move |ctx, scene, size| painter(ctx, scene, size)
+    }
+}
+
+impl<F, K, State, Action> ViewMarker for Canvas<F, K, State, Action> {}
+impl<F, K, State, Action> View<State, Action, ViewCtx> for Canvas<F, K, State, Action>
+where
+    F: Fn(&mut PaintCtx, &mut Scene, Size) + Send + Sync + 'static,
+    K: PartialEq + Send + Sync + 'static,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Canvas>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.new_pod(
+                widgets::Canvas::new(self.widget_painter())
+                    .with_preferred_size(self.preferred_size)
+                    .with_pointer_events(self.on_pointer.is_some()),
+            )
+        });
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.key.is_none() || self.key != prev.key {
+            widgets::Canvas::set_painter(&mut element, self.widget_painter());
+        }
+        if self.preferred_size != prev.preferred_size {
+            widgets::Canvas::set_preferred_size(&mut element, self.preferred_size);
+        }
+        if self.on_pointer.is_some() != prev.on_pointer.is_some() {
+            widgets::Canvas::set_pointer_events(&mut element, self.on_pointer.is_some());
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, ctx: &mut ViewCtx, element: Mut<Self::Element>) {
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            id_path.is_empty(),
+            "id path should be empty in Canvas::message"
+        );
+        match message.downcast::<masonry::core::Action>() {
+            Ok(action) => match (&*action, &self.on_pointer) {
+                (masonry::core::Action::CanvasPointer(event), Some(on_pointer)) => {
+                    MessageResult::Action(on_pointer(app_state, event))
+                }
+                _ => {
+                    tracing::error!("Wrong action type in Canvas::message: {action:?}");
+                    MessageResult::Stale(action)
+                }
+            },
+            Err(message) => {
+                tracing::error!("Wrong message type in Canvas::message: {message:?}");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@
 
 use masonry::core::ArcStr;
 use masonry::widgets;
+pub use masonry::widgets::CheckboxState;
 
 use crate::core::{DynMessage, Mut, ViewMarker};
 use crate::{MessageResult, Pod, View, ViewCtx, ViewId};
@@ -96,8 +97,8 @@ where
         );
         match message.downcast::<masonry::core::Action>() {
             Ok(action) => {
-                if let masonry::core::Action::CheckboxToggled(checked) = *action {
-                    MessageResult::Action((self.callback)(app_state, checked))
+                if let masonry::core::Action::CheckboxToggled(state) = *action {
+                    MessageResult::Action((self.callback)(app_state, state.is_checked()))
                 } else {
                     tracing::error!("Wrong action type in Checkbox::message: {action:?}");
                     MessageResult::Stale(action)
@@ -110,3 +111,103 @@ where
         }
     }
 }
+
+/// A checkbox which can also be shown as [indeterminate](CheckboxState::Indeterminate).
+///
+/// This is useful for a checkbox which controls a group of other checkboxes, such as
+/// a "select all" checkbox, which is indeterminate whilst only some of the group are selected.
+/// Clicking an indeterminate checkbox makes it checked.
+///
+/// # Example
+/// ```ignore
+/// use xilem::view::{tristate_checkbox, CheckboxState};
+///
+/// tristate_checkbox("Select all", app_state.select_all_state(), |app_state: &mut State, new_state| {
+///     app_state.select_all(new_state == CheckboxState::Checked);
+/// })
+/// ```
+pub fn tristate_checkbox<F, State, Action>(
+    label: impl Into<ArcStr>,
+    state: CheckboxState,
+    callback: F,
+) -> TristateCheckbox<F>
+where
+    F: Fn(&mut State, CheckboxState) -> Action + Send + Sync + 'static,
+{
+    TristateCheckbox {
+        label: label.into(),
+        callback,
+        state,
+    }
+}
+
+/// The [`View`] created by [`tristate_checkbox`] from a `label`, a [`CheckboxState`] and a callback.
+///
+/// See `tristate_checkbox` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct TristateCheckbox<F> {
+    label: ArcStr,
+    state: CheckboxState,
+    callback: F,
+}
+
+impl<F> ViewMarker for TristateCheckbox<F> {}
+impl<F, State, Action> View<State, Action, ViewCtx> for TristateCheckbox<F>
+where
+    F: Fn(&mut State, CheckboxState) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Checkbox>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        ctx.with_leaf_action_widget(|ctx| {
+            ctx.new_pod(widgets::Checkbox::new(self.state, self.label.clone()))
+        })
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if prev.label != self.label {
+            widgets::Checkbox::set_text(&mut element, self.label.clone());
+        }
+        if prev.state != self.state {
+            widgets::Checkbox::set_checked(&mut element, self.state);
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, ctx: &mut ViewCtx, element: Mut<Self::Element>) {
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            id_path.is_empty(),
+            "id path should be empty in TristateCheckbox::message"
+        );
+        match message.downcast::<masonry::core::Action>() {
+            Ok(action) => {
+                if let masonry::core::Action::CheckboxToggled(state) = *action {
+                    MessageResult::Action((self.callback)(app_state, state))
+                } else {
+                    tracing::error!("Wrong action type in TristateCheckbox::message: {action:?}");
+                    MessageResult::Stale(action)
+                }
+            }
+            Err(message) => {
+                tracing::error!("Wrong message type in TristateCheckbox::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+pub use masonry::widgets::ClipShape;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which clips the widget created by `child` to `shape`, such as a circle for
+/// avatars or a rounded rectangle for cards.
+///
+/// Pointer events outside of the shape don't reach the child.
+///
+/// This can also be created using [`WidgetView::clip`] and [`WidgetView::clip_rounded`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{ClipShape, clip, label, sized_box};
+/// use xilem::palette;
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+/// clip(
+///     sized_box(label("AB")).width(32.).height(32.).background(palette::css::TEAL),
+///     ClipShape::Ellipse,
+/// )
+/// # }
+/// ```
+pub fn clip<Child, State, Action>(child: Child, shape: ClipShape) -> Clip<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Clip {
+        child,
+        shape,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`clip`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Clip<V, State, Action> {
+    child: V,
+    shape: ClipShape,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for Clip<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Clip<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Clip<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let pod = ctx.new_pod(
+            widgets::Clip::from_pod(child.into_widget_pod()).with_shape(self.shape.clone()),
+        );
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.shape != prev.shape {
+            widgets::Clip::set_shape(&mut element, self.shape.clone());
+        }
+        let child = widgets::Clip::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::Clip::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which shows the widget created by `child` only whilst `show` is true.
+///
+/// Whilst hidden, the widget is kept but stashed, so it takes up no space and isn't
+/// painted or interactive. When it is shown again, it has kept its state, such as the
+/// scroll position of a portal or the selection in a textbox.
+/// The child view is still rebuilt whilst hidden.
+///
+/// Compared to using an `Option` of the child view, this keeps the widget (and the
+/// resources it holds) alive whilst hidden, and its parent always has the same
+/// number of children. Prefer an `Option` if the child is rarely shown or is expensive
+/// to keep up to date, or if spacing between the children of a [`flex`](crate::view::flex)
+/// should collapse whilst hidden.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{conditional, flex, label};
+/// # use xilem::WidgetView;
+///
+/// struct State {
+///     show_details: bool,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<State> + use<> {
+/// flex((
+///     label("Summary"),
+///     conditional(state.show_details, label("Details")),
+/// ))
+/// # }
+/// ```
+pub fn conditional<Child, State, Action>(
+    show: bool,
+    child: Child,
+) -> Conditional<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Conditional {
+        child,
+        show,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`conditional`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Conditional<V, State, Action> {
+    child: V,
+    show: bool,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for Conditional<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Conditional<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Conditional<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let pod = ctx
+            .new_pod(widgets::Conditional::from_pod(child.into_widget_pod()).with_shown(self.show));
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.show != prev.show {
+            widgets::Conditional::set_shown(&mut element, self.show);
+        }
+        let child = widgets::Conditional::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::Conditional::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+pub use masonry::widgets::MenuItem;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+type Callback<State, Action> = Box<dyn Fn(&mut State, &[usize]) -> Action + Send + Sync + 'static>;
+
+/// A view which shows a menu of `items` when the widget created by `child` is right-clicked,
+/// or when Shift+F10 or the Menu key is pressed whilst it is focused.
+///
+/// `on_select` is called with the path of indices leading to the item the user chose:
+/// the index of an item in `items`, followed by its index in each submenu.
+///
+/// This can also be created using [`WidgetView::context_menu`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{MenuItem, context_menu, label};
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+/// context_menu(
+///     label("Right-click me"),
+///     vec![
+///         MenuItem::new("Copy"),
+///         MenuItem::new("Paste").with_enabled(false),
+///     ],
+///     |_, path| println!("Chose item {path:?}"),
+/// )
+/// # }
+/// ```
+pub fn context_menu<Child, State, Action, F>(
+    child: Child,
+    items: Vec<MenuItem>,
+    on_select: F,
+) -> ContextMenu<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State, &[usize]) -> Action + Send + Sync + 'static,
+{
+    ContextMenu {
+        child,
+        items,
+        on_select: Box::new(on_select),
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`context_menu`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct ContextMenu<V, State, Action> {
+    child: V,
+    items: Vec<MenuItem>,
+    on_select: Callback<State, Action>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, State, Action> ViewMarker for ContextMenu<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for ContextMenu<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::ContextMenu<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx));
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.new_pod(widgets::ContextMenu::from_pod(
+                child.into_widget_pod(),
+                self.items.iter().cloned(),
+            ))
+        });
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.items != prev.items {
+            widgets::ContextMenu::set_items(&mut element, self.items.iter().cloned());
+        }
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::ContextMenu::child_mut(&mut element);
+            self.child.rebuild(&prev.child, view_state, ctx, child);
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::ContextMenu::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child);
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                self.child.message(view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    if let masonry::core::Action::ContextMenuItemSelected(path) = &*action {
+                        MessageResult::Action((self.on_select)(app_state, path))
+                    } else {
+                        tracing::error!("Wrong action type in ContextMenu::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in ContextMenu::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in ContextMenu::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
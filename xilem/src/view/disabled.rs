@@ -0,0 +1,110 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which disables the widget created by `child`, and all of its descendants,
+/// whilst `disabled` is true.
+///
+/// Disabled widgets are dimmed and ignore clicks and key presses, but are still hovered,
+/// so a [`tooltip`](crate::view::tooltip) can explain why they can't be used.
+///
+/// This can also be created using [`WidgetView::disabled`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{button, disabled};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     unsaved_changes: bool,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// disabled(
+///     button("Save", |state: &mut AppState| state.unsaved_changes = false),
+///     !state.unsaved_changes,
+/// )
+/// # }
+/// ```
+pub fn disabled<Child, State, Action>(
+    child: Child,
+    disabled: bool,
+) -> Disabled<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Disabled {
+        child,
+        disabled,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`disabled`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Disabled<V, State, Action> {
+    child: V,
+    disabled: bool,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for Disabled<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Disabled<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::DisabledScope<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let pod = ctx.new_pod(widgets::DisabledScope::from_pod(
+            child.into_widget_pod(),
+            self.disabled,
+        ));
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.disabled != prev.disabled {
+            widgets::DisabledScope::set_disabled(&mut element, self.disabled);
+        }
+        let child = widgets::DisabledScope::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::DisabledScope::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
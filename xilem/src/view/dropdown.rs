@@ -0,0 +1,127 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use masonry::core::{ArcStr, DropDownAction};
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId};
+
+type Callback<State, Action> = Box<dyn Fn(&mut State, usize) -> Action + Send + Sync + 'static>;
+
+/// A drop down for choosing one of `options`, of which the one at `selected_index` is selected.
+///
+/// Each option is shown using its [`Display`] implementation.
+/// `on_select` is called with the index of the option the user selects.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::dropdown;
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     size: usize,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// dropdown(["Small", "Medium", "Large"], state.size, |state: &mut AppState, size| {
+///     state.size = size;
+/// })
+/// # }
+/// ```
+pub fn dropdown<T, F, State, Action>(
+    options: impl IntoIterator<Item = T>,
+    selected_index: usize,
+    on_select: F,
+) -> DropDown<State, Action>
+where
+    T: Display,
+    F: Fn(&mut State, usize) -> Action + Send + Sync + 'static,
+{
+    DropDown {
+        options: options
+            .into_iter()
+            .map(|option| option.to_string().into())
+            .collect(),
+        selected_index,
+        on_select: Box::new(on_select),
+    }
+}
+
+/// The [`View`] created by [`dropdown`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct DropDown<State, Action> {
+    options: Vec<ArcStr>,
+    selected_index: usize,
+    on_select: Callback<State, Action>,
+}
+
+impl<State, Action> ViewMarker for DropDown<State, Action> {}
+impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for DropDown<State, Action> {
+    type Element = Pod<widgets::DropDown>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        ctx.with_leaf_action_widget(|ctx| {
+            ctx.new_pod(widgets::DropDown::new(
+                self.options.iter().cloned(),
+                self.selected_index,
+            ))
+        })
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if prev.options != self.options {
+            widgets::DropDown::set_options(
+                &mut element,
+                self.options.iter().cloned(),
+                self.selected_index,
+            );
+        } else if element.widget.selected() != self.selected_index {
+            // As in `slider`, we compare to the widget's selection rather than the previous
+            // view's, so that the selection is reset if the app doesn't store it.
+            widgets::DropDown::set_selected(&mut element, self.selected_index);
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, ctx: &mut ViewCtx, element: Mut<Self::Element>) {
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            id_path.is_empty(),
+            "id path should be empty in DropDown::message"
+        );
+        match message.downcast::<masonry::core::Action>() {
+            Ok(action) => match *action {
+                masonry::core::Action::DropDown(DropDownAction::Selected(index)) => {
+                    MessageResult::Action((self.on_select)(app_state, index))
+                }
+                _ => {
+                    tracing::error!("Wrong action type in DropDown::message: {action:?}");
+                    MessageResult::Stale(action)
+                }
+            },
+            Err(message) => {
+                tracing::error!("Wrong message type in DropDown::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewId, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, WidgetView};
+
+/// A section with a `header`, which the user can click to show or hide the `content`
+/// below it.
+///
+/// The section is expanded whilst `is_open` is `true`. When the user expands or collapses
+/// it, `on_toggle` is called with whether it is now open, and should update the app state
+/// which `is_open` comes from.
+///
+/// The content of a collapsed section is hidden, but keeps its state.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{expander, label};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     advanced_open: bool,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// expander(
+///     state.advanced_open,
+///     |state: &mut AppState, open| state.advanced_open = open,
+///     label("Advanced"),
+///     label("Advanced settings"),
+/// )
+/// .animated(true)
+/// # }
+/// ```
+pub fn expander<State, Action, F, Header, Content>(
+    is_open: bool,
+    on_toggle: F,
+    header: Header,
+    content: Content,
+) -> Expander<Header, Content, F, State, Action>
+where
+    F: Fn(&mut State, bool) -> Action + Send + Sync + 'static,
+    Header: WidgetView<State, Action>,
+    Content: WidgetView<State, Action>,
+{
+    Expander {
+        header,
+        content,
+        is_open,
+        animated: false,
+        on_toggle,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`expander`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Expander<Header, Content, F, State, Action> {
+    header: Header,
+    content: Content,
+    is_open: bool,
+    animated: bool,
+    on_toggle: F,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<Header, Content, F, State, Action> Expander<Header, Content, F, State, Action> {
+    /// Set whether expanding and collapsing is animated, which defaults to `false`.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+const HEADER_VIEW_ID: ViewId = ViewId::new(0);
+const CONTENT_VIEW_ID: ViewId = ViewId::new(1);
+
+impl<Header, Content, F, State, Action> ViewMarker for Expander<Header, Content, F, State, Action> {}
+impl<Header, Content, F, State, Action> View<State, Action, ViewCtx>
+    for Expander<Header, Content, F, State, Action>
+where
+    Header: WidgetView<State, Action>,
+    Content: WidgetView<State, Action>,
+    F: Fn(&mut State, bool) -> Action + Send + Sync + 'static,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Expander>;
+    type ViewState = (Header::ViewState, Content::ViewState);
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (header, header_state) = ctx.with_id(HEADER_VIEW_ID, |ctx| self.header.build(ctx));
+        let (content, content_state) = ctx.with_id(CONTENT_VIEW_ID, |ctx| self.content.build(ctx));
+        let widget = widgets::Expander::from_pods(
+            header.erased_widget_pod(),
+            content.erased_widget_pod(),
+            self.is_open,
+        )
+        .with_animation(self.animated);
+        let pod = ctx.with_action_widget(|ctx| ctx.new_pod(widget));
+        (pod, (header_state, content_state))
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (header_state, content_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.animated != prev.animated {
+            widgets::Expander::set_animation(&mut element, self.animated);
+        }
+        // The user may have toggled the section without the app state following, so this
+        // is set even if `is_open` didn't change.
+        widgets::Expander::set_expanded(&mut element, self.is_open);
+        ctx.with_id(HEADER_VIEW_ID, |ctx| {
+            let mut header = widgets::Expander::header_mut(&mut element);
+            self.header
+                .rebuild(&prev.header, header_state, ctx, header.downcast());
+        });
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            let mut content = widgets::Expander::content_mut(&mut element);
+            self.content
+                .rebuild(&prev.content, content_state, ctx, content.downcast());
+        });
+    }
+
+    fn teardown(
+        &self,
+        (header_state, content_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(HEADER_VIEW_ID, |ctx| {
+            let mut header = widgets::Expander::header_mut(&mut element);
+            self.header.teardown(header_state, ctx, header.downcast());
+        });
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            let mut content = widgets::Expander::content_mut(&mut element);
+            self.content
+                .teardown(content_state, ctx, content.downcast());
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        (header_state, content_state): &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&HEADER_VIEW_ID, rest)) => {
+                self.header.message(header_state, rest, message, app_state)
+            }
+            Some((&CONTENT_VIEW_ID, rest)) => {
+                self.content
+                    .message(content_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    if let masonry::core::Action::ExpanderToggled(open) = *action {
+                        MessageResult::Action((self.on_toggle)(app_state, open))
+                    } else {
+                        tracing::error!("Wrong action type in Expander::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in Expander::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Expander::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
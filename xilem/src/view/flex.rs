@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 
 use masonry::core::{FromDynWidget, Widget, WidgetMut};
 use masonry::widgets::{self};
-pub use masonry::widgets::{Axis, CrossAxisAlignment, FlexParams, MainAxisAlignment};
+pub use masonry::widgets::{Axis, CrossAxisAlignment, FlexParams, FlexWrap, MainAxisAlignment};
 
 use crate::core::{
     AppendVec, DynMessage, ElementSplice, MessageResult, Mut, SuperElement, View, ViewElement,
@@ -27,7 +27,7 @@ use crate::{AnyWidgetView, Pod, ViewCtx, WidgetView};
 ///     label: impl Into<Label>,
 ///     callback: impl Fn(&mut i32) + Send + Sync + 'static,
 /// ) -> impl WidgetView<i32> {
-///     sized_box(button(label, callback)).width(40.).height(40.)
+///     sized_box(button(label.into(), callback)).width(40.).height(40.)
 /// }
 ///
 /// fn app_logic(data: &mut i32) -> impl WidgetView<i32> + use<> {
@@ -65,6 +65,8 @@ pub fn flex<State, Action, Seq: FlexSequence<State, Action>>(
         main_axis_alignment: MainAxisAlignment::Start,
         fill_major_axis: false,
         gap: None,
+        wrap: FlexWrap::NoWrap,
+        run_spacing: 0.0,
         phantom: PhantomData,
     }
 }
@@ -80,6 +82,8 @@ pub struct Flex<Seq, State, Action = ()> {
     main_axis_alignment: MainAxisAlignment,
     fill_major_axis: bool,
     gap: Option<f64>,
+    wrap: FlexWrap,
+    run_spacing: f64,
     phantom: PhantomData<fn() -> (State, Action)>,
 }
 
@@ -128,6 +132,31 @@ impl<Seq, State, Action> Flex<Seq, State, Action> {
         }
         self
     }
+
+    /// Wrap children onto a new line (or "run") when they don't fit in the main axis.
+    ///
+    /// See [`FlexWrap::Wrap`] for details.
+    pub fn wrap(mut self) -> Self {
+        self.wrap = FlexWrap::Wrap;
+        self
+    }
+
+    /// Set the spacing between lines in logical pixels, when children [wrap](Self::wrap).
+    ///
+    /// This defaults to zero.
+    ///
+    /// ## Panics
+    ///
+    /// If `run_spacing` is not a non-negative finite value.
+    #[track_caller]
+    pub fn run_spacing(mut self, run_spacing: f64) -> Self {
+        if run_spacing.is_finite() && run_spacing >= 0.0 {
+            self.run_spacing = run_spacing;
+        } else {
+            panic!("Invalid `run_spacing` {run_spacing}, expected a non-negative finite value.")
+        }
+        self
+    }
 }
 
 impl<Seq, State, Action> ViewMarker for Flex<Seq, State, Action> {}
@@ -145,6 +174,8 @@ where
         let mut elements = AppendVec::default();
         let mut widget = widgets::Flex::for_axis(self.axis)
             .raw_gap(self.gap)
+            .wrap(self.wrap)
+            .run_spacing(self.run_spacing)
             .cross_axis_alignment(self.cross_axis_alignment)
             .must_fill_main_axis(self.fill_major_axis)
             .main_axis_alignment(self.main_axis_alignment);
@@ -184,6 +215,12 @@ where
         if prev.gap != self.gap {
             widgets::Flex::set_raw_gap(&mut element, self.gap);
         }
+        if prev.wrap != self.wrap {
+            widgets::Flex::set_wrap(&mut element, self.wrap);
+        }
+        if prev.run_spacing != self.run_spacing {
+            widgets::Flex::set_run_spacing(&mut element, self.run_spacing);
+        }
         // TODO: Re-use scratch space?
         let mut splice = FlexSplice::new(element);
         self.sequence
@@ -356,6 +393,11 @@ impl ElementSplice<FlexElement> for FlexSplice<'_> {
     fn skip(&mut self, n: usize) {
         self.idx += n;
     }
+
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        widgets::Flex::move_child(&mut self.element, self.idx + offset, self.idx);
+        true
+    }
 }
 
 /// An ordered sequence of views for a [`Flex`] view.
@@ -654,6 +696,33 @@ where
     pub fn into_any_flex(self) -> AnyFlexChild<State, Action> {
         AnyFlexChild::Item(flex_item(Box::new(self.view), self.params))
     }
+
+    /// Set the smallest size this item is given on the main axis of the [`Flex`].
+    ///
+    /// See [`FlexParams::with_min_main`] for how the bounds are applied.
+    ///
+    /// # Examples
+    /// ```
+    /// use xilem::view::{flex, label, FlexExt};
+    /// # use xilem::{WidgetView};
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> {
+    /// flex(label("Can shrink, but not too much").flex(1.0).min_main(120.0))
+    /// # }
+    ///
+    /// ```
+    pub fn min_main(mut self, min_main: f64) -> Self {
+        self.params = self.params.with_min_main(min_main);
+        self
+    }
+
+    /// Set the largest size this item is given on the main axis of the [`Flex`].
+    ///
+    /// See [`FlexParams::with_max_main`] for how the bounds are applied.
+    pub fn max_main(mut self, max_main: f64) -> Self {
+        self.params = self.params.with_max_main(max_main);
+        self
+    }
 }
 
 mod hidden {
@@ -0,0 +1,369 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewId, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, WidgetView};
+
+/// A view which focuses the widget created by `child` when it is first built.
+///
+/// If that widget doesn't accept focus itself (such as a [`textbox`](crate::view::textbox)),
+/// its first descendant which does is focused instead.
+///
+/// To control when focus is requested based on your app's state, use [`when`](Autofocus::when).
+/// To move focus from an event handler, use [`focus_with`] instead.
+///
+/// This can also be created using [`WidgetView::focus_on_build`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{autofocus, textbox};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     search: String,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// autofocus(textbox(state.search.clone(), |state: &mut AppState, text| {
+///     state.search = text;
+/// }))
+/// # }
+/// ```
+pub fn autofocus<Child, State, Action>(child: Child) -> Autofocus<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Autofocus {
+        child,
+        should_focus: true,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`autofocus`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Autofocus<V, State, Action> {
+    child: V,
+    should_focus: bool,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> Autofocus<V, State, Action> {
+    /// Only focus the child whilst `should_focus` is true.
+    ///
+    /// Focus is requested when the child is first built with `should_focus` set,
+    /// and whenever `should_focus` changes from false to true.
+    /// It is not requested again on other rebuilds, so that the user can move focus elsewhere
+    /// without it being taken back.
+    pub fn when(mut self, should_focus: bool) -> Self {
+        self.should_focus = should_focus;
+        self
+    }
+}
+
+impl<V, State, Action> ViewMarker for Autofocus<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Autofocus<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<Child::Widget>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (pod, state) = self.child.build(ctx);
+        if self.should_focus {
+            ctx.request_focus(pod.id);
+        }
+        (pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        self.child
+            .rebuild(&prev.child, view_state, ctx, element.reborrow_mut());
+        // Only act on the rising edge, to avoid stealing focus on every rebuild.
+        if self.should_focus && !prev.should_focus {
+            ctx.request_focus(element.ctx.widget_id());
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<Self::Element>,
+    ) {
+        self.child.teardown(view_state, ctx, element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
+
+/// A handle which event handlers can use to focus the widget of a [`focus_with`] view.
+///
+/// Handles are cheap to clone, and all clones refer to the same request,
+/// so one can be kept in your app's state and another given to the view.
+#[derive(Clone, Debug, Default)]
+pub struct FocusHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl FocusHandle {
+    /// Create a new handle, which has no focus request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request that the widget of the `focus_with` view given this handle be focused.
+    ///
+    /// The request is applied when that view is next rebuilt, which happens after the
+    /// event handler which made the request returns.
+    pub fn request_focus(&self) {
+        self.requested.store(true, Ordering::Relaxed);
+    }
+
+    fn take_request(&self) -> bool {
+        self.requested.swap(false, Ordering::Relaxed)
+    }
+}
+
+/// A view which focuses the widget created by `child` whenever focus is requested
+/// through `handle`.
+///
+/// As with [`autofocus`], if that widget doesn't accept focus itself, its first
+/// descendant which does is focused instead.
+///
+/// This can also be created using [`WidgetView::focus_with`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{FocusHandle, button, flex, focus_with, textbox};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     search: String,
+///     search_focus: FocusHandle,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// flex((
+///     focus_with(
+///         textbox(state.search.clone(), |state: &mut AppState, text| {
+///             state.search = text;
+///         }),
+///         state.search_focus.clone(),
+///     ),
+///     button("Clear", |state: &mut AppState| {
+///         state.search.clear();
+///         state.search_focus.request_focus();
+///     }),
+/// ))
+/// # }
+/// ```
+pub fn focus_with<Child, State, Action>(
+    child: Child,
+    handle: FocusHandle,
+) -> FocusWith<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    FocusWith {
+        child,
+        handle,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`focus_with`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct FocusWith<V, State, Action> {
+    child: V,
+    handle: FocusHandle,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for FocusWith<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for FocusWith<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<Child::Widget>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (pod, state) = self.child.build(ctx);
+        if self.handle.take_request() {
+            ctx.request_focus(pod.id);
+        }
+        (pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        self.child
+            .rebuild(&prev.child, view_state, ctx, element.reborrow_mut());
+        if self.handle.take_request() {
+            ctx.request_focus(element.ctx.widget_id());
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<Self::Element>,
+    ) {
+        self.child.teardown(view_state, ctx, element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
+
+/// A view which sets the position of the widget created by `child` in the order
+/// Tab moves focus in.
+///
+/// Widgets with a positive index are focused first, in ascending order of index,
+/// followed by those with no index or an index of 0, in the order they appear.
+/// Widgets with a negative index are skipped by Tab, but can still be clicked,
+/// or focused with [`autofocus`] or [`focus_with`].
+/// The index applies to all of the focusable widgets inside `child`, unless they are
+/// inside another `tab_index`.
+///
+/// This can also be created using [`WidgetView::tab_index`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{flex, tab_index, textbox};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     name: String,
+///     search: String,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// flex((
+///     textbox(state.name.clone(), |state: &mut AppState, text| {
+///         state.name = text;
+///     }),
+///     // Tab reaches the search box before the name box.
+///     tab_index(
+///         textbox(state.search.clone(), |state: &mut AppState, text| {
+///             state.search = text;
+///         }),
+///         1,
+///     ),
+/// ))
+/// # }
+/// ```
+pub fn tab_index<Child, State, Action>(child: Child, index: i32) -> TabIndex<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    TabIndex {
+        child,
+        index,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`tab_index`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct TabIndex<V, State, Action> {
+    child: V,
+    index: i32,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for TabIndex<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for TabIndex<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::TabIndex<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let pod = ctx.new_pod(widgets::TabIndex::from_pod(
+            child.into_widget_pod(),
+            self.index,
+        ));
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.index != prev.index {
+            widgets::TabIndex::set_index(&mut element, self.index);
+        }
+        let child = widgets::TabIndex::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::TabIndex::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
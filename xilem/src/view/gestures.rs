@@ -0,0 +1,213 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+pub use masonry::core::DragPhase;
+use masonry::core::GestureAction;
+use masonry::kurbo::{Point, Vec2};
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+type GestureCallback<State, Action> = Box<dyn Fn(&mut State) -> Action + Send + Sync + 'static>;
+type DragCallback<State, Action> =
+    Box<dyn Fn(&mut State, Point, Vec2, DragPhase) -> Action + Send + Sync + 'static>;
+
+/// A view which recognises double clicks, long presses and drags on the widget
+/// created by `child`.
+///
+/// Callbacks are added with [`on_double_click`](Gestures::on_double_click),
+/// [`on_long_press`](Gestures::on_long_press) and [`on_drag`](Gestures::on_drag),
+/// which can also be called on any view through [`WidgetView`].
+/// The pointer is only captured during drags if there is an `on_drag` callback.
+/// See [`GestureDetector`](masonry::widgets::GestureDetector) for how gestures are recognised.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{DragPhase, label};
+/// use xilem::WidgetView;
+///
+/// struct AppState {
+///     zoomed: bool,
+///     offset: f64,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// label("Map")
+///     .on_double_click(|state: &mut AppState| state.zoomed = !state.zoomed)
+///     .on_drag(|state: &mut AppState, _start, delta, phase| {
+///         if phase == DragPhase::Ended {
+///             state.offset += delta.x;
+///         }
+///     })
+/// # }
+/// ```
+pub fn gestures<State, Action, Child>(child: Child) -> Gestures<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Gestures {
+        child,
+        on_double_click: None,
+        on_long_press: None,
+        on_drag: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`gestures`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Gestures<V, State, Action> {
+    child: V,
+    on_double_click: Option<GestureCallback<State, Action>>,
+    on_long_press: Option<GestureCallback<State, Action>>,
+    on_drag: Option<DragCallback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> Gestures<V, State, Action> {
+    /// Call `callback` when the child is double clicked or double tapped.
+    pub fn on_double_click<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        self.on_double_click = Some(Box::new(callback));
+        self
+    }
+
+    /// Call `callback` when the child is pressed and held without moving.
+    ///
+    /// This is called whilst the pointer is still down.
+    pub fn on_long_press<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        self.on_long_press = Some(Box::new(callback));
+        self
+    }
+
+    /// Call `callback` as the child is dragged.
+    ///
+    /// `callback` is given where the drag started relative to the child, how far the pointer
+    /// has moved since then, and which part of the drag this is.
+    /// If the drag is [cancelled](DragPhase::Cancelled), any changes made whilst dragging
+    /// should usually be undone.
+    pub fn on_drag<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut State, Point, Vec2, DragPhase) -> Action + Send + Sync + 'static,
+    {
+        self.on_drag = Some(Box::new(callback));
+        self
+    }
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, State, Action> ViewMarker for Gestures<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Gestures<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::GestureDetector<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx));
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.new_pod(
+                widgets::GestureDetector::from_pod(child.into_widget_pod())
+                    .with_capture_drags(self.on_drag.is_some()),
+            )
+        });
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.on_drag.is_some() != prev.on_drag.is_some() {
+            widgets::GestureDetector::set_capture_drags(&mut element, self.on_drag.is_some());
+        }
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::GestureDetector::child_mut(&mut element);
+            self.child.rebuild(&prev.child, view_state, ctx, child);
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::GestureDetector::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child);
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                self.child.message(view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => match &*action {
+                    masonry::core::Action::Gesture(gesture) => {
+                        let result = match (*gesture, &self.on_double_click, &self.on_long_press) {
+                            (GestureAction::DoubleTap, Some(on_double_click), _) => {
+                                Some(on_double_click(app_state))
+                            }
+                            (GestureAction::LongPress, _, Some(on_long_press)) => {
+                                Some(on_long_press(app_state))
+                            }
+                            (
+                                GestureAction::Drag {
+                                    start,
+                                    delta,
+                                    phase,
+                                },
+                                _,
+                                _,
+                            ) => self
+                                .on_drag
+                                .as_ref()
+                                .map(|on_drag| on_drag(app_state, start, delta, phase)),
+                            // Gestures without a callback, such as taps, are ignored.
+                            _ => None,
+                        };
+                        result.map_or(MessageResult::Nop, MessageResult::Action)
+                    }
+                    _ => {
+                        tracing::error!("Wrong action type in Gestures::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                },
+                Err(message) => {
+                    tracing::error!("Wrong message type in Gestures::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Gestures::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -261,6 +261,11 @@ impl ElementSplice<GridElement> for GridSplice<'_> {
         self.idx += n;
     }
 
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        widgets::Grid::move_child(&mut self.element, self.idx + offset, self.idx);
+        true
+    }
+
     fn delete<R>(&mut self, f: impl FnOnce(Mut<GridElement>) -> R) -> R {
         let ret = {
             let child = GridElementMut {
@@ -391,6 +396,28 @@ where
     }
 }
 
+impl<V, State, Action> GridItem<V, State, Action> {
+    /// Set the number of columns and rows this item spans.
+    ///
+    /// # Examples
+    /// ```
+    /// use xilem::view::{GridExt, grid, label};
+    /// # use xilem::WidgetView;
+    ///
+    /// # fn view<State: 'static>() -> impl WidgetView<State> {
+    /// grid((
+    ///     label("Settings").grid_pos(0, 0).span(2, 1),
+    ///     label("Name").grid_pos(0, 1),
+    ///     label("Value").grid_pos(1, 1),
+    /// ), 2, 2)
+    /// # }
+    /// ```
+    pub fn span(mut self, cols: i32, rows: i32) -> Self {
+        self.params = GridParams::new(self.params.x, self.params.y, cols, rows);
+        self
+    }
+}
+
 impl<V, State, Action> ViewMarker for GridItem<V, State, Action> {}
 
 impl<State, Action, V> View<State, Action, ViewCtx> for GridItem<V, State, Action>
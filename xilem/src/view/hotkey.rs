@@ -0,0 +1,189 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+pub use masonry::core::Hotkey;
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+type HotkeyCallback<State, Action> = Box<dyn Fn(&mut State) -> Action + Send + Sync + 'static>;
+
+/// A view which calls `callback` whenever `hotkey` is pressed, wherever focus is in the window.
+///
+/// The hotkey is only triggered if the focused widget doesn't handle the key itself,
+/// so e.g. a focused [`textbox`](crate::view::textbox) still receives the arrow keys,
+/// but not Ctrl+S.
+/// More hotkeys can be added to the same view with [`hotkey`](Hotkeys::hotkey).
+/// If several views register the same hotkey, a warning is logged and the one which
+/// registered it first receives it.
+///
+/// `child` is shown unchanged; to have hotkeys which apply to your whole app,
+/// wrap the root view.
+///
+/// This can also be created using [`WidgetView::hotkey`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{Hotkey, hotkey, label};
+/// use xilem::winit::keyboard::{ModifiersState, NamedKey};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     saved: bool,
+///     menu_open: bool,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// hotkey(
+///     label("Editor"),
+///     Hotkey::character(ModifiersState::CONTROL, "s"),
+///     |state: &mut AppState| state.saved = true,
+/// )
+/// .hotkey(
+///     Hotkey::new(ModifiersState::empty(), NamedKey::Escape),
+///     |state: &mut AppState| state.menu_open = false,
+/// )
+/// # }
+/// ```
+pub fn hotkey<State, Action, Child, F>(
+    child: Child,
+    hotkey: Hotkey,
+    callback: F,
+) -> Hotkeys<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State) -> Action + Send + Sync + 'static,
+{
+    Hotkeys {
+        child,
+        bindings: vec![(hotkey, Box::new(callback))],
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`hotkey`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Hotkeys<V, State, Action> {
+    child: V,
+    bindings: Vec<(Hotkey, HotkeyCallback<State, Action>)>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> Hotkeys<V, State, Action> {
+    /// Also call `callback` whenever `hotkey` is pressed.
+    ///
+    /// If this view already has a callback for `hotkey`, the first one added is called.
+    pub fn hotkey<F>(mut self, hotkey: Hotkey, callback: F) -> Self
+    where
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        self.bindings.push((hotkey, Box::new(callback)));
+        self
+    }
+
+    fn hotkeys(&self) -> Vec<Hotkey> {
+        self.bindings
+            .iter()
+            .map(|(hotkey, _)| hotkey.clone())
+            .collect()
+    }
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, State, Action> ViewMarker for Hotkeys<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Hotkeys<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Hotkeys<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx));
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.new_pod(widgets::Hotkeys::from_pod(
+                child.into_widget_pod(),
+                self.hotkeys(),
+            ))
+        });
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let hotkeys = self.hotkeys();
+        if hotkeys != prev.hotkeys() {
+            widgets::Hotkeys::set_hotkeys(&mut element, hotkeys);
+        }
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::Hotkeys::child_mut(&mut element);
+            self.child.rebuild(&prev.child, view_state, ctx, child);
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::Hotkeys::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child);
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                self.child.message(view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    let callback = match &*action {
+                        masonry::core::Action::HotkeyPressed(pressed) => self
+                            .bindings
+                            .iter()
+                            .find(|(hotkey, _)| hotkey == pressed)
+                            .map(|(_, callback)| callback),
+                        _ => None,
+                    };
+                    match callback {
+                        Some(callback) => MessageResult::Action(callback(app_state)),
+                        None => {
+                            tracing::error!("Wrong action type in Hotkeys::message: {action:?}");
+                            MessageResult::Stale(action)
+                        }
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in Hotkeys::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Hotkeys::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
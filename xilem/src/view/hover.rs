@@ -0,0 +1,127 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which calls `callback` with `true` when the pointer starts hovering the widget
+/// created by `child`, and with `false` when it stops.
+///
+/// Hovering any of the child's descendants counts as hovering the child.
+/// This is also called when the child moves under a stationary pointer, e.g. when
+/// a [`portal`](crate::view::portal) containing it is scrolled.
+///
+/// This can also be created using [`WidgetView::on_hover`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{label, on_hover};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     show_preview: bool,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// on_hover(label("report.pdf"), |state: &mut AppState, hovered| {
+///     state.show_preview = hovered;
+/// })
+/// # }
+/// ```
+pub fn on_hover<State, Action, Child, F>(child: Child, callback: F) -> OnHover<Child, F>
+where
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State, bool) -> Action + Send + Sync + 'static,
+{
+    OnHover { child, callback }
+}
+
+/// The view for [`on_hover`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct OnHover<V, F> {
+    child: V,
+    callback: F,
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, F> ViewMarker for OnHover<V, F> {}
+impl<Child, F, State, Action> View<State, Action, ViewCtx> for OnHover<Child, F>
+where
+    Child: WidgetView<State, Action>,
+    F: Fn(&mut State, bool) -> Action + Send + Sync + 'static,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::HoverDetector<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx));
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.new_pod(widgets::HoverDetector::from_pod(child.into_widget_pod()))
+        });
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::HoverDetector::child_mut(&mut element);
+            self.child.rebuild(&prev.child, view_state, ctx, child);
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child = widgets::HoverDetector::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child);
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                self.child.message(view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    if let masonry::core::Action::HoverChanged(hovered) = *action {
+                        MessageResult::Action((self.callback)(app_state, hovered))
+                    } else {
+                        tracing::error!("Wrong action type in OnHover::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in OnHover::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in OnHover::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //! The bitmap image widget.
 
 use masonry::core::ObjectFit;
-use masonry::widgets::{self};
+use masonry::widgets::{self, Alignment};
 
 use crate::core::{DynMessage, Mut, ViewMarker};
 use crate::{MessageResult, Pod, View, ViewCtx, ViewId};
@@ -12,7 +12,8 @@ use crate::{MessageResult, Pod, View, ViewCtx, ViewId};
 /// Displays the bitmap `image`.
 ///
 /// By default, the Image will scale to fit its box constraints ([`ObjectFit::Fill`]).
-/// To configure this, call [`fit`](Image::fit) on the returned value.
+/// To configure this, call [`fit`](Image::fit) on the returned value, and use
+/// [`alignment`](Image::alignment) to choose where the image is placed within the widget.
 ///
 /// Corresponds to the [`Image`](widgets::Image) widget.
 ///
@@ -26,6 +27,7 @@ pub fn image(image: &vello::peniko::Image) -> Image {
         // easier than documenting that cloning is cheap.
         image: image.clone(),
         object_fit: ObjectFit::default(),
+        alignment: Alignment::default(),
     }
 }
 
@@ -36,6 +38,7 @@ pub fn image(image: &vello::peniko::Image) -> Image {
 pub struct Image {
     image: vello::peniko::Image,
     object_fit: ObjectFit,
+    alignment: Alignment,
 }
 
 impl Image {
@@ -44,6 +47,15 @@ impl Image {
         self.object_fit = fill;
         self
     }
+
+    /// Specify where the image is placed when it doesn't fill the widget, or which part
+    /// of it is shown when it is clipped.
+    ///
+    /// The default is [`Alignment::Center`].
+    pub fn alignment(mut self, alignment: impl Into<Alignment>) -> Self {
+        self.alignment = alignment.into();
+        self
+    }
 }
 
 impl ViewMarker for Image {}
@@ -52,7 +64,11 @@ impl<State, Action> View<State, Action, ViewCtx> for Image {
     type ViewState = ();
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
-        let pod = ctx.new_pod(widgets::Image::new(self.image.clone()));
+        let pod = ctx.new_pod(
+            widgets::Image::new(self.image.clone())
+                .fit_mode(self.object_fit)
+                .alignment(self.alignment),
+        );
         (pod, ())
     }
 
@@ -66,6 +82,9 @@ impl<State, Action> View<State, Action, ViewCtx> for Image {
         if prev.object_fit != self.object_fit {
             widgets::Image::set_fit_mode(&mut element, self.object_fit);
         }
+        if prev.alignment != self.alignment {
+            widgets::Image::set_alignment(&mut element, self.alignment);
+        }
         if prev.image != self.image {
             widgets::Image::set_image_data(&mut element, self.image.clone());
         }
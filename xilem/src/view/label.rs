@@ -1,7 +1,9 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::core::{ArcStr, StyleProperty};
+use std::ops::Range;
+
+use masonry::core::{ArcStr, RichText, StyleAttribute, StyleProperty};
 use masonry::parley::style::{FontStack, FontWeight};
 use masonry::widgets::{
     LineBreaking, {self},
@@ -9,7 +11,7 @@ use masonry::widgets::{
 use vello::peniko::Brush;
 
 use crate::core::{DynMessage, Mut, ViewMarker};
-use crate::{Color, MessageResult, Pod, TextAlignment, View, ViewCtx, ViewId};
+use crate::{MessageResult, Pod, TextAlignment, View, ViewCtx, ViewId};
 
 /// A non-interactive text element.
 /// # Example
@@ -30,12 +32,38 @@ use crate::{Color, MessageResult, Pod, TextAlignment, View, ViewCtx, ViewId};
 pub fn label(label: impl Into<ArcStr>) -> Label {
     Label {
         label: label.into(),
-        text_brush: Color::WHITE.into(),
+        text_brush: None,
         alignment: TextAlignment::default(),
         text_size: masonry::theme::TEXT_SIZE_NORMAL,
         weight: FontWeight::NORMAL,
         font: FontStack::List(std::borrow::Cow::Borrowed(&[])),
         line_break_mode: LineBreaking::Overflow,
+        spans: Vec::new(),
+    }
+}
+
+/// A non-interactive text element, with styles applied to ranges of the text.
+///
+/// The spans are applied on top of the label's global styles, such as its [brush](Label::brush).
+/// # Example
+///
+/// ```ignore
+/// use xilem::palette;
+/// use xilem::view::rich_label;
+/// use masonry::core::{RichText, StyleAttribute};
+/// use masonry::parley::FontWeight;
+///
+/// rich_label(
+///     RichText::new("Some bold and red text.")
+///         .with_span(5..9, StyleAttribute::Weight(FontWeight::BOLD))
+///         .with_span(14..17, StyleAttribute::Brush(palette::css::RED.into())),
+/// )
+/// ```
+pub fn rich_label(text: RichText) -> Label {
+    let (text, spans) = text.into_parts();
+    Label {
+        spans,
+        ..label(text)
     }
 }
 
@@ -45,19 +73,22 @@ pub fn label(label: impl Into<ArcStr>) -> Label {
 #[must_use = "View values do nothing unless provided to Xilem."]
 pub struct Label {
     label: ArcStr,
-    text_brush: Brush,
+    text_brush: Option<Brush>,
     alignment: TextAlignment,
     text_size: f32,
     weight: FontWeight,
     font: FontStack<'static>,
-    line_break_mode: LineBreaking, // TODO: add more attributes of `masonry::widgets::Label`
+    line_break_mode: LineBreaking,
+    spans: Vec<(Range<usize>, StyleAttribute)>, // TODO: add more attributes of `masonry::widgets::Label`
 }
 
 impl Label {
     /// In most cases brush sets text color, but gradients and images are also supported.
+    ///
+    /// By default, the text is painted with the [theme's text color](masonry::theme::Theme::text_color).
     #[doc(alias = "color")]
     pub fn brush(mut self, brush: impl Into<Brush>) -> Self {
-        self.text_brush = brush.into();
+        self.text_brush = Some(brush.into());
         self
     }
 
@@ -111,15 +142,17 @@ impl<State, Action> View<State, Action, ViewCtx> for Label {
     type ViewState = ();
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
-        let widget_pod = ctx.new_pod(
-            widgets::Label::new(self.label.clone())
-                .with_brush(self.text_brush.clone())
-                .with_alignment(self.alignment)
-                .with_style(StyleProperty::FontSize(self.text_size))
-                .with_style(StyleProperty::FontWeight(self.weight))
-                .with_style(StyleProperty::FontStack(self.font.clone()))
-                .with_line_break_mode(self.line_break_mode),
-        );
+        let mut label = widgets::Label::new(self.label.clone())
+            .with_spans(self.spans.clone())
+            .with_alignment(self.alignment)
+            .with_style(StyleProperty::FontSize(self.text_size))
+            .with_style(StyleProperty::FontWeight(self.weight))
+            .with_style(StyleProperty::FontStack(self.font.clone()))
+            .with_line_break_mode(self.line_break_mode);
+        if let Some(brush) = &self.text_brush {
+            label = label.with_brush(brush.clone());
+        }
+        let widget_pod = ctx.new_pod(label);
         (widget_pod, ())
     }
 
@@ -133,8 +166,14 @@ impl<State, Action> View<State, Action, ViewCtx> for Label {
         if prev.label != self.label {
             widgets::Label::set_text(&mut element, self.label.clone());
         }
+        if prev.spans != self.spans {
+            widgets::Label::set_spans(&mut element, self.spans.clone());
+        }
         if prev.text_brush != self.text_brush {
-            widgets::Label::set_brush(&mut element, self.text_brush.clone());
+            match &self.text_brush {
+                Some(brush) => widgets::Label::set_brush(&mut element, brush.clone()),
+                None => widgets::Label::clear_brush(&mut element),
+            }
         }
         if prev.alignment != self.alignment {
             widgets::Label::set_alignment(&mut element, self.alignment);
@@ -6,27 +6,77 @@
 mod task;
 pub use task::*;
 
+mod timer;
+pub use timer::*;
+
 mod worker;
 pub use worker::*;
 
+mod animate;
+pub use animate::*;
+
+#[cfg(feature = "async_image")]
+mod async_image;
+#[cfg(feature = "async_image")]
+pub use async_image::*;
+
 mod button;
 pub use button::*;
 
+mod canvas;
+pub use canvas::*;
+
 mod checkbox;
 pub use checkbox::*;
 
+mod conditional;
+pub use conditional::*;
+
+mod context_menu;
+pub use context_menu::*;
+
+mod disabled;
+pub use disabled::*;
+
+mod dropdown;
+pub use dropdown::*;
+
+mod expander;
+pub use expander::*;
+
 mod flex;
 pub use flex::*;
 
+mod focus;
+pub use focus::*;
+
+mod gestures;
+pub use gestures::*;
+
 mod grid;
 pub use grid::*;
 
+mod hotkey;
+pub use hotkey::*;
+
+mod hover;
+pub use hover::*;
+
 mod sized_box;
 pub use sized_box::*;
 
+mod slider;
+pub use slider::*;
+
 mod spinner;
 pub use spinner::*;
 
+mod split;
+pub use split::*;
+
+mod tabs;
+pub use tabs::*;
+
 mod image;
 pub use image::*;
 
@@ -36,15 +86,39 @@ pub use label::*;
 mod variable_label;
 pub use variable_label::*;
 
+mod virtual_list;
+pub use virtual_list::*;
+
 mod progress_bar;
 pub use progress_bar::*;
 
+mod rate_limit;
+pub use rate_limit::*;
+
 mod prose;
 pub use prose::*;
 
+mod radio_group;
+pub use radio_group::*;
+
 mod textbox;
 pub use textbox::*;
 
+mod tooltip;
+pub use tooltip::*;
+
+mod modal;
+pub use modal::*;
+
+mod opacity;
+pub use opacity::*;
+
+mod theme;
+pub use theme::*;
+
+mod clip;
+pub use clip::*;
+
 mod portal;
 pub use portal::*;
 
@@ -53,3 +127,6 @@ pub use zstack::*;
 
 mod transform;
 pub use transform::*;
+
+mod transformed_box;
+pub use transformed_box::*;
@@ -0,0 +1,194 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets::{self, ModalOptions};
+use vello::peniko::Brush;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+type DismissCallback<State, Action> = Box<dyn Fn(&mut State) -> Action + Send + Sync + 'static>;
+
+/// A view which, whilst `is_open` is true, shows `content` centered above a scrim.
+///
+/// Whilst the modal is open, the widgets beneath it can't be clicked, and
+/// focus can't leave the modal.
+/// When it is closed, focus is restored to the widget which had it before the modal opened.
+///
+/// Unlike [`RootWidget::push_overlay`](masonry::widgets::RootWidget::push_overlay), the modal
+/// is part of the view tree it is created in, so it only covers the space given to it by its
+/// parent. To cover the whole window, place it in a [`zstack`](crate::view::zstack) above
+/// the rest of the app.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{button, flex, label, modal, zstack};
+/// # use xilem::WidgetView;
+///
+/// struct State {
+///     confirming: bool,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<State> + use<> {
+/// zstack((
+///     button("Delete", |state: &mut State| state.confirming = true),
+///     modal(
+///         state.confirming,
+///         flex((
+///             label("Are you sure?"),
+///             button("Cancel", |state: &mut State| state.confirming = false),
+///         )),
+///     )
+///     .on_dismiss(|state: &mut State| state.confirming = false),
+/// ))
+/// # }
+/// ```
+pub fn modal<State, Action, Content>(
+    is_open: bool,
+    content: Content,
+) -> Modal<Content, State, Action>
+where
+    Content: WidgetView<State, Action>,
+{
+    Modal {
+        content,
+        is_open,
+        scrim: ModalOptions::default().scrim,
+        on_dismiss: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`modal`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Modal<V, State, Action> {
+    content: V,
+    is_open: bool,
+    scrim: Brush,
+    on_dismiss: Option<DismissCallback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> Modal<V, State, Action> {
+    /// Set the brush used to dim the area behind the modal's content.
+    pub fn scrim(mut self, scrim: impl Into<Brush>) -> Self {
+        self.scrim = scrim.into();
+        self
+    }
+
+    /// Allow the user to close the modal by pressing Escape, calling `on_dismiss` when they do.
+    ///
+    /// The modal is closed straight away; `on_dismiss` should update the app state so that
+    /// `is_open` is false in the next rebuild.
+    pub fn on_dismiss<F>(mut self, on_dismiss: F) -> Self
+    where
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        self.on_dismiss = Some(Box::new(on_dismiss));
+        self
+    }
+
+    fn options(&self) -> ModalOptions {
+        ModalOptions {
+            scrim: self.scrim.clone(),
+            dismissable: self.on_dismiss.is_some(),
+        }
+    }
+}
+
+const CONTENT_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, State, Action> ViewMarker for Modal<V, State, Action> {}
+impl<Content, State, Action> View<State, Action, ViewCtx> for Modal<Content, State, Action>
+where
+    Content: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Modal<Content::Widget>>;
+    type ViewState = Content::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (content, content_state) = ctx.with_id(CONTENT_VIEW_ID, |ctx| self.content.build(ctx));
+        let pod = ctx.with_action_widget(|ctx| {
+            ctx.new_pod(
+                widgets::Modal::from_pod(content.into_widget_pod(), self.options())
+                    .with_open(self.is_open),
+            )
+        });
+        (pod, content_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.scrim != prev.scrim || self.on_dismiss.is_some() != prev.on_dismiss.is_some() {
+            widgets::Modal::set_options(&mut element, self.options());
+        }
+        // The modal may have been dismissed since the last rebuild, so compare
+        // against the widget rather than the previous view.
+        if self.is_open != element.widget.is_open() {
+            widgets::Modal::set_open(&mut element, self.is_open);
+        }
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            let content = widgets::Modal::child_mut(&mut element);
+            self.content
+                .rebuild(&prev.content, view_state, ctx, content);
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        // Release the focus trap, so that focus isn't stuck in a removed modal.
+        widgets::Modal::set_open(&mut element, false);
+        ctx.with_id(CONTENT_VIEW_ID, |ctx| {
+            let content = widgets::Modal::child_mut(&mut element);
+            self.content.teardown(view_state, ctx, content);
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CONTENT_VIEW_ID, rest)) => {
+                self.content.message(view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => match (&*action, &self.on_dismiss) {
+                    (masonry::core::Action::ModalDismissed, Some(on_dismiss)) => {
+                        MessageResult::Action(on_dismiss(app_state))
+                    }
+                    _ => {
+                        tracing::error!("Wrong action type in Modal::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                },
+                Err(message) => {
+                    tracing::error!("Wrong message type in Modal::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Modal::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
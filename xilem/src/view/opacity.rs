@@ -0,0 +1,101 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which paints the widget created by `child` with `alpha`, from 0.0 (invisible)
+/// to 1.0 (opaque).
+///
+/// The child is still laid out, and can still be interacted with, when it is invisible.
+/// Changing `alpha` only causes the child to be repainted, so this can be used to fade it
+/// in and out.
+///
+/// This can also be created using [`WidgetView::opacity`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{label, opacity};
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+/// opacity(label("Disabled feature"), 0.5)
+/// # }
+/// ```
+pub fn opacity<Child, State, Action>(child: Child, alpha: f64) -> Opacity<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Opacity {
+        child,
+        alpha,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`opacity`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Opacity<V, State, Action> {
+    child: V,
+    alpha: f64,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for Opacity<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Opacity<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Opacity<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let pod = ctx.new_pod(widgets::Opacity::from_pod(
+            child.into_widget_pod(),
+            self.alpha,
+        ));
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.alpha != prev.alpha {
+            widgets::Opacity::set_alpha(&mut element, self.alpha);
+        }
+        let child = widgets::Opacity::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::Opacity::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
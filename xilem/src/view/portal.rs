@@ -3,11 +3,28 @@
 
 use std::marker::PhantomData;
 
+use masonry::core::PortalAction;
 use masonry::widgets;
+pub use masonry::widgets::{ScrollAxes, ScrollBarStyle};
+use vello::kurbo::{Size, Vec2};
 
 use crate::core::{DynMessage, Mut, ViewMarker};
 use crate::{MessageResult, Pod, View, ViewCtx, ViewId, WidgetView};
 
+type ScrollCallback<State, Action> =
+    Box<dyn Fn(&mut State, ScrollInfo) -> Action + Send + Sync + 'static>;
+
+/// The scroll state of a [`portal`], passed to [`Portal::on_scroll`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollInfo {
+    /// How far the viewport is from the top left of the content.
+    pub offset: Vec2,
+    /// The size of the portal.
+    pub viewport: Size,
+    /// The size of the portal's content.
+    pub content: Size,
+}
+
 /// A view which puts `child` into a scrollable region.
 ///
 /// This corresponds to the Masonry [`Portal`](masonry::widgets::Portal) widget.
@@ -17,6 +34,13 @@ where
 {
     Portal {
         child,
+        scroll_axes: ScrollAxes::default(),
+        scrollbar_style: ScrollBarStyle::default(),
+        overlay_scrollbars: false,
+        kinetic_scrolling: false,
+        kinetic_deceleration: masonry::theme::KINETIC_SCROLL_DECELERATION,
+        smooth_scrolling: false,
+        on_scroll: None,
         phantom: PhantomData,
     }
 }
@@ -24,9 +48,76 @@ where
 #[must_use = "View values do nothing unless provided to Xilem."]
 pub struct Portal<V, State, Action> {
     child: V,
+    scroll_axes: ScrollAxes,
+    scrollbar_style: ScrollBarStyle,
+    overlay_scrollbars: bool,
+    kinetic_scrolling: bool,
+    kinetic_deceleration: f64,
+    smooth_scrolling: bool,
+    on_scroll: Option<ScrollCallback<State, Action>>,
     phantom: PhantomData<(State, Action)>,
 }
 
+impl<V, State, Action> Portal<V, State, Action> {
+    /// Set the axes along which the content can be scrolled.
+    ///
+    /// The default is [`ScrollAxes::Vertical`].
+    pub fn scroll_axes(mut self, scroll_axes: ScrollAxes) -> Self {
+        self.scroll_axes = scroll_axes;
+        self
+    }
+
+    /// Set how the scrollbars are drawn.
+    pub fn scrollbar_style(mut self, style: ScrollBarStyle) -> Self {
+        self.scrollbar_style = style;
+        self
+    }
+
+    /// Set whether the scrollbars are hidden until the content is scrolled or hovered.
+    ///
+    /// See [`masonry::widgets::Portal::overlay_scrollbars`] for details.
+    pub fn overlay_scrollbars(mut self, overlay: bool) -> Self {
+        self.overlay_scrollbars = overlay;
+        self
+    }
+
+    /// Set whether wheel and touchpad scrolling keeps going after the gesture ends.
+    ///
+    /// See [`masonry::widgets::Portal::kinetic_scrolling`] for details.
+    pub fn kinetic_scrolling(mut self, kinetic: bool) -> Self {
+        self.kinetic_scrolling = kinetic;
+        self
+    }
+
+    /// Set how quickly kinetic scrolling slows down.
+    ///
+    /// See [`masonry::widgets::Portal::kinetic_deceleration`] for details.
+    pub fn kinetic_deceleration(mut self, deceleration: f64) -> Self {
+        self.kinetic_deceleration = deceleration;
+        self
+    }
+
+    /// Set whether scrolling with the wheel is animated.
+    pub fn smooth_scrolling(mut self, smooth: bool) -> Self {
+        self.smooth_scrolling = smooth;
+        self
+    }
+
+    /// Call `on_scroll` when the viewport moves, or when the size of the portal or its
+    /// content changes.
+    ///
+    /// This is called at most once per frame.
+    pub fn on_scroll<F>(mut self, on_scroll: F) -> Self
+    where
+        F: Fn(&mut State, ScrollInfo) -> Action + Send + Sync + 'static,
+    {
+        self.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
 impl<V, State, Action> ViewMarker for Portal<V, State, Action> {}
 impl<Child, State, Action> View<State, Action, ViewCtx> for Portal<Child, State, Action>
 where
@@ -38,10 +129,15 @@ where
     type ViewState = Child::ViewState;
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
-        // The Portal `View` doesn't get any messages directly (yet - scroll events?), so doesn't need to
-        // use ctx.with_id.
-        let (child, child_state) = self.child.build(ctx);
-        let widget_pod = ctx.new_pod(widgets::Portal::new_pod(child.into_widget_pod()));
+        let (child, child_state) = ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx));
+        let widget = widgets::Portal::new_pod(child.into_widget_pod())
+            .scroll_axes(self.scroll_axes)
+            .scrollbar_style(self.scrollbar_style.clone())
+            .overlay_scrollbars(self.overlay_scrollbars)
+            .kinetic_scrolling(self.kinetic_scrolling)
+            .kinetic_deceleration(self.kinetic_deceleration)
+            .smooth_scrolling(self.smooth_scrolling);
+        let widget_pod = ctx.with_action_widget(|ctx| ctx.new_pod(widget));
         (widget_pod, child_state)
     }
 
@@ -52,9 +148,29 @@ where
         ctx: &mut ViewCtx,
         mut element: Mut<Self::Element>,
     ) {
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child
-            .rebuild(&prev.child, view_state, ctx, child_element);
+        if self.scroll_axes != prev.scroll_axes {
+            widgets::Portal::set_scroll_axes(&mut element, self.scroll_axes);
+        }
+        if self.scrollbar_style != prev.scrollbar_style {
+            widgets::Portal::set_scrollbar_style(&mut element, self.scrollbar_style.clone());
+        }
+        if self.overlay_scrollbars != prev.overlay_scrollbars {
+            widgets::Portal::set_overlay_scrollbars(&mut element, self.overlay_scrollbars);
+        }
+        if self.kinetic_scrolling != prev.kinetic_scrolling {
+            widgets::Portal::set_kinetic_scrolling(&mut element, self.kinetic_scrolling);
+        }
+        if self.kinetic_deceleration != prev.kinetic_deceleration {
+            widgets::Portal::set_kinetic_deceleration(&mut element, self.kinetic_deceleration);
+        }
+        if self.smooth_scrolling != prev.smooth_scrolling {
+            widgets::Portal::set_smooth_scrolling(&mut element, self.smooth_scrolling);
+        }
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child_element = widgets::Portal::child_mut(&mut element);
+            self.child
+                .rebuild(&prev.child, view_state, ctx, child_element);
+        });
     }
 
     fn teardown(
@@ -63,8 +179,11 @@ where
         ctx: &mut ViewCtx,
         mut element: Mut<Self::Element>,
     ) {
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child.teardown(view_state, ctx, child_element);
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            let child_element = widgets::Portal::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child_element);
+        });
+        ctx.teardown_leaf(element);
     }
 
     fn message(
@@ -74,6 +193,41 @@ where
         message: DynMessage,
         app_state: &mut State,
     ) -> MessageResult<Action> {
-        self.child.message(view_state, id_path, message, app_state)
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => {
+                self.child.message(view_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    if let masonry::core::Action::Portal(PortalAction::ScrollChanged {
+                        offset,
+                        viewport,
+                        content,
+                    }) = *action
+                    {
+                        let Some(on_scroll) = &self.on_scroll else {
+                            return MessageResult::Nop;
+                        };
+                        let info = ScrollInfo {
+                            offset,
+                            viewport,
+                            content,
+                        };
+                        MessageResult::Action(on_scroll(app_state, info))
+                    } else {
+                        tracing::error!("Wrong action type in Portal::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in Portal::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Portal::message");
+                MessageResult::Stale(message)
+            }
+        }
     }
 }
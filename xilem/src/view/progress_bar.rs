@@ -6,6 +6,10 @@ use masonry::widgets;
 use crate::core::{DynMessage, Mut, ViewMarker};
 use crate::{MessageResult, Pod, View, ViewCtx, ViewId};
 
+/// A progress bar, showing `progress` in the range 0 to 1.
+///
+/// If `progress` is `None`, the length of the operation is unknown, and an animated
+/// indeterminate progress bar is shown instead.
 pub fn progress_bar(progress: Option<f64>) -> ProgressBar {
     ProgressBar { progress }
 }
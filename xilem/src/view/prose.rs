@@ -1,22 +1,29 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::ops::Range;
+
 use masonry::core::{ArcStr, StyleProperty};
+pub use masonry::widgets::{HighlightStyle, LineNumbering};
 use masonry::widgets::{
     LineBreaking, {self},
 };
 use vello::peniko::Brush;
 
 use crate::core::{DynMessage, Mut, ViewMarker};
-use crate::{Color, MessageResult, Pod, TextAlignment, View, ViewCtx, ViewId};
+use crate::{MessageResult, Pod, TextAlignment, View, ViewCtx, ViewId};
 
 pub fn prose(content: impl Into<ArcStr>) -> Prose {
     Prose {
         content: content.into(),
-        text_brush: Color::WHITE.into(),
+        text_brush: None,
         alignment: TextAlignment::default(),
         text_size: masonry::theme::TEXT_SIZE_NORMAL,
         line_break_mode: LineBreaking::WordWrap,
+        line_numbers: false,
+        line_numbering: LineNumbering::Logical,
+        highlights: Vec::new(),
+        highlight_style: HighlightStyle::default(),
     }
 }
 
@@ -34,18 +41,25 @@ pub fn inline_prose(content: impl Into<ArcStr>) -> Prose {
 pub struct Prose {
     content: ArcStr,
 
-    text_brush: Brush,
+    text_brush: Option<Brush>,
     alignment: TextAlignment,
     text_size: f32,
     line_break_mode: LineBreaking,
+    line_numbers: bool,
+    line_numbering: LineNumbering,
+    highlights: Vec<Range<usize>>,
+    highlight_style: HighlightStyle,
     // TODO: disabled: bool,
     // TODO: add more attributes of `masonry::widgets::Prose`
 }
 
 impl Prose {
+    /// Set the brush used to paint the text.
+    ///
+    /// By default, the text is painted with the [theme's text color](masonry::theme::Theme::text_color).
     #[doc(alias = "color")]
     pub fn brush(mut self, brush: impl Into<Brush>) -> Self {
-        self.text_brush = brush.into();
+        self.text_brush = Some(brush.into());
         self
     }
 
@@ -63,6 +77,51 @@ impl Prose {
         self.line_break_mode = line_break_mode;
         self
     }
+
+    /// Show line numbers in a gutter before the text, such as for viewing code or logs.
+    ///
+    /// See [`masonry::widgets::TextArea::with_line_numbers`] for details.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Set which lines are numbered when [line numbers](Self::line_numbers) are shown.
+    ///
+    /// By default, the lines as separated by newlines are numbered.
+    pub fn line_numbering(mut self, line_numbering: LineNumbering) -> Self {
+        self.line_numbering = line_numbering;
+        self
+    }
+
+    /// Fill in a background behind byte ranges of the text, such as the matches of a search.
+    ///
+    /// The highlights are only updated in the widget when the ranges change.
+    /// See [`masonry::widgets::TextArea::with_highlights`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xilem::view::prose;
+    /// # use xilem::WidgetView;
+    ///
+    /// # fn view(log: &str, search: &str) -> impl WidgetView<()> + use<> {
+    /// let matches = log
+    ///     .match_indices(search)
+    ///     .map(|(start, found)| start..start + found.len());
+    /// prose(log.to_string()).highlights(matches.collect::<Vec<_>>())
+    /// # }
+    /// ```
+    pub fn highlights(mut self, ranges: impl Into<Vec<Range<usize>>>) -> Self {
+        self.highlights = ranges.into();
+        self
+    }
+
+    /// Set how the [highlights](Self::highlights) are painted.
+    pub fn highlight_style(mut self, style: HighlightStyle) -> Self {
+        self.highlight_style = style;
+        self
+    }
 }
 
 fn line_break_clips(linebreaking: LineBreaking) -> bool {
@@ -75,11 +134,18 @@ impl<State, Action> View<State, Action, ViewCtx> for Prose {
     type ViewState = ();
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
-        let text_area = widgets::TextArea::new_immutable(&self.content)
-            .with_brush(self.text_brush.clone())
+        let mut text_area = widgets::TextArea::new_immutable(&self.content)
             .with_alignment(self.alignment)
             .with_style(StyleProperty::FontSize(self.text_size))
-            .with_word_wrap(self.line_break_mode == LineBreaking::WordWrap);
+            .with_word_wrap(self.line_break_mode == LineBreaking::WordWrap)
+            .with_line_numbers(self.line_numbers)
+            .with_line_numbering(self.line_numbering);
+        if let Some(brush) = &self.text_brush {
+            text_area = text_area.with_brush(brush.clone());
+        }
+        if !self.highlights.is_empty() {
+            text_area = text_area.with_highlights(self.highlights.clone(), self.highlight_style);
+        }
         let widget_pod = ctx.new_pod(
             widgets::Prose::from_text_area(text_area)
                 .with_clip(line_break_clips(self.line_break_mode)),
@@ -99,7 +165,10 @@ impl<State, Action> View<State, Action, ViewCtx> for Prose {
             widgets::TextArea::reset_text(&mut text_area, &self.content);
         }
         if prev.text_brush != self.text_brush {
-            widgets::TextArea::set_brush(&mut text_area, self.text_brush.clone());
+            match &self.text_brush {
+                Some(brush) => widgets::TextArea::set_brush(&mut text_area, brush.clone()),
+                None => widgets::TextArea::clear_brush(&mut text_area),
+            }
         }
         if prev.alignment != self.alignment {
             widgets::TextArea::set_alignment(&mut text_area, self.alignment);
@@ -110,6 +179,23 @@ impl<State, Action> View<State, Action, ViewCtx> for Prose {
                 StyleProperty::FontSize(self.text_size),
             );
         }
+        if prev.line_numbers != self.line_numbers {
+            widgets::TextArea::set_line_numbers(&mut text_area, self.line_numbers);
+        }
+        if prev.line_numbering != self.line_numbering {
+            widgets::TextArea::set_line_numbering(&mut text_area, self.line_numbering);
+        }
+        if prev.highlights != self.highlights || prev.highlight_style != self.highlight_style {
+            if self.highlights.is_empty() {
+                widgets::TextArea::clear_highlights(&mut text_area);
+            } else {
+                widgets::TextArea::set_highlights(
+                    &mut text_area,
+                    self.highlights.clone(),
+                    self.highlight_style,
+                );
+            }
+        }
         if prev.line_break_mode != self.line_break_mode {
             widgets::TextArea::set_word_wrap(
                 &mut text_area,
@@ -0,0 +1,229 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry::core::{ArcStr, RadioButtonAction};
+use masonry::widgets::{self, RadioButton};
+
+use crate::core::{DynMessage, Mut, View, ViewMarker, ViewPathTracker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId};
+
+/// A group of radio buttons, of which only the one for `selected` is checked.
+///
+/// Each option is a value and the label shown for it.
+/// `on_select` is called with an option's value when the user selects its radio button.
+/// Whilst a radio button in the group is focused, the arrow keys select the next or previous
+/// option (wrapping around at either end), and move focus to its button.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::radio_group;
+/// # use xilem::WidgetView;
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Size {
+///     Small,
+///     Medium,
+///     Large,
+/// }
+///
+/// struct AppState {
+///     size: Size,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// radio_group(
+///     [
+///         (Size::Small, "Small"),
+///         (Size::Medium, "Medium"),
+///         (Size::Large, "Large"),
+///     ],
+///     state.size,
+///     |state: &mut AppState, size| state.size = size,
+/// )
+/// # }
+/// ```
+pub fn radio_group<V, L, F, State, Action>(
+    options: impl IntoIterator<Item = (V, L)>,
+    selected: V,
+    on_select: F,
+) -> RadioGroup<V, F>
+where
+    V: PartialEq + Clone,
+    L: Into<ArcStr>,
+    F: Fn(&mut State, V) -> Action + Send + Sync + 'static,
+{
+    RadioGroup {
+        options: options
+            .into_iter()
+            .map(|(value, label)| (value, label.into()))
+            .collect(),
+        selected,
+        on_select,
+    }
+}
+
+/// The [`View`] created by [`radio_group`].
+///
+/// See `radio_group` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct RadioGroup<V, F> {
+    options: Vec<(V, ArcStr)>,
+    selected: V,
+    on_select: F,
+}
+
+impl<V, F> RadioGroup<V, F>
+where
+    V: PartialEq,
+{
+    fn is_selected(&self, index: usize) -> bool {
+        self.options[index].0 == self.selected
+    }
+
+    fn build_button(&self, ctx: &mut ViewCtx, index: usize) -> Pod<RadioButton> {
+        ctx.with_id(view_id(index), |ctx| {
+            ctx.with_action_widget(|ctx| {
+                ctx.new_pod(RadioButton::new(
+                    self.is_selected(index),
+                    self.options[index].1.clone(),
+                ))
+            })
+        })
+    }
+}
+
+fn view_id(index: usize) -> ViewId {
+    ViewId::new(index.try_into().expect("Fewer than 2^64 options"))
+}
+
+impl<V, F> ViewMarker for RadioGroup<V, F> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for RadioGroup<V, F>
+where
+    V: PartialEq + Clone + 'static,
+    F: Fn(&mut State, V) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Flex>;
+    /// The option whose button should be focused in the next rebuild,
+    /// after it was selected using the arrow keys.
+    type ViewState = Option<usize>;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let mut flex =
+            widgets::Flex::column().cross_axis_alignment(widgets::CrossAxisAlignment::Start);
+        for index in 0..self.options.len() {
+            let button = self.build_button(ctx, index);
+            flex = flex.with_child_pod(button.erased_widget_pod());
+        }
+        (ctx.new_pod(flex), None)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        pending_focus: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let common = self.options.len().min(prev.options.len());
+        for index in 0..common {
+            let mut child = widgets::Flex::child_mut(&mut element, index)
+                .expect("RadioGroup only contains radio buttons");
+            let mut button = child.downcast::<RadioButton>();
+            if self.options[index].1 != prev.options[index].1 {
+                RadioButton::set_text(&mut button, self.options[index].1.clone());
+            }
+            // As the button selects itself when clicked, we compare to the widget's
+            // state, rather than the previous view's.
+            let checked = self.is_selected(index);
+            if button.widget.is_checked() != checked {
+                RadioButton::set_checked(&mut button, checked);
+            }
+        }
+        for index in (common..prev.options.len()).rev() {
+            let mut child = widgets::Flex::child_mut(&mut element, index)
+                .expect("RadioGroup only contains radio buttons");
+            ctx.with_id(view_id(index), |ctx| {
+                ctx.teardown_leaf(child.downcast::<RadioButton>());
+            });
+            widgets::Flex::remove_child(&mut element, index);
+        }
+        for index in common..self.options.len() {
+            let button = self.build_button(ctx, index);
+            widgets::Flex::insert_child_pod(&mut element, index, button.erased_widget_pod());
+        }
+
+        if let Some(index) = pending_focus.take() {
+            if index < self.options.len() {
+                let child = widgets::Flex::child_mut(&mut element, index)
+                    .expect("RadioGroup only contains radio buttons");
+                ctx.request_focus(child.ctx.widget_id());
+            }
+        }
+    }
+
+    fn teardown(
+        &self,
+        _: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        for index in 0..self.options.len() {
+            let mut child = widgets::Flex::child_mut(&mut element, index)
+                .expect("RadioGroup only contains radio buttons");
+            ctx.with_id(view_id(index), |ctx| {
+                ctx.teardown_leaf(child.downcast::<RadioButton>());
+            });
+        }
+    }
+
+    fn message(
+        &self,
+        pending_focus: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let Some((index, rest)) = id_path.split_first() else {
+            tracing::error!("Missing id path in RadioGroup::message");
+            return MessageResult::Stale(message);
+        };
+        debug_assert!(
+            rest.is_empty(),
+            "id path should only contain the option in RadioGroup::message"
+        );
+        let index = usize::try_from(index.routing_id()).unwrap_or(usize::MAX);
+        let len = self.options.len();
+        if index >= len {
+            // The option was removed before this message was handled.
+            return MessageResult::Stale(message);
+        }
+        match message.downcast::<masonry::core::Action>() {
+            Ok(action) => {
+                let target = match *action {
+                    masonry::core::Action::RadioButton(RadioButtonAction::Selected) => index,
+                    masonry::core::Action::RadioButton(RadioButtonAction::SelectNext) => {
+                        let target = (index + 1) % len;
+                        *pending_focus = Some(target);
+                        target
+                    }
+                    masonry::core::Action::RadioButton(RadioButtonAction::SelectPrevious) => {
+                        let target = (index + len - 1) % len;
+                        *pending_focus = Some(target);
+                        target
+                    }
+                    _ => {
+                        tracing::error!("Wrong action type in RadioGroup::message: {action:?}");
+                        return MessageResult::Stale(action);
+                    }
+                };
+                let value = self.options[target].0.clone();
+                MessageResult::Action((self.on_select)(app_state, value))
+            }
+            Err(message) => {
+                tracing::error!("Wrong message type in RadioGroup::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
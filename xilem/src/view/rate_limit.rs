@@ -0,0 +1,466 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::mem::Discriminant;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+use crate::core::{
+    DynMessage, Message, MessageProxy, MessageResult, Mut, View, ViewId, ViewMarker,
+    ViewPathTracker,
+};
+use crate::{ViewCtx, WidgetView};
+
+/// A view which delays the messages from `child` until it has gone `duration` without
+/// sending any, then delivers only the latest.
+///
+/// This is useful for expensive reactions to frequent events, such as searching as the
+/// user types into a [`textbox`](crate::view::textbox): the app's callbacks are only
+/// called once the user pauses.
+///
+/// Messages are collapsed separately for each kind of message from each view, so that
+/// e.g. a textbox's latest text change is still delivered if its selection then changes.
+/// Pending messages are dropped if this view is removed from the tree, unless
+/// [`flush_on_teardown`](RateLimit::flush_on_teardown) is set.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use xilem::view::{debounce, textbox};
+/// # use xilem::WidgetView;
+///
+/// struct Search {
+///     query: String,
+///     results: Vec<String>,
+/// }
+///
+/// # fn view(search: &mut Search) -> impl WidgetView<Search> + use<> {
+/// debounce(
+///     Duration::from_millis(300),
+///     textbox(search.query.clone(), |search: &mut Search, query| {
+///         // This is only called once the user stops typing for 300ms.
+///         search.query = query;
+///     }),
+/// )
+/// # }
+/// ```
+pub fn debounce<Child, State, Action>(
+    duration: Duration,
+    child: Child,
+) -> RateLimit<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    RateLimit {
+        child,
+        duration,
+        mode: Mode::Debounce,
+        flush_on_teardown: false,
+        phantom: PhantomData,
+    }
+}
+
+/// A view which delivers the messages from `child` at most once every `duration`.
+///
+/// The first message is delivered straight away. Any messages sent in the following
+/// `duration` are held back, and only the latest is delivered once that time has passed,
+/// which starts another `duration` in which messages are held back.
+///
+/// Like [`debounce`], messages are collapsed separately for each kind of message from each
+/// view, and pending messages are dropped if this view is removed from the tree, unless
+/// [`flush_on_teardown`](RateLimit::flush_on_teardown) is set.
+pub fn throttle<Child, State, Action>(
+    duration: Duration,
+    child: Child,
+) -> RateLimit<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    RateLimit {
+        child,
+        duration,
+        mode: Mode::Throttle,
+        flush_on_teardown: false,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`debounce`] or [`throttle`].
+///
+/// See their docs for more details.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct RateLimit<V, State, Action> {
+    child: V,
+    duration: Duration,
+    mode: Mode,
+    flush_on_teardown: bool,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> RateLimit<V, State, Action> {
+    /// Set whether the messages which are being held back when this view is removed from
+    /// the tree are delivered straight away, rather than dropped.
+    ///
+    /// The app's callbacks can't be called whilst the view is being removed, so the
+    /// flushed messages are sent to this view's position in the tree, like the messages
+    /// from async tasks. They are delivered to the child without any further delay by
+    /// the view which is there when they are handled.
+    ///
+    /// This defaults to `false`.
+    pub fn flush_on_teardown(mut self, flush: bool) -> Self {
+        self.flush_on_teardown = flush;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Debounce,
+    Throttle,
+}
+
+/// The messages which a [`RateLimit`] sends to itself.
+#[derive(Debug)]
+enum RateLimitMessage {
+    /// Sent when the timer fires, or when there are more pending messages to deliver.
+    ///
+    /// This contains the generation of the timer which sent it, so that messages from
+    /// cancelled timers can be ignored.
+    Deliver(u64),
+    /// A message which was pending when the view was torn down, with the path to the
+    /// view within the child which it is for.
+    Flush(Vec<ViewId>, DynMessage),
+}
+
+/// What kind of message a message is, so that only messages of the same kind are collapsed.
+///
+/// As most messages are Masonry actions, these are further split by their variant.
+type MessageKind = (TypeId, Option<Discriminant<masonry::core::Action>>);
+
+fn message_kind(message: &dyn Message) -> MessageKind {
+    let message = message.as_any();
+    let action = message
+        .downcast_ref::<masonry::core::Action>()
+        .map(std::mem::discriminant);
+    (message.type_id(), action)
+}
+
+/// The state used to implement `View` for [`RateLimit`].
+#[doc(hidden)] // Implementation detail, public because of trait visibility rules
+pub struct RateLimitState<ChildState> {
+    child: ChildState,
+    /// The messages being held back, in the order they were first sent, with the path
+    /// to the view within the child which they are for.
+    pending: Vec<(Vec<ViewId>, MessageKind, DynMessage)>,
+    /// The timer after which pending messages are delivered.
+    ///
+    /// For throttling, this also marks that messages are being held back.
+    timer: Option<JoinHandle<()>>,
+    /// The generation of the current timer.
+    generation: u64,
+    proxy: MessageProxy<RateLimitMessage>,
+    runtime: Handle,
+}
+
+impl<ChildState> RateLimitState<ChildState> {
+    /// Start a new timer, cancelling the current one.
+    fn restart_timer(&mut self, duration: Duration) {
+        self.cancel_timer();
+        let proxy = self.proxy.clone();
+        let generation = self.generation;
+        self.timer = Some(self.runtime.spawn(async move {
+            tokio::time::sleep(duration).await;
+            drop(proxy.message(RateLimitMessage::Deliver(generation)));
+        }));
+    }
+
+    fn cancel_timer(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            timer.abort();
+        }
+        self.generation += 1;
+    }
+
+    /// Hold back `message`, replacing any pending message of the same kind from the same view.
+    fn hold(&mut self, id_path: &[ViewId], message: DynMessage) {
+        let kind = message_kind(&*message);
+        match self
+            .pending
+            .iter_mut()
+            .find(|(path, pending_kind, _)| path == id_path && *pending_kind == kind)
+        {
+            Some((_, _, pending)) => *pending = message,
+            None => self.pending.push((id_path.to_vec(), kind, message)),
+        }
+    }
+}
+
+const CHILD_VIEW_ID: ViewId = ViewId::new(0);
+
+impl<V, State, Action> ViewMarker for RateLimit<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for RateLimit<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Child::Element;
+    type ViewState = RateLimitState<Child::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (element, child) = ctx.with_id(CHILD_VIEW_ID, |ctx| self.child.build(ctx));
+        let path: Arc<[ViewId]> = ctx.view_path().into();
+        let state = RateLimitState {
+            child,
+            pending: Vec::new(),
+            timer: None,
+            generation: 0,
+            proxy: MessageProxy::new(ctx.proxy.clone(), path),
+            runtime: ctx.runtime().handle().clone(),
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            self.child
+                .rebuild(&prev.child, &mut view_state.child, ctx, element);
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<Self::Element>,
+    ) {
+        view_state.cancel_timer();
+        let pending = std::mem::take(&mut view_state.pending);
+        if self.flush_on_teardown {
+            for (path, _, message) in pending {
+                drop(
+                    view_state
+                        .proxy
+                        .message(RateLimitMessage::Flush(path, message)),
+                );
+            }
+        }
+        ctx.with_id(CHILD_VIEW_ID, |ctx| {
+            self.child.teardown(&mut view_state.child, ctx, element);
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD_VIEW_ID, rest)) => match self.mode {
+                Mode::Throttle if view_state.timer.is_none() => {
+                    view_state.restart_timer(self.duration);
+                    self.child
+                        .message(&mut view_state.child, rest, message, app_state)
+                }
+                Mode::Throttle => {
+                    view_state.hold(rest, message);
+                    MessageResult::Nop
+                }
+                Mode::Debounce => {
+                    view_state.hold(rest, message);
+                    view_state.restart_timer(self.duration);
+                    MessageResult::Nop
+                }
+            },
+            None => match message
+                .downcast::<RateLimitMessage>()
+                .map(|message| *message)
+            {
+                Ok(RateLimitMessage::Flush(path, message)) => {
+                    self.child
+                        .message(&mut view_state.child, &path, message, app_state)
+                }
+                Ok(RateLimitMessage::Deliver(generation)) => {
+                    if generation != view_state.generation {
+                        // From a timer which has since been cancelled.
+                        return MessageResult::Nop;
+                    }
+                    if view_state.pending.is_empty() {
+                        view_state.cancel_timer();
+                        return MessageResult::Nop;
+                    }
+                    // Only one action can be returned, so each pending message is
+                    // delivered in a separate `Deliver`.
+                    let (path, _, message) = view_state.pending.remove(0);
+                    if view_state.pending.is_empty() {
+                        match self.mode {
+                            // Throttling continues until a whole `duration` passes
+                            // without any messages.
+                            Mode::Throttle => view_state.restart_timer(self.duration),
+                            Mode::Debounce => view_state.cancel_timer(),
+                        }
+                    } else {
+                        drop(
+                            view_state
+                                .proxy
+                                .message(RateLimitMessage::Deliver(view_state.generation)),
+                        );
+                    }
+                    self.child
+                        .message(&mut view_state.child, &path, message, app_state)
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in RateLimit::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in RateLimit::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use masonry::core::PointerButton;
+    use masonry::testing::TestHarness;
+
+    use super::*;
+    use crate::TestMessages;
+    use crate::view::button;
+
+    /// A message from a button which was pressed.
+    fn pressed() -> DynMessage {
+        Box::new(masonry::core::Action::ButtonPressed(PointerButton::Primary))
+    }
+
+    /// Run the tasks of `ctx` whilst its paused clock moves forward by `duration`.
+    fn advance(ctx: &ViewCtx, duration: Duration) {
+        ctx.runtime().block_on(tokio::time::sleep(duration));
+    }
+
+    /// Handle the messages which `view` has sent to itself, returning how many there were.
+    fn handle_sent<V: View<u32, (), ViewCtx>>(
+        view: &V,
+        view_state: &mut V::ViewState,
+        messages: &TestMessages,
+        count: &mut u32,
+    ) -> usize {
+        let mut handled = 0;
+        // Handling a message can send more, so `try_iter` isn't used.
+        while let Ok((id_path, message)) = messages.try_recv() {
+            let _ = view.message(view_state, &id_path, message, count);
+            handled += 1;
+        }
+        handled
+    }
+
+    #[test]
+    fn debounce_delivers_latest() {
+        let view = debounce(
+            Duration::from_secs(1),
+            button("+", |count: &mut u32| *count += 1),
+        );
+        let (mut ctx, messages) = ViewCtx::for_test_with_paused_clock();
+        let (_, mut state) = view.build(&mut ctx);
+        let mut count = 0;
+
+        for _ in 0..3 {
+            let result = view.message(&mut state, &[CHILD_VIEW_ID], pressed(), &mut count);
+            assert!(matches!(result, MessageResult::Nop));
+            advance(&ctx, Duration::from_millis(500));
+        }
+        // Each press restarted the timer.
+        assert_eq!(handle_sent(&view, &mut state, &messages, &mut count), 0);
+        assert_eq!(count, 0);
+
+        advance(&ctx, Duration::from_millis(600));
+        handle_sent(&view, &mut state, &messages, &mut count);
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn throttle_delivers_at_most_once_per_duration() {
+        let view = throttle(
+            Duration::from_secs(1),
+            button("+", |count: &mut u32| *count += 1),
+        );
+        let (mut ctx, messages) = ViewCtx::for_test_with_paused_clock();
+        let (_, mut state) = view.build(&mut ctx);
+        let mut count = 0;
+
+        // The first press is delivered straight away, and the next are held back.
+        for _ in 0..3 {
+            let _ = view.message(&mut state, &[CHILD_VIEW_ID], pressed(), &mut count);
+            advance(&ctx, Duration::from_millis(200));
+        }
+        assert_eq!(count, 1);
+
+        advance(&ctx, Duration::from_millis(500));
+        handle_sent(&view, &mut state, &messages, &mut count);
+        assert_eq!(count, 2);
+
+        // Once a whole duration passes without any presses, the next is delivered straight away.
+        advance(&ctx, Duration::from_millis(1100));
+        handle_sent(&view, &mut state, &messages, &mut count);
+        assert_eq!(count, 2);
+        let _ = view.message(&mut state, &[CHILD_VIEW_ID], pressed(), &mut count);
+        assert_eq!(count, 3);
+    }
+
+    /// Press the button in `view`, tear `view` down, and return how many times the
+    /// button's callback is called afterwards.
+    fn presses_after_teardown(
+        view: RateLimit<impl WidgetView<u32, Widget = masonry::widgets::Button>, u32, ()>,
+    ) -> u32 {
+        let (mut ctx, messages) = ViewCtx::for_test_with_paused_clock();
+        let (pod, mut state) = view.build(&mut ctx);
+        let mut harness = TestHarness::create(*pod.widget);
+        let mut count = 0;
+
+        let _ = view.message(&mut state, &[CHILD_VIEW_ID], pressed(), &mut count);
+        harness.edit_root_widget(|mut root| {
+            view.teardown(&mut state, &mut ctx, root.downcast());
+        });
+        advance(&ctx, Duration::from_secs(5));
+        handle_sent(&view, &mut state, &messages, &mut count);
+        count
+    }
+
+    #[test]
+    fn teardown_drops_pending() {
+        let view = debounce(
+            Duration::from_secs(1),
+            button("+", |count: &mut u32| *count += 1),
+        );
+        assert_eq!(presses_after_teardown(view), 0);
+    }
+
+    #[test]
+    fn teardown_flushes_pending() {
+        let view = debounce(
+            Duration::from_secs(1),
+            button("+", |count: &mut u32| *count += 1),
+        )
+        .flush_on_teardown(true);
+        assert_eq!(presses_after_teardown(view), 1);
+    }
+}
@@ -40,6 +40,7 @@ where
         inner,
         height: None,
         width: None,
+        aspect_ratio: None,
         background: None,
         border: None,
         corner_radius: RoundedRectRadii::from_single_radius(0.0),
@@ -56,6 +57,7 @@ pub struct SizedBox<V, State, Action = ()> {
     inner: V,
     width: Option<f64>,
     height: Option<f64>,
+    aspect_ratio: Option<f64>,
     background: Option<Brush>,
     border: Option<BorderStyle>,
     corner_radius: RoundedRectRadii,
@@ -76,6 +78,19 @@ impl<V, State, Action> SizedBox<V, State, Action> {
         self
     }
 
+    /// Keep the container's width divided by its height equal to `aspect_ratio`.
+    ///
+    /// See [`masonry::widgets::SizedBox::aspect_ratio`] for how this interacts with
+    /// the width, height and the space given by the parent.
+    ///
+    /// # Panics
+    ///
+    /// When the view is built, if `aspect_ratio` is not a positive finite value.
+    pub fn aspect_ratio(mut self, aspect_ratio: f64) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
     /// Expand container to fit the parent.
     ///
     /// Only call this method if you want your widget to occupy all available
@@ -157,6 +172,9 @@ where
             .raw_height(self.height)
             .rounded(self.corner_radius)
             .padding(self.padding);
+        if let Some(aspect_ratio) = self.aspect_ratio {
+            widget = widget.aspect_ratio(aspect_ratio);
+        }
         if let Some(background) = &self.background {
             widget = widget.background(background.clone());
         }
@@ -186,6 +204,14 @@ where
                 None => widgets::SizedBox::unset_height(&mut element),
             }
         }
+        if self.aspect_ratio != prev.aspect_ratio {
+            match self.aspect_ratio {
+                Some(aspect_ratio) => {
+                    widgets::SizedBox::set_aspect_ratio(&mut element, aspect_ratio);
+                }
+                None => widgets::SizedBox::unset_aspect_ratio(&mut element),
+            }
+        }
         if self.background != prev.background {
             match &self.background {
                 Some(background) => {
@@ -0,0 +1,170 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::RangeInclusive;
+
+use masonry::core::SliderAction;
+use masonry::widgets::{self, Axis};
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId};
+
+type ChangeCallback<State, Action> = Box<dyn Fn(&mut State, f64) -> Action + Send + Sync + 'static>;
+type FinishedCallback<State, Action> = Box<dyn Fn(&mut State) -> Action + Send + Sync + 'static>;
+
+/// A slider for picking a number from `range`.
+///
+/// `on_change` is called whenever the user changes the value, including during a drag.
+/// To only react once the user has finished dragging, use [`on_editing_finished`](Slider::on_editing_finished).
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::slider;
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     volume: f64,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// slider(0.0..=1.0, state.volume, |state: &mut AppState, volume| {
+///     state.volume = volume;
+/// })
+/// .step(0.05)
+/// # }
+/// ```
+pub fn slider<F, State, Action>(
+    range: RangeInclusive<f64>,
+    value: f64,
+    on_change: F,
+) -> Slider<State, Action>
+where
+    F: Fn(&mut State, f64) -> Action + Send + Sync + 'static,
+{
+    Slider {
+        range,
+        value,
+        step: None,
+        axis: Axis::Horizontal,
+        on_change: Box::new(on_change),
+        on_editing_finished: None,
+    }
+}
+
+/// The [`View`] created by [`slider`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Slider<State, Action> {
+    range: RangeInclusive<f64>,
+    value: f64,
+    step: Option<f64>,
+    axis: Axis,
+    on_change: ChangeCallback<State, Action>,
+    on_editing_finished: Option<FinishedCallback<State, Action>>,
+}
+
+impl<State, Action> Slider<State, Action> {
+    /// Snap the value to multiples of `step` from the start of the range.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the direction the slider moves along.
+    ///
+    /// Vertical sliders have their minimum at the bottom.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set a callback which is called when the user releases the slider after dragging it.
+    pub fn on_editing_finished<F>(mut self, on_editing_finished: F) -> Self
+    where
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        self.on_editing_finished = Some(Box::new(on_editing_finished));
+        self
+    }
+}
+
+impl<State, Action> ViewMarker for Slider<State, Action> {}
+impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for Slider<State, Action> {
+    type Element = Pod<widgets::Slider>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        ctx.with_leaf_action_widget(|ctx| {
+            let mut slider =
+                widgets::Slider::new(*self.range.start(), *self.range.end(), self.value)
+                    .with_axis(self.axis);
+            if let Some(step) = self.step {
+                slider = slider.with_step(step);
+            }
+            ctx.new_pod(slider)
+        })
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if prev.range != self.range {
+            widgets::Slider::set_range(&mut element, *self.range.start(), *self.range.end());
+        }
+        if prev.step != self.step {
+            widgets::Slider::set_step(&mut element, self.step);
+        }
+        if prev.axis != self.axis {
+            widgets::Slider::set_axis(&mut element, self.axis);
+        }
+        // As in `textbox`, we compare to the widget's value rather than the previous view's,
+        // so that the value is reset if the app doesn't store the value from `on_change`.
+        if element.widget.value() != self.value {
+            widgets::Slider::set_value(&mut element, self.value);
+        }
+    }
+
+    fn teardown(&self, (): &mut Self::ViewState, ctx: &mut ViewCtx, element: Mut<Self::Element>) {
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            id_path.is_empty(),
+            "id path should be empty in Slider::message"
+        );
+        match message.downcast::<masonry::core::Action>() {
+            Ok(action) => match *action {
+                masonry::core::Action::Slider(SliderAction::ValueChanged(value)) => {
+                    MessageResult::Action((self.on_change)(app_state, value))
+                }
+                masonry::core::Action::Slider(SliderAction::DragFinished) => {
+                    match &self.on_editing_finished {
+                        Some(on_editing_finished) => {
+                            MessageResult::Action(on_editing_finished(app_state))
+                        }
+                        None => MessageResult::Nop,
+                    }
+                }
+                _ => {
+                    tracing::error!("Wrong action type in Slider::message: {action:?}");
+                    MessageResult::Stale(action)
+                }
+            },
+            Err(message) => {
+                tracing::error!("Wrong message type in Slider::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets::{self, Axis};
+
+use crate::core::{DynMessage, Mut, View, ViewId, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, WidgetView};
+
+type ResizeCallback<State, Action> = Box<dyn Fn(&mut State, f64) -> Action + Send + Sync + 'static>;
+
+/// A view which lays out `child1` and `child2` side by side, with a splitter bar between them
+/// which the user can drag to resize them.
+///
+/// The children are laid out left and right by default; use [`direction`](Split::direction)
+/// to lay them out up and down instead.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{label, split};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     sidebar_fraction: f64,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// split(label("Files"), label("Editor"))
+///     .fraction(state.sidebar_fraction)
+///     .on_resize(|state: &mut AppState, fraction| {
+///         state.sidebar_fraction = fraction;
+///     })
+/// # }
+/// ```
+pub fn split<ChildA, ChildB, State, Action>(
+    child1: ChildA,
+    child2: ChildB,
+) -> Split<ChildA, ChildB, State, Action>
+where
+    ChildA: WidgetView<State, Action>,
+    ChildB: WidgetView<State, Action>,
+{
+    Split {
+        child1,
+        child2,
+        axis: Axis::Horizontal,
+        fraction: 0.5,
+        min_size: (0.0, 0.0),
+        bar_size: 6.0,
+        draggable: true,
+        on_resize: None,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`split`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Split<ChildA, ChildB, State, Action> {
+    child1: ChildA,
+    child2: ChildB,
+    axis: Axis,
+    fraction: f64,
+    min_size: (f64, f64),
+    bar_size: f64,
+    draggable: bool,
+    on_resize: Option<ResizeCallback<State, Action>>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<ChildA, ChildB, State, Action> Split<ChildA, ChildB, State, Action> {
+    /// Set the axis which is split in two.
+    ///
+    /// [`Axis::Horizontal`] (the default) lays the children out left and right,
+    /// and [`Axis::Vertical`] lays them out up and down.
+    pub fn direction(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set the split point, as a fraction of the space shared by the two children.
+    ///
+    /// The value must be between `0.0` and `1.0`, inclusive, and defaults to `0.5`.
+    /// The splitter bar is only moved back to this split point if it changes, so the
+    /// user can drag the bar without the app needing to store where it was dragged to.
+    pub fn fraction(mut self, fraction: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "fraction must be in the range [0.0-1.0]!"
+        );
+        self.fraction = fraction;
+        self
+    }
+
+    /// Set the minimum size of each child along the split axis.
+    pub fn min_size(mut self, first: f64, second: f64) -> Self {
+        self.min_size = (first, second);
+        self
+    }
+
+    /// Set the size of the splitter bar, which defaults to `6.0`.
+    pub fn bar_size(mut self, bar_size: f64) -> Self {
+        self.bar_size = bar_size;
+        self
+    }
+
+    /// Set whether the user can drag the splitter bar, which defaults to `true`.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Set a callback which is called with the new split point, as a fraction of the space
+    /// shared by the two children, when the user moves the splitter bar.
+    ///
+    /// This can be used to store the split point, e.g. to restore it with
+    /// [`fraction`](Self::fraction) when the app is next opened.
+    pub fn on_resize<F>(mut self, on_resize: F) -> Self
+    where
+        F: Fn(&mut State, f64) -> Action + Send + Sync + 'static,
+    {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+}
+
+const CHILD1_VIEW_ID: ViewId = ViewId::new(0);
+const CHILD2_VIEW_ID: ViewId = ViewId::new(1);
+
+impl<ChildA, ChildB, State, Action> ViewMarker for Split<ChildA, ChildB, State, Action> {}
+impl<ChildA, ChildB, State, Action> View<State, Action, ViewCtx>
+    for Split<ChildA, ChildB, State, Action>
+where
+    ChildA: WidgetView<State, Action>,
+    ChildB: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Split<ChildA::Widget, ChildB::Widget>>;
+    type ViewState = (ChildA::ViewState, ChildB::ViewState);
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child1, child1_state) = ctx.with_id(CHILD1_VIEW_ID, |ctx| self.child1.build(ctx));
+        let (child2, child2_state) = ctx.with_id(CHILD2_VIEW_ID, |ctx| self.child2.build(ctx));
+        let widget = widgets::Split::from_pods(
+            self.axis,
+            child1.into_widget_pod(),
+            child2.into_widget_pod(),
+        )
+        .split_point(self.fraction)
+        .min_size(self.min_size.0, self.min_size.1)
+        .bar_size(self.bar_size)
+        .draggable(self.draggable);
+        let pod = ctx.with_action_widget(|ctx| ctx.new_pod(widget));
+        (pod, (child1_state, child2_state))
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (child1_state, child2_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.axis != prev.axis {
+            widgets::Split::set_split_axis(&mut element, self.axis);
+        }
+        // The user may have moved the bar since, so this only moves it back if the app
+        // asks for a different split point.
+        if self.fraction != prev.fraction {
+            widgets::Split::set_split_point(&mut element, self.fraction);
+        }
+        if self.min_size != prev.min_size {
+            widgets::Split::set_min_size(&mut element, self.min_size.0, self.min_size.1);
+        }
+        if self.bar_size != prev.bar_size {
+            widgets::Split::set_bar_size(&mut element, self.bar_size);
+        }
+        if self.draggable != prev.draggable {
+            widgets::Split::set_draggable(&mut element, self.draggable);
+        }
+        ctx.with_id(CHILD1_VIEW_ID, |ctx| {
+            let child = widgets::Split::child1_mut(&mut element);
+            self.child1.rebuild(&prev.child1, child1_state, ctx, child);
+        });
+        ctx.with_id(CHILD2_VIEW_ID, |ctx| {
+            let child = widgets::Split::child2_mut(&mut element);
+            self.child2.rebuild(&prev.child2, child2_state, ctx, child);
+        });
+    }
+
+    fn teardown(
+        &self,
+        (child1_state, child2_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        ctx.with_id(CHILD1_VIEW_ID, |ctx| {
+            let child = widgets::Split::child1_mut(&mut element);
+            self.child1.teardown(child1_state, ctx, child);
+        });
+        ctx.with_id(CHILD2_VIEW_ID, |ctx| {
+            let child = widgets::Split::child2_mut(&mut element);
+            self.child2.teardown(child2_state, ctx, child);
+        });
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        (child1_state, child2_state): &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        match id_path.split_first() {
+            Some((&CHILD1_VIEW_ID, rest)) => {
+                self.child1.message(child1_state, rest, message, app_state)
+            }
+            Some((&CHILD2_VIEW_ID, rest)) => {
+                self.child2.message(child2_state, rest, message, app_state)
+            }
+            None => match message.downcast::<masonry::core::Action>() {
+                Ok(action) => {
+                    if let masonry::core::Action::SplitResized(change) = *action {
+                        match &self.on_resize {
+                            Some(on_resize) => {
+                                MessageResult::Action(on_resize(app_state, change.fraction))
+                            }
+                            None => MessageResult::Nop,
+                        }
+                    } else {
+                        tracing::error!("Wrong action type in Split::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                }
+                Err(message) => {
+                    tracing::error!("Wrong message type in Split::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            },
+            _ => {
+                tracing::warn!("Got unexpected id path in Split::message");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
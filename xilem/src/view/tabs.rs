@@ -0,0 +1,355 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{ArcStr, Widget, WidgetMut, WidgetPod};
+use masonry::widgets::{self, Label, TabPanel};
+
+use crate::core::{
+    AppendVec, DynMessage, ElementSplice, Mut, SuperElement, View, ViewElement, ViewMarker,
+    ViewSequence,
+};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A row of tabs, of which the one at index `selected` shows its content below the tabs.
+///
+/// Each tab is created with [`tab`], from its label and the view of its content.
+/// `on_select` is called with a tab's index when the user selects it, by clicking its
+/// label or using the arrow keys whilst the tabs are focused.
+///
+/// Only the content of the selected tab is shown, but the widgets of the other tabs are
+/// kept, so they keep their state (such as the scroll position of a portal), and switching
+/// tabs doesn't rebuild them.
+/// The views of the other tabs are still rebuilt when the app state changes.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{label, tab, tabs};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     tab: usize,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// tabs(
+///     state.tab,
+///     |state: &mut AppState, tab| state.tab = tab,
+///     (
+///         tab("General", label("General settings")),
+///         tab("Advanced", label("Advanced settings")),
+///     ),
+/// )
+/// # }
+/// ```
+pub fn tabs<State, Action, Seq, F>(selected: usize, on_select: F, sequence: Seq) -> Tabs<Seq, F>
+where
+    Seq: TabsSequence<State, Action>,
+    F: Fn(&mut State, usize) -> Action + Send + Sync + 'static,
+{
+    Tabs {
+        sequence,
+        selected,
+        on_select,
+    }
+}
+
+/// The view for [`tabs`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Tabs<Seq, F> {
+    sequence: Seq,
+    selected: usize,
+    on_select: F,
+}
+
+impl<Seq, F> ViewMarker for Tabs<Seq, F> {}
+impl<State, Action, Seq, F> View<State, Action, ViewCtx> for Tabs<Seq, F>
+where
+    State: 'static,
+    Action: 'static,
+    Seq: TabsSequence<State, Action>,
+    F: Fn(&mut State, usize) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Tabs>;
+    type ViewState = Seq::SeqState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let mut elements = AppendVec::default();
+        let seq_state = self.sequence.seq_build(ctx, &mut elements);
+        let mut widget = widgets::Tabs::new().with_selected(self.selected);
+        for element in elements.into_inner() {
+            widget = widget.with_tab_pod(
+                WidgetPod::new(Label::new(element.label)).erased(),
+                element.content.erased_widget_pod(),
+            );
+        }
+        let pod = ctx.with_action_widget(|ctx| ctx.new_pod(widget));
+        (pod, seq_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let mut splice = TabsSplice::new(element.reborrow_mut());
+        self.sequence
+            .seq_rebuild(&prev.sequence, view_state, ctx, &mut splice);
+        debug_assert!(splice.scratch.is_empty());
+        // Tabs which are added or removed before the selected tab keep the same tab
+        // selected, which might not be the one at the same index.
+        widgets::Tabs::set_selected(&mut element, self.selected);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<Self::Element>,
+    ) {
+        let mut splice = TabsSplice::new(element);
+        self.sequence.seq_teardown(view_state, ctx, &mut splice);
+        debug_assert!(splice.scratch.into_inner().is_empty());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        if !id_path.is_empty() {
+            return self
+                .sequence
+                .seq_message(view_state, id_path, message, app_state);
+        }
+        match message.downcast::<masonry::core::Action>() {
+            Ok(action) => {
+                if let masonry::core::Action::TabSelected(index) = *action {
+                    MessageResult::Action((self.on_select)(app_state, index))
+                } else {
+                    tracing::error!("Wrong action type in Tabs::message: {action:?}");
+                    MessageResult::Stale(action)
+                }
+            }
+            Err(message) => {
+                tracing::error!("Wrong message type in Tabs::message: {message:?}");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
+
+// --- MARK: Tab ---
+
+/// A tab in [`tabs`], with a `label` and the view of its `content`.
+pub fn tab<V, State, Action>(label: impl Into<ArcStr>, content: V) -> Tab<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    Tab {
+        label: label.into(),
+        content,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`tab`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Tab<V, State, Action> {
+    label: ArcStr,
+    content: V,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for Tab<V, State, Action> {}
+impl<V, State, Action> View<State, Action, ViewCtx> for Tab<V, State, Action>
+where
+    State: 'static,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = TabsElement;
+    type ViewState = V::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (content, state) = self.content.build(ctx);
+        let element = TabsElement {
+            label: self.label.clone(),
+            content: content.erased(),
+        };
+        (element, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.label != prev.label {
+            widgets::Tabs::set_label(&mut element.parent, element.idx, self.label.clone());
+        }
+        let mut panel = widgets::Tabs::panel_mut(&mut element.parent, element.idx);
+        let mut content = TabPanel::child_mut(&mut panel);
+        self.content
+            .rebuild(&prev.content, view_state, ctx, content.downcast());
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let mut panel = widgets::Tabs::panel_mut(&mut element.parent, element.idx);
+        let mut content = TabPanel::child_mut(&mut panel);
+        self.content.teardown(view_state, ctx, content.downcast());
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.content
+            .message(view_state, id_path, message, app_state)
+    }
+}
+
+// --- MARK: TabsElement ---
+
+/// A struct implementing [`ViewElement`] for a tab in [`Tabs`].
+pub struct TabsElement {
+    label: ArcStr,
+    content: Pod<dyn Widget>,
+}
+
+/// A mutable version of `TabsElement`.
+pub struct TabsElementMut<'w> {
+    parent: WidgetMut<'w, widgets::Tabs>,
+    idx: usize,
+}
+
+impl ViewElement for TabsElement {
+    type Mut<'a> = TabsElementMut<'a>;
+}
+
+impl SuperElement<Self, ViewCtx> for TabsElement {
+    fn upcast(_ctx: &mut ViewCtx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        mut this: Mut<Self>,
+        f: impl FnOnce(Mut<Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let r = {
+            let parent = this.parent.reborrow_mut();
+            let reborrow = TabsElementMut {
+                idx: this.idx,
+                parent,
+            };
+            f(reborrow)
+        };
+        (this, r)
+    }
+}
+
+// --- MARK: Sequence ---
+
+/// A trait implementing `ViewSequence` for `TabsElement`.
+pub trait TabsSequence<State, Action = ()>:
+    ViewSequence<State, Action, ViewCtx, TabsElement>
+{
+}
+
+impl<Seq, State, Action> TabsSequence<State, Action> for Seq where
+    Seq: ViewSequence<State, Action, ViewCtx, TabsElement>
+{
+}
+
+// --- MARK: Splice ---
+
+/// An implementation of [`ElementSplice`] for `TabsElement`.
+pub struct TabsSplice<'w> {
+    idx: usize,
+    element: WidgetMut<'w, widgets::Tabs>,
+    scratch: AppendVec<TabsElement>,
+}
+
+impl<'w> TabsSplice<'w> {
+    fn new(element: WidgetMut<'w, widgets::Tabs>) -> Self {
+        Self {
+            idx: 0,
+            element,
+            scratch: AppendVec::default(),
+        }
+    }
+
+    fn insert_tab(&mut self, element: TabsElement) {
+        widgets::Tabs::insert_tab_pod(
+            &mut self.element,
+            self.idx,
+            WidgetPod::new(Label::new(element.label)).erased(),
+            element.content.erased_widget_pod(),
+        );
+        self.idx += 1;
+    }
+}
+
+impl ElementSplice<TabsElement> for TabsSplice<'_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<TabsElement>) -> R) -> R {
+        let mut scratch = std::mem::take(&mut self.scratch);
+        let ret = f(&mut scratch);
+        for element in scratch.drain() {
+            self.insert_tab(element);
+        }
+        ret
+    }
+
+    fn insert(&mut self, element: TabsElement) {
+        self.insert_tab(element);
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<TabsElement>) -> R) -> R {
+        let child = TabsElementMut {
+            parent: self.element.reborrow_mut(),
+            idx: self.idx,
+        };
+        let ret = f(child);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        widgets::Tabs::move_tab(&mut self.element, self.idx + offset, self.idx);
+        true
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<TabsElement>) -> R) -> R {
+        let ret = {
+            let child = TabsElementMut {
+                parent: self.element.reborrow_mut(),
+                idx: self.idx,
+            };
+            f(child)
+        };
+        widgets::Tabs::remove_tab(&mut self.element, self.idx);
+        ret
+    }
+}
@@ -1,7 +1,11 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::widgets;
+use std::time::Duration;
+
+use masonry::core::{ArcStr, SelectionChange};
+use masonry::widgets::{self, TabAction};
+pub use masonry::widgets::{BorderStyle, Direction, ScrollAnchor, TextStats};
 use vello::peniko::Brush;
 
 use crate::core::{DynMessage, Mut, View, ViewMarker};
@@ -12,6 +16,9 @@ use crate::{Color, MessageResult, Pod, TextAlignment, ViewCtx, ViewId};
 // the textbox will always be reset to the initial state. This will be very annoying for the user.
 
 type Callback<State, Action> = Box<dyn Fn(&mut State, String) -> Action + Send + Sync + 'static>;
+type SelectionCallback<State, Action> =
+    Box<dyn Fn(&mut State, SelectionChange) -> Action + Send + Sync + 'static>;
+type ClearCallback<State, Action> = Box<dyn Fn(&mut State) -> Action + Send + Sync + 'static>;
 
 pub fn textbox<F, State, Action>(contents: String, on_changed: F) -> Textbox<State, Action>
 where
@@ -22,8 +29,23 @@ where
         contents,
         on_changed: Box::new(on_changed),
         on_enter: None,
-        text_brush: Color::WHITE.into(),
+        on_selection_changed: None,
+        on_clear: None,
+        text_brush: None,
         alignment: TextAlignment::default(),
+        base_direction: Direction::default(),
+        tab_action: TabAction::default(),
+        scroll_anchor: ScrollAnchor::default(),
+        auto_fit: None,
+        line_height: None,
+        letter_spacing: 0.0,
+        border: Some(BorderStyle::default()),
+        focus_border: Some(BorderStyle::FOCUS_RING),
+        background: None,
+        prefix: None,
+        suffix: None,
+        clear_button: false,
+        debounce: None,
         // TODO?: disabled: false,
     }
 }
@@ -33,15 +55,33 @@ pub struct Textbox<State, Action> {
     contents: String,
     on_changed: Callback<State, Action>,
     on_enter: Option<Callback<State, Action>>,
-    text_brush: Brush,
+    on_selection_changed: Option<SelectionCallback<State, Action>>,
+    on_clear: Option<ClearCallback<State, Action>>,
+    text_brush: Option<Brush>,
     alignment: TextAlignment,
+    base_direction: Direction,
+    tab_action: TabAction,
+    scroll_anchor: ScrollAnchor,
+    auto_fit: Option<(f32, f32)>,
+    line_height: Option<f32>,
+    letter_spacing: f32,
+    border: Option<BorderStyle>,
+    focus_border: Option<BorderStyle>,
+    background: Option<Color>,
+    prefix: Option<ArcStr>,
+    suffix: Option<ArcStr>,
+    clear_button: bool,
+    debounce: Option<Duration>,
     // TODO: add more attributes of `masonry::widgets::TextBox`
 }
 
 impl<State, Action> Textbox<State, Action> {
+    /// Set the brush used to paint the text.
+    ///
+    /// By default, the text is painted with the [theme's text color](masonry::theme::Theme::text_color).
     #[doc(alias = "color")]
     pub fn brush(mut self, color: impl Into<Brush>) -> Self {
-        self.text_brush = color.into();
+        self.text_brush = Some(color.into());
         self
     }
 
@@ -50,6 +90,162 @@ impl<State, Action> Textbox<State, Action> {
         self
     }
 
+    /// Set the base direction of the text, which decides which side start and end alignment
+    /// refer to.
+    ///
+    /// By default, this is the direction of the first strongly directional character.
+    /// See [`masonry::widgets::TextArea::with_base_direction`] for details.
+    pub fn base_direction(mut self, direction: Direction) -> Self {
+        self.base_direction = direction;
+        self
+    }
+
+    /// Set what happens when the Tab key is pressed in this textbox.
+    ///
+    /// By default, Tab moves focus to the next widget.
+    pub fn tab_action(mut self, tab_action: TabAction) -> Self {
+        self.tab_action = tab_action;
+        self
+    }
+
+    /// Set what is scrolled into view after the text is edited, when this textbox is
+    /// inside a [`portal`](crate::view::portal).
+    ///
+    /// By default, the caret is revealed.
+    pub fn scroll_anchor(mut self, scroll_anchor: ScrollAnchor) -> Self {
+        self.scroll_anchor = scroll_anchor;
+        self
+    }
+
+    /// Shrink the font size so that the text fits on one line, between `min_size` and `max_size`.
+    ///
+    /// This disables word wrapping.
+    /// See [`masonry::widgets::TextArea::with_auto_fit`] for details.
+    pub fn auto_fit(mut self, min_size: f32, max_size: f32) -> Self {
+        self.auto_fit = Some((min_size, max_size));
+        self
+    }
+
+    /// Set the height of each line of text, as a multiple of the font size.
+    ///
+    /// By default, lines are 1.2 times the font size.
+    /// See [`masonry::widgets::TextArea::with_line_height`] for details.
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = Some(line_height);
+        self
+    }
+
+    /// Set the extra space added after each character, in logical pixels.
+    ///
+    /// Positive values spread the text out, and negative values pull it together.
+    /// By default, this is 0.
+    /// See [`masonry::widgets::TextArea::with_letter_spacing`] for details.
+    #[doc(alias = "tracking")]
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Set the outline drawn around the text, or `None` for no outline.
+    ///
+    /// This defaults to a 1 logical pixel white rectangle.
+    pub fn border(mut self, border: impl Into<Option<BorderStyle>>) -> Self {
+        self.border = border.into();
+        self
+    }
+
+    /// Set the outline drawn instead of the normal one whilst the textbox is focused.
+    ///
+    /// This defaults to [`BorderStyle::FOCUS_RING`].
+    /// If this is `None`, the normal outline is also drawn whilst focused.
+    pub fn focus_border(mut self, focus_border: impl Into<Option<BorderStyle>>) -> Self {
+        self.focus_border = focus_border.into();
+        self
+    }
+
+    /// Set the color filled in behind the text.
+    pub fn background(mut self, background: impl Into<Option<Color>>) -> Self {
+        self.background = background.into();
+        self
+    }
+
+    /// Show fixed text before the editable text, such as a currency symbol.
+    ///
+    /// See [`masonry::widgets::Textbox::with_prefix`] for details.
+    pub fn prefix(mut self, prefix: impl Into<ArcStr>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Show fixed text after the editable text, such as a unit.
+    ///
+    /// See [`masonry::widgets::Textbox::with_suffix`] for details.
+    pub fn suffix(mut self, suffix: impl Into<ArcStr>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Show a button after the text which clears it, whilst there is any text.
+    ///
+    /// Clearing the text calls `on_changed` with the empty string, like any other edit,
+    /// as well as the callback set with [`on_clear`](Self::on_clear).
+    pub fn clear_button(mut self, clear_button: bool) -> Self {
+        self.clear_button = clear_button;
+        self
+    }
+
+    /// Only call `on_changed` once the user has stopped typing for `debounce`.
+    ///
+    /// The text shown in the textbox still updates live; only the callback is debounced,
+    /// and it is passed the latest text.
+    /// Any pending change is delivered straight away when the textbox loses focus, and
+    /// before `on_enter` is called, so no edit is dropped.
+    ///
+    /// Whilst a change is pending, `contents` isn't applied to the textbox, as it is
+    /// expected to lag behind what the user has typed.
+    pub fn debounce(mut self, debounce: impl Into<Option<Duration>>) -> Self {
+        self.debounce = debounce.into();
+        self
+    }
+
+    /// Replace the callback for text changes with one which is also passed counts of the
+    /// new text, such as for a live character count.
+    ///
+    /// The counts are only worked out for changes whilst this callback is set.
+    /// See [`TextStats`] for how each count is defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xilem::view::{TextStats, textbox};
+    /// # use xilem::WidgetView;
+    ///
+    /// struct Composer {
+    ///     draft: String,
+    ///     remaining: usize,
+    /// }
+    ///
+    /// # fn view(composer: &mut Composer) -> impl WidgetView<Composer> + use<> {
+    /// textbox(composer.draft.clone(), |composer: &mut Composer, text| {
+    ///     composer.draft = text;
+    /// })
+    /// .on_changed_with_stats(|composer: &mut Composer, text, stats: TextStats| {
+    ///     composer.draft = text;
+    ///     composer.remaining = 280_usize.saturating_sub(stats.graphemes);
+    /// })
+    /// # }
+    /// ```
+    pub fn on_changed_with_stats<F>(mut self, on_changed: F) -> Self
+    where
+        F: Fn(&mut State, String, TextStats) -> Action + Send + Sync + 'static,
+    {
+        self.on_changed = Box::new(move |state, text| {
+            let stats = TextStats::new(&text);
+            on_changed(state, text, stats)
+        });
+        self
+    }
+
     pub fn on_enter<F>(mut self, on_enter: F) -> Self
     where
         F: Fn(&mut State, String) -> Action + Send + Sync + 'static,
@@ -57,6 +253,34 @@ impl<State, Action> Textbox<State, Action> {
         self.on_enter = Some(Box::new(on_enter));
         self
     }
+
+    /// Set a callback which is called when the selection or caret moves.
+    ///
+    /// This is separate from the callback for text changes, although editing the text will
+    /// usually also move the caret.
+    /// Whilst the user is dragging out a selection, this is only called once they release
+    /// the pointer.
+    pub fn on_selection_changed<F>(mut self, on_selection_changed: F) -> Self
+    where
+        F: Fn(&mut State, SelectionChange) -> Action + Send + Sync + 'static,
+    {
+        self.on_selection_changed = Some(Box::new(on_selection_changed));
+        self
+    }
+
+    /// Set a callback which is called when the user clears the text with the
+    /// [clear button](Self::clear_button).
+    ///
+    /// This is called as well as `on_changed`, so it is only needed for things which only
+    /// apply to clearing, such as resetting search results without waiting for a
+    /// [debounced](Self::debounce) change.
+    pub fn on_clear<F>(mut self, on_clear: F) -> Self
+    where
+        F: Fn(&mut State) -> Action + Send + Sync + 'static,
+    {
+        self.on_clear = Some(Box::new(on_clear));
+        self
+    }
 }
 
 impl<State, Action> ViewMarker for Textbox<State, Action> {}
@@ -66,10 +290,35 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for Textbox<S
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
         // TODO: Maybe we want a shared TextArea View?
-        let text_area = widgets::TextArea::new_editable(&self.contents)
-            .with_brush(self.text_brush.clone())
-            .with_alignment(self.alignment);
-        let textbox = widgets::Textbox::from_text_area(text_area);
+        let mut text_area = widgets::TextArea::new_editable(&self.contents)
+            .with_alignment(self.alignment)
+            .with_base_direction(self.base_direction)
+            .with_tab_action(self.tab_action)
+            .with_scroll_anchor(self.scroll_anchor)
+            .with_change_debounce(self.debounce);
+        if self.line_height.is_some() {
+            text_area = text_area.with_line_height(self.line_height);
+        }
+        if self.letter_spacing != 0.0 {
+            text_area = text_area.with_letter_spacing(self.letter_spacing);
+        }
+        if let Some((min_size, max_size)) = self.auto_fit {
+            text_area = text_area.with_auto_fit(min_size, max_size);
+        }
+        if let Some(brush) = &self.text_brush {
+            text_area = text_area.with_brush(brush.clone());
+        }
+        let mut textbox = widgets::Textbox::from_text_area(text_area)
+            .with_border(self.border)
+            .with_focus_border(self.focus_border)
+            .with_background(self.background);
+        if let Some(prefix) = &self.prefix {
+            textbox = textbox.with_prefix(prefix.clone());
+        }
+        if let Some(suffix) = &self.suffix {
+            textbox = textbox.with_suffix(suffix.clone());
+        }
+        textbox = textbox.with_clear_button(self.clear_button);
 
         // Ensure that the actions from the *inner* TextArea get routed correctly.
         let id = textbox.area_pod().id();
@@ -85,6 +334,25 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for Textbox<S
         _ctx: &mut ViewCtx,
         mut element: Mut<Self::Element>,
     ) {
+        if prev.border != self.border {
+            widgets::Textbox::set_border(&mut element, self.border);
+        }
+        if prev.focus_border != self.focus_border {
+            widgets::Textbox::set_focus_border(&mut element, self.focus_border);
+        }
+        if prev.background != self.background {
+            widgets::Textbox::set_background(&mut element, self.background);
+        }
+        if prev.prefix != self.prefix {
+            widgets::Textbox::set_prefix(&mut element, self.prefix.clone());
+        }
+        if prev.suffix != self.suffix {
+            widgets::Textbox::set_suffix(&mut element, self.suffix.clone());
+        }
+        if prev.clear_button != self.clear_button {
+            widgets::Textbox::set_clear_button(&mut element, self.clear_button);
+        }
+
         let mut text_area = widgets::Textbox::text_mut(&mut element);
 
         // Unlike the other properties, we don't compare to the previous value;
@@ -94,16 +362,41 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for Textbox<S
         // without calling `set_text`.
 
         // This is probably not the right behaviour, but determining what is the right behaviour is hard
-        if text_area.widget.text() != &self.contents {
+        // Whilst a debounced change is pending, the app hasn't seen the latest text yet.
+        if !text_area.widget.has_pending_change() && text_area.widget.text() != &self.contents {
             widgets::TextArea::reset_text(&mut text_area, &self.contents);
         }
 
         if prev.text_brush != self.text_brush {
-            widgets::TextArea::set_brush(&mut text_area, self.text_brush.clone());
+            match &self.text_brush {
+                Some(brush) => widgets::TextArea::set_brush(&mut text_area, brush.clone()),
+                None => widgets::TextArea::clear_brush(&mut text_area),
+            }
         }
         if prev.alignment != self.alignment {
             widgets::TextArea::set_alignment(&mut text_area, self.alignment);
         }
+        if prev.base_direction != self.base_direction {
+            widgets::TextArea::set_base_direction(&mut text_area, self.base_direction);
+        }
+        if prev.tab_action != self.tab_action {
+            widgets::TextArea::set_tab_action(&mut text_area, self.tab_action);
+        }
+        if prev.scroll_anchor != self.scroll_anchor {
+            widgets::TextArea::set_scroll_anchor(&mut text_area, self.scroll_anchor);
+        }
+        if prev.auto_fit != self.auto_fit {
+            widgets::TextArea::set_auto_fit(&mut text_area, self.auto_fit);
+        }
+        if prev.line_height != self.line_height {
+            widgets::TextArea::set_line_height(&mut text_area, self.line_height);
+        }
+        if prev.letter_spacing != self.letter_spacing {
+            widgets::TextArea::set_letter_spacing(&mut text_area, self.letter_spacing);
+        }
+        if prev.debounce != self.debounce {
+            widgets::TextArea::set_change_debounce(&mut text_area, self.debounce);
+        }
     }
 
     fn teardown(&self, _: &mut Self::ViewState, ctx: &mut ViewCtx, element: Mut<Self::Element>) {
@@ -133,6 +426,20 @@ impl<State: 'static, Action: 'static> View<State, Action, ViewCtx> for Textbox<S
                     tracing::error!("Textbox::message: on_enter is not set");
                     MessageResult::Stale(action)
                 }
+                masonry::core::Action::TextSelectionChanged(selection) => {
+                    match &self.on_selection_changed {
+                        Some(on_selection_changed) => {
+                            MessageResult::Action(on_selection_changed(app_state, selection))
+                        }
+                        // Selection changes are always sent, so this isn't an error.
+                        None => MessageResult::Nop,
+                    }
+                }
+                masonry::core::Action::TextCleared => match &self.on_clear {
+                    Some(on_clear) => MessageResult::Action(on_clear(app_state)),
+                    // The text change is also reported to `on_changed`.
+                    None => MessageResult::Nop,
+                },
                 _ => {
                     tracing::error!("Wrong action type in Textbox::message: {action:?}");
                     MessageResult::Stale(action)
@@ -0,0 +1,113 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+pub use masonry::theme::Theme;
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, ViewId, WidgetView};
+
+/// A view which styles the widget created by `child`, and all of its descendants,
+/// using `theme` instead of the theme of its parent.
+///
+/// Changing `theme` restyles the existing widgets, rather than creating new ones,
+/// so e.g. text being edited keeps its selection.
+/// To change the theme of the whole window, this can wrap the root view.
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{Theme, checkbox, theme};
+/// # use xilem::WidgetView;
+///
+/// struct AppState {
+///     dark_mode: bool,
+/// }
+///
+/// # fn view(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+/// theme(
+///     if state.dark_mode { Theme::dark() } else { Theme::light() },
+///     checkbox("Dark mode", state.dark_mode, |state: &mut AppState, checked| {
+///         state.dark_mode = checked;
+///     }),
+/// )
+/// # }
+/// ```
+pub fn theme<Child, State, Action>(
+    theme: impl Into<Arc<Theme>>,
+    child: Child,
+) -> Themed<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Themed {
+        child,
+        theme: theme.into(),
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`theme`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Themed<V, State, Action> {
+    child: V,
+    theme: Arc<Theme>,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> ViewMarker for Themed<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Themed<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Themed<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let pod = ctx.new_pod(widgets::Themed::from_pod(
+            child.into_widget_pod(),
+            self.theme.clone(),
+        ));
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.theme != prev.theme {
+            widgets::Themed::set_theme(&mut element, self.theme.clone());
+        }
+        let child = widgets::Themed::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::Themed::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::{self, MissedTickBehavior};
+
+use crate::ViewCtx;
+use crate::core::{
+    DynMessage, MessageProxy, MessageResult, Mut, NoElement, View, ViewId, ViewMarker,
+    ViewPathTracker,
+};
+
+/// A view which calls `on_tick` every `period`, until it is no longer in the tree.
+///
+/// The first call happens one `period` after the view is built.
+/// If the app is too busy to handle a tick on time, the following ticks are delayed,
+/// rather than being called in a burst to catch up.
+///
+/// If `period` changes when the view is rebuilt, the interval starts again with the new period.
+///
+/// This has no element, so is generally used alongside another view with
+/// [`fork`](crate::core::fork).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use xilem::core::fork;
+/// use xilem::view::{interval, label};
+/// # use xilem::WidgetView;
+///
+/// struct Clock {
+///     seconds: u32,
+/// }
+///
+/// # fn view(clock: &mut Clock) -> impl WidgetView<Clock> + use<> {
+/// fork(
+///     label(format!("{} seconds", clock.seconds)),
+///     interval(Duration::from_secs(1), |clock: &mut Clock| clock.seconds += 1),
+/// )
+/// # }
+/// ```
+pub fn interval<State, Action, F>(period: Duration, on_tick: F) -> Timer<F>
+where
+    F: Fn(&mut State) -> Action + 'static,
+{
+    Timer {
+        duration: period,
+        repeating: true,
+        callback: on_tick,
+    }
+}
+
+/// A view which calls `on_timeout` once, `delay` after it is added to the tree.
+///
+/// If the view is removed from the tree before then, `on_timeout` isn't called.
+/// If `delay` changes when the view is rebuilt before the timeout, it starts
+/// again with the new delay.
+///
+/// This has no element, so is generally used alongside another view with
+/// [`fork`](crate::core::fork).
+pub fn timeout<State, Action, F>(delay: Duration, on_timeout: F) -> Timer<F>
+where
+    F: Fn(&mut State) -> Action + 'static,
+{
+    Timer {
+        duration: delay,
+        repeating: false,
+        callback: on_timeout,
+    }
+}
+
+/// The [`View`] created by [`interval`] or [`timeout`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Timer<F> {
+    duration: Duration,
+    repeating: bool,
+    callback: F,
+}
+
+/// The message sent to a [`Timer`] each time it fires.
+#[derive(Debug)]
+struct TimerFired;
+
+impl<F> Timer<F> {
+    /// Start the timer, sending [`TimerFired`] to this view when it fires.
+    fn spawn(&self, ctx: &mut ViewCtx) -> JoinHandle<()> {
+        let path: Arc<[ViewId]> = ctx.view_path().into();
+        let proxy = MessageProxy::new(ctx.proxy.clone(), path);
+        let duration = self.duration;
+        let repeating = self.repeating;
+        ctx.runtime().spawn(async move {
+            if !repeating {
+                time::sleep(duration).await;
+                drop(proxy.message(TimerFired));
+                return;
+            }
+            let mut interval = time::interval_at(time::Instant::now() + duration, duration);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                if proxy.message(TimerFired).is_err() {
+                    // The app has closed.
+                    break;
+                }
+            }
+        })
+    }
+}
+
+impl<F> ViewMarker for Timer<F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for Timer<F>
+where
+    F: Fn(&mut State) -> Action + 'static,
+{
+    type Element = NoElement;
+
+    type ViewState = JoinHandle<()>;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        (NoElement, self.spawn(ctx))
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        join_handle: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        (): Mut<Self::Element>,
+    ) {
+        if self.duration != prev.duration || self.repeating != prev.repeating {
+            join_handle.abort();
+            *join_handle = self.spawn(ctx);
+        }
+    }
+
+    fn teardown(&self, join_handle: &mut Self::ViewState, _: &mut ViewCtx, _: Mut<Self::Element>) {
+        join_handle.abort();
+    }
+
+    fn message(
+        &self,
+        _: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            id_path.is_empty(),
+            "id path should be empty in Timer::message"
+        );
+        match message.downcast::<TimerFired>() {
+            Ok(_) => MessageResult::Action((self.callback)(app_state)),
+            Err(message) => {
+                tracing::error!("Wrong message type in Timer::message: {message:?}");
+                MessageResult::Stale(message)
+            }
+        }
+    }
+}
+
+// --- MARK: TESTS ---
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run the tasks of `ctx` whilst its paused clock moves forward by `duration`.
+    fn advance(ctx: &ViewCtx, duration: Duration) {
+        ctx.runtime().block_on(time::sleep(duration));
+    }
+
+    #[test]
+    fn interval_ticks_until_teardown() {
+        let view = interval(Duration::from_secs(1), |ticks: &mut u32| *ticks += 1);
+        let (mut ctx, messages) = ViewCtx::for_test_with_paused_clock();
+        let (_, mut join_handle) = view.build(&mut ctx);
+
+        advance(&ctx, Duration::from_millis(3500));
+        let mut ticks = 0;
+        for (id_path, message) in messages.try_iter() {
+            let result = view.message(&mut join_handle, &id_path, message, &mut ticks);
+            assert!(matches!(result, MessageResult::Action(())));
+        }
+        assert_eq!(ticks, 3);
+
+        view.teardown(&mut join_handle, &mut ctx, ());
+        advance(&ctx, Duration::from_secs(5));
+        assert_eq!(
+            messages.try_iter().count(),
+            0,
+            "the interval stops on teardown"
+        );
+    }
+
+    #[test]
+    fn timeout_fires_once() {
+        let view = timeout(Duration::from_secs(1), |fired: &mut u32| *fired += 1);
+        let (mut ctx, messages) = ViewCtx::for_test_with_paused_clock();
+        let (_, _join_handle) = view.build(&mut ctx);
+
+        advance(&ctx, Duration::from_millis(500));
+        assert_eq!(messages.try_iter().count(), 0);
+        advance(&ctx, Duration::from_secs(5));
+        assert_eq!(messages.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn timeout_cancelled_by_teardown() {
+        let view = timeout(Duration::from_secs(1), |fired: &mut u32| *fired += 1);
+        let (mut ctx, messages) = ViewCtx::for_test_with_paused_clock();
+        let (_, mut join_handle) = view.build(&mut ctx);
+
+        advance(&ctx, Duration::from_millis(500));
+        view.teardown(&mut join_handle, &mut ctx, ());
+        advance(&ctx, Duration::from_secs(5));
+        assert_eq!(messages.try_iter().count(), 0);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use masonry::core::ArcStr;
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewId, ViewMarker};
+use crate::{MessageResult, Pod, ViewCtx, WidgetView};
+
+/// A view which shows `text` in a tooltip when the pointer rests on the widget created by `child`.
+///
+/// This can also be created using [`WidgetView::tooltip`].
+///
+/// # Examples
+///
+/// ```
+/// use xilem::view::{button, tooltip};
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+/// tooltip(button("Save", |_| {}), "Save the current file")
+/// # }
+/// ```
+pub fn tooltip<Child, State, Action>(
+    child: Child,
+    text: impl Into<ArcStr>,
+) -> Tooltip<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    Tooltip {
+        child,
+        text: text.into(),
+        delay: widgets::DEFAULT_TOOLTIP_DELAY,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`tooltip`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Tooltip<V, State, Action> {
+    child: V,
+    text: ArcStr,
+    delay: Duration,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> Tooltip<V, State, Action> {
+    /// Set how long the pointer must rest on the child before the tooltip is shown.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl<V, State, Action> ViewMarker for Tooltip<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for Tooltip<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Tooltip<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let widget = widgets::Tooltip::from_pod(child.into_widget_pod(), self.text.clone())
+            .with_delay(self.delay);
+        (ctx.new_pod(widget), child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.text != prev.text {
+            widgets::Tooltip::set_text(&mut element, self.text.clone());
+        }
+        if self.delay != prev.delay {
+            widgets::Tooltip::set_delay(&mut element, self.delay);
+        }
+        let child = widgets::Tooltip::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::Tooltip::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
@@ -4,6 +4,7 @@
 use std::marker::PhantomData;
 
 use crate::core::{DynMessage, View, ViewMarker};
+use crate::view::{TransformedBox, transformed_box};
 use crate::{Affine, Pod, ViewCtx, WidgetView};
 
 /// A view which transforms the widget created by child.
@@ -17,6 +18,11 @@ use crate::{Affine, Pod, ViewCtx, WidgetView};
 /// Transformations apply in order.
 /// That is, calling [`rotate`](Transformed::rotate) then [`translate`](Transformed::translate)
 /// will move the rotated widget.
+///
+/// Unlike every other way of transforming a view in Xilem, these transforms are applied about
+/// the top-left corner of the widget, as they set the transform of the child's widget directly.
+/// The [`transform`], [`rotate`] and [`scale`] functions and the equivalent [`WidgetView`]
+/// methods instead use a [`transformed_box`], which applies them about the widget's center.
 pub fn transformed<Child, State, Action>(child: Child) -> Transformed<Child, State, Action>
 where
     Child: WidgetView<State, Action>,
@@ -28,6 +34,52 @@ where
     }
 }
 
+/// A view which applies `transform` to the widget created by `child`, about its center.
+///
+/// This is shorthand for `transformed_box(child).transform(transform)`, and is the same as
+/// [`WidgetView::transform`]; see [`transformed_box`] for details.
+///
+/// The child is laid out as if it weren't transformed, and the transform
+/// is then applied when it is painted and hit-tested.
+/// Pointer events the child receives are in its local (untransformed) coordinates.
+pub fn transform<Child, State, Action>(
+    child: Child,
+    transform: Affine,
+) -> TransformedBox<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    transformed_box(child).transform(transform)
+}
+
+/// A view which rotates the widget created by `child` by `radians` about its center.
+///
+/// This is shorthand for `transformed_box(child).rotate(radians)`, and is the same as
+/// [`WidgetView::rotate`]; see [`transformed_box`] for details.
+pub fn rotate<Child, State, Action>(
+    child: Child,
+    radians: f64,
+) -> TransformedBox<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    transformed_box(child).rotate(radians)
+}
+
+/// A view which scales the widget created by `child` by `factor` about its center.
+///
+/// This is shorthand for `transformed_box(child).scale(factor)`, and is the same as
+/// [`WidgetView::scale`]; see [`transformed_box`] for details.
+pub fn scale<Child, State, Action>(
+    child: Child,
+    factor: f64,
+) -> TransformedBox<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    transformed_box(child).scale(factor)
+}
+
 /// The view for [`transformed`].
 pub struct Transformed<V, State, Action> {
     child: V,
@@ -37,13 +89,14 @@ pub struct Transformed<V, State, Action> {
 
 impl<V, State, Action> Transformed<V, State, Action> {
     #[must_use]
-    /// Rotate the widget by `radians` radians about the origin of its natural location.
+    /// Rotate the widget by `radians` radians about the origin (top-left corner) of its
+    /// natural location.
     pub fn rotate(mut self, radians: f64) -> Self {
         self.transform = self.transform.then_rotate(radians);
         self
     }
 
-    /// Scale the widget by `uniform` in each axis.
+    /// Scale the widget by `uniform` in each axis, about the origin of its natural location.
     #[must_use]
     pub fn scale(mut self, uniform: f64) -> Self {
         self.transform = self.transform.then_scale(uniform);
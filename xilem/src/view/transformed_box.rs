@@ -0,0 +1,149 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, Mut, View, ViewMarker};
+use crate::{Affine, MessageResult, Pod, Vec2, ViewCtx, ViewId, WidgetView};
+
+/// A view which transforms the widget created by `child` about its center.
+///
+/// Unlike [`transformed`](crate::view::transformed), this wraps the child in a
+/// container widget, so it can be nested, and it can be used on views which
+/// are already transformed (the inner transform is replaced).
+/// The transform can be set using the methods on the return type, which apply in order.
+///
+/// By default, the container takes up the child's untransformed size, so transforming
+/// the child doesn't move the widgets around it; use
+/// [`transformed_bounds`](TransformedBox::transformed_bounds) to change this.
+///
+/// This can also be created using [`WidgetView::transform`], [`WidgetView::rotate`] and
+/// [`WidgetView::scale`], or the [`transform`](crate::view::transform),
+/// [`rotate`](crate::view::rotate) and [`scale`](crate::view::scale) functions.
+/// All of these transform the widget about its center.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::FRAC_PI_4;
+///
+/// use xilem::view::{label, transformed_box};
+/// # use xilem::WidgetView;
+///
+/// # fn view<State: 'static>() -> impl WidgetView<State> + use<State> {
+/// transformed_box(label("↑")).rotate(FRAC_PI_4).scale(1.5)
+/// # }
+/// ```
+pub fn transformed_box<Child, State, Action>(child: Child) -> TransformedBox<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+{
+    TransformedBox {
+        child,
+        transform: Affine::IDENTITY,
+        transformed_bounds: false,
+        phantom: PhantomData,
+    }
+}
+
+/// The view for [`transformed_box`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct TransformedBox<V, State, Action> {
+    child: V,
+    transform: Affine,
+    transformed_bounds: bool,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+impl<V, State, Action> TransformedBox<V, State, Action> {
+    /// Rotate the widget by `radians` radians about its center.
+    pub fn rotate(mut self, radians: f64) -> Self {
+        self.transform = self.transform.then_rotate(radians);
+        self
+    }
+
+    /// Scale the widget by `factor` about its center.
+    pub fn scale(mut self, factor: f64) -> Self {
+        self.transform = self.transform.then_scale(factor);
+        self
+    }
+
+    /// Displace the widget by `v` from its natural location.
+    pub fn translate(mut self, v: impl Into<Vec2>) -> Self {
+        self.transform = self.transform.then_translate(v.into());
+        self
+    }
+
+    /// Apply an arbitrary 2d transform to the widget, about its center.
+    pub fn transform(mut self, transform: Affine) -> Self {
+        self.transform = transform * self.transform;
+        self
+    }
+
+    /// Whether the container takes up the size of the bounding box of the transformed
+    /// widget, rather than the widget's untransformed size.
+    pub fn transformed_bounds(mut self, transformed_bounds: bool) -> Self {
+        self.transformed_bounds = transformed_bounds;
+        self
+    }
+}
+
+impl<V, State, Action> ViewMarker for TransformedBox<V, State, Action> {}
+impl<Child, State, Action> View<State, Action, ViewCtx> for TransformedBox<Child, State, Action>
+where
+    Child: WidgetView<State, Action>,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::Transformed<Child::Widget>>;
+    type ViewState = Child::ViewState;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = self.child.build(ctx);
+        let pod = ctx.new_pod(
+            widgets::Transformed::from_pod(child.into_widget_pod())
+                .transform(self.transform)
+                .with_transformed_bounds(self.transformed_bounds),
+        );
+        (pod, child_state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.transform != prev.transform {
+            widgets::Transformed::set_transform(&mut element, self.transform);
+        }
+        if self.transformed_bounds != prev.transformed_bounds {
+            widgets::Transformed::set_transformed_bounds(&mut element, self.transformed_bounds);
+        }
+        let child = widgets::Transformed::child_mut(&mut element);
+        self.child.rebuild(&prev.child, view_state, ctx, child);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        let child = widgets::Transformed::child_mut(&mut element);
+        self.child.teardown(view_state, ctx, child);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        self.child.message(view_state, id_path, message, app_state)
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{DynMessage, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A vertically scrolling list of `row_count` rows, each `row_height` tall, which only
+/// creates views for the rows in or near its viewport.
+///
+/// `row` is called with the index of each row which needs a view, both when it scrolls
+/// into view and whenever the list is rebuilt, so that rows are kept up to date.
+/// As it isn't given the app's state, it should capture whatever it needs, such as an
+/// [`Arc`](std::sync::Arc) of the items being shown.
+///
+/// This allows lists of many thousands of items, which would be too slow to build with
+/// a [`flex`](crate::view::flex) of a view for each item. Messages from the rows' views
+/// reach their callbacks as normal, so a row's callbacks can use its index.
+///
+/// The list takes up all of the available height, so should be given a finite height,
+/// e.g. by putting it in a [`sized_box`](crate::view::sized_box) or giving it flex.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use xilem::view::{button, virtual_list};
+/// # use xilem::WidgetView;
+///
+/// struct Inbox {
+///     subjects: Arc<Vec<String>>,
+///     opened: Option<usize>,
+/// }
+///
+/// # fn view(inbox: &mut Inbox) -> impl WidgetView<Inbox> + use<> {
+/// let subjects = inbox.subjects.clone();
+/// virtual_list(inbox.subjects.len(), 30., move |index| {
+///     button(subjects[index].clone(), move |inbox: &mut Inbox| {
+///         inbox.opened = Some(index);
+///     })
+/// })
+/// # }
+/// ```
+pub fn virtual_list<State, Action, V, F>(
+    row_count: usize,
+    row_height: f64,
+    row: F,
+) -> VirtualList<F, V, State, Action>
+where
+    V: WidgetView<State, Action>,
+    F: Fn(usize) -> V + Send + Sync + 'static,
+{
+    VirtualList {
+        row,
+        row_count,
+        row_height,
+        overscan: 2,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`virtual_list`].
+///
+/// See `virtual_list` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct VirtualList<F, V, State, Action = ()> {
+    row: F,
+    row_count: usize,
+    row_height: f64,
+    overscan: usize,
+    phantom: PhantomData<fn() -> (V, State, Action)>,
+}
+
+impl<F, V, State, Action> VirtualList<F, V, State, Action> {
+    /// Set how many rows either side of the viewport have views.
+    ///
+    /// These rows can be scrolled into view before the list is rebuilt.
+    /// The default is 2.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+/// A row which has a view, and so a widget.
+struct Row<V, ViewState> {
+    view: V,
+    view_state: ViewState,
+    /// Distinguishes this row from earlier rows at the same index, so that messages
+    /// to those rows aren't routed to this one.
+    generation: u32,
+}
+
+/// The state used to implement `View` for [`VirtualList`].
+#[doc(hidden)] // Implementation detail, public because of trait visibility rules
+pub struct VirtualListState<V, ViewState> {
+    rows: BTreeMap<usize, Row<V, ViewState>>,
+    next_generation: u32,
+}
+
+/// Turns the index of a row and its generation into a packed id.
+fn row_view_id(index: usize, generation: u32) -> ViewId {
+    let index: u32 = index
+        .try_into()
+        .expect("Rows in a virtual list must be indexable by u32");
+    ViewId::new((u64::from(generation) << 32) | u64::from(index))
+}
+
+/// Undoes [`row_view_id`].
+fn row_index_generation(view_id: ViewId) -> (usize, u32) {
+    #![allow(clippy::cast_possible_truncation)]
+    let view_id = view_id.routing_id();
+    (view_id as u32 as usize, (view_id >> 32) as u32)
+}
+
+impl<F, V, State, Action> ViewMarker for VirtualList<F, V, State, Action> {}
+impl<F, V, State, Action> View<State, Action, ViewCtx> for VirtualList<F, V, State, Action>
+where
+    V: WidgetView<State, Action>,
+    F: Fn(usize) -> V + Send + Sync + 'static,
+    State: 'static,
+    Action: 'static,
+{
+    type Element = Pod<widgets::VirtualList>;
+    type ViewState = VirtualListState<V, V::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
+        // The widget requests the rows it needs during its first layout, which
+        // leads to a rebuild in which they are added.
+        let widget =
+            widgets::VirtualList::new(self.row_count, self.row_height).with_overscan(self.overscan);
+        let pod = ctx.with_action_widget(|ctx| ctx.new_pod(widget));
+        let state = VirtualListState {
+            rows: BTreeMap::new(),
+            next_generation: 0,
+        };
+        (pod, state)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        if self.row_count != prev.row_count {
+            widgets::VirtualList::set_row_count(&mut element, self.row_count);
+        }
+        if self.row_height != prev.row_height {
+            widgets::VirtualList::set_row_height(&mut element, self.row_height);
+        }
+        if self.overscan != prev.overscan {
+            widgets::VirtualList::set_overscan(&mut element, self.overscan);
+        }
+
+        let needed = element.widget.needed_rows();
+        let needed = needed.start.min(self.row_count)..needed.end.min(self.row_count);
+        // Remove the rows which are no longer needed.
+        let removed = view_state
+            .rows
+            .keys()
+            .copied()
+            .filter(|index| !needed.contains(index))
+            .collect::<Vec<_>>();
+        for index in removed {
+            let mut row = view_state.rows.remove(&index).unwrap();
+            ctx.with_id(row_view_id(index, row.generation), |ctx| {
+                let mut child = widgets::VirtualList::row_mut(&mut element, index)
+                    .expect("Each row with a view has a widget");
+                row.view
+                    .teardown(&mut row.view_state, ctx, child.downcast());
+            });
+            widgets::VirtualList::remove_row(&mut element, index);
+        }
+
+        for index in needed {
+            let view = (self.row)(index);
+            match view_state.rows.get_mut(&index) {
+                Some(row) => {
+                    ctx.with_id(row_view_id(index, row.generation), |ctx| {
+                        let mut child = widgets::VirtualList::row_mut(&mut element, index)
+                            .expect("Each row with a view has a widget");
+                        view.rebuild(&row.view, &mut row.view_state, ctx, child.downcast());
+                    });
+                    row.view = view;
+                }
+                None => {
+                    let generation = view_state.next_generation;
+                    view_state.next_generation = generation.wrapping_add(1);
+                    let (child, child_state) =
+                        ctx.with_id(row_view_id(index, generation), |ctx| view.build(ctx));
+                    widgets::VirtualList::insert_row_pod(
+                        &mut element,
+                        index,
+                        child.erased_widget_pod(),
+                    );
+                    view_state.rows.insert(
+                        index,
+                        Row {
+                            view,
+                            view_state: child_state,
+                            generation,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<Self::Element>,
+    ) {
+        for (&index, row) in &mut view_state.rows {
+            ctx.with_id(row_view_id(index, row.generation), |ctx| {
+                let mut child = widgets::VirtualList::row_mut(&mut element, index)
+                    .expect("Each row with a view has a widget");
+                row.view
+                    .teardown(&mut row.view_state, ctx, child.downcast());
+            });
+        }
+        ctx.teardown_leaf(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: DynMessage,
+        app_state: &mut State,
+    ) -> MessageResult<Action> {
+        let Some((first, rest)) = id_path.split_first() else {
+            return match message.downcast::<masonry::core::Action>() {
+                // The rows are added and removed in the rebuild which this requests.
+                Ok(action) => match *action {
+                    masonry::core::Action::VirtualListRange(_) => MessageResult::RequestRebuild,
+                    _ => {
+                        tracing::error!("Wrong action type in VirtualList::message: {action:?}");
+                        MessageResult::Stale(action)
+                    }
+                },
+                Err(message) => {
+                    tracing::error!("Wrong message type in VirtualList::message: {message:?}");
+                    MessageResult::Stale(message)
+                }
+            };
+        };
+        let (index, generation) = row_index_generation(*first);
+        match view_state.rows.get_mut(&index) {
+            Some(row) if row.generation == generation => {
+                row.view
+                    .message(&mut row.view_state, rest, message, app_state)
+            }
+            // The row has since been scrolled out of view.
+            _ => MessageResult::Stale(message),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use std::marker::PhantomData;
 
 use masonry::core::{FromDynWidget, Widget, WidgetMut};
 use masonry::widgets::{
-    Alignment, ChildAlignment, {self},
+    Alignment, ChildAlignment, Transition, ZStackSizing, {self},
 };
 use xilem_core::{MessageResult, ViewId};
 
@@ -15,7 +15,7 @@ use crate::core::{
     AppendVec, DynMessage, ElementSplice, Mut, SuperElement, View, ViewElement, ViewMarker,
     ViewSequence,
 };
-use crate::{Pod, ViewCtx, WidgetView};
+use crate::{Color, Pod, ViewCtx, WidgetView};
 
 /// A widget that lays out its children on top of each other.
 /// The children are laid out back to front.
@@ -39,6 +39,10 @@ pub fn zstack<State, Action, Seq: ZStackSequence<State, Action>>(sequence: Seq)
     ZStack {
         sequence,
         alignment: Alignment::default(),
+        sizing: ZStackSizing::default(),
+        background: None,
+        corner_radius: 0.0,
+        clip: false,
     }
 }
 
@@ -49,6 +53,10 @@ pub fn zstack<State, Action, Seq: ZStackSequence<State, Action>>(sequence: Seq)
 pub struct ZStack<Seq> {
     sequence: Seq,
     alignment: Alignment,
+    sizing: ZStackSizing,
+    background: Option<Color>,
+    corner_radius: f64,
+    clip: bool,
 }
 
 impl<Seq> ZStack<Seq> {
@@ -57,6 +65,40 @@ impl<Seq> ZStack<Seq> {
         self.alignment = alignment.into();
         self
     }
+
+    /// Changes how the size of the stack is determined.
+    ///
+    /// See [`ZStackSizing`] for details.
+    pub fn sizing(mut self, sizing: ZStackSizing) -> Self {
+        self.sizing = sizing;
+        self
+    }
+
+    /// Fills the whole stack with `color`, behind all of its children.
+    ///
+    /// This is useful for using a stack as a card.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Rounds the corners of the [background](Self::background) by `radius`.
+    pub fn corner_radius(mut self, radius: f64) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Whether to clip the children to the bounds of the stack, including its rounded corners.
+    ///
+    /// Parts of children outside the bounds are neither painted nor clickable.
+    /// This is off by default.
+    ///
+    /// This shadows [`WidgetView::clip`](crate::WidgetView::clip), which wraps the stack in
+    /// another view instead.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
 }
 
 impl<Seq> ViewMarker for ZStack<Seq> {}
@@ -72,10 +114,19 @@ where
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
         let mut elements = AppendVec::default();
-        let mut widget = widgets::ZStack::new().with_alignment(self.alignment);
+        let mut widget = widgets::ZStack::new()
+            .with_alignment(self.alignment)
+            .with_sizing(self.sizing)
+            .with_background(self.background)
+            .with_corner_radius(self.corner_radius)
+            .with_clip(self.clip);
         let seq_state = self.sequence.seq_build(ctx, &mut elements);
         for child in elements.into_inner() {
-            widget = widget.with_child_pod(child.widget.erased_widget_pod(), child.alignment);
+            widget = widget.with_transitioned_child_pod(
+                child.widget.erased_widget_pod(),
+                child.alignment,
+                child.transition,
+            );
         }
         let pod = ctx.new_pod(widget);
         (pod, seq_state)
@@ -91,6 +142,18 @@ where
         if self.alignment != prev.alignment {
             widgets::ZStack::set_alignment(&mut element, self.alignment);
         }
+        if self.sizing != prev.sizing {
+            widgets::ZStack::set_sizing(&mut element, self.sizing);
+        }
+        if self.background != prev.background {
+            widgets::ZStack::set_background(&mut element, self.background);
+        }
+        if self.corner_radius != prev.corner_radius {
+            widgets::ZStack::set_corner_radius(&mut element, self.corner_radius);
+        }
+        if self.clip != prev.clip {
+            widgets::ZStack::set_clip(&mut element, self.clip);
+        }
 
         let mut splice = ZStackSplice::new(element);
         self.sequence
@@ -121,6 +184,53 @@ where
     }
 }
 
+// --- MARK: Overlay ---
+
+/// A view which shows `overlay_child` on top of `base`, aligned to the top trailing corner.
+///
+/// The stack takes the size of `base` (see [`ZStackSizing::FirstChild`]), so that the overlay,
+/// such as a badge or a close button, doesn't grow it.
+/// The overlay's alignment can be changed using [`ZStack::overlay_alignment`].
+///
+/// # Example
+///
+/// ```
+/// use masonry::widgets::Alignment;
+/// use xilem::WidgetView;
+/// use xilem::view::{button, label, overlay};
+///
+/// fn view<State: 'static>() -> impl WidgetView<State> {
+///     overlay(button("Inbox", |_| {}), label("3")).overlay_alignment(Alignment::TopLeading)
+/// }
+/// ```
+pub fn overlay<State, Action, Base, Over>(base: Base, overlay_child: Over) -> Overlay<Base, Over>
+where
+    State: 'static,
+    Action: 'static,
+    Base: WidgetView<State, Action>,
+    Over: WidgetView<State, Action>,
+{
+    // Overlays are given the base's size, so the overlay is aligned within a nested stack.
+    zstack((
+        base,
+        zstack(overlay_child).alignment(Alignment::TopTrailing),
+    ))
+    .sizing(ZStackSizing::FirstChild)
+}
+
+/// The view created by [`overlay`].
+///
+/// This is a [`ZStack`] of the base view and a same-sized stack which aligns the overlay view.
+pub type Overlay<Base, Over> = ZStack<(Base, ZStack<Over>)>;
+
+impl<Base, Over> Overlay<Base, Over> {
+    /// Changes where the overlay is placed on top of the base.
+    pub fn overlay_alignment(mut self, alignment: impl Into<Alignment>) -> Self {
+        self.sequence.1.alignment = alignment.into();
+        self
+    }
+}
+
 // --- MARK: ZStackExt ---
 
 /// A trait that extends a [`WidgetView`] with methods to provide parameters for a parent [`ZStack`].
@@ -136,6 +246,38 @@ pub trait ZStackExt<State, Action>: WidgetView<State, Action> {
     {
         zstack_item(self, alignment)
     }
+
+    /// Animates this view using `transition` when it is added to or removed from the parent [`ZStack`].
+    ///
+    /// When the view is removed, its widget stays in the stack until the animation has finished.
+    /// This can only be used on views that are direct children of a [`ZStack`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use masonry::widgets::Transition;
+    /// use xilem::WidgetView;
+    /// use xilem::view::{ZStackExt, label, zstack};
+    ///
+    /// fn view<State: 'static>(show_toast: bool) -> impl WidgetView<State> {
+    ///     zstack((
+    ///         label("Content"),
+    ///         show_toast.then(|| {
+    ///             label("Saved").transition(Transition::fade(Duration::from_millis(200)))
+    ///         }),
+    ///     ))
+    /// }
+    /// ```
+    fn transition(self, transition: Transition) -> ZStackItem<Self, State, Action>
+    where
+        State: 'static,
+        Action: 'static,
+        Self: Sized,
+    {
+        zstack_item(self, ChildAlignment::ParentAligned).transition(transition)
+    }
 }
 
 impl<State, Action, V: WidgetView<State, Action>> ZStackExt<State, Action> for V {}
@@ -145,6 +287,7 @@ impl<State, Action, V: WidgetView<State, Action>> ZStackExt<State, Action> for V
 pub struct ZStackItem<V, State, Action> {
     view: V,
     alignment: ChildAlignment,
+    transition: Transition,
     phantom: PhantomData<fn() -> (State, Action)>,
 }
 
@@ -162,10 +305,21 @@ where
     ZStackItem {
         view,
         alignment: alignment.into(),
+        transition: Transition::NONE,
         phantom: PhantomData,
     }
 }
 
+impl<V, State, Action> ZStackItem<V, State, Action> {
+    /// Animates this item using `transition` when it is added to or removed from the [`ZStack`].
+    ///
+    /// See [`ZStackExt::transition`] for more details.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
 impl<V, State, Action> ViewMarker for ZStackItem<V, State, Action> {}
 
 impl<State, Action, V> View<State, Action, ViewCtx> for ZStackItem<V, State, Action>
@@ -180,7 +334,9 @@ where
 
     fn build(&self, ctx: &mut ViewCtx) -> (Self::Element, Self::ViewState) {
         let (pod, state) = self.view.build(ctx);
-        (ZStackElement::new(pod.erased(), self.alignment), state)
+        let mut element = ZStackElement::new(pod.erased(), self.alignment);
+        element.transition = self.transition;
+        (element, state)
     }
 
     fn rebuild(
@@ -192,12 +348,19 @@ where
     ) {
         {
             if self.alignment != prev.alignment {
-                widgets::ZStack::update_child_alignment(
+                widgets::ZStack::set_child_alignment(
                     &mut element.parent,
                     element.idx,
                     self.alignment,
                 );
             }
+            if self.transition != prev.transition {
+                widgets::ZStack::set_child_transition(
+                    &mut element.parent,
+                    element.idx,
+                    self.transition,
+                );
+            }
             let mut child = widgets::ZStack::child_mut(&mut element.parent, element.idx)
                 .expect("ZStackWrapper always has a widget child");
             self.view
@@ -233,6 +396,7 @@ where
 pub struct ZStackElement {
     widget: Pod<dyn Widget>,
     alignment: ChildAlignment,
+    transition: Transition,
 }
 
 /// A mutable version of `ZStackElement`.
@@ -243,7 +407,11 @@ pub struct ZStackElementMut<'w> {
 
 impl ZStackElement {
     fn new(widget: Pod<dyn Widget>, alignment: ChildAlignment) -> Self {
-        Self { widget, alignment }
+        Self {
+            widget,
+            alignment,
+            transition: Transition::NONE,
+        }
     }
 }
 
@@ -328,10 +496,12 @@ impl ElementSplice<ZStackElement> for ZStackSplice<'_> {
     fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<ZStackElement>) -> R) -> R {
         let ret = f(&mut self.scratch);
         for element in self.scratch.drain() {
-            widgets::ZStack::insert_child_pod(
+            widgets::ZStack::insert_transitioned_child_pod_at(
                 &mut self.element,
+                self.idx,
                 element.widget.erased_widget_pod(),
                 element.alignment,
+                element.transition,
             );
             self.idx += 1;
         }
@@ -339,10 +509,12 @@ impl ElementSplice<ZStackElement> for ZStackSplice<'_> {
     }
 
     fn insert(&mut self, element: ZStackElement) {
-        widgets::ZStack::insert_child_pod(
+        widgets::ZStack::insert_transitioned_child_pod_at(
             &mut self.element,
+            self.idx,
             element.widget.erased_widget_pod(),
             element.alignment,
+            element.transition,
         );
         self.idx += 1;
     }
@@ -361,6 +533,11 @@ impl ElementSplice<ZStackElement> for ZStackSplice<'_> {
         self.idx += n;
     }
 
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        widgets::ZStack::move_child(&mut self.element, self.idx + offset, self.idx);
+        true
+    }
+
     fn delete<R>(&mut self, f: impl FnOnce(Mut<ZStackElement>) -> R) -> R {
         let ret = {
             let child = ZStackElementMut {
@@ -369,7 +546,83 @@ impl ElementSplice<ZStackElement> for ZStackSplice<'_> {
             };
             f(child)
         };
+        // If the child has a transition, the stack keeps it until it has animated out.
         widgets::ZStack::remove_child(&mut self.element, self.idx);
         ret
     }
 }
+
+// MARK: Tests
+
+#[cfg(test)]
+mod tests {
+    use masonry::core::WidgetId;
+    use masonry::testing::TestHarness;
+
+    use super::*;
+    use crate::core::{Keyed, keyed};
+    use crate::view::{Label, label};
+
+    fn stack(items: &[u32]) -> ZStack<Keyed<u32, Label>> {
+        zstack(keyed(
+            items.to_vec(),
+            |item| *item,
+            |item| label(item.to_string()),
+        ))
+    }
+
+    /// The text and id of each label in the stack, back to front.
+    fn labels(harness: &TestHarness) -> Vec<(String, WidgetId)> {
+        harness
+            .root_widget()
+            .children()
+            .iter()
+            .map(|child| {
+                let label = child.downcast::<widgets::Label>().unwrap();
+                (label.text().to_string(), child.id())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keyed_children_are_inserted_and_moved_in_place() {
+        let (mut ctx, _) = ViewCtx::for_test();
+        let first = stack(&[1, 3]);
+        let (pod, mut state) = View::<(), (), ViewCtx>::build(&first, &mut ctx);
+        let mut harness = TestHarness::create(*pod.widget);
+        let initial = labels(&harness);
+
+        // A new key is inserted between the existing children, rather than at the front.
+        let second = stack(&[1, 2, 3]);
+        harness.edit_root_widget(|mut root| {
+            let root = root.downcast::<widgets::ZStack>();
+            View::<(), (), ViewCtx>::rebuild(&second, &first, &mut state, &mut ctx, root);
+        });
+        let inserted = labels(&harness);
+        let texts = |entries: &[(String, WidgetId)]| {
+            entries
+                .iter()
+                .map(|(text, _)| text.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(texts(&inserted), ["1", "2", "3"]);
+        assert_eq!(inserted[0], initial[0]);
+        assert_eq!(inserted[2], initial[1]);
+
+        // Reordering the keys moves the existing widgets.
+        let third = stack(&[3, 1, 2]);
+        harness.edit_root_widget(|mut root| {
+            let root = root.downcast::<widgets::ZStack>();
+            View::<(), (), ViewCtx>::rebuild(&third, &second, &mut state, &mut ctx, root);
+        });
+        let moved = labels(&harness);
+        assert_eq!(
+            moved,
+            [
+                inserted[2].clone(),
+                inserted[0].clone(),
+                inserted[1].clone()
+            ]
+        );
+    }
+}
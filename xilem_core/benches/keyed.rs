@@ -0,0 +1,249 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compares rebuilding a `Vec` sequence with a `keyed` sequence when 1000 items are reordered.
+//!
+//! Run with `cargo bench -p xilem_core --bench keyed`.
+//!
+//! Each element models a widget: building one allocates its state, and rebuilding it with a
+//! different item's text replaces its text, which would e.g. require a label to lay out again.
+//! As well as the time taken, this reports how many elements were built, torn down,
+//! given different text and moved, as these are the costs which matter for real widgets.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use xilem_core::{
+    AppendVec, DynMessage, ElementSplice, Keyed, MessageResult, Mut, SuperElement, View,
+    ViewElement, ViewId, ViewMarker, ViewPathTracker, ViewSequence, keyed,
+};
+
+const ITEMS: u32 = 1000;
+const ITERATIONS: u32 = 200;
+
+#[derive(Default)]
+struct Ctx {
+    path: Vec<ViewId>,
+    counts: Counts,
+}
+
+impl ViewPathTracker for Ctx {
+    fn push_id(&mut self, id: ViewId) {
+        self.path.push(id);
+    }
+    fn pop_id(&mut self) {
+        self.path.pop();
+    }
+    fn view_path(&mut self) -> &[ViewId] {
+        &self.path
+    }
+}
+
+/// How much work has been done on elements.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+struct Counts {
+    built: u32,
+    torn_down: u32,
+    text_changed: u32,
+    moved: u32,
+}
+
+/// A model of a widget, such as a row in a list.
+struct Row {
+    text: String,
+    /// The state which a widget would keep, such as a text box's selection.
+    _state: Box<[u64; 32]>,
+}
+
+impl ViewElement for Row {
+    type Mut<'a> = &'a mut Self;
+}
+
+impl SuperElement<Self, Ctx> for Row {
+    fn upcast(_: &mut Ctx, child: Self) -> Self {
+        child
+    }
+
+    fn with_downcast_val<R>(
+        this: Self::Mut<'_>,
+        f: impl FnOnce(Mut<'_, Self>) -> R,
+    ) -> (Self::Mut<'_>, R) {
+        let ret = f(this);
+        (this, ret)
+    }
+}
+
+/// The view for an item, which shows its text.
+struct Item {
+    text: String,
+}
+
+impl ViewMarker for Item {}
+impl View<(), (), Ctx> for Item {
+    type Element = Row;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut Ctx) -> (Row, ()) {
+        ctx.counts.built += 1;
+        let row = Row {
+            text: self.text.clone(),
+            _state: Box::new([0; 32]),
+        };
+        (row, ())
+    }
+
+    fn rebuild(&self, prev: &Self, (): &mut (), ctx: &mut Ctx, element: Mut<'_, Row>) {
+        if self.text != prev.text {
+            ctx.counts.text_changed += 1;
+            element.text.clone_from(&self.text);
+        }
+    }
+
+    fn teardown(&self, (): &mut (), ctx: &mut Ctx, _: Mut<'_, Row>) {
+        ctx.counts.torn_down += 1;
+    }
+
+    fn message(&self, (): &mut (), _: &[ViewId], _: DynMessage, (): &mut ()) -> MessageResult<()> {
+        MessageResult::Nop
+    }
+}
+
+/// An [`ElementSplice`] over a `Vec` of rows, which counts how many rows it moves.
+struct RowSplice<'a> {
+    rows: &'a mut Vec<Row>,
+    idx: usize,
+    scratch: AppendVec<Row>,
+    moved: u32,
+}
+
+impl<'a> RowSplice<'a> {
+    fn new(rows: &'a mut Vec<Row>) -> Self {
+        Self {
+            rows,
+            idx: 0,
+            scratch: AppendVec::default(),
+            moved: 0,
+        }
+    }
+}
+
+impl ElementSplice<Row> for RowSplice<'_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<Row>) -> R) -> R {
+        let ret = f(&mut self.scratch);
+        for row in self.scratch.drain() {
+            self.rows.insert(self.idx, row);
+            self.idx += 1;
+        }
+        ret
+    }
+
+    fn insert(&mut self, row: Row) {
+        self.rows.insert(self.idx, row);
+        self.idx += 1;
+    }
+
+    fn mutate<R>(&mut self, f: impl FnOnce(&mut Row) -> R) -> R {
+        let ret = f(&mut self.rows[self.idx]);
+        self.idx += 1;
+        ret
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.idx += n;
+    }
+
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        let row = self.rows.remove(self.idx + offset);
+        self.rows.insert(self.idx, row);
+        self.moved += 1;
+        true
+    }
+
+    fn delete<R>(&mut self, f: impl FnOnce(&mut Row) -> R) -> R {
+        let ret = f(&mut self.rows[self.idx]);
+        self.rows.remove(self.idx);
+        ret
+    }
+}
+
+/// The text of each item, which is kept in the element so that changes can be detected.
+fn text(id: u32) -> String {
+    format!("Item number {id:>4}")
+}
+
+fn vec_sequence(ids: &[u32]) -> Vec<Item> {
+    ids.iter().map(|&id| Item { text: text(id) }).collect()
+}
+
+fn keyed_sequence(ids: &[u32]) -> Keyed<u32, Item> {
+    keyed(ids.iter().copied(), |id| *id, |id| Item { text: text(id) })
+}
+
+/// Rebuild `make(a)` to `make(b)` `ITERATIONS` times, returning the average time and work of
+/// each rebuild.
+///
+/// The sequence is rebuilt back to `make(a)` between each of these, which isn't timed,
+/// as the reverse of a change can take different work.
+fn bench<Seq: ViewSequence<(), (), Ctx, Row>>(
+    a: &[u32],
+    b: &[u32],
+    make: impl Fn(&[u32]) -> Seq,
+) -> (Duration, Counts) {
+    let mut ctx = Ctx::default();
+    let mut elements = AppendVec::default();
+    let mut state = make(a).seq_build(&mut ctx, &mut elements);
+    let mut rows = elements.into_inner();
+
+    let mut time = Duration::ZERO;
+    let mut counts = Counts::default();
+    for iteration in 0..ITERATIONS {
+        let view_a = make(a);
+        let view_b = make(b);
+        ctx.counts = Counts::default();
+        let mut splice = RowSplice::new(&mut rows);
+        let start = Instant::now();
+        view_b.seq_rebuild(&view_a, &mut state, &mut ctx, &mut splice);
+        time += start.elapsed();
+        if iteration == 0 {
+            counts = Counts {
+                moved: splice.moved,
+                ..ctx.counts
+            };
+        }
+        view_a.seq_rebuild(
+            &view_b,
+            &mut state,
+            &mut ctx,
+            &mut RowSplice::new(&mut rows),
+        );
+        black_box(&rows);
+    }
+    (time / ITERATIONS, counts)
+}
+
+fn main() {
+    let items: Vec<u32> = (0..ITEMS).collect();
+    let mut inserted = vec![ITEMS];
+    inserted.extend(&items);
+    let mut last_to_first = vec![ITEMS - 1];
+    last_to_first.extend(0..ITEMS - 1);
+    let reversed: Vec<u32> = items.iter().rev().copied().collect();
+    // A fixed shuffle, as though the items were sorted by something else.
+    let mut shuffled = items.clone();
+    shuffled.sort_by_key(|id| id.wrapping_mul(2_654_435_761) % ITEMS);
+
+    let cases: [(&str, &[u32]); 5] = [
+        ("unchanged", &items),
+        ("insert at start", &inserted),
+        ("move last to first", &last_to_first),
+        ("shuffle", &shuffled),
+        ("reverse", &reversed),
+    ];
+    for (name, reordered) in cases {
+        let (vec_time, vec_counts) = bench(&items, reordered, vec_sequence);
+        let (keyed_time, keyed_counts) = bench(&items, reordered, keyed_sequence);
+        println!("{name} ({ITEMS} items):");
+        println!("  Vec:   {vec_time:>10.2?} per rebuild, {vec_counts:?}");
+        println!("  keyed: {keyed_time:>10.2?} per rebuild, {keyed_counts:?}");
+    }
+}
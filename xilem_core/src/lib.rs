@@ -41,8 +41,10 @@ pub use view::{View, ViewId, ViewMarker, ViewPathTracker};
 
 mod views;
 pub use views::{
-    Adapt, AdaptThunk, Fork, Frozen, MapAction, MapState, Memoize, OrphanView, RunOnce, adapt,
-    fork, frozen, lens, map_action, map_state, memoize, one_of, run_once, run_once_raw,
+    Adapt, AdaptThunk, Fork, Frozen, Keyed, MapAction, MapState, MapStateWith, Memoize, MemoizeArc,
+    OrphanView, RunOnce, WhenElse, WhenEmpty, WhenEmptyExt, adapt, fork, frozen, keyed, lens,
+    map_action, map_state, map_state_with, memoize, memoize_arc, one_of, run_once, run_once_raw,
+    when, when_else, when_empty,
 };
 
 mod message;
@@ -76,6 +76,9 @@ impl<T> Default for AppendVec<T> {
 ///  - An [`array`] of `ViewSequence` values.
 ///  - Tuples of `ViewSequences` with up to 15 elements.
 ///    These can be nested if an ad-hoc sequence of more than 15 sequences is needed.
+///    A tuple concatenates the elements of its members, which can be sequences of
+///    different types, such as `(header, rows, footer)` where `rows` is a `Vec`.
+///    Each member keeps its place even as the sequences before it grow and shrink.
 ///
 pub trait ViewSequence<State, Action, Context, Element, Message = DynMessage>: 'static
 where
@@ -138,6 +141,19 @@ pub trait ElementSplice<Element: ViewElement> {
     fn mutate<R>(&mut self, f: impl FnOnce(Element::Mut<'_>) -> R) -> R;
     /// Don't make any changes to the next n existing elements.
     fn skip(&mut self, n: usize);
+    /// Move the existing element `offset` places after the next existing element, so that
+    /// it becomes the next existing element.
+    ///
+    /// The elements in between are shifted back by one place.
+    /// Unlike deleting and re-inserting the element, this keeps the element itself
+    /// (and so e.g. the state of a widget), which is used for [`keyed`](crate::keyed) sequences.
+    ///
+    /// Returns `false` without making any changes if this splice can't move elements,
+    /// which is the default.
+    /// In that case, keyed sequences build a new element in place of the moved one instead.
+    fn move_to_current(&mut self, _offset: usize) -> bool {
+        false
+    }
     /// Delete the next existing element, after running a function on it.
     fn delete<R>(&mut self, f: impl FnOnce(Element::Mut<'_>) -> R) -> R;
 }
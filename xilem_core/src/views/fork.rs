@@ -127,6 +127,10 @@ impl ElementSplice<NoElement> for NoElements {
 
     fn skip(&mut self, _: usize) {}
 
+    fn move_to_current(&mut self, _: usize) -> bool {
+        true
+    }
+
     fn delete<R>(&mut self, f: impl FnOnce(<NoElement as crate::ViewElement>::Mut<'_>) -> R) -> R {
         f(())
     }
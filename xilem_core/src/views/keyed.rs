@@ -0,0 +1,295 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::vec::Vec;
+
+use crate::{
+    AppendVec, ElementSplice, MessageResult, SuperElement, View, ViewElement, ViewId, ViewMarker,
+    ViewPathTracker, ViewSequence,
+};
+
+/// A view sequence with a view for each of `items`, which matches views to elements by their
+/// key rather than their position.
+///
+/// `key` is called with each item to get its key, which must be unique within the sequence,
+/// and `view` then turns the item into its view.
+///
+/// The `ViewSequence` implementation for [`Vec`] matches views by position, so when an item
+/// is inserted or the items are reordered, every view after that point is rebuilt with a
+/// different item's view.
+/// This would e.g. give a text box another item's text but keep its own selection.
+/// Instead, a `keyed` sequence rebuilds each item's view against that item's previous view,
+/// moving its element if the item has moved.
+/// Elements are only built for new keys, and are only torn down for keys which are no longer
+/// present.
+/// If the parent can't move its elements (see [`ElementSplice::move_to_current`]), an item
+/// which has moved gets a new element instead.
+///
+/// Each item has a single view, and so a single element.
+///
+/// # Examples
+///
+/// ```ignore
+/// flex(keyed(
+///     &state.todos,
+///     |todo| todo.id,
+///     |todo| textbox(todo.text.clone(), move |state: &mut AppState, text| {
+///         state.set_text(todo.id, text);
+///     }),
+/// ))
+/// ```
+///
+/// # Panics
+///
+/// When the sequence is built or rebuilt, if two items have the same key.
+pub fn keyed<Item, Key, V>(
+    items: impl IntoIterator<Item = Item>,
+    key: impl Fn(&Item) -> Key,
+    view: impl Fn(Item) -> V,
+) -> Keyed<Key, V> {
+    Keyed {
+        children: items
+            .into_iter()
+            .map(|item| (key(&item), view(item)))
+            .collect(),
+    }
+}
+
+/// The view sequence for [`keyed`].
+#[derive(Debug)]
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Keyed<Key, V> {
+    children: Vec<(Key, V)>,
+}
+
+/// The state used to implement `ViewSequence` for [`Keyed`].
+#[allow(unnameable_types)] // reason: Implementation detail, public because of trait visibility rules
+#[derive(Debug)]
+pub struct KeyedState<ViewState> {
+    /// The state of each child, in the same order as the children.
+    children: Vec<KeyedChild<ViewState>>,
+    /// The generation which the next new child will have.
+    next_generation: u64,
+}
+
+/// The state of the child view for a key.
+#[derive(Debug)]
+struct KeyedChild<ViewState> {
+    view_state: ViewState,
+    /// The id of the child, which is unique within the sequence.
+    ///
+    /// As a key can be removed and then added again, this is a new value for each
+    /// child which is built, so that messages to a removed child are treated as stale.
+    generation: u64,
+}
+
+/// Panics if two of `children` have the same key, and returns the set of keys otherwise.
+fn unique_keys<Key: Ord, V>(children: &[(Key, V)]) -> BTreeSet<&Key> {
+    let keys: BTreeSet<&Key> = children.iter().map(|(key, _)| key).collect();
+    assert_eq!(
+        keys.len(),
+        children.len(),
+        "Keys in a keyed sequence must be unique"
+    );
+    keys
+}
+
+impl<State, Action, Context, Element, Key, V, Message>
+    ViewSequence<State, Action, Context, Element, Message> for Keyed<Key, V>
+where
+    Key: Ord + 'static,
+    Context: ViewPathTracker,
+    V: View<State, Action, Context, Message> + ViewMarker,
+    Element: SuperElement<V::Element, Context>,
+    V::Element: ViewElement,
+{
+    type SeqState = KeyedState<V::ViewState>;
+
+    fn seq_build(&self, ctx: &mut Context, elements: &mut AppendVec<Element>) -> Self::SeqState {
+        unique_keys(&self.children);
+        let mut next_generation = 0;
+        let children = self
+            .children
+            .iter()
+            .map(|(_, view)| {
+                let generation = next_generation;
+                next_generation += 1;
+                let (element, view_state) =
+                    ctx.with_id(ViewId::new(generation), |ctx| view.build(ctx));
+                elements.push(Element::upcast(ctx, element));
+                KeyedChild {
+                    view_state,
+                    generation,
+                }
+            })
+            .collect();
+        KeyedState {
+            children,
+            next_generation,
+        }
+    }
+
+    fn seq_rebuild(
+        &self,
+        prev: &Self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        let unchanged_keys = self.children.len() == prev.children.len()
+            && self
+                .children
+                .iter()
+                .zip(&prev.children)
+                .all(|((key, _), (prev_key, _))| key == prev_key);
+        if unchanged_keys {
+            // The most common case, where no elements need to be added, removed or moved.
+            for (((_, view), (_, prev_view)), child) in self
+                .children
+                .iter()
+                .zip(&prev.children)
+                .zip(&mut seq_state.children)
+            {
+                ctx.with_id(ViewId::new(child.generation), |ctx| {
+                    elements.mutate(|element| {
+                        Element::with_downcast(element, |element| {
+                            view.rebuild(prev_view, &mut child.view_state, ctx, element);
+                        });
+                    });
+                });
+            }
+            return;
+        }
+
+        let keys = unique_keys(&self.children);
+        let prev_indices: BTreeMap<&Key, usize> = prev
+            .children
+            .iter()
+            .enumerate()
+            .map(|(index, (key, _))| (key, index))
+            .collect();
+        let mut prev_states: Vec<Option<KeyedChild<V::ViewState>>> =
+            core::mem::take(&mut seq_state.children)
+                .into_iter()
+                .map(Some)
+                .collect();
+        // The indices in `prev` of the existing elements from the next one onwards, in order.
+        let mut remaining: VecDeque<usize> = (0..prev.children.len()).collect();
+        // The indices in `prev` of elements which have been replaced by a new element,
+        // because they couldn't be moved.
+        let mut replaced = BTreeSet::new();
+        let mut children = self.children.iter();
+        loop {
+            // Remove the elements of removed keys as they are reached, so that the element
+            // of an existing key is next if it hasn't moved.
+            // Once every child has been rebuilt, only the elements of removed keys remain.
+            while let Some(&next) = remaining.front() {
+                let (prev_key, prev_view) = &prev.children[next];
+                if keys.contains(prev_key) && !replaced.contains(&next) {
+                    break;
+                }
+                let mut child = prev_states[next].take().expect(
+                    "Inconsistent ViewSequence state. Perhaps the parent is mixing up children",
+                );
+                ctx.with_id(ViewId::new(child.generation), |ctx| {
+                    elements.delete(|element| {
+                        Element::with_downcast(element, |element| {
+                            prev_view.teardown(&mut child.view_state, ctx, element);
+                        });
+                    });
+                });
+                remaining.pop_front();
+            }
+            let Some((key, view)) = children.next() else {
+                break;
+            };
+
+            let mut prev_index = prev_indices.get(key).copied();
+            if let Some(index) = prev_index {
+                let offset = remaining
+                    .iter()
+                    .position(|&remaining_index| remaining_index == index)
+                    .expect("Each existing key has an element which hasn't been reached yet");
+                if offset == 0 || elements.move_to_current(offset) {
+                    remaining.remove(offset);
+                } else {
+                    // The elements can't be moved, so this key gets a new element, and
+                    // its old element is removed once it is reached.
+                    replaced.insert(index);
+                    prev_index = None;
+                }
+            }
+            let Some(prev_index) = prev_index else {
+                // A new (or replaced) key, so build its element in place.
+                let generation = seq_state.next_generation;
+                // Overflow handling: as in `Option`, this can never realistically overflow.
+                seq_state.next_generation = generation.wrapping_add(1);
+                let (element, view_state) =
+                    ctx.with_id(ViewId::new(generation), |ctx| view.build(ctx));
+                elements.insert(Element::upcast(ctx, element));
+                seq_state.children.push(KeyedChild {
+                    view_state,
+                    generation,
+                });
+                continue;
+            };
+
+            let mut child = prev_states[prev_index].take().expect(
+                "Inconsistent ViewSequence state. Perhaps the parent is mixing up children",
+            );
+            let prev_view = &prev.children[prev_index].1;
+            ctx.with_id(ViewId::new(child.generation), |ctx| {
+                elements.mutate(|element| {
+                    Element::with_downcast(element, |element| {
+                        view.rebuild(prev_view, &mut child.view_state, ctx, element);
+                    });
+                });
+            });
+            seq_state.children.push(child);
+        }
+    }
+
+    fn seq_teardown(
+        &self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        for ((_, view), child) in self.children.iter().zip(&mut seq_state.children) {
+            ctx.with_id(ViewId::new(child.generation), |ctx| {
+                elements.delete(|element| {
+                    Element::with_downcast(element, |element| {
+                        view.teardown(&mut child.view_state, ctx, element);
+                    });
+                });
+            });
+        }
+    }
+
+    fn seq_message(
+        &self,
+        seq_state: &mut Self::SeqState,
+        id_path: &[ViewId],
+        message: Message,
+        app_state: &mut State,
+    ) -> MessageResult<Action, Message> {
+        let (start, rest) = id_path
+            .split_first()
+            .expect("Id path has elements for Keyed");
+        let generation = start.routing_id();
+        // Messages are rare compared to rebuilds, so this doesn't keep a map by generation.
+        let Some((index, child)) = seq_state
+            .children
+            .iter_mut()
+            .enumerate()
+            .find(|(_, child)| child.generation == generation)
+        else {
+            // The child for this key has since been removed
+            return MessageResult::Stale(message);
+        };
+        self.children[index]
+            .1
+            .message(&mut child.view_state, rest, message, app_state)
+    }
+}
@@ -6,6 +6,10 @@ use core::marker::PhantomData;
 
 use crate::{MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
 
+/// The marker for the generic parameters of [`MapState`] and [`MapStateWith`].
+type StatePhantom<ParentState, ChildState, Action, Context, Message> =
+    PhantomData<fn(ParentState) -> (ChildState, Action, Context, Message)>;
+
 /// The View for [`map_state`] and [`lens`].
 ///
 /// See their documentation for more context.
@@ -13,7 +17,7 @@ use crate::{MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
 pub struct MapState<V, F, ParentState, ChildState, Action, Context, Message> {
     map_state: F,
     child: V,
-    phantom: PhantomData<fn(ParentState) -> (ChildState, Action, Context, Message)>,
+    phantom: StatePhantom<ParentState, ChildState, Action, Context, Message>,
 }
 
 impl<V, F, ParentState, ChildState, Action, Context, Message> Debug
@@ -71,6 +75,106 @@ where
     }
 }
 
+/// The View for [`map_state_with`].
+///
+/// See its documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct MapStateWith<V, Get, Set, ParentState, ChildState, Action, Context, Message> {
+    get: Get,
+    set: Set,
+    child: V,
+    phantom: StatePhantom<ParentState, ChildState, Action, Context, Message>,
+}
+
+impl<V, Get, Set, ParentState, ChildState, Action, Context, Message> Debug
+    for MapStateWith<V, Get, Set, ParentState, ChildState, Action, Context, Message>
+where
+    V: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MapStateWith")
+            .field("child", &self.child)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A view which gives its child a state computed from the parent state, rather than
+/// borrowed from it like in [`map_state`].
+///
+/// When the child handles a message, its state is created by `get`, and is then
+/// written back to the parent state by `set`.
+/// This allows using a component with a state which isn't stored as-is in the parent state,
+/// such as a struct made from several fields, or a value stored in a different format.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_core::docs::DocsView as WidgetView;
+/// use xilem_core::map_state_with;
+///
+/// struct Size {
+///     width: f64,
+///     height: f64,
+/// }
+///
+/// struct AppState {
+///     width: f64,
+///     height: f64,
+///     title: String,
+/// }
+///
+/// fn size_editor(size: &mut Size) -> impl WidgetView<Size> + use<> {
+///     // ...
+///     # xilem_core::run_once(|| {})
+/// }
+///
+/// fn app_logic(state: &mut AppState) -> impl WidgetView<AppState> + use<> {
+///     let mut size = Size {
+///         width: state.width,
+///         height: state.height,
+///     };
+///     map_state_with(
+///         size_editor(&mut size),
+///         |state: &AppState| Size {
+///             width: state.width,
+///             height: state.height,
+///         },
+///         |state: &mut AppState, size| {
+///             state.width = size.width;
+///             state.height = size.height;
+///         },
+///     )
+/// }
+/// ```
+pub fn map_state_with<
+    ParentState,
+    ChildState,
+    Action,
+    Context: ViewPathTracker,
+    Message,
+    V,
+    Get,
+    Set,
+>(
+    view: V,
+    get: Get,
+    set: Set,
+) -> MapStateWith<V, Get, Set, ParentState, ChildState, Action, Context, Message>
+where
+    ParentState: 'static,
+    ChildState: 'static,
+    V: View<ChildState, Action, Context, Message>,
+    Get: Fn(&ParentState) -> ChildState + 'static,
+    Set: Fn(&mut ParentState, ChildState) + 'static,
+{
+    MapStateWith {
+        get,
+        set,
+        child: view,
+        phantom: PhantomData,
+    }
+}
+
 /// An adapter which allows using a component which only uses one field of the current state.
 ///
 /// In Xilem, many components are functions of the form `fn my_component(&mut SomeState) -> impl WidgetView<SomeState>`.
@@ -179,3 +283,62 @@ where
             .message(view_state, id_path, message, (self.map_state)(app_state))
     }
 }
+
+impl<V, Get, Set, ParentState, ChildState, Action, Context, Message> ViewMarker
+    for MapStateWith<V, Get, Set, ParentState, ChildState, Action, Context, Message>
+{
+}
+impl<ParentState, ChildState, Action, Context, Message, V, Get, Set>
+    View<ParentState, Action, Context, Message>
+    for MapStateWith<V, Get, Set, ParentState, ChildState, Action, Context, Message>
+where
+    ParentState: 'static,
+    ChildState: 'static,
+    V: View<ChildState, Action, Context, Message>,
+    Get: Fn(&ParentState) -> ChildState + 'static,
+    Set: Fn(&mut ParentState, ChildState) + 'static,
+    Action: 'static,
+    Context: ViewPathTracker + 'static,
+    Message: 'static,
+{
+    type ViewState = V::ViewState;
+    type Element = V::Element;
+
+    fn build(&self, ctx: &mut Context) -> (Self::Element, Self::ViewState) {
+        self.child.build(ctx)
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut Context,
+        element: Mut<'_, Self::Element>,
+    ) {
+        self.child.rebuild(&prev.child, view_state, ctx, element);
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut Context,
+        element: Mut<'_, Self::Element>,
+    ) {
+        self.child.teardown(view_state, ctx, element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        id_path: &[ViewId],
+        message: Message,
+        app_state: &mut ParentState,
+    ) -> MessageResult<Action, Message> {
+        let mut child_state = (self.get)(app_state);
+        let result = self
+            .child
+            .message(view_state, id_path, message, &mut child_state);
+        (self.set)(app_state, child_state);
+        result
+    }
+}
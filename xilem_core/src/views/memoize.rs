@@ -5,6 +5,8 @@ use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::mem::size_of;
 
+use alloc::sync::Arc;
+
 use crate::{MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
 
 /// A view which supports Memoization.
@@ -40,6 +42,15 @@ It's not possible in Rust currently to check whether the (content of the) callba
 
 /// Memoize the view, until the `data` changes (in which case `view` is called again)
 ///
+/// Whilst `data` is equal to its previous value, rebuilding skips both calling `view`
+/// and rebuilding the view it returned, so large subtrees which only depend on `data`
+/// aren't diffed again.
+/// Messages and teardown still go to the most recently created view.
+/// If a message to that view requests a rebuild, it is recreated in the next rebuild
+/// even if `data` is unchanged.
+///
+/// To compare large data cheaply by pointer instead, use [`memoize_arc`].
+///
 /// # Examples
 ///
 /// (From the Xilem implementation)
@@ -72,6 +83,51 @@ where
     }
 }
 
+/// Memoize the view, until `data` is replaced with a different [`Arc`].
+///
+/// This is [`memoize`], but compares `data` using [`Arc::ptr_eq`] rather than by value,
+/// which is much cheaper for large data.
+/// As a result, `view` is called again whenever a new `Arc` is used, even if the value
+/// inside it is equal to the previous value.
+///
+/// # Examples
+///
+/// ```ignore
+/// fn contacts_list(contacts: &Arc<Vec<Contact>>) -> impl WidgetView<AppState> {
+///     memoize_arc(
+///         contacts.clone(), // only a new `Arc` leads to the list being rebuilt
+///         |contacts| flex(contacts.iter().map(contact_row).collect::<Vec<_>>()),
+///     )
+/// }
+/// ```
+pub fn memoize_arc<State, Action, Context, Message, T, V, InitView>(
+    data: Arc<T>,
+    init_view: InitView,
+) -> MemoizeArc<T, impl Fn(&ArcPtr<T>) -> V + 'static, State, Action, Context, Message>
+where
+    T: ?Sized + 'static,
+    InitView: Fn(&Arc<T>) -> V + 'static,
+    V: View<State, Action, Context, Message>,
+    Context: ViewPathTracker,
+{
+    memoize(ArcPtr(data), move |data: &ArcPtr<T>| init_view(&data.0))
+}
+
+/// The View for [`memoize_arc`].
+pub type MemoizeArc<T, ViewFn, State, Action, Context, Message> =
+    Memoize<ArcPtr<T>, ViewFn, State, Action, Context, Message>;
+
+/// An [`Arc`] which is compared by pointer, used as the data of [`memoize_arc`].
+#[allow(unnameable_types)] // reason: Implementation detail, public because of trait visibility rules
+#[derive(Debug)]
+pub struct ArcPtr<T: ?Sized>(Arc<T>);
+
+impl<T: ?Sized> PartialEq for ArcPtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 #[allow(unnameable_types)] // reason: Implementation detail, public because of trait visibility rules
 #[derive(Debug)]
 pub struct MemoizeState<V, VState> {
@@ -7,8 +7,11 @@ pub use run_once::{RunOnce, run_once, run_once_raw};
 mod adapt;
 pub use adapt::{Adapt, AdaptThunk, adapt};
 
+mod keyed;
+pub use keyed::{Keyed, keyed};
+
 mod map_state;
-pub use map_state::{MapState, lens, map_state};
+pub use map_state::{MapState, MapStateWith, lens, map_state, map_state_with};
 
 mod map_action;
 pub use map_action::{MapAction, map_action};
@@ -17,9 +20,15 @@ mod fork;
 pub use fork::{Fork, fork};
 
 mod memoize;
-pub use memoize::{Frozen, Memoize, frozen, memoize};
+pub use memoize::{Frozen, Memoize, MemoizeArc, frozen, memoize, memoize_arc};
 
 pub mod one_of;
 
 mod orphan;
 pub use orphan::OrphanView;
+
+mod when_empty;
+pub use when_empty::{WhenEmpty, WhenEmptyExt, when_empty};
+
+mod when;
+pub use when::{WhenElse, when, when_else};
@@ -0,0 +1,200 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    AppendVec, ElementSplice, MessageResult, ViewElement, ViewId, ViewPathTracker, ViewSequence,
+};
+
+/// A view sequence which contains `seq` if `condition` is true, and nothing otherwise.
+///
+/// This is a more readable way to write `condition.then(|| seq)`, and uses the
+/// `ViewSequence` implementation for [`Option`].
+/// Each time `condition` becomes true, `seq` is built afresh.
+///
+/// # Examples
+///
+/// ```ignore
+/// flex((
+///     label("Sign in"),
+///     when(state.show_error, label("Incorrect password")),
+///     button("Submit", |state: &mut AppState| state.submit()),
+/// ))
+/// ```
+pub fn when<Seq>(condition: bool, seq: Seq) -> Option<Seq> {
+    condition.then_some(seq)
+}
+
+/// A view sequence which contains `if_true` if `condition` is true, and `if_false` otherwise.
+///
+/// When `condition` changes, the elements of the previous branch are removed and the other
+/// branch is built in their place, so switching between branches doesn't disturb the
+/// elements of sibling views.
+/// This differs from [`Either`](crate::one_of::Either), which is a single view whose element
+/// is replaced, and which requires both branches to be views rather than sequences.
+///
+/// # Examples
+///
+/// ```ignore
+/// flex((
+///     label("Account"),
+///     when_else(
+///         state.signed_in,
+///         button("Sign out", |state: &mut AppState| state.sign_out()),
+///         (textbox(..), button("Sign in", |state: &mut AppState| state.sign_in())),
+///     ),
+/// ))
+/// ```
+pub fn when_else<IfTrue, IfFalse>(
+    condition: bool,
+    if_true: IfTrue,
+    if_false: IfFalse,
+) -> WhenElse<IfTrue, IfFalse> {
+    WhenElse {
+        branch: if condition {
+            Branch::True(if_true)
+        } else {
+            Branch::False(if_false)
+        },
+    }
+}
+
+/// The view sequence for [`when_else`].
+#[derive(Debug)]
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct WhenElse<IfTrue, IfFalse> {
+    branch: Branch<IfTrue, IfFalse>,
+}
+
+/// One of the branches of a [`WhenElse`], or of its state.
+#[derive(Debug)]
+enum Branch<IfTrue, IfFalse> {
+    /// The branch used when the condition is true.
+    True(IfTrue),
+    /// The branch used when the condition is false.
+    False(IfFalse),
+}
+
+/// The state used to implement `ViewSequence` for [`WhenElse`].
+#[allow(unnameable_types)] // reason: Implementation detail, public because of trait visibility rules
+#[derive(Debug)]
+pub struct WhenElseState<TrueState, FalseState> {
+    /// The state of the branch which is currently shown.
+    inner: Branch<TrueState, FalseState>,
+    /// The generation the shown branch is at.
+    ///
+    /// This is incremented each time the branch changes, so that messages
+    /// sent to the elements of a previous branch are treated as stale.
+    generation: u64,
+}
+
+impl<State, Action, Context, Element, IfTrue, IfFalse, Message>
+    ViewSequence<State, Action, Context, Element, Message> for WhenElse<IfTrue, IfFalse>
+where
+    IfTrue: ViewSequence<State, Action, Context, Element, Message>,
+    IfFalse: ViewSequence<State, Action, Context, Element, Message>,
+    Context: ViewPathTracker,
+    Element: ViewElement,
+{
+    type SeqState = WhenElseState<IfTrue::SeqState, IfFalse::SeqState>;
+
+    fn seq_build(&self, ctx: &mut Context, elements: &mut AppendVec<Element>) -> Self::SeqState {
+        let generation = 0;
+        let inner = ctx.with_id(ViewId::new(generation), |ctx| match &self.branch {
+            Branch::True(seq) => Branch::True(seq.seq_build(ctx, elements)),
+            Branch::False(seq) => Branch::False(seq.seq_build(ctx, elements)),
+        });
+        WhenElseState { inner, generation }
+    }
+
+    fn seq_rebuild(
+        &self,
+        prev: &Self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        let id = ViewId::new(seq_state.generation);
+        match (&self.branch, &prev.branch, &mut seq_state.inner) {
+            (Branch::True(seq), Branch::True(prev), Branch::True(inner_state)) => {
+                ctx.with_id(id, |ctx| seq.seq_rebuild(prev, inner_state, ctx, elements));
+                return;
+            }
+            (Branch::False(seq), Branch::False(prev), Branch::False(inner_state)) => {
+                ctx.with_id(id, |ctx| seq.seq_rebuild(prev, inner_state, ctx, elements));
+                return;
+            }
+            // The branch has changed, so remove the elements of the previous one
+            (_, Branch::True(prev), Branch::True(inner_state)) => {
+                ctx.with_id(id, |ctx| prev.seq_teardown(inner_state, ctx, elements));
+            }
+            (_, Branch::False(prev), Branch::False(inner_state)) => {
+                ctx.with_id(id, |ctx| prev.seq_teardown(inner_state, ctx, elements));
+            }
+            // This could only happen if some malicious parent view was messing with our
+            // internal state (i.e. mixing up the state from different instances)
+            _ => {
+                panic!("Inconsistent ViewSequence state. Perhaps the parent is mixing up children")
+            }
+        }
+
+        // Then build the new branch in their place, with a new path so that
+        // messages to the previous branch are stale.
+        // Overflow handling: as in `Option`, this can never realistically overflow.
+        seq_state.generation = seq_state.generation.wrapping_add(1);
+        let new_id = ViewId::new(seq_state.generation);
+        seq_state.inner = ctx.with_id(new_id, |ctx| {
+            elements.with_scratch(|elements| match &self.branch {
+                Branch::True(seq) => Branch::True(seq.seq_build(ctx, elements)),
+                Branch::False(seq) => Branch::False(seq.seq_build(ctx, elements)),
+            })
+        });
+    }
+
+    fn seq_teardown(
+        &self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        ctx.with_id(ViewId::new(seq_state.generation), |ctx| {
+            match (&self.branch, &mut seq_state.inner) {
+                (Branch::True(seq), Branch::True(inner_state)) => {
+                    seq.seq_teardown(inner_state, ctx, elements);
+                }
+                (Branch::False(seq), Branch::False(inner_state)) => {
+                    seq.seq_teardown(inner_state, ctx, elements);
+                }
+                _ => panic!(
+                    "Inconsistent ViewSequence state. Perhaps the parent is mixing up children"
+                ),
+            }
+        });
+    }
+
+    fn seq_message(
+        &self,
+        seq_state: &mut Self::SeqState,
+        id_path: &[ViewId],
+        message: Message,
+        app_state: &mut State,
+    ) -> MessageResult<Action, Message> {
+        let (start, rest) = id_path
+            .split_first()
+            .expect("Id path has elements for WhenElse");
+        if start.routing_id() != seq_state.generation {
+            // The message was sent to a previous branch
+            return MessageResult::Stale(message);
+        }
+        match (&self.branch, &mut seq_state.inner) {
+            (Branch::True(seq), Branch::True(inner_state)) => {
+                seq.seq_message(inner_state, rest, message, app_state)
+            }
+            (Branch::False(seq), Branch::False(inner_state)) => {
+                seq.seq_message(inner_state, rest, message, app_state)
+            }
+            _ => {
+                panic!("Inconsistent ViewSequence state. Perhaps the parent is mixing up children")
+            }
+        }
+    }
+}
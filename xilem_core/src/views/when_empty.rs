@@ -0,0 +1,216 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::vec::Vec;
+
+use crate::{
+    AppendVec, ElementSplice, MessageResult, ViewElement, ViewId, ViewPathTracker, ViewSequence,
+};
+
+/// The id used for the items of a [`WhenEmpty`].
+const ITEMS_ID: ViewId = ViewId::new(0);
+/// The id used for the placeholder of a [`WhenEmpty`].
+///
+/// This is followed by the placeholder's generation in the view path.
+const PLACEHOLDER_ID: ViewId = ViewId::new(1);
+
+/// A view sequence which shows `placeholder` when `items` is empty, and `items` otherwise.
+///
+/// The placeholder and the items share a single position in the parent sequence, so
+/// switching between them doesn't disturb the elements of sibling views.
+///
+/// See also [`WhenEmptyExt::when_empty`], which is usually more convenient.
+///
+/// # Examples
+///
+/// ```ignore
+/// flex((
+///     label("Todos"),
+///     when_empty(todos.iter().map(todo_item).collect(), label("Nothing to do!")),
+///     button("Add", |state: &mut AppState| state.add_todo()),
+/// ))
+/// ```
+pub fn when_empty<Seq, Placeholder>(
+    items: Vec<Seq>,
+    placeholder: Placeholder,
+) -> WhenEmpty<Seq, Placeholder> {
+    WhenEmpty { items, placeholder }
+}
+
+/// An extension trait for [`Vec`]-based view sequences, to show a placeholder when they are empty.
+pub trait WhenEmptyExt<Seq> {
+    /// Show `placeholder` in place of this sequence whilst it is empty.
+    ///
+    /// See [`when_empty`] for more details.
+    fn when_empty<Placeholder>(self, placeholder: Placeholder) -> WhenEmpty<Seq, Placeholder>;
+}
+
+impl<Seq> WhenEmptyExt<Seq> for Vec<Seq> {
+    fn when_empty<Placeholder>(self, placeholder: Placeholder) -> WhenEmpty<Seq, Placeholder> {
+        when_empty(self, placeholder)
+    }
+}
+
+/// The view sequence for [`when_empty`].
+#[derive(Debug)]
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct WhenEmpty<Seq, Placeholder> {
+    items: Vec<Seq>,
+    placeholder: Placeholder,
+}
+
+/// The state used to implement `ViewSequence` for [`WhenEmpty`].
+#[allow(unnameable_types)] // reason: Implementation detail, public because of trait visibility rules
+#[derive(Debug)]
+pub struct WhenEmptyState<ItemsState, PlaceholderState> {
+    items: ItemsState,
+    /// The state of the placeholder, if it is currently shown.
+    placeholder: Option<PlaceholderState>,
+    /// The generation the placeholder is at.
+    ///
+    /// This is incremented each time the placeholder is removed, so that messages
+    /// sent to a previous placeholder are treated as stale.
+    generation: u64,
+}
+
+impl<State, Action, Context, Element, Seq, Placeholder, Message>
+    ViewSequence<State, Action, Context, Element, Message> for WhenEmpty<Seq, Placeholder>
+where
+    Seq: ViewSequence<State, Action, Context, Element, Message>,
+    Placeholder: ViewSequence<State, Action, Context, Element, Message>,
+    Context: ViewPathTracker,
+    Element: ViewElement,
+{
+    type SeqState = WhenEmptyState<
+        <Vec<Seq> as ViewSequence<State, Action, Context, Element, Message>>::SeqState,
+        Placeholder::SeqState,
+    >;
+
+    fn seq_build(&self, ctx: &mut Context, elements: &mut AppendVec<Element>) -> Self::SeqState {
+        let generation = 0;
+        let placeholder = self.items.is_empty().then(|| {
+            ctx.with_id(PLACEHOLDER_ID, |ctx| {
+                ctx.with_id(ViewId::new(generation), |ctx| {
+                    self.placeholder.seq_build(ctx, elements)
+                })
+            })
+        });
+        let items = ctx.with_id(ITEMS_ID, |ctx| self.items.seq_build(ctx, elements));
+        WhenEmptyState {
+            items,
+            placeholder,
+            generation,
+        }
+    }
+
+    fn seq_rebuild(
+        &self,
+        prev: &Self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        assert_eq!(
+            prev.items.is_empty(),
+            seq_state.placeholder.is_some(),
+            "Inconsistent ViewSequence state. Perhaps the parent is mixing up children"
+        );
+        let generation = ViewId::new(seq_state.generation);
+        match (self.items.is_empty(), seq_state.placeholder.as_mut()) {
+            (true, Some(placeholder_state)) => {
+                ctx.with_id(PLACEHOLDER_ID, |ctx| {
+                    ctx.with_id(generation, |ctx| {
+                        self.placeholder.seq_rebuild(
+                            &prev.placeholder,
+                            placeholder_state,
+                            ctx,
+                            elements,
+                        );
+                    });
+                });
+            }
+            (true, None) => {
+                // The sequence has just become empty, so show the placeholder in its place
+                let placeholder_state = ctx.with_id(PLACEHOLDER_ID, |ctx| {
+                    ctx.with_id(generation, |ctx| {
+                        elements.with_scratch(|elements| self.placeholder.seq_build(ctx, elements))
+                    })
+                });
+                seq_state.placeholder = Some(placeholder_state);
+            }
+            (false, Some(placeholder_state)) => {
+                ctx.with_id(PLACEHOLDER_ID, |ctx| {
+                    ctx.with_id(generation, |ctx| {
+                        prev.placeholder
+                            .seq_teardown(placeholder_state, ctx, elements);
+                    });
+                });
+                seq_state.placeholder = None;
+                // As in `Option`, we increment the generation on the falling edge
+                seq_state.generation = seq_state.generation.wrapping_add(1);
+            }
+            (false, None) => {
+                // The placeholder wasn't shown before, and still isn't
+            }
+        }
+        ctx.with_id(ITEMS_ID, |ctx| {
+            self.items
+                .seq_rebuild(&prev.items, &mut seq_state.items, ctx, elements);
+        });
+    }
+
+    fn seq_teardown(
+        &self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        assert_eq!(
+            self.items.is_empty(),
+            seq_state.placeholder.is_some(),
+            "Inconsistent ViewSequence state. Perhaps the parent is mixing up children"
+        );
+        if let Some(placeholder_state) = seq_state.placeholder.as_mut() {
+            ctx.with_id(PLACEHOLDER_ID, |ctx| {
+                ctx.with_id(ViewId::new(seq_state.generation), |ctx| {
+                    self.placeholder
+                        .seq_teardown(placeholder_state, ctx, elements);
+                });
+            });
+        }
+        ctx.with_id(ITEMS_ID, |ctx| {
+            self.items.seq_teardown(&mut seq_state.items, ctx, elements);
+        });
+    }
+
+    fn seq_message(
+        &self,
+        seq_state: &mut Self::SeqState,
+        id_path: &[ViewId],
+        message: Message,
+        app_state: &mut State,
+    ) -> MessageResult<Action, Message> {
+        let (start, rest) = id_path
+            .split_first()
+            .expect("Id path has elements for WhenEmpty");
+        if *start == ITEMS_ID {
+            return self
+                .items
+                .seq_message(&mut seq_state.items, rest, message, app_state);
+        }
+        assert_eq!(
+            *start, PLACEHOLDER_ID,
+            "Unexpected id path for WhenEmpty: {id_path:?}"
+        );
+        let (generation, rest) = rest
+            .split_first()
+            .expect("Id path has a generation for the placeholder of WhenEmpty");
+        match seq_state.placeholder.as_mut() {
+            Some(placeholder_state) if generation.routing_id() == seq_state.generation => self
+                .placeholder
+                .seq_message(placeholder_state, rest, message, app_state),
+            // The message was sent to a previous placeholder
+            _ => MessageResult::Stale(message),
+        }
+    }
+}
@@ -74,13 +74,32 @@ pub(super) struct Action {
 pub(super) struct SequenceView<Seq> {
     id: u32,
     seq: Seq,
+    /// Whether the children can be moved by `ElementSplice::move_to_current`
+    supports_moves: bool,
 }
 
 pub(super) fn sequence<Seq>(id: u32, seq: Seq) -> SequenceView<Seq>
 where
     Seq: ViewSequence<(), Action, TestCtx, TestElement>,
 {
-    SequenceView { id, seq }
+    SequenceView {
+        id,
+        seq,
+        supports_moves: true,
+    }
+}
+
+/// A sequence view whose children can't be moved, like an `ElementSplice` which
+/// uses the default `move_to_current`
+pub(super) fn sequence_without_moves<Seq>(id: u32, seq: Seq) -> SequenceView<Seq>
+where
+    Seq: ViewSequence<(), Action, TestCtx, TestElement>,
+{
+    SequenceView {
+        id,
+        seq,
+        supports_moves: false,
+    }
 }
 
 impl<Seq> ViewMarker for SequenceView<Seq> {}
@@ -124,6 +143,7 @@ where
             inner: element.children.as_mut().unwrap(),
             ix: 0,
             scratch: &mut view_state.1,
+            supports_moves: self.supports_moves,
         };
         self.seq
             .seq_rebuild(&prev.seq, &mut view_state.0, ctx, &mut elements);
@@ -141,6 +161,7 @@ where
             inner: element.children.as_mut().unwrap(),
             ix: 0,
             scratch: &mut view_state.1,
+            supports_moves: self.supports_moves,
         };
         self.seq.seq_teardown(&mut view_state.0, ctx, &mut elements);
     }
@@ -257,6 +278,7 @@ pub(super) struct SeqTracker<'a> {
     scratch: &'a mut AppendVec<TestElement>,
     ix: usize,
     inner: &'a mut SeqChildren,
+    supports_moves: bool,
 }
 
 #[track_caller]
@@ -271,12 +293,14 @@ impl ElementSplice<TestElement> for SeqTracker<'_> {
     fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<TestElement>) -> R) -> R {
         let ret = f(self.scratch);
         for element in self.scratch.drain() {
-            self.inner.active.push(element);
+            self.inner.active.insert(self.ix, element);
+            self.ix += 1;
         }
         ret
     }
     fn insert(&mut self, element: TestElement) {
-        self.inner.active.push(element);
+        self.inner.active.insert(self.ix, element);
+        self.ix += 1;
     }
     fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, TestElement>) -> R) -> R {
         let ix = self.ix;
@@ -286,6 +310,14 @@ impl ElementSplice<TestElement> for SeqTracker<'_> {
     fn skip(&mut self, n: usize) {
         self.ix += n;
     }
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if !self.supports_moves {
+            return false;
+        }
+        let element = self.inner.active.remove(self.ix + offset);
+        self.inner.active.insert(self.ix, element);
+        true
+    }
     fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, TestElement>) -> R) -> R {
         let ret = f(&mut self.inner.active[self.ix]);
         let val = self.inner.active.remove(self.ix);
@@ -0,0 +1,178 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`keyed`], which matches the views of a sequence to elements by key.
+//!
+//! [`keyed`]: xilem_core::keyed
+
+#![expect(
+    clippy::shadow_unrelated,
+    reason = "Deferred: Noisy. Fix is to use scopes"
+)]
+
+mod common;
+use common::*;
+use xilem_core::{Keyed, MessageResult, View, keyed};
+
+/// A keyed sequence with a view for each of `ids`, keyed by its id.
+fn keyed_ops(ids: &[u32]) -> Keyed<u32, OperationView<0>> {
+    keyed(ids.iter().copied(), |id| *id, OperationView)
+}
+
+/// The ids which the active children were built with, in order.
+fn active_ids(element: &TestElement) -> Vec<u32> {
+    let children = element.children.as_ref().unwrap();
+    children
+        .active
+        .iter()
+        .map(|child| match child.operations[0] {
+            Operation::Build(id) => id,
+            _ => panic!("The first operation of an element is always a build"),
+        })
+        .collect()
+}
+
+#[test]
+fn insert_at_start_keeps_elements() {
+    let view = sequence(0, keyed_ops(&[1, 2, 3]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [1, 2, 3]);
+
+    let view2 = sequence(1, keyed_ops(&[0, 1, 2, 3]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [0, 1, 2, 3]);
+
+    let children = element.children.as_ref().unwrap();
+    assert!(children.deleted.is_empty());
+    assert_eq!(children.active[0].operations, [Operation::Build(0)]);
+    // Each existing element is rebuilt against its own previous view.
+    for (child, id) in children.active[1..].iter().zip(1..) {
+        assert_eq!(
+            child.operations,
+            [
+                Operation::Build(id),
+                Operation::Rebuild { from: id, to: id }
+            ]
+        );
+    }
+}
+
+#[test]
+fn reorder_moves_elements() {
+    let view = sequence(0, keyed_ops(&[1, 2, 3, 4, 5]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+
+    let view2 = sequence(1, keyed_ops(&[5, 3, 1, 2, 4]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [5, 3, 1, 2, 4]);
+    let children = element.children.as_ref().unwrap();
+    assert!(children.deleted.is_empty());
+    for child in &children.active {
+        assert_eq!(child.operations.len(), 2);
+    }
+
+    let view3 = sequence(2, keyed_ops(&[4, 2, 1, 3, 5]));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [4, 2, 1, 3, 5]);
+
+    // Messages are routed by key, whatever the current position.
+    let children = element.children.as_ref().unwrap();
+    let path = children.active[0].view_path.clone();
+    let result = view3.message(&mut state, &path, Box::new(()), &mut ());
+    assert_action(result, 4);
+}
+
+#[test]
+fn reorder_without_moves_rebuilds_moved_elements() {
+    let view = sequence_without_moves(0, keyed_ops(&[1, 2, 3]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    let children = element.children.as_ref().unwrap();
+    let path_3 = children.active[2].view_path.clone();
+
+    // The element for 3 can't be moved to the start, so it is replaced.
+    let view2 = sequence_without_moves(1, keyed_ops(&[3, 1, 2]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [3, 1, 2]);
+    let children = element.children.as_ref().unwrap();
+    assert_eq!(children.active[0].operations, [Operation::Build(3)]);
+    for (child, id) in children.active[1..].iter().zip(1..) {
+        assert_eq!(
+            child.operations,
+            [
+                Operation::Build(id),
+                Operation::Rebuild { from: id, to: id }
+            ]
+        );
+    }
+    assert_eq!(children.deleted.len(), 1);
+    let (_, deleted) = &children.deleted[0];
+    assert_eq!(
+        deleted.operations,
+        [Operation::Build(3), Operation::Teardown(3)]
+    );
+
+    // Messages to the replaced element are stale.
+    let result = view2.message(&mut state, &path_3, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let path_3 = children.active[0].view_path.clone();
+    let result = view2.message(&mut state, &path_3, Box::new(()), &mut ());
+    assert_action(result, 3);
+}
+
+#[test]
+fn remove_and_readd() {
+    let view = sequence(0, keyed_ops(&[1, 2, 3, 4]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    let children = element.children.as_ref().unwrap();
+    let path_2 = children.active[1].view_path.clone();
+
+    // Remove an element whilst moving another past it.
+    let view2 = sequence(1, keyed_ops(&[4, 1, 3]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [4, 1, 3]);
+    let children = element.children.as_ref().unwrap();
+    assert_eq!(children.deleted.len(), 1);
+    let (_, deleted) = &children.deleted[0];
+    assert_eq!(
+        deleted.operations,
+        [Operation::Build(2), Operation::Teardown(2)]
+    );
+
+    // The key is built afresh when added again, so messages to the old element are stale.
+    let view3 = sequence(2, keyed_ops(&[4, 2, 1, 3]));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [4, 2, 1, 3]);
+    let children = element.children.as_ref().unwrap();
+    assert_eq!(children.active[1].operations, [Operation::Build(2)]);
+    assert_ne!(children.active[1].view_path, path_2);
+    let result = view3.message(&mut state, &path_2, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let path_2 = children.active[1].view_path.clone();
+    let result = view3.message(&mut state, &path_2, Box::new(()), &mut ());
+    assert_action(result, 2);
+
+    view3.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    let children = element.children.as_ref().unwrap();
+    assert!(children.active.is_empty());
+    assert_eq!(children.deleted.len(), 5);
+}
+
+#[test]
+#[should_panic(expected = "Keys in a keyed sequence must be unique")]
+fn duplicate_keys() {
+    let view = sequence(0, keyed_ops(&[1, 2, 1]));
+    let mut ctx = TestCtx::default();
+    let _ = view.build(&mut ctx);
+}
@@ -0,0 +1,110 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`map_state`] and [`map_state_with`], which run views against part of the state.
+//!
+//! [`map_state`]: xilem_core::map_state
+//! [`map_state_with`]: xilem_core::map_state_with
+
+mod common;
+use common::*;
+use xilem_core::{
+    DynMessage, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker, map_state,
+    map_state_with,
+};
+
+/// A view which adds its value to the state when it gets a message.
+struct Add(i32);
+
+impl ViewMarker for Add {}
+impl View<i32, (), TestCtx> for Add {
+    type Element = TestElement;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut TestCtx) -> (Self::Element, Self::ViewState) {
+        let element = TestElement {
+            operations: vec![Operation::Build(0)],
+            view_path: ctx.view_path().to_vec(),
+            children: None,
+        };
+        (element, ())
+    }
+
+    fn rebuild(&self, _: &Self, (): &mut (), _: &mut TestCtx, element: Mut<'_, TestElement>) {
+        element
+            .operations
+            .push(Operation::Rebuild { from: 0, to: 0 });
+    }
+
+    fn teardown(&self, (): &mut (), _: &mut TestCtx, element: Mut<'_, TestElement>) {
+        element.operations.push(Operation::Teardown(0));
+    }
+
+    fn message(
+        &self,
+        (): &mut (),
+        _: &[ViewId],
+        _: DynMessage,
+        app_state: &mut i32,
+    ) -> MessageResult<()> {
+        *app_state += self.0;
+        MessageResult::Action(())
+    }
+}
+
+struct Parent {
+    count: i32,
+    /// A count which is stored as text, so can't be borrowed as an `i32`.
+    text_count: String,
+}
+
+#[test]
+fn map_state_borrows_field() {
+    let view = map_state(Add(2), |parent: &mut Parent| &mut parent.count);
+    let mut ctx = TestCtx::default();
+    let (element, mut view_state) = view.build(&mut ctx);
+    assert_eq!(element.operations, [Operation::Build(0)]);
+
+    let mut parent = Parent {
+        count: 1,
+        text_count: String::new(),
+    };
+    let result = view.message(&mut view_state, &[], Box::new(()), &mut parent);
+    assert!(matches!(result, MessageResult::Action(())));
+    assert_eq!(parent.count, 3);
+}
+
+#[test]
+fn map_state_with_writes_back() {
+    let view = || {
+        map_state_with(
+            Add(5),
+            |parent: &Parent| parent.text_count.parse::<i32>().unwrap(),
+            |parent: &mut Parent, count| parent.text_count = count.to_string(),
+        )
+    };
+    let mut ctx = TestCtx::default();
+    let view1 = view();
+    let (mut element, mut view_state) = view1.build(&mut ctx);
+
+    // Building and rebuilding pass straight through to the child.
+    let view2 = view();
+    view2.rebuild(&view1, &mut view_state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(
+        element.operations,
+        [Operation::Build(0), Operation::Rebuild { from: 0, to: 0 }]
+    );
+
+    let mut parent = Parent {
+        count: 0,
+        text_count: "10".into(),
+    };
+    let result = view2.message(&mut view_state, &[], Box::new(()), &mut parent);
+    assert!(matches!(result, MessageResult::Action(())));
+    assert_eq!(parent.text_count, "15");
+    assert_eq!(parent.count, 0);
+
+    view2.teardown(&mut view_state, &mut ctx, &mut element);
+    assert_eq!(element.operations.last(), Some(&Operation::Teardown(0)));
+}
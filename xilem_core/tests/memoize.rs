@@ -0,0 +1,112 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`memoize`], which skips rebuilding its child whilst its data is unchanged.
+//!
+//! [`memoize`]: xilem_core::memoize
+
+#![expect(
+    clippy::shadow_unrelated,
+    reason = "Deferred: Noisy. Fix is to use scopes"
+)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+mod common;
+use common::*;
+use xilem_core::{View, memoize, memoize_arc};
+
+/// How many times the child views of the memoized views have been created.
+///
+/// The view functions passed to `memoize` can't capture, so this is global.
+/// Only one test uses it, so tests running in parallel don't interfere.
+static VIEWS_CREATED: AtomicU32 = AtomicU32::new(0);
+
+fn memoized(data: u32) -> impl View<(), Action, TestCtx, Element = TestElement> {
+    memoize(data, |data: &u32| {
+        VIEWS_CREATED.fetch_add(1, Ordering::Relaxed);
+        OperationView::<0>(*data)
+    })
+}
+
+#[test]
+fn memoize_skips_unchanged_data() {
+    let mut ctx = TestCtx::default();
+    let view1 = memoized(1);
+    let (mut element, mut state) = view1.build(&mut ctx);
+    assert_eq!(VIEWS_CREATED.load(Ordering::Relaxed), 1);
+    assert_eq!(element.operations, [Operation::Build(1)]);
+
+    // The same data doesn't create the child view again, nor rebuild its element.
+    let view2 = memoized(1);
+    view2.rebuild(&view1, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(VIEWS_CREATED.load(Ordering::Relaxed), 1);
+    assert_eq!(element.operations, [Operation::Build(1)]);
+
+    // Messages still reach the child built for the original data.
+    let result = view2.message(&mut state, &[], Box::new(()), &mut ());
+    assert_action(result, 1);
+
+    let view3 = memoized(2);
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(VIEWS_CREATED.load(Ordering::Relaxed), 2);
+    assert_eq!(
+        element.operations,
+        [Operation::Build(1), Operation::Rebuild { from: 1, to: 2 }]
+    );
+    let result = view3.message(&mut state, &[], Box::new(()), &mut ());
+    assert_action(result, 2);
+
+    view3.teardown(&mut state, &mut ctx, &mut element);
+    assert_eq!(element.operations.last(), Some(&Operation::Teardown(2)));
+}
+
+#[test]
+fn teardown_after_skipped_rebuild() {
+    fn memoized_uncounted(data: u32) -> impl View<(), Action, TestCtx, Element = TestElement> {
+        memoize(data, |data: &u32| OperationView::<0>(*data))
+    }
+    let mut ctx = TestCtx::default();
+    let view1 = memoized_uncounted(5);
+    let (mut element, mut state) = view1.build(&mut ctx);
+    let view2 = memoized_uncounted(5);
+    view2.rebuild(&view1, &mut state, &mut ctx, &mut element);
+    // The child view built for `view1` is torn down, as `view2` never created one.
+    view2.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(
+        element.operations,
+        [Operation::Build(5), Operation::Teardown(5)]
+    );
+}
+
+#[test]
+fn memoize_arc_compares_pointers() {
+    fn memoized_arc(data: Arc<u32>) -> impl View<(), Action, TestCtx, Element = TestElement> {
+        memoize_arc(data, |data: &Arc<u32>| OperationView::<0>(**data))
+    }
+    let data = Arc::new(1);
+    let mut ctx = TestCtx::default();
+    let view1 = memoized_arc(data.clone());
+    let (mut element, mut state) = view1.build(&mut ctx);
+
+    // The same `Arc` skips the rebuild.
+    let view2 = memoized_arc(data);
+    view2.rebuild(&view1, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(element.operations, [Operation::Build(1)]);
+
+    // A different `Arc` doesn't, even with an equal value.
+    let view3 = memoized_arc(Arc::new(1));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(
+        element.operations,
+        [Operation::Build(1), Operation::Rebuild { from: 1, to: 1 }]
+    );
+    let result = view3.message(&mut state, &[], Box::new(()), &mut ());
+    assert_action(result, 1);
+}
@@ -199,4 +199,86 @@ fn two_element_message() {
     assert_action(result, 1);
 }
 
+/// A tuple can concatenate sequences of different types, such as a header,
+/// a `Vec` of rows and a footer.
+#[test]
+fn heterogeneous_members() {
+    fn active_ids(element: &TestElement) -> Vec<u32> {
+        let children = element.children.as_ref().unwrap();
+        children
+            .active
+            .iter()
+            .map(|child| match child.operations.first() {
+                Some(Operation::Build(id)) => *id,
+                _ => panic!("Every child should start with a build"),
+            })
+            .collect()
+    }
+
+    let view = sequence(0, (record_ops(1), vec![record_ops(2)], record_ops(3)));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 2, 3]);
+
+    // The middle sequence grows
+    let view2 = sequence(
+        0,
+        (
+            record_ops(1),
+            vec![record_ops(2), record_ops(4), record_ops(5)],
+            record_ops(3),
+        ),
+    );
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 2, 4, 5, 3]);
+    let seq_children = element.children.as_ref().unwrap();
+    // The footer was rebuilt in place, rather than being recreated
+    assert_eq!(
+        seq_children.active[4].operations,
+        &[Operation::Build(3), Operation::Rebuild { from: 3, to: 3 }]
+    );
+    let header_path = seq_children.active[0].view_path.clone();
+    let row_path = seq_children.active[2].view_path.clone();
+    let footer_path = seq_children.active[4].view_path.clone();
+    assert_action(
+        view2.message(&mut state, &header_path, Box::new(()), &mut ()),
+        1,
+    );
+    assert_action(
+        view2.message(&mut state, &row_path, Box::new(()), &mut ()),
+        4,
+    );
+    assert_action(
+        view2.message(&mut state, &footer_path, Box::new(()), &mut ()),
+        3,
+    );
+
+    // And shrinks to nothing
+    let view3 = sequence(0, (record_ops(1), vec![], record_ops(6)));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 3]);
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.deleted.len(), 3);
+    assert!(seq_children.deleted.iter().all(|(idx, _)| *idx == 1));
+    assert_eq!(
+        seq_children.active[1].operations,
+        &[
+            Operation::Build(3),
+            Operation::Rebuild { from: 3, to: 3 },
+            Operation::Rebuild { from: 3, to: 6 }
+        ]
+    );
+    assert_action(
+        view3.message(&mut state, &footer_path, Box::new(()), &mut ()),
+        6,
+    );
+
+    view3.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert!(element.children.as_ref().unwrap().active.is_empty());
+}
+
 // We don't test higher tuples, because these all use the same implementation
@@ -0,0 +1,205 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`when`] and [`when_else`], which conditionally include sequences.
+//!
+//! [`when`]: xilem_core::when
+//! [`when_else`]: xilem_core::when_else
+
+#![expect(
+    clippy::shadow_unrelated,
+    reason = "Deferred: Noisy. Fix is to use scopes"
+)]
+
+mod common;
+use common::*;
+use xilem_core::{MessageResult, View, when, when_else};
+
+fn record_ops(id: u32) -> OperationView<0> {
+    OperationView(id)
+}
+
+/// The ids the active children of `element` were built with, in order.
+fn active_ids(element: &TestElement) -> Vec<u32> {
+    element
+        .children
+        .as_ref()
+        .unwrap()
+        .active
+        .iter()
+        .map(|child| match child.operations.first() {
+            Some(Operation::Build(id)) => *id,
+            _ => panic!("Every child should start with a build"),
+        })
+        .collect()
+}
+
+#[test]
+fn when_toggles() {
+    let view = sequence(
+        0,
+        (record_ops(1), when(false, record_ops(2)), record_ops(3)),
+    );
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 3]);
+
+    let view2 = sequence(0, (record_ops(1), when(true, record_ops(4)), record_ops(3)));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 4, 3]);
+    let shown_path = element.children.as_ref().unwrap().active[1]
+        .view_path
+        .clone();
+    let result = view2.message(&mut state, &shown_path, Box::new(()), &mut ());
+    assert_action(result, 4);
+
+    // Staying shown rebuilds the same element
+    let view3 = sequence(0, (record_ops(1), when(true, record_ops(5)), record_ops(3)));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(
+        element.children.as_ref().unwrap().active[1].operations,
+        &[Operation::Build(4), Operation::Rebuild { from: 4, to: 5 }]
+    );
+
+    let view4 = sequence(
+        0,
+        (record_ops(1), when(false, record_ops(6)), record_ops(3)),
+    );
+    view4.rebuild(&view3, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 3]);
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.deleted.len(), 1);
+    let (child_idx, child) = seq_children.deleted.first().unwrap();
+    assert_eq!(*child_idx, 1);
+    assert_eq!(
+        child.operations,
+        &[
+            Operation::Build(4),
+            Operation::Rebuild { from: 4, to: 5 },
+            Operation::Teardown(5)
+        ]
+    );
+    let result = view4.message(&mut state, &shown_path, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+}
+
+#[test]
+fn when_else_same_branch_rebuilds() {
+    let view = sequence(0, when_else(true, record_ops(1), record_ops(2)));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1]);
+
+    let view2 = sequence(0, when_else(true, record_ops(3), record_ops(4)));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.deleted.is_empty());
+    assert_eq!(seq_children.active.len(), 1);
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(1), Operation::Rebuild { from: 1, to: 3 }]
+    );
+    let path = seq_children.active[0].view_path.clone();
+    let result = view2.message(&mut state, &path, Box::new(()), &mut ());
+    assert_action(result, 3);
+
+    view2.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.active.is_empty());
+    assert_eq!(seq_children.deleted.len(), 1);
+}
+
+#[test]
+fn when_else_switches_branches() {
+    let view = sequence(
+        0,
+        (
+            record_ops(1),
+            when_else(true, record_ops(2), (record_ops(3), record_ops(4))),
+            record_ops(5),
+        ),
+    );
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 2, 5]);
+    let true_path = element.children.as_ref().unwrap().active[1]
+        .view_path
+        .clone();
+
+    // To the false branch, which has two elements
+    let view2 = sequence(
+        0,
+        (
+            record_ops(1),
+            when_else(false, record_ops(6), (record_ops(7), record_ops(8))),
+            record_ops(5),
+        ),
+    );
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 7, 8, 5]);
+    let seq_children = element.children.as_ref().unwrap();
+    // The siblings were rebuilt in place, rather than being recreated
+    assert_eq!(
+        seq_children.active[3].operations,
+        &[Operation::Build(5), Operation::Rebuild { from: 5, to: 5 }]
+    );
+    assert_eq!(seq_children.deleted.len(), 1);
+    let (child_idx, child) = seq_children.deleted.first().unwrap();
+    assert_eq!(*child_idx, 1);
+    assert_eq!(
+        child.operations,
+        &[Operation::Build(2), Operation::Teardown(2)]
+    );
+    let false_path = seq_children.active[2].view_path.clone();
+    let result = view2.message(&mut state, &true_path, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let result = view2.message(&mut state, &false_path, Box::new(()), &mut ());
+    assert_action(result, 8);
+
+    // And back again
+    let view3 = sequence(
+        0,
+        (
+            record_ops(1),
+            when_else(true, record_ops(9), (record_ops(10), record_ops(11))),
+            record_ops(5),
+        ),
+    );
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 9, 5]);
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.deleted.len(), 3);
+    assert!(
+        seq_children.deleted[1..]
+            .iter()
+            .all(|(child_idx, _)| *child_idx == 1)
+    );
+    assert_eq!(
+        seq_children.deleted[2].1.operations,
+        &[Operation::Build(8), Operation::Teardown(8)]
+    );
+
+    // The new true branch has a different path to the old one
+    let new_true_path = seq_children.active[1].view_path.clone();
+    assert_ne!(new_true_path, true_path);
+    let result = view3.message(&mut state, &true_path, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let result = view3.message(&mut state, &false_path, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let result = view3.message(&mut state, &new_true_path, Box::new(()), &mut ());
+    assert_action(result, 9);
+
+    view3.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert!(element.children.as_ref().unwrap().active.is_empty());
+}
@@ -0,0 +1,186 @@
+// Copyright 2025 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`when_empty`], which shows a placeholder in place of an empty sequence.
+//!
+//! [`when_empty`]: xilem_core::when_empty
+
+#![expect(
+    clippy::shadow_unrelated,
+    reason = "Deferred: Noisy. Fix is to use scopes"
+)]
+
+mod common;
+use common::*;
+use xilem_core::{MessageResult, View, WhenEmptyExt};
+
+fn record_ops(id: u32) -> OperationView<0> {
+    OperationView(id)
+}
+
+/// The ids the active children of `element` were built with, in order.
+fn active_ids(element: &TestElement) -> Vec<u32> {
+    element
+        .children
+        .as_ref()
+        .unwrap()
+        .active
+        .iter()
+        .map(|child| match child.operations.first() {
+            Some(Operation::Build(id)) => *id,
+            _ => panic!("Every child should start with a build"),
+        })
+        .collect()
+}
+
+#[test]
+fn empty_shows_placeholder() {
+    let view = sequence(0, Vec::<OperationView<0>>::new().when_empty(record_ops(1)));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1]);
+
+    let result = view.message(
+        &mut state,
+        &element.children.as_ref().unwrap().active[0].view_path,
+        Box::new(()),
+        &mut (),
+    );
+    assert_action(result, 1);
+
+    view.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.active.is_empty());
+    assert_eq!(seq_children.deleted.len(), 1);
+    let (_, child) = seq_children.deleted.first().unwrap();
+    assert_eq!(
+        child.operations,
+        &[Operation::Build(1), Operation::Teardown(1)]
+    );
+}
+
+#[test]
+fn non_empty_hides_placeholder() {
+    let view = sequence(
+        0,
+        vec![record_ops(1), record_ops(2)].when_empty(record_ops(3)),
+    );
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 2]);
+
+    let view2 = sequence(
+        0,
+        vec![record_ops(4), record_ops(5)].when_empty(record_ops(6)),
+    );
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.deleted.is_empty());
+    assert_eq!(seq_children.active.len(), 2);
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(1), Operation::Rebuild { from: 1, to: 4 }]
+    );
+}
+
+#[test]
+fn transitions_preserve_siblings() {
+    let view = sequence(
+        0,
+        (
+            record_ops(1),
+            Vec::<OperationView<0>>::new().when_empty(record_ops(2)),
+            record_ops(3),
+        ),
+    );
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 2, 3]);
+    let placeholder_path = element.children.as_ref().unwrap().active[1]
+        .view_path
+        .clone();
+
+    // Empty to one item
+    let view2 = sequence(
+        0,
+        (
+            record_ops(4),
+            vec![record_ops(5)].when_empty(record_ops(6)),
+            record_ops(7),
+        ),
+    );
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 5, 3]);
+    let seq_children = element.children.as_ref().unwrap();
+    // The siblings were rebuilt in place, rather than being recreated
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(1), Operation::Rebuild { from: 1, to: 4 }]
+    );
+    assert_eq!(
+        seq_children.active[2].operations,
+        &[Operation::Build(3), Operation::Rebuild { from: 3, to: 7 }]
+    );
+    assert_eq!(seq_children.deleted.len(), 1);
+    let (child_idx, child) = seq_children.deleted.first().unwrap();
+    assert_eq!(*child_idx, 1);
+    assert_eq!(
+        child.operations,
+        &[Operation::Build(2), Operation::Teardown(2)]
+    );
+
+    // Messages to the removed placeholder are stale
+    let result = view2.message(&mut state, &placeholder_path, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let item_path = seq_children.active[1].view_path.clone();
+    let result = view2.message(&mut state, &item_path, Box::new(()), &mut ());
+    assert_action(result, 5);
+
+    // And back to empty
+    let view3 = sequence(
+        0,
+        (
+            record_ops(8),
+            Vec::<OperationView<0>>::new().when_empty(record_ops(9)),
+            record_ops(10),
+        ),
+    );
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), &[1, 9, 3]);
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(
+        seq_children.active[2].operations,
+        &[
+            Operation::Build(3),
+            Operation::Rebuild { from: 3, to: 7 },
+            Operation::Rebuild { from: 7, to: 10 }
+        ]
+    );
+    assert_eq!(seq_children.deleted.len(), 2);
+    let (child_idx, child) = seq_children.deleted.last().unwrap();
+    // The placeholder is inserted before the item is removed
+    assert_eq!(*child_idx, 2);
+    assert_eq!(
+        child.operations,
+        &[Operation::Build(5), Operation::Teardown(5)]
+    );
+
+    // The new placeholder has a different path to the old one
+    let new_placeholder_path = seq_children.active[1].view_path.clone();
+    assert_ne!(new_placeholder_path, placeholder_path);
+    let result = view3.message(&mut state, &placeholder_path, Box::new(()), &mut ());
+    assert!(matches!(result, MessageResult::Stale(_)));
+    let result = view3.message(&mut state, &new_placeholder_path, Box::new(()), &mut ());
+    assert_action(result, 9);
+
+    view3.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    assert!(element.children.as_ref().unwrap().active.is_empty());
+}
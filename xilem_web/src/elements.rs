@@ -198,6 +198,17 @@ impl ElementSplice<AnyPod> for DomChildrenSplice<'_, '_, '_, '_> {
         self.ix += n;
     }
 
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if offset == 0 {
+            return true;
+        }
+        let (moved, next) = self.children.move_to_next(offset);
+        self.parent
+            .insert_before(moved.node.as_ref(), Some(next.node.as_ref()))
+            .unwrap_throw();
+        true
+    }
+
     fn delete<R>(&mut self, f: impl FnOnce(Mut<AnyPod>) -> R) -> R {
         let mut child = self.children.delete_next();
         let child = child.as_mut(self.parent, true);
@@ -41,6 +41,19 @@ impl<'v, 's, T> VecSplice<'v, 's, T> {
         self.ix += 1;
     }
 
+    /// Move the element `offset` places after the next one, so that it becomes the next one.
+    ///
+    /// Returns the moved element, and the element which now follows it.
+    pub fn move_to_next(&mut self, offset: usize) -> (&mut T, &mut T) {
+        self.clear_tail();
+        // The scratch holds the next elements in reverse order.
+        let ix = self.scratch.len() - 1 - offset;
+        let value = self.scratch.remove(ix);
+        self.scratch.push(value);
+        let (moved, rest) = self.scratch.split_last_mut().unwrap();
+        (moved, rest.last_mut().unwrap())
+    }
+
     pub fn next_mut(&mut self) -> Option<&mut T> {
         self.v
             .get_mut(self.ix + 1)